@@ -1,11 +1,13 @@
-use crate::Color;
+use crate::{Color, Gradient};
 
 /// The background of some element.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Background {
     /// A solid color
     Color(Color),
-    // TODO: Add gradient and image variants
+    /// A linear gradient between two colors
+    Gradient(Gradient),
+    // TODO: Add image variant
 }
 
 impl From<Color> for Background {
@@ -13,3 +15,9 @@ impl From<Color> for Background {
         Background::Color(color)
     }
 }
+
+impl From<Gradient> for Background {
+    fn from(gradient: Gradient) -> Self {
+        Background::Gradient(gradient)
+    }
+}
@@ -0,0 +1,59 @@
+/// The radii of the corners of a rectangle.
+///
+/// A [`BorderRadius`] can be created from a single radius, shared by all of
+/// the corners, or from an array with the radius of every corner, in
+/// clockwise order starting from the top-left corner.
+///
+/// [`BorderRadius`]: struct.BorderRadius.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BorderRadius {
+    /// The radius of the top-left corner.
+    pub top_left: u16,
+
+    /// The radius of the top-right corner.
+    pub top_right: u16,
+
+    /// The radius of the bottom-right corner.
+    pub bottom_right: u16,
+
+    /// The radius of the bottom-left corner.
+    pub bottom_left: u16,
+}
+
+impl BorderRadius {
+    /// Returns the largest radius of the corners of the [`BorderRadius`].
+    ///
+    /// It can be used where corners of different radii are not supported.
+    ///
+    /// [`BorderRadius`]: struct.BorderRadius.html
+    pub fn max(&self) -> u16 {
+        self.top_left
+            .max(self.top_right)
+            .max(self.bottom_right)
+            .max(self.bottom_left)
+    }
+}
+
+impl From<u16> for BorderRadius {
+    fn from(radius: u16) -> BorderRadius {
+        BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+}
+
+impl From<[u16; 4]> for BorderRadius {
+    fn from(
+        [top_left, top_right, bottom_right, bottom_left]: [u16; 4],
+    ) -> BorderRadius {
+        BorderRadius {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::Color;
+
+/// The border drawn along a single side of a rectangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorderSide {
+    /// The width of the [`BorderSide`].
+    ///
+    /// [`BorderSide`]: struct.BorderSide.html
+    pub width: u16,
+
+    /// The color of the [`BorderSide`].
+    ///
+    /// [`BorderSide`]: struct.BorderSide.html
+    pub color: Color,
+}
+
+impl BorderSide {
+    /// A [`BorderSide`] that is not drawn.
+    ///
+    /// [`BorderSide`]: struct.BorderSide.html
+    pub const NONE: BorderSide = BorderSide {
+        width: 0,
+        color: Color::TRANSPARENT,
+    };
+
+    /// Creates a new [`BorderSide`] with the given width and color.
+    ///
+    /// [`BorderSide`]: struct.BorderSide.html
+    pub fn new(width: u16, color: Color) -> BorderSide {
+        BorderSide { width, color }
+    }
+}
+
+impl Default for BorderSide {
+    fn default() -> BorderSide {
+        BorderSide::NONE
+    }
+}
+
+/// The borders of every side of a rectangle.
+///
+/// Sides that are left out are not drawn:
+///
+/// ```
+/// use iced_core::{BorderSide, Borders, Color};
+///
+/// let underline = Borders {
+///     bottom: BorderSide::new(2, Color::BLACK),
+///     ..Borders::default()
+/// };
+///
+/// assert_eq!(underline.top, BorderSide::NONE);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Borders {
+    /// The border of the top side.
+    pub top: BorderSide,
+
+    /// The border of the right side.
+    pub right: BorderSide,
+
+    /// The border of the bottom side.
+    pub bottom: BorderSide,
+
+    /// The border of the left side.
+    pub left: BorderSide,
+}
+
+impl Borders {
+    /// Creates some [`Borders`] with the same width and color on every side.
+    ///
+    /// [`Borders`]: struct.Borders.html
+    pub fn uniform(width: u16, color: Color) -> Borders {
+        Borders::from(BorderSide::new(width, color))
+    }
+
+    /// Returns true if any side of the [`Borders`] is drawn.
+    ///
+    /// [`Borders`]: struct.Borders.html
+    pub fn is_visible(&self) -> bool {
+        self.top.width > 0
+            || self.right.width > 0
+            || self.bottom.width > 0
+            || self.left.width > 0
+    }
+}
+
+impl From<BorderSide> for Borders {
+    fn from(side: BorderSide) -> Borders {
+        Borders {
+            top: side,
+            right: side,
+            bottom: side,
+            left: side,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Access the clipboard of the system.
+use std::fmt;
+
+/// The kind of contents stored in a clipboard.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Flavor {
+    /// Plain text, encoded in UTF-8.
+    Text,
+
+    /// HTML markup, encoded in UTF-8.
+    Html,
+
+    /// An image, encoded in PNG.
+    Image,
+
+    /// Any other kind of contents, identified by its MIME type.
+    Custom(String),
+}
+
+/// An operation to be performed on the clipboard.
+pub enum Action<T> {
+    /// Reads the contents of the clipboard in the given [`Flavor`] and
+    /// produces a message with them, if available.
+    ///
+    /// [`Flavor`]: enum.Flavor.html
+    Read(Flavor, Box<dyn Fn(Option<Vec<u8>>) -> T + Send>),
+
+    /// Writes the given contents to the clipboard in the given [`Flavor`].
+    ///
+    /// [`Flavor`]: enum.Flavor.html
+    Write(Flavor, Vec<u8>),
+}
+
+impl<T> Action<T> {
+    /// Applies a transformation to the message produced by the [`Action`].
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn map<A>(
+        self,
+        f: std::sync::Arc<dyn Fn(T) -> A + Send + Sync>,
+    ) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        match self {
+            Action::Read(flavor, read) => Action::Read(
+                flavor,
+                Box::new(move |contents| f(read(contents))),
+            ),
+            Action::Write(flavor, contents) => Action::Write(flavor, contents),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Read(flavor, _) => write!(f, "Action::Read({:?})", flavor),
+            Action::Write(flavor, contents) => write!(
+                f,
+                "Action::Write({:?}, {} bytes)",
+                flavor,
+                contents.len()
+            ),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::Size;
+
+/// The strategy used to fit the contents of a widget to its bounding box.
+///
+/// Each variant mirrors the CSS property of the same name, [`object-fit`].
+///
+/// [`object-fit`]: https://developer.mozilla.org/en-US/docs/Web/CSS/object-fit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentFit {
+    /// Scale as big as it can be, while fitting entirely inside the bounds
+    /// and keeping its aspect ratio.
+    Contain,
+
+    /// Scale as small as it can be, while covering the whole bounds and
+    /// keeping its aspect ratio. The contents are cropped.
+    Cover,
+
+    /// Stretch to fill the bounds exactly, ignoring the aspect ratio.
+    Fill,
+
+    /// Behave like [`Contain`], but never scale up the contents.
+    ///
+    /// [`Contain`]: #variant.Contain
+    ScaleDown,
+
+    /// Keep the original size of the contents, cropping them if they
+    /// overflow the bounds.
+    None,
+}
+
+impl ContentFit {
+    /// Returns the size of contents of the given size once fitted in the
+    /// given bounds.
+    pub fn fit(self, content: Size, bounds: Size) -> Size {
+        let horizontal = bounds.width / content.width;
+        let vertical = bounds.height / content.height;
+
+        let scale = match self {
+            ContentFit::Contain => horizontal.min(vertical),
+            ContentFit::Cover => horizontal.max(vertical),
+            ContentFit::Fill => return bounds,
+            ContentFit::ScaleDown => horizontal.min(vertical).min(1.0),
+            ContentFit::None => 1.0,
+        };
+
+        Size::new(content.width * scale, content.height * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_keeps_aspect_ratio() {
+        let content = Size::new(200.0, 100.0);
+        let bounds = Size::new(100.0, 100.0);
+
+        assert_eq!(
+            ContentFit::Contain.fit(content, bounds),
+            Size::new(100.0, 50.0)
+        );
+        assert_eq!(
+            ContentFit::Cover.fit(content, bounds),
+            Size::new(200.0, 100.0)
+        );
+        assert_eq!(ContentFit::Fill.fit(content, bounds), bounds);
+        assert_eq!(
+            ContentFit::ScaleDown.fit(Size::new(20.0, 10.0), bounds),
+            Size::new(20.0, 10.0)
+        );
+        assert_eq!(ContentFit::None.fit(content, bounds), content);
+    }
+}
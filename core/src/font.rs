@@ -1,5 +1,10 @@
+//! Load and use fonts.
+use std::borrow::Cow;
+use std::fmt;
+use std::path::PathBuf;
+
 /// A font.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Font {
     /// The default font.
     ///
@@ -15,6 +20,12 @@ pub enum Font {
         /// The bytes of the external font
         bytes: &'static [u8],
     },
+
+    /// A font registered at runtime, referenced by its family name.
+    ///
+    /// If no font with the given family name has been loaded, the default
+    /// font is used instead.
+    Family(&'static str),
 }
 
 impl Default for Font {
@@ -22,3 +33,83 @@ impl Default for Font {
         Font::Default
     }
 }
+
+/// An error produced when loading a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The font file could not be read.
+    Io(std::io::ErrorKind),
+
+    /// The bytes do not contain a valid font, or its family name is missing.
+    Invalid,
+
+    /// The renderer does not support loading fonts.
+    Unsupported,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(kind) => {
+                write!(f, "the font could not be read: {:?}", kind)
+            }
+            Error::Invalid => write!(f, "the font is invalid"),
+            Error::Unsupported => {
+                write!(f, "the renderer does not support loading fonts")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A font loading operation.
+pub enum Action<T> {
+    /// Registers the font contained in the given bytes and produces a message
+    /// with the result.
+    Load(
+        Cow<'static, [u8]>,
+        Box<dyn Fn(Result<(), Error>) -> T + Send>,
+    ),
+
+    /// Reads and registers the font file at the given path and produces a
+    /// message with the result.
+    LoadFromPath(PathBuf, Box<dyn Fn(Result<(), Error>) -> T + Send>),
+}
+
+impl<T> Action<T> {
+    /// Applies a transformation to the message produced by the [`Action`].
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn map<A>(
+        self,
+        f: std::sync::Arc<dyn Fn(T) -> A + Send + Sync>,
+    ) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        match self {
+            Action::Load(bytes, on_load) => {
+                Action::Load(bytes, Box::new(move |result| f(on_load(result))))
+            }
+            Action::LoadFromPath(path, on_load) => Action::LoadFromPath(
+                path,
+                Box::new(move |result| f(on_load(result))),
+            ),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Load(bytes, _) => {
+                write!(f, "Action::Load({} bytes)", bytes.len())
+            }
+            Action::LoadFromPath(path, _) => {
+                write!(f, "Action::LoadFromPath({:?})", path)
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::Color;
+
+/// A linear transition between two colors.
+///
+/// The [`Gradient`] goes across the whole element it fills, from the
+/// `start` color to the `end` color, following its `angle`.
+///
+/// [`Gradient`]: struct.Gradient.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gradient {
+    /// The direction of the [`Gradient`], in radians.
+    ///
+    /// An angle of `0.0` goes from left to right, while an angle of
+    /// `std::f32::consts::FRAC_PI_2` goes from top to bottom.
+    ///
+    /// [`Gradient`]: struct.Gradient.html
+    pub angle: f32,
+
+    /// The color where the [`Gradient`] starts.
+    ///
+    /// [`Gradient`]: struct.Gradient.html
+    pub start: Color,
+
+    /// The color where the [`Gradient`] ends.
+    ///
+    /// [`Gradient`]: struct.Gradient.html
+    pub end: Color,
+}
+
+impl Gradient {
+    /// Creates a new [`Gradient`] from its angle, in radians, and its colors.
+    ///
+    /// [`Gradient`]: struct.Gradient.html
+    pub fn new(angle: f32, start: Color, end: Color) -> Gradient {
+        Gradient { angle, start, end }
+    }
+
+    /// Creates a new vertical [`Gradient`], going from top to bottom.
+    ///
+    /// [`Gradient`]: struct.Gradient.html
+    pub fn vertical(top: Color, bottom: Color) -> Gradient {
+        Gradient::new(std::f32::consts::FRAC_PI_2, top, bottom)
+    }
+
+    /// Creates a new horizontal [`Gradient`], going from left to right.
+    ///
+    /// [`Gradient`]: struct.Gradient.html
+    pub fn horizontal(left: Color, right: Color) -> Gradient {
+        Gradient::new(0.0, left, right)
+    }
+
+    /// Returns the color of the [`Gradient`] halfway through.
+    ///
+    /// It can be used where gradients are not supported.
+    ///
+    /// [`Gradient`]: struct.Gradient.html
+    pub fn average(&self) -> Color {
+        Color {
+            r: (self.start.r + self.end.r) / 2.0,
+            g: (self.start.g + self.end.g) / 2.0,
+            b: (self.start.b + self.end.b) / 2.0,
+            a: (self.start.a + self.end.a) / 2.0,
+        }
+    }
+}
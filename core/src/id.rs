@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+use std::sync::atomic::{self, AtomicUsize};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The identifier of a widget.
+///
+/// It allows referring to a widget outside of the view logic, for instance
+/// to focus it with a command.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(Internal);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Internal {
+    Unique(usize),
+    Custom(Cow<'static, str>),
+}
+
+impl Id {
+    /// Creates a custom [`Id`] with the given name.
+    ///
+    /// [`Id`]: struct.Id.html
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Id {
+        Id(Internal::Custom(name.into()))
+    }
+
+    /// Creates a unique [`Id`], different from any other.
+    ///
+    /// [`Id`]: struct.Id.html
+    pub fn unique() -> Id {
+        Id(Internal::Unique(
+            NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_ids_are_different() {
+        assert_ne!(Id::unique(), Id::unique());
+        assert_eq!(Id::new("search"), Id::new(String::from("search")));
+    }
+}
@@ -15,6 +15,38 @@ pub struct ModifiersState {
 }
 
 impl ModifiersState {
+    /// No modifiers.
+    pub const NONE: ModifiersState = ModifiersState {
+        shift: false,
+        control: false,
+        alt: false,
+        logo: false,
+    };
+
+    /// The shift modifier.
+    pub const SHIFT: ModifiersState = ModifiersState {
+        shift: true,
+        ..ModifiersState::NONE
+    };
+
+    /// The control modifier.
+    pub const CONTROL: ModifiersState = ModifiersState {
+        control: true,
+        ..ModifiersState::NONE
+    };
+
+    /// The alt modifier.
+    pub const ALT: ModifiersState = ModifiersState {
+        alt: true,
+        ..ModifiersState::NONE
+    };
+
+    /// The logo modifier (e.g. windows key, command key...).
+    pub const LOGO: ModifiersState = ModifiersState {
+        logo: true,
+        ..ModifiersState::NONE
+    };
+
     /// Returns true if the current [`ModifiersState`] has at least the same
     /// modifiers enabled as the given value, and false otherwise.
     ///
@@ -28,3 +60,16 @@ impl ModifiersState {
         shift && control && alt && logo
     }
 }
+
+impl std::ops::BitOr for ModifiersState {
+    type Output = ModifiersState;
+
+    fn bitor(self, other: ModifiersState) -> ModifiersState {
+        ModifiersState {
+            shift: self.shift || other.shift,
+            control: self.control || other.control,
+            alt: self.alt || other.alt,
+            logo: self.logo || other.logo,
+        }
+    }
+}
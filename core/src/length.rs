@@ -1,5 +1,5 @@
 /// The strategy used to fill space in a specific dimension.
-#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Length {
     /// Fill all the remaining space
     Fill,
@@ -18,6 +18,26 @@ pub enum Length {
 
     /// Fill a fixed amount of space
     Units(u16),
+
+    /// Fill a percentage of the available space of the parent.
+    ///
+    /// It behaves like `Length::Shrink` when the available space is
+    /// unbounded, like inside a `Scrollable`.
+    Percent(f32),
+
+    /// Fill a percentage of the available space of the parent, kept between
+    /// a minimum and a maximum amount of units.
+    ///
+    /// It takes the maximum amount of units when the available space is
+    /// unbounded.
+    Clamped {
+        /// The minimum amount of units
+        min: u16,
+        /// The maximum amount of units
+        max: u16,
+        /// The preferred percentage of the available space
+        preferred: f32,
+    },
 }
 
 impl Length {
@@ -34,6 +54,61 @@ impl Length {
             Length::FillPortion(factor) => *factor,
             Length::Shrink => 0,
             Length::Units(_) => 0,
+            Length::Percent(_) => 0,
+            Length::Clamped { .. } => 0,
+        }
+    }
+
+    /// Returns the fixed amount of space the [`Length`] takes, given the
+    /// available space of its parent, if any.
+    ///
+    /// [`Length`]: enum.Length.html
+    pub fn fixed(&self, available: f32) -> Option<f32> {
+        match self {
+            Length::Fill | Length::FillPortion(_) | Length::Shrink => None,
+            Length::Units(units) => Some(f32::from(*units)),
+            Length::Percent(percent) => {
+                if available.is_finite() {
+                    Some(available * percent / 100.0)
+                } else {
+                    None
+                }
+            }
+            Length::Clamped {
+                min,
+                max,
+                preferred,
+            } => {
+                let preferred = if available.is_finite() {
+                    available * preferred / 100.0
+                } else {
+                    f32::from(*max)
+                };
+
+                Some(preferred.min(f32::from(*max)).max(f32::from(*min)))
+            }
+        }
+    }
+}
+
+impl std::hash::Hash for Length {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Length::Fill | Length::Shrink => {}
+            Length::FillPortion(factor) => factor.hash(state),
+            Length::Units(units) => units.hash(state),
+            Length::Percent(percent) => percent.to_bits().hash(state),
+            Length::Clamped {
+                min,
+                max,
+                preferred,
+            } => {
+                min.hash(state);
+                max.hash(state);
+                preferred.to_bits().hash(state);
+            }
         }
     }
 }
@@ -43,3 +118,23 @@ impl From<u16> for Length {
         Length::Units(units)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_lengths_resolve_against_available_space() {
+        let clamped = Length::Clamped {
+            min: 100,
+            max: 300,
+            preferred: 50.0,
+        };
+
+        assert_eq!(Length::Percent(25.0).fixed(400.0), Some(100.0));
+        assert_eq!(Length::Percent(25.0).fixed(f32::INFINITY), None);
+        assert_eq!(clamped.fixed(400.0), Some(200.0));
+        assert_eq!(clamped.fixed(100.0), Some(100.0));
+        assert_eq!(clamped.fixed(f32::INFINITY), Some(300.0));
+    }
+}
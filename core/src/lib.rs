@@ -14,25 +14,44 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod clipboard;
+pub mod font;
 pub mod keyboard;
 pub mod mouse;
+pub mod notification;
+pub mod stylus;
+pub mod system;
+pub mod touch;
+pub mod tray;
+pub mod window;
 
 mod align;
 mod background;
+mod border_radius;
+mod borders;
 mod color;
-mod font;
+mod content_fit;
+mod gradient;
+mod id;
 mod length;
 mod point;
 mod rectangle;
+mod shadow;
 mod size;
 mod vector;
 
 pub use align::{Align, HorizontalAlignment, VerticalAlignment};
 pub use background::Background;
+pub use border_radius::BorderRadius;
+pub use borders::{BorderSide, Borders};
 pub use color::Color;
+pub use content_fit::ContentFit;
 pub use font::Font;
+pub use gradient::Gradient;
+pub use id::Id;
 pub use length::Length;
 pub use point::Point;
 pub use rectangle::Rectangle;
+pub use shadow::Shadow;
 pub use size::Size;
 pub use vector::Vector;
@@ -1,8 +1,10 @@
 //! Reuse basic mouse types.
 mod button;
+mod double_click;
 mod event;
 mod interaction;
 
 pub use button::Button;
+pub use double_click::DoubleClick;
 pub use event::{Event, ScrollDelta};
 pub use interaction::Interaction;
@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+/// The settings used to recognize double and triple clicks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoubleClick {
+    /// The maximum time between two clicks for them to be consecutive.
+    pub threshold: Duration,
+
+    /// The maximum distance between two clicks for them to be consecutive,
+    /// in logical pixels.
+    pub distance: u16,
+}
+
+impl Default for DoubleClick {
+    fn default() -> DoubleClick {
+        DoubleClick {
+            threshold: Duration::from_millis(300),
+            distance: 4,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::Button;
+use crate::Vector;
 
 /// A mouse event.
 ///
@@ -23,6 +24,15 @@ pub enum Event {
         y: f32,
     },
 
+    /// The mouse was moved by a raw amount, without any acceleration.
+    ///
+    /// It is only reported while the mouse cursor is grabbed, even if the
+    /// cursor cannot move any further.
+    RawMotion {
+        /// The movement of the mouse.
+        delta: Vector,
+    },
+
     /// A mouse button was pressed.
     ButtonPressed(Button),
 
@@ -0,0 +1,80 @@
+//! Notify the user through the desktop.
+use std::fmt;
+
+/// A notification displayed by the notification service of the system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// The title of the notification.
+    pub title: String,
+
+    /// The body of the notification.
+    pub body: String,
+
+    /// The icon of the notification, if any.
+    ///
+    /// It can be either the path to an image file or the name of an icon of
+    /// the system theme. It is ignored on macOS.
+    pub icon: Option<String>,
+}
+
+/// An error produced when showing a [`Notification`].
+///
+/// [`Notification`]: struct.Notification.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The notification service of the system failed to show the
+    /// notification, for the given reason.
+    Failed(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Failed(reason) => {
+                write!(f, "the notification could not be shown: {}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A notification operation.
+pub enum Action<T> {
+    /// Shows the given [`Notification`] and produces a message with the
+    /// result.
+    ///
+    /// [`Notification`]: struct.Notification.html
+    Show(Notification, Box<dyn Fn(Result<(), Error>) -> T + Send>),
+}
+
+impl<T> Action<T> {
+    /// Applies a transformation to the message produced by the [`Action`].
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn map<A>(
+        self,
+        f: std::sync::Arc<dyn Fn(T) -> A + Send + Sync>,
+    ) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        match self {
+            Action::Show(notification, on_show) => Action::Show(
+                notification,
+                Box::new(move |result| f(on_show(result))),
+            ),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Show(notification, _) => {
+                write!(f, "Action::Show({:?})", notification)
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{Color, Vector};
+
+/// A drop shadow cast by an element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// The color of the [`Shadow`].
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub color: Color,
+
+    /// The offset of the [`Shadow`] from the element casting it.
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub offset: Vector,
+
+    /// The blur radius of the [`Shadow`].
+    ///
+    /// The edges of the [`Shadow`] fade out over this distance.
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub blur_radius: f32,
+}
@@ -0,0 +1,64 @@
+//! Reuse basic stylus types.
+use crate::Point;
+
+/// A stylus interaction.
+///
+/// Only the interactions of a stylus touching the surface are reported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// The stylus touched the surface.
+    Pressed(Sample),
+
+    /// The stylus was moved over the surface.
+    Moved(Sample),
+
+    /// The stylus was lifted from the surface.
+    Lifted(Sample),
+
+    /// The system cancelled the tracking of the stylus.
+    Lost(Sample),
+}
+
+impl Event {
+    /// Returns the [`Sample`] of the stylus reported by the [`Event`].
+    ///
+    /// [`Sample`]: struct.Sample.html
+    /// [`Event`]: enum.Event.html
+    pub fn sample(&self) -> Sample {
+        match self {
+            Event::Pressed(sample)
+            | Event::Moved(sample)
+            | Event::Lifted(sample)
+            | Event::Lost(sample) => *sample,
+        }
+    }
+}
+
+/// The state of a stylus at a specific point in time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// The position of the tip of the stylus.
+    pub position: Point,
+
+    /// The pressure applied to the surface, from `0.0` to `1.0`.
+    pub pressure: f32,
+
+    /// The angle between the stylus and the normal of the surface, in
+    /// radians.
+    ///
+    /// It is `0.0` when the stylus is perpendicular to the surface.
+    pub tilt: f32,
+
+    /// The tool in use.
+    pub tool: Tool,
+}
+
+/// The end of a stylus touching the surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tool {
+    /// The tip of the stylus.
+    Pen,
+
+    /// The eraser of the stylus.
+    Eraser,
+}
@@ -0,0 +1,11 @@
+//! Query the system the application runs on.
+
+/// The color scheme preferred by the user, as configured in the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Appearance {
+    /// Dark text on light backgrounds.
+    Light,
+
+    /// Light text on dark backgrounds.
+    Dark,
+}
@@ -0,0 +1,51 @@
+//! Reuse basic touch types.
+use crate::Point;
+
+/// A touch interaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// A finger touched the screen.
+    FingerPressed {
+        /// The identifier of the finger
+        id: Finger,
+
+        /// The position of the finger
+        position: Point,
+    },
+
+    /// A finger was moved over the screen.
+    FingerMoved {
+        /// The identifier of the finger
+        id: Finger,
+
+        /// The new position of the finger
+        position: Point,
+    },
+
+    /// A finger was lifted from the screen.
+    FingerLifted {
+        /// The identifier of the finger
+        id: Finger,
+
+        /// The last position of the finger
+        position: Point,
+    },
+
+    /// The system cancelled the tracking of a finger.
+    ///
+    /// This can happen, for instance, when the window loses focus.
+    FingerLost {
+        /// The identifier of the finger
+        id: Finger,
+
+        /// The last position of the finger
+        position: Point,
+    },
+}
+
+/// A unique identifier of a finger touching the screen.
+///
+/// The identifier of a finger is only guaranteed to be unique until the
+/// finger is lifted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Finger(pub u64);
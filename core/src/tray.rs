@@ -0,0 +1,241 @@
+//! Show an icon in the system tray.
+use crate::window::Icon;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// An icon in the system tray, along with its tooltip and its menu.
+///
+/// A [`Tray`] produces a message when its icon is clicked and when an
+/// [`Item`] of its menu is selected.
+///
+/// [`Tray`]: struct.Tray.html
+/// [`Item`]: struct.Item.html
+pub struct Tray<T> {
+    icon: Icon,
+    tooltip: Option<String>,
+    on_click: Option<Box<dyn Fn() -> T + Send>>,
+    menu: Vec<Item<T>>,
+}
+
+impl<T> Tray<T> {
+    /// Creates a new [`Tray`] with the given [`Icon`].
+    ///
+    /// [`Tray`]: struct.Tray.html
+    /// [`Icon`]: ../window/struct.Icon.html
+    pub fn new(icon: Icon) -> Self {
+        Tray {
+            icon,
+            tooltip: None,
+            on_click: None,
+            menu: Vec::new(),
+        }
+    }
+
+    /// Sets the tooltip of the [`Tray`], shown while the cursor is over its
+    /// icon.
+    ///
+    /// [`Tray`]: struct.Tray.html
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Sets the message produced when the icon of the [`Tray`] is clicked.
+    ///
+    /// [`Tray`]: struct.Tray.html
+    pub fn on_click(mut self, message: T) -> Self
+    where
+        T: Clone + Send + 'static,
+    {
+        self.on_click = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Adds an [`Item`] to the menu of the [`Tray`].
+    ///
+    /// The menu is opened with a right click on the icon.
+    ///
+    /// [`Item`]: struct.Item.html
+    /// [`Tray`]: struct.Tray.html
+    pub fn push(mut self, item: Item<T>) -> Self {
+        self.menu.push(item);
+        self
+    }
+
+    /// Returns the [`Icon`] of the [`Tray`].
+    ///
+    /// [`Icon`]: ../window/struct.Icon.html
+    /// [`Tray`]: struct.Tray.html
+    pub fn icon(&self) -> &Icon {
+        &self.icon
+    }
+
+    /// Returns the tooltip of the [`Tray`], if any.
+    ///
+    /// [`Tray`]: struct.Tray.html
+    pub fn tooltip_text(&self) -> Option<&str> {
+        self.tooltip.as_deref()
+    }
+
+    /// Returns the items of the menu of the [`Tray`].
+    ///
+    /// [`Tray`]: struct.Tray.html
+    pub fn menu(&self) -> &[Item<T>] {
+        &self.menu
+    }
+
+    /// Produces the message of a click on the icon of the [`Tray`], if any.
+    ///
+    /// [`Tray`]: struct.Tray.html
+    pub fn click(&self) -> Option<T> {
+        self.on_click.as_ref().map(|on_click| on_click())
+    }
+
+    /// Applies a transformation to the messages produced by the [`Tray`].
+    ///
+    /// [`Tray`]: struct.Tray.html
+    pub fn map<A>(self, f: Arc<dyn Fn(T) -> A + Send + Sync>) -> Tray<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        Tray {
+            icon: self.icon,
+            tooltip: self.tooltip,
+            on_click: self.on_click.map(|on_click| {
+                let f = f.clone();
+
+                Box::new(move || f(on_click())) as Box<dyn Fn() -> A + Send>
+            }),
+            menu: self
+                .menu
+                .into_iter()
+                .map(|item| item.map(f.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Tray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tray")
+            .field("tooltip", &self.tooltip)
+            .field("menu", &self.menu)
+            .finish()
+    }
+}
+
+/// An entry in the menu of a [`Tray`].
+///
+/// [`Tray`]: struct.Tray.html
+pub struct Item<T> {
+    label: String,
+    on_select: Option<Box<dyn Fn() -> T + Send>>,
+}
+
+impl<T> Item<T> {
+    /// Creates a new [`Item`] with the given label.
+    ///
+    /// An [`Item`] without a message is displayed as disabled.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub fn new(label: impl Into<String>) -> Self {
+        Item {
+            label: label.into(),
+            on_select: None,
+        }
+    }
+
+    /// Sets the message produced when the [`Item`] is selected.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub fn on_select(mut self, message: T) -> Self
+    where
+        T: Clone + Send + 'static,
+    {
+        self.on_select = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Returns the label of the [`Item`].
+    ///
+    /// [`Item`]: struct.Item.html
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns whether the [`Item`] can be selected.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub fn is_enabled(&self) -> bool {
+        self.on_select.is_some()
+    }
+
+    /// Produces the message of selecting the [`Item`], if any.
+    ///
+    /// [`Item`]: struct.Item.html
+    pub fn select(&self) -> Option<T> {
+        self.on_select.as_ref().map(|on_select| on_select())
+    }
+
+    fn map<A>(self, f: Arc<dyn Fn(T) -> A + Send + Sync>) -> Item<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        Item {
+            label: self.label,
+            on_select: self.on_select.map(|on_select| {
+                Box::new(move || f(on_select())) as Box<dyn Fn() -> A + Send>
+            }),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Item<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Item")
+            .field("label", &self.label)
+            .field("is_enabled", &self.is_enabled())
+            .finish()
+    }
+}
+
+/// An operation to be performed on the system tray.
+pub enum Action<T> {
+    /// Shows the given [`Tray`], replacing the current one, if any.
+    ///
+    /// [`Tray`]: struct.Tray.html
+    Show(Tray<T>),
+
+    /// Removes the current [`Tray`], if any.
+    ///
+    /// [`Tray`]: struct.Tray.html
+    Remove,
+}
+
+impl<T> Action<T> {
+    /// Applies a transformation to the messages produced by the [`Action`].
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn map<A>(self, f: Arc<dyn Fn(T) -> A + Send + Sync>) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        match self {
+            Action::Show(tray) => Action::Show(tray.map(f)),
+            Action::Remove => Action::Remove,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Show(tray) => write!(f, "Action::Show({:?})", tray),
+            Action::Remove => write!(f, "Action::Remove"),
+        }
+    }
+}
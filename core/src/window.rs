@@ -0,0 +1,16 @@
+//! Interact with the windows of your application.
+mod action;
+mod direction;
+mod icon;
+mod id;
+mod monitor;
+mod position;
+mod settings;
+
+pub use action::Action;
+pub use direction::Direction;
+pub use icon::{Error as IconError, Icon};
+pub use id::Id;
+pub use monitor::Monitor;
+pub use position::Position;
+pub use settings::Settings;
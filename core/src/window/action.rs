@@ -0,0 +1,309 @@
+use crate::window::{Direction, Id, Monitor, Settings};
+
+use std::fmt;
+
+/// An operation to be performed on the windows of an application.
+///
+/// You can perform an [`Action`] by returning a `Command` from the `update`
+/// logic of your application.
+///
+/// Actions that change a window apply to the window with the given [`Id`].
+/// Single-window applications can target their window with [`Id::MAIN`].
+///
+/// [`Action`]: enum.Action.html
+/// [`Id`]: struct.Id.html
+/// [`Id::MAIN`]: struct.Id.html#associatedconstant.MAIN
+pub enum Action<T> {
+    /// Shows or hides the mouse cursor while it is over the window.
+    SetCursorVisible(Id, bool),
+
+    /// Grabs or releases the mouse cursor.
+    ///
+    /// A grabbed cursor is confined to the window and raw mouse motion is
+    /// reported, which allows the cursor to move indefinitely.
+    SetCursorGrab(Id, bool),
+
+    /// Resizes the window to the given logical size.
+    Resize {
+        /// The window to resize
+        id: Id,
+
+        /// The new logical width of the window
+        width: u32,
+
+        /// The new logical height of the window
+        height: u32,
+    },
+
+    /// Moves the window to the given logical position on the desktop.
+    Move {
+        /// The window to move
+        id: Id,
+
+        /// The new logical X coordinate of the window
+        x: i32,
+
+        /// The new logical Y coordinate of the window
+        y: i32,
+    },
+
+    /// Moves the window to the center of its current monitor.
+    Center(Id),
+
+    /// Starts moving the window with the cursor, until the left mouse button
+    /// is released.
+    ///
+    /// It should be performed right after the left mouse button is pressed,
+    /// which allows drawing a custom title bar.
+    Drag(Id),
+
+    /// Starts resizing the window from the given [`Direction`] with the
+    /// cursor, until the left mouse button is released.
+    ///
+    /// It should be performed right after the left mouse button is pressed,
+    /// which allows drawing custom resize borders.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    DragResize(Id, Direction),
+
+    /// Changes the minimum logical size of the window, if any.
+    SetMinSize(Id, Option<(u32, u32)>),
+
+    /// Changes the maximum logical size of the window, if any.
+    SetMaxSize(Id, Option<(u32, u32)>),
+
+    /// Makes the window resizable or not.
+    SetResizable(Id, bool),
+
+    /// Keeps the window on top of other windows, or not.
+    SetAlwaysOnTop(Id, bool),
+
+    /// Minimizes or restores the window.
+    Minimize(Id, bool),
+
+    /// Maximizes or restores the window.
+    Maximize(Id, bool),
+
+    /// Makes the window fill its current monitor without borders, or restores
+    /// it.
+    SetFullscreen(Id, bool),
+
+    /// Shows or hides the decorations of the window, like its border and its
+    /// title bar.
+    SetDecorations(Id, bool),
+
+    /// Shows or hides the window.
+    ///
+    /// A hidden window keeps running, but it is not displayed on the desktop
+    /// nor in the task bar.
+    SetVisible(Id, bool),
+
+    /// Scales the user interface of the window by the given factor, on top of
+    /// the scale factor of the system.
+    ///
+    /// This can be used to zoom in and out.
+    SetScaleFactor(Id, f64),
+
+    /// Queries the monitors connected to the system and produces a message
+    /// with them.
+    FetchMonitors(Box<dyn Fn(Vec<Monitor>) -> T + Send>),
+
+    /// Requests the attention of the user, for instance by bouncing the icon
+    /// of the application in the dock.
+    ///
+    /// It is only supported on macOS.
+    RequestAttention(Id),
+
+    /// Opens a new window with the given [`Id`] and [`Settings`].
+    ///
+    /// Only multi-window applications can open new windows. Otherwise, or if
+    /// the window cannot be opened, a `SpawnFailed` window event is produced.
+    ///
+    /// [`Id`]: struct.Id.html
+    /// [`Settings`]: struct.Settings.html
+    Spawn {
+        /// The identifier of the new window
+        id: Id,
+
+        /// The settings of the new window
+        settings: Settings,
+    },
+
+    /// Closes the window with the given [`Id`].
+    ///
+    /// Closing the last window of an application exits it. Single-window
+    /// applications can only close their window using [`Id::MAIN`].
+    ///
+    /// [`Id`]: struct.Id.html
+    Close(Id),
+
+    /// Exits the application, closing all of its windows.
+    Exit,
+}
+
+impl<T> Action<T> {
+    /// Returns the [`Id`] of the window the [`Action`] applies to, if any.
+    ///
+    /// [`Id`]: struct.Id.html
+    /// [`Action`]: enum.Action.html
+    pub fn window(&self) -> Option<Id> {
+        match self {
+            Action::SetCursorVisible(id, _)
+            | Action::SetCursorGrab(id, _)
+            | Action::Resize { id, .. }
+            | Action::Move { id, .. }
+            | Action::Center(id)
+            | Action::Drag(id)
+            | Action::DragResize(id, _)
+            | Action::SetMinSize(id, _)
+            | Action::SetMaxSize(id, _)
+            | Action::SetResizable(id, _)
+            | Action::SetAlwaysOnTop(id, _)
+            | Action::Minimize(id, _)
+            | Action::Maximize(id, _)
+            | Action::SetFullscreen(id, _)
+            | Action::SetDecorations(id, _)
+            | Action::SetVisible(id, _)
+            | Action::SetScaleFactor(id, _)
+            | Action::RequestAttention(id)
+            | Action::Close(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Applies a transformation to the message produced by the [`Action`].
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn map<A>(
+        self,
+        f: std::sync::Arc<dyn Fn(T) -> A + Send + Sync>,
+    ) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        match self {
+            Action::SetCursorVisible(id, is_visible) => {
+                Action::SetCursorVisible(id, is_visible)
+            }
+            Action::SetCursorGrab(id, is_grabbed) => {
+                Action::SetCursorGrab(id, is_grabbed)
+            }
+            Action::Resize { id, width, height } => {
+                Action::Resize { id, width, height }
+            }
+            Action::Move { id, x, y } => Action::Move { id, x, y },
+            Action::Center(id) => Action::Center(id),
+            Action::Drag(id) => Action::Drag(id),
+            Action::DragResize(id, direction) => {
+                Action::DragResize(id, direction)
+            }
+            Action::SetMinSize(id, size) => Action::SetMinSize(id, size),
+            Action::SetMaxSize(id, size) => Action::SetMaxSize(id, size),
+            Action::SetResizable(id, is_resizable) => {
+                Action::SetResizable(id, is_resizable)
+            }
+            Action::SetAlwaysOnTop(id, is_always_on_top) => {
+                Action::SetAlwaysOnTop(id, is_always_on_top)
+            }
+            Action::Minimize(id, is_minimized) => {
+                Action::Minimize(id, is_minimized)
+            }
+            Action::Maximize(id, is_maximized) => {
+                Action::Maximize(id, is_maximized)
+            }
+            Action::SetFullscreen(id, is_fullscreen) => {
+                Action::SetFullscreen(id, is_fullscreen)
+            }
+            Action::SetDecorations(id, has_decorations) => {
+                Action::SetDecorations(id, has_decorations)
+            }
+            Action::SetVisible(id, is_visible) => {
+                Action::SetVisible(id, is_visible)
+            }
+            Action::SetScaleFactor(id, scale_factor) => {
+                Action::SetScaleFactor(id, scale_factor)
+            }
+            Action::FetchMonitors(fetch) => {
+                Action::FetchMonitors(Box::new(move |monitors| {
+                    f(fetch(monitors))
+                }))
+            }
+            Action::RequestAttention(id) => Action::RequestAttention(id),
+            Action::Spawn { id, settings } => Action::Spawn { id, settings },
+            Action::Close(id) => Action::Close(id),
+            Action::Exit => Action::Exit,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::SetCursorVisible(id, is_visible) => {
+                write!(f, "Action::SetCursorVisible({:?}, {})", id, is_visible)
+            }
+            Action::SetCursorGrab(id, is_grabbed) => {
+                write!(f, "Action::SetCursorGrab({:?}, {})", id, is_grabbed)
+            }
+            Action::Resize { id, width, height } => write!(
+                f,
+                "Action::Resize {{ id: {:?}, width: {}, height: {} }}",
+                id, width, height
+            ),
+            Action::Move { id, x, y } => {
+                write!(f, "Action::Move {{ id: {:?}, x: {}, y: {} }}", id, x, y)
+            }
+            Action::Center(id) => write!(f, "Action::Center({:?})", id),
+            Action::Drag(id) => write!(f, "Action::Drag({:?})", id),
+            Action::DragResize(id, direction) => {
+                write!(f, "Action::DragResize({:?}, {:?})", id, direction)
+            }
+            Action::SetMinSize(id, size) => {
+                write!(f, "Action::SetMinSize({:?}, {:?})", id, size)
+            }
+            Action::SetMaxSize(id, size) => {
+                write!(f, "Action::SetMaxSize({:?}, {:?})", id, size)
+            }
+            Action::SetResizable(id, is_resizable) => {
+                write!(f, "Action::SetResizable({:?}, {})", id, is_resizable)
+            }
+            Action::SetAlwaysOnTop(id, is_always_on_top) => write!(
+                f,
+                "Action::SetAlwaysOnTop({:?}, {})",
+                id, is_always_on_top
+            ),
+            Action::Minimize(id, is_minimized) => {
+                write!(f, "Action::Minimize({:?}, {})", id, is_minimized)
+            }
+            Action::Maximize(id, is_maximized) => {
+                write!(f, "Action::Maximize({:?}, {})", id, is_maximized)
+            }
+            Action::SetFullscreen(id, is_fullscreen) => {
+                write!(f, "Action::SetFullscreen({:?}, {})", id, is_fullscreen)
+            }
+            Action::SetDecorations(id, has_decorations) => write!(
+                f,
+                "Action::SetDecorations({:?}, {})",
+                id, has_decorations
+            ),
+            Action::SetVisible(id, is_visible) => {
+                write!(f, "Action::SetVisible({:?}, {})", id, is_visible)
+            }
+            Action::SetScaleFactor(id, scale_factor) => {
+                write!(f, "Action::SetScaleFactor({:?}, {})", id, scale_factor)
+            }
+            Action::FetchMonitors(_) => write!(f, "Action::FetchMonitors"),
+            Action::RequestAttention(id) => {
+                write!(f, "Action::RequestAttention({:?})", id)
+            }
+            Action::Spawn { id, settings } => write!(
+                f,
+                "Action::Spawn {{ id: {:?}, settings: {:?} }}",
+                id, settings
+            ),
+            Action::Close(id) => write!(f, "Action::Close({:?})", id),
+            Action::Exit => write!(f, "Action::Exit"),
+        }
+    }
+}
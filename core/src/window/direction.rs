@@ -0,0 +1,75 @@
+use crate::{Point, Size};
+
+/// A border or a corner of a window, used to resize it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl Direction {
+    /// Returns the [`Direction`] of the border of a window with the given
+    /// size that contains the given position, if any.
+    ///
+    /// The borders of the window are considered to be `border` units wide.
+    /// This allows windows without decorations to provide their own resize
+    /// regions.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    pub fn hit_test(
+        size: Size,
+        position: Point,
+        border: f32,
+    ) -> Option<Direction> {
+        let is_north = position.y < border;
+        let is_south = position.y >= size.height - border;
+        let is_west = position.x < border;
+        let is_east = position.x >= size.width - border;
+
+        match (is_north, is_south, is_west, is_east) {
+            (true, _, true, _) => Some(Direction::NorthWest),
+            (true, _, _, true) => Some(Direction::NorthEast),
+            (_, true, true, _) => Some(Direction::SouthWest),
+            (_, true, _, true) => Some(Direction::SouthEast),
+            (true, _, _, _) => Some(Direction::North),
+            (_, true, _, _) => Some(Direction::South),
+            (_, _, true, _) => Some(Direction::West),
+            (_, _, _, true) => Some(Direction::East),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit_test_finds_borders_and_corners() {
+        let size = Size::new(100.0, 50.0);
+
+        assert_eq!(
+            Direction::hit_test(size, Point::new(50.0, 25.0), 4.0),
+            None
+        );
+        assert_eq!(
+            Direction::hit_test(size, Point::new(50.0, 1.0), 4.0),
+            Some(Direction::North)
+        );
+        assert_eq!(
+            Direction::hit_test(size, Point::new(99.0, 49.0), 4.0),
+            Some(Direction::SouthEast)
+        );
+        assert_eq!(
+            Direction::hit_test(size, Point::new(0.0, 25.0), 4.0),
+            Some(Direction::West)
+        );
+    }
+}
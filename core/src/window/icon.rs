@@ -0,0 +1,160 @@
+use std::fmt;
+
+/// The icon of a window, shown in its title bar and in the task bar.
+///
+/// An [`Icon`] holds its pixels in 8-bit RGBA, row by row, starting from the
+/// top-left corner.
+///
+/// [`Icon`]: struct.Icon.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Icon {
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+}
+
+impl Icon {
+    /// Creates an [`Icon`] from its RGBA pixels and its dimensions.
+    ///
+    /// [`Icon`]: struct.Icon.html
+    pub fn from_rgba(
+        rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+    ) -> Result<Icon, Error> {
+        let pixel_count = rgba.len() / 4;
+
+        if pixel_count * 4 != rgba.len() {
+            return Err(Error::InvalidData {
+                byte_count: rgba.len(),
+            });
+        }
+
+        if pixel_count as u64 != u64::from(width) * u64::from(height) {
+            return Err(Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            });
+        }
+
+        Ok(Icon {
+            rgba,
+            width,
+            height,
+        })
+    }
+
+    /// Creates an [`Icon`] by decoding the contents of an image file.
+    ///
+    /// The format of the image is guessed from its contents. PNG and ICO
+    /// files, among others, are supported.
+    ///
+    /// [`Icon`]: struct.Icon.html
+    #[cfg(feature = "image")]
+    pub fn from_file_data(data: &[u8]) -> Result<Icon, Error> {
+        let image = image::load_from_memory(data)
+            .map_err(|error| Error::Decoding(error.to_string()))?
+            .into_rgba8();
+
+        let (width, height) = image.dimensions();
+
+        Icon::from_rgba(image.into_raw(), width, height)
+    }
+
+    /// Returns the RGBA pixels of the [`Icon`].
+    ///
+    /// [`Icon`]: struct.Icon.html
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    /// Returns the width of the [`Icon`], in pixels.
+    ///
+    /// [`Icon`]: struct.Icon.html
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`Icon`], in pixels.
+    ///
+    /// [`Icon`]: struct.Icon.html
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+/// An error produced when creating an [`Icon`].
+///
+/// [`Icon`]: struct.Icon.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The amount of bytes is not a multiple of 4.
+    InvalidData {
+        /// The amount of bytes provided
+        byte_count: usize,
+    },
+
+    /// The amount of pixels does not match the given dimensions.
+    DimensionsMismatch {
+        /// The width provided
+        width: u32,
+        /// The height provided
+        height: u32,
+        /// The amount of pixels provided
+        pixel_count: usize,
+    },
+
+    /// The image file could not be decoded.
+    Decoding(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidData { byte_count } => write!(
+                f,
+                "the amount of bytes ({}) is not a multiple of 4",
+                byte_count
+            ),
+            Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            } => write!(
+                f,
+                "{} pixels do not match the dimensions {}x{}",
+                pixel_count, width, height
+            ),
+            Error::Decoding(error) => {
+                write!(f, "the image could not be decoded: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_rgba_checks_dimensions() {
+        assert!(Icon::from_rgba(vec![0; 16], 2, 2).is_ok());
+
+        assert_eq!(
+            Icon::from_rgba(vec![0; 15], 2, 2),
+            Err(Error::InvalidData { byte_count: 15 })
+        );
+
+        assert_eq!(
+            Icon::from_rgba(vec![0; 16], 4, 4),
+            Err(Error::DimensionsMismatch {
+                width: 4,
+                height: 4,
+                pixel_count: 4,
+            })
+        );
+    }
+}
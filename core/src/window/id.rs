@@ -0,0 +1,40 @@
+use std::sync::atomic::{self, AtomicU64};
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The identifier of a window.
+///
+/// The first window of an application is [`Id::MAIN`]. Additional windows
+/// are given a unique [`Id`] when spawned.
+///
+/// [`Id`]: struct.Id.html
+/// [`Id::MAIN`]: #associatedconstant.MAIN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+impl Id {
+    /// The [`Id`] of the first window of an application.
+    ///
+    /// [`Id`]: struct.Id.html
+    pub const MAIN: Id = Id(0);
+
+    /// Creates a unique [`Id`], different from any other.
+    ///
+    /// [`Id`]: struct.Id.html
+    pub fn unique() -> Id {
+        Id(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spawned_windows_are_not_main() {
+        let id = Id::unique();
+
+        assert_ne!(id, Id::MAIN);
+        assert_ne!(id, Id::unique());
+    }
+}
@@ -0,0 +1,27 @@
+/// A monitor connected to the system.
+///
+/// Its position and size are given in physical pixels of the desktop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The name of the monitor, if known.
+    pub name: Option<String>,
+
+    /// The position of the top-left corner of the monitor on the desktop.
+    pub position: (i32, i32),
+
+    /// The resolution of the monitor.
+    pub size: (u32, u32),
+
+    /// The scale factor of the monitor, used to convert logical units to
+    /// physical pixels.
+    pub scale_factor: f64,
+
+    /// The maximum refresh rate supported by the monitor at its resolution,
+    /// in hertz, if known.
+    ///
+    /// The current refresh rate is not available, and it may be lower.
+    pub refresh_rate: Option<u16>,
+
+    /// Whether the window is currently displayed on the monitor.
+    pub is_current: bool,
+}
@@ -0,0 +1,12 @@
+/// The initial position of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// The position is chosen by the system.
+    Default,
+
+    /// The window is centered in the monitor where it is opened.
+    Centered,
+
+    /// The window is placed at the given logical coordinates of the desktop.
+    Specific(i32, i32),
+}
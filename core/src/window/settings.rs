@@ -0,0 +1,69 @@
+use crate::window::{Icon, Position};
+
+/// The settings of a window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// The size of the window.
+    pub size: (u32, u32),
+
+    /// The minimum size of the window, if any.
+    pub min_size: Option<(u32, u32)>,
+
+    /// The maximum size of the window, if any.
+    pub max_size: Option<(u32, u32)>,
+
+    /// The initial position of the window.
+    pub position: Position,
+
+    /// Whether the window should be resizable or not.
+    pub resizable: bool,
+
+    /// Whether the window should have a border, a title bar, etc. or not.
+    pub decorations: bool,
+
+    /// Whether the window should always be on top of other windows.
+    pub always_on_top: bool,
+
+    /// Whether the background of the window should be transparent.
+    ///
+    /// It can only be set when the window is created.
+    pub transparent: bool,
+
+    /// The scale factor of the user interface of the window, applied on top
+    /// of the scale factor of the system.
+    ///
+    /// It can be changed at any time with a `SetScaleFactor` action.
+    pub scale_factor: f64,
+
+    /// The icon of the window.
+    ///
+    /// If `None`, the default icon of the system is used.
+    pub icon: Option<Icon>,
+
+    /// Whether the window should close when the user requests it, for
+    /// instance by pressing its close button.
+    ///
+    /// If `true`, a `Closed` window event is produced once the window closes.
+    /// If `false`, a `CloseRequested` window event is produced instead, and
+    /// the window stays open until it is closed with a command. This allows
+    /// asking the user to save any unsaved changes first.
+    pub close_on_request: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            size: (1024, 768),
+            min_size: None,
+            max_size: None,
+            position: Position::Default,
+            resizable: true,
+            decorations: true,
+            always_on_top: false,
+            transparent: false,
+            scale_factor: 1.0,
+            icon: None,
+            close_on_request: true,
+        }
+    }
+}
@@ -70,13 +70,14 @@ impl Sandbox for Example {
 mod bezier {
     use iced::{
         canvas::{self, Canvas, Cursor, Event, Frame, Geometry, Path, Stroke},
-        mouse, Element, Length, Point, Rectangle,
+        keyboard, mouse, Element, Length, Point, Rectangle,
     };
 
     #[derive(Default)]
     pub struct State {
         pending: Option<Pending>,
         cache: canvas::Cache,
+        canvas: canvas::State,
     }
 
     impl State {
@@ -84,12 +85,20 @@ mod bezier {
             &'a mut self,
             curves: &'a [Curve],
         ) -> Element<'a, Curve> {
+            let State {
+                pending,
+                cache,
+                canvas,
+            } = self;
+
             Canvas::new(Bezier {
-                state: self,
+                pending,
+                cache,
                 curves,
             })
             .width(Length::Fill)
             .height(Length::Fill)
+            .state(canvas)
             .into()
         }
 
@@ -99,7 +108,8 @@ mod bezier {
     }
 
     struct Bezier<'a> {
-        state: &'a mut State,
+        pending: &'a mut Option<Pending>,
+        cache: &'a canvas::Cache,
         curves: &'a [Curve],
     }
 
@@ -110,20 +120,30 @@ mod bezier {
             bounds: Rectangle,
             cursor: Cursor,
         ) -> Option<Curve> {
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) = event
+            {
+                *self.pending = None;
+
+                return None;
+            }
+
             let cursor_position = cursor.position_in(&bounds)?;
 
             match event {
                 Event::Mouse(mouse_event) => match mouse_event {
                     mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                        match self.state.pending {
+                        match *self.pending {
                             None => {
-                                self.state.pending = Some(Pending::One {
+                                *self.pending = Some(Pending::One {
                                     from: cursor_position,
                                 });
                                 None
                             }
                             Some(Pending::One { from }) => {
-                                self.state.pending = Some(Pending::Two {
+                                *self.pending = Some(Pending::Two {
                                     from,
                                     to: cursor_position,
                                 });
@@ -131,7 +151,7 @@ mod bezier {
                                 None
                             }
                             Some(Pending::Two { from, to }) => {
-                                self.state.pending = None;
+                                *self.pending = None;
 
                                 Some(Curve {
                                     from,
@@ -143,12 +163,13 @@ mod bezier {
                     }
                     _ => None,
                 },
+                _ => None,
             }
         }
 
         fn draw(&self, bounds: Rectangle, cursor: Cursor) -> Vec<Geometry> {
             let content =
-                self.state.cache.draw(bounds.size(), |frame: &mut Frame| {
+                self.cache.draw(bounds.size(), |frame: &mut Frame| {
                     Curve::draw_all(self.curves, frame);
 
                     frame.stroke(
@@ -157,7 +178,7 @@ mod bezier {
                     );
                 });
 
-            if let Some(pending) = &self.state.pending {
+            if let Some(pending) = self.pending.as_ref() {
                 let pending_curve = pending.draw(bounds, cursor);
 
                 vec![content, pending_curve]
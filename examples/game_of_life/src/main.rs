@@ -5,10 +5,11 @@ mod style;
 use grid::Grid;
 use iced::{
     button::{self, Button},
+    checkbox::{self, Checkbox},
     executor,
     slider::{self, Slider},
-    time, Align, Application, Checkbox, Column, Command, Container, Element,
-    Length, Row, Settings, Subscription, Text,
+    time, Align, Application, Column, Command, Container, Element, Length, Row,
+    Settings, Subscription, Text,
 };
 use std::time::{Duration, Instant};
 
@@ -381,6 +382,7 @@ mod grid {
                     },
                     _ => None,
                 },
+                _ => None,
             }
         }
 
@@ -741,6 +743,7 @@ struct Controls {
     next_button: button::State,
     clear_button: button::State,
     speed_slider: slider::State,
+    grid_checkbox: checkbox::State,
 }
 
 impl Controls {
@@ -788,10 +791,15 @@ impl Controls {
             .push(playback_controls)
             .push(speed_controls)
             .push(
-                Checkbox::new(is_grid_enabled, "Grid", Message::ToggleGrid)
-                    .size(16)
-                    .spacing(5)
-                    .text_size(16),
+                Checkbox::new(
+                    &mut self.grid_checkbox,
+                    is_grid_enabled,
+                    "Grid",
+                    Message::ToggleGrid,
+                )
+                .size(16)
+                .spacing(5)
+                .text_size(16),
             )
             .push(
                 Button::new(&mut self.clear_button, Text::new("Clear"))
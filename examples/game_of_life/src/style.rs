@@ -1,4 +1,4 @@
-use iced::{button, container, slider, Background, Color};
+use iced::{button, container, slider, Background, Color, Theme};
 
 const ACTIVE: Color = Color::from_rgb(
     0x72 as f32 / 255.0,
@@ -21,7 +21,7 @@ const HOVERED: Color = Color::from_rgb(
 pub struct Container;
 
 impl container::StyleSheet for Container {
-    fn style(&self) -> container::Style {
+    fn style(&self, _theme: &Theme) -> container::Style {
         container::Style {
             background: Some(Background::Color(Color::from_rgb8(
                 0x36, 0x39, 0x3F,
@@ -35,7 +35,7 @@ impl container::StyleSheet for Container {
 pub struct Button;
 
 impl button::StyleSheet for Button {
-    fn active(&self) -> button::Style {
+    fn active(&self, _theme: &Theme) -> button::Style {
         button::Style {
             background: Some(Background::Color(ACTIVE)),
             border_radius: 3,
@@ -44,19 +44,19 @@ impl button::StyleSheet for Button {
         }
     }
 
-    fn hovered(&self) -> button::Style {
+    fn hovered(&self, theme: &Theme) -> button::Style {
         button::Style {
             background: Some(Background::Color(HOVERED)),
             text_color: Color::WHITE,
-            ..self.active()
+            ..self.active(theme)
         }
     }
 
-    fn pressed(&self) -> button::Style {
+    fn pressed(&self, theme: &Theme) -> button::Style {
         button::Style {
             border_width: 1,
             border_color: Color::WHITE,
-            ..self.hovered()
+            ..self.hovered(theme)
         }
     }
 }
@@ -64,7 +64,7 @@ impl button::StyleSheet for Button {
 pub struct Clear;
 
 impl button::StyleSheet for Clear {
-    fn active(&self) -> button::Style {
+    fn active(&self, _theme: &Theme) -> button::Style {
         button::Style {
             background: Some(Background::Color(DESTRUCTIVE)),
             border_radius: 3,
@@ -73,22 +73,22 @@ impl button::StyleSheet for Clear {
         }
     }
 
-    fn hovered(&self) -> button::Style {
+    fn hovered(&self, theme: &Theme) -> button::Style {
         button::Style {
             background: Some(Background::Color(Color {
                 a: 0.5,
                 ..DESTRUCTIVE
             })),
             text_color: Color::WHITE,
-            ..self.active()
+            ..self.active(theme)
         }
     }
 
-    fn pressed(&self) -> button::Style {
+    fn pressed(&self, theme: &Theme) -> button::Style {
         button::Style {
             border_width: 1,
             border_color: Color::WHITE,
-            ..self.hovered()
+            ..self.hovered(theme)
         }
     }
 }
@@ -96,7 +96,7 @@ impl button::StyleSheet for Clear {
 pub struct Slider;
 
 impl slider::StyleSheet for Slider {
-    fn active(&self) -> slider::Style {
+    fn active(&self, _theme: &Theme) -> slider::Style {
         slider::Style {
             rail_colors: (ACTIVE, Color { a: 0.1, ..ACTIVE }),
             handle: slider::Handle {
@@ -108,8 +108,8 @@ impl slider::StyleSheet for Slider {
         }
     }
 
-    fn hovered(&self) -> slider::Style {
-        let active = self.active();
+    fn hovered(&self, theme: &Theme) -> slider::Style {
+        let active = self.active(theme);
 
         slider::Style {
             handle: slider::Handle {
@@ -120,8 +120,8 @@ impl slider::StyleSheet for Slider {
         }
     }
 
-    fn dragging(&self) -> slider::Style {
-        let active = self.active();
+    fn dragging(&self, theme: &Theme) -> slider::Style {
+        let active = self.active(theme);
 
         slider::Style {
             handle: slider::Handle {
@@ -22,6 +22,7 @@ enum Message {
     Resized(pane_grid::ResizeEvent),
     Close(pane_grid::Pane),
     CloseFocused,
+    ToggleMaximized,
 }
 
 impl Sandbox for Example {
@@ -89,6 +90,13 @@ impl Sandbox for Example {
                     let _ = self.panes.close(&pane);
                 }
             }
+            Message::ToggleMaximized => {
+                if self.panes.maximized().is_some() {
+                    self.panes.restore();
+                } else if let Some(pane) = self.panes.active() {
+                    self.panes.maximize(&pane);
+                }
+            }
         }
     }
 
@@ -130,6 +138,7 @@ fn handle_hotkey(event: pane_grid::KeyPressEvent) -> Option<Message> {
         KeyCode::V => Some(Message::SplitFocused(Axis::Vertical)),
         KeyCode::H => Some(Message::SplitFocused(Axis::Horizontal)),
         KeyCode::W => Some(Message::CloseFocused),
+        KeyCode::M => Some(Message::ToggleMaximized),
         _ => direction.map(Message::FocusAdjacent),
     }
 }
@@ -225,7 +234,7 @@ impl Content {
 }
 
 mod style {
-    use iced::{button, container, Background, Color, Vector};
+    use iced::{button, container, Background, Color, Theme, Vector};
 
     const SURFACE: Color = Color::from_rgb(
         0xF2 as f32 / 255.0,
@@ -250,7 +259,7 @@ mod style {
     }
 
     impl container::StyleSheet for Pane {
-        fn style(&self) -> container::Style {
+        fn style(&self, _theme: &Theme) -> container::Style {
             container::Style {
                 background: Some(Background::Color(SURFACE)),
                 border_width: 2,
@@ -269,7 +278,7 @@ mod style {
     }
 
     impl button::StyleSheet for Button {
-        fn active(&self) -> button::Style {
+        fn active(&self, _theme: &Theme) -> button::Style {
             let (background, text_color) = match self {
                 Button::Primary => (Some(ACTIVE), Color::WHITE),
                 Button::Destructive => {
@@ -286,8 +295,8 @@ mod style {
             }
         }
 
-        fn hovered(&self) -> button::Style {
-            let active = self.active();
+        fn hovered(&self, theme: &Theme) -> button::Style {
+            let active = self.active(theme);
 
             let background = match self {
                 Button::Primary => Some(HOVERED),
@@ -8,8 +8,9 @@
 //! [1]: https://developer.mozilla.org/en-US/docs/Web/API/Canvas_API/Tutorial/Basic_animations#An_animated_solar_system
 use iced::{
     canvas::{self, Cursor, Path, Stroke},
-    executor, time, window, Application, Canvas, Color, Command, Element,
-    Length, Point, Rectangle, Settings, Size, Subscription, Vector,
+    executor, window, Application, Canvas, Color, Command, Element,
+    HorizontalAlignment, Length, Point, Rectangle, Settings, Size,
+    Subscription, Vector, VerticalAlignment,
 };
 
 use std::time::Instant;
@@ -59,8 +60,7 @@ impl Application for SolarSystem {
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        time::every(std::time::Duration::from_millis(10))
-            .map(|instant| Message::Tick(instant))
+        window::frames().map(Message::Tick)
     }
 
     fn view(&mut self) -> Element<Message> {
@@ -107,6 +107,18 @@ impl State {
         self.system_cache.clear();
     }
 
+    fn label(content: &str, position: Point) -> canvas::Text {
+        canvas::Text {
+            content: String::from(content),
+            position,
+            color: Color::from_rgba8(255, 255, 255, 0.7),
+            size: 14.0,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Top,
+            ..canvas::Text::default()
+        }
+    }
+
     fn generate_stars(width: u32, height: u32) -> Vec<(Point, f32)> {
         use rand::Rng;
 
@@ -162,11 +174,19 @@ impl<Message> canvas::Program<Message> for State {
             let orbit = Path::circle(center, Self::ORBIT_RADIUS);
 
             frame.fill(&sun, Color::from_rgb8(0xF9, 0xD7, 0x1C));
+            frame.fill_text(Self::label(
+                "Sun",
+                Point::new(center.x, center.y + Self::SUN_RADIUS + 5.0),
+            ));
             frame.stroke(
                 &orbit,
                 Stroke {
                     width: 1.0,
                     color: Color::from_rgba8(0, 153, 255, 0.1),
+                    line_dash: canvas::LineDash {
+                        segments: &[3.0, 6.0],
+                        offset: 0,
+                    },
                     ..Stroke::default()
                 },
             );
@@ -190,6 +210,10 @@ impl<Message> canvas::Program<Message> for State {
                 );
 
                 frame.fill(&earth, Color::from_rgb8(0x6B, 0x93, 0xD6));
+                frame.fill_text(Self::label(
+                    "Earth",
+                    Point::new(0.0, Self::EARTH_RADIUS + 5.0),
+                ));
 
                 frame.with_save(|frame| {
                     frame.rotate(rotation * 10.0);
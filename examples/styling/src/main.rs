@@ -1,7 +1,7 @@
 use iced::{
-    button, scrollable, slider, text_input, Align, Button, Checkbox, Column,
-    Container, Element, Length, ProgressBar, Radio, Row, Sandbox, Scrollable,
-    Settings, Slider, Space, Text, TextInput,
+    button, checkbox, radio, scrollable, slider, text_input, Align, Button,
+    Checkbox, Column, Container, Element, Length, ProgressBar, Radio, Row,
+    Sandbox, Scrollable, Settings, Slider, Space, Text, TextInput,
 };
 
 pub fn main() {
@@ -11,12 +11,14 @@ pub fn main() {
 #[derive(Default)]
 struct Styling {
     theme: style::Theme,
+    theme_radios: [radio::State; 2],
     scroll: scrollable::State,
     input: text_input::State,
     input_value: String,
     button: button::State,
     slider: slider::State,
     slider_value: f32,
+    toggle: checkbox::State,
     toggle_value: bool,
 }
 
@@ -51,20 +53,26 @@ impl Sandbox for Styling {
     }
 
     fn view(&mut self) -> Element<Message> {
-        let choose_theme = style::Theme::ALL.iter().fold(
-            Column::new().spacing(10).push(Text::new("Choose a theme:")),
-            |column, theme| {
-                column.push(
-                    Radio::new(
-                        *theme,
-                        &format!("{:?}", theme),
-                        Some(self.theme),
-                        Message::ThemeChanged,
+        let selected_theme = self.theme;
+
+        let choose_theme = style::Theme::ALL
+            .iter()
+            .zip(self.theme_radios.iter_mut())
+            .fold(
+                Column::new().spacing(10).push(Text::new("Choose a theme:")),
+                |column, (theme, state)| {
+                    column.push(
+                        Radio::new(
+                            state,
+                            *theme,
+                            &format!("{:?}", theme),
+                            Some(selected_theme),
+                            Message::ThemeChanged,
+                        )
+                        .style(selected_theme),
                     )
-                    .style(self.theme),
-                )
-            },
-        );
+                },
+            );
 
         let text_input = TextInput::new(
             &mut self.input,
@@ -101,6 +109,7 @@ impl Sandbox for Styling {
             .push(Text::new("You did it!"));
 
         let checkbox = Checkbox::new(
+            &mut self.toggle,
             self.toggle_value,
             "Toggle me!",
             Message::CheckboxToggled,
@@ -229,12 +238,12 @@ mod style {
     }
 
     mod light {
-        use iced::{button, Background, Color, Vector};
+        use iced::{button, Background, Color, Theme, Vector};
 
         pub struct Button;
 
         impl button::StyleSheet for Button {
-            fn active(&self) -> button::Style {
+            fn active(&self, _theme: &Theme) -> button::Style {
                 button::Style {
                     background: Some(Background::Color(Color::from_rgb(
                         0.11, 0.42, 0.87,
@@ -246,11 +255,11 @@ mod style {
                 }
             }
 
-            fn hovered(&self) -> button::Style {
+            fn hovered(&self, theme: &Theme) -> button::Style {
                 button::Style {
                     text_color: Color::WHITE,
                     shadow_offset: Vector::new(1.0, 2.0),
-                    ..self.active()
+                    ..self.active(theme)
                 }
             }
         }
@@ -259,7 +268,7 @@ mod style {
     mod dark {
         use iced::{
             button, checkbox, container, progress_bar, radio, scrollable,
-            slider, text_input, Background, Color,
+            slider, text_input, Background, Color, Theme,
         };
 
         const SURFACE: Color = Color::from_rgb(
@@ -289,7 +298,7 @@ mod style {
         pub struct Container;
 
         impl container::StyleSheet for Container {
-            fn style(&self) -> container::Style {
+            fn style(&self, _theme: &Theme) -> container::Style {
                 container::Style {
                     background: Some(Background::Color(Color::from_rgb8(
                         0x36, 0x39, 0x3F,
@@ -303,7 +312,7 @@ mod style {
         pub struct Radio;
 
         impl radio::StyleSheet for Radio {
-            fn active(&self) -> radio::Style {
+            fn active(&self, _theme: &Theme) -> radio::Style {
                 radio::Style {
                     background: Background::Color(SURFACE),
                     dot_color: ACTIVE,
@@ -312,10 +321,10 @@ mod style {
                 }
             }
 
-            fn hovered(&self) -> radio::Style {
+            fn hovered(&self, theme: &Theme) -> radio::Style {
                 radio::Style {
                     background: Background::Color(Color { a: 0.5, ..SURFACE }),
-                    ..self.active()
+                    ..self.active(theme)
                 }
             }
         }
@@ -323,7 +332,7 @@ mod style {
         pub struct TextInput;
 
         impl text_input::StyleSheet for TextInput {
-            fn active(&self) -> text_input::Style {
+            fn active(&self, _theme: &Theme) -> text_input::Style {
                 text_input::Style {
                     background: Background::Color(SURFACE),
                     border_radius: 2,
@@ -332,31 +341,31 @@ mod style {
                 }
             }
 
-            fn focused(&self) -> text_input::Style {
+            fn focused(&self, theme: &Theme) -> text_input::Style {
                 text_input::Style {
                     border_width: 1,
                     border_color: ACCENT,
-                    ..self.active()
+                    ..self.active(theme)
                 }
             }
 
-            fn hovered(&self) -> text_input::Style {
+            fn hovered(&self, theme: &Theme) -> text_input::Style {
                 text_input::Style {
                     border_width: 1,
                     border_color: Color { a: 0.3, ..ACCENT },
-                    ..self.focused()
+                    ..self.focused(theme)
                 }
             }
 
-            fn placeholder_color(&self) -> Color {
+            fn placeholder_color(&self, _theme: &Theme) -> Color {
                 Color::from_rgb(0.4, 0.4, 0.4)
             }
 
-            fn value_color(&self) -> Color {
+            fn value_color(&self, _theme: &Theme) -> Color {
                 Color::WHITE
             }
 
-            fn selection_color(&self) -> Color {
+            fn selection_color(&self, _theme: &Theme) -> Color {
                 ACTIVE
             }
         }
@@ -364,7 +373,7 @@ mod style {
         pub struct Button;
 
         impl button::StyleSheet for Button {
-            fn active(&self) -> button::Style {
+            fn active(&self, _theme: &Theme) -> button::Style {
                 button::Style {
                     background: Some(Background::Color(ACTIVE)),
                     border_radius: 3,
@@ -373,19 +382,19 @@ mod style {
                 }
             }
 
-            fn hovered(&self) -> button::Style {
+            fn hovered(&self, theme: &Theme) -> button::Style {
                 button::Style {
                     background: Some(Background::Color(HOVERED)),
                     text_color: Color::WHITE,
-                    ..self.active()
+                    ..self.active(theme)
                 }
             }
 
-            fn pressed(&self) -> button::Style {
+            fn pressed(&self, theme: &Theme) -> button::Style {
                 button::Style {
                     border_width: 1,
                     border_color: Color::WHITE,
-                    ..self.hovered()
+                    ..self.hovered(theme)
                 }
             }
         }
@@ -393,8 +402,10 @@ mod style {
         pub struct Scrollable;
 
         impl scrollable::StyleSheet for Scrollable {
-            fn active(&self) -> scrollable::Scrollbar {
+            fn active(&self, _theme: &Theme) -> scrollable::Scrollbar {
                 scrollable::Scrollbar {
+                    width: 10,
+                    margin: 2,
                     background: Some(Background::Color(SURFACE)),
                     border_radius: 2,
                     border_width: 0,
@@ -408,8 +419,8 @@ mod style {
                 }
             }
 
-            fn hovered(&self) -> scrollable::Scrollbar {
-                let active = self.active();
+            fn hovered(&self, theme: &Theme) -> scrollable::Scrollbar {
+                let active = self.active(theme);
 
                 scrollable::Scrollbar {
                     background: Some(Background::Color(Color {
@@ -424,8 +435,8 @@ mod style {
                 }
             }
 
-            fn dragging(&self) -> scrollable::Scrollbar {
-                let hovered = self.hovered();
+            fn dragging(&self, theme: &Theme) -> scrollable::Scrollbar {
+                let hovered = self.hovered(theme);
 
                 scrollable::Scrollbar {
                     scroller: scrollable::Scroller {
@@ -440,7 +451,7 @@ mod style {
         pub struct Slider;
 
         impl slider::StyleSheet for Slider {
-            fn active(&self) -> slider::Style {
+            fn active(&self, _theme: &Theme) -> slider::Style {
                 slider::Style {
                     rail_colors: (ACTIVE, Color { a: 0.1, ..ACTIVE }),
                     handle: slider::Handle {
@@ -452,8 +463,8 @@ mod style {
                 }
             }
 
-            fn hovered(&self) -> slider::Style {
-                let active = self.active();
+            fn hovered(&self, theme: &Theme) -> slider::Style {
+                let active = self.active(theme);
 
                 slider::Style {
                     handle: slider::Handle {
@@ -464,8 +475,8 @@ mod style {
                 }
             }
 
-            fn dragging(&self) -> slider::Style {
-                let active = self.active();
+            fn dragging(&self, theme: &Theme) -> slider::Style {
+                let active = self.active(theme);
 
                 slider::Style {
                     handle: slider::Handle {
@@ -480,7 +491,7 @@ mod style {
         pub struct ProgressBar;
 
         impl progress_bar::StyleSheet for ProgressBar {
-            fn style(&self) -> progress_bar::Style {
+            fn style(&self, _theme: &Theme) -> progress_bar::Style {
                 progress_bar::Style {
                     background: Background::Color(SURFACE),
                     bar: Background::Color(ACTIVE),
@@ -492,7 +503,11 @@ mod style {
         pub struct Checkbox;
 
         impl checkbox::StyleSheet for Checkbox {
-            fn active(&self, is_checked: bool) -> checkbox::Style {
+            fn active(
+                &self,
+                _theme: &Theme,
+                is_checked: bool,
+            ) -> checkbox::Style {
                 checkbox::Style {
                     background: Background::Color(if is_checked {
                         ACTIVE
@@ -506,13 +521,17 @@ mod style {
                 }
             }
 
-            fn hovered(&self, is_checked: bool) -> checkbox::Style {
+            fn hovered(
+                &self,
+                theme: &Theme,
+                is_checked: bool,
+            ) -> checkbox::Style {
                 checkbox::Style {
                     background: Background::Color(Color {
                         a: 0.8,
                         ..if is_checked { ACTIVE } else { SURFACE }
                     }),
-                    ..self.active(is_checked)
+                    ..self.active(theme, is_checked)
                 }
             }
         }
@@ -1,7 +1,7 @@
 use iced::{
-    button, scrollable, slider, text_input, Button, Checkbox, Color, Column,
-    Container, Element, HorizontalAlignment, Image, Length, Radio, Row,
-    Sandbox, Scrollable, Settings, Slider, Space, Text, TextInput,
+    button, checkbox, radio, scrollable, slider, text_input, Button, Checkbox,
+    Color, Column, Container, Element, HorizontalAlignment, Image, Length,
+    Radio, Row, Sandbox, Scrollable, Settings, Slider, Space, Text, TextInput,
 };
 
 pub fn main() {
@@ -125,6 +125,7 @@ impl Steps {
                 },
                 Step::RowsAndColumns {
                     layout: Layout::Row,
+                    layout_radios: [radio::State::new(); 2],
                     spacing_slider: slider::State::new(),
                     spacing: 20,
                 },
@@ -134,7 +135,10 @@ impl Steps {
                     color_sliders: [slider::State::new(); 3],
                     color: Color::BLACK,
                 },
-                Step::Radio { selection: None },
+                Step::Radio {
+                    selection: None,
+                    radios: [radio::State::new(); 6],
+                },
                 Step::Image {
                     width: 300,
                     slider: slider::State::new(),
@@ -144,8 +148,11 @@ impl Steps {
                     value: String::new(),
                     is_secure: false,
                     state: text_input::State::new(),
+                    secure_checkbox: checkbox::State::new(),
+                },
+                Step::Debugger {
+                    checkbox: checkbox::State::new(),
                 },
-                Step::Debugger,
                 Step::End,
             ],
             current: 0,
@@ -194,6 +201,7 @@ enum Step {
     },
     RowsAndColumns {
         layout: Layout,
+        layout_radios: [radio::State; 2],
         spacing_slider: slider::State,
         spacing: u16,
     },
@@ -205,6 +213,7 @@ enum Step {
     },
     Radio {
         selection: Option<Language>,
+        radios: [radio::State; 6],
     },
     Image {
         width: u16,
@@ -215,8 +224,11 @@ enum Step {
         value: String,
         is_secure: bool,
         state: text_input::State,
+        secure_checkbox: checkbox::State,
+    },
+    Debugger {
+        checkbox: checkbox::State,
     },
-    Debugger,
     End,
 }
 
@@ -238,12 +250,12 @@ impl<'a> Step {
     fn update(&mut self, msg: StepMessage, debug: &mut bool) {
         match msg {
             StepMessage::DebugToggled(value) => {
-                if let Step::Debugger = self {
+                if let Step::Debugger { .. } = self {
                     *debug = value;
                 }
             }
             StepMessage::LanguageSelected(language) => {
-                if let Step::Radio { selection } = self {
+                if let Step::Radio { selection, .. } = self {
                     *selection = Some(language);
                 }
             }
@@ -300,7 +312,7 @@ impl<'a> Step {
             Step::RowsAndColumns { .. } => "Rows and columns",
             Step::Scrollable => "Scrollable",
             Step::TextInput { .. } => "Text input",
-            Step::Debugger => "Debugger",
+            Step::Debugger { .. } => "Debugger",
             Step::End => "End",
         }
     }
@@ -308,14 +320,14 @@ impl<'a> Step {
     fn can_continue(&self) -> bool {
         match self {
             Step::Welcome => true,
-            Step::Radio { selection } => *selection == Some(Language::Rust),
+            Step::Radio { selection, .. } => *selection == Some(Language::Rust),
             Step::Slider { .. } => true,
             Step::Text { .. } => true,
             Step::Image { .. } => true,
             Step::RowsAndColumns { .. } => true,
             Step::Scrollable => true,
             Step::TextInput { value, .. } => !value.is_empty(),
-            Step::Debugger => true,
+            Step::Debugger { .. } => true,
             Step::End => false,
         }
     }
@@ -323,7 +335,9 @@ impl<'a> Step {
     fn view(&mut self, debug: bool) -> Element<StepMessage> {
         match self {
             Step::Welcome => Self::welcome(),
-            Step::Radio { selection } => Self::radio(*selection),
+            Step::Radio { selection, radios } => {
+                Self::radio(*selection, radios)
+            }
             Step::Slider { state, value } => Self::slider(state, *value),
             Step::Text {
                 size_slider,
@@ -334,16 +348,23 @@ impl<'a> Step {
             Step::Image { width, slider } => Self::image(*width, slider),
             Step::RowsAndColumns {
                 layout,
+                layout_radios,
                 spacing_slider,
                 spacing,
-            } => Self::rows_and_columns(*layout, spacing_slider, *spacing),
+            } => Self::rows_and_columns(
+                *layout,
+                layout_radios,
+                spacing_slider,
+                *spacing,
+            ),
             Step::Scrollable => Self::scrollable(),
             Step::TextInput {
                 value,
                 is_secure,
                 state,
-            } => Self::text_input(value, *is_secure, state),
-            Step::Debugger => Self::debugger(debug),
+                secure_checkbox,
+            } => Self::text_input(value, *is_secure, state, secure_checkbox),
+            Step::Debugger { checkbox } => Self::debugger(debug, checkbox),
             Step::End => Self::end(),
         }
         .into()
@@ -410,10 +431,14 @@ impl<'a> Step {
 
     fn rows_and_columns(
         layout: Layout,
+        layout_radios: &'a mut [radio::State; 2],
         spacing_slider: &'a mut slider::State,
         spacing: u16,
     ) -> Column<'a, StepMessage> {
+        let [row_state, column_state] = layout_radios;
+
         let row_radio = Radio::new(
+            row_state,
             Layout::Row,
             "Row",
             Some(layout),
@@ -421,6 +446,7 @@ impl<'a> Step {
         );
 
         let column_radio = Radio::new(
+            column_state,
             Layout::Column,
             "Column",
             Some(layout),
@@ -520,15 +546,19 @@ impl<'a> Step {
             .push(color_section)
     }
 
-    fn radio(selection: Option<Language>) -> Column<'a, StepMessage> {
+    fn radio(
+        selection: Option<Language>,
+        radios: &'a mut [radio::State; 6],
+    ) -> Column<'a, StepMessage> {
         let question = Column::new()
             .padding(20)
             .spacing(10)
             .push(Text::new("Iced is written in...").size(24))
-            .push(Language::all().iter().cloned().fold(
+            .push(Language::all().iter().cloned().zip(radios.iter_mut()).fold(
                 Column::new().padding(10).spacing(20),
-                |choices, language| {
+                |choices, (language, state)| {
                     choices.push(Radio::new(
+                        state,
                         language,
                         language,
                         selection,
@@ -603,6 +633,7 @@ impl<'a> Step {
         value: &str,
         is_secure: bool,
         state: &'a mut text_input::State,
+        secure_checkbox: &'a mut checkbox::State,
     ) -> Column<'a, StepMessage> {
         let text_input = TextInput::new(
             state,
@@ -622,6 +653,7 @@ impl<'a> Step {
                 text_input
             })
             .push(Checkbox::new(
+                secure_checkbox,
                 is_secure,
                 "Enable password mode",
                 StepMessage::ToggleSecureInput,
@@ -641,7 +673,10 @@ impl<'a> Step {
             )
     }
 
-    fn debugger(debug: bool) -> Column<'a, StepMessage> {
+    fn debugger(
+        debug: bool,
+        checkbox: &'a mut checkbox::State,
+    ) -> Column<'a, StepMessage> {
         Self::container("Debugger")
             .push(Text::new(
                 "You can ask Iced to visually explain the layouting of the \
@@ -659,6 +694,7 @@ impl<'a> Step {
                 )
             } else {
                 Element::new(Checkbox::new(
+                    checkbox,
                     debug,
                     "Explain layout",
                     StepMessage::DebugToggled,
@@ -749,7 +785,7 @@ pub enum Layout {
 }
 
 mod style {
-    use iced::{button, Background, Color, Vector};
+    use iced::{button, Background, Color, Theme, Vector};
 
     pub enum Button {
         Primary,
@@ -757,7 +793,7 @@ mod style {
     }
 
     impl button::StyleSheet for Button {
-        fn active(&self) -> button::Style {
+        fn active(&self, _theme: &Theme) -> button::Style {
             button::Style {
                 background: Some(Background::Color(match self {
                     Button::Primary => Color::from_rgb(0.11, 0.42, 0.87),
@@ -770,11 +806,11 @@ mod style {
             }
         }
 
-        fn hovered(&self) -> button::Style {
+        fn hovered(&self, theme: &Theme) -> button::Style {
             button::Style {
                 text_color: Color::WHITE,
                 shadow_offset: Vector::new(1.0, 2.0),
-                ..self.active()
+                ..self.active(theme)
             }
         }
     }
@@ -1,5 +1,6 @@
 use crate::BoxFuture;
 use futures::future::{Future, FutureExt};
+use iced_core::{clipboard, font, notification, tray, window, Id};
 
 /// A collection of async operations.
 ///
@@ -10,6 +11,12 @@ use futures::future::{Future, FutureExt};
 /// [`Command::perform`]: #method.perform
 pub struct Command<T> {
     futures: Vec<BoxFuture<T>>,
+    focus: Option<Id>,
+    window: Vec<window::Action<T>>,
+    notifications: Vec<notification::Action<T>>,
+    clipboard: Vec<clipboard::Action<T>>,
+    fonts: Vec<font::Action<T>>,
+    tray: Vec<tray::Action<T>>,
 }
 
 impl<T> Command<T> {
@@ -21,6 +28,107 @@ impl<T> Command<T> {
     pub fn none() -> Self {
         Self {
             futures: Vec::new(),
+            focus: None,
+            window: Vec::new(),
+            notifications: Vec::new(),
+            clipboard: Vec::new(),
+            fonts: Vec::new(),
+            tray: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that focuses the widget with the given `Id`.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn focus(id: Id) -> Self {
+        Self {
+            futures: Vec::new(),
+            focus: Some(id),
+            window: Vec::new(),
+            notifications: Vec::new(),
+            clipboard: Vec::new(),
+            fonts: Vec::new(),
+            tray: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the given [`window::Action`].
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`window::Action`]: ../iced_core/window/enum.Action.html
+    pub fn window(action: window::Action<T>) -> Self {
+        Self {
+            futures: Vec::new(),
+            focus: None,
+            window: vec![action],
+            notifications: Vec::new(),
+            clipboard: Vec::new(),
+            fonts: Vec::new(),
+            tray: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the given [`notification::Action`].
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`notification::Action`]: ../iced_core/notification/enum.Action.html
+    pub fn notify(action: notification::Action<T>) -> Self {
+        Self {
+            futures: Vec::new(),
+            focus: None,
+            window: Vec::new(),
+            notifications: vec![action],
+            clipboard: Vec::new(),
+            fonts: Vec::new(),
+            tray: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the given [`clipboard::Action`].
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`clipboard::Action`]: ../iced_core/clipboard/enum.Action.html
+    pub fn clipboard(action: clipboard::Action<T>) -> Self {
+        Self {
+            futures: Vec::new(),
+            focus: None,
+            window: Vec::new(),
+            notifications: Vec::new(),
+            clipboard: vec![action],
+            fonts: Vec::new(),
+            tray: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the given [`font::Action`].
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`font::Action`]: ../iced_core/font/enum.Action.html
+    pub fn font(action: font::Action<T>) -> Self {
+        Self {
+            futures: Vec::new(),
+            focus: None,
+            window: Vec::new(),
+            notifications: Vec::new(),
+            clipboard: Vec::new(),
+            fonts: vec![action],
+            tray: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the given [`tray::Action`].
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`tray::Action`]: ../iced_core/tray/enum.Action.html
+    pub fn tray(action: tray::Action<T>) -> Self {
+        Self {
+            futures: Vec::new(),
+            focus: None,
+            window: Vec::new(),
+            notifications: Vec::new(),
+            clipboard: Vec::new(),
+            fonts: Vec::new(),
+            tray: vec![action],
         }
     }
 
@@ -34,6 +142,12 @@ impl<T> Command<T> {
     ) -> Command<A> {
         Command {
             futures: vec![Box::pin(future.map(f))],
+            focus: None,
+            window: Vec::new(),
+            notifications: Vec::new(),
+            clipboard: Vec::new(),
+            fonts: Vec::new(),
+            tray: Vec::new(),
         }
     }
 
@@ -47,6 +161,12 @@ impl<T> Command<T> {
     ) -> Command<A> {
         Command {
             futures: vec![Box::pin(future.map(f))],
+            focus: None,
+            window: Vec::new(),
+            notifications: Vec::new(),
+            clipboard: Vec::new(),
+            fonts: Vec::new(),
+            tray: Vec::new(),
         }
     }
 
@@ -60,8 +180,10 @@ impl<T> Command<T> {
     ) -> Command<A>
     where
         T: 'static,
+        A: 'static,
     {
-        let f = std::sync::Arc::new(f);
+        let f: std::sync::Arc<dyn Fn(T) -> A + Send + Sync> =
+            std::sync::Arc::new(f);
 
         Command {
             futures: self
@@ -74,6 +196,32 @@ impl<T> Command<T> {
                         as BoxFuture<A>
                 })
                 .collect(),
+            focus: self.focus,
+            window: self
+                .window
+                .drain(..)
+                .map(|action| action.map(f.clone()))
+                .collect(),
+            notifications: self
+                .notifications
+                .drain(..)
+                .map(|action| action.map(f.clone()))
+                .collect(),
+            clipboard: self
+                .clipboard
+                .drain(..)
+                .map(|action| action.map(f.clone()))
+                .collect(),
+            fonts: self
+                .fonts
+                .drain(..)
+                .map(|action| action.map(f.clone()))
+                .collect(),
+            tray: self
+                .tray
+                .drain(..)
+                .map(|action| action.map(f.clone()))
+                .collect(),
         }
     }
 
@@ -98,6 +246,14 @@ impl<T> Command<T> {
                         as BoxFuture<A>
                 })
                 .collect(),
+            focus: self.focus,
+            // Window actions, notifications, the clipboard, font loading, and
+            // the tray are not supported on the Web
+            window: Vec::new(),
+            notifications: Vec::new(),
+            clipboard: Vec::new(),
+            fonts: Vec::new(),
+            tray: Vec::new(),
         }
     }
 
@@ -105,15 +261,71 @@ impl<T> Command<T> {
     /// commands.
     ///
     /// Once this command is run, all the commands will be executed at once.
+    /// The last focus request of the commands is kept, while their window
+    /// actions, notifications, clipboard actions, font actions, and tray
+    /// actions are performed in order.
     ///
     /// [`Command`]: struct.Command.html
     pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
-        Self {
-            futures: commands
-                .into_iter()
-                .flat_map(|command| command.futures)
-                .collect(),
+        let mut batch = Self::none();
+
+        for command in commands {
+            batch.futures.extend(command.futures);
+            batch.window.extend(command.window);
+            batch.notifications.extend(command.notifications);
+            batch.clipboard.extend(command.clipboard);
+            batch.fonts.extend(command.fonts);
+            batch.tray.extend(command.tray);
+
+            if command.focus.is_some() {
+                batch.focus = command.focus;
+            }
         }
+
+        batch
+    }
+
+    /// Takes the `Id` of the widget that the [`Command`] focuses, if any.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_focus(&mut self) -> Option<Id> {
+        self.focus.take()
+    }
+
+    /// Takes the window actions that the [`Command`] performs, in order.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_window_actions(&mut self) -> Vec<window::Action<T>> {
+        std::mem::take(&mut self.window)
+    }
+
+    /// Takes the notification actions that the [`Command`] performs, in
+    /// order.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_notifications(&mut self) -> Vec<notification::Action<T>> {
+        std::mem::take(&mut self.notifications)
+    }
+
+    /// Takes the clipboard actions that the [`Command`] performs, in order.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_clipboard_actions(&mut self) -> Vec<clipboard::Action<T>> {
+        std::mem::take(&mut self.clipboard)
+    }
+
+    /// Takes the font actions that the [`Command`] performs, in order.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_font_actions(&mut self) -> Vec<font::Action<T>> {
+        std::mem::take(&mut self.fonts)
+    }
+
+    /// Takes the tray actions that the [`Command`] performs, in order.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn take_tray_actions(&mut self) -> Vec<tray::Action<T>> {
+        std::mem::take(&mut self.tray)
     }
 
     /// Converts a [`Command`] into its underlying list of futures.
@@ -132,6 +344,12 @@ where
     fn from(future: A) -> Self {
         Self {
             futures: vec![future.boxed()],
+            focus: None,
+            window: Vec::new(),
+            notifications: Vec::new(),
+            clipboard: Vec::new(),
+            fonts: Vec::new(),
+            tray: Vec::new(),
         }
     }
 }
@@ -144,6 +362,12 @@ where
     fn from(future: A) -> Self {
         Self {
             futures: vec![future.boxed_local()],
+            focus: None,
+            window: Vec::new(),
+            notifications: Vec::new(),
+            clipboard: Vec::new(),
+            fonts: Vec::new(),
+            tray: Vec::new(),
         }
     }
 }
@@ -7,8 +7,11 @@ use iced_graphics::font;
 use iced_graphics::Layer;
 use iced_graphics::Primitive;
 use iced_native::mouse;
+use iced_native::rich_text::{Fragment, Run};
 use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
 
+use std::borrow::Cow;
+
 /// A [`glow`] graphics backend for [`iced`].
 ///
 /// [`glow`]: https://github.com/grovesNL/glow
@@ -25,7 +28,11 @@ impl Backend {
     ///
     /// [`Backend`]: struct.Backend.html
     pub fn new(gl: &glow::Context, settings: Settings) -> Self {
-        let text_pipeline = text::Pipeline::new(gl, settings.default_font);
+        let text_pipeline = text::Pipeline::new(
+            gl,
+            settings.default_font,
+            settings.fallback_fonts,
+        );
         let quad_pipeline = quad::Pipeline::new(gl);
         let triangle_pipeline = triangle::Pipeline::new(gl);
 
@@ -64,6 +71,8 @@ impl Backend {
             );
         }
 
+        self.triangle_pipeline.trim_cache(gl);
+
         *mouse_interaction
     }
 
@@ -89,7 +98,7 @@ impl Backend {
             );
         }
 
-        if !layer.meshes.is_empty() {
+        if !layer.meshes.is_empty() || !layer.textured_meshes.is_empty() {
             let scaled = transformation
                 * Transformation::scale(scale_factor, scale_factor);
 
@@ -99,6 +108,7 @@ impl Backend {
                 scaled,
                 scale_factor,
                 &layer.meshes,
+                &layer.textured_meshes,
             );
         }
 
@@ -127,18 +137,23 @@ impl Backend {
                         (text.bounds.width * scale_factor).ceil(),
                         (text.bounds.height * scale_factor).ceil(),
                     ),
-                    text: vec![glow_glyph::Text {
-                        text: text.content,
-                        scale: glow_glyph::ab_glyph::PxScale {
-                            x: text.size * scale_factor,
-                            y: text.size * scale_factor,
-                        },
-                        font_id: self.text_pipeline.find_font(text.font),
-                        extra: glow_glyph::Extra {
-                            color: text.color,
-                            z: 0.0,
-                        },
-                    }],
+                    text: self
+                        .text_pipeline
+                        .segments(text.content, text.font)
+                        .into_iter()
+                        .map(|(range, font_id)| glow_glyph::Text {
+                            text: &text.content[range],
+                            scale: glow_glyph::ab_glyph::PxScale {
+                                x: text.size * scale_factor,
+                                y: text.size * scale_factor,
+                            },
+                            font_id,
+                            extra: glow_glyph::Extra {
+                                color: text.color,
+                                z: 0.0,
+                            },
+                        })
+                        .collect(),
                     layout: glow_glyph::Layout::default()
                         .h_align(match text.horizontal_alignment {
                             HorizontalAlignment::Left => {
@@ -186,6 +201,13 @@ impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
         self.text_pipeline.trim_measurement_cache()
     }
+
+    fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), iced_native::font::Error> {
+        self.text_pipeline.load_font(bytes)
+    }
 }
 
 impl backend::Text for Backend {
@@ -201,6 +223,16 @@ impl backend::Text for Backend {
     ) -> (f32, f32) {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
+
+    fn layout(
+        &self,
+        fragments: &[Fragment<'_, Font>],
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+    ) -> Vec<Run> {
+        self.text_pipeline
+            .layout(fragments, bounds, horizontal_alignment)
+    }
 }
 
 #[cfg(feature = "image")]
@@ -208,6 +240,13 @@ impl backend::Image for Backend {
     fn dimensions(&self, _handle: &iced_native::image::Handle) -> (u32, u32) {
         (50, 50)
     }
+
+    fn frame_delays(
+        &self,
+        _handle: &iced_native::image::Handle,
+    ) -> Vec<std::time::Duration> {
+        Vec::new()
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -209,7 +209,7 @@ unsafe fn create_instance_buffer(
     gl.enable_vertex_attrib_array(4);
     gl.vertex_attrib_pointer_f32(
         4,
-        1,
+        4,
         glow::FLOAT,
         false,
         stride,
@@ -220,14 +220,102 @@ unsafe fn create_instance_buffer(
     gl.enable_vertex_attrib_array(5);
     gl.vertex_attrib_pointer_f32(
         5,
-        1,
+        4,
         glow::FLOAT,
         false,
         stride,
-        4 * (2 + 2 + 4 + 4 + 1),
+        4 * (2 + 2 + 4 + 4 + 4),
     );
     gl.vertex_attrib_divisor(5, 1);
 
+    gl.enable_vertex_attrib_array(6);
+    gl.vertex_attrib_pointer_f32(
+        6,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 4),
+    );
+    gl.vertex_attrib_divisor(6, 1);
+
+    gl.enable_vertex_attrib_array(7);
+    gl.vertex_attrib_pointer_f32(
+        7,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 * 4),
+    );
+    gl.vertex_attrib_divisor(7, 1);
+
+    gl.enable_vertex_attrib_array(8);
+    gl.vertex_attrib_pointer_f32(
+        8,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 * 4 + 4),
+    );
+    gl.vertex_attrib_divisor(8, 1);
+
+    gl.enable_vertex_attrib_array(9);
+    gl.vertex_attrib_pointer_f32(
+        9,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 * 4 + 4 + 4),
+    );
+    gl.vertex_attrib_divisor(9, 1);
+
+    gl.enable_vertex_attrib_array(10);
+    gl.vertex_attrib_pointer_f32(
+        10,
+        1,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 * 4 + 4 + 4 + 4),
+    );
+    gl.vertex_attrib_divisor(10, 1);
+
+    gl.enable_vertex_attrib_array(11);
+    gl.vertex_attrib_pointer_f32(
+        11,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 * 4 + 4 + 4 + 4 + 1),
+    );
+    gl.vertex_attrib_divisor(11, 1);
+
+    gl.enable_vertex_attrib_array(12);
+    gl.vertex_attrib_pointer_f32(
+        12,
+        2,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 * 4 + 4 + 4 + 4 + 1 + 4),
+    );
+    gl.vertex_attrib_divisor(12, 1);
+
+    gl.enable_vertex_attrib_array(13);
+    gl.vertex_attrib_pointer_f32(
+        13,
+        1,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 * 4 + 4 + 4 + 4 + 1 + 4 + 2),
+    );
+    gl.vertex_attrib_divisor(13, 1);
+
     gl.bind_vertex_array(None);
     gl.bind_buffer(glow::ARRAY_BUFFER, None);
 
@@ -11,6 +11,14 @@ pub struct Settings {
     /// If `None` is provided, a default system font will be chosen.
     pub default_font: Option<&'static [u8]>,
 
+    /// The fonts used to render the characters missing in a font, in order
+    /// of priority.
+    ///
+    /// The default font is tried after them. When the
+    /// `default_system_font` feature is enabled, fonts of the system able to
+    /// render the remaining characters are discovered and used afterwards.
+    pub fallback_fonts: &'static [iced_native::Font],
+
     /// The antialiasing strategy that will be used for triangle primitives.
     pub antialiasing: Option<Antialiasing>,
 }
@@ -19,6 +27,7 @@ impl Default for Settings {
     fn default() -> Settings {
         Settings {
             default_font: None,
+            fallback_fonts: &[],
             antialiasing: None,
         }
     }
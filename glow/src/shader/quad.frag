@@ -3,68 +3,138 @@
 uniform float u_ScreenHeight;
 
 in vec4 v_Color;
-in vec4 v_BorderColor;
+in vec4 v_BorderColorTop;
+in vec4 v_BorderColorRight;
+in vec4 v_BorderColorBottom;
+in vec4 v_BorderColorLeft;
 in vec2 v_Pos;
 in vec2 v_Scale;
-in float v_BorderRadius;
-in float v_BorderWidth;
+in vec4 v_BorderRadius;
+in vec4 v_BorderWidth;
+in vec4 v_GradientColor;
+in float v_GradientAngle;
+in vec4 v_ShadowColor;
+in vec2 v_ShadowOffset;
+in float v_ShadowBlur;
 
 out vec4 o_Color;
 
-float distance(in vec2 frag_coord, in vec2 position, in vec2 size, float radius)
+// Signed distance from a box centered at the origin, with the radius of each
+// corner given clockwise from the top-left one
+float rounded_box(vec2 point, vec2 half_size, vec4 radii)
 {
-    // TODO: Try SDF approach: https://www.shadertoy.com/view/wd3XRN
-    vec2 inner_size = size - vec2(radius, radius) * 2.0;
-    vec2 top_left = position + vec2(radius, radius);
-    vec2 bottom_right = top_left + inner_size;
+    float radius = point.x < 0.0
+        ? (point.y < 0.0 ? radii.x : radii.w)
+        : (point.y < 0.0 ? radii.y : radii.z);
 
-    vec2 top_left_distance = top_left - frag_coord;
-    vec2 bottom_right_distance = frag_coord - bottom_right;
+    radius = min(radius, min(half_size.x, half_size.y));
 
-    vec2 distance = vec2(
-        max(max(top_left_distance.x, bottom_right_distance.x), 0.0),
-        max(max(top_left_distance.y, bottom_right_distance.y), 0.0)
-    );
+    vec2 q = abs(point) - half_size + vec2(radius);
 
-    return sqrt(distance.x * distance.x + distance.y * distance.y);
+    return min(max(q.x, q.y), 0.0) + length(max(q, vec2(0.0))) - radius;
 }
 
 void main() {
-    vec4 mixed_color;
-
     vec2 fragCoord = vec2(gl_FragCoord.x, u_ScreenHeight - gl_FragCoord.y);
 
+    vec2 half_size = v_Scale / 2.0;
+    vec2 point = fragCoord - (v_Pos + half_size);
+
+    vec2 direction = vec2(cos(v_GradientAngle), sin(v_GradientAngle));
+    float extent = max(
+        abs(half_size.x * direction.x) + abs(half_size.y * direction.y),
+        0.0001
+    );
+    float t = clamp(dot(point, direction) / (2.0 * extent) + 0.5, 0.0, 1.0);
+
+    vec4 mixed_color = mix(v_Color, v_GradientColor, t);
+
     // TODO: Remove branching (?)
-    if(v_BorderWidth > 0) {
-        float internal_border = max(v_BorderRadius - v_BorderWidth, 0.0);
-
-        float internal_distance = distance(
-            fragCoord,
-            v_Pos + vec2(v_BorderWidth),
-            v_Scale - vec2(v_BorderWidth * 2.0),
-            internal_border
+    if(any(greaterThan(v_BorderWidth, vec4(0.0)))) {
+        // The widths of the borders are given clockwise from the top side
+        vec2 internal_offset = vec2(
+            v_BorderWidth.w - v_BorderWidth.y,
+            v_BorderWidth.x - v_BorderWidth.z
+        ) / 2.0;
+
+        vec2 internal_size = max(
+            half_size - vec2(
+                v_BorderWidth.w + v_BorderWidth.y,
+                v_BorderWidth.x + v_BorderWidth.z
+            ) / 2.0,
+            vec2(0.0)
         );
 
-        float border_mix = smoothstep(
-            max(internal_border - 0.5, 0.0),
-            internal_border + 0.5,
-            internal_distance
+        vec4 internal_radius = max(
+            v_BorderRadius - vec4(
+                max(v_BorderWidth.x, v_BorderWidth.w),
+                max(v_BorderWidth.x, v_BorderWidth.y),
+                max(v_BorderWidth.z, v_BorderWidth.y),
+                max(v_BorderWidth.z, v_BorderWidth.w)
+            ),
+            vec4(0.0)
         );
 
-        mixed_color = mix(v_Color, v_BorderColor, border_mix);
-    } else {
-        mixed_color = v_Color;
+        float internal_distance = rounded_box(
+            point - internal_offset,
+            internal_size,
+            internal_radius
+        );
+
+        float border_mix = smoothstep(-0.5, 0.5, internal_distance);
+
+        // Corners are split between sides along the diagonal joining their
+        // outer and inner corners
+        vec4 side_distance = vec4(
+            point.y + half_size.y,
+            half_size.x - point.x,
+            half_size.y - point.y,
+            point.x + half_size.x
+        ) / max(v_BorderWidth, vec4(0.0001));
+
+        vec4 border_color = v_BorderColorTop;
+        float nearest = side_distance.x;
+
+        if(side_distance.y < nearest) {
+            border_color = v_BorderColorRight;
+            nearest = side_distance.y;
+        }
+
+        if(side_distance.z < nearest) {
+            border_color = v_BorderColorBottom;
+            nearest = side_distance.z;
+        }
+
+        if(side_distance.w < nearest) {
+            border_color = v_BorderColorLeft;
+        }
+
+        mixed_color = mix(mixed_color, border_color, border_mix);
     }
 
-    float d = distance(
-        fragCoord,
-        v_Pos,
-        v_Scale,
-        v_BorderRadius
-    );
+    float d = rounded_box(point, half_size, v_BorderRadius);
+    float radius_alpha = 1.0 - smoothstep(-0.5, 0.5, d);
+
+    vec4 quad_color = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
 
-    float radius_alpha =
-        1.0 - smoothstep(max(v_BorderRadius - 0.5, 0.0), v_BorderRadius + 0.5, d);
+    if(v_ShadowColor.a > 0.0) {
+        float blur = max(v_ShadowBlur, 0.5);
+        float shadow_distance =
+            rounded_box(point - v_ShadowOffset, half_size, v_BorderRadius);
 
-    o_Color = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
+        float shadow_alpha =
+            v_ShadowColor.a * (1.0 - smoothstep(-blur, blur, shadow_distance));
+
+        // Draw the quad over its shadow
+        float alpha = quad_color.a + shadow_alpha * (1.0 - quad_color.a);
+
+        vec3 color = (
+            quad_color.xyz * quad_color.a
+                + v_ShadowColor.xyz * shadow_alpha * (1.0 - quad_color.a)
+        ) / max(alpha, 0.0001);
+
+        o_Color = vec4(color, alpha);
+    } else {
+        o_Color = quad_color;
+    }
 }
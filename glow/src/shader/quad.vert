@@ -6,16 +6,32 @@ uniform float u_Scale;
 layout(location = 0) in vec2 i_Pos;
 layout(location = 1) in vec2 i_Scale;
 layout(location = 2) in vec4 i_Color;
-layout(location = 3) in vec4 i_BorderColor;
-layout(location = 4) in float i_BorderRadius;
-layout(location = 5) in float i_BorderWidth;
+layout(location = 3) in vec4 i_BorderColorTop;
+layout(location = 4) in vec4 i_BorderColorRight;
+layout(location = 5) in vec4 i_BorderColorBottom;
+layout(location = 6) in vec4 i_BorderColorLeft;
+layout(location = 7) in vec4 i_BorderRadius;
+layout(location = 8) in vec4 i_BorderWidth;
+layout(location = 9) in vec4 i_GradientColor;
+layout(location = 10) in float i_GradientAngle;
+layout(location = 11) in vec4 i_ShadowColor;
+layout(location = 12) in vec2 i_ShadowOffset;
+layout(location = 13) in float i_ShadowBlur;
 
 out vec4 v_Color;
-out vec4 v_BorderColor;
+out vec4 v_BorderColorTop;
+out vec4 v_BorderColorRight;
+out vec4 v_BorderColorBottom;
+out vec4 v_BorderColorLeft;
 out vec2 v_Pos;
 out vec2 v_Scale;
-out float v_BorderRadius;
-out float v_BorderWidth;
+out vec4 v_BorderRadius;
+out vec4 v_BorderWidth;
+out vec4 v_GradientColor;
+out float v_GradientAngle;
+out vec4 v_ShadowColor;
+out vec2 v_ShadowOffset;
+out float v_ShadowBlur;
 
 const vec2 positions[4] = vec2[](
     vec2(0.0, 0.0),
@@ -28,20 +44,39 @@ void main() {
     vec2 q_Pos = positions[gl_VertexID];
     vec2 p_Pos = i_Pos * u_Scale;
     vec2 p_Scale = i_Scale  * u_Scale;
+    vec2 p_ShadowOffset = i_ShadowOffset * u_Scale;
+    float p_ShadowBlur = i_ShadowBlur * u_Scale;
+
+    // Grow the quad to fit its shadow, if it casts any
+    float has_shadow = step(0.001, i_ShadowColor.a);
+    vec2 shadow_min = p_Pos + p_ShadowOffset - vec2(p_ShadowBlur);
+    vec2 shadow_max = p_Pos + p_Scale + p_ShadowOffset + vec2(p_ShadowBlur);
+
+    vec2 q_Min = mix(p_Pos, min(p_Pos, shadow_min), has_shadow);
+    vec2 q_Max = mix(p_Pos + p_Scale, max(p_Pos + p_Scale, shadow_max), has_shadow);
+    vec2 q_Scale = q_Max - q_Min;
 
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(q_Scale.x + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, q_Scale.y + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(q_Min - vec2(0.5, 0.5), 0.0, 1.0)
     );
 
     v_Color = i_Color;
-    v_BorderColor = i_BorderColor;
+    v_BorderColorTop = i_BorderColorTop;
+    v_BorderColorRight = i_BorderColorRight;
+    v_BorderColorBottom = i_BorderColorBottom;
+    v_BorderColorLeft = i_BorderColorLeft;
     v_Pos = p_Pos;
     v_Scale = p_Scale;
     v_BorderRadius = i_BorderRadius * u_Scale;
     v_BorderWidth = i_BorderWidth * u_Scale;
+    v_GradientColor = i_GradientColor;
+    v_GradientAngle = i_GradientAngle;
+    v_ShadowColor = i_ShadowColor;
+    v_ShadowOffset = p_ShadowOffset;
+    v_ShadowBlur = p_ShadowBlur;
 
     gl_Position = u_Transform * i_Transform * vec4(q_Pos, 0.0, 1.0);
 }
@@ -5,7 +5,9 @@ use glow::HasContext;
 use iced_graphics::layer;
 use std::marker::PhantomData;
 
-pub use iced_graphics::triangle::{Mesh2D, Vertex2D};
+pub use iced_graphics::triangle::{Blend, Mesh2D, Vertex2D};
+
+mod textured;
 
 const VERTEX_BUFFER_SIZE: usize = 10_000;
 const INDEX_BUFFER_SIZE: usize = 10_000;
@@ -18,6 +20,7 @@ pub(crate) struct Pipeline {
     indices: Buffer<u32>,
     transform_location: <glow::Context as HasContext>::UniformLocation,
     current_transform: Transformation,
+    textured: textured::Pipeline,
 }
 
 impl Pipeline {
@@ -103,6 +106,7 @@ impl Pipeline {
             indices,
             transform_location,
             current_transform: Transformation::identity(),
+            textured: textured::Pipeline::new(gl),
         }
     }
 
@@ -113,6 +117,7 @@ impl Pipeline {
         transformation: Transformation,
         scale_factor: f32,
         meshes: &[layer::Mesh<'_>],
+        textured_meshes: &[layer::TexturedMesh<'_>],
     ) {
         unsafe {
             gl.enable(glow::MULTISAMPLE);
@@ -144,12 +149,14 @@ impl Pipeline {
         let mut last_vertex = 0;
         let mut last_index = 0;
 
-        for layer::Mesh { buffers, .. } in meshes {
+        for mesh in meshes {
+            let buffers = mesh.buffers;
+
             unsafe {
                 gl.buffer_sub_data_u8_slice(
                     glow::ARRAY_BUFFER,
                     (last_vertex * std::mem::size_of::<Vertex2D>()) as i32,
-                    bytemuck::cast_slice(&buffers.vertices),
+                    bytemuck::cast_slice(&mesh.vertices()),
                 );
 
                 gl.buffer_sub_data_u8_slice(
@@ -167,18 +174,40 @@ impl Pipeline {
         let mut last_vertex = 0;
         let mut last_index = 0;
 
-        for layer::Mesh {
-            buffers,
-            origin,
-            clip_bounds,
-        } in meshes
-        {
+        for mesh in meshes {
+            let layer::Mesh {
+                buffers,
+                origin,
+                clip_bounds,
+                ..
+            } = mesh;
+
             let transform =
                 transformation * Transformation::translate(origin.x, origin.y);
 
             let clip_bounds = (*clip_bounds * scale_factor).snap();
 
             unsafe {
+                if mesh.is_premultiplied() {
+                    let (src, dst) = match mesh.blend {
+                        Blend::Normal => (glow::ONE, glow::ONE_MINUS_SRC_ALPHA),
+                        Blend::Multiply => {
+                            (glow::DST_COLOR, glow::ONE_MINUS_SRC_ALPHA)
+                        }
+                        Blend::Screen => (glow::ONE, glow::ONE_MINUS_SRC_COLOR),
+                        Blend::Additive => (glow::ONE, glow::ONE),
+                    };
+
+                    gl.blend_func_separate(
+                        src,
+                        dst,
+                        glow::ONE,
+                        glow::ONE_MINUS_SRC_ALPHA,
+                    );
+                } else {
+                    gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+                }
+
                 if self.current_transform != transform {
                     let matrix: [f32; 16] = transform.into();
                     gl.uniform_matrix_4_f32_slice(
@@ -212,11 +241,26 @@ impl Pipeline {
         }
 
         unsafe {
+            gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
             gl.bind_vertex_array(None);
             gl.use_program(None);
             gl.disable(glow::SCISSOR_TEST);
             gl.disable(glow::MULTISAMPLE);
         }
+
+        if !textured_meshes.is_empty() {
+            self.textured.draw(
+                gl,
+                target_height,
+                transformation,
+                scale_factor,
+                textured_meshes,
+            );
+        }
+    }
+
+    pub fn trim_cache(&mut self, gl: &glow::Context) {
+        self.textured.trim_cache(gl);
     }
 }
 
@@ -0,0 +1,287 @@
+//! Draw meshes of triangles filled with the repeated tiles of an image.
+use super::Buffer;
+use crate::program;
+use crate::Transformation;
+use glow::HasContext;
+use iced_graphics::layer;
+use iced_graphics::triangle::TexturedVertex2D;
+use iced_native::image;
+use std::collections::{HashMap, HashSet};
+
+const VERTEX_BUFFER_SIZE: usize = 1_000;
+const INDEX_BUFFER_SIZE: usize = 1_000;
+
+#[derive(Debug)]
+pub(super) struct Pipeline {
+    program: <glow::Context as HasContext>::Program,
+    vertex_array: <glow::Context as HasContext>::VertexArray,
+    vertices: Buffer<TexturedVertex2D>,
+    indices: Buffer<u32>,
+    transform_location: <glow::Context as HasContext>::UniformLocation,
+    textures: HashMap<u64, <glow::Context as HasContext>::Texture>,
+    hits: HashSet<u64>,
+}
+
+impl Pipeline {
+    pub fn new(gl: &glow::Context) -> Pipeline {
+        let program = unsafe {
+            program::create(
+                gl,
+                &[
+                    (
+                        glow::VERTEX_SHADER,
+                        include_str!("../shader/textured.vert"),
+                    ),
+                    (
+                        glow::FRAGMENT_SHADER,
+                        include_str!("../shader/textured.frag"),
+                    ),
+                ],
+            )
+        };
+
+        let transform_location =
+            unsafe { gl.get_uniform_location(program, "u_Transform") }
+                .expect("Get transform location");
+
+        let texture_location =
+            unsafe { gl.get_uniform_location(program, "u_Texture") }
+                .expect("Get texture location");
+
+        unsafe {
+            gl.use_program(Some(program));
+            gl.uniform_1_i32(Some(texture_location), 0);
+            gl.use_program(None);
+        }
+
+        let vertex_array =
+            unsafe { gl.create_vertex_array().expect("Create vertex array") };
+
+        unsafe {
+            gl.bind_vertex_array(Some(vertex_array));
+        }
+
+        let vertices = unsafe {
+            Buffer::new(
+                gl,
+                glow::ARRAY_BUFFER,
+                glow::DYNAMIC_DRAW,
+                VERTEX_BUFFER_SIZE,
+            )
+        };
+
+        let indices = unsafe {
+            Buffer::new(
+                gl,
+                glow::ELEMENT_ARRAY_BUFFER,
+                glow::DYNAMIC_DRAW,
+                INDEX_BUFFER_SIZE,
+            )
+        };
+
+        unsafe {
+            let stride = std::mem::size_of::<TexturedVertex2D>() as i32;
+
+            gl.enable_vertex_attrib_array(0);
+            gl.vertex_attrib_pointer_f32(0, 2, glow::FLOAT, false, stride, 0);
+
+            gl.enable_vertex_attrib_array(1);
+            gl.vertex_attrib_pointer_f32(
+                1,
+                2,
+                glow::FLOAT,
+                false,
+                stride,
+                4 * 2,
+            );
+
+            gl.bind_vertex_array(None);
+        }
+
+        Pipeline {
+            program,
+            vertex_array,
+            vertices,
+            indices,
+            transform_location,
+            textures: HashMap::new(),
+            hits: HashSet::new(),
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        gl: &glow::Context,
+        target_height: u32,
+        transformation: Transformation,
+        scale_factor: f32,
+        meshes: &[layer::TexturedMesh<'_>],
+    ) {
+        unsafe {
+            gl.enable(glow::MULTISAMPLE);
+            gl.enable(glow::SCISSOR_TEST);
+            gl.use_program(Some(self.program));
+            gl.bind_vertex_array(Some(self.vertex_array));
+            gl.active_texture(glow::TEXTURE0);
+        }
+
+        for mesh in meshes {
+            let layer::TexturedMesh {
+                buffers,
+                origin,
+                texture,
+                clip_bounds,
+            } = mesh;
+
+            if buffers.indices.is_empty() {
+                continue;
+            }
+
+            let texture = match self.texture(gl, texture) {
+                Some(texture) => texture,
+                None => continue,
+            };
+
+            let transform =
+                transformation * Transformation::translate(origin.x, origin.y);
+
+            let clip_bounds = (*clip_bounds * scale_factor).snap();
+
+            unsafe {
+                self.vertices.bind(gl, buffers.vertices.len());
+                self.indices.bind(gl, buffers.indices.len());
+
+                gl.buffer_sub_data_u8_slice(
+                    glow::ARRAY_BUFFER,
+                    0,
+                    bytemuck::cast_slice(&buffers.vertices),
+                );
+
+                gl.buffer_sub_data_u8_slice(
+                    glow::ELEMENT_ARRAY_BUFFER,
+                    0,
+                    bytemuck::cast_slice(&buffers.indices),
+                );
+
+                let matrix: [f32; 16] = transform.into();
+                gl.uniform_matrix_4_f32_slice(
+                    Some(self.transform_location),
+                    false,
+                    &matrix,
+                );
+
+                gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+
+                gl.scissor(
+                    clip_bounds.x as i32,
+                    (target_height - (clip_bounds.y + clip_bounds.height))
+                        as i32,
+                    clip_bounds.width as i32,
+                    clip_bounds.height as i32,
+                );
+
+                gl.draw_elements(
+                    glow::TRIANGLES,
+                    buffers.indices.len() as i32,
+                    glow::UNSIGNED_INT,
+                    0,
+                );
+            }
+        }
+
+        unsafe {
+            gl.bind_texture(glow::TEXTURE_2D, None);
+            gl.bind_vertex_array(None);
+            gl.use_program(None);
+            gl.disable(glow::SCISSOR_TEST);
+            gl.disable(glow::MULTISAMPLE);
+        }
+    }
+
+    /// Deletes the textures that were not drawn since the last call.
+    pub fn trim_cache(&mut self, gl: &glow::Context) {
+        let hits = &self.hits;
+
+        self.textures.retain(|id, texture| {
+            let is_used = hits.contains(id);
+
+            if !is_used {
+                unsafe { gl.delete_texture(*texture) };
+            }
+
+            is_used
+        });
+
+        self.hits.clear();
+    }
+
+    fn texture(
+        &mut self,
+        gl: &glow::Context,
+        handle: &image::Handle,
+    ) -> Option<<glow::Context as HasContext>::Texture> {
+        let id = handle.id();
+
+        if let Some(texture) = self.textures.get(&id) {
+            let _ = self.hits.insert(id);
+
+            return Some(*texture);
+        }
+
+        let (width, height, pixels) = match handle.data() {
+            image::Data::Pixels {
+                width,
+                height,
+                pixels,
+            } if *width > 0 && *height > 0 => (*width, *height, pixels),
+            _ => return None,
+        };
+
+        let texture = unsafe {
+            let texture = gl.create_texture().expect("Create texture");
+
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                glow::SRGB8_ALPHA8 as i32,
+                width as i32,
+                height as i32,
+                0,
+                glow::BGRA,
+                glow::UNSIGNED_BYTE,
+                Some(pixels),
+            );
+
+            // Tiles are repeated by the sampler
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_S,
+                glow::REPEAT as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_T,
+                glow::REPEAT as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::NEAREST as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::LINEAR as i32,
+            );
+
+            texture
+        };
+
+        let _ = self.textures.insert(id, texture);
+        let _ = self.hits.insert(id);
+
+        Some(texture)
+    }
+}
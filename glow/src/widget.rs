@@ -9,34 +9,121 @@
 //! ```
 use crate::Renderer;
 
+pub mod autocomplete;
 pub mod button;
 pub mod checkbox;
+pub mod collapsible;
 pub mod container;
+pub mod context_menu;
+pub mod date_picker;
+pub mod draggable;
+pub mod drop_target;
+pub mod file_drop;
+pub mod gauge;
+pub mod icon;
+pub mod knob;
+pub mod lazy_list;
+pub mod menu_bar;
+pub mod modal;
+pub mod number_input;
 pub mod pane_grid;
+pub mod pick_list;
 pub mod progress_bar;
+pub mod progress_circle;
 pub mod radio;
+pub mod range_slider;
+pub mod rich_text;
+pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod spinner;
+pub mod table;
+pub mod tabs;
+pub mod tag_input;
+pub mod text_editor;
 pub mod text_input;
+pub mod time_picker;
+pub mod toast;
+pub mod toggler;
+pub mod tooltip;
+pub mod tree_view;
 
+#[doc(no_inline)]
+pub use autocomplete::Autocomplete;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use collapsible::Collapsible;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_target::DropTarget;
+#[doc(no_inline)]
+pub use file_drop::FileDrop;
+#[doc(no_inline)]
+pub use gauge::Gauge;
+#[doc(no_inline)]
+pub use icon::Icon;
+#[doc(no_inline)]
+pub use knob::Knob;
+#[doc(no_inline)]
+pub use lazy_list::LazyList;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
+pub use pick_list::PickList;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
+pub use progress_circle::ProgressCircle;
+#[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
+pub use rule::Rule;
+#[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
+pub use tag_input::TagInput;
+#[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
+pub use toast::Toast;
+#[doc(no_inline)]
+pub use toggler::Toggler;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use tree_view::TreeView;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
@@ -46,13 +133,42 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
+#[cfg(feature = "chart")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chart")))]
+pub mod chart;
+
 pub use iced_native::{Image, Space};
 
+/// A container that sizes its content to a fixed width to height ratio.
+pub type AspectRatio<'a, Message> =
+    iced_native::AspectRatio<'a, Message, Renderer>;
+
 /// A container that distributes its contents vertically.
 pub type Column<'a, Message> = iced_native::Column<'a, Message, Renderer>;
 
+/// A container that floats some elements over its content.
+pub type Float<'a, Message> = iced_native::Float<'a, Message, Renderer>;
+
+/// A container that arranges its contents in a grid.
+pub type Grid<'a, Message> = iced_native::Grid<'a, Message, Renderer>;
+
+/// A widget that builds its content from the space available to it.
+pub type Responsive<'a, Message> =
+    iced_native::Responsive<'a, Message, Renderer>;
+
 /// A container that distributes its contents horizontally.
 pub type Row<'a, Message> = iced_native::Row<'a, Message, Renderer>;
 
+/// A container that produces messages when some keyboard shortcuts are
+/// pressed.
+pub type Shortcuts<'a, Message> = iced_native::Shortcuts<'a, Message, Renderer>;
+
+/// A container that layers its contents on top of each other.
+pub type Stack<'a, Message> = iced_native::Stack<'a, Message, Renderer>;
+
 /// A paragraph of text.
 pub type Text = iced_native::Text<Renderer>;
+
+/// A container that distributes its contents horizontally, wrapping them
+/// into new lines.
+pub type Wrap<'a, Message> = iced_native::Wrap<'a, Message, Renderer>;
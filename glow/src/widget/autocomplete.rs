@@ -0,0 +1,15 @@
+//! Suggest values while your users type in a text field.
+//!
+//! An [`Autocomplete`] has some local [`State`].
+//!
+//! [`Autocomplete`]: type.Autocomplete.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::autocomplete::{menu, State, Style, StyleSheet};
+
+/// A text field showing the suggestions matching its value.
+///
+/// This is an alias of an `iced_native` autocomplete with an `iced_wgpu::Renderer`.
+pub type Autocomplete<'a, T, Message> =
+    iced_native::Autocomplete<'a, T, Message, Renderer>;
@@ -0,0 +1,7 @@
+//! Plot data in line, bar, scatter, and pie charts.
+//!
+//! Charts are drawn on a [`Canvas`] and can be zoomed with the mouse wheel
+//! and panned by dragging.
+//!
+//! [`Canvas`]: ../canvas/struct.Canvas.html
+pub use iced_graphics::chart::*;
@@ -2,8 +2,9 @@
 use crate::Renderer;
 
 pub use iced_graphics::checkbox::{Style, StyleSheet};
+pub use iced_native::checkbox::State;
 
 /// A box that can be checked.
 ///
 /// This is an alias of an `iced_native` checkbox with an `iced_wgpu::Renderer`.
-pub type Checkbox<Message> = iced_native::Checkbox<Message, Renderer>;
+pub type Checkbox<'a, Message> = iced_native::Checkbox<'a, Message, Renderer>;
@@ -0,0 +1,16 @@
+//! Show and hide content below a clickable header.
+//!
+//! A [`Collapsible`] has some local [`State`].
+//!
+//! [`Collapsible`]: type.Collapsible.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::collapsible::{Group, State, Style, StyleSheet};
+
+/// A section with a clickable header that expands and collapses its content.
+///
+/// This is an alias of an `iced_native` collapsible with an
+/// `iced_glow::Renderer`.
+pub type Collapsible<'a, Message> =
+    iced_native::Collapsible<'a, Message, Renderer>;
@@ -0,0 +1,18 @@
+//! Let your users pick a date from a calendar.
+//!
+//! A [`DatePicker`] has some local [`State`].
+//!
+//! [`DatePicker`]: type.DatePicker.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::date_picker::{
+    Calendar, Cell, Date, State, Style, StyleSheet, Weekday,
+};
+
+/// A field showing a date that opens a calendar when clicked.
+///
+/// This is an alias of an `iced_native` date picker with an
+/// `iced_glow::Renderer`.
+pub type DatePicker<'a, Message> =
+    iced_native::DatePicker<'a, Message, Renderer>;
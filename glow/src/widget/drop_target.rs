@@ -0,0 +1,17 @@
+//! Receive the payloads of a [`Draggable`].
+//!
+//! A [`DropTarget`] has some local [`State`].
+//!
+//! [`Draggable`]: ../draggable/type.Draggable.html
+//! [`DropTarget`]: type.DropTarget.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::drop_target::State;
+
+/// A container that receives the payloads dragged over it.
+///
+/// This is an alias of an `iced_native` drop target with an
+/// `iced_glow::Renderer`.
+pub type DropTarget<'a, Payload, Message> =
+    iced_native::DropTarget<'a, Payload, Message, Renderer>;
@@ -0,0 +1,2 @@
+//! Display icons from the icon font bundled with the renderer.
+pub use iced_graphics::icon::{Glyph, Icon};
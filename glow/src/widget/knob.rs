@@ -0,0 +1,16 @@
+//! Display an interactive rotary selector of a single value from a range of
+//! values.
+//!
+//! A [`Knob`] has some local [`State`].
+//!
+//! [`Knob`]: type.Knob.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::knob::{Style, StyleSheet};
+pub use iced_native::knob::State;
+
+/// A rotary control that selects a single value from a range of values.
+///
+/// This is an alias of an `iced_native` knob with an `iced_glow::Renderer`.
+pub type Knob<'a, Message> = iced_native::Knob<'a, Message, Renderer>;
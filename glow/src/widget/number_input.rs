@@ -0,0 +1,15 @@
+//! Let your users edit numbers with a field and a couple of step buttons.
+//!
+//! A [`NumberInput`] has some local [`State`].
+//!
+//! [`NumberInput`]: struct.NumberInput.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::number_input::{Number, State, Style, StyleSheet};
+
+/// A field that can be filled with a number.
+///
+/// This is an alias of an `iced_native` number input with an `iced_glow::Renderer`.
+pub type NumberInput<'a, T, Message> =
+    iced_native::NumberInput<'a, T, Message, Renderer>;
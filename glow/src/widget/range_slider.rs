@@ -0,0 +1,18 @@
+//! Display an interactive selector of an interval from a range of values.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+//!
+//! [`RangeSlider`]: struct.RangeSlider.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::range_slider::{
+    Handle, HandleShape, Orientation, State, Style, StyleSheet,
+};
+
+/// A bar with two handles that selects an interval from a range of values.
+///
+/// This is an alias of an `iced_native` range slider with an
+/// `iced_glow::Renderer`.
+pub type RangeSlider<'a, Message> =
+    iced_native::RangeSlider<'a, Message, Renderer>;
@@ -2,10 +2,10 @@
 use crate::Renderer;
 
 pub use iced_graphics::scrollable::{Scrollbar, Scroller, StyleSheet};
-pub use iced_native::scrollable::State;
+pub use iced_native::scrollable::{Direction, State, Viewport};
 
-/// A widget that can vertically display an infinite amount of content
-/// with a scrollbar.
+/// A widget that can display an infinite amount of content with
+/// scrollbars.
 ///
 /// This is an alias of an `iced_native` scrollable with a default
 /// `Renderer`.
@@ -0,0 +1,18 @@
+//! Let your users pick a time of the day.
+//!
+//! A [`TimePicker`] has some local [`State`].
+//!
+//! [`TimePicker`]: type.TimePicker.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::time_picker::{
+    Calendar, Cell, HourFormat, State, Style, StyleSheet, Time,
+};
+
+/// A field showing a time that opens a set of spinners when clicked.
+///
+/// This is an alias of an `iced_native` time picker with an
+/// `iced_glow::Renderer`.
+pub type TimePicker<'a, Message> =
+    iced_native::TimePicker<'a, Message, Renderer>;
@@ -0,0 +1,10 @@
+//! Show toggle controls using togglers.
+use crate::Renderer;
+
+pub use iced_graphics::toggler::{Style, StyleSheet};
+pub use iced_native::toggler::State;
+
+/// A switch that can be toggled on and off, next to a label.
+///
+/// This is an alias of an `iced_native` toggler with an `iced_glow::Renderer`.
+pub type Toggler<'a, Message> = iced_native::Toggler<'a, Message, Renderer>;
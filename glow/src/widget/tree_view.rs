@@ -0,0 +1,16 @@
+//! Display hierarchical data with expandable nodes.
+//!
+//! A [`TreeView`] has some local [`State`].
+//!
+//! [`TreeView`]: type.TreeView.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::tree_view::{Node, Row, State, Style, StyleSheet};
+
+/// A widget displaying a tree of nodes that can be expanded, collapsed, and
+/// selected.
+///
+/// This is an alias of an `iced_native` tree view with an `iced_glow::Renderer`.
+pub type TreeView<'a, T, Message> =
+    iced_native::TreeView<'a, T, Message, Renderer>;
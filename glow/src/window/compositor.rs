@@ -2,7 +2,7 @@ use crate::{Backend, Renderer, Settings, Viewport};
 
 use core::ffi::c_void;
 use glow::HasContext;
-use iced_graphics::{Antialiasing, Size};
+use iced_graphics::{Antialiasing, Size, Theme};
 use iced_native::mouse;
 
 /// A window graphics backend for iced powered by `glow`.
@@ -21,8 +21,6 @@ impl iced_graphics::window::GLCompositor for Compositor {
     ) -> (Self, Self::Renderer) {
         let gl = glow::Context::from_loader_function(loader_function);
 
-        gl.clear_color(1.0, 1.0, 1.0, 1.0);
-
         // Enable auto-conversion from/to sRGB
         gl.enable(glow::FRAMEBUFFER_SRGB);
 
@@ -56,6 +54,10 @@ impl iced_graphics::window::GLCompositor for Compositor {
         }
     }
 
+    fn set_theme(&mut self, renderer: &mut Renderer, theme: Theme) {
+        renderer.set_theme(theme);
+    }
+
     fn draw<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -64,8 +66,15 @@ impl iced_graphics::window::GLCompositor for Compositor {
         overlay: &[T],
     ) -> mouse::Interaction {
         let gl = &self.gl;
+        let background = renderer.theme().palette().background;
 
         unsafe {
+            gl.clear_color(
+                background.r,
+                background.g,
+                background.b,
+                background.a,
+            );
             gl.clear(glow::COLOR_BUFFER_BIT);
         }
 
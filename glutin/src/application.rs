@@ -4,7 +4,7 @@ use iced_graphics::window;
 use iced_graphics::Viewport;
 use iced_winit::application;
 use iced_winit::conversion;
-use iced_winit::{Clipboard, Debug, Proxy, Settings};
+use iced_winit::{Clipboard, Debug, Drag, Proxy, Settings, SystemTray};
 
 pub use iced_winit::Application;
 pub use iced_winit::{program, Program};
@@ -30,6 +30,8 @@ pub fn run<A, E, C>(
     let mut debug = Debug::new();
     debug.startup_started();
 
+    mouse::click::configure(settings.resolve_double_click());
+
     let event_loop = EventLoop::with_user_event();
     let mut runtime = {
         let executor = E::new().expect("Create executor");
@@ -39,7 +41,9 @@ pub fn run<A, E, C>(
     };
 
     let flags = settings.flags;
-    let (application, init_command) = runtime.enter(|| A::new(flags));
+    let (application, mut init_command) = runtime.enter(|| A::new(flags));
+    let init_font_actions = init_command.take_font_actions();
+    let mut init_tray_actions = init_command.take_tray_actions();
     runtime.spawn(init_command);
 
     let subscription = application.subscription();
@@ -47,9 +51,10 @@ pub fn run<A, E, C>(
 
     let mut title = application.title();
     let mut mode = application.mode();
+    let mut theme = application.theme();
 
     let context = {
-        let builder = settings.window.into_builder(
+        let builder = settings.window.clone().into_builder(
             &title,
             mode,
             event_loop.primary_monitor(),
@@ -67,16 +72,32 @@ pub fn run<A, E, C>(
         }
     };
 
+    settings.window.place(context.window());
+
+    let mut appearance = application::detect_appearance(context.window());
+
+    if let Some(appearance) = appearance {
+        runtime.broadcast(iced_native::Event::Window(
+            iced_native::window::Event::AppearanceChanged(appearance),
+        ));
+    }
+
     let clipboard = Clipboard::new(&context.window());
+    let proxy = event_loop.create_proxy();
+    let mut system_tray = SystemTray::new(event_loop.create_proxy());
     let mut mouse_interaction = mouse::Interaction::default();
     let mut modifiers = glutin::event::ModifiersState::default();
 
     let physical_size = context.window().inner_size();
     let mut viewport = Viewport::with_physical_size(
         Size::new(physical_size.width, physical_size.height),
-        context.window().scale_factor(),
+        context.window().scale_factor() * settings.window.scale_factor,
     );
+    let mut scale_factor = settings.window.scale_factor;
     let mut resized = false;
+    let mut is_cursor_grabbed = false;
+    let mut drag = Drag::new();
+    let close_on_request = settings.window.close_on_request;
 
     #[allow(unsafe_code)]
     let (mut compositor, mut renderer) = unsafe {
@@ -85,6 +106,15 @@ pub fn run<A, E, C>(
         })
     };
 
+    compositor.set_theme(&mut renderer, theme);
+
+    application::load_fonts(&mut renderer, settings.fonts);
+
+    for action in init_font_actions {
+        let _ = proxy
+            .send_event(application::handle_font_action(action, &mut renderer));
+    }
+
     let mut state = program::State::new(
         application,
         viewport.logical_size(),
@@ -93,7 +123,29 @@ pub fn run<A, E, C>(
     );
     debug.startup_finished();
 
+    let mut redraw_at = None;
+
     event_loop.run(move |event, _, control_flow| match event {
+        event::Event::NewEvents(event::StartCause::Init) => {
+            // Some platforms can only show a tray once the event loop runs
+            for action in init_tray_actions.drain(..) {
+                system_tray.perform(action);
+            }
+        }
+        event::Event::NewEvents(event::StartCause::ResumeTimeReached {
+            ..
+        }) => {
+            // A widget requested a frame for this instant
+            if redraw_at.take().is_some() {
+                state.queue_event(iced_native::Event::Window(
+                    iced_native::window::Event::RedrawRequested(
+                        std::time::Instant::now(),
+                    ),
+                ));
+
+                context.window().request_redraw();
+            }
+        }
         event::Event::MainEventsCleared => {
             let command = runtime.enter(|| {
                 state.update(
@@ -105,7 +157,72 @@ pub fn run<A, E, C>(
             });
 
             // If the application was updated
-            if let Some(command) = command {
+            if let Some(mut command) = command {
+                for action in command.take_window_actions() {
+                    if !application::targets_main_window(&action) {
+                        continue;
+                    }
+
+                    if let iced_winit::window::Action::SetScaleFactor(
+                        _,
+                        new_scale_factor,
+                    ) = action
+                    {
+                        scale_factor = new_scale_factor;
+
+                        let event = application::scale_viewport(
+                            context.window(),
+                            scale_factor,
+                            &mut viewport,
+                            &mut resized,
+                        );
+
+                        state.relayout(
+                            viewport.logical_size(),
+                            &mut renderer,
+                            &mut debug,
+                        );
+
+                        runtime.broadcast(event);
+                        continue;
+                    }
+
+                    if let Some(event) = application::handle_window_action(
+                        action,
+                        context.window(),
+                        &proxy,
+                        control_flow,
+                        &mut is_cursor_grabbed,
+                        &mut drag,
+                    ) {
+                        state.queue_event(event.clone());
+                        runtime.broadcast(event);
+                    }
+                }
+
+                for action in command.take_notifications() {
+                    runtime.spawn(application::show_notification(action));
+                }
+
+                for action in command.take_tray_actions() {
+                    system_tray.perform(action);
+                }
+
+                for action in command.take_clipboard_actions() {
+                    application::handle_clipboard_action(
+                        action,
+                        clipboard.as_ref(),
+                        proxy.clone(),
+                    );
+                }
+
+                for action in command.take_font_actions() {
+                    let _ = proxy.send_event(application::handle_font_action(
+                        action,
+                        &mut renderer,
+                    ));
+                }
+
                 runtime.spawn(command);
 
                 let program = state.program();
@@ -134,6 +251,25 @@ pub fn run<A, E, C>(
 
                     mode = new_mode;
                 }
+
+                if program.should_exit() {
+                    *control_flow = ControlFlow::Exit;
+                }
+
+                // Update theme
+                let new_theme = program.theme();
+
+                if theme != new_theme {
+                    compositor.set_theme(&mut renderer, new_theme);
+
+                    state.relayout(
+                        viewport.logical_size(),
+                        &mut renderer,
+                        &mut debug,
+                    );
+
+                    theme = new_theme;
+                }
             }
 
             context.window().request_redraw();
@@ -176,17 +312,52 @@ pub fn run<A, E, C>(
                 mouse_interaction = new_mouse_interaction;
             }
 
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
+            let frame = iced_native::Event::Window(
+                iced_native::window::Event::RedrawRequested(
+                    std::time::Instant::now(),
+                ),
+            );
+
+            // Widgets with built-in animations keep requesting frames while
+            // they are drawn, either right away or at a later instant
+            redraw_at =
+                iced_native::Renderer::take_redraw_request(&mut renderer);
+
+            if matches!(redraw_at, Some(at) if at <= std::time::Instant::now())
+            {
+                redraw_at = None;
+
+                state.queue_event(frame.clone());
+                context.window().request_redraw();
+            }
+
+            // Subscriptions to `window::frames` will produce a message,
+            // which will trigger another redraw
+            runtime.broadcast(frame);
         }
         event::Event::WindowEvent {
             event: window_event,
             ..
         } => {
+            if let event::WindowEvent::CloseRequested = window_event {
+                if close_on_request {
+                    *control_flow = ControlFlow::Exit;
+                    return;
+                }
+            }
+
+            drag.handle_window_event(&window_event, context.window());
+
+            if let Some(event) =
+                application::handle_appearance(&window_event, &mut appearance)
+            {
+                state.queue_event(event.clone());
+                runtime.broadcast(event);
+            }
+
             application::handle_window_event(
                 &window_event,
-                context.window(),
-                control_flow,
+                scale_factor,
                 &mut modifiers,
                 &mut viewport,
                 &mut resized,
@@ -202,8 +373,20 @@ pub fn run<A, E, C>(
                 runtime.broadcast(event);
             }
         }
+        event::Event::DeviceEvent {
+            event: device_event,
+            ..
+        } if is_cursor_grabbed => {
+            if let Some(event) = conversion::device_event(&device_event) {
+                state.queue_event(event.clone());
+                runtime.broadcast(event);
+            }
+        }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            *control_flow = match redraw_at {
+                Some(at) => ControlFlow::WaitUntil(at),
+                None => ControlFlow::Wait,
+            };
         }
     })
 }
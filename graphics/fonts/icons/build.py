@@ -0,0 +1,240 @@
+#!/usr/bin/env python3
+"""Builds `Icons.ttf` out of the outlines in `glyphs.py`.
+
+Usage: python3 build.py [--preview PATH]
+
+The font is written next to this directory. Every glyph gets the next
+codepoint of the private use area, starting at U+F100, except for the check
+mark, which keeps the U+F00C codepoint of Font Awesome. The codepoints are
+printed so they can be compared with `Glyph::codepoint` in
+`native/src/widget/icon.rs`.
+
+`--preview` also renders every glyph to a grayscale PNG, in codepoint order.
+"""
+import math
+import os
+import struct
+import sys
+import zlib
+
+from geometry import area
+from glyphs import CHECK, ICONS
+
+OUTPUT = os.path.join(os.path.dirname(os.path.abspath(__file__)), '..', 'Icons.ttf')
+CHECK_CODEPOINT = 0xF00C
+FIRST = 0xF100
+Y_OFFSET = -150  # The design square covers the whole em, from the descender
+
+def orient(shapes):
+    contours = []
+    for shape, hole in shapes:
+        pts = [(int(round(x)), int(round(y)) + Y_OFFSET) for x, y in shape]
+        # Remove consecutive duplicates
+        dedup = []
+        for q in pts:
+            if not dedup or dedup[-1] != q:
+                dedup.append(q)
+        if dedup[0] == dedup[-1]:
+            dedup.pop()
+        a = area(dedup)
+        # TrueType fills clockwise contours
+        if (a > 0) != hole:
+            dedup.reverse()
+        contours.append([(x, y, True) for x, y in dedup])
+    return contours
+
+def encode_glyph(contours):
+    if not contours:
+        return b''
+    pts = [p for c in contours for p in c]
+    xs = [p[0] for p in pts]; ys = [p[1] for p in pts]
+    out = struct.pack('>hhhhh', len(contours), min(xs), min(ys), max(xs), max(ys))
+    end = -1
+    for c in contours:
+        end += len(c)
+        out += struct.pack('>H', end)
+    out += struct.pack('>H', 0)
+    flags = b''; xd = b''; yd = b''
+    px = py = 0
+    for x, y, on in pts:
+        dx, dy = x - px, y - py
+        f = 1 if on else 0
+        if dx == 0:
+            f |= 16
+        elif -255 <= dx <= 255:
+            f |= 2 | (16 if dx > 0 else 0); xd += bytes([abs(dx)])
+        else:
+            xd += struct.pack('>h', dx)
+        if dy == 0:
+            f |= 32
+        elif -255 <= dy <= 255:
+            f |= 4 | (32 if dy > 0 else 0); yd += bytes([abs(dy)])
+        else:
+            yd += struct.pack('>h', dy)
+        flags += bytes([f])
+        px, py = x, y
+    out += flags + xd + yd
+    while len(out) % 4:
+        out += b'\0'
+    return out
+
+def checksum(b):
+    b = b + b'\0' * ((4 - len(b) % 4) % 4)
+    return sum(struct.unpack('>%dI' % (len(b) // 4), b)) & 0xFFFFFFFF
+
+def build():
+    glyphs = [[]]  # .notdef
+    entries = []
+    cp = FIRST
+    for name, doc, shapes in ICONS:
+        if shapes is None:
+            glyphs.append(CHECK)
+            entries.append((name, doc, CHECK_CODEPOINT))
+        else:
+            glyphs.append(orient(shapes))
+            entries.append((name, doc, cp))
+            cp += 1
+
+    glyf = b''; loca = []
+    for c in glyphs:
+        loca.append(len(glyf)); glyf += encode_glyph(c)
+    loca.append(len(glyf))
+
+    allpts = [p for c in glyphs for cc in c for p in cc]
+    xmin = min(p[0] for p in allpts); xmax = max(p[0] for p in allpts)
+    ymin = min(p[1] for p in allpts); ymax = max(p[1] for p in allpts)
+    n = len(glyphs)
+    max_points = max(sum(len(cc) for cc in c) for c in glyphs)
+    max_contours = max(len(c) for c in glyphs)
+
+    head = struct.pack('>IIIIHHqqhhhhHHhhh', 0x00010000, 0x00010000, 0, 0x5F0F3CF5, 0x000B, 1000,
+                       0, 0, xmin, ymin, xmax, ymax, 0, 8, 2, 1, 0)
+    lsbs = [min((p[0] for cc in c for p in cc), default=0) for c in glyphs]
+    rsbs = [1000 - max((p[0] for cc in c for p in cc), default=0) for c in glyphs]
+    hhea = struct.pack('>IhhhHhhhhhhhhhhhH', 0x00010000, 850, -150, 0, 1000, min(lsbs), min(rsbs), xmax,
+                       1, 0, 0, 0, 0, 0, 0, 0, n)
+    maxp = struct.pack('>IHHHHHHHHHHHHHH', 0x00010000, n, max_points, max_contours, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0)
+    hmtx = b''.join(struct.pack('>Hh', 1000, l) for l in lsbs)
+    loca_b = b''.join(struct.pack('>I', o) for o in loca)
+
+    # cmap format 4: one segment for the check mark, one for the rest, and the terminator
+    segs = [(CHECK_CODEPOINT, CHECK_CODEPOINT, 1), (FIRST, cp - 1, 2), (0xFFFF, 0xFFFF, 0)]
+    segx2 = 2 * len(segs)
+    sr = 2 * 2 ** int(math.floor(math.log2(len(segs))))
+    es = int(math.log2(sr // 2))
+    sub = struct.pack('>HHHHHH', 4, 0, 0, segx2, sr, es) + struct.pack('>H', segx2 - sr)
+    sub += b''.join(struct.pack('>H', e) for s, e, g in segs) + b'\0\0'
+    sub += b''.join(struct.pack('>H', s) for s, e, g in segs)
+    sub += b''.join(struct.pack('>h', ((g - s) + 0x8000) % 0x10000 - 0x8000 if g else 1) for s, e, g in segs)
+    sub += b''.join(struct.pack('>H', 0) for _ in segs)
+    sub = sub[:2] + struct.pack('>H', len(sub)) + sub[4:]
+    cmap = struct.pack('>HH', 0, 2) + struct.pack('>HHI', 0, 3, 20) + struct.pack('>HHI', 3, 1, 20) + sub
+
+    names = {
+        0: 'The check mark is part of Font Awesome, licensed under the SIL Open Font License 1.1. The rest of the glyphs are part of iced.',
+        1: 'Iced Icons', 2: 'Regular', 3: 'Iced Icons', 4: 'Iced Icons', 5: 'Version 1.0', 6: 'IcedIcons',
+    }
+    recs = b''; strs = b''
+    for nid, s in sorted(names.items()):
+        b = s.encode('utf-16-be')
+        recs += struct.pack('>HHHHHH', 3, 1, 0x409, nid, len(b), len(strs)); strs += b
+    name = struct.pack('>HHH', 0, len(names), 6 + len(recs)) + recs + strs
+
+    post = struct.pack('>IIhhIIIII', 0x00030000, 0, -75, 50, 0, 0, 0, 0, 0)
+    os2 = struct.pack(
+        '>HhHHHhhhhhhhhhhh10s16s4sHHHhhhHHII',
+        1,  # version
+        1000,  # xAvgCharWidth
+        400,  # usWeightClass
+        5,  # usWidthClass
+        0,  # fsType
+        634, 700, 0, 140,  # subscript size and offset
+        634, 700, 0, 480,  # superscript size and offset
+        49, 258,  # strikeout size and position
+        0,  # sFamilyClass
+        bytes([2, 0, 5, 3, 0, 0, 0, 0, 0, 0]),  # panose
+        bytes(16),  # ulUnicodeRange
+        b'PfEd',  # achVendID
+        0x40,  # fsSelection: regular
+        CHECK_CODEPOINT, cp - 1,  # usFirstCharIndex, usLastCharIndex
+        850, -150, 90,  # sTypoAscender, sTypoDescender, sTypoLineGap
+        850, 150,  # usWinAscent, usWinDescent
+        1, 0,  # ulCodePageRange: Latin 1
+    )
+
+    tables = {'OS/2': os2, 'cmap': cmap, 'glyf': glyf, 'head': head, 'hhea': hhea, 'hmtx': hmtx,
+              'loca': loca_b, 'maxp': maxp, 'name': name, 'post': post}
+    tags = sorted(tables)
+    nt = len(tags)
+    sr = 16 * 2 ** int(math.floor(math.log2(nt)))
+    out = struct.pack('>IHHHH', 0x00010000, nt, sr, int(math.log2(sr // 16)), nt * 16 - sr)
+    off = 12 + 16 * nt
+    body = b''
+    for t in tags:
+        d = tables[t]
+        out += struct.pack('>4sIII', t.encode(), checksum(d), off + len(body), len(d))
+        body += d + b'\0' * ((4 - len(d) % 4) % 4)
+    font = bytearray(out + body)
+    adj = (0xB1B0AFBA - checksum(bytes(font))) & 0xFFFFFFFF
+    head_off = off + sum(len(tables[t]) + (4 - len(tables[t]) % 4) % 4 for t in tags[:tags.index('head')])
+    struct.pack_into('>I', font, head_off + 8, adj)
+    return bytes(font), glyphs, entries
+
+def preview(glyphs, entries, path, cell=96):
+    cols = 10
+    rows = (len(entries) + cols - 1) // cols
+    w, h = cols * cell, rows * cell
+    img = bytearray([255] * (w * h))
+    for idx, contours in enumerate(glyphs[1:]):
+        ox, oy = (idx % cols) * cell, (idx // cols) * cell
+        segs = []
+        for c in contours:
+            # Flatten quadratic curves of the copied check mark
+            pts = []
+            m = len(c)
+            for i in range(m):
+                x, y, on = c[i]
+                if on:
+                    pts.append((x, y))
+                else:
+                    px, py, pon = c[i - 1]
+                    nx, ny, non = c[(i + 1) % m]
+                    if not pon: px, py = (px + x) / 2, (py + y) / 2
+                    if not non: nx, ny = (nx + x) / 2, (ny + y) / 2
+                    for k in range(1, 9):
+                        t = k / 8
+                        pts.append(((1-t)**2*px + 2*(1-t)*t*x + t*t*nx, (1-t)**2*py + 2*(1-t)*t*y + t*t*ny))
+            for i in range(len(pts)):
+                segs.append((pts[i], pts[(i + 1) % len(pts)]))
+        for py_ in range(cell):
+            for px_ in range(cell):
+                cov = 0
+                for sy in (0.25, 0.75):
+                    for sx in (0.25, 0.75):
+                        x = (px_ + sx) / cell * 1000
+                        y = 850 - (py_ + sy) / cell * 1000
+                        wn = 0
+                        for (x0, y0), (x1, y1) in segs:
+                            if (y0 <= y) != (y1 <= y):
+                                xi = x0 + (y - y0) * (x1 - x0) / (y1 - y0)
+                                if xi > x:
+                                    wn += 1 if y1 > y0 else -1
+                        cov += min(abs(wn), 1)
+                img[(oy + py_) * w + ox + px_] = 255 - int(cov / 4 * 255)
+    raw = b''.join(b'\0' + bytes(img[r * w:(r + 1) * w]) for r in range(h))
+    def chunk(t, d):
+        return struct.pack('>I', len(d)) + t + d + struct.pack('>I', zlib.crc32(t + d) & 0xFFFFFFFF)
+    png = b'\x89PNG\r\n\x1a\n' + chunk(b'IHDR', struct.pack('>IIBBBBB', w, h, 8, 0, 0, 0, 0)) + chunk(b'IDAT', zlib.compress(raw)) + chunk(b'IEND', b'')
+    open(path, 'wb').write(png)
+
+if __name__ == '__main__':
+    font, glyphs, entries = build()
+
+    with open(OUTPUT, 'wb') as f:
+        f.write(font)
+
+    for name, _, codepoint in entries:
+        print('U+%04X %s' % (codepoint, name))
+
+    if '--preview' in sys.argv:
+        preview(glyphs, entries, sys.argv[sys.argv.index('--preview') + 1])
@@ -0,0 +1,69 @@
+"""Helpers to build glyph outlines out of polygons."""
+import math
+
+STEP = math.radians(6)
+
+def rect(x0, y0, x1, y1):
+    return [(x0, y0), (x0, y1), (x1, y1), (x1, y0)]
+
+def arc_points(cx, cy, r, a0, a1, rx=None, ry=None):
+    # Angles in degrees, counter-clockwise from a0 to a1 (a1 may be < a0 for cw)
+    a0, a1 = math.radians(a0), math.radians(a1)
+    n = max(2, int(math.ceil(abs(a1 - a0) / STEP)))
+    rx = rx if rx is not None else r
+    ry = ry if ry is not None else r
+    return [(cx + rx * math.cos(a0 + (a1 - a0) * i / n),
+             cy + ry * math.sin(a0 + (a1 - a0) * i / n)) for i in range(n + 1)]
+
+def circle(cx, cy, r):
+    return arc_points(cx, cy, r, 0, 360)[:-1]
+
+def ellipse(cx, cy, rx, ry):
+    return arc_points(cx, cy, 0, 0, 360, rx, ry)[:-1]
+
+def band(cx, cy, r_out, r_in, a0, a1):
+    return arc_points(cx, cy, r_out, a0, a1) + list(reversed(arc_points(cx, cy, r_in, a0, a1)))
+
+def line(p, q, w):
+    (x0, y0), (x1, y1) = p, q
+    dx, dy = x1 - x0, y1 - y0
+    l = math.hypot(dx, dy)
+    nx, ny = -dy / l * w / 2, dx / l * w / 2
+    return [(x0 + nx, y0 + ny), (x1 + nx, y1 + ny), (x1 - nx, y1 - ny), (x0 - nx, y0 - ny)]
+
+def polyline(points, w, round_joins=True):
+    shapes = [line(points[i], points[i + 1], w) for i in range(len(points) - 1)]
+    if round_joins:
+        for p in points[1:-1]:
+            shapes.append(circle(p[0], p[1], w / 2))
+    return shapes
+
+def regular(cx, cy, r_out, r_in, n, start=90):
+    pts = []
+    for i in range(2 * n):
+        a = math.radians(start + 180.0 * i / n)
+        r = r_out if i % 2 == 0 else r_in
+        pts.append((cx + r * math.cos(a), cy + r * math.sin(a)))
+    return pts
+
+def transform(shape, f):
+    return [f(x, y) for x, y in shape]
+
+def rotate(shapes, degrees, cx=500, cy=500):
+    a = math.radians(degrees)
+    c, s = math.cos(a), math.sin(a)
+    def f(x, y):
+        x, y = x - cx, y - cy
+        return (cx + x * c - y * s, cy + x * s + y * c)
+    return [(transform(sh, f), hole) for sh, hole in shapes]
+
+def mirror_x(shapes, cx=500):
+    return [(transform(sh, lambda x, y: (2 * cx - x, y)), hole) for sh, hole in shapes]
+
+def area(shape):
+    a = 0
+    for i in range(len(shape)):
+        x0, y0 = shape[i]
+        x1, y1 = shape[(i + 1) % len(shape)]
+        a += x0 * y1 - x1 * y0
+    return a / 2
@@ -0,0 +1,220 @@
+"""The outlines of every glyph in `Icons.ttf`.
+
+Outlines are drawn on a 1000 units square, from (0, 0) at the bottom left to
+(1000, 1000) at the top right. `F` marks filled shapes and `H` marks holes.
+"""
+import math
+
+from geometry import *
+
+def F(*shapes):
+    return [(s, False) for s in shapes]
+
+def H(*shapes):
+    return [(s, True) for s in shapes]
+
+W = 100  # stroke width
+
+def ring(cx=500, cy=500, r_out=400, r_in=310):
+    return F(circle(cx, cy, r_out)) + H(circle(cx, cy, r_in))
+
+def rrect(x0, y0, x1, y1, r):
+    pts = []
+    pts += arc_points(x0 + r, y0 + r, r, 180, 270)
+    pts += arc_points(x1 - r, y0 + r, r, 270, 360)
+    pts += arc_points(x1 - r, y1 - r, r, 0, 90)
+    pts += arc_points(x0 + r, y1 - r, r, 90, 180)
+    return pts
+
+def frame(x0, y0, x1, y1, t, r=60):
+    return F(rrect(x0, y0, x1, y1, r)) + H(rect(x0 + t, y0 + t, x1 - t, y1 - t))
+
+def arrow_up():
+    return F(*polyline([(220, 520), (500, 800), (780, 520)], 110)) + F(line((500, 800), (500, 150), 110), circle(500, 800, 55))
+
+def chevron_up():
+    return F(*polyline([(180, 330), (500, 650), (820, 330)], 120))
+
+def caret_up():
+    return F([(180, 320), (500, 680), (820, 320)])
+
+def tray():
+    return F(*polyline([(150, 380), (150, 150), (850, 150), (850, 380)], W))
+
+def search(extra=()):
+    shapes = F(circle(420, 580, 290)) + H(circle(420, 580, 195))
+    shapes += F(line((420 + 150, 580 - 150), (860, 140), 130))
+    return shapes + F(*extra)
+
+def speaker():
+    return F([(110, 360), (110, 640), (270, 640), (480, 840), (480, 160), (270, 360)])
+
+def moon():
+    # Crescent between two circles, sampled explicitly
+    c1, r1 = (470, 500), 380
+    c2, r2 = (680, 660), 320
+    pts = []
+    for i in range(0, 361, 2):
+        a = math.radians(i)
+        p = (c1[0] + r1 * math.cos(a), c1[1] + r1 * math.sin(a))
+        if math.hypot(p[0] - c2[0], p[1] - c2[1]) >= r2:
+            pts.append((i, p))
+    # Find the gap in angles to start the outer arc right after it
+    angles = [a for a, _ in pts]
+    gap = max(range(len(angles)), key=lambda k: (angles[k] - angles[k - 1]) % 360)
+    outer = [p for _, p in pts[gap:] + pts[:gap]]
+    # Inner arc of the second circle, from the end of the outer arc back to its start
+    def ang(p, c):
+        return math.degrees(math.atan2(p[1] - c[1], p[0] - c[0]))
+    a_end, a_start = ang(outer[-1], c2), ang(outer[0], c2)
+    # Go through the part of c2 that lies inside c1 (clockwise)
+    if a_start > a_end:
+        a_start -= 360
+    inner = arc_points(c2[0], c2[1], r2, a_end, a_start)
+    return F(outer + inner[1:-1])
+
+def sun():
+    shapes = F(circle(500, 500, 190))
+    for i in range(8):
+        a = math.radians(45 * i)
+        p = (500 + 290 * math.cos(a), 500 + 290 * math.sin(a))
+        q = (500 + 400 * math.cos(a), 500 + 400 * math.sin(a))
+        shapes += F(line(p, q, 90), circle(p[0], p[1], 45), circle(q[0], q[1], 45))
+    return shapes
+
+def gear():
+    shapes = F(circle(500, 500, 290))
+    for i in range(8):
+        a = math.radians(22.5 + 45 * i)
+        p = (500 + 220 * math.cos(a), 500 + 220 * math.sin(a))
+        q = (500 + 400 * math.cos(a), 500 + 400 * math.sin(a))
+        shapes += F(line(p, q, 150))
+    return shapes + H(circle(500, 500, 120))
+
+def refresh(mirror=False):
+    # Arc from the top right, counter-clockwise, ending in an arrow head
+    shapes = F(band(500, 500, 380, 280, 60, 330))
+    a = math.radians(60)
+    tx, ty = math.sin(a), -math.cos(a)  # clockwise tangent
+    rx, ry = math.cos(a), math.sin(a)
+    base_in = (500 + 180 * rx, 500 + 180 * ry)
+    base_out = (500 + 480 * rx, 500 + 480 * ry)
+    mid = (500 + 330 * rx, 500 + 330 * ry)
+    tip = (mid[0] + 200 * tx, mid[1] + 200 * ty)
+    shapes += F([base_in, base_out, tip])
+    return mirror_x(shapes) if mirror else shapes
+
+def undo(mirror=False):
+    # Hook arrow pointing left
+    shapes = F(band(560, 420, 320, 220, -90, 90))
+    shapes += F(rect(300, 640, 560, 740), rect(300, 100, 560, 200))
+    shapes += F([(110, 690), (340, 900), (340, 480)])
+    return mirror_x(shapes) if mirror else shapes
+
+def pin():
+    pts = arc_points(500, 600, 300, -35, 215)
+    return F(pts + [(500, 80)]) + H(circle(500, 600, 110))
+
+def lock(open_=False):
+    shapes = F(rrect(190, 100, 810, 540, 60)) + H(circle(500, 350, 75))
+    if open_:
+        shapes += F(band(620, 640, 235, 145, 0, 180), rect(385, 500, 475, 640))
+    else:
+        shapes += F(band(500, 600, 235, 145, 0, 180), rect(265, 500, 355, 600), rect(645, 500, 735, 600))
+    return shapes
+
+def help_():
+    shapes = ring()
+    shapes += F(band(500, 590, 145, 65, -60, 200))
+    a = math.radians(-60)
+    end = (500 + 105 * math.cos(a), 590 + 105 * math.sin(a))
+    shapes += F(line(end, (500, 430), 80), circle(end[0], end[1], 40), rect(460, 360, 540, 440))
+    shapes += F(circle(500, 250, 55))
+    return shapes
+
+# The check mark of Font Awesome, in font units, as (x, y, on curve) points.
+#
+# It was the only glyph of the previous icon font, so it keeps its outline and
+# its codepoint. Entries without shapes use it.
+CHECK = [[
+    (933, 534, True), (933, 512, False), (917, 496, True), (437, 16, True),
+    (421, 1, False), (377, 1, False), (361, 16, True), (83, 294, True),
+    (68, 310, False), (68, 354, False), (83, 370, True), (159, 446, True),
+    (175, 462, False), (219, 462, False), (235, 446, True), (399, 281, True),
+    (765, 648, True), (781, 664, False), (825, 664, False), (841, 648, True),
+    (917, 572, True), (933, 557, False),
+]]
+
+ICONS = [
+    # (name, doc, shapes), in codepoint order
+    ("Check", "A check mark.", None),
+    ("Close", "A cross, used to close or dismiss content.", F(line((230, 230), (770, 770), 120), line((230, 770), (770, 230), 120))),
+    ("Plus", "A plus sign.", F(rect(445, 180, 555, 820), rect(180, 445, 820, 555))),
+    ("Minus", "A minus sign.", F(rect(180, 445, 820, 555))),
+    ("ArrowUp", "An arrow pointing up.", arrow_up()),
+    ("ArrowDown", "An arrow pointing down.", rotate(arrow_up(), 180)),
+    ("ArrowLeft", "An arrow pointing left.", rotate(arrow_up(), 90)),
+    ("ArrowRight", "An arrow pointing right.", rotate(arrow_up(), -90)),
+    ("ChevronUp", "A chevron pointing up.", chevron_up()),
+    ("ChevronDown", "A chevron pointing down.", rotate(chevron_up(), 180)),
+    ("ChevronLeft", "A chevron pointing left.", rotate(chevron_up(), 90)),
+    ("ChevronRight", "A chevron pointing right.", rotate(chevron_up(), -90)),
+    ("CaretUp", "A triangle pointing up.", caret_up()),
+    ("CaretDown", "A triangle pointing down.", rotate(caret_up(), 180)),
+    ("CaretLeft", "A triangle pointing left.", rotate(caret_up(), 90)),
+    ("CaretRight", "A triangle pointing right.", rotate(caret_up(), -90)),
+    ("Menu", "Three horizontal bars, usually opening a menu.", F(rrect(120, 170, 880, 280, 50), rrect(120, 445, 880, 555, 50), rrect(120, 720, 880, 830, 50))),
+    ("MoreHorizontal", "Three dots in a row.", F(circle(180, 500, 95), circle(500, 500, 95), circle(820, 500, 95))),
+    ("MoreVertical", "Three dots in a column.", F(circle(500, 180, 95), circle(500, 500, 95), circle(500, 820, 95))),
+    ("Grip", "A grid of dots, used as a drag handle.", F(*[circle(x, y, 75) for x in (360, 640) for y in (200, 500, 800)])),
+    ("Search", "A magnifying glass.", search()),
+    ("ZoomIn", "A magnifying glass with a plus sign.", search([rect(385, 545, 455, 615), rect(300, 545, 540, 615), rect(385, 460, 455, 700)])),
+    ("ZoomOut", "A magnifying glass with a minus sign.", search([rect(300, 545, 540, 615)])),
+    ("Home", "A house.", F([(200, 110), (200, 520), (500, 790), (800, 520), (800, 110), (590, 110), (590, 360), (410, 360), (410, 110)]) + F(*polyline([(90, 500), (500, 880), (910, 500)], 100))),
+    ("Settings", "A gear.", gear()),
+    ("User", "The silhouette of a person.", F(circle(500, 690, 180)) + F([(150, 110), (150, 260)] + arc_points(500, 260, 0, 180, 90, 350, 230)[1:] + arc_points(500, 260, 0, 90, 0, 350, 230)[1:] + [(850, 110)])),
+    ("Heart", "A heart.", F(circle(330, 630, 200), circle(670, 630, 200), [(148, 548), (500, 130), (852, 548), (500, 650)])),
+    ("Star", "A star.", F(regular(500, 480, 430, 180, 5))),
+    ("Trash", "A trash can.", F(rrect(160, 680, 840, 770, 30), rect(390, 740, 610, 860)) + F([(230, 100), (200, 630), (800, 630), (770, 100)]) + H(rect(370, 200, 440, 530), rect(560, 200, 630, 530))),
+    ("Edit", "A pencil.", F(line((330, 330), (700, 700), 190), line((740, 740), (830, 830), 190), [(263, 397), (130, 130), (397, 263)])),
+    ("Copy", "Two overlapping pages.", frame(330, 100, 860, 680, 90) + F([(140, 300), (140, 860), (660, 860), (660, 770), (230, 770), (230, 300)])),
+    ("Save", "A floppy disk.", F([(130, 130), (130, 870), (690, 870), (870, 690), (870, 130)]) + H(rect(260, 600, 640, 780), circle(500, 340, 110))),
+    ("Folder", "A folder.", F([(100, 150), (100, 820), (400, 820), (480, 720), (900, 720), (900, 150)])),
+    ("File", "A page with a folded corner.", F([(190, 90), (190, 910), (530, 910), (530, 620), (810, 620), (810, 90)], [(620, 910), (810, 720), (620, 720)])),
+    ("Download", "An arrow pointing down to a tray.", tray() + F(*polyline([(280, 520), (500, 300), (720, 520)], 110)) + F(line((500, 300), (500, 880), 110), circle(500, 300, 55))),
+    ("Upload", "An arrow pointing up from a tray.", tray() + F(*polyline([(280, 660), (500, 880), (720, 660)], 110)) + F(line((500, 880), (500, 300), 110), circle(500, 880, 55))),
+    ("Refresh", "A circular arrow.", refresh()),
+    ("Undo", "An arrow turning back to the left.", undo()),
+    ("Redo", "An arrow turning back to the right.", undo(True)),
+    ("Lock", "A closed padlock.", lock()),
+    ("Unlock", "An open padlock.", lock(True)),
+    ("Eye", "An eye.", F([(60, 500)] + [((1 - t) ** 2 * 60 + 2 * (1 - t) * t * 500 + t * t * 940, (1 - t) ** 2 * 500 + 2 * (1 - t) * t * 960 + t * t * 500) for t in [i / 24 for i in range(1, 24)]] + [(940, 500)] + [((1 - t) ** 2 * 940 + 2 * (1 - t) * t * 500 + t * t * 60, (1 - t) ** 2 * 500 + 2 * (1 - t) * t * 40 + t * t * 500) for t in [i / 24 for i in range(1, 24)]]) + H(ellipse(500, 500, 330, 170)) + F(circle(500, 500, 130))),
+    ("Bell", "A bell.", F([(130, 220), (250, 340), (250, 560)] + arc_points(500, 560, 250, 180, 0)[1:-1] + [(750, 560), (750, 340), (870, 220)], circle(500, 150, 85), circle(500, 830, 55))),
+    ("Mail", "An envelope.", frame(90, 170, 910, 830, 90) + F(*polyline([(180, 740), (500, 470), (820, 740)], 90))),
+    ("Calendar", "A calendar.", F(rrect(110, 90, 890, 810, 60)) + H(rect(200, 180, 800, 600)) + F(rect(270, 730, 370, 910), rect(630, 730, 730, 910)) + F(*[rect(x, y, x + 110, y + 110) for x in (270, 445, 620) for y in (250, 420)])),
+    ("Clock", "A clock.", ring() + F(rect(455, 455, 545, 780), line((500, 500), (690, 380), 90), circle(500, 500, 60))),
+    ("Info", "The letter i in a circle.", ring() + F(circle(500, 690, 62), rect(455, 230, 545, 570))),
+    ("Warning", "An exclamation mark in a triangle.", F([(500, 900), (950, 120), (50, 120)]) + H([(500, 730), (795, 210), (205, 210)]) + F(rect(458, 400, 542, 610), circle(500, 310, 52))),
+    ("Error", "A cross in a circle.", ring() + F(line((360, 360), (640, 640), 100), line((360, 640), (640, 360), 100))),
+    ("Help", "A question mark in a circle.", help_()),
+    ("CheckCircle", "A check mark in a circle.", ring() + F(*polyline([(320, 500), (450, 370), (690, 610)], 100))),
+    ("Play", "A triangle pointing right, used to play media.", F([(250, 110), (250, 890), (870, 500)])),
+    ("Pause", "Two vertical bars, used to pause media.", F(rrect(200, 140, 420, 860, 40), rrect(580, 140, 800, 860, 40))),
+    ("Stop", "A square, used to stop media.", F(rrect(170, 170, 830, 830, 50))),
+    ("Circle", "A filled circle.", F(circle(500, 500, 330))),
+    ("Filter", "A funnel.", F([(90, 860), (910, 860), (580, 450), (580, 90), (420, 210), (420, 450)])),
+    ("Bookmark", "A bookmark.", F([(210, 90), (210, 910), (790, 910), (790, 90), (500, 330)])),
+    ("Flag", "A flag.", F(rect(150, 60, 245, 920), [(245, 420), (245, 880), (870, 880), (710, 650), (870, 420)])),
+    ("Power", "A power symbol.", F(band(500, 470, 390, 295, 125, 415), rect(453, 450, 547, 920))),
+    ("Expand", "Four corners, used to expand content.", F(rect(90, 90, 190, 380), rect(90, 90, 380, 190), rect(810, 90, 910, 380), rect(620, 90, 910, 190), rect(90, 620, 190, 910), rect(90, 810, 380, 910), rect(810, 620, 910, 910), rect(620, 810, 910, 910))),
+    ("Grid", "Four squares in a grid.", F(rrect(120, 120, 450, 450, 40), rrect(550, 120, 880, 450, 40), rrect(120, 550, 450, 880, 40), rrect(550, 550, 880, 880, 40))),
+    ("List", "A bulleted list.", F(*[circle(170, y, 70) for y in (220, 500, 780)]) + F(*[rrect(320, y - 50, 900, y + 50, 30) for y in (220, 500, 780)])),
+    ("Image", "A picture of mountains.", frame(80, 140, 920, 860, 90) + F([(170, 230), (420, 530), (560, 380), (650, 470), (830, 290), (830, 230)], circle(650, 620, 75))),
+    ("Send", "A paper plane.", F([(70, 540), (930, 880), (660, 100), (500, 360), (850, 820), (420, 420)])),
+    ("VolumeUp", "A speaker emitting sound.", speaker() + F(band(480, 500, 190, 110, -50, 50), band(480, 500, 360, 280, -50, 50))),
+    ("VolumeOff", "A crossed out speaker.", speaker() + F(line((600, 380), (860, 640), 90), line((600, 640), (860, 380), 90))),
+    ("Sun", "A sun.", sun()),
+    ("Moon", "A crescent moon.", moon()),
+    ("Location", "A map pin.", pin()),
+    ("Code", "Two angle brackets around a slash.", F(*polyline([(320, 230), (90, 500), (320, 770)], 100)) + F(*polyline([(680, 230), (910, 500), (680, 770)], 100)) + F(line((580, 160), (420, 840), 90))),
+]
@@ -1,7 +1,12 @@
 //! Write a graphics backend.
+use iced_native::font;
 use iced_native::image;
+use iced_native::rich_text::{Fragment, Run};
 use iced_native::svg;
-use iced_native::{Font, Size};
+use iced_native::{Font, HorizontalAlignment, Size};
+
+use std::borrow::Cow;
+use std::time::Duration;
 
 /// The graphics backend of a [`Renderer`].
 ///
@@ -13,11 +18,27 @@ pub trait Backend {
     /// `iced_wgpu` and `iced_glow` because of limitations in the text rendering
     /// pipeline. It will be removed in the future.
     fn trim_measurements(&mut self) {}
+
+    /// Registers the font contained in the given bytes by its family name.
+    ///
+    /// By default, this fails with [`font::Error::Unsupported`].
+    ///
+    /// [`font::Error::Unsupported`]: ../../iced_native/font/enum.Error.html#variant.Unsupported
+    fn load_font(
+        &mut self,
+        _bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        Err(font::Error::Unsupported)
+    }
 }
 
 /// A graphics backend that supports text rendering.
 pub trait Text {
     /// The icon font of the backend.
+    ///
+    /// It must contain every [`icon::Glyph`].
+    ///
+    /// [`icon::Glyph`]: ../widget/icon/enum.Glyph.html
     const ICON_FONT: Font;
 
     /// The `char` representing a ✔ icon in the [`ICON_FONT`].
@@ -35,12 +56,25 @@ pub trait Text {
         font: Font,
         bounds: Size,
     ) -> (f32, f32);
+
+    /// Lays out the given fragments as a single paragraph that fits in the
+    /// provided bounds, returning the resulting runs.
+    fn layout(
+        &self,
+        fragments: &[Fragment<'_, Font>],
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+    ) -> Vec<Run>;
 }
 
 /// A graphics backend that supports image rendering.
 pub trait Image {
     /// Returns the dimensions of the provided image.
     fn dimensions(&self, handle: &image::Handle) -> (u32, u32);
+
+    /// Returns the delays of the frames of the provided image, if it is
+    /// animated.
+    fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration>;
 }
 
 /// A graphics backend that supports SVG rendering.
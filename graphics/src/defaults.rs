@@ -1,5 +1,6 @@
 //! Use default styling attributes to inherit styles.
 use iced_native::Color;
+use iced_style::theme::Theme;
 
 /// Some default styling attributes.
 #[derive(Debug, Clone, Copy)]
@@ -17,6 +18,12 @@ impl Default for Defaults {
 }
 
 /// Some default text styling attributes.
+///
+/// By default, text takes the color of the light [`Theme`]. The [`Renderer`]
+/// uses the color of its own [`Theme`] instead.
+///
+/// [`Theme`]: ../theme/enum.Theme.html
+/// [`Renderer`]: ../struct.Renderer.html
 #[derive(Debug, Clone, Copy)]
 pub struct Text {
     /// The default color of text
@@ -26,7 +33,7 @@ pub struct Text {
 impl Default for Text {
     fn default() -> Text {
         Text {
-            color: Color::BLACK,
+            color: Theme::Light.palette().text,
         }
     }
 }
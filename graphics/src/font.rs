@@ -12,12 +12,23 @@ pub use font_kit::{
     error::SelectionError as LoadError, family_name::FamilyName as Family,
 };
 
+/// Returns the family name of the font contained in the given bytes, if
+/// they contain a valid font.
+pub fn family_name(bytes: &[u8]) -> Option<String> {
+    ttf_parser::Font::from_data(bytes, 0)?.family_name()
+}
+
 /// A built-in fallback font, for convenience.
 #[cfg(feature = "font-fallback")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-fallback")))]
 pub const FALLBACK: &[u8] = include_bytes!("../fonts/Lato-Regular.ttf");
 
 /// A built-in icon font, for convenience.
+///
+/// It contains every [`icon::Glyph`]. It is built from the sources in the
+/// `fonts/icons` directory of this crate.
+///
+/// [`icon::Glyph`]: ../widget/icon/enum.Glyph.html
 #[cfg(feature = "font-icons")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-icons")))]
 pub const ICONS: iced_native::Font = iced_native::Font::External {
@@ -31,3 +42,40 @@ pub const ICONS: iced_native::Font = iced_native::Font::External {
 #[cfg(feature = "font-icons")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-icons")))]
 pub const CHECKMARK_ICON: char = '\u{F00C}';
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "font-fallback")]
+    fn reads_family_name() {
+        assert_eq!(family_name(FALLBACK), Some(String::from("Lato")));
+    }
+
+    #[test]
+    #[cfg(feature = "font-icons")]
+    fn contains_every_icon() {
+        use iced_native::icon::Glyph;
+
+        let bytes = match ICONS {
+            iced_native::Font::External { bytes, .. } => bytes,
+            _ => unreachable!(),
+        };
+
+        let font = ttf_parser::Font::from_data(bytes, 0).expect("Load icons");
+
+        for glyph in Glyph::ALL {
+            assert!(
+                font.glyph_index(glyph.codepoint()).is_some(),
+                "{:?} is missing",
+                glyph
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_fonts() {
+        assert_eq!(family_name(b"not a font"), None);
+    }
+}
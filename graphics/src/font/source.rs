@@ -1,9 +1,17 @@
 use crate::font::{Family, LoadError};
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+
 /// A font source that can find and load system fonts.
-#[allow(missing_debug_implementations)]
+///
+/// Looking fonts up is expensive, so a [`Source`] should be created once and
+/// reused. Every fallback family is only read from the system once.
+///
+/// [`Source`]: struct.Source.html
 pub struct Source {
     raw: font_kit::source::SystemSource,
+    fallbacks: RefCell<HashMap<&'static str, Option<Vec<u8>>>>,
 }
 
 impl Source {
@@ -13,6 +21,7 @@ impl Source {
     pub fn new() -> Self {
         Source {
             raw: font_kit::source::SystemSource::new(),
+            fallbacks: RefCell::new(HashMap::new()),
         }
     }
 
@@ -30,8 +39,11 @@ impl Source {
                 use std::io::Read;
 
                 let mut buf = Vec::new();
-                let mut reader = std::fs::File::open(path).expect("Read font");
-                let _ = reader.read_to_end(&mut buf);
+                let mut reader = std::fs::File::open(path)
+                    .map_err(|_| LoadError::CannotAccessSource)?;
+                let _ = reader
+                    .read_to_end(&mut buf)
+                    .map_err(|_| LoadError::CannotAccessSource)?;
 
                 Ok(buf)
             }
@@ -40,4 +52,181 @@ impl Source {
             }
         }
     }
+
+    /// Finds and loads a font able to render the given character.
+    ///
+    /// The fonts that are commonly installed for the script of the character
+    /// are tried first, followed by the default sans-serif font of the system.
+    /// Only fonts with outlines for the character are considered.
+    pub fn load_fallback(&self, c: char) -> Option<Vec<u8>> {
+        Script::of(c)
+            .families()
+            .iter()
+            .map(|name| (*name, Family::Title(String::from(*name))))
+            .chain(std::iter::once(("sans-serif", Family::SansSerif)))
+            .find_map(|(name, family)| {
+                let mut fallbacks = self.fallbacks.borrow_mut();
+
+                fallbacks
+                    .entry(name)
+                    .or_insert_with(|| self.load(&[family]).ok())
+                    .as_ref()
+                    .filter(|bytes| covers(bytes, c))
+                    .cloned()
+            })
+    }
+}
+
+impl std::fmt::Debug for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Source")
+            .field("fallbacks", &self.fallbacks.borrow().keys())
+            .finish()
+    }
+}
+
+/// Returns whether the font has an outline for the given character.
+///
+/// Glyphs only available as bitmaps, like in most color emoji fonts, cannot
+/// be drawn.
+fn covers(bytes: &[u8], c: char) -> bool {
+    ttf_parser::Font::from_data(bytes, 0)
+        .and_then(|font| {
+            font.glyph_index(c)
+                .and_then(|glyph| font.glyph_bounding_box(glyph))
+        })
+        .is_some()
+}
+
+/// A group of characters that are usually covered by the same fonts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Common,
+    Arabic,
+    Hebrew,
+    Devanagari,
+    Thai,
+    Hangul,
+    Cjk,
+    Symbols,
+    Emoji,
+}
+
+impl Script {
+    fn of(c: char) -> Script {
+        match c as u32 {
+            0x0590..=0x05FF | 0xFB1D..=0xFB4F => Script::Hebrew,
+            0x0600..=0x06FF
+            | 0x0750..=0x077F
+            | 0x08A0..=0x08FF
+            | 0xFB50..=0xFDFF
+            | 0xFE70..=0xFEFF => Script::Arabic,
+            0x0900..=0x097F | 0xA8E0..=0xA8FF => Script::Devanagari,
+            0x0E00..=0x0E7F => Script::Thai,
+            0x1100..=0x11FF
+            | 0x3130..=0x318F
+            | 0xA960..=0xA97F
+            | 0xAC00..=0xD7FF => Script::Hangul,
+            0x2E80..=0x2FDF
+            | 0x3000..=0x312F
+            | 0x3190..=0x9FFF
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFFEF
+            | 0x20000..=0x3FFFF => Script::Cjk,
+            0x2190..=0x21FF
+            | 0x2200..=0x22FF
+            | 0x2300..=0x23FF
+            | 0x2500..=0x25FF
+            | 0x2700..=0x27BF
+            | 0x2900..=0x2BFF
+            | 0x1D400..=0x1D7FF => Script::Symbols,
+            0x2600..=0x26FF | 0x1F000..=0x1FAFF => Script::Emoji,
+            _ => Script::Common,
+        }
+    }
+
+    fn families(self) -> &'static [&'static str] {
+        match self {
+            Script::Common => &[
+                "Noto Sans",
+                "DejaVu Sans",
+                "Segoe UI",
+                "Arial",
+                "Helvetica",
+                "Arial Unicode MS",
+            ],
+            Script::Arabic => &[
+                "Noto Sans Arabic",
+                "Noto Naskh Arabic",
+                "DejaVu Sans",
+                "Segoe UI",
+                "Geeza Pro",
+            ],
+            Script::Hebrew => &[
+                "Noto Sans Hebrew",
+                "DejaVu Sans",
+                "Segoe UI",
+                "Arial Hebrew",
+            ],
+            Script::Devanagari => &[
+                "Noto Sans Devanagari",
+                "Nirmala UI",
+                "Mangal",
+                "Kohinoor Devanagari",
+            ],
+            Script::Thai => {
+                &["Noto Sans Thai", "Leelawadee UI", "Tahoma", "Thonburi"]
+            }
+            Script::Hangul => &[
+                "Noto Sans CJK KR",
+                "Source Han Sans KR",
+                "Malgun Gothic",
+                "Apple SD Gothic Neo",
+                "NanumGothic",
+            ],
+            Script::Cjk => &[
+                "Noto Sans CJK SC",
+                "Noto Sans CJK JP",
+                "Source Han Sans SC",
+                "WenQuanYi Micro Hei",
+                "Microsoft YaHei",
+                "Yu Gothic",
+                "PingFang SC",
+                "Hiragino Sans",
+                "Droid Sans Fallback",
+            ],
+            Script::Symbols => &[
+                "DejaVu Sans",
+                "Noto Sans Symbols",
+                "Noto Sans Symbols2",
+                "Noto Sans Math",
+                "Segoe UI Symbol",
+                "Apple Symbols",
+                "Symbola",
+            ],
+            // Color emoji fonts, like Noto Color Emoji or Apple Color Emoji,
+            // only contain bitmaps and cannot be drawn
+            Script::Emoji => {
+                &["Noto Emoji", "Symbola", "Segoe UI Emoji", "Segoe UI Symbol"]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_scripts() {
+        assert_eq!(Script::of('a'), Script::Common);
+        assert_eq!(Script::of('я'), Script::Common);
+        assert_eq!(Script::of('ש'), Script::Hebrew);
+        assert_eq!(Script::of('漢'), Script::Cjk);
+        assert_eq!(Script::of('か'), Script::Cjk);
+        assert_eq!(Script::of('한'), Script::Hangul);
+        assert_eq!(Script::of('→'), Script::Symbols);
+        assert_eq!(Script::of('😀'), Script::Emoji);
+    }
 }
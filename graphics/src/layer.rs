@@ -3,9 +3,11 @@ use crate::image;
 use crate::svg;
 use crate::triangle;
 use crate::{
-    Background, Font, HorizontalAlignment, Point, Primitive, Rectangle, Size,
-    Vector, VerticalAlignment, Viewport,
+    Background, BorderRadius, Borders, Font, HorizontalAlignment, Point,
+    Primitive, Rectangle, Shadow, Size, Vector, VerticalAlignment, Viewport,
 };
+use iced_native::Color;
+use std::borrow::Cow;
 
 /// A group of primitives that should be clipped together.
 #[derive(Debug, Clone)]
@@ -25,6 +27,13 @@ pub struct Layer<'a> {
     /// [`Layer`]: struct.Layer.html
     pub meshes: Vec<Mesh<'a>>,
 
+    /// The textured meshes of the [`Layer`].
+    ///
+    /// They are drawn after the meshes of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub textured_meshes: Vec<TexturedMesh<'a>>,
+
     /// The text of the [`Layer`].
     ///
     /// [`Layer`]: struct.Layer.html
@@ -45,6 +54,7 @@ impl<'a> Layer<'a> {
             bounds,
             quads: Vec::new(),
             meshes: Vec::new(),
+            textured_meshes: Vec::new(),
             text: Vec::new(),
             images: Vec::new(),
         }
@@ -98,7 +108,12 @@ impl<'a> Layer<'a> {
 
         let mut layers = vec![first_layer];
 
-        Self::process_primitive(&mut layers, Vector::new(0.0, 0.0), primitive);
+        Self::process_primitive(
+            &mut layers,
+            Vector::new(0.0, 0.0),
+            Compositing::default(),
+            primitive,
+        );
 
         layers
     }
@@ -106,6 +121,7 @@ impl<'a> Layer<'a> {
     fn process_primitive(
         layers: &mut Vec<Self>,
         translation: Vector,
+        compositing: Compositing,
         primitive: &'a Primitive,
     ) {
         match primitive {
@@ -113,7 +129,12 @@ impl<'a> Layer<'a> {
             Primitive::Group { primitives } => {
                 // TODO: Inspect a bit and regroup (?)
                 for primitive in primitives {
-                    Self::process_primitive(layers, translation, primitive)
+                    Self::process_primitive(
+                        layers,
+                        translation,
+                        compositing,
+                        primitive,
+                    )
                 }
             }
             Primitive::Text {
@@ -131,7 +152,7 @@ impl<'a> Layer<'a> {
                     content,
                     bounds: *bounds + translation,
                     size: *size,
-                    color: color.into_linear(),
+                    color: compositing.apply(color.into_linear()),
                     font: *font,
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
@@ -146,22 +167,41 @@ impl<'a> Layer<'a> {
             } => {
                 let layer = layers.last_mut().unwrap();
 
-                // TODO: Move some of these computations to the GPU (?)
-                layer.quads.push(Quad {
-                    position: [
-                        bounds.x + translation.x,
-                        bounds.y + translation.y,
-                    ],
-                    size: [bounds.width, bounds.height],
-                    color: match background {
-                        Background::Color(color) => color.into_linear(),
-                    },
-                    border_radius: *border_radius as f32,
-                    border_width: *border_width as f32,
-                    border_color: border_color.into_linear(),
-                });
+                layer.quads.push(Quad::new(
+                    *bounds + translation,
+                    background,
+                    BorderRadius::from(*border_radius),
+                    Borders::uniform(*border_width, *border_color),
+                    None,
+                    compositing,
+                ));
+            }
+            Primitive::Card {
+                bounds,
+                background,
+                border_radius,
+                borders,
+                shadow,
+            } => {
+                let layer = layers.last_mut().unwrap();
+
+                layer.quads.push(Quad::new(
+                    *bounds + translation,
+                    background,
+                    *border_radius,
+                    *borders,
+                    shadow.as_ref(),
+                    compositing,
+                ));
             }
             Primitive::Mesh2D { buffers, size } => {
+                let bounds = layers.last().unwrap().bounds;
+
+                // Meshes drawn after a textured mesh must stay on top of it
+                if !layers.last().unwrap().textured_meshes.is_empty() {
+                    layers.push(Layer::new(bounds));
+                }
+
                 let layer = layers.last_mut().unwrap();
 
                 let bounds = Rectangle::new(
@@ -175,6 +215,30 @@ impl<'a> Layer<'a> {
                         origin: Point::new(translation.x, translation.y),
                         buffers,
                         clip_bounds,
+                        blend: compositing.blend,
+                        opacity: compositing.opacity,
+                    });
+                }
+            }
+            Primitive::TexturedMesh2D {
+                buffers,
+                texture,
+                size,
+            } => {
+                let layer = layers.last_mut().unwrap();
+
+                let bounds = Rectangle::new(
+                    Point::new(translation.x, translation.y),
+                    *size,
+                );
+
+                // Only draw visible content
+                if let Some(clip_bounds) = layer.bounds.intersection(&bounds) {
+                    layer.textured_meshes.push(TexturedMesh {
+                        origin: Point::new(translation.x, translation.y),
+                        buffers,
+                        texture,
+                        clip_bounds,
                     });
                 }
             }
@@ -198,6 +262,7 @@ impl<'a> Layer<'a> {
                         layers,
                         translation
                             - Vector::new(offset.x as f32, offset.y as f32),
+                        compositing,
                         content,
                     );
                     layers.push(new_layer);
@@ -210,33 +275,65 @@ impl<'a> Layer<'a> {
                 Self::process_primitive(
                     layers,
                     translation + *new_translation,
-                    &content,
+                    compositing,
+                    content,
+                );
+            }
+            Primitive::Composite {
+                blend,
+                opacity,
+                content,
+            } => {
+                Self::process_primitive(
+                    layers,
+                    translation,
+                    Compositing {
+                        blend: *blend,
+                        opacity: compositing.opacity * opacity,
+                    },
+                    content,
                 );
             }
             Primitive::Cached { cache } => {
-                Self::process_primitive(layers, translation, &cache);
+                Self::process_primitive(
+                    layers,
+                    translation,
+                    compositing,
+                    cache,
+                );
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                bounds,
+                frame,
+            } => {
                 let layer = layers.last_mut().unwrap();
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
                     bounds: *bounds + translation,
+                    frame: *frame,
                 });
             }
-            Primitive::Svg { handle, bounds } => {
+            Primitive::Svg {
+                handle,
+                bounds,
+                appearance,
+            } => {
                 let layer = layers.last_mut().unwrap();
 
                 layer.images.push(Image::Vector {
                     handle: handle.clone(),
                     bounds: *bounds + translation,
+                    appearance: *appearance,
                 });
             }
         }
     }
 }
 
-/// A colored rectangle with a border.
+/// A colored rectangle with borders and, optionally, a gradient fill and
+/// a drop shadow.
 ///
 /// This type can be directly uploaded to GPU memory.
 #[derive(Debug, Clone, Copy)]
@@ -257,20 +354,109 @@ pub struct Quad {
     /// [`Quad`]: struct.Quad.html
     pub color: [f32; 4],
 
-    /// The border color of the [`Quad`], in __linear RGB__.
+    /// The border color of each side of the [`Quad`], in __linear RGB__,
+    /// clockwise from the top one.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub border_color: [[f32; 4]; 4],
+
+    /// The radius of each corner of the [`Quad`], clockwise from the
+    /// top-left one.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub border_radius: [f32; 4],
+
+    /// The border width of each side of the [`Quad`], clockwise from the
+    /// top one.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub border_width: [f32; 4],
+
+    /// The color the [`Quad`] fades into following its gradient, in
+    /// __linear RGB__.
+    ///
+    /// It is equal to the `color` of a solid [`Quad`].
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub gradient_color: [f32; 4],
+
+    /// The angle of the gradient of the [`Quad`], in radians.
     ///
     /// [`Quad`]: struct.Quad.html
-    pub border_color: [f32; 4],
+    pub gradient_angle: f32,
 
-    /// The border radius of the [`Quad`].
+    /// The color of the shadow of the [`Quad`], in __linear RGB__.
+    ///
+    /// A transparent color means the [`Quad`] casts no shadow.
     ///
     /// [`Quad`]: struct.Quad.html
-    pub border_radius: f32,
+    pub shadow_color: [f32; 4],
 
-    /// The border width of the [`Quad`].
+    /// The offset of the shadow of the [`Quad`].
     ///
     /// [`Quad`]: struct.Quad.html
-    pub border_width: f32,
+    pub shadow_offset: [f32; 2],
+
+    /// The blur radius of the shadow of the [`Quad`].
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub shadow_blur: f32,
+}
+
+impl Quad {
+    fn new(
+        bounds: Rectangle,
+        background: &Background,
+        border_radius: BorderRadius,
+        borders: Borders,
+        shadow: Option<&Shadow>,
+        compositing: Compositing,
+    ) -> Quad {
+        let (color, gradient_color, gradient_angle) = match background {
+            Background::Color(color) => (*color, *color, 0.0),
+            Background::Gradient(gradient) => {
+                (gradient.start, gradient.end, gradient.angle)
+            }
+        };
+
+        let (shadow_color, shadow_offset, shadow_blur) = match shadow {
+            Some(shadow) => (
+                shadow.color,
+                [shadow.offset.x, shadow.offset.y],
+                shadow.blur_radius,
+            ),
+            None => (Color::TRANSPARENT, [0.0, 0.0], 0.0),
+        };
+
+        Quad {
+            position: [bounds.x, bounds.y],
+            size: [bounds.width, bounds.height],
+            color: compositing.apply(color.into_linear()),
+            border_color: [
+                compositing.apply(borders.top.color.into_linear()),
+                compositing.apply(borders.right.color.into_linear()),
+                compositing.apply(borders.bottom.color.into_linear()),
+                compositing.apply(borders.left.color.into_linear()),
+            ],
+            border_radius: [
+                f32::from(border_radius.top_left),
+                f32::from(border_radius.top_right),
+                f32::from(border_radius.bottom_right),
+                f32::from(border_radius.bottom_left),
+            ],
+            border_width: [
+                f32::from(borders.top.width),
+                f32::from(borders.right.width),
+                f32::from(borders.bottom.width),
+                f32::from(borders.left.width),
+            ],
+            gradient_color: compositing.apply(gradient_color.into_linear()),
+            gradient_angle,
+            shadow_color: compositing.apply(shadow_color.into_linear()),
+            shadow_offset,
+            shadow_blur,
+        }
+    }
 }
 
 /// A mesh of triangles.
@@ -290,6 +476,101 @@ pub struct Mesh<'a> {
     ///
     /// [`Mesh`]: struct.Mesh.html
     pub clip_bounds: Rectangle<f32>,
+
+    /// The blend mode of the [`Mesh`].
+    ///
+    /// [`Mesh`]: struct.Mesh.html
+    pub blend: triangle::Blend,
+
+    /// The opacity of the [`Mesh`].
+    ///
+    /// [`Mesh`]: struct.Mesh.html
+    pub opacity: f32,
+}
+
+impl<'a> Mesh<'a> {
+    /// Returns true if the [`Mesh`] needs to be drawn with premultiplied
+    /// colors; that is, when it is blended in a special way or it is not
+    /// fully opaque.
+    ///
+    /// [`Mesh`]: struct.Mesh.html
+    pub fn is_premultiplied(&self) -> bool {
+        self.blend != triangle::Blend::Normal || self.opacity < 1.0
+    }
+
+    /// Returns the vertices of the [`Mesh`] ready to be uploaded.
+    ///
+    /// If the [`Mesh`] [`is_premultiplied`], its colors are multiplied by
+    /// their alpha and the opacity of the [`Mesh`].
+    ///
+    /// [`Mesh`]: struct.Mesh.html
+    /// [`is_premultiplied`]: #method.is_premultiplied
+    pub fn vertices(&self) -> Cow<'a, [triangle::Vertex2D]> {
+        if !self.is_premultiplied() {
+            return Cow::Borrowed(&self.buffers.vertices);
+        }
+
+        Cow::Owned(
+            self.buffers
+                .vertices
+                .iter()
+                .map(|vertex| {
+                    let [r, g, b, a] = vertex.color;
+                    let alpha = a * self.opacity;
+
+                    triangle::Vertex2D {
+                        color: [r * alpha, g * alpha, b * alpha, alpha],
+                        ..*vertex
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+/// A mesh of triangles filled with the repeated tiles of an image.
+#[derive(Debug, Clone, Copy)]
+pub struct TexturedMesh<'a> {
+    /// The origin of the vertices of the [`TexturedMesh`].
+    ///
+    /// [`TexturedMesh`]: struct.TexturedMesh.html
+    pub origin: Point,
+
+    /// The vertex and index buffers of the [`TexturedMesh`].
+    ///
+    /// [`TexturedMesh`]: struct.TexturedMesh.html
+    pub buffers: &'a triangle::TexturedMesh2D,
+
+    /// The image repeated over the [`TexturedMesh`].
+    ///
+    /// [`TexturedMesh`]: struct.TexturedMesh.html
+    pub texture: &'a image::Handle,
+
+    /// The clipping bounds of the [`TexturedMesh`].
+    ///
+    /// [`TexturedMesh`]: struct.TexturedMesh.html
+    pub clip_bounds: Rectangle<f32>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Compositing {
+    blend: triangle::Blend,
+    opacity: f32,
+}
+
+impl Compositing {
+    fn apply(self, [r, g, b, a]: [f32; 4]) -> [f32; 4] {
+        [r, g, b, a * self.opacity]
+    }
+}
+
+impl Default for Compositing {
+    fn default() -> Compositing {
+        Compositing {
+            blend: triangle::Blend::Normal,
+            opacity: 1.0,
+        }
+    }
 }
 
 /// A paragraph of text.
@@ -341,6 +622,9 @@ pub enum Image {
 
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The frame of the image, if it is animated.
+        frame: usize,
     },
     /// A vector image.
     Vector {
@@ -349,6 +633,9 @@ pub enum Image {
 
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The colors and opacity of the image.
+        appearance: svg::Appearance,
     },
 }
 
@@ -19,6 +19,7 @@ mod widget;
 pub mod backend;
 pub mod font;
 pub mod layer;
+pub mod overlay;
 pub mod triangle;
 pub mod window;
 
@@ -34,7 +35,10 @@ pub use renderer::Renderer;
 pub use transformation::Transformation;
 pub use viewport::Viewport;
 
+pub use iced_style::theme::{self, Theme};
+
 pub use iced_native::{
-    Background, Font, HorizontalAlignment, Point, Rectangle, Size, Vector,
+    Background, BorderRadius, BorderSide, Borders, Font, Gradient,
+    HorizontalAlignment, Point, Rectangle, Shadow, Size, Vector,
     VerticalAlignment,
 };
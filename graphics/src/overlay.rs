@@ -0,0 +1,2 @@
+//! Display interactive elements on top of other widgets.
+pub mod menu;
@@ -0,0 +1,89 @@
+//! Build and show dropdown menus.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    mouse, overlay, Color, Font, HorizontalAlignment, Point, Rectangle,
+    VerticalAlignment,
+};
+
+pub use iced_style::menu::Style;
+
+impl<B> overlay::menu::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Option<Style>;
+
+    fn draw<T: ToString>(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        options: &[T],
+        hovered_option: Option<usize>,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        style: &Option<Style>,
+    ) -> Self::Output {
+        let style = style.unwrap_or_else(|| Style::from(self.theme()));
+        let is_mouse_over = bounds.contains(cursor_position);
+        let option_height = f32::from(text_size + padding * 2);
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: 0,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        }];
+
+        for (i, option) in options.iter().enumerate() {
+            let is_selected = hovered_option == Some(i);
+
+            let bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + option_height * i as f32,
+                width: bounds.width,
+                height: option_height,
+            };
+
+            if is_selected {
+                primitives.push(Primitive::Quad {
+                    bounds,
+                    background: style.selected_background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            primitives.push(Primitive::Text {
+                content: option.to_string(),
+                bounds: Rectangle {
+                    x: bounds.x + f32::from(padding),
+                    y: bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..bounds
+                },
+                size: f32::from(text_size),
+                font,
+                color: if is_selected {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
@@ -1,6 +1,6 @@
 use iced_native::{
-    image, svg, Background, Color, Font, HorizontalAlignment, Rectangle, Size,
-    Vector, VerticalAlignment,
+    image, svg, Background, BorderRadius, Borders, Color, Font,
+    HorizontalAlignment, Rectangle, Shadow, Size, Vector, VerticalAlignment,
 };
 
 use crate::triangle;
@@ -46,12 +46,28 @@ pub enum Primitive {
         /// The border color of the quad
         border_color: Color,
     },
+    /// A quad primitive with independent corner radii, borders on each side
+    /// and an optional drop shadow
+    Card {
+        /// The bounds of the card
+        bounds: Rectangle,
+        /// The background of the card
+        background: Background,
+        /// The radius of each corner of the card
+        border_radius: BorderRadius,
+        /// The border of each side of the card
+        borders: Borders,
+        /// The shadow cast by the card, if any
+        shadow: Option<Shadow>,
+    },
     /// An image primitive
     Image {
         /// The handle of the image
         handle: image::Handle,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The frame of the image to draw, if it is animated
+        frame: usize,
     },
     /// An SVG primitive
     Svg {
@@ -60,6 +76,9 @@ pub enum Primitive {
 
         /// The bounds of the viewport
         bounds: Rectangle,
+
+        /// The colors and opacity of the SVG
+        appearance: svg::Appearance,
     },
     /// A clip primitive
     Clip {
@@ -90,6 +109,35 @@ pub enum Primitive {
         /// Any geometry that falls out of this region will be clipped.
         size: Size,
     },
+    /// A mesh of triangles filled with the repeated tiles of an image.
+    ///
+    /// Only images built from pixels are supported.
+    TexturedMesh2D {
+        /// The vertex and index buffers of the mesh
+        buffers: triangle::TexturedMesh2D,
+
+        /// The image repeated over the mesh
+        texture: image::Handle,
+
+        /// The size of the drawable region of the mesh.
+        ///
+        /// Any geometry that falls out of this region will be clipped.
+        size: Size,
+    },
+    /// A primitive that is composited with a blend mode and an opacity.
+    ///
+    /// The blend mode is only applied to meshes, while the opacity is applied
+    /// to every mesh, quad, and text individually.
+    Composite {
+        /// The blend mode used to draw the meshes of the content
+        blend: triangle::Blend,
+
+        /// The opacity of the content, between `0.0` and `1.0`
+        opacity: f32,
+
+        /// The primitive to composite
+        content: Box<Primitive>,
+    },
     /// A cached primitive.
     ///
     /// This can be useful if you are implementing a widget where primitive
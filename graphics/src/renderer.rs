@@ -1,12 +1,31 @@
-use crate::{Backend, Defaults, Primitive};
+use crate::{defaults, Backend, Defaults, Primitive, Theme};
+use iced_native::font;
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
-use iced_native::{Background, Color, Element, Point, Widget};
+use iced_native::{
+    Background, Color, Element, Point, Rectangle, Vector, Widget,
+};
+
+use std::borrow::Cow;
+use std::time::Instant;
+
+/// The width of the ring drawn around focused widgets.
+const FOCUS_RING_WIDTH: f32 = 2.0;
+
+/// The color of the ring drawn around focused widgets.
+const FOCUS_RING_COLOR: Color = Color {
+    r: 0.3,
+    g: 0.55,
+    b: 1.0,
+    a: 1.0,
+};
 
 /// A backend-agnostic renderer that supports all the built-in widgets.
 #[derive(Debug)]
 pub struct Renderer<B: Backend> {
     backend: B,
+    theme: Theme,
+    redraw_request: Option<Instant>,
 }
 
 impl<B: Backend> Renderer<B> {
@@ -15,7 +34,11 @@ impl<B: Backend> Renderer<B> {
     /// [`Renderer`]: struct.Renderer.html
     /// [`Backend`]: backend/trait.Backend.html
     pub fn new(backend: B) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            theme: Theme::Light,
+            redraw_request: None,
+        }
     }
 
     /// Returns a reference to the [`Backend`] of the [`Renderer`].
@@ -33,6 +56,29 @@ impl<B: Backend> Renderer<B> {
     pub fn backend_mut(&mut self) -> &mut B {
         &mut self.backend
     }
+
+    /// Returns the [`Theme`] the [`Renderer`] draws the built-in widgets
+    /// with.
+    ///
+    /// Custom widgets can use its [`Palette`] to blend in with the built-in
+    /// ones.
+    ///
+    /// [`Theme`]: theme/enum.Theme.html
+    /// [`Renderer`]: struct.Renderer.html
+    /// [`Palette`]: theme/struct.Palette.html
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Sets the [`Theme`] of the [`Renderer`].
+    ///
+    /// The runtime calls it with the theme of the application.
+    ///
+    /// [`Theme`]: theme/enum.Theme.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
 }
 
 impl<B> iced_native::Renderer for Renderer<B>
@@ -42,6 +88,14 @@ where
     type Output = (Primitive, mouse::Interaction);
     type Defaults = Defaults;
 
+    fn defaults(&self) -> Defaults {
+        Defaults {
+            text: defaults::Text {
+                color: self.theme.palette().text,
+            },
+        }
+    }
+
     fn layout<'a, Message>(
         &mut self,
         element: &Element<'a, Message, Self>,
@@ -53,6 +107,83 @@ where
 
         layout
     }
+
+    fn overlay(
+        &mut self,
+        (base_primitive, base_cursor): (Primitive, mouse::Interaction),
+        (overlay_primitive, overlay_cursor): (Primitive, mouse::Interaction),
+        overlay_bounds: Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        (
+            Primitive::Group {
+                primitives: vec![
+                    base_primitive,
+                    // Clipping starts a new layer, drawn on top of the base
+                    Primitive::Clip {
+                        bounds: overlay_bounds,
+                        offset: Vector::new(0, 0),
+                        content: Box::new(overlay_primitive),
+                    },
+                ],
+            },
+            std::cmp::max(base_cursor, overlay_cursor),
+        )
+    }
+
+    fn focus_ring(
+        &mut self,
+        (base_primitive, base_cursor): (Primitive, mouse::Interaction),
+        bounds: Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let ring = Rectangle {
+            x: bounds.x - FOCUS_RING_WIDTH,
+            y: bounds.y - FOCUS_RING_WIDTH,
+            width: bounds.width + 2.0 * FOCUS_RING_WIDTH,
+            height: bounds.height + 2.0 * FOCUS_RING_WIDTH,
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    base_primitive,
+                    Primitive::Clip {
+                        bounds: ring,
+                        offset: Vector::new(0, 0),
+                        content: Box::new(Primitive::Quad {
+                            bounds: ring,
+                            background: Background::Color(Color::TRANSPARENT),
+                            border_radius: FOCUS_RING_WIDTH as u16 * 2,
+                            border_width: FOCUS_RING_WIDTH as u16,
+                            border_color: FOCUS_RING_COLOR,
+                        }),
+                    },
+                ],
+            },
+            base_cursor,
+        )
+    }
+
+    fn request_redraw(&mut self) {
+        self.request_redraw_at(Instant::now());
+    }
+
+    fn request_redraw_at(&mut self, at: Instant) {
+        self.redraw_request = Some(match self.redraw_request {
+            Some(current) => current.min(at),
+            None => at,
+        });
+    }
+
+    fn take_redraw_request(&mut self) -> Option<Instant> {
+        self.redraw_request.take()
+    }
+
+    fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        self.backend.load_font(bytes)
+    }
 }
 
 impl<B> layout::Debugger for Renderer<B>
@@ -30,3 +30,64 @@ unsafe impl bytemuck::Zeroable for Vertex2D {}
 
 #[allow(unsafe_code)]
 unsafe impl bytemuck::Pod for Vertex2D {}
+
+/// A set of [`TexturedVertex2D`] and indices representing a list of
+/// triangles filled with the repeated tiles of an image.
+///
+/// [`TexturedVertex2D`]: struct.TexturedVertex2D.html
+#[derive(Clone, Debug)]
+pub struct TexturedMesh2D {
+    /// The vertices of the mesh
+    pub vertices: Vec<TexturedVertex2D>,
+
+    /// The list of vertex indices that defines the triangles of the mesh.
+    ///
+    /// Therefore, this list should always have a length that is a multiple of
+    /// 3.
+    pub indices: Vec<u32>,
+}
+
+/// A two-dimensional vertex with some texture coordinates.
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct TexturedVertex2D {
+    /// The vertex position
+    pub position: [f32; 2],
+    /// The texture coordinates of the vertex.
+    ///
+    /// A unit covers a whole tile, so the texture repeats every `1.0`.
+    pub uv: [f32; 2],
+}
+
+#[allow(unsafe_code)]
+unsafe impl bytemuck::Zeroable for TexturedVertex2D {}
+
+#[allow(unsafe_code)]
+unsafe impl bytemuck::Pod for TexturedVertex2D {}
+
+/// The way the colors of a mesh are combined with the colors drawn behind it.
+///
+/// When antialiasing is enabled, some backends render the meshes of a layer
+/// offscreen. In that case, meshes are only blended with the meshes drawn
+/// before them in the same layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Blend {
+    /// The mesh is drawn over its background.
+    Normal,
+
+    /// The colors of the mesh are multiplied by its background, darkening it.
+    Multiply,
+
+    /// The inverted colors of the mesh and its background are multiplied,
+    /// lightening it.
+    Screen,
+
+    /// The colors of the mesh are added to its background.
+    Additive,
+}
+
+impl Default for Blend {
+    fn default() -> Blend {
+        Blend::Normal
+    }
+}
@@ -7,48 +7,134 @@
 //! ```
 //! use iced_graphics::{button, Button};
 //! ```
+pub mod autocomplete;
 pub mod button;
 pub mod checkbox;
+pub mod collapsible;
 pub mod container;
+pub mod context_menu;
+pub mod date_picker;
+pub mod gauge;
+pub mod icon;
 pub mod image;
+pub mod knob;
+pub mod lazy_list;
+pub mod menu_bar;
+pub mod modal;
+pub mod number_input;
 pub mod pane_grid;
+pub mod pick_list;
 pub mod progress_bar;
+pub mod progress_circle;
 pub mod radio;
+pub mod range_slider;
+pub mod rich_text;
+pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod spinner;
 pub mod svg;
+pub mod table;
+pub mod tabs;
+pub mod tag_input;
+pub mod text_editor;
 pub mod text_input;
+pub mod time_picker;
+pub mod toast;
+pub mod toggler;
+pub mod tooltip;
+pub mod tree_view;
 
 mod column;
+mod float;
+mod grid;
 mod row;
 mod space;
+mod stack;
 mod text;
+mod wrap;
 
+#[doc(no_inline)]
+pub use autocomplete::Autocomplete;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use collapsible::Collapsible;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use gauge::Gauge;
+#[doc(no_inline)]
+pub use icon::Icon;
+#[doc(no_inline)]
+pub use knob::Knob;
+#[doc(no_inline)]
+pub use lazy_list::LazyList;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
+pub use pick_list::PickList;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
+pub use progress_circle::ProgressCircle;
+#[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
+pub use rule::Rule;
+#[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
+pub use tag_input::TagInput;
+#[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
+pub use toast::Toast;
+#[doc(no_inline)]
+pub use toggler::Toggler;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use tree_view::TreeView;
 
 pub use column::Column;
+pub use float::Float;
+pub use grid::Grid;
 pub use image::Image;
 pub use row::Row;
 pub use space::Space;
+pub use stack::Stack;
 pub use svg::Svg;
 pub use text::Text;
+pub use wrap::Wrap;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
@@ -57,3 +143,7 @@ pub mod canvas;
 #[cfg(feature = "canvas")]
 #[doc(no_inline)]
 pub use canvas::Canvas;
+
+#[cfg(feature = "chart")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chart")))]
+pub mod chart;
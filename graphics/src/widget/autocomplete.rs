@@ -0,0 +1,17 @@
+//! Suggest values while your users type in a text field.
+//!
+//! An [`Autocomplete`] has some local [`State`].
+//!
+//! [`Autocomplete`]: type.Autocomplete.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::autocomplete::State;
+pub use iced_style::menu;
+pub use iced_style::text_input::{Style, StyleSheet};
+
+/// A text field showing the suggestions matching its value.
+///
+/// This is an alias of an `iced_native` autocomplete with an `iced_wgpu::Renderer`.
+pub type Autocomplete<'a, T, Message, Backend> =
+    iced_native::Autocomplete<'a, T, Message, Renderer<Backend>>;
@@ -35,6 +35,7 @@ where
         cursor_position: Point,
         is_disabled: bool,
         is_pressed: bool,
+        is_focused: bool,
         style: &Box<dyn StyleSheet>,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
@@ -42,15 +43,17 @@ where
         let is_mouse_over = bounds.contains(cursor_position);
 
         let styling = if is_disabled {
-            style.disabled()
+            style.disabled(self.theme())
         } else if is_mouse_over {
             if is_pressed {
-                style.pressed()
+                style.pressed(self.theme())
             } else {
-                style.hovered()
+                style.hovered(self.theme())
             }
+        } else if is_focused {
+            style.focused(self.theme())
         } else {
-            style.active()
+            style.active(self.theme())
         };
 
         let (content, _) = content.draw(
@@ -65,15 +68,29 @@ where
         );
 
         (
-            if styling.background.is_some() || styling.border_width > 0 {
-                let background = Primitive::Quad {
-                    bounds,
-                    background: styling
-                        .background
-                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
-                    border_radius: styling.border_radius,
-                    border_width: styling.border_width,
-                    border_color: styling.border_color,
+            if styling.background.is_some()
+                || styling.border_width > 0
+                || styling.borders.is_some()
+            {
+                let background = styling
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT));
+
+                let background = match styling.borders {
+                    Some(borders) => Primitive::Card {
+                        bounds,
+                        background,
+                        border_radius: styling.border_radius.into(),
+                        borders,
+                        shadow: None,
+                    },
+                    None => Primitive::Quad {
+                        bounds,
+                        background,
+                        border_radius: styling.border_radius,
+                        border_width: styling.border_width,
+                        border_color: styling.border_color,
+                    },
                 };
 
                 if styling.shadow_offset == Vector::default() {
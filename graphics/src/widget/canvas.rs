@@ -6,35 +6,53 @@
 //!
 //! [`Canvas`]: struct.Canvas.html
 //! [`Frame`]: struct.Frame.html
-use crate::{Backend, Defaults, Primitive, Renderer};
+use crate::backend::{self, Backend};
+use crate::{Defaults, Primitive, Renderer};
+use iced_native::touch::Gesture;
 use iced_native::{
-    layout, mouse, Clipboard, Element, Hasher, Layout, Length, Point, Size,
-    Vector, Widget,
+    focus, keyboard, layout, mouse, touch, window, Clipboard, Element, Hasher,
+    Id, Layout, Length, Point, Size, Vector, Widget,
 };
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::time::Instant;
 
 pub mod path;
 
 mod cache;
 mod cursor;
 mod event;
+mod export;
 mod fill;
 mod frame;
 mod geometry;
+mod matrix;
+mod measurer;
+mod pattern;
 mod program;
+mod scene;
+mod shadow;
+mod state;
 mod stroke;
 mod text;
 
+pub use crate::triangle::Blend;
 pub use cache::Cache;
 pub use cursor::Cursor;
 pub use event::Event;
+pub use export::{Dropped, ExportError, Pixels};
 pub use fill::{Fill, FillRule};
 pub use frame::Frame;
 pub use geometry::Geometry;
+pub use matrix::Matrix;
+pub use measurer::Measurer;
 pub use path::Path;
+pub use pattern::Pattern;
 pub use program::Program;
-pub use stroke::{LineCap, LineJoin, Stroke};
+pub use scene::{NodeId, Scene};
+pub use shadow::Shadow;
+pub use state::State;
+pub use stroke::{LineCap, LineDash, LineJoin, Stroke};
 pub use text::Text;
 
 /// A widget capable of drawing 2D graphics.
@@ -95,14 +113,17 @@ pub use text::Text;
 /// let canvas = Canvas::new(Circle { radius: 50.0 });
 /// ```
 #[derive(Debug)]
-pub struct Canvas<Message, P: Program<Message>> {
+pub struct Canvas<'a, Message, P: Program<Message>> {
     width: Length,
     height: Length,
     program: P,
+    state: Option<&'a mut State>,
+    tab_index: Option<u16>,
+    id: Option<Id>,
     phantom: PhantomData<Message>,
 }
 
-impl<Message, P: Program<Message>> Canvas<Message, P> {
+impl<'a, Message, P: Program<Message>> Canvas<'a, Message, P> {
     const DEFAULT_SIZE: u16 = 100;
 
     /// Creates a new [`Canvas`].
@@ -113,6 +134,9 @@ impl<Message, P: Program<Message>> Canvas<Message, P> {
             width: Length::Units(Self::DEFAULT_SIZE),
             height: Length::Units(Self::DEFAULT_SIZE),
             program,
+            state: None,
+            tab_index: None,
+            id: None,
             phantom: PhantomData,
         }
     }
@@ -132,12 +156,49 @@ impl<Message, P: Program<Message>> Canvas<Message, P> {
         self.height = height;
         self
     }
+
+    /// Sets the [`State`] of the [`Canvas`].
+    ///
+    /// The [`State`] keeps track of previous clicks and of the pressed
+    /// modifier keys, allowing the [`Canvas`] to report double and triple
+    /// clicks, as well as modifier-clicks, in [`Event::Click`].
+    ///
+    /// It also allows the [`Canvas`] to be focused. Then, the [`Canvas`] only
+    /// receives [`Event::Keyboard`] while focused, instead of receiving every
+    /// keyboard event.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Event::Click`]: enum.Event.html#variant.Click
+    /// [`Event::Keyboard`]: enum.Event.html#variant.Keyboard
+    pub fn state(mut self, state: &'a mut State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the position of the [`Canvas`] in the focus order.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Canvas`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../../iced_native/struct.Id.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
 }
 
-impl<Message, P, B> Widget<Message, Renderer<B>> for Canvas<Message, P>
+impl<'a, Message, P, B> Widget<Message, Renderer<B>> for Canvas<'a, Message, P>
 where
     P: Program<Message>,
-    B: Backend,
+    B: Backend + backend::Text,
 {
     fn width(&self) -> Length {
         self.width
@@ -173,6 +234,25 @@ where
             iced_native::Event::Mouse(mouse_event) => {
                 Some(Event::Mouse(mouse_event))
             }
+            iced_native::Event::Keyboard(keyboard_event) => {
+                match &mut self.state {
+                    // Keyboard events only reach the program while focused
+                    Some(state) => {
+                        state.track_modifiers(keyboard_event);
+
+                        None
+                    }
+                    // A canvas without a state cannot be focused, so it
+                    // receives every keyboard event
+                    None => Some(Event::Keyboard(keyboard_event)),
+                }
+            }
+            iced_native::Event::Touch(touch_event) => {
+                Some(Event::Touch(touch_event))
+            }
+            iced_native::Event::Stylus(stylus_event) => {
+                Some(Event::Stylus(stylus_event))
+            }
             _ => None,
         };
 
@@ -185,11 +265,86 @@ where
                 messages.push(message);
             }
         }
+
+        if let Some(state) = &mut self.state {
+            let gestures = match event {
+                iced_native::Event::Touch(touch::Event::FingerPressed {
+                    position,
+                    ..
+                }) if !bounds.contains(position) => Vec::new(),
+                iced_native::Event::Touch(touch_event) => {
+                    state.recognize(touch_event, Instant::now())
+                }
+                iced_native::Event::Window(window::Event::RedrawRequested(
+                    now,
+                )) => state.tick(now).into_iter().collect(),
+                _ => Vec::new(),
+            };
+
+            let offset = Vector::new(bounds.x, bounds.y);
+
+            for gesture in gestures {
+                let gesture = match gesture {
+                    Gesture::Tap { position } => Gesture::Tap {
+                        position: position - offset,
+                    },
+                    Gesture::LongPress { position } => Gesture::LongPress {
+                        position: position - offset,
+                    },
+                    Gesture::Pinch { center, scale } => Gesture::Pinch {
+                        center: center - offset,
+                        scale,
+                    },
+                    Gesture::Pan { translation } => {
+                        Gesture::Pan { translation }
+                    }
+                };
+
+                if let Some(message) =
+                    self.program.update(Event::Gesture(gesture), bounds, cursor)
+                {
+                    messages.push(message);
+                }
+            }
+        }
+
+        if let iced_native::Event::Mouse(mouse::Event::ButtonPressed(button)) =
+            event
+        {
+            if let Some(state) = &mut self.state {
+                state.set_focused(bounds.contains(cursor_position));
+            }
+
+            if let Some(position) = cursor.position_in(&bounds) {
+                let (kind, modifiers) = match &mut self.state {
+                    Some(state) => {
+                        (state.click(button, position), state.modifiers())
+                    }
+                    None => (
+                        mouse::click::Kind::Single,
+                        keyboard::ModifiersState::default(),
+                    ),
+                };
+
+                let click = Event::Click {
+                    button,
+                    position,
+                    kind,
+                    modifiers,
+                };
+
+                if let Some(message) =
+                    self.program.update(click, bounds, cursor)
+                {
+                    messages.push(message);
+                }
+            }
+        }
     }
 
     fn draw(
         &self,
-        _renderer: &mut Renderer<B>,
+        renderer: &mut Renderer<B>,
         _defaults: &Defaults,
         layout: Layout<'_>,
         cursor_position: Point,
@@ -198,13 +353,30 @@ where
         let translation = Vector::new(bounds.x, bounds.y);
         let cursor = Cursor::from_window_position(cursor_position);
 
+        // Long presses are recognized when a later frame is drawn
+        if matches!(
+            &self.state,
+            Some(state) if state.is_waiting_for_gesture()
+        ) {
+            iced_native::Renderer::request_redraw(renderer);
+        }
+
+        let backend = renderer.backend();
+        let measure = |content: &str, size, font, bounds| {
+            backend.measure(content, size, font, bounds)
+        };
+
         (
             Primitive::Translate {
                 translation,
                 content: Box::new(Primitive::Group {
                     primitives: self
                         .program
-                        .draw(bounds, cursor)
+                        .draw_with_measurer(
+                            bounds,
+                            cursor,
+                            &Measurer::new(&measure),
+                        )
                         .into_iter()
                         .map(Geometry::into_primitive)
                         .collect(),
@@ -221,16 +393,62 @@ where
         self.width.hash(state);
         self.height.hash(state);
     }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        // A canvas without a state cannot be focused
+        if let Some(state) = &mut self.state {
+            targets.push(focus::Target::new(
+                *state,
+                layout.bounds(),
+                self.tab_index,
+                self.id.clone(),
+            ));
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer<B>,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        let state = match &mut self.state {
+            Some(state) if focus::Focusable::is_focused(*state) => state,
+            _ => return false,
+        };
+
+        state.track_modifiers(event);
+
+        let bounds = layout.bounds();
+        let cursor = Cursor::Unavailable;
+
+        match self.program.update(Event::Keyboard(event), bounds, cursor) {
+            Some(message) => {
+                messages.push(message);
+
+                true
+            }
+            None => false,
+        }
+    }
 }
 
-impl<'a, Message, P, B> From<Canvas<Message, P>>
+impl<'a, Message, P, B> From<Canvas<'a, Message, P>>
     for Element<'a, Message, Renderer<B>>
 where
     Message: 'static,
     P: Program<Message> + 'a,
-    B: Backend,
+    B: Backend + backend::Text,
 {
-    fn from(canvas: Canvas<Message, P>) -> Element<'a, Message, Renderer<B>> {
+    fn from(
+        canvas: Canvas<'a, Message, P>,
+    ) -> Element<'a, Message, Renderer<B>> {
         Element::new(canvas)
     }
 }
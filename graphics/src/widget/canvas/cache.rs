@@ -1,17 +1,20 @@
 use crate::{
     canvas::{Frame, Geometry},
-    Primitive,
+    triangle, Primitive,
 };
 
-use iced_native::Size;
-use std::{cell::RefCell, sync::Arc};
+use iced_native::{Rectangle, Size, Vector};
+use std::{cell::RefCell, collections::HashMap, sync::Arc};
 
 enum State {
     Empty,
-    Filled {
-        bounds: Size,
-        primitive: Arc<Primitive>,
-    },
+    Filled { bounds: Size, tiles: Vec<Tile> },
+}
+
+#[derive(Debug)]
+struct Tile {
+    region: Rectangle,
+    primitive: Option<Arc<Primitive>>,
 }
 
 impl Default for State {
@@ -24,12 +27,16 @@ impl Default for State {
 /// A [`Cache`] will not redraw its geometry unless the dimensions of its layer
 /// change or it is explicitly cleared.
 ///
+/// A [`Cache`] can also be split in tiles. In that case, specific regions of
+/// it can be invalidated, and only the tiles intersecting them will be redrawn.
+///
 /// [`Layer`]: ../trait.Layer.html
 /// [`Cache`]: struct.Cache.html
 /// [`Geometry`]: struct.Geometry.html
 #[derive(Debug, Default)]
 pub struct Cache {
     state: RefCell<State>,
+    tile_size: Option<Size>,
 }
 
 impl Cache {
@@ -39,6 +46,20 @@ impl Cache {
     pub fn new() -> Self {
         Cache {
             state: Default::default(),
+            tile_size: None,
+        }
+    }
+
+    /// Creates a new empty [`Cache`] split in tiles of the given size.
+    ///
+    /// Use [`invalidate`] to redraw only the tiles that have changed.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    /// [`invalidate`]: #method.invalidate
+    pub fn tiled(tile_size: Size) -> Self {
+        Cache {
+            state: Default::default(),
+            tile_size: Some(tile_size),
         }
     }
 
@@ -49,48 +70,273 @@ impl Cache {
         *self.state.borrow_mut() = State::Empty;
     }
 
+    /// Invalidates a region of the [`Cache`], forcing a redraw of every tile
+    /// that intersects it the next time it is used.
+    ///
+    /// If the [`Cache`] is not tiled, this is equivalent to [`clear`] when the
+    /// region intersects its bounds.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    /// [`clear`]: #method.clear
+    pub fn invalidate(&mut self, region: Rectangle) {
+        if let State::Filled { tiles, .. } = &mut *self.state.borrow_mut() {
+            for tile in tiles.iter_mut() {
+                if tile.region.intersection(&region).is_some() {
+                    tile.primitive = None;
+                }
+            }
+        }
+    }
+
     /// Draws [`Geometry`] using the provided closure and stores it in the
     /// [`Cache`].
     ///
     /// The closure will only be called when
     /// - the bounds have changed since the previous draw call.
     /// - the [`Cache`] is empty or has been explicitly cleared.
+    /// - a tile of the [`Cache`] has been invalidated.
     ///
     /// Otherwise, the previously stored [`Geometry`] will be returned. The
     /// [`Cache`] is not cleared in this case. In other words, it will keep
     /// returning the stored [`Geometry`] if needed.
     ///
+    /// When tiles are redrawn, the closure is called once with a [`Frame`]
+    /// covering the whole bounds, but any geometry falling outside of the
+    /// redrawn tiles will be skipped.
+    ///
     /// [`Cache`]: struct.Cache.html
+    /// [`Frame`]: struct.Frame.html
     pub fn draw(&self, bounds: Size, draw_fn: impl Fn(&mut Frame)) -> Geometry {
-        use std::ops::Deref;
+        let mut state = self.state.borrow_mut();
 
-        if let State::Filled {
-            bounds: cached_bounds,
-            primitive,
-        } = self.state.borrow().deref()
-        {
-            if *cached_bounds == bounds {
-                return Geometry::from_primitive(Primitive::Cached {
-                    cache: primitive.clone(),
-                });
+        let is_outdated = match &*state {
+            State::Empty => true,
+            State::Filled {
+                bounds: cached_bounds,
+                ..
+            } => *cached_bounds != bounds,
+        };
+
+        if is_outdated {
+            *state = State::Filled {
+                bounds,
+                tiles: self.tiles(bounds),
+            };
+        }
+
+        let tiles = match &mut *state {
+            State::Filled { tiles, .. } => tiles,
+            State::Empty => unreachable!(),
+        };
+
+        let outdated: Vec<usize> = tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, tile)| tile.primitive.is_none())
+            .map(|(i, _)| i)
+            .collect();
+
+        if tiles.len() == 1 && !outdated.is_empty() {
+            let mut frame = Frame::new(bounds);
+            draw_fn(&mut frame);
+
+            tiles[0].primitive =
+                Some(Arc::new(frame.into_geometry().into_primitive()));
+        } else if !outdated.is_empty() {
+            let regions: Vec<Rectangle> =
+                outdated.iter().map(|i| tiles[*i].region).collect();
+
+            for (i, primitive) in outdated
+                .into_iter()
+                .zip(draw_tiles(bounds, &regions, &draw_fn))
+            {
+                tiles[i].primitive = Some(Arc::new(primitive));
             }
         }
 
-        let mut frame = Frame::new(bounds);
-        draw_fn(&mut frame);
+        let primitives = tiles
+            .iter()
+            .filter_map(|tile| tile.primitive.clone())
+            .map(|cache| Primitive::Cached { cache })
+            .collect();
 
-        let primitive = {
-            let geometry = frame.into_geometry();
+        Geometry::from_primitive(Primitive::Group { primitives })
+    }
 
-            Arc::new(geometry.into_primitive())
+    fn tiles(&self, bounds: Size) -> Vec<Tile> {
+        let tile_size = match self.tile_size {
+            Some(tile_size)
+                if tile_size.width > 0.0 && tile_size.height > 0.0 =>
+            {
+                tile_size
+            }
+            _ => {
+                return vec![Tile {
+                    region: Rectangle::with_size(bounds),
+                    primitive: None,
+                }];
+            }
         };
 
-        *self.state.borrow_mut() = State::Filled {
-            bounds,
-            primitive: primitive.clone(),
+        let columns = (bounds.width / tile_size.width).ceil().max(1.0) as usize;
+        let rows = (bounds.height / tile_size.height).ceil().max(1.0) as usize;
+
+        (0..rows)
+            .flat_map(|row| {
+                (0..columns).map(move |column| {
+                    let x = column as f32 * tile_size.width;
+                    let y = row as f32 * tile_size.height;
+
+                    Tile {
+                        region: Rectangle {
+                            x,
+                            y,
+                            width: tile_size.width.min(bounds.width - x),
+                            height: tile_size.height.min(bounds.height - y),
+                        },
+                        primitive: None,
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+/// Draws the given regions at once and splits the result in a primitive per
+/// region.
+fn draw_tiles(
+    bounds: Size,
+    regions: &[Rectangle],
+    draw_fn: impl Fn(&mut Frame),
+) -> Vec<Primitive> {
+    let mut frame = Frame::with_culling_region(bounds, union(regions));
+    draw_fn(&mut frame);
+
+    let primitives = match frame.into_geometry().into_primitive() {
+        Primitive::Group { primitives } => primitives,
+        primitive => vec![primitive],
+    };
+
+    let mut parts = vec![Vec::new(); regions.len()];
+
+    for primitive in primitives {
+        match primitive {
+            Primitive::Mesh2D { buffers, size } => {
+                for (part, buffers) in
+                    parts.iter_mut().zip(split(&buffers, regions))
+                {
+                    if !buffers.indices.is_empty() {
+                        part.push(Primitive::Mesh2D { buffers, size });
+                    }
+                }
+            }
+            primitive => {
+                // Text, images and clipped layers are shared by every region
+                // instead of being copied
+                let primitive = Arc::new(primitive);
+
+                for part in parts.iter_mut() {
+                    part.push(Primitive::Cached {
+                        cache: primitive.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    regions
+        .iter()
+        .zip(parts)
+        .map(|(region, primitives)| Primitive::Clip {
+            bounds: *region,
+            offset: Vector::new(0, 0),
+            content: Box::new(Primitive::Group { primitives }),
+        })
+        .collect()
+}
+
+/// Splits a mesh in the triangles intersecting each of the given regions.
+fn split(
+    mesh: &triangle::Mesh2D,
+    regions: &[Rectangle],
+) -> Vec<triangle::Mesh2D> {
+    let mut parts = vec![
+        triangle::Mesh2D {
+            vertices: Vec::new(),
+            indices: Vec::new(),
         };
+        regions.len()
+    ];
+
+    let mut mappings = vec![HashMap::new(); regions.len()];
+
+    for triangle in mesh.indices.chunks_exact(3) {
+        let positions =
+            triangle.iter().map(|i| mesh.vertices[*i as usize].position);
 
-        Geometry::from_primitive(Primitive::Cached { cache: primitive })
+        let (min_x, min_y, max_x, max_y) = positions.fold(
+            (
+                f32::INFINITY,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                f32::NEG_INFINITY,
+            ),
+            |(min_x, min_y, max_x, max_y), [x, y]| {
+                (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+            },
+        );
+
+        for ((region, part), mapping) in regions
+            .iter()
+            .zip(parts.iter_mut())
+            .zip(mappings.iter_mut())
+        {
+            if max_x < region.x
+                || min_x > region.x + region.width
+                || max_y < region.y
+                || min_y > region.y + region.height
+            {
+                continue;
+            }
+
+            for i in triangle {
+                let index = *mapping.entry(*i).or_insert_with(|| {
+                    part.vertices.push(mesh.vertices[*i as usize]);
+
+                    part.vertices.len() as u32 - 1
+                });
+
+                part.indices.push(index);
+            }
+        }
+    }
+
+    parts
+}
+
+fn union(regions: &[Rectangle]) -> Rectangle {
+    let (min_x, min_y, max_x, max_y) = regions.iter().fold(
+        (
+            f32::INFINITY,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NEG_INFINITY,
+        ),
+        |(min_x, min_y, max_x, max_y), region| {
+            (
+                min_x.min(region.x),
+                min_y.min(region.y),
+                max_x.max(region.x + region.width),
+                max_y.max(region.y + region.height),
+            )
+        },
+    );
+
+    Rectangle {
+        x: min_x,
+        y: min_y,
+        width: max_x - min_x,
+        height: max_y - min_y,
     }
 }
 
@@ -98,11 +344,82 @@ impl std::fmt::Debug for State {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             State::Empty => write!(f, "Empty"),
-            State::Filled { primitive, bounds } => f
+            State::Filled { tiles, bounds } => f
                 .debug_struct("Filled")
-                .field("primitive", primitive)
+                .field("tiles", tiles)
                 .field("bounds", bounds)
                 .finish(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::Path;
+    use iced_native::{Color, Point};
+    use std::cell::Cell;
+
+    fn draw(cache: &Cache, calls: &Cell<usize>) -> Vec<Primitive> {
+        let geometry = cache.draw(Size::new(20.0, 20.0), |frame| {
+            calls.set(calls.get() + 1);
+
+            frame.fill(
+                &Path::rectangle(Point::new(0.0, 0.0), Size::new(20.0, 20.0)),
+                Color::BLACK,
+            );
+        });
+
+        match geometry.into_primitive() {
+            Primitive::Group { primitives } => primitives,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn draws_outdated_tiles_at_once() {
+        let mut cache = Cache::tiled(Size::new(10.0, 10.0));
+        let calls = Cell::new(0);
+
+        assert_eq!(draw(&cache, &calls).len(), 4);
+        assert_eq!(calls.get(), 1);
+
+        let _ = draw(&cache, &calls);
+        assert_eq!(calls.get(), 1);
+
+        cache.invalidate(Rectangle::new(
+            Point::new(12.0, 12.0),
+            Size::new(2.0, 2.0),
+        ));
+
+        assert_eq!(draw(&cache, &calls).len(), 4);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn splits_meshes_between_tiles() {
+        let mesh = triangle::Mesh2D {
+            vertices: [[0.0, 0.0], [4.0, 0.0], [0.0, 4.0], [16.0, 16.0]]
+                .iter()
+                .map(|position| triangle::Vertex2D {
+                    position: *position,
+                    color: [0.0, 0.0, 0.0, 1.0],
+                })
+                .collect(),
+            indices: vec![0, 1, 2, 1, 3, 2],
+        };
+
+        let parts = split(
+            &mesh,
+            &[
+                Rectangle::new(Point::new(0.0, 0.0), Size::new(2.0, 2.0)),
+                Rectangle::new(Point::new(14.0, 14.0), Size::new(2.0, 2.0)),
+            ],
+        );
+
+        assert_eq!(parts[0].indices, vec![0, 1, 2, 1, 3, 2]);
+        assert_eq!(parts[0].vertices.len(), 4);
+        assert_eq!(parts[1].indices, vec![0, 1, 2]);
+        assert_eq!(parts[1].vertices.len(), 3);
+    }
+}
@@ -1,4 +1,8 @@
+use iced_native::keyboard;
 use iced_native::mouse;
+use iced_native::stylus;
+use iced_native::touch::{self, Gesture};
+use iced_native::Point;
 
 /// A [`Canvas`] event.
 ///
@@ -6,5 +10,134 @@ use iced_native::mouse;
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Event {
     /// A mouse event.
+    ///
+    /// Every mouse event is forwarded to the [`Program`], including
+    /// `mouse::Event::WheelScrolled`. The scroll delta is reported in lines
+    /// or in pixels depending on the input device, which can be leveraged to
+    /// implement zooming and panning:
+    ///
+    /// ```no_run
+    /// # mod iced {
+    /// #     pub use iced_graphics::canvas;
+    /// #     pub use iced_native::{mouse, Rectangle};
+    /// # }
+    /// use iced::canvas::{Cursor, Event, Geometry, Program};
+    /// use iced::{mouse, Rectangle};
+    ///
+    /// struct Plot {
+    ///     zoom: f32,
+    /// }
+    ///
+    /// impl Program<()> for Plot {
+    ///     fn update(
+    ///         &mut self,
+    ///         event: Event,
+    ///         bounds: Rectangle,
+    ///         cursor: Cursor,
+    ///     ) -> Option<()> {
+    ///         if !cursor.is_over(&bounds) {
+    ///             return None;
+    ///         }
+    ///
+    ///         if let Event::Mouse(mouse::Event::WheelScrolled { delta }) =
+    ///             event
+    ///         {
+    ///             let lines = match delta {
+    ///                 mouse::ScrollDelta::Lines { y, .. } => y,
+    ///                 mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+    ///             };
+    ///
+    ///             self.zoom = (self.zoom * (1.0 + lines / 10.0)).max(0.1);
+    ///         }
+    ///
+    ///         None
+    ///     }
+    ///
+    ///     fn draw(&self, _bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry> {
+    ///         Vec::new()
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Program`]: trait.Program.html
     Mouse(mouse::Event),
+
+    /// A keyboard event.
+    ///
+    /// A [`Canvas`] with a [`State`] can be focused, and its [`Program`] only
+    /// receives keyboard events while focused. It gains the focus when
+    /// clicked or through `Tab`, and loses it when a click happens outside of
+    /// its bounds. The cursor is always [`Cursor::Unavailable`] for these
+    /// events.
+    ///
+    /// A [`Canvas`] without a [`State`] receives every keyboard event, along
+    /// with the current cursor.
+    ///
+    /// [`Program`]: trait.Program.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`State`]: struct.State.html
+    /// [`Cursor::Unavailable`]: enum.Cursor.html#variant.Unavailable
+    Keyboard(keyboard::Event),
+
+    /// A mouse button was pressed while the cursor was over the [`Canvas`].
+    ///
+    /// This event is produced right after the corresponding
+    /// `mouse::Event::ButtonPressed` and bundles the button, the position of
+    /// the cursor relative to the [`Canvas`], and the kind of click.
+    ///
+    /// Double and triple clicks are only detected when the [`Canvas`] is
+    /// given a [`State`]. Otherwise, every click is a single click.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`State`]: struct.State.html
+    Click {
+        /// The button that was pressed
+        button: mouse::Button,
+
+        /// The position of the cursor, relative to the [`Canvas`]
+        ///
+        /// [`Canvas`]: struct.Canvas.html
+        position: Point,
+
+        /// The kind of click
+        kind: mouse::click::Kind,
+
+        /// The modifier keys pressed during the click
+        ///
+        /// They are only tracked when the [`Canvas`] is given a [`State`].
+        /// Otherwise, no modifiers are reported.
+        ///
+        /// [`Canvas`]: struct.Canvas.html
+        /// [`State`]: struct.State.html
+        modifiers: keyboard::ModifiersState,
+    },
+
+    /// A touch event.
+    ///
+    /// Like mouse events, every touch event is forwarded to the [`Program`]
+    /// with positions relative to the window.
+    ///
+    /// [`Program`]: trait.Program.html
+    Touch(touch::Event),
+
+    /// A stylus event.
+    ///
+    /// Like mouse events, every stylus event is forwarded to the [`Program`]
+    /// with positions relative to the window.
+    ///
+    /// [`Program`]: trait.Program.html
+    Stylus(stylus::Event),
+
+    /// A touch [`Gesture`] was recognized.
+    ///
+    /// This event is produced right after the touch event completing the
+    /// [`Gesture`]. Only gestures started over the [`Canvas`] are recognized,
+    /// and their positions are relative to the [`Canvas`].
+    ///
+    /// Gestures are only recognized when the [`Canvas`] is given a [`State`].
+    ///
+    /// [`Gesture`]: ../../../iced_native/touch/enum.Gesture.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`State`]: struct.State.html
+    Gesture(Gesture),
 }
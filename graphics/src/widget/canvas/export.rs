@@ -0,0 +1,1795 @@
+//! Convert primitives into SVG documents and raster images, without the need
+//! of a window or a GPU.
+use crate::triangle::{self, Blend, TexturedVertex2D, Vertex2D};
+use crate::Primitive;
+
+use ab_glyph::{Font as _, FontArc, PxScale, ScaleFont};
+use iced_native::{image, svg};
+use iced_native::{
+    Background, BorderRadius, Borders, Color, Font, Gradient,
+    HorizontalAlignment, Point, Rectangle, Size, Vector, VerticalAlignment,
+};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+pub(crate) fn svg(primitive: &Primitive, size: Size) -> String {
+    let mut document = Svg {
+        content: String::new(),
+        clip_paths: 0,
+        gradients: 0,
+        patterns: 0,
+        opacity: 1.0,
+    };
+
+    document.push(primitive, Vector::new(0.0, 0.0));
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+         xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+         width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">\n{content}</svg>\n",
+        width = size.width,
+        height = size.height,
+        content = document.content
+    )
+}
+
+/// The pixels of a rasterized [`Geometry`].
+///
+/// [`Geometry`]: struct.Geometry.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pixels {
+    /// The width of the image, in pixels.
+    pub width: u32,
+
+    /// The height of the image, in pixels.
+    pub height: u32,
+
+    /// The pixels of the image in RGBA format, row by row.
+    pub rgba: Vec<u8>,
+
+    /// The kinds of content that could not be rasterized and were left out
+    /// of the image.
+    pub dropped: Vec<Dropped>,
+}
+
+impl Pixels {
+    /// Returns whether every primitive was rasterized.
+    pub fn is_complete(&self) -> bool {
+        self.dropped.is_empty()
+    }
+
+    /// Encodes the [`Pixels`] as a PNG image.
+    ///
+    /// [`Pixels`]: struct.Pixels.html
+    #[cfg(feature = "png")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "png")))]
+    pub fn to_png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        let mut bytes = Vec::new();
+
+        {
+            let mut encoder =
+                png::Encoder::new(&mut bytes, self.width, self.height);
+            encoder.set_color(png::ColorType::RGBA);
+            encoder.set_depth(png::BitDepth::Eight);
+
+            encoder.write_header()?.write_image_data(&self.rgba)?;
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// A kind of content that the rasterizer of a [`Geometry`] cannot draw.
+///
+/// [`Geometry`]: struct.Geometry.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dropped {
+    /// Text with a font that could not be loaded.
+    Text,
+
+    /// An SVG image.
+    Svg,
+
+    /// An image that was not decoded into pixels.
+    Image,
+}
+
+/// An error that occurred when rasterizing a [`Geometry`].
+///
+/// [`Geometry`]: struct.Geometry.html
+#[derive(Debug)]
+pub enum ExportError {
+    /// The image is too large to be allocated.
+    TooLarge {
+        /// The requested width, in pixels.
+        width: u32,
+
+        /// The requested height, in pixels.
+        height: u32,
+    },
+
+    /// Some content could not be rasterized.
+    Dropped(Vec<Dropped>),
+
+    /// The PNG encoder failed.
+    #[cfg(feature = "png")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "png")))]
+    Encoding(png::EncodingError),
+}
+
+impl std::fmt::Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::TooLarge { width, height } => {
+                write!(f, "image of {}x{} pixels is too large", width, height)
+            }
+            ExportError::Dropped(dropped) => {
+                write!(f, "content could not be rasterized: {:?}", dropped)
+            }
+            #[cfg(feature = "png")]
+            ExportError::Encoding(error) => {
+                write!(f, "PNG encoding failed: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+pub(crate) fn rgba(
+    primitive: &Primitive,
+    width: u32,
+    height: u32,
+    scale: f32,
+) -> Result<Pixels, ExportError> {
+    let too_large = || ExportError::TooLarge { width, height };
+
+    let length = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|pixels| pixels.checked_mul(4))
+        .ok_or_else(too_large)?;
+
+    let mut rgba = Vec::new();
+    rgba.try_reserve_exact(length).map_err(|_| too_large())?;
+
+    let mut fonts = Fonts::default();
+    let mut dropped = Vec::new();
+
+    // Samples are only kept for a band of rows at a time. Otherwise, they
+    // would need 16 times the memory of the resulting image.
+    for top in (0..height).step_by(Raster::BAND as usize) {
+        let rows = (height - top).min(Raster::BAND);
+
+        let samples = allocate(
+            width as usize * rows as usize * Raster::SAMPLES.len(),
+            [0.0; 4],
+        )
+        .ok_or_else(too_large)?;
+
+        let mut raster = Raster {
+            width,
+            top,
+            rows,
+            scale,
+            samples,
+            blend: Blend::Normal,
+            opacity: 1.0,
+            fonts: &mut fonts,
+            dropped: &mut dropped,
+        };
+
+        raster.push(
+            primitive,
+            Vector::new(0.0, 0.0),
+            Rectangle::new(
+                Point::new(0.0, top as f32 / scale),
+                Size::new(width as f32 / scale, rows as f32 / scale),
+            ),
+        );
+
+        raster.resolve(&mut rgba);
+    }
+
+    Ok(Pixels {
+        width,
+        height,
+        rgba,
+        dropped,
+    })
+}
+
+/// Allocates a buffer filled with the given value, failing gracefully when
+/// there is not enough memory.
+fn allocate<T: Clone>(length: usize, value: T) -> Option<Vec<T>> {
+    let mut buffer = Vec::new();
+    buffer.try_reserve_exact(length).ok()?;
+    buffer.resize(length, value);
+
+    Some(buffer)
+}
+
+struct Svg {
+    content: String,
+    clip_paths: usize,
+    gradients: usize,
+    patterns: usize,
+    opacity: f32,
+}
+
+impl Svg {
+    fn push(&mut self, primitive: &Primitive, translation: Vector) {
+        match primitive {
+            Primitive::None => {}
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    self.push(primitive, translation);
+                }
+            }
+            Primitive::Cached { cache } => {
+                self.push(cache, translation);
+            }
+            Primitive::Composite {
+                blend,
+                opacity,
+                content,
+            } => {
+                let mode = match blend {
+                    Blend::Normal => "normal",
+                    Blend::Multiply => "multiply",
+                    Blend::Screen => "screen",
+                    Blend::Additive => "plus-lighter",
+                };
+
+                let _ = writeln!(
+                    self.content,
+                    "<g style=\"mix-blend-mode:{}\">",
+                    mode
+                );
+
+                // Like in the renderers, the opacity is applied to every
+                // shape individually instead of to the whole group
+                let previous_opacity = self.opacity;
+                self.opacity *= opacity;
+
+                self.push(content, translation);
+
+                self.opacity = previous_opacity;
+                self.content.push_str("</g>\n");
+            }
+            Primitive::Translate {
+                translation: new_translation,
+                content,
+            } => {
+                self.push(content, translation + *new_translation);
+            }
+            Primitive::Clip {
+                bounds,
+                offset,
+                content,
+            } => {
+                let bounds = *bounds + translation;
+                let id = self.clip_paths;
+
+                self.clip_paths += 1;
+
+                let _ = writeln!(
+                    self.content,
+                    "<clipPath id=\"clip{}\"><rect x=\"{}\" y=\"{}\" \
+                     width=\"{}\" height=\"{}\"/></clipPath>\
+                     <g clip-path=\"url(#clip{})\">",
+                    id, bounds.x, bounds.y, bounds.width, bounds.height, id
+                );
+
+                self.push(
+                    content,
+                    translation - Vector::new(offset.x as f32, offset.y as f32),
+                );
+
+                self.content.push_str("</g>\n");
+            }
+            Primitive::Mesh2D { buffers, .. } => {
+                let mut current: Option<[f32; 4]> = None;
+
+                for triangle in buffers.indices.chunks_exact(3) {
+                    let vertices = [
+                        buffers.vertices[triangle[0] as usize],
+                        buffers.vertices[triangle[1] as usize],
+                        buffers.vertices[triangle[2] as usize],
+                    ];
+
+                    // Consecutive triangles with the same color are merged
+                    // in a single path to avoid seams between them.
+                    let color = vertices[0].color;
+
+                    if current != Some(color) {
+                        if current.is_some() {
+                            self.content.push_str("\"/>\n");
+                        }
+
+                        let _ = write!(
+                            self.content,
+                            "<path {} d=\"",
+                            fill(self.fade(from_linear(color)))
+                        );
+
+                        current = Some(color);
+                    }
+
+                    let [a, b, c] = vertices;
+
+                    let _ = write!(
+                        self.content,
+                        "M{} {}L{} {}L{} {}Z",
+                        a.position[0] + translation.x,
+                        a.position[1] + translation.y,
+                        b.position[0] + translation.x,
+                        b.position[1] + translation.y,
+                        c.position[0] + translation.x,
+                        c.position[1] + translation.y,
+                    );
+                }
+
+                if current.is_some() {
+                    self.content.push_str("\"/>\n");
+                }
+            }
+            Primitive::TexturedMesh2D {
+                buffers, texture, ..
+            } => {
+                if let image::Data::Pixels {
+                    width,
+                    height,
+                    pixels,
+                } = texture.data()
+                {
+                    self.textured_mesh(
+                        buffers,
+                        (*width, *height, pixels),
+                        translation,
+                    );
+                }
+            }
+            Primitive::Quad {
+                bounds,
+                background,
+                border_radius,
+                border_width,
+                border_color,
+            } => {
+                self.quad(
+                    *bounds + translation,
+                    background,
+                    BorderRadius::from(*border_radius),
+                    Borders::uniform(*border_width, *border_color),
+                );
+            }
+            Primitive::Card {
+                bounds,
+                background,
+                border_radius,
+                borders,
+                ..
+            } => {
+                self.quad(
+                    *bounds + translation,
+                    background,
+                    *border_radius,
+                    *borders,
+                );
+            }
+            Primitive::Text {
+                content,
+                bounds,
+                color,
+                size,
+                font,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                let bounds = *bounds + translation;
+
+                let anchor = match horizontal_alignment {
+                    HorizontalAlignment::Left => "start",
+                    HorizontalAlignment::Center => "middle",
+                    HorizontalAlignment::Right => "end",
+                };
+
+                let baseline = match vertical_alignment {
+                    VerticalAlignment::Top => "hanging",
+                    VerticalAlignment::Center => "central",
+                    VerticalAlignment::Bottom => "text-after-edge",
+                };
+
+                let family = match font {
+                    Font::Default => "sans-serif",
+                    Font::External { name, .. } | Font::Family(name) => name,
+                };
+
+                let _ = writeln!(
+                    self.content,
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\" \
+                     font-family=\"{}\" text-anchor=\"{}\" \
+                     dominant-baseline=\"{}\" {}>{}</text>",
+                    bounds.x,
+                    bounds.y,
+                    size,
+                    escape(family),
+                    anchor,
+                    baseline,
+                    fill(self.fade(*color)),
+                    escape(content)
+                );
+            }
+            Primitive::Image { handle, bounds, .. } => {
+                if let image::Data::Path(path) = handle.data() {
+                    let bounds = *bounds + translation;
+
+                    let _ = writeln!(
+                        self.content,
+                        "<image x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+                         preserveAspectRatio=\"none\" xlink:href=\"{}\"/>",
+                        bounds.x,
+                        bounds.y,
+                        bounds.width,
+                        bounds.height,
+                        escape(&path.to_string_lossy())
+                    );
+                }
+            }
+            Primitive::Svg { handle, bounds, .. } => {
+                if let svg::Data::Path(path) = handle.data() {
+                    let bounds = *bounds + translation;
+
+                    let _ = writeln!(
+                        self.content,
+                        "<image x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+                         preserveAspectRatio=\"none\" xlink:href=\"{}\"/>",
+                        bounds.x,
+                        bounds.y,
+                        bounds.width,
+                        bounds.height,
+                        escape(&path.to_string_lossy())
+                    );
+                }
+            }
+        }
+    }
+
+    /// Applies the current opacity to the given color.
+    fn fade(&self, color: Color) -> Color {
+        Color {
+            a: color.a * self.opacity,
+            ..color
+        }
+    }
+
+    fn textured_mesh(
+        &mut self,
+        buffers: &triangle::TexturedMesh2D,
+        (width, height, pixels): (u32, u32, &[u8]),
+        translation: Vector,
+    ) {
+        // The texture coordinates of the whole mesh are an affine transform
+        // of its positions, which can be recovered from any triangle
+        let transform = buffers.indices.chunks_exact(3).find_map(|triangle| {
+            pattern_transform([
+                buffers.vertices[triangle[0] as usize],
+                buffers.vertices[triangle[1] as usize],
+                buffers.vertices[triangle[2] as usize],
+            ])
+        });
+
+        let [a, b, c, d, e, f] = match transform {
+            Some(transform) => transform,
+            None => return,
+        };
+
+        let id = self.patterns;
+
+        self.patterns += 1;
+
+        let _ = writeln!(
+            self.content,
+            "<pattern id=\"pattern{}\" patternUnits=\"userSpaceOnUse\" \
+             width=\"1\" height=\"1\" \
+             patternTransform=\"matrix({} {} {} {} {} {})\">",
+            id,
+            a,
+            b,
+            c,
+            d,
+            e + translation.x,
+            f + translation.y,
+        );
+
+        // Contiguous pixels with the same color become a single rectangle
+        for y in 0..height {
+            let mut x = 0;
+
+            while x < width {
+                let start = x;
+                let color = texel(pixels, width, x, y);
+
+                while x < width && texel(pixels, width, x, y) == color {
+                    x += 1;
+                }
+
+                if color.a > 0.0 {
+                    let _ = writeln!(
+                        self.content,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" \
+                         height=\"{}\" {}/>",
+                        start as f32 / width as f32,
+                        y as f32 / height as f32,
+                        (x - start) as f32 / width as f32,
+                        1.0 / height as f32,
+                        fill(color),
+                    );
+                }
+            }
+        }
+
+        let _ = write!(
+            self.content,
+            "</pattern>\n<path fill=\"url(#pattern{})\" d=\"",
+            id
+        );
+
+        for triangle in buffers.indices.chunks_exact(3) {
+            let _ = write!(self.content, "M");
+
+            for (i, index) in triangle.iter().enumerate() {
+                let vertex = buffers.vertices[*index as usize];
+
+                let _ = write!(
+                    self.content,
+                    "{}{} {}",
+                    if i == 0 { "" } else { "L" },
+                    vertex.position[0] + translation.x,
+                    vertex.position[1] + translation.y,
+                );
+            }
+
+            self.content.push('Z');
+        }
+
+        self.content.push_str("\"/>\n");
+    }
+
+    fn quad(
+        &mut self,
+        bounds: Rectangle,
+        background: &Background,
+        border_radius: BorderRadius,
+        borders: Borders,
+    ) {
+        let background = match background {
+            Background::Color(color) => Background::Color(self.fade(*color)),
+            Background::Gradient(gradient) => Background::Gradient(Gradient {
+                start: self.fade(gradient.start),
+                end: self.fade(gradient.end),
+                ..*gradient
+            }),
+        };
+
+        let mut borders = borders;
+
+        for side in [
+            &mut borders.top,
+            &mut borders.right,
+            &mut borders.bottom,
+            &mut borders.left,
+        ]
+        .iter_mut()
+        {
+            side.color = self.fade(side.color);
+        }
+
+        let paint = match &background {
+            Background::Color(color) => fill(*color),
+            Background::Gradient(gradient) => {
+                let id = self.gradients;
+                let (start, end) = gradient_line(bounds, gradient);
+
+                self.gradients += 1;
+
+                let _ = writeln!(
+                    self.content,
+                    "<linearGradient id=\"gradient{}\" \
+                     gradientUnits=\"userSpaceOnUse\" \
+                     x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\">\
+                     <stop offset=\"0\" stop-color=\"{}\" \
+                     stop-opacity=\"{}\"/>\
+                     <stop offset=\"1\" stop-color=\"{}\" \
+                     stop-opacity=\"{}\"/></linearGradient>",
+                    id,
+                    start.x,
+                    start.y,
+                    end.x,
+                    end.y,
+                    hex(gradient.start),
+                    gradient.start.a,
+                    hex(gradient.end),
+                    gradient.end.a,
+                );
+
+                format!("fill=\"url(#gradient{})\"", id)
+            }
+        };
+
+        if border_radius == BorderRadius::from(border_radius.top_left) {
+            let _ = write!(
+                self.content,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+                 rx=\"{}\" {}",
+                bounds.x,
+                bounds.y,
+                bounds.width,
+                bounds.height,
+                border_radius.top_left,
+                paint,
+            );
+        } else {
+            let limit = (bounds.width / 2.0).min(bounds.height / 2.0);
+            let radius = |radius: u16| f32::from(radius).min(limit);
+
+            let top_left = radius(border_radius.top_left);
+            let top_right = radius(border_radius.top_right);
+            let bottom_right = radius(border_radius.bottom_right);
+            let bottom_left = radius(border_radius.bottom_left);
+
+            let (left, top) = (bounds.x, bounds.y);
+            let (right, bottom) =
+                (bounds.x + bounds.width, bounds.y + bounds.height);
+
+            let _ = write!(
+                self.content,
+                "<path d=\"M{},{} H{} A{r1},{r1} 0 0 1 {},{} V{} \
+                 A{r2},{r2} 0 0 1 {},{} H{} A{r3},{r3} 0 0 1 {},{} V{} \
+                 A{r0},{r0} 0 0 1 {},{} Z\" {}",
+                left + top_left,
+                top,
+                right - top_right,
+                right,
+                top + top_right,
+                bottom - bottom_right,
+                right - bottom_right,
+                bottom,
+                left + bottom_left,
+                left,
+                bottom - bottom_left,
+                top + top_left,
+                left + top_left,
+                top,
+                paint,
+                r0 = top_left,
+                r1 = top_right,
+                r2 = bottom_right,
+                r3 = bottom_left,
+            );
+        }
+
+        if borders == Borders::from(borders.top) {
+            if borders.top.width > 0 {
+                let _ = write!(
+                    self.content,
+                    " stroke=\"{}\" stroke-opacity=\"{}\" \
+                     stroke-width=\"{}\"",
+                    hex(borders.top.color),
+                    borders.top.color.a,
+                    borders.top.width
+                );
+            }
+
+            self.content.push_str("/>\n");
+        } else {
+            self.content.push_str("/>\n");
+
+            let (left, top) = (bounds.x, bounds.y);
+            let (right, bottom) =
+                (bounds.x + bounds.width, bounds.y + bounds.height);
+
+            for (side, (x1, y1, x2, y2)) in [
+                (borders.top, (left, top, right, top)),
+                (borders.right, (right, top, right, bottom)),
+                (borders.bottom, (left, bottom, right, bottom)),
+                (borders.left, (left, top, left, bottom)),
+            ]
+            .iter()
+            {
+                if side.width > 0 {
+                    let _ = writeln!(
+                        self.content,
+                        "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" \
+                         stroke=\"{}\" stroke-opacity=\"{}\" \
+                         stroke-width=\"{}\"/>",
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        hex(side.color),
+                        side.color.a,
+                        side.width
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// A band of rows of an image being rasterized.
+struct Raster<'a> {
+    width: u32,
+    top: u32,
+    rows: u32,
+    // The amount of pixels per unit of the primitives
+    scale: f32,
+    // Premultiplied colors in linear RGBA, per sample
+    samples: Vec<[f32; 4]>,
+    blend: Blend,
+    opacity: f32,
+    fonts: &'a mut Fonts,
+    dropped: &'a mut Vec<Dropped>,
+}
+
+impl<'a> Raster<'a> {
+    const BAND: u32 = 16;
+
+    const SAMPLES: [(f32, f32); 4] =
+        [(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)];
+
+    fn push(
+        &mut self,
+        primitive: &Primitive,
+        translation: Vector,
+        clip: Rectangle,
+    ) {
+        match primitive {
+            Primitive::None => {}
+            Primitive::Text {
+                content,
+                bounds,
+                color,
+                size,
+                font,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                if content.is_empty() {
+                    return;
+                }
+
+                match self.fonts.get(*font) {
+                    Some(font) => self.text(
+                        &font,
+                        content,
+                        *bounds + translation,
+                        *color,
+                        *size,
+                        *horizontal_alignment,
+                        *vertical_alignment,
+                        clip,
+                    ),
+                    None => self.drop(Dropped::Text),
+                }
+            }
+            Primitive::Svg { .. } => {
+                self.drop(Dropped::Svg);
+            }
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    self.push(primitive, translation, clip);
+                }
+            }
+            Primitive::Cached { cache } => {
+                self.push(cache, translation, clip);
+            }
+            Primitive::Composite {
+                blend,
+                opacity,
+                content,
+            } => {
+                let (previous_blend, previous_opacity) =
+                    (self.blend, self.opacity);
+
+                self.blend = *blend;
+                self.opacity *= opacity;
+
+                self.push(content, translation, clip);
+
+                self.blend = previous_blend;
+                self.opacity = previous_opacity;
+            }
+            Primitive::Translate {
+                translation: new_translation,
+                content,
+            } => {
+                self.push(content, translation + *new_translation, clip);
+            }
+            Primitive::Clip {
+                bounds,
+                offset,
+                content,
+            } => {
+                if let Some(clip) = clip.intersection(&(*bounds + translation))
+                {
+                    self.push(
+                        content,
+                        translation
+                            - Vector::new(offset.x as f32, offset.y as f32),
+                        clip,
+                    );
+                }
+            }
+            Primitive::Mesh2D { buffers, size } => {
+                let bounds = Rectangle::new(
+                    Point::new(translation.x, translation.y),
+                    *size,
+                );
+
+                if let Some(clip) = clip.intersection(&bounds) {
+                    for triangle in buffers.indices.chunks_exact(3) {
+                        let vertices = [
+                            buffers.vertices[triangle[0] as usize],
+                            buffers.vertices[triangle[1] as usize],
+                            buffers.vertices[triangle[2] as usize],
+                        ];
+
+                        let [color_a, color_b, color_c] = [
+                            vertices[0].color,
+                            vertices[1].color,
+                            vertices[2].color,
+                        ];
+
+                        let is_flat = color_a == color_b && color_b == color_c;
+
+                        self.fill_triangle(
+                            positions(vertices, translation),
+                            clip,
+                            self.blend,
+                            |[weight_a, weight_b, weight_c]| {
+                                if is_flat {
+                                    return Some(color_a);
+                                }
+
+                                let mut color = [0.0; 4];
+
+                                for (i, channel) in color.iter_mut().enumerate()
+                                {
+                                    *channel = color_a[i] * weight_a
+                                        + color_b[i] * weight_b
+                                        + color_c[i] * weight_c;
+                                }
+
+                                Some(color)
+                            },
+                        );
+                    }
+                }
+            }
+            Primitive::TexturedMesh2D {
+                buffers,
+                texture,
+                size,
+            } => {
+                let (width, height, pixels) = match texture.data() {
+                    image::Data::Pixels {
+                        width,
+                        height,
+                        pixels,
+                    } => (*width, *height, pixels),
+                    _ => {
+                        self.drop(Dropped::Image);
+                        return;
+                    }
+                };
+
+                if width == 0 || height == 0 {
+                    return;
+                }
+
+                let bounds = Rectangle::new(
+                    Point::new(translation.x, translation.y),
+                    *size,
+                );
+
+                // Like images, textures are not affected by the blend mode or
+                // the opacity
+                let opacity = std::mem::replace(&mut self.opacity, 1.0);
+
+                if let Some(clip) = clip.intersection(&bounds) {
+                    for triangle in buffers.indices.chunks_exact(3) {
+                        let vertices = [
+                            buffers.vertices[triangle[0] as usize],
+                            buffers.vertices[triangle[1] as usize],
+                            buffers.vertices[triangle[2] as usize],
+                        ];
+
+                        let position = |vertex: TexturedVertex2D| {
+                            (
+                                vertex.position[0] + translation.x,
+                                vertex.position[1] + translation.y,
+                            )
+                        };
+
+                        self.fill_triangle(
+                            [
+                                position(vertices[0]),
+                                position(vertices[1]),
+                                position(vertices[2]),
+                            ],
+                            clip,
+                            Blend::Normal,
+                            |weights| {
+                                let mut uv = [0.0; 2];
+
+                                for (vertex, weight) in
+                                    vertices.iter().zip(weights.iter())
+                                {
+                                    uv[0] += vertex.uv[0] * weight;
+                                    uv[1] += vertex.uv[1] * weight;
+                                }
+
+                                // Tiles repeat in both directions
+                                let repeat = |t: f32, length: u32| {
+                                    ((t * length as f32)
+                                        .floor()
+                                        .rem_euclid(length as f32)
+                                        as u32)
+                                        .min(length - 1)
+                                };
+
+                                Some(
+                                    texel(
+                                        pixels,
+                                        width,
+                                        repeat(uv[0], width),
+                                        repeat(uv[1], height),
+                                    )
+                                    .into_linear(),
+                                )
+                            },
+                        );
+                    }
+                }
+
+                self.opacity = opacity;
+            }
+            Primitive::Quad {
+                bounds,
+                background,
+                border_radius,
+                border_width,
+                border_color,
+            } => {
+                self.quad(
+                    *bounds + translation,
+                    clip,
+                    background,
+                    BorderRadius::from(*border_radius),
+                    Borders::uniform(*border_width, *border_color),
+                );
+            }
+            Primitive::Card {
+                bounds,
+                background,
+                border_radius,
+                borders,
+                ..
+            } => {
+                self.quad(
+                    *bounds + translation,
+                    clip,
+                    background,
+                    *border_radius,
+                    *borders,
+                );
+            }
+            Primitive::Image { handle, bounds, .. } => {
+                if let image::Data::Pixels {
+                    width,
+                    height,
+                    pixels,
+                } = handle.data()
+                {
+                    let bounds = *bounds + translation;
+                    let (width, height) = (*width, *height);
+
+                    if width == 0 || height == 0 {
+                        return;
+                    }
+
+                    // Like in the renderers, images are not affected by the
+                    // blend mode or the opacity
+                    let opacity = std::mem::replace(&mut self.opacity, 1.0);
+
+                    self.fill(bounds, clip, Blend::Normal, |x, y| {
+                        let u = ((x - bounds.x) / bounds.width * width as f32)
+                            as u32;
+                        let v = ((y - bounds.y) / bounds.height * height as f32)
+                            as u32;
+
+                        Some(
+                            texel(
+                                pixels,
+                                width,
+                                u.min(width - 1),
+                                v.min(height - 1),
+                            )
+                            .into_linear(),
+                        )
+                    });
+
+                    self.opacity = opacity;
+                } else {
+                    self.drop(Dropped::Image);
+                }
+            }
+        }
+    }
+
+    fn drop(&mut self, content: Dropped) {
+        if !self.dropped.contains(&content) {
+            self.dropped.push(content);
+        }
+    }
+
+    /// Fills a triangle with the colors produced by the given closure out of
+    /// the barycentric weights of its vertices.
+    fn fill_triangle(
+        &mut self,
+        [a, b, c]: [(f32, f32); 3],
+        clip: Rectangle,
+        blend: Blend,
+        shade: impl Fn([f32; 3]) -> Option<[f32; 4]>,
+    ) {
+        let area = edge(a, b, c);
+
+        if area == 0.0 {
+            return;
+        }
+
+        // Make every triangle counter-clockwise
+        let is_flipped = area < 0.0;
+        let (b, c) = if is_flipped { (c, b) } else { (b, c) };
+        let area = area.abs();
+
+        let bounds = Rectangle {
+            x: a.0.min(b.0).min(c.0),
+            y: a.1.min(b.1).min(c.1),
+            width: a.0.max(b.0).max(c.0) - a.0.min(b.0).min(c.0),
+            height: a.1.max(b.1).max(c.1) - a.1.min(b.1).min(c.1),
+        };
+
+        self.fill(bounds, clip, blend, |x, y| {
+            let p = (x, y);
+
+            if !(is_inside(a, b, p) && is_inside(b, c, p) && is_inside(c, a, p))
+            {
+                return None;
+            }
+
+            let weight_a = edge(b, c, p) / area;
+            let weight_b = edge(c, a, p) / area;
+            let weight_c = 1.0 - weight_a - weight_b;
+
+            if is_flipped {
+                shade([weight_a, weight_c, weight_b])
+            } else {
+                shade([weight_a, weight_b, weight_c])
+            }
+        });
+    }
+
+    /// Blends the samples produced by the given closure inside of the
+    /// provided bounds.
+    ///
+    /// The bounds, the clip region, and the positions of the samples are in
+    /// the units of the primitives.
+    fn fill(
+        &mut self,
+        bounds: Rectangle,
+        clip: Rectangle,
+        blend: Blend,
+        sample: impl Fn(f32, f32) -> Option<[f32; 4]>,
+    ) {
+        let scale = self.scale;
+
+        let min_x = (bounds.x.max(clip.x) * scale).max(0.0).floor() as u32;
+        let min_y =
+            (bounds.y.max(clip.y) * scale).max(self.top as f32).floor() as u32;
+        let max_x = ((bounds.x + bounds.width).min(clip.x + clip.width) * scale)
+            .min(self.width as f32)
+            .ceil() as u32;
+        let max_y = ((bounds.y + bounds.height).min(clip.y + clip.height)
+            * scale)
+            .min((self.top + self.rows) as f32)
+            .ceil() as u32;
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let pixel = ((y - self.top) as usize * self.width as usize
+                    + x as usize)
+                    * Self::SAMPLES.len();
+
+                for (i, (offset_x, offset_y)) in
+                    Self::SAMPLES.iter().enumerate()
+                {
+                    let sample_x = (x as f32 + offset_x) / scale;
+                    let sample_y = (y as f32 + offset_y) / scale;
+
+                    if !clip.contains(Point::new(sample_x, sample_y)) {
+                        continue;
+                    }
+
+                    if let Some([r, g, b, a]) = sample(sample_x, sample_y) {
+                        let a = a * self.opacity;
+                        let source = [r * a, g * a, b * a];
+                        let target = &mut self.samples[pixel + i];
+
+                        for c in 0..3 {
+                            target[c] = match blend {
+                                Blend::Normal => {
+                                    source[c] + target[c] * (1.0 - a)
+                                }
+                                Blend::Multiply => {
+                                    source[c] * target[c]
+                                        + target[c] * (1.0 - a)
+                                }
+                                Blend::Screen => {
+                                    source[c] + target[c] * (1.0 - source[c])
+                                }
+                                Blend::Additive => source[c] + target[c],
+                            };
+                        }
+
+                        target[3] = a + target[3] * (1.0 - a);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resolves the samples of the band and appends the resulting pixels to
+    /// the given RGBA buffer.
+    fn resolve(self, rgba: &mut Vec<u8>) {
+        for samples in self.samples.chunks_exact(Self::SAMPLES.len()) {
+            let mut color = [0.0; 4];
+
+            for sample in samples {
+                for i in 0..4 {
+                    color[i] += sample[i] / samples.len() as f32;
+                }
+            }
+
+            let [r, g, b, a] = color;
+
+            let color = if a > 0.0 {
+                from_linear([r / a, g / a, b / a, a])
+            } else {
+                Color::TRANSPARENT
+            };
+
+            rgba.extend_from_slice(&[
+                (color.r * 255.0).round() as u8,
+                (color.g * 255.0).round() as u8,
+                (color.b * 255.0).round() as u8,
+                (color.a * 255.0).round() as u8,
+            ]);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn text(
+        &mut self,
+        font: &FontArc,
+        content: &str,
+        bounds: Rectangle,
+        color: Color,
+        size: f32,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        clip: Rectangle,
+    ) {
+        let font = font.as_scaled(PxScale::from(size));
+        let color = color.into_linear();
+
+        let line_height = font.height() + font.line_gap();
+        let lines = content.lines().count() as f32;
+
+        let top = match vertical_alignment {
+            VerticalAlignment::Top => bounds.y,
+            VerticalAlignment::Center => bounds.y - line_height * lines / 2.0,
+            VerticalAlignment::Bottom => bounds.y - line_height * lines,
+        };
+
+        for (i, line) in content.lines().enumerate() {
+            let line_top = top + line_height * i as f32;
+
+            if line_top > clip.y + clip.height
+                || line_top + line_height < clip.y
+            {
+                continue;
+            }
+
+            let glyphs: Vec<_> =
+                line.chars().map(|c| font.glyph_id(c)).collect();
+
+            let width: f32 = glyphs
+                .iter()
+                .zip(glyphs.iter().skip(1))
+                .map(|(a, b)| font.h_advance(*a) + font.kern(*a, *b))
+                .sum::<f32>()
+                + glyphs.last().map(|id| font.h_advance(*id)).unwrap_or(0.0);
+
+            let mut caret = match horizontal_alignment {
+                HorizontalAlignment::Left => bounds.x,
+                HorizontalAlignment::Center => bounds.x - width / 2.0,
+                HorizontalAlignment::Right => bounds.x - width,
+            };
+
+            let baseline = line_top + font.ascent();
+            let mut previous = None;
+
+            for id in glyphs {
+                if let Some(previous) = previous {
+                    caret += font.kern(previous, id);
+                }
+
+                // Glyphs are outlined directly in pixels
+                let glyph = id.with_scale_and_position(
+                    PxScale::from(size * self.scale),
+                    ab_glyph::point(caret * self.scale, baseline * self.scale),
+                );
+
+                caret += font.h_advance(id);
+                previous = Some(id);
+
+                if let Some(outline) = font.outline_glyph(glyph) {
+                    self.glyph(&outline, color, clip);
+                }
+            }
+        }
+    }
+
+    fn glyph(
+        &mut self,
+        outline: &ab_glyph::OutlinedGlyph,
+        [r, g, b, a]: [f32; 4],
+        clip: Rectangle,
+    ) {
+        let area = outline.px_bounds();
+        let scale = self.scale;
+
+        let bounds = Rectangle {
+            x: area.min.x / scale,
+            y: area.min.y / scale,
+            width: area.width() / scale,
+            height: area.height() / scale,
+        };
+
+        if clip.intersection(&bounds).is_none() {
+            return;
+        }
+
+        let width = area.width() as usize;
+        let mut coverage = vec![0.0; width * area.height() as usize];
+
+        outline.draw(|x, y, value| {
+            coverage[y as usize * width + x as usize] = value;
+        });
+
+        // Glyphs are drawn with their coverage, which is already
+        // antialiased, so every sample of a pixel gets the same value
+        self.fill(bounds, clip, self.blend, |x, y| {
+            let column = ((x - bounds.x) * scale) as usize;
+            let row = ((y - bounds.y) * scale) as usize;
+
+            coverage
+                .get(row * width + column)
+                .filter(|value| **value > 0.0)
+                .map(|value| [r, g, b, a * value.min(1.0)])
+        });
+    }
+
+    fn quad(
+        &mut self,
+        bounds: Rectangle,
+        clip: Rectangle,
+        background: &Background,
+        border_radius: BorderRadius,
+        borders: Borders,
+    ) {
+        let [top, right, bottom, left] = [
+            borders.top.width,
+            borders.right.width,
+            borders.bottom.width,
+            borders.left.width,
+        ];
+
+        let inner_bounds = Rectangle {
+            x: bounds.x + f32::from(left),
+            y: bounds.y + f32::from(top),
+            width: (bounds.width - f32::from(left) - f32::from(right)).max(0.0),
+            height: (bounds.height - f32::from(top) - f32::from(bottom))
+                .max(0.0),
+        };
+
+        let inner_radius = BorderRadius {
+            top_left: border_radius.top_left.saturating_sub(top.max(left)),
+            top_right: border_radius.top_right.saturating_sub(top.max(right)),
+            bottom_right: border_radius
+                .bottom_right
+                .saturating_sub(bottom.max(right)),
+            bottom_left: border_radius
+                .bottom_left
+                .saturating_sub(bottom.max(left)),
+        };
+
+        // Corners are split between sides along the diagonal joining their
+        // outer and inner corners
+        let border_color = |x: f32, y: f32| {
+            let sides = [
+                (borders.top, y - bounds.y),
+                (borders.right, bounds.x + bounds.width - x),
+                (borders.bottom, bounds.y + bounds.height - y),
+                (borders.left, x - bounds.x),
+            ];
+
+            sides
+                .iter()
+                .filter(|(side, _)| side.width > 0)
+                .map(|(side, distance)| {
+                    (side.color, distance / f32::from(side.width))
+                })
+                .min_by(|(_, a), (_, b)| {
+                    a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(color, _)| color.into_linear())
+        };
+
+        let (start, end, line) = match background {
+            Background::Color(color) => {
+                let color = color.into_linear();
+
+                (color, color, None)
+            }
+            Background::Gradient(gradient) => (
+                gradient.start.into_linear(),
+                gradient.end.into_linear(),
+                Some(gradient_line(bounds, gradient)),
+            ),
+        };
+
+        self.fill(bounds, clip, Blend::Normal, |x, y| {
+            let distance = rounded_distance(bounds, border_radius, x, y);
+
+            if distance > 0.0 {
+                None
+            } else if borders.is_visible()
+                && rounded_distance(inner_bounds, inner_radius, x, y) > 0.0
+            {
+                border_color(x, y)
+            } else if let Some((a, b)) = line {
+                let (dx, dy) = (b.x - a.x, b.y - a.y);
+                let length = dx * dx + dy * dy;
+
+                // Every point of the bounds projects inside the gradient line
+                let t = if length > 0.0 {
+                    ((x - a.x) * dx + (y - a.y) * dy) / length
+                } else {
+                    0.0
+                };
+
+                let mut color = [0.0; 4];
+
+                for (i, channel) in color.iter_mut().enumerate() {
+                    *channel = start[i] + (end[i] - start[i]) * t;
+                }
+
+                Some(color)
+            } else {
+                Some(start)
+            }
+        });
+    }
+}
+
+/// The fonts used to rasterize text, loaded once per image.
+#[derive(Default)]
+struct Fonts {
+    loaded: HashMap<Font, Option<FontArc>>,
+}
+
+impl Fonts {
+    /// Returns the font used to rasterize the given [`Font`].
+    ///
+    /// [`Font::Default`] is the built-in fallback font, since the default
+    /// font of a renderer is not known here. Families are looked up in the
+    /// system fonts and fall back to the built-in font, too.
+    ///
+    /// [`Font`]: ../../../iced_native/enum.Font.html
+    /// [`Font::Default`]: ../../../iced_native/enum.Font.html#variant.Default
+    fn get(&mut self, font: Font) -> Option<FontArc> {
+        if let Some(loaded) = self.loaded.get(&font) {
+            return loaded.clone();
+        }
+
+        let loaded = match font {
+            Font::Default => Self::fallback(),
+            Font::External { bytes, .. } => FontArc::try_from_slice(bytes).ok(),
+            Font::Family(name) => {
+                Self::system(name).or_else(|| self.get(Font::Default))
+            }
+        };
+
+        let _ = self.loaded.insert(font, loaded.clone());
+
+        loaded
+    }
+
+    #[cfg(feature = "font-fallback")]
+    fn fallback() -> Option<FontArc> {
+        FontArc::try_from_slice(crate::font::FALLBACK).ok()
+    }
+
+    #[cfg(not(feature = "font-fallback"))]
+    fn fallback() -> Option<FontArc> {
+        None
+    }
+
+    #[cfg(feature = "font-source")]
+    fn system(name: &str) -> Option<FontArc> {
+        let bytes = crate::font::Source::new()
+            .load(&[crate::font::Family::Title(name.to_owned())])
+            .ok()?;
+
+        FontArc::try_from_vec(bytes).ok()
+    }
+
+    #[cfg(not(feature = "font-source"))]
+    fn system(_name: &str) -> Option<FontArc> {
+        None
+    }
+}
+
+fn positions(vertices: [Vertex2D; 3], translation: Vector) -> [(f32, f32); 3] {
+    let position = |vertex: Vertex2D| {
+        (
+            vertex.position[0] + translation.x,
+            vertex.position[1] + translation.y,
+        )
+    };
+
+    [
+        position(vertices[0]),
+        position(vertices[1]),
+        position(vertices[2]),
+    ]
+}
+
+/// Returns the color of a pixel of an image stored in BGRA.
+fn texel(pixels: &[u8], width: u32, x: u32, y: u32) -> Color {
+    let i = (y as usize * width as usize + x as usize) * 4;
+
+    Color::from_rgba8(
+        pixels[i + 2],
+        pixels[i + 1],
+        pixels[i],
+        pixels[i + 3] as f32 / 255.0,
+    )
+}
+
+/// Computes the affine transform, as in the `matrix` of an SVG transform,
+/// mapping the texture coordinates of a triangle to its positions.
+fn pattern_transform(vertices: [TexturedVertex2D; 3]) -> Option<[f32; 6]> {
+    let [a, b, c] = vertices;
+
+    let (du1, dv1) = (b.uv[0] - a.uv[0], b.uv[1] - a.uv[1]);
+    let (du2, dv2) = (c.uv[0] - a.uv[0], c.uv[1] - a.uv[1]);
+    let (dx1, dy1) =
+        (b.position[0] - a.position[0], b.position[1] - a.position[1]);
+    let (dx2, dy2) =
+        (c.position[0] - a.position[0], c.position[1] - a.position[1]);
+
+    let determinant = du1 * dv2 - du2 * dv1;
+
+    if determinant == 0.0 {
+        return None;
+    }
+
+    let m00 = (dx1 * dv2 - dx2 * dv1) / determinant;
+    let m01 = (dx2 * du1 - dx1 * du2) / determinant;
+    let m10 = (dy1 * dv2 - dy2 * dv1) / determinant;
+    let m11 = (dy2 * du1 - dy1 * du2) / determinant;
+
+    Some([
+        m00,
+        m10,
+        m01,
+        m11,
+        a.position[0] - m00 * a.uv[0] - m01 * a.uv[1],
+        a.position[1] - m10 * a.uv[0] - m11 * a.uv[1],
+    ])
+}
+
+/// Determines whether a point lies on the inner side of an edge of a
+/// counter-clockwise triangle.
+///
+/// Points exactly on the edge are only considered inside for one of its two
+/// possible directions, so samples on the edge shared by two triangles are
+/// never drawn twice.
+fn is_inside(a: (f32, f32), b: (f32, f32), p: (f32, f32)) -> bool {
+    let edge = edge(a, b, p);
+
+    edge > 0.0 || (edge == 0.0 && (b.1 > a.1 || (b.1 == a.1 && b.0 < a.0)))
+}
+
+fn edge(a: (f32, f32), b: (f32, f32), p: (f32, f32)) -> f32 {
+    (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+}
+
+fn rounded_distance(
+    bounds: Rectangle,
+    border_radius: BorderRadius,
+    x: f32,
+    y: f32,
+) -> f32 {
+    let radius = match (x < bounds.center_x(), y < bounds.center_y()) {
+        (true, true) => border_radius.top_left,
+        (false, true) => border_radius.top_right,
+        (false, false) => border_radius.bottom_right,
+        (true, false) => border_radius.bottom_left,
+    };
+
+    let radius = f32::from(radius)
+        .min(bounds.width / 2.0)
+        .min(bounds.height / 2.0);
+
+    let dx = (x - bounds.center_x()).abs() - (bounds.width / 2.0 - radius);
+    let dy = (y - bounds.center_y()).abs() - (bounds.height / 2.0 - radius);
+
+    let outside = (dx.max(0.0).powi(2) + dy.max(0.0).powi(2)).sqrt();
+    let inside = dx.max(dy).min(0.0);
+
+    outside + inside - radius
+}
+
+/// Returns the start and end points of the given gradient, spanning the
+/// provided bounds.
+fn gradient_line(bounds: Rectangle, gradient: &Gradient) -> (Point, Point) {
+    let (sin, cos) = gradient.angle.sin_cos();
+    let extent =
+        (bounds.width / 2.0 * cos).abs() + (bounds.height / 2.0 * sin).abs();
+
+    (
+        Point::new(
+            bounds.center_x() - cos * extent,
+            bounds.center_y() - sin * extent,
+        ),
+        Point::new(
+            bounds.center_x() + cos * extent,
+            bounds.center_y() + sin * extent,
+        ),
+    )
+}
+
+fn from_linear([r, g, b, a]: [f32; 4]) -> Color {
+    fn srgb_component(u: f32) -> f32 {
+        if u <= 0.0031308 {
+            u * 12.92
+        } else {
+            1.055 * u.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    Color::new(
+        srgb_component(r).min(1.0),
+        srgb_component(g).min(1.0),
+        srgb_component(b).min(1.0),
+        a.min(1.0),
+    )
+}
+
+fn hex(color: Color) -> String {
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        (color.r * 255.0).round() as u8,
+        (color.g * 255.0).round() as u8,
+        (color.b * 255.0).round() as u8
+    )
+}
+
+fn fill(color: Color) -> String {
+    format!("fill=\"{}\" fill-opacity=\"{}\"", hex(color), color.a)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dropped, ExportError};
+    use crate::canvas::{Frame, Geometry, Path};
+    use crate::Primitive;
+    use iced_native::{
+        Background, BorderRadius, BorderSide, Borders, Color, Gradient, Point,
+        Rectangle, Size,
+    };
+
+    fn square() -> crate::canvas::Geometry {
+        let mut frame = Frame::new(Size::new(4.0, 4.0));
+
+        frame.fill(
+            &Path::rectangle(Point::new(1.0, 1.0), Size::new(2.0, 2.0)),
+            Color::from_rgb(1.0, 0.0, 0.0),
+        );
+
+        frame.into_geometry()
+    }
+
+    #[test]
+    fn rasterizes_filled_rectangle() {
+        let pixels = square().to_rgba(4, 4).expect("Rasterize geometry").rgba;
+
+        let pixel = |x: usize, y: usize| {
+            let i = (y * 4 + x) * 4;
+            [pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]]
+        };
+
+        assert_eq!(pixels.len(), 4 * 4 * 4);
+        assert_eq!(pixel(0, 0), [0, 0, 0, 0]);
+        assert_eq!(pixel(1, 1), [255, 0, 0, 255]);
+        assert_eq!(pixel(2, 2), [255, 0, 0, 255]);
+        assert_eq!(pixel(3, 3), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn exports_filled_rectangle_as_svg() {
+        let svg = square().to_svg(Size::new(4.0, 4.0));
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("viewBox=\"0 0 4 4\""));
+        assert!(svg.contains("<path fill=\"#ff0000\" fill-opacity=\"1\""));
+    }
+
+    #[test]
+    fn exports_shape_opacity_as_svg() {
+        let svg = square().with_shape_opacity(0.5).to_svg(Size::new(4.0, 4.0));
+
+        assert!(!svg.contains("<g opacity"));
+        assert!(svg.contains("<path fill=\"#ff0000\" fill-opacity=\"0.5\""));
+    }
+
+    #[test]
+    fn rasterizes_card_with_gradient_and_corner_radius() {
+        let card = Geometry::from_primitive(Primitive::Card {
+            bounds: Rectangle::with_size(Size::new(8.0, 8.0)),
+            background: Background::Gradient(Gradient::horizontal(
+                Color::from_rgb(1.0, 0.0, 0.0),
+                Color::from_rgb(0.0, 0.0, 1.0),
+            )),
+            border_radius: BorderRadius::from([4, 0, 0, 0]),
+            borders: Borders::default(),
+            shadow: None,
+        });
+
+        let pixels = card.to_rgba(8, 8).expect("Rasterize geometry").rgba;
+
+        let pixel = |x: usize, y: usize| {
+            let i = (y * 8 + x) * 4;
+            [pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]]
+        };
+
+        assert_eq!(pixel(0, 0)[3], 0);
+        assert_eq!(pixel(7, 0)[3], 255);
+        assert_eq!(pixel(0, 7)[3], 255);
+        assert!(pixel(0, 7)[0] > pixel(7, 7)[0]);
+        assert!(pixel(0, 7)[2] < pixel(7, 7)[2]);
+    }
+
+    #[test]
+    fn rasterizes_card_with_bottom_border() {
+        let card = Geometry::from_primitive(Primitive::Card {
+            bounds: Rectangle::with_size(Size::new(4.0, 4.0)),
+            background: Background::Color(Color::WHITE),
+            border_radius: BorderRadius::default(),
+            borders: Borders {
+                bottom: BorderSide::new(1, Color::from_rgb(1.0, 0.0, 0.0)),
+                ..Borders::default()
+            },
+            shadow: None,
+        });
+
+        let pixels = card.to_rgba(4, 4).expect("Rasterize geometry").rgba;
+
+        let pixel = |x: usize, y: usize| {
+            let i = (y * 4 + x) * 4;
+            [pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]]
+        };
+
+        assert_eq!(pixel(0, 0), [255, 255, 255, 255]);
+        assert_eq!(pixel(0, 2), [255, 255, 255, 255]);
+        assert_eq!(pixel(0, 3), [255, 0, 0, 255]);
+        assert_eq!(pixel(3, 3), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn rasterizes_rows_of_every_band() {
+        let mut frame = Frame::new(Size::new(2.0, 40.0));
+
+        frame.fill(
+            &Path::rectangle(Point::new(0.0, 10.0), Size::new(2.0, 20.0)),
+            Color::from_rgb(1.0, 0.0, 0.0),
+        );
+
+        let pixels = frame
+            .into_geometry()
+            .to_rgba(2, 40)
+            .expect("Rasterize geometry")
+            .rgba;
+
+        let alpha = |y: usize| pixels[y * 2 * 4 + 3];
+
+        assert_eq!(pixels.len(), 2 * 40 * 4);
+        assert_eq!(alpha(9), 0);
+        assert_eq!(alpha(10), 255);
+        assert_eq!(alpha(16), 255);
+        assert_eq!(alpha(29), 255);
+        assert_eq!(alpha(30), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "font-fallback")]
+    fn rasterizes_text() {
+        let mut frame = Frame::new(Size::new(100.0, 40.0));
+
+        frame.fill_text(crate::canvas::Text {
+            content: String::from("Hello"),
+            size: 32.0,
+            color: Color::BLACK,
+            ..crate::canvas::Text::default()
+        });
+
+        let pixels = frame
+            .into_geometry()
+            .to_rgba(100, 40)
+            .expect("Rasterize geometry");
+
+        assert!(pixels.is_complete());
+        assert!(pixels.rgba.chunks_exact(4).any(|pixel| pixel[3] == 255));
+    }
+
+    #[test]
+    fn reports_dropped_svg() {
+        let svg = Geometry::from_primitive(Primitive::Svg {
+            handle: iced_native::svg::Handle::from_path("icon.svg"),
+            bounds: Rectangle::with_size(Size::new(4.0, 4.0)),
+            appearance: iced_native::svg::Appearance::default(),
+        });
+
+        let pixels = svg.to_rgba(4, 4).expect("Rasterize geometry");
+
+        assert!(!pixels.is_complete());
+        assert_eq!(pixels.dropped, vec![Dropped::Svg]);
+        assert!(square()
+            .to_rgba(4, 4)
+            .expect("Rasterize geometry")
+            .is_complete());
+    }
+
+    #[test]
+    fn rejects_images_too_large() {
+        match square().to_rgba(u32::MAX, u32::MAX) {
+            Err(ExportError::TooLarge { width, height }) => {
+                assert_eq!((width, height), (u32::MAX, u32::MAX));
+            }
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+}
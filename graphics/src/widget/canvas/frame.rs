@@ -1,7 +1,10 @@
-use iced_native::{Point, Rectangle, Size, Vector};
+use iced_native::{image, Point, Rectangle, Size, Vector};
 
 use crate::{
-    canvas::{Fill, Geometry, Path, Stroke, Text},
+    canvas::{
+        shadow, Fill, FillRule, Geometry, Matrix, Path, Pattern, Shadow,
+        Stroke, Text,
+    },
     triangle, Primitive,
 };
 
@@ -14,6 +17,7 @@ pub struct Frame {
     buffers: lyon::tessellation::VertexBuffers<triangle::Vertex2D, u32>,
     primitives: Vec<Primitive>,
     transforms: Transforms,
+    culling_region: Option<Rectangle>,
 }
 
 #[derive(Debug)]
@@ -47,6 +51,18 @@ impl Frame {
                     is_identity: true,
                 },
             },
+            culling_region: None,
+        }
+    }
+
+    /// Creates a new empty [`Frame`] that skips any geometry falling
+    /// completely outside of the given region.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    pub(crate) fn with_culling_region(size: Size, region: Rectangle) -> Frame {
+        Frame {
+            culling_region: Some(region),
+            ..Frame::new(size)
         }
     }
 
@@ -92,6 +108,10 @@ impl Frame {
             BuffersBuilder, FillOptions, FillTessellator,
         };
 
+        if self.is_culled(path.bounds(), 0.0) {
+            return;
+        }
+
         let Fill { color, rule } = fill.into();
 
         let mut buffers = BuffersBuilder::new(
@@ -113,6 +133,122 @@ impl Frame {
         let _ = result.expect("Tessellate path");
     }
 
+    /// Draws the given [`Path`] on the [`Frame`] by filling it with the
+    /// provided style, casting a [`Shadow`] behind it.
+    ///
+    /// The offset and the blur radius of the [`Shadow`] are not affected by
+    /// the current transform of the [`Frame`].
+    ///
+    /// [`Path`]: path/struct.Path.html
+    /// [`Frame`]: struct.Frame.html
+    /// [`Shadow`]: struct.Shadow.html
+    pub fn fill_with_shadow(
+        &mut self,
+        path: &Path,
+        fill: impl Into<Fill>,
+        shadow: Shadow,
+    ) {
+        use lyon::tessellation::{
+            BuffersBuilder, FillOptions, FillTessellator,
+        };
+
+        let fill = fill.into();
+        let margin = shadow.blur_radius.max(0.0)
+            + shadow.offset.x.abs().max(shadow.offset.y.abs());
+
+        if self.is_culled(path.bounds(), margin) {
+            return;
+        }
+
+        let color = shadow.color.into_linear();
+        let shadow_path =
+            path.transformed(&self.transforms.current.raw.post_translate(
+                lyon::math::Vector::new(shadow.offset.x, shadow.offset.y),
+            ));
+
+        let _ = FillTessellator::new()
+            .tessellate_path(
+                shadow_path.raw(),
+                &FillOptions::default().with_fill_rule(fill.rule.into()),
+                &mut BuffersBuilder::new(&mut self.buffers, FillVertex(color)),
+            )
+            .expect("Tessellate path");
+
+        shadow::blur(
+            &shadow_path.contours(),
+            fill.rule,
+            shadow.blur_radius,
+            color,
+            &mut self.buffers,
+        );
+
+        self.fill(path, fill);
+    }
+
+    /// Draws the given [`Path`] on the [`Frame`] by filling it with the
+    /// repeated tiles of a [`Pattern`].
+    ///
+    /// The tiles are drawn as a texture, which is not affected by the blend
+    /// mode or the opacity of the resulting [`Geometry`].
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Path`]: path/struct.Path.html
+    /// [`Frame`]: struct.Frame.html
+    /// [`Pattern`]: struct.Pattern.html
+    pub fn fill_pattern(
+        &mut self,
+        path: &Path,
+        pattern: &Pattern,
+        rule: FillRule,
+    ) {
+        use lyon::tessellation::{
+            geometry_builder::Positions, BuffersBuilder, FillOptions,
+            FillTessellator, VertexBuffers,
+        };
+
+        if self.is_culled(path.bounds(), 0.0) || pattern.is_empty() {
+            return;
+        }
+
+        let mut shape = VertexBuffers::new();
+        let options = FillOptions::default().with_fill_rule(rule.into());
+
+        let _ = FillTessellator::new()
+            .tessellate_path(
+                path.raw(),
+                &options,
+                &mut BuffersBuilder::new(&mut shape, Positions),
+            )
+            .expect("Tessellate path");
+
+        let tile = pattern.size();
+        let transform = self.transforms.current.raw;
+
+        let buffers = triangle::TexturedMesh2D {
+            vertices: shape
+                .vertices
+                .iter()
+                .map(|point| {
+                    let position = transform.transform_point(*point);
+
+                    triangle::TexturedVertex2D {
+                        position: [position.x, position.y],
+                        uv: [point.x / tile.width, point.y / tile.height],
+                    }
+                })
+                .collect(),
+            indices: shape.indices,
+        };
+
+        self.flush_buffers();
+
+        self.primitives.push(Primitive::TexturedMesh2D {
+            buffers,
+            texture: pattern.texture().clone(),
+            size: self.size,
+        });
+    }
+
     /// Draws an axis-aligned rectangle given its top-left corner coordinate and
     /// its `Size` on the [`Frame`] by filling it with the provided style.
     ///
@@ -125,6 +261,10 @@ impl Frame {
     ) {
         use lyon::tessellation::{BuffersBuilder, FillOptions};
 
+        if self.is_culled(Rectangle::new(top_left, size), 0.0) {
+            return;
+        }
+
         let Fill { color, rule } = fill.into();
 
         let mut buffers = BuffersBuilder::new(
@@ -155,13 +295,27 @@ impl Frame {
     ///
     /// [`Path`]: path/struct.Path.html
     /// [`Frame`]: struct.Frame.html
-    pub fn stroke(&mut self, path: &Path, stroke: impl Into<Stroke>) {
+    pub fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
         use lyon::tessellation::{
             BuffersBuilder, StrokeOptions, StrokeTessellator,
         };
 
         let stroke = stroke.into();
 
+        if self.is_culled(path.bounds(), stroke.width / 2.0) {
+            return;
+        }
+
+        let dashed;
+
+        let path = if stroke.line_dash.segments.is_empty() {
+            path
+        } else {
+            dashed = path.dashed(stroke.line_dash);
+
+            &dashed
+        };
+
         let mut buffers = BuffersBuilder::new(
             &mut self.buffers,
             StrokeVertex(stroke.color.into_linear()),
@@ -235,6 +389,36 @@ impl Frame {
         });
     }
 
+    /// Draws the raster image of the given [`image::Handle`] on the [`Frame`],
+    /// stretching it to fit the provided bounds.
+    ///
+    /// Only translations and scalings are applied to the bounds of the image.
+    /// Rotated images are currently not supported.
+    ///
+    /// Additionally, images will be rendered on top of the geometry of the
+    /// same layer of a [`Canvas`]. Use different layers if you need to draw
+    /// geometry on top of an image.
+    ///
+    /// The renderer must support images for them to be displayed. For
+    /// instance, `iced_wgpu` needs its `image` feature enabled.
+    ///
+    /// [`image::Handle`]: ../image/struct.Handle.html
+    /// [`Frame`]: struct.Frame.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn draw_image(&mut self, handle: image::Handle, bounds: Rectangle) {
+        if self.is_culled(bounds, 0.0) {
+            return;
+        }
+
+        let bounds = self.transform_rectangle(bounds);
+
+        self.primitives.push(Primitive::Image {
+            handle,
+            bounds,
+            frame: 0,
+        });
+    }
+
     /// Stores the current transform of the [`Frame`] and executes the given
     /// drawing operations, restoring the transform afterwards.
     ///
@@ -251,6 +435,45 @@ impl Frame {
         self.transforms.current = self.transforms.previous.pop().unwrap();
     }
 
+    /// Executes the given drawing operations within a [`Rectangle`] region,
+    /// clipping any geometry that overflows its bounds.
+    ///
+    /// The region is positioned using the current transform of the [`Frame`].
+    /// The drawing operations are performed in a new coordinate system with
+    /// its origin at the top-left corner of the region.
+    ///
+    /// Only translations and scalings are taken into account when positioning
+    /// the region, as clipping regions are always axis-aligned.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    /// [`Rectangle`]: ../struct.Rectangle.html
+    pub fn with_clip(&mut self, region: Rectangle, f: impl FnOnce(&mut Frame)) {
+        let region = self.transform_rectangle(region);
+
+        let mut frame = match self.culling_region {
+            Some(culling_region) => Frame::with_culling_region(
+                region.size(),
+                culling_region + Vector::new(-region.x, -region.y),
+            ),
+            None => Frame::new(region.size()),
+        };
+
+        f(&mut frame);
+
+        let translation = Vector::new(region.x, region.y);
+
+        self.flush_buffers();
+
+        self.primitives.push(Primitive::Translate {
+            translation,
+            content: Box::new(Primitive::Clip {
+                bounds: Rectangle::with_size(region.size()),
+                offset: Vector::new(0, 0),
+                content: Box::new(frame.into_geometry().into_primitive()),
+            }),
+        });
+    }
+
     /// Applies a translation to the current transform of the [`Frame`].
     ///
     /// [`Frame`]: struct.Frame.html
@@ -285,29 +508,120 @@ impl Frame {
     /// [`Frame`]: struct.Frame.html
     #[inline]
     pub fn scale(&mut self, scale: f32) {
+        self.scale_xy(scale, scale);
+    }
+
+    /// Applies a non-uniform scaling to the current transform of the
+    /// [`Frame`], using a different factor for each axis.
+    ///
+    /// Negative factors can be used to mirror the coordinate system.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    #[inline]
+    pub fn scale_xy(&mut self, x: f32, y: f32) {
         self.transforms.current.raw =
-            self.transforms.current.raw.pre_scale(scale, scale);
+            self.transforms.current.raw.pre_scale(x, y);
         self.transforms.current.is_identity = false;
     }
 
+    /// Applies an arbitrary [`Matrix`] to the current transform of the
+    /// [`Frame`].
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    /// [`Frame`]: struct.Frame.html
+    #[inline]
+    pub fn transform(&mut self, matrix: Matrix) {
+        self.transforms.current.raw =
+            self.transforms.current.raw.pre_transform(matrix.raw());
+        self.transforms.current.is_identity = false;
+    }
+
+    /// Returns the current transform of the [`Frame`] as a [`Matrix`].
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    /// [`Frame`]: struct.Frame.html
+    #[inline]
+    pub fn matrix(&self) -> Matrix {
+        let m = self.transforms.current.raw;
+
+        Matrix::new(m.m11, m.m12, m.m21, m.m22, m.m31, m.m32)
+    }
+
     /// Produces the [`Geometry`] representing everything drawn on the [`Frame`].
     ///
     /// [`Frame`]: struct.Frame.html
     /// [`Geometry`]: struct.Geometry.html
     pub fn into_geometry(mut self) -> Geometry {
+        self.flush_buffers();
+
+        Geometry::from_primitive(Primitive::Group {
+            primitives: self.primitives,
+        })
+    }
+
+    fn is_culled(&self, bounds: Rectangle, margin: f32) -> bool {
+        let region = match self.culling_region {
+            Some(region) => region,
+            None => return false,
+        };
+
+        let bounds = lyon::math::Rect::new(
+            lyon::math::Point::new(bounds.x - margin, bounds.y - margin),
+            lyon::math::Size::new(
+                bounds.width + margin * 2.0,
+                bounds.height + margin * 2.0,
+            ),
+        );
+
+        let bounds = if self.transforms.current.is_identity {
+            bounds
+        } else {
+            self.transforms.current.raw.transform_rect(&bounds)
+        };
+
+        bounds.max_x() < region.x
+            || bounds.min_x() > region.x + region.width
+            || bounds.max_y() < region.y
+            || bounds.min_y() > region.y + region.height
+    }
+
+    fn transform_rectangle(&self, rectangle: Rectangle) -> Rectangle {
+        if self.transforms.current.is_identity {
+            return rectangle;
+        }
+
+        let top_left =
+            self.transforms.current.raw.transform_point(
+                lyon::math::Point::new(rectangle.x, rectangle.y),
+            );
+
+        let size = self.transforms.current.raw.transform_vector(
+            lyon::math::Vector::new(rectangle.width, rectangle.height),
+        );
+
+        Rectangle {
+            x: top_left.x,
+            y: top_left.y,
+            width: size.x,
+            height: size.y,
+        }
+    }
+
+    fn flush_buffers(&mut self) {
         if !self.buffers.indices.is_empty() {
+            let buffers = std::mem::replace(
+                &mut self.buffers,
+                lyon::tessellation::VertexBuffers::new(),
+            );
+
             self.primitives.push(Primitive::Mesh2D {
                 buffers: triangle::Mesh2D {
-                    vertices: self.buffers.vertices,
-                    indices: self.buffers.indices,
+                    vertices: buffers.vertices,
+                    indices: buffers.indices,
                 },
                 size: self.size,
             });
         }
-
-        Geometry::from_primitive(Primitive::Group {
-            primitives: self.primitives,
-        })
     }
 }
 
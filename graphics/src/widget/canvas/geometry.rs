@@ -1,5 +1,10 @@
+use crate::canvas::export::{self, Pixels};
+use crate::canvas::ExportError;
+use crate::triangle::Blend;
 use crate::Primitive;
 
+use iced_native::Size;
+
 /// A bunch of shapes that can be drawn.
 ///
 /// [`Geometry`] can be easily generated with a [`Frame`] or stored in a
@@ -25,6 +30,114 @@ impl Geometry {
     pub fn into_primitive(self) -> Primitive {
         self.0
     }
+
+    /// Sets the [`Blend`] mode used to composite the [`Geometry`] with the
+    /// contents drawn behind it.
+    ///
+    /// [`Blend`]: enum.Blend.html
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn with_blend(self, blend: Blend) -> Self {
+        match self.0 {
+            Primitive::Composite {
+                opacity, content, ..
+            } => Self(Primitive::Composite {
+                blend,
+                opacity,
+                content,
+            }),
+            primitive => Self(Primitive::Composite {
+                blend,
+                opacity: 1.0,
+                content: Box::new(primitive),
+            }),
+        }
+    }
+
+    /// Sets the opacity of every shape of the [`Geometry`], between `0.0` and
+    /// `1.0`.
+    ///
+    /// This is not a group opacity: the [`Geometry`] is not drawn on its own
+    /// and faded afterwards. Instead, the opacity is applied to the color of
+    /// every fill, stroke, and text individually. Therefore, overlapping
+    /// shapes of the same [`Geometry`] can be seen through each other.
+    ///
+    /// Images and patterns are not affected.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn with_shape_opacity(self, opacity: f32) -> Self {
+        match self.0 {
+            Primitive::Composite { blend, content, .. } => {
+                Self(Primitive::Composite {
+                    blend,
+                    opacity,
+                    content,
+                })
+            }
+            primitive => Self(Primitive::Composite {
+                blend: Blend::Normal,
+                opacity,
+                content: Box::new(primitive),
+            }),
+        }
+    }
+
+    /// Produces an SVG document of the given size out of the [`Geometry`].
+    ///
+    /// Meshes, quads and text are exported as vector shapes. Images and SVG
+    /// images are only referenced when they were loaded from a file path.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn to_svg(&self, size: Size) -> String {
+        export::svg(&self.0, size)
+    }
+
+    /// Rasterizes the [`Geometry`] on the CPU, without the need of a window.
+    ///
+    /// The result contains `width * height` pixels in RGBA format. Text is
+    /// drawn with the built-in font, unless it uses an external font or a
+    /// system font family. SVG images and images not already decoded into
+    /// pixels cannot be rasterized; they are left out and listed in
+    /// [`Pixels::dropped`].
+    ///
+    /// It fails with [`ExportError::TooLarge`] if the image does not fit in
+    /// memory.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Pixels::dropped`]: struct.Pixels.html#structfield.dropped
+    /// [`ExportError::TooLarge`]: enum.ExportError.html#variant.TooLarge
+    pub fn to_rgba(
+        &self,
+        width: u32,
+        height: u32,
+    ) -> Result<Pixels, ExportError> {
+        export::rgba(&self.0, width, height, 1.0)
+    }
+
+    /// Rasterizes the [`Geometry`] and encodes the result as a PNG image.
+    ///
+    /// It fails with [`ExportError::Dropped`] if any content could not be
+    /// rasterized. Use [`to_rgba`] and [`Pixels::to_png`] to encode the
+    /// image anyway.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`ExportError::Dropped`]: enum.ExportError.html#variant.Dropped
+    /// [`to_rgba`]: #method.to_rgba
+    /// [`Pixels::to_png`]: struct.Pixels.html#method.to_png
+    #[cfg(feature = "png")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "png")))]
+    pub fn to_png(
+        &self,
+        width: u32,
+        height: u32,
+    ) -> Result<Vec<u8>, ExportError> {
+        let pixels = self.to_rgba(width, height)?;
+
+        if !pixels.is_complete() {
+            return Err(ExportError::Dropped(pixels.dropped));
+        }
+
+        pixels.to_png().map_err(ExportError::Encoding)
+    }
 }
 
 impl From<Geometry> for Primitive {
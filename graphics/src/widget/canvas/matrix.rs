@@ -0,0 +1,138 @@
+use iced_native::{Point, Vector};
+
+/// A 2D affine transformation.
+///
+/// A [`Matrix`] can be applied to a [`Frame`] to draw using an arbitrary
+/// coordinate system. For instance, you can mirror the Y axis to use
+/// cartesian coordinates.
+///
+/// [`Matrix`]: struct.Matrix.html
+/// [`Frame`]: struct.Frame.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix {
+    raw: lyon::math::Transform,
+}
+
+impl Matrix {
+    /// Creates a new [`Matrix`] given its coefficients in row-major order.
+    ///
+    /// A point `(x, y)` is transformed into:
+    ///
+    /// ```text
+    /// x' = m11 * x + m21 * y + m31
+    /// y' = m12 * x + m22 * y + m32
+    /// ```
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    pub fn new(
+        m11: f32,
+        m12: f32,
+        m21: f32,
+        m22: f32,
+        m31: f32,
+        m32: f32,
+    ) -> Matrix {
+        Matrix {
+            raw: lyon::math::Transform::row_major(m11, m12, m21, m22, m31, m32),
+        }
+    }
+
+    /// Creates an identity [`Matrix`], which does not transform anything.
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    pub fn identity() -> Matrix {
+        Matrix {
+            raw: lyon::math::Transform::identity(),
+        }
+    }
+
+    /// Creates a [`Matrix`] representing the given translation.
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    pub fn translation(translation: Vector) -> Matrix {
+        Matrix {
+            raw: lyon::math::Transform::create_translation(
+                translation.x,
+                translation.y,
+            ),
+        }
+    }
+
+    /// Creates a [`Matrix`] representing a rotation of the given angle, in
+    /// radians.
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    pub fn rotation(angle: f32) -> Matrix {
+        Matrix {
+            raw: lyon::math::Transform::create_rotation(
+                lyon::math::Angle::radians(-angle),
+            ),
+        }
+    }
+
+    /// Creates a [`Matrix`] representing a scaling with the given factors for
+    /// each axis.
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    pub fn scaling(x: f32, y: f32) -> Matrix {
+        Matrix {
+            raw: lyon::math::Transform::create_scale(x, y),
+        }
+    }
+
+    /// Returns a [`Matrix`] that applies the current [`Matrix`] and then the
+    /// given one.
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    pub fn then(&self, other: &Matrix) -> Matrix {
+        Matrix {
+            raw: self.raw.post_transform(&other.raw),
+        }
+    }
+
+    /// Returns the inverse of the [`Matrix`], if it exists.
+    ///
+    /// This can be useful to map the position of the cursor to the coordinate
+    /// system used to draw.
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    pub fn inverse(&self) -> Option<Matrix> {
+        self.raw.inverse().map(|raw| Matrix { raw })
+    }
+
+    /// Applies the [`Matrix`] to the given [`Point`].
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    /// [`Point`]: ../struct.Point.html
+    pub fn transform_point(&self, point: Point) -> Point {
+        let transformed = self
+            .raw
+            .transform_point(lyon::math::Point::new(point.x, point.y));
+
+        Point::new(transformed.x, transformed.y)
+    }
+
+    /// Applies the [`Matrix`] to the given [`Vector`], ignoring any
+    /// translation.
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    /// [`Vector`]: ../struct.Vector.html
+    pub fn transform_vector(&self, vector: Vector) -> Vector {
+        let transformed = self
+            .raw
+            .transform_vector(lyon::math::Vector::new(vector.x, vector.y));
+
+        Vector::new(transformed.x, transformed.y)
+    }
+
+    #[inline]
+    pub(crate) fn raw(&self) -> &lyon::math::Transform {
+        &self.raw
+    }
+}
+
+impl Default for Matrix {
+    fn default() -> Matrix {
+        Matrix::identity()
+    }
+}
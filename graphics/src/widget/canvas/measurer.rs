@@ -0,0 +1,42 @@
+use iced_native::{Font, Size};
+
+/// Measures text using the fonts of the renderer drawing a [`Canvas`].
+///
+/// A [`Measurer`] is provided to [`Program::draw_with_measurer`], so text
+/// can be positioned based on its actual dimensions.
+///
+/// [`Canvas`]: struct.Canvas.html
+/// [`Measurer`]: struct.Measurer.html
+/// [`Program::draw_with_measurer`]: trait.Program.html#method.draw_with_measurer
+#[allow(missing_debug_implementations)]
+pub struct Measurer<'a> {
+    measure: &'a Measure<'a>,
+}
+
+type Measure<'a> = dyn Fn(&str, f32, Font, Size) -> (f32, f32) + 'a;
+
+impl<'a> Measurer<'a> {
+    pub(crate) fn new(measure: &'a Measure<'a>) -> Self {
+        Measurer { measure }
+    }
+
+    /// Measures the given text contents with the provided size and font,
+    /// without any wrapping.
+    pub fn measure_text(&self, content: &str, size: f32, font: Font) -> Size {
+        self.measure_paragraph(content, size, font, Size::INFINITY)
+    }
+
+    /// Measures the given text contents with the provided size and font,
+    /// returning the size of a paragraph that fits in the provided bounds.
+    pub fn measure_paragraph(
+        &self,
+        content: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> Size {
+        let (width, height) = (self.measure)(content, size, font, bounds);
+
+        Size::new(width, height)
+    }
+}
@@ -1,13 +1,18 @@
 //! Build different kinds of 2D shapes.
 pub mod arc;
 
+mod boolean;
 mod builder;
+mod svg;
 
 #[doc(no_inline)]
 pub use arc::Arc;
 pub use builder::Builder;
+pub use svg::ParseError;
 
-use iced_native::{Point, Size};
+use crate::canvas::{FillRule, LineDash};
+
+use iced_native::{Point, Rectangle, Size};
 
 /// An immutable set of points that may or may not be connected.
 ///
@@ -62,11 +67,288 @@ impl Path {
         Self::new(|p| p.circle(center, radius))
     }
 
+    /// Creates a new [`Path`] from the given [SVG path data].
+    ///
+    /// All the commands of the SVG path mini-language are supported,
+    /// including relative commands, smooth curves, and elliptical arcs.
+    ///
+    /// ```
+    /// use iced_graphics::canvas::Path;
+    ///
+    /// let triangle = Path::from_svg("M 10 10 L 20 20 H 10 Z");
+    /// assert!(triangle.is_ok());
+    ///
+    /// let invalid = Path::from_svg("M 10 L 20 20");
+    /// assert!(invalid.is_err());
+    /// ```
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [SVG path data]: https://www.w3.org/TR/SVG/paths.html#PathData
+    pub fn from_svg(data: &str) -> Result<Self, ParseError> {
+        let mut builder = Builder::new();
+
+        svg::parse(data, builder.raw_mut())?;
+
+        Ok(builder.build())
+    }
+
+    /// Returns the axis-aligned bounding [`Rectangle`] of the [`Path`].
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`Rectangle`]: ../struct.Rectangle.html
+    pub fn bounds(&self) -> Rectangle {
+        let rect = lyon::algorithms::aabb::bounding_rect(self.raw.iter());
+
+        Rectangle {
+            x: rect.origin.x,
+            y: rect.origin.y,
+            width: rect.size.width,
+            height: rect.size.height,
+        }
+    }
+
+    /// Returns whether the given [`Point`] lies inside the [`Path`] when
+    /// filled using the provided [`FillRule`].
+    ///
+    /// Open subpaths are implicitly closed, just like when filling.
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`Point`]: ../struct.Point.html
+    /// [`FillRule`]: ../enum.FillRule.html
+    pub fn contains(&self, point: Point, rule: FillRule) -> bool {
+        lyon::algorithms::hit_test::hit_test_path(
+            &lyon::math::Point::new(point.x, point.y),
+            self.raw.iter(),
+            rule.into(),
+            TOLERANCE,
+        )
+    }
+
+    /// Returns whether the given [`Point`] lies on the stroke of the [`Path`]
+    /// when drawn with the provided width.
+    ///
+    /// A larger width can be used to make hit testing more forgiving.
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`Point`]: ../struct.Point.html
+    pub fn stroke_contains(&self, point: Point, width: f32) -> bool {
+        let point = lyon::math::Point::new(point.x, point.y);
+        let max_distance = width / 2.0;
+
+        self.segments(false).any(|(from, to)| {
+            distance_to_segment(point, from, to) <= max_distance
+        })
+    }
+
+    /// Returns whether the filled area of the [`Path`] intersects the given
+    /// [`Rectangle`], using the `NonZero` [`FillRule`].
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`Rectangle`]: ../struct.Rectangle.html
+    /// [`FillRule`]: ../enum.FillRule.html
+    pub fn intersects(&self, rectangle: &Rectangle) -> bool {
+        let bounds = self.bounds();
+
+        if bounds.x > rectangle.x + rectangle.width
+            || rectangle.x > bounds.x + bounds.width
+            || bounds.y > rectangle.y + rectangle.height
+            || rectangle.y > bounds.y + bounds.height
+        {
+            return false;
+        }
+
+        let top_left = rectangle.position();
+        let top_right = Point::new(rectangle.x + rectangle.width, rectangle.y);
+        let bottom_right = Point::new(
+            rectangle.x + rectangle.width,
+            rectangle.y + rectangle.height,
+        );
+        let bottom_left =
+            Point::new(rectangle.x, rectangle.y + rectangle.height);
+
+        let corners = [top_left, top_right, bottom_right, bottom_left];
+
+        // The rectangle may be completely inside of the path
+        if corners
+            .iter()
+            .any(|corner| self.contains(*corner, FillRule::NonZero))
+        {
+            return true;
+        }
+
+        let edges: Vec<_> = corners
+            .iter()
+            .zip(corners.iter().cycle().skip(1))
+            .map(|(from, to)| lyon::geom::LineSegment {
+                from: lyon::math::Point::new(from.x, from.y),
+                to: lyon::math::Point::new(to.x, to.y),
+            })
+            .collect();
+
+        // Otherwise, the outline of the path must cross or be inside of the
+        // rectangle
+        self.segments(true).any(|(from, to)| {
+            let segment = lyon::geom::LineSegment { from, to };
+
+            rectangle.contains(Point::new(from.x, from.y))
+                || edges.iter().any(|edge| edge.intersects(&segment))
+        })
+    }
+
+    /// Combines the filled areas of two paths into a [`Path`] covering both.
+    ///
+    /// Both paths are filled using the `NonZero` [`FillRule`] and their
+    /// curves are approximated with line segments. The resulting [`Path`] is
+    /// made of closed subpaths that should also be filled using the
+    /// `NonZero` [`FillRule`].
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`FillRule`]: ../enum.FillRule.html
+    pub fn union(&self, other: &Path) -> Path {
+        boolean::combine(self, other, boolean::Operation::Union)
+    }
+
+    /// Produces a [`Path`] covering the area filled by both paths.
+    ///
+    /// See [`union`] for details on how paths are combined.
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`union`]: #method.union
+    pub fn intersection(&self, other: &Path) -> Path {
+        boolean::combine(self, other, boolean::Operation::Intersection)
+    }
+
+    /// Produces a [`Path`] covering the area filled by this [`Path`] but not
+    /// by the other one.
+    ///
+    /// See [`union`] for details on how paths are combined.
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`union`]: #method.union
+    pub fn difference(&self, other: &Path) -> Path {
+        boolean::combine(self, other, boolean::Operation::Difference)
+    }
+
+    /// Produces a [`Path`] covering the area filled by exactly one of the
+    /// paths.
+    ///
+    /// See [`union`] for details on how paths are combined.
+    ///
+    /// [`Path`]: struct.Path.html
+    /// [`union`]: #method.union
+    pub fn xor(&self, other: &Path) -> Path {
+        boolean::combine(self, other, boolean::Operation::Xor)
+    }
+
+    /// Returns the line segments of the flattened [`Path`].
+    ///
+    /// If `close` is true, every subpath will be closed.
+    ///
+    /// [`Path`]: struct.Path.html
+    fn segments(
+        &self,
+        close: bool,
+    ) -> impl Iterator<Item = (lyon::math::Point, lyon::math::Point)> + '_ {
+        use lyon::path::iterator::PathIterator;
+        use lyon::path::PathEvent;
+
+        self.raw.iter().flattened(TOLERANCE).filter_map(
+            move |event| match event {
+                PathEvent::Line { from, to } => Some((from, to)),
+                PathEvent::End {
+                    last,
+                    first,
+                    close: is_closed,
+                } if close || is_closed => Some((last, first)),
+                _ => None,
+            },
+        )
+    }
+
+    /// Returns the points of every subpath of the flattened [`Path`], as if
+    /// they were closed.
+    ///
+    /// [`Path`]: struct.Path.html
+    pub(crate) fn contours(&self) -> Vec<Vec<lyon::math::Point>> {
+        use lyon::path::iterator::PathIterator;
+        use lyon::path::PathEvent;
+
+        let mut contours = Vec::new();
+        let mut current = Vec::new();
+
+        for event in self.raw.iter().flattened(TOLERANCE) {
+            match event {
+                PathEvent::Begin { at } => {
+                    current.push(at);
+                }
+                PathEvent::Line { to, .. } if current.last() != Some(&to) => {
+                    current.push(to);
+                }
+                PathEvent::End { .. } => {
+                    if current.len() > 1 && current.first() == current.last() {
+                        let _ = current.pop();
+                    }
+
+                    if current.len() > 2 {
+                        contours.push(std::mem::take(&mut current));
+                    } else {
+                        current.clear();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        contours
+    }
+
     #[inline]
     pub(crate) fn raw(&self) -> &lyon::path::Path {
         &self.raw
     }
 
+    pub(crate) fn dashed(&self, line_dash: LineDash<'_>) -> Path {
+        use lyon::algorithms::walk::{walk_along_path, RepeatedPattern};
+        use lyon::path::iterator::PathIterator;
+
+        // An odd number of segments is repeated to obtain an even pattern
+        let repeated;
+
+        let intervals = if line_dash.segments.len() % 2 == 1 {
+            repeated = [line_dash.segments, line_dash.segments].concat();
+
+            &repeated[..]
+        } else {
+            line_dash.segments
+        };
+
+        Path::new(|builder| {
+            let mut is_drawing = false;
+
+            walk_along_path(
+                self.raw.iter().flattened(TOLERANCE),
+                0.0,
+                &mut RepeatedPattern {
+                    callback: |position: lyon::math::Point, _tangent, _| {
+                        let point = Point::new(position.x, position.y);
+
+                        if is_drawing {
+                            builder.line_to(point);
+                        } else {
+                            builder.move_to(point);
+                        }
+
+                        is_drawing = !is_drawing;
+
+                        true
+                    },
+                    intervals,
+                    index: line_dash.offset,
+                },
+            );
+        })
+    }
+
     #[inline]
     pub(crate) fn transformed(
         &self,
@@ -77,3 +359,129 @@ impl Path {
         }
     }
 }
+
+const TOLERANCE: f32 = 0.01;
+
+fn distance_to_segment(
+    point: lyon::math::Point,
+    from: lyon::math::Point,
+    to: lyon::math::Point,
+) -> f32 {
+    let segment = to - from;
+    let length_squared = segment.square_length();
+
+    if length_squared == 0.0 {
+        return (point - from).length();
+    }
+
+    let t = ((point - from).dot(segment) / length_squared).clamp(0.0, 1.0);
+
+    (point - (from + segment * t)).length()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rectangle_contains_points() {
+        let path =
+            Path::rectangle(Point::new(10.0, 10.0), Size::new(20.0, 10.0));
+
+        assert!(path.contains(Point::new(15.0, 15.0), FillRule::NonZero));
+        assert!(!path.contains(Point::new(5.0, 15.0), FillRule::NonZero));
+        assert!(!path.contains(Point::new(15.0, 25.0), FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn line_stroke_contains_points() {
+        let path = Path::line(Point::new(0.0, 0.0), Point::new(10.0, 0.0));
+
+        assert!(path.stroke_contains(Point::new(5.0, 0.9), 2.0));
+        assert!(!path.stroke_contains(Point::new(5.0, 1.1), 2.0));
+        assert!(!path.stroke_contains(Point::new(11.5, 0.0), 2.0));
+    }
+
+    #[test]
+    fn boolean_operations_on_overlapping_squares() {
+        let a = Path::rectangle(Point::new(0.0, 0.0), Size::new(10.0, 10.0));
+        let b = Path::rectangle(Point::new(5.0, 5.0), Size::new(10.0, 10.0));
+
+        let only_a = Point::new(2.0, 2.0);
+        let both = Point::new(7.0, 7.0);
+        let only_b = Point::new(12.0, 12.0);
+        let none = Point::new(12.0, 2.0);
+
+        let check = |path: Path, expected: [bool; 4]| {
+            let actual = [only_a, both, only_b, none]
+                .iter()
+                .map(|point| path.contains(*point, FillRule::NonZero))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, expected);
+        };
+
+        check(a.union(&b), [true, true, true, false]);
+        check(a.intersection(&b), [false, true, false, false]);
+        check(a.difference(&b), [true, false, false, false]);
+        check(a.xor(&b), [true, false, true, false]);
+    }
+
+    #[test]
+    fn union_of_adjacent_squares_has_a_single_contour() {
+        let a = Path::rectangle(Point::new(0.0, 0.0), Size::new(10.0, 10.0));
+        let b = Path::rectangle(Point::new(10.0, 0.0), Size::new(10.0, 10.0));
+
+        let union = a.union(&b);
+
+        assert!(union.contains(Point::new(10.0, 5.0), FillRule::NonZero));
+        assert!(!union.stroke_contains(Point::new(10.0, 5.0), 1.0));
+        assert_eq!(union.bounds().width, 20.0);
+    }
+
+    #[test]
+    fn svg_path_data_is_parsed() {
+        let path = Path::from_svg(
+            "M10,10 l10 0 v10 h-10z M30 30 c0 10 10 10 10 0 s10-10 10 0 \
+             q5 5 10 0 t10 0 a5 5 0 0 1 10 0 A5 5 0 1 0 30 30",
+        )
+        .expect("Parse path data");
+
+        assert!(path.contains(Point::new(15.0, 15.0), FillRule::NonZero));
+        assert!(!path.contains(Point::new(25.0, 15.0), FillRule::NonZero));
+
+        assert_eq!(
+            Path::from_svg("M 1e1-2.5.5 L 3").unwrap_err(),
+            ParseError::ExpectedNumber { position: 12 }
+        );
+        assert_eq!(
+            Path::from_svg("L 10 10").unwrap_err(),
+            ParseError::MissingMoveTo
+        );
+        assert_eq!(
+            Path::from_svg("M 0 0 A 1 1 0 2 0 1 1").unwrap_err(),
+            ParseError::ExpectedFlag { position: 14 }
+        );
+        assert_eq!(
+            Path::from_svg("M 0 0 X").unwrap_err(),
+            ParseError::UnexpectedCharacter { position: 6 }
+        );
+    }
+
+    #[test]
+    fn circle_intersects_rectangles() {
+        let path = Path::circle(Point::new(0.0, 0.0), 10.0);
+
+        let inside = Rectangle::new(Point::new(-1.0, -1.0), Size::UNIT);
+        let crossing =
+            Rectangle::new(Point::new(5.0, 5.0), Size::new(10.0, 10.0));
+        let surrounding =
+            Rectangle::new(Point::new(-20.0, -20.0), Size::new(40.0, 40.0));
+        let corner = Rectangle::new(Point::new(8.0, 8.0), Size::new(5.0, 5.0));
+
+        assert!(path.intersects(&inside));
+        assert!(path.intersects(&crossing));
+        assert!(path.intersects(&surrounding));
+        assert!(!path.intersects(&corner));
+    }
+}
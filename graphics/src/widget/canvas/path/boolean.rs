@@ -0,0 +1,308 @@
+//! Combine the filled areas of two paths.
+use super::{distance_to_segment, Path};
+
+use iced_native::Point;
+use lyon::math::{self, Vector};
+use std::collections::HashMap;
+
+/// Coordinates are snapped to a grid of this size before combining paths,
+/// so almost identical points are merged.
+const GRID: f32 = 1.0 / 1024.0;
+
+const EPSILON: f32 = 1.0e-3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Operation {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    from: math::Point,
+    to: math::Point,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Inside,
+    Outside,
+    SameBoundary,
+    OppositeBoundary,
+}
+
+pub(super) fn combine(a: &Path, b: &Path, operation: Operation) -> Path {
+    let mut a = edges(a);
+    let mut b = edges(b);
+
+    split(&mut a, &mut b);
+
+    let mut selected = Vec::new();
+
+    for edge in &a {
+        match (operation, locate(*edge, &b)) {
+            (Operation::Union, Location::Outside)
+            | (Operation::Union, Location::SameBoundary)
+            | (Operation::Intersection, Location::Inside)
+            | (Operation::Intersection, Location::SameBoundary)
+            | (Operation::Difference, Location::Outside)
+            | (Operation::Difference, Location::OppositeBoundary)
+            | (Operation::Xor, Location::Outside) => selected.push(*edge),
+            (Operation::Xor, Location::Inside) => {
+                selected.push(edge.reversed())
+            }
+            _ => {}
+        }
+    }
+
+    // Edges shared by both paths were already considered when locating the
+    // edges of the first one
+    for edge in &b {
+        match (operation, locate(*edge, &a)) {
+            (Operation::Union, Location::Outside)
+            | (Operation::Intersection, Location::Inside)
+            | (Operation::Xor, Location::Outside) => selected.push(*edge),
+            (Operation::Difference, Location::Inside)
+            | (Operation::Xor, Location::Inside) => {
+                selected.push(edge.reversed())
+            }
+            _ => {}
+        }
+    }
+
+    build(selected)
+}
+
+impl Edge {
+    fn reversed(self) -> Edge {
+        Edge {
+            from: self.to,
+            to: self.from,
+        }
+    }
+
+    fn direction(&self) -> Vector {
+        self.to - self.from
+    }
+
+    fn midpoint(&self) -> math::Point {
+        self.from.lerp(self.to, 0.5)
+    }
+}
+
+fn snap(point: math::Point) -> math::Point {
+    math::Point::new(
+        (point.x / GRID).round() * GRID,
+        (point.y / GRID).round() * GRID,
+    )
+}
+
+fn edges(path: &Path) -> Vec<Edge> {
+    path.segments(true)
+        .map(|(from, to)| Edge {
+            from: snap(from),
+            to: snap(to),
+        })
+        .filter(|edge| edge.from != edge.to)
+        .collect()
+}
+
+/// Splits the edges of both sets at their intersections, so the resulting
+/// edges only meet at their endpoints.
+fn split(a: &mut Vec<Edge>, b: &mut Vec<Edge>) {
+    let mut splits_a: Vec<Vec<(f32, math::Point)>> = vec![Vec::new(); a.len()];
+    let mut splits_b: Vec<Vec<(f32, math::Point)>> = vec![Vec::new(); b.len()];
+
+    for (i, edge_a) in a.iter().enumerate() {
+        for (j, edge_b) in b.iter().enumerate() {
+            if !bounds_overlap(*edge_a, *edge_b) {
+                continue;
+            }
+
+            let r = edge_a.direction();
+            let s = edge_b.direction();
+            let denominator = r.cross(s);
+            let offset = edge_b.from - edge_a.from;
+
+            if denominator.abs() <= f32::EPSILON * r.length() * s.length() {
+                // Collinear edges overlap where the endpoints of one lie on
+                // the other
+                for point in [edge_b.from, edge_b.to].iter() {
+                    if let Some(t) = parameter_on(*edge_a, *point) {
+                        splits_a[i].push((t, *point));
+                    }
+                }
+
+                for point in [edge_a.from, edge_a.to].iter() {
+                    if let Some(t) = parameter_on(*edge_b, *point) {
+                        splits_b[j].push((t, *point));
+                    }
+                }
+
+                continue;
+            }
+
+            let t = offset.cross(s) / denominator;
+            let u = offset.cross(r) / denominator;
+
+            if !(0.0..=1.0).contains(&t) || !(0.0..=1.0).contains(&u) {
+                continue;
+            }
+
+            // Intersections close to an endpoint are snapped to it, so the
+            // same point is shared by both edges
+            let point = if t * r.length() < EPSILON {
+                edge_a.from
+            } else if (1.0 - t) * r.length() < EPSILON {
+                edge_a.to
+            } else if u * s.length() < EPSILON {
+                edge_b.from
+            } else if (1.0 - u) * s.length() < EPSILON {
+                edge_b.to
+            } else {
+                snap(edge_a.from + r * t)
+            };
+
+            splits_a[i].push((t, point));
+            splits_b[j].push((u, point));
+        }
+    }
+
+    *a = apply_splits(a, splits_a);
+    *b = apply_splits(b, splits_b);
+}
+
+fn bounds_overlap(a: Edge, b: Edge) -> bool {
+    a.from.x.min(a.to.x) <= b.from.x.max(b.to.x) + EPSILON
+        && b.from.x.min(b.to.x) <= a.from.x.max(a.to.x) + EPSILON
+        && a.from.y.min(a.to.y) <= b.from.y.max(b.to.y) + EPSILON
+        && b.from.y.min(b.to.y) <= a.from.y.max(a.to.y) + EPSILON
+}
+
+/// Returns the parameter of the given point along the edge, if the point
+/// lies strictly inside of it.
+fn parameter_on(edge: Edge, point: math::Point) -> Option<f32> {
+    let direction = edge.direction();
+    let length = direction.length();
+    let t = (point - edge.from).dot(direction) / (length * length);
+
+    if t * length > EPSILON
+        && (1.0 - t) * length > EPSILON
+        && distance_to_segment(point, edge.from, edge.to) < EPSILON
+    {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+fn apply_splits(
+    edges: &[Edge],
+    splits: Vec<Vec<(f32, math::Point)>>,
+) -> Vec<Edge> {
+    let mut result = Vec::with_capacity(edges.len());
+
+    for (edge, mut splits) in edges.iter().zip(splits) {
+        splits.sort_by(|(a, _), (b, _)| {
+            a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut from = edge.from;
+
+        for (_, point) in splits.into_iter().chain(Some((1.0, edge.to))) {
+            if point != from {
+                result.push(Edge { from, to: point });
+                from = point;
+            }
+        }
+    }
+
+    result
+}
+
+/// Locates an edge relative to the area filled by a set of edges, using the
+/// `NonZero` fill rule.
+fn locate(edge: Edge, edges: &[Edge]) -> Location {
+    let midpoint = edge.midpoint();
+
+    for other in edges {
+        if distance_to_segment(midpoint, other.from, other.to) < EPSILON {
+            return if edge.direction().dot(other.direction()) > 0.0 {
+                Location::SameBoundary
+            } else {
+                Location::OppositeBoundary
+            };
+        }
+    }
+
+    if winding_number(midpoint, edges) != 0 {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+fn winding_number(point: math::Point, edges: &[Edge]) -> i32 {
+    edges.iter().fold(0, |winding, edge| {
+        let side = edge.direction().cross(point - edge.from);
+
+        if edge.from.y <= point.y {
+            if edge.to.y > point.y && side > 0.0 {
+                return winding + 1;
+            }
+        } else if edge.to.y <= point.y && side < 0.0 {
+            return winding - 1;
+        }
+
+        winding
+    })
+}
+
+/// Chains the given edges into closed contours.
+fn build(edges: Vec<Edge>) -> Path {
+    let key = |point: math::Point| (point.x.to_bits(), point.y.to_bits());
+
+    let mut outgoing: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+
+    for (i, edge) in edges.iter().enumerate() {
+        outgoing.entry(key(edge.from)).or_default().push(i);
+    }
+
+    let mut used = vec![false; edges.len()];
+
+    Path::new(|builder| {
+        for start in 0..edges.len() {
+            if used[start] {
+                continue;
+            }
+
+            used[start] = true;
+
+            let first = edges[start].from;
+            let mut current = edges[start].to;
+
+            builder.move_to(Point::new(first.x, first.y));
+            builder.line_to(Point::new(current.x, current.y));
+
+            while current != first {
+                let next = outgoing.get(&key(current)).and_then(|candidates| {
+                    candidates.iter().copied().find(|i| !used[*i])
+                });
+
+                match next {
+                    Some(i) => {
+                        used[i] = true;
+                        current = edges[i].to;
+
+                        builder.line_to(Point::new(current.x, current.y));
+                    }
+                    None => break,
+                }
+            }
+
+            builder.close();
+        }
+    })
+}
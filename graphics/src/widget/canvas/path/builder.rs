@@ -167,6 +167,13 @@ impl Builder {
         self.raw.close()
     }
 
+    #[inline]
+    pub(super) fn raw_mut(
+        &mut self,
+    ) -> &mut lyon::path::builder::SvgPathBuilder<lyon::path::Builder> {
+        &mut self.raw
+    }
+
     /// Builds the [`Path`] of this [`Builder`].
     ///
     /// [`Path`]: struct.Path.html
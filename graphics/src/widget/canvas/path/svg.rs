@@ -0,0 +1,341 @@
+//! Parse the SVG path mini-language.
+use lyon::math::{Angle, Point, Vector};
+use lyon::path::builder::SvgBuilder;
+use lyon::path::ArcFlags;
+
+use std::fmt;
+
+/// An error produced when parsing SVG path data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// A character that does not start a command or a number was found at
+    /// the given byte offset.
+    UnexpectedCharacter {
+        /// The byte offset of the character
+        position: usize,
+    },
+
+    /// A number was expected at the given byte offset.
+    ExpectedNumber {
+        /// The byte offset where the number was expected
+        position: usize,
+    },
+
+    /// An arc flag (`0` or `1`) was expected at the given byte offset.
+    ExpectedFlag {
+        /// The byte offset where the flag was expected
+        position: usize,
+    },
+
+    /// The path data does not start with a move command.
+    MissingMoveTo,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedCharacter { position } => {
+                write!(f, "unexpected character at offset {}", position)
+            }
+            ParseError::ExpectedNumber { position } => {
+                write!(f, "expected a number at offset {}", position)
+            }
+            ParseError::ExpectedFlag { position } => {
+                write!(f, "expected an arc flag at offset {}", position)
+            }
+            ParseError::MissingMoveTo => {
+                write!(f, "path data must start with a move command")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub(super) fn parse(
+    data: &str,
+    builder: &mut impl SvgBuilder,
+) -> Result<(), ParseError> {
+    let mut parser = Parser {
+        data: data.as_bytes(),
+        position: 0,
+        last_relative: false,
+    };
+
+    let mut command = None;
+
+    loop {
+        parser.skip_separators();
+
+        let next = match parser.peek() {
+            Some(next) => next,
+            None => break,
+        };
+
+        let is_relative = if next.is_ascii_alphabetic() {
+            parser.position += 1;
+
+            let is_relative = next.is_ascii_lowercase();
+            let new_command = next.to_ascii_uppercase();
+
+            if command.is_none() && new_command != b'M' {
+                return Err(ParseError::MissingMoveTo);
+            }
+
+            command = Some(new_command);
+
+            if new_command == b'Z' {
+                builder.close();
+                continue;
+            }
+
+            is_relative
+        } else {
+            if !(next.is_ascii_digit() || b"+-.".contains(&next)) {
+                return Err(ParseError::UnexpectedCharacter {
+                    position: parser.position,
+                });
+            }
+
+            match command {
+                // Numbers after a move command are implicit lines
+                Some(b'M') => {
+                    command = Some(b'L');
+                }
+                Some(b'Z') => {
+                    return Err(ParseError::UnexpectedCharacter {
+                        position: parser.position,
+                    });
+                }
+                Some(_) => {}
+                None => return Err(ParseError::MissingMoveTo),
+            }
+
+            parser.last_relative
+        };
+
+        parser.last_relative = is_relative;
+
+        match command.expect("Command") {
+            b'M' => {
+                let (x, y) = (parser.number()?, parser.number()?);
+
+                if is_relative {
+                    builder.relative_move_to(Vector::new(x, y));
+                } else {
+                    builder.move_to(Point::new(x, y));
+                }
+            }
+            b'L' => {
+                let (x, y) = (parser.number()?, parser.number()?);
+
+                if is_relative {
+                    builder.relative_line_to(Vector::new(x, y));
+                } else {
+                    builder.line_to(Point::new(x, y));
+                }
+            }
+            b'H' => {
+                let x = parser.number()?;
+
+                if is_relative {
+                    builder.relative_horizontal_line_to(x);
+                } else {
+                    builder.horizontal_line_to(x);
+                }
+            }
+            b'V' => {
+                let y = parser.number()?;
+
+                if is_relative {
+                    builder.relative_vertical_line_to(y);
+                } else {
+                    builder.vertical_line_to(y);
+                }
+            }
+            b'C' => {
+                let (x1, y1) = (parser.number()?, parser.number()?);
+                let (x2, y2) = (parser.number()?, parser.number()?);
+                let (x, y) = (parser.number()?, parser.number()?);
+
+                if is_relative {
+                    builder.relative_cubic_bezier_to(
+                        Vector::new(x1, y1),
+                        Vector::new(x2, y2),
+                        Vector::new(x, y),
+                    );
+                } else {
+                    builder.cubic_bezier_to(
+                        Point::new(x1, y1),
+                        Point::new(x2, y2),
+                        Point::new(x, y),
+                    );
+                }
+            }
+            b'S' => {
+                let (x2, y2) = (parser.number()?, parser.number()?);
+                let (x, y) = (parser.number()?, parser.number()?);
+
+                if is_relative {
+                    builder.smooth_relative_cubic_bezier_to(
+                        Vector::new(x2, y2),
+                        Vector::new(x, y),
+                    );
+                } else {
+                    builder.smooth_cubic_bezier_to(
+                        Point::new(x2, y2),
+                        Point::new(x, y),
+                    );
+                }
+            }
+            b'Q' => {
+                let (x1, y1) = (parser.number()?, parser.number()?);
+                let (x, y) = (parser.number()?, parser.number()?);
+
+                if is_relative {
+                    builder.relative_quadratic_bezier_to(
+                        Vector::new(x1, y1),
+                        Vector::new(x, y),
+                    );
+                } else {
+                    builder.quadratic_bezier_to(
+                        Point::new(x1, y1),
+                        Point::new(x, y),
+                    );
+                }
+            }
+            b'T' => {
+                let (x, y) = (parser.number()?, parser.number()?);
+
+                if is_relative {
+                    builder
+                        .smooth_relative_quadratic_bezier_to(Vector::new(x, y));
+                } else {
+                    builder.smooth_quadratic_bezier_to(Point::new(x, y));
+                }
+            }
+            b'A' => {
+                let radii = Vector::new(parser.number()?, parser.number()?);
+                let x_rotation = Angle::degrees(parser.number()?);
+
+                let flags = ArcFlags {
+                    large_arc: parser.flag()?,
+                    sweep: parser.flag()?,
+                };
+
+                let (x, y) = (parser.number()?, parser.number()?);
+
+                if is_relative {
+                    builder.relative_arc_to(
+                        radii,
+                        x_rotation,
+                        flags,
+                        Vector::new(x, y),
+                    );
+                } else {
+                    builder.arc_to(radii, x_rotation, flags, Point::new(x, y));
+                }
+            }
+            _ => {
+                return Err(ParseError::UnexpectedCharacter {
+                    position: parser.position - 1,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+struct Parser<'a> {
+    data: &'a [u8],
+    position: usize,
+    last_relative: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.position).copied()
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r')
+        | Some(b',') = self.peek()
+        {
+            self.position += 1;
+        }
+    }
+
+    fn number(&mut self) -> Result<f32, ParseError> {
+        self.skip_separators();
+
+        let start = self.position;
+
+        if let Some(b'+') | Some(b'-') = self.peek() {
+            self.position += 1;
+        }
+
+        let digits = self.digits();
+
+        let decimals = if let Some(b'.') = self.peek() {
+            self.position += 1;
+            self.digits()
+        } else {
+            0
+        };
+
+        if digits + decimals == 0 {
+            self.position = start;
+
+            return Err(ParseError::ExpectedNumber { position: start });
+        }
+
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            let exponent = self.position;
+
+            self.position += 1;
+
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.position += 1;
+            }
+
+            // An `e` not followed by digits is not part of the number
+            if self.digits() == 0 {
+                self.position = exponent;
+            }
+        }
+
+        std::str::from_utf8(&self.data[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or(ParseError::ExpectedNumber { position: start })
+    }
+
+    fn flag(&mut self) -> Result<bool, ParseError> {
+        self.skip_separators();
+
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => {
+                return Err(ParseError::ExpectedFlag {
+                    position: self.position,
+                })
+            }
+        };
+
+        self.position += 1;
+
+        Ok(flag)
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.position;
+
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+
+        self.position - start
+    }
+}
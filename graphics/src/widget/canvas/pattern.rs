@@ -0,0 +1,166 @@
+use crate::canvas::{export, Frame};
+
+use iced_native::{image, Size};
+
+/// A tile of pixels that can be repeated to fill a [`Path`].
+///
+/// A [`Pattern`] can be drawn procedurally with a [`Frame`] or built from
+/// the pixels of an image, and then used with [`Frame::fill_pattern`] to
+/// draw hatched regions, checkerboards, textured backgrounds, etc.
+///
+/// Tiles are laid out in the coordinate system of the filled [`Path`],
+/// starting at the origin. Therefore, adjacent shapes filled with the same
+/// [`Pattern`] line up seamlessly.
+///
+/// [`Pattern`]: struct.Pattern.html
+/// [`Path`]: path/struct.Path.html
+/// [`Frame`]: struct.Frame.html
+/// [`Frame::fill_pattern`]: struct.Frame.html#method.fill_pattern
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    size: Size,
+    texture: image::Handle,
+}
+
+impl Pattern {
+    const SCALE: f32 = 2.0;
+
+    /// Creates a new [`Pattern`] with a tile of the given size, drawn by the
+    /// provided closure.
+    ///
+    /// The tile is rasterized once, at twice its size to stay sharp on high
+    /// DPI displays. Anything drawn outside of the bounds of the tile is
+    /// discarded.
+    ///
+    /// [`Pattern`]: struct.Pattern.html
+    pub fn new(size: Size, draw: impl FnOnce(&mut Frame)) -> Pattern {
+        let mut frame = Frame::new(size);
+
+        draw(&mut frame);
+
+        let width = (size.width * Self::SCALE).ceil().max(0.0) as u32;
+        let height = (size.height * Self::SCALE).ceil().max(0.0) as u32;
+
+        let pixels = export::rgba(
+            &frame.into_geometry().into_primitive(),
+            width,
+            height,
+            Self::SCALE,
+        );
+
+        match pixels {
+            Ok(pixels) => Pattern {
+                size,
+                texture: texture(width, height, &pixels.rgba),
+            },
+            Err(_) => Pattern {
+                size,
+                texture: texture(0, 0, &[]),
+            },
+        }
+    }
+
+    /// Creates a new [`Pattern`] out of the pixels of an image in RGBA
+    /// format.
+    ///
+    /// Every pixel of the image is mapped to a square unit of the tile.
+    ///
+    /// # Panics
+    /// Panics if the amount of pixels does not match the given dimensions.
+    ///
+    /// [`Pattern`]: struct.Pattern.html
+    pub fn from_rgba(width: u32, height: u32, pixels: &[u8]) -> Pattern {
+        assert_eq!(
+            Some(pixels.len()),
+            (width as usize)
+                .checked_mul(height as usize)
+                .and_then(|length| length.checked_mul(4))
+        );
+
+        Pattern {
+            size: Size::new(width as f32, height as f32),
+            texture: texture(width, height, pixels),
+        }
+    }
+
+    /// Returns the size of a tile of the [`Pattern`].
+    ///
+    /// [`Pattern`]: struct.Pattern.html
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the image containing a tile of the [`Pattern`].
+    ///
+    /// [`Pattern`]: struct.Pattern.html
+    pub(crate) fn texture(&self) -> &image::Handle {
+        &self.texture
+    }
+
+    /// Returns whether the [`Pattern`] draws nothing.
+    ///
+    /// [`Pattern`]: struct.Pattern.html
+    pub(crate) fn is_empty(&self) -> bool {
+        match self.texture.data() {
+            image::Data::Pixels { width, height, .. } => {
+                *width == 0
+                    || *height == 0
+                    || self.size.width <= 0.0
+                    || self.size.height <= 0.0
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Builds an image out of RGBA pixels.
+fn texture(width: u32, height: u32, rgba: &[u8]) -> image::Handle {
+    // Images are stored in BGRA
+    let bgra = rgba
+        .chunks_exact(4)
+        .flat_map(|pixel| vec![pixel[2], pixel[1], pixel[0], pixel[3]])
+        .collect();
+
+    image::Handle::from_pixels(width, height, bgra)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::{FillRule, Path};
+
+    #[test]
+    fn checkerboard_fills_alternating_squares() {
+        let checkerboard = Pattern::from_rgba(
+            2,
+            2,
+            &[255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 255],
+        );
+
+        let mut frame = Frame::new(Size::new(4.0, 4.0));
+
+        frame.fill_pattern(
+            &Path::rectangle(
+                iced_native::Point::new(1.0, 0.0),
+                Size::new(3.0, 4.0),
+            ),
+            &checkerboard,
+            FillRule::NonZero,
+        );
+
+        let pixels = frame
+            .into_geometry()
+            .to_rgba(4, 4)
+            .expect("Rasterize pattern")
+            .rgba;
+        let alpha = |x: usize, y: usize| pixels[(y * 4 + x) * 4 + 3];
+
+        assert_eq!(alpha(0, 0), 0);
+        assert_eq!(alpha(1, 0), 0);
+        assert_eq!(alpha(2, 0), 255);
+        assert_eq!(alpha(3, 0), 0);
+        assert_eq!(alpha(1, 1), 255);
+        assert_eq!(alpha(2, 1), 0);
+        assert_eq!(alpha(3, 3), 255);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::canvas::{Cursor, Event, Geometry};
+use crate::canvas::{Cursor, Event, Geometry, Measurer};
 use iced_native::{mouse, Rectangle};
 
 /// The state and logic of a [`Canvas`].
@@ -40,7 +40,27 @@ pub trait Program<Message> {
     /// [`Geometry`]: struct.Geometry.html
     /// [`Frame`]: struct.Frame.html
     /// [`Cache`]: struct.Cache.html
-    fn draw(&self, bounds: Rectangle, cursor: Cursor) -> Vec<Geometry>;
+    fn draw(&self, _bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry> {
+        Vec::new()
+    }
+
+    /// Draws the state of the [`Program`] with the help of a [`Measurer`],
+    /// which can be used to obtain the dimensions of text before drawing it.
+    ///
+    /// By default, this method simply calls [`draw`]. Programs that need to
+    /// measure text should implement this method instead.
+    ///
+    /// [`Program`]: trait.Program.html
+    /// [`Measurer`]: struct.Measurer.html
+    /// [`draw`]: #method.draw
+    fn draw_with_measurer(
+        &self,
+        bounds: Rectangle,
+        cursor: Cursor,
+        _measurer: &Measurer<'_>,
+    ) -> Vec<Geometry> {
+        self.draw(bounds, cursor)
+    }
 
     /// Returns the current mouse interaction of the [`Program`].
     ///
@@ -75,6 +95,15 @@ where
         T::draw(self, bounds, cursor)
     }
 
+    fn draw_with_measurer(
+        &self,
+        bounds: Rectangle,
+        cursor: Cursor,
+        measurer: &Measurer<'_>,
+    ) -> Vec<Geometry> {
+        T::draw_with_measurer(self, bounds, cursor, measurer)
+    }
+
     fn mouse_interaction(
         &self,
         bounds: Rectangle,
@@ -0,0 +1,306 @@
+use crate::canvas::{Frame, Geometry, Matrix};
+use crate::Primitive;
+
+use iced_native::Size;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// A retained collection of drawable nodes.
+///
+/// Every node of a [`Scene`] is drawn with its own closure, transform, and
+/// z-index. The resulting geometry is cached per node. Therefore, updating a
+/// single node of a large [`Scene`] only redraws that node.
+///
+/// Nodes with a higher z-index are drawn on top. Nodes with the same z-index
+/// are drawn in the order they were added.
+///
+/// [`Scene`]: struct.Scene.html
+#[derive(Default)]
+pub struct Scene {
+    nodes: BTreeMap<NodeId, Node>,
+    next_id: u64,
+    bounds: RefCell<Option<Size>>,
+}
+
+/// The identifier of a node in a [`Scene`].
+///
+/// [`Scene`]: struct.Scene.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(u64);
+
+struct Node {
+    draw: Box<dyn Fn(&mut Frame)>,
+    transform: Matrix,
+    z_index: i32,
+    primitive: RefCell<Option<Arc<Primitive>>>,
+}
+
+impl Scene {
+    /// Creates a new empty [`Scene`].
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn new() -> Scene {
+        Scene::default()
+    }
+
+    /// Adds a new node to the [`Scene`], drawn by the given closure, and
+    /// returns its [`NodeId`].
+    ///
+    /// The node is added on top of the other nodes with a z-index of `0`.
+    ///
+    /// [`Scene`]: struct.Scene.html
+    /// [`NodeId`]: struct.NodeId.html
+    pub fn add(&mut self, draw: impl Fn(&mut Frame) + 'static) -> NodeId {
+        let id = NodeId(self.next_id);
+        self.next_id += 1;
+
+        let _ = self.nodes.insert(
+            id,
+            Node {
+                draw: Box::new(draw),
+                transform: Matrix::identity(),
+                z_index: 0,
+                primitive: RefCell::new(None),
+            },
+        );
+
+        id
+    }
+
+    /// Removes a node from the [`Scene`].
+    ///
+    /// Returns `false` if the node was not part of the [`Scene`].
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn remove(&mut self, id: NodeId) -> bool {
+        self.nodes.remove(&id).is_some()
+    }
+
+    /// Returns whether the [`Scene`] contains the given node.
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.nodes.contains_key(&id)
+    }
+
+    /// Returns the amount of nodes in the [`Scene`].
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether the [`Scene`] has no nodes.
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Removes every node of the [`Scene`].
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    /// Replaces the closure used to draw a node of the [`Scene`].
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn set_draw(
+        &mut self,
+        id: NodeId,
+        draw: impl Fn(&mut Frame) + 'static,
+    ) {
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.draw = Box::new(draw);
+            node.invalidate();
+        }
+    }
+
+    /// Sets the transform of a node of the [`Scene`].
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn set_transform(&mut self, id: NodeId, transform: Matrix) {
+        if let Some(node) = self.nodes.get_mut(&id) {
+            if node.transform != transform {
+                node.transform = transform;
+                node.invalidate();
+            }
+        }
+    }
+
+    /// Returns the transform of a node of the [`Scene`], if it exists.
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn transform(&self, id: NodeId) -> Option<Matrix> {
+        self.nodes.get(&id).map(|node| node.transform)
+    }
+
+    /// Sets the z-index of a node of the [`Scene`].
+    ///
+    /// Changing the z-index of a node does not redraw it.
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn set_z_index(&mut self, id: NodeId, z_index: i32) {
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.z_index = z_index;
+        }
+    }
+
+    /// Returns the z-index of a node of the [`Scene`], if it exists.
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn z_index(&self, id: NodeId) -> Option<i32> {
+        self.nodes.get(&id).map(|node| node.z_index)
+    }
+
+    /// Forces a node of the [`Scene`] to be redrawn the next time the
+    /// [`Scene`] is drawn.
+    ///
+    /// This is useful when the closure of the node captures shared state
+    /// that has changed.
+    ///
+    /// [`Scene`]: struct.Scene.html
+    pub fn invalidate(&mut self, id: NodeId) {
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.invalidate();
+        }
+    }
+
+    /// Draws the [`Scene`], producing some [`Geometry`].
+    ///
+    /// Only the nodes that have changed since the last draw call are redrawn,
+    /// unless the bounds have changed.
+    ///
+    /// [`Scene`]: struct.Scene.html
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn draw(&self, bounds: Size) -> Geometry {
+        let mut cached_bounds = self.bounds.borrow_mut();
+
+        if *cached_bounds != Some(bounds) {
+            for node in self.nodes.values() {
+                *node.primitive.borrow_mut() = None;
+            }
+
+            *cached_bounds = Some(bounds);
+        }
+
+        let mut nodes: Vec<&Node> = self.nodes.values().collect();
+
+        // The sort is stable, so insertion order is kept for equal z-indices
+        nodes.sort_by_key(|node| node.z_index);
+
+        let primitives = nodes
+            .into_iter()
+            .map(|node| Primitive::Cached {
+                cache: node.primitive(bounds),
+            })
+            .collect();
+
+        Geometry::from_primitive(Primitive::Group { primitives })
+    }
+}
+
+impl Node {
+    fn invalidate(&mut self) {
+        *self.primitive.get_mut() = None;
+    }
+
+    fn primitive(&self, bounds: Size) -> Arc<Primitive> {
+        let mut primitive = self.primitive.borrow_mut();
+
+        if let Some(primitive) = &*primitive {
+            return primitive.clone();
+        }
+
+        let mut frame = Frame::new(bounds);
+
+        frame.transform(self.transform);
+        (self.draw)(&mut frame);
+
+        let new_primitive = Arc::new(frame.into_geometry().into_primitive());
+        *primitive = Some(new_primitive.clone());
+
+        new_primitive
+    }
+}
+
+impl std::fmt::Debug for Scene {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scene")
+            .field("nodes", &self.nodes)
+            .field("bounds", &self.bounds)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Node")
+            .field("transform", &self.transform)
+            .field("z_index", &self.z_index)
+            .field("primitive", &self.primitive)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::Path;
+
+    use iced_native::{Color, Point};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn only_changed_nodes_are_redrawn() {
+        let draws = Rc::new(Cell::new(0));
+        let mut scene = Scene::new();
+
+        let nodes: Vec<NodeId> = (0..10)
+            .map(|i| {
+                let draws = draws.clone();
+
+                scene.add(move |frame| {
+                    draws.set(draws.get() + 1);
+
+                    frame.fill(
+                        &Path::rectangle(
+                            Point::new(i as f32, 0.0),
+                            Size::new(1.0, 1.0),
+                        ),
+                        Color::BLACK,
+                    );
+                })
+            })
+            .collect();
+
+        let size = Size::new(10.0, 1.0);
+
+        let _ = scene.draw(size);
+        assert_eq!(draws.get(), 10);
+
+        scene.set_transform(
+            nodes[3],
+            Matrix::translation(iced_native::Vector::new(0.0, 1.0)),
+        );
+        scene.set_z_index(nodes[5], -1);
+        assert!(scene.remove(nodes[7]));
+
+        let geometry = scene.draw(size);
+        assert_eq!(draws.get(), 11);
+
+        match geometry.into_primitive() {
+            Primitive::Group { primitives } => {
+                assert_eq!(primitives.len(), 9)
+            }
+            _ => panic!("Scene should produce a group of primitives"),
+        }
+
+        let _ = scene.draw(Size::new(20.0, 1.0));
+        assert_eq!(draws.get(), 20);
+    }
+}
@@ -0,0 +1,246 @@
+use crate::canvas::FillRule;
+use crate::triangle;
+
+use iced_native::{Color, Vector};
+use lyon::math::Point;
+use lyon::tessellation::VertexBuffers;
+
+/// The style of a drop shadow.
+///
+/// A [`Shadow`] can be cast by any filled [`Path`] with
+/// [`Frame::fill_with_shadow`].
+///
+/// [`Shadow`]: struct.Shadow.html
+/// [`Path`]: path/struct.Path.html
+/// [`Frame::fill_with_shadow`]: struct.Frame.html#method.fill_with_shadow
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// The offset of the shadow from the shape casting it.
+    pub offset: Vector,
+    /// The distance over which the edges of the shadow fade out.
+    pub blur_radius: f32,
+    /// The color of the shadow.
+    pub color: Color,
+}
+
+impl Shadow {
+    /// Sets the offset of the [`Shadow`].
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub fn with_offset(self, offset: Vector) -> Self {
+        Shadow { offset, ..self }
+    }
+
+    /// Sets the blur radius of the [`Shadow`].
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub fn with_blur_radius(self, blur_radius: f32) -> Self {
+        Shadow {
+            blur_radius,
+            ..self
+        }
+    }
+
+    /// Sets the color of the [`Shadow`].
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub fn with_color(self, color: Color) -> Self {
+        Shadow { color, ..self }
+    }
+}
+
+impl Default for Shadow {
+    fn default() -> Shadow {
+        Shadow {
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 4.0,
+            color: Color {
+                a: 0.3,
+                ..Color::BLACK
+            },
+        }
+    }
+}
+
+/// Produces the blurred edges of a shadow cast by the given contours.
+///
+/// The edges fade out linearly from the given color to full transparency
+/// over the given radius, outside of the filled area.
+pub(crate) fn blur(
+    contours: &[Vec<Point>],
+    rule: FillRule,
+    radius: f32,
+    color: [f32; 4],
+    buffers: &mut VertexBuffers<triangle::Vertex2D, u32>,
+) {
+    if radius <= 0.0 {
+        return;
+    }
+
+    let transparent = [color[0], color[1], color[2], 0.0];
+
+    let mut vertex = |point: Point, color: [f32; 4]| {
+        buffers.vertices.push(triangle::Vertex2D {
+            position: [point.x, point.y],
+            color,
+        });
+
+        buffers.vertices.len() as u32 - 1
+    };
+
+    let mut triangles = Vec::new();
+
+    for contour in contours {
+        let side = match outward_side(contour, contours, rule) {
+            Some(side) => side,
+            None => continue,
+        };
+
+        let normal = |from: Point, to: Point| {
+            let direction = (to - from).normalize();
+
+            lyon::math::Vector::new(direction.y, -direction.x) * side
+        };
+
+        for (i, &from) in contour.iter().enumerate() {
+            let to = contour[(i + 1) % contour.len()];
+            let previous = contour[(i + contour.len() - 1) % contour.len()];
+
+            let edge_normal = normal(from, to);
+            let previous_normal = normal(previous, from);
+
+            let a = vertex(from, color);
+            let b = vertex(to, color);
+            let c = vertex(to + edge_normal * radius, transparent);
+            let d = vertex(from + edge_normal * radius, transparent);
+
+            triangles.extend_from_slice(&[a, b, c, a, c, d]);
+
+            // Convex corners are rounded with a fan of triangles
+            let turn = (from - previous).cross(to - from) * side;
+
+            if turn > 0.0 {
+                let angle = previous_normal
+                    .cross(edge_normal)
+                    .atan2(previous_normal.dot(edge_normal));
+
+                let steps = (angle.abs() / (std::f32::consts::PI / 8.0))
+                    .ceil()
+                    .max(1.0) as usize;
+
+                let mut last =
+                    vertex(from + previous_normal * radius, transparent);
+
+                for step in 1..=steps {
+                    let (sin, cos) =
+                        (angle * step as f32 / steps as f32).sin_cos();
+
+                    let rotated = lyon::math::Vector::new(
+                        previous_normal.x * cos - previous_normal.y * sin,
+                        previous_normal.x * sin + previous_normal.y * cos,
+                    );
+
+                    let next = vertex(from + rotated * radius, transparent);
+
+                    triangles.extend_from_slice(&[a, last, next]);
+                    last = next;
+                }
+            }
+        }
+    }
+
+    buffers.indices.extend(triangles);
+}
+
+/// Returns the sign that turns the right-hand normals of the edges of a
+/// contour into normals pointing away from the filled area.
+fn outward_side(
+    contour: &[Point],
+    contours: &[Vec<Point>],
+    rule: FillRule,
+) -> Option<f32> {
+    const EPSILON: f32 = 1.0e-2;
+
+    let (from, to) = contour
+        .iter()
+        .zip(contour.iter().cycle().skip(1))
+        .find(|(from, to)| (**to - **from).length() > EPSILON)?;
+
+    let direction = (*to - *from).normalize();
+    let probe = from.lerp(*to, 0.5)
+        + lyon::math::Vector::new(direction.y, -direction.x) * EPSILON;
+
+    let winding = winding_number(probe, contours);
+
+    let is_inside = match rule {
+        FillRule::NonZero => winding != 0,
+        FillRule::EvenOdd => winding % 2 != 0,
+    };
+
+    Some(if is_inside { -1.0 } else { 1.0 })
+}
+
+fn winding_number(point: Point, contours: &[Vec<Point>]) -> i32 {
+    let mut winding = 0;
+
+    for contour in contours {
+        for (from, to) in contour.iter().zip(contour.iter().cycle().skip(1)) {
+            let side = (*to - *from).cross(point - *from);
+
+            if from.y <= point.y {
+                if to.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if to.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+
+    winding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::{Frame, Path};
+
+    use iced_native::Size;
+
+    #[test]
+    fn shadow_fades_out_around_the_shape() {
+        let mut frame = Frame::new(Size::new(20.0, 20.0));
+
+        frame.fill_with_shadow(
+            &Path::rectangle(
+                iced_native::Point::new(5.0, 5.0),
+                Size::new(6.0, 6.0),
+            ),
+            Color::TRANSPARENT,
+            Shadow {
+                offset: Vector::new(2.0, 2.0),
+                blur_radius: 4.0,
+                color: Color::BLACK,
+            },
+        );
+
+        let pixels = frame
+            .into_geometry()
+            .to_rgba(20, 20)
+            .expect("Rasterize shadow")
+            .rgba;
+        let alpha = |x: usize, y: usize| pixels[(y * 20 + x) * 4 + 3];
+
+        assert_eq!(alpha(10, 10), 255);
+        assert_eq!(alpha(2, 10), 0);
+        assert_eq!(alpha(18, 18), 0);
+
+        // Alpha decreases as we move away from the offset shape
+        assert!(alpha(13, 10) > alpha(14, 10));
+        assert!(alpha(14, 10) > alpha(15, 10));
+        assert!(alpha(15, 10) > 0);
+
+        // Corners are rounded
+        assert!(alpha(15, 15) < alpha(15, 10));
+    }
+}
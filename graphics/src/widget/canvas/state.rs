@@ -0,0 +1,102 @@
+use iced_native::touch::{self, gesture, Gesture};
+use iced_native::{focus, keyboard, mouse, Point};
+
+use std::time::Instant;
+
+/// The local state of a [`Canvas`].
+///
+/// A [`Canvas`] only needs a [`State`] to detect double and triple clicks,
+/// to keep track of the pressed modifier keys, to recognize touch gestures
+/// and to take the keyboard focus. Without it, every [`Event::Click`] is
+/// reported as a single click with no modifiers, and no [`Event::Gesture`] or
+/// [`Event::Keyboard`] is produced.
+///
+/// [`Canvas`]: struct.Canvas.html
+/// [`State`]: struct.State.html
+/// [`Event::Click`]: enum.Event.html#variant.Click
+/// [`Event::Gesture`]: enum.Event.html#variant.Gesture
+/// [`Event::Keyboard`]: enum.Event.html#variant.Keyboard
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    last_click: Option<(mouse::Button, mouse::Click)>,
+    modifiers: keyboard::ModifiersState,
+    gestures: gesture::Recognizer,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    pub(crate) fn set_focused(&mut self, is_focused: bool) {
+        self.is_focused = is_focused;
+    }
+
+    pub(crate) fn modifiers(&self) -> keyboard::ModifiersState {
+        self.modifiers
+    }
+
+    pub(crate) fn track_modifiers(&mut self, event: keyboard::Event) {
+        match event {
+            keyboard::Event::KeyPressed { modifiers, .. }
+            | keyboard::Event::KeyReleased { modifiers, .. }
+            | keyboard::Event::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers;
+            }
+            keyboard::Event::CharacterReceived(_) => {}
+        }
+    }
+
+    pub(crate) fn recognize(
+        &mut self,
+        event: touch::Event,
+        now: Instant,
+    ) -> Vec<Gesture> {
+        self.gestures.update(event, now)
+    }
+
+    pub(crate) fn tick(&mut self, now: Instant) -> Option<Gesture> {
+        self.gestures.tick(now)
+    }
+
+    pub(crate) fn is_waiting_for_gesture(&self) -> bool {
+        self.gestures.is_waiting()
+    }
+
+    pub(crate) fn click(
+        &mut self,
+        button: mouse::Button,
+        position: Point,
+    ) -> mouse::click::Kind {
+        let previous = match self.last_click {
+            Some((last_button, last_click)) if last_button == button => {
+                Some(last_click)
+            }
+            _ => None,
+        };
+
+        let click = mouse::Click::new(position, previous);
+
+        self.last_click = Some((button, click));
+
+        click.kind()
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
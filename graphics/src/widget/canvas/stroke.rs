@@ -2,7 +2,7 @@ use iced_native::Color;
 
 /// The style of a stroke.
 #[derive(Debug, Clone, Copy)]
-pub struct Stroke {
+pub struct Stroke<'a> {
     /// The color of the stroke.
     pub color: Color,
     /// The distance between the two edges of the stroke.
@@ -12,20 +12,22 @@ pub struct Stroke {
     /// The shape to be used at the corners of paths or basic shapes when they
     /// are stroked.
     pub line_join: LineJoin,
+    /// The dash pattern used when stroking the line.
+    pub line_dash: LineDash<'a>,
 }
 
-impl Stroke {
+impl<'a> Stroke<'a> {
     /// Sets the color of the [`Stroke`].
     ///
     /// [`Stroke`]: struct.Stroke.html
-    pub fn with_color(self, color: Color) -> Stroke {
+    pub fn with_color(self, color: Color) -> Self {
         Stroke { color, ..self }
     }
 
     /// Sets the width of the [`Stroke`].
     ///
     /// [`Stroke`]: struct.Stroke.html
-    pub fn with_width(self, width: f32) -> Stroke {
+    pub fn with_width(self, width: f32) -> Self {
         Stroke { width, ..self }
     }
 
@@ -33,7 +35,7 @@ impl Stroke {
     ///
     /// [`LineCap`]: enum.LineCap.html
     /// [`Stroke`]: struct.Stroke.html
-    pub fn with_line_cap(self, line_cap: LineCap) -> Stroke {
+    pub fn with_line_cap(self, line_cap: LineCap) -> Self {
         Stroke { line_cap, ..self }
     }
 
@@ -41,18 +43,27 @@ impl Stroke {
     ///
     /// [`LineJoin`]: enum.LineJoin.html
     /// [`Stroke`]: struct.Stroke.html
-    pub fn with_line_join(self, line_join: LineJoin) -> Stroke {
+    pub fn with_line_join(self, line_join: LineJoin) -> Self {
         Stroke { line_join, ..self }
     }
+
+    /// Sets the [`LineDash`] of the [`Stroke`].
+    ///
+    /// [`LineDash`]: struct.LineDash.html
+    /// [`Stroke`]: struct.Stroke.html
+    pub fn with_line_dash(self, line_dash: LineDash<'a>) -> Self {
+        Stroke { line_dash, ..self }
+    }
 }
 
-impl Default for Stroke {
-    fn default() -> Stroke {
+impl<'a> Default for Stroke<'a> {
+    fn default() -> Stroke<'a> {
         Stroke {
             color: Color::BLACK,
             width: 1.0,
             line_cap: LineCap::default(),
             line_join: LineJoin::default(),
+            line_dash: LineDash::default(),
         }
     }
 }
@@ -113,3 +124,21 @@ impl From<LineJoin> for lyon::tessellation::LineJoin {
         }
     }
 }
+
+/// The dash pattern used when stroking the line.
+///
+/// A solid line is drawn when no segments are provided.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LineDash<'a> {
+    /// The alternating lengths of lines and gaps which describe the pattern.
+    ///
+    /// If an odd number of lengths is provided, the list is repeated to
+    /// produce an even number of entries. For instance, `[5.0]` is
+    /// equivalent to `[5.0, 5.0]`.
+    pub segments: &'a [f32],
+
+    /// The index of the segment in [`segments`] where the pattern starts.
+    ///
+    /// [`segments`]: #structfield.segments
+    pub offset: usize,
+}
@@ -0,0 +1,44 @@
+//! Plot data in line, bar, scatter, and pie charts.
+//!
+//! Charts are built on top of a [`Canvas`]. Cartesian charts draw their axes,
+//! ticks, and a legend, show a tooltip when hovering their data, and can be
+//! zoomed with the mouse wheel and panned by dragging them.
+//!
+//! # Example
+//! ```
+//! # mod iced {
+//! #     pub use iced_graphics::chart;
+//! #     pub use iced_native::Color;
+//! # }
+//! use iced::chart::{self, LineChart, Series};
+//! use iced::Color;
+//!
+//! let mut state = chart::State::new();
+//!
+//! let series = vec![Series::new(
+//!     "Temperature",
+//!     Color::from_rgb(0.8, 0.3, 0.2),
+//!     vec![(0.0, 12.5), (1.0, 14.0), (2.0, 13.2), (3.0, 16.8)],
+//! )];
+//!
+//! let chart = LineChart::new(&mut state, &series);
+//! ```
+//!
+//! [`Canvas`]: ../canvas/struct.Canvas.html
+mod axis;
+mod bar;
+mod cartesian;
+mod line;
+mod pie;
+mod scatter;
+mod series;
+mod state;
+
+pub use bar::BarChart;
+pub use line::LineChart;
+pub use pie::PieChart;
+pub use scatter::ScatterChart;
+pub use series::{Series, Slice};
+pub use state::State;
+
+pub use iced_style::chart::{Style, StyleSheet};
@@ -0,0 +1,39 @@
+/// Returns evenly spaced values inside the given range, at round intervals,
+/// along with the interval.
+///
+/// The interval is a power of ten multiplied by 1, 2, or 5, chosen so there
+/// are about `count` ticks.
+pub(crate) fn ticks(min: f32, max: f32, count: usize) -> (Vec<f32>, f32) {
+    let span = max - min;
+
+    if !span.is_finite() || span <= 0.0 {
+        return (vec![min], 1.0);
+    }
+
+    let raw_step = span / count.max(1) as f32;
+    let magnitude = 10.0_f32.powf(raw_step.log10().floor());
+    let residual = raw_step / magnitude;
+
+    let step = magnitude
+        * if residual > 5.0 {
+            10.0
+        } else if residual > 2.0 {
+            5.0
+        } else if residual > 1.0 {
+            2.0
+        } else {
+            1.0
+        };
+
+    let first = (min / step).ceil() as i64;
+    let last = (max / step).floor() as i64;
+
+    ((first..=last).map(|i| i as f32 * step).collect(), step)
+}
+
+/// Formats a tick value with as many decimals as its interval needs.
+pub(crate) fn format(value: f32, step: f32) -> String {
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+
+    format!("{:.*}", decimals, value)
+}
@@ -0,0 +1,264 @@
+use crate::backend::{self, Backend};
+use crate::canvas::{
+    Canvas, Cursor, Event, Frame, Geometry, Measurer, Path, Program, Stroke,
+};
+use crate::chart::state::Domain;
+use crate::chart::{cartesian, Series, State, StyleSheet};
+use crate::Renderer;
+use iced_native::{mouse, Element, Length, Rectangle, Vector};
+
+/// A chart plotting each [`Series`] as bars grouped by category.
+///
+/// The horizontal coordinate of every point is the index of its category.
+/// Therefore, the [`Series`] of a [`BarChart`] are most easily created with
+/// [`Series::from_values`].
+///
+/// It can be zoomed and panned, and shows the value of the bar under the
+/// cursor in a tooltip.
+///
+/// [`Series`]: struct.Series.html
+/// [`BarChart`]: struct.BarChart.html
+/// [`Series::from_values`]: struct.Series.html#method.from_values
+#[allow(missing_debug_implementations)]
+pub struct BarChart<'a> {
+    state: &'a mut State,
+    series: &'a [Series],
+    categories: Vec<String>,
+    width: Length,
+    height: Length,
+    style: Box<dyn StyleSheet>,
+}
+
+impl<'a> BarChart<'a> {
+    /// Creates a new [`BarChart`] plotting the given [`Series`].
+    ///
+    /// [`BarChart`]: struct.BarChart.html
+    /// [`Series`]: struct.Series.html
+    pub fn new(state: &'a mut State, series: &'a [Series]) -> Self {
+        BarChart {
+            state,
+            series,
+            categories: Vec::new(),
+            width: Length::Fill,
+            height: Length::Units(300),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the labels of the categories of the [`BarChart`].
+    ///
+    /// By default, categories are labeled with their index.
+    ///
+    /// [`BarChart`]: struct.BarChart.html
+    pub fn categories(mut self, categories: Vec<String>) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    /// Sets the width of the [`BarChart`].
+    ///
+    /// [`BarChart`]: struct.BarChart.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`BarChart`].
+    ///
+    /// [`BarChart`]: struct.BarChart.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`BarChart`].
+    ///
+    /// [`BarChart`]: struct.BarChart.html
+    pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a> BarChart<'a> {
+    const GROUP_WIDTH: f32 = 0.8;
+
+    fn domain(&self) -> Domain {
+        let mut domain = cartesian::domain(self.series);
+
+        let (min, max) = self
+            .series
+            .iter()
+            .flat_map(|series| series.points.iter())
+            .fold((0.0_f32, 0.0_f32), |(min, max), (_, y)| {
+                (min.min(*y), max.max(*y))
+            });
+
+        let padding = (max - min) * 0.05;
+
+        domain.x = (domain.x.0 - 0.5, domain.x.1 + 0.5);
+        domain.y = cartesian::widen((
+            if min < 0.0 { min - padding } else { 0.0 },
+            if max > 0.0 { max + padding } else { 0.0 },
+        ));
+
+        domain
+    }
+
+    fn category(&self, x: f32) -> String {
+        self.categories
+            .get(x.round() as usize)
+            .cloned()
+            .unwrap_or_else(|| x.to_string())
+    }
+
+    fn ticks(&self, plane: &cartesian::Plane) -> Vec<(f32, String)> {
+        let domain = self.domain();
+
+        let first = plane.x.0.max(domain.x.0 + 0.5).ceil() as i64;
+        let last = plane.x.1.min(domain.x.1 - 0.5).floor() as i64;
+
+        let count = (last - first + 1).max(1);
+        let fitting = (plane.area.width / 60.0).max(1.0) as i64;
+        let stride = ((count + fitting - 1) / fitting) as usize;
+
+        (first..=last)
+            .step_by(stride.max(1))
+            .map(|x| (x as f32, self.category(x as f32)))
+            .collect()
+    }
+
+    fn bars<'b>(
+        &'b self,
+        plane: &'b cartesian::Plane,
+    ) -> impl Iterator<Item = (&'a Series, (f32, f32), Rectangle)> + 'b {
+        let width = Self::GROUP_WIDTH / self.series.len().max(1) as f32;
+
+        self.series.iter().enumerate().flat_map(move |(i, series)| {
+            series.points.iter().map(move |&(x, y)| {
+                let left = x - Self::GROUP_WIDTH / 2.0 + i as f32 * width;
+
+                let a = plane.project((left, y));
+                let b = plane.project((left + width, 0.0));
+
+                let bar = Rectangle {
+                    x: a.x.min(b.x),
+                    y: a.y.min(b.y),
+                    width: (b.x - a.x).abs(),
+                    height: (b.y - a.y).abs(),
+                };
+
+                (series, (x, y), bar)
+            })
+        })
+    }
+}
+
+impl<'a, Message> Program<Message> for BarChart<'a> {
+    fn update(
+        &mut self,
+        event: Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<Message> {
+        let area = cartesian::area(bounds.size(), &self.style.style());
+
+        self.state
+            .update(event, bounds, cursor, self.domain(), area);
+
+        None
+    }
+
+    fn draw_with_measurer(
+        &self,
+        bounds: Rectangle,
+        cursor: Cursor,
+        measurer: &Measurer<'_>,
+    ) -> Vec<Geometry> {
+        let style = self.style.style();
+        let mut frame = Frame::new(bounds.size());
+
+        let area = cartesian::area(bounds.size(), &style);
+        let plane =
+            cartesian::Plane::new(area, self.state.visible(self.domain()));
+
+        cartesian::draw_axes(&mut frame, &plane, self.ticks(&plane), &style);
+
+        let bars: Vec<_> = self.bars(&plane).collect();
+
+        let hovered = cursor.position_in(&bounds).and_then(|cursor| {
+            if area.contains(cursor) {
+                bars.iter().find(|(_, _, bar)| bar.contains(cursor))
+            } else {
+                None
+            }
+        });
+
+        frame.with_clip(area, |frame| {
+            frame.translate(Vector::new(-area.x, -area.y));
+
+            for (series, _, bar) in &bars {
+                frame.fill_rectangle(bar.position(), bar.size(), series.color);
+            }
+
+            if let Some((_, _, bar)) = hovered {
+                frame.stroke(
+                    &Path::rectangle(bar.position(), bar.size()),
+                    Stroke::default()
+                        .with_color(style.axis_color)
+                        .with_width(2.0),
+                );
+            }
+        });
+
+        cartesian::draw_legend(
+            &mut frame,
+            cartesian::legend_origin(),
+            self.series
+                .iter()
+                .map(|series| (series.label.as_str(), series.color)),
+            measurer,
+            &style,
+        );
+
+        if let (Some((series, (x, y), _)), Some(cursor)) =
+            (hovered, cursor.position_in(&bounds))
+        {
+            cartesian::draw_tooltip(
+                &mut frame,
+                cursor,
+                &format!("{}\n{}: {}", series.label, self.category(*x), y),
+                measurer,
+                &style,
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        cartesian::mouse_interaction(
+            self.state,
+            bounds,
+            cursor,
+            &self.style.style(),
+        )
+    }
+}
+
+impl<'a, Message, B> From<BarChart<'a>> for Element<'a, Message, Renderer<B>>
+where
+    Message: 'static,
+    B: Backend + backend::Text,
+{
+    fn from(chart: BarChart<'a>) -> Element<'a, Message, Renderer<B>> {
+        let width = chart.width;
+        let height = chart.height;
+
+        Canvas::new(chart).width(width).height(height).into()
+    }
+}
@@ -0,0 +1,340 @@
+use crate::canvas::{Cursor, Frame, Measurer, Path, Stroke, Text};
+use crate::chart::axis;
+use crate::chart::state::Domain;
+use crate::chart::{Series, State, Style};
+use iced_native::{
+    mouse, Color, Font, HorizontalAlignment, Point, Rectangle, Size, Vector,
+    VerticalAlignment,
+};
+
+const MARGIN_LEFT: f32 = 50.0;
+const MARGIN_RIGHT: f32 = 15.0;
+const MARGIN_TOP: f32 = 15.0;
+const MARGIN_BOTTOM: f32 = 30.0;
+const LEGEND_SPACING: f32 = 15.0;
+const TOOLTIP_PADDING: f32 = 6.0;
+
+/// The visible part of a [`Domain`] mapped to an area of a canvas.
+///
+/// [`Domain`]: ../state/struct.Domain.html
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Plane {
+    pub area: Rectangle,
+    pub x: (f32, f32),
+    pub y: (f32, f32),
+}
+
+impl Plane {
+    pub fn new(area: Rectangle, domain: Domain) -> Self {
+        Plane {
+            area,
+            x: domain.x,
+            y: domain.y,
+        }
+    }
+
+    pub fn project(&self, (x, y): (f32, f32)) -> Point {
+        Point::new(
+            self.area.x
+                + (x - self.x.0) / (self.x.1 - self.x.0) * self.area.width,
+            self.area.y + self.area.height
+                - (y - self.y.0) / (self.y.1 - self.y.0) * self.area.height,
+        )
+    }
+}
+
+/// Returns the area where the data of a cartesian chart is plotted, relative
+/// to the bounds of its canvas.
+pub(crate) fn area(size: Size, style: &Style) -> Rectangle {
+    let legend = f32::from(style.text_size) + 10.0;
+
+    Rectangle {
+        x: MARGIN_LEFT,
+        y: MARGIN_TOP + legend,
+        width: (size.width - MARGIN_LEFT - MARGIN_RIGHT).max(1.0),
+        height: (size.height - MARGIN_TOP - MARGIN_BOTTOM - legend).max(1.0),
+    }
+}
+
+/// Returns the [`Domain`] of all the points of the given [`Series`], leaving
+/// some room above and below them.
+///
+/// [`Domain`]: ../state/struct.Domain.html
+/// [`Series`]: ../struct.Series.html
+pub(crate) fn domain(series: &[Series]) -> Domain {
+    let mut points = series.iter().flat_map(|series| series.points.iter());
+
+    let first = match points.next() {
+        Some(point) => *point,
+        None => {
+            return Domain {
+                x: (0.0, 1.0),
+                y: (0.0, 1.0),
+            }
+        }
+    };
+
+    let (x, y) = points.fold(
+        ((first.0, first.0), (first.1, first.1)),
+        |(x, y), (px, py)| {
+            ((x.0.min(*px), x.1.max(*px)), (y.0.min(*py), y.1.max(*py)))
+        },
+    );
+
+    let padding = (y.1 - y.0) * 0.05;
+
+    Domain {
+        x: widen(x),
+        y: widen((y.0 - padding, y.1 + padding)),
+    }
+}
+
+/// Makes sure the given range is not empty.
+pub(crate) fn widen((min, max): (f32, f32)) -> (f32, f32) {
+    if max > min {
+        (min, max)
+    } else {
+        (min - 1.0, max + 1.0)
+    }
+}
+
+/// Returns the ticks of a numeric range, along with their labels.
+pub(crate) fn numeric_ticks(
+    (min, max): (f32, f32),
+    count: usize,
+) -> Vec<(f32, String)> {
+    let (ticks, step) = axis::ticks(min, max, count);
+
+    ticks
+        .into_iter()
+        .map(|tick| (tick, axis::format(tick, step)))
+        .collect()
+}
+
+/// Draws the grid, the axes, and the tick labels of a [`Plane`].
+///
+/// [`Plane`]: struct.Plane.html
+pub(crate) fn draw_axes(
+    frame: &mut Frame,
+    plane: &Plane,
+    x_ticks: Vec<(f32, String)>,
+    style: &Style,
+) {
+    let area = plane.area;
+    let bottom = area.y + area.height;
+    let text_size = f32::from(style.text_size);
+
+    let grid = Stroke::default().with_color(style.grid_color);
+
+    for (tick, label) in x_ticks {
+        let x = plane.project((tick, plane.y.0)).x;
+
+        frame.stroke(
+            &Path::line(Point::new(x, area.y), Point::new(x, bottom)),
+            grid,
+        );
+
+        frame.stroke(
+            &Path::line(Point::new(x, bottom), Point::new(x, bottom + 4.0)),
+            Stroke::default().with_color(style.axis_color),
+        );
+
+        frame.fill_text(Text {
+            content: label,
+            position: Point::new(x, bottom + 6.0),
+            color: style.text_color,
+            size: text_size,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Top,
+            ..Text::default()
+        });
+    }
+
+    let y_ticks =
+        numeric_ticks(plane.y, (area.height / 40.0).max(2.0) as usize);
+
+    for (tick, label) in y_ticks {
+        let y = plane.project((plane.x.0, tick)).y;
+
+        frame.stroke(
+            &Path::line(
+                Point::new(area.x, y),
+                Point::new(area.x + area.width, y),
+            ),
+            grid,
+        );
+
+        frame.stroke(
+            &Path::line(Point::new(area.x - 4.0, y), Point::new(area.x, y)),
+            Stroke::default().with_color(style.axis_color),
+        );
+
+        frame.fill_text(Text {
+            content: label,
+            position: Point::new(area.x - 6.0, y),
+            color: style.text_color,
+            size: text_size,
+            horizontal_alignment: HorizontalAlignment::Right,
+            vertical_alignment: VerticalAlignment::Center,
+            ..Text::default()
+        });
+    }
+
+    let axes = Path::new(|p| {
+        p.move_to(Point::new(area.x, area.y));
+        p.line_to(Point::new(area.x, bottom));
+        p.line_to(Point::new(area.x + area.width, bottom));
+    });
+
+    frame.stroke(&axes, Stroke::default().with_color(style.axis_color));
+}
+
+/// Draws a row of legend entries, each one a colored square followed by a
+/// label.
+pub(crate) fn draw_legend<'a>(
+    frame: &mut Frame,
+    origin: Point,
+    entries: impl Iterator<Item = (&'a str, Color)>,
+    measurer: &Measurer<'_>,
+    style: &Style,
+) {
+    let text_size = f32::from(style.text_size);
+    let mut x = origin.x;
+
+    for (label, color) in entries {
+        frame.fill_rectangle(
+            Point::new(x, origin.y + (text_size - 10.0) / 2.0),
+            Size::new(10.0, 10.0),
+            color,
+        );
+
+        x += 15.0;
+
+        frame.fill_text(Text {
+            content: label.to_string(),
+            position: Point::new(x, origin.y),
+            color: style.text_color,
+            size: text_size,
+            ..Text::default()
+        });
+
+        x += measurer.measure_text(label, text_size, Font::Default).width
+            + LEGEND_SPACING;
+    }
+}
+
+/// Draws a tooltip with the given contents next to the cursor, keeping it
+/// inside the frame.
+///
+/// The tooltip is drawn on its own layer, on top of any text drawn before.
+pub(crate) fn draw_tooltip(
+    frame: &mut Frame,
+    cursor: Point,
+    content: &str,
+    measurer: &Measurer<'_>,
+    style: &Style,
+) {
+    let text_size = f32::from(style.text_size);
+    let size = measurer.measure_text(content, text_size, Font::Default);
+
+    let width = size.width + TOOLTIP_PADDING * 2.0;
+    let height = size.height + TOOLTIP_PADDING * 2.0;
+
+    let x = if cursor.x + 12.0 + width > frame.width() {
+        (cursor.x - 12.0 - width).max(0.0)
+    } else {
+        cursor.x + 12.0
+    };
+
+    let y = if cursor.y + 12.0 + height > frame.height() {
+        (cursor.y - 12.0 - height).max(0.0)
+    } else {
+        cursor.y + 12.0
+    };
+
+    let region = Rectangle::with_size(frame.size());
+
+    frame.with_clip(region, |frame| {
+        frame.translate(Vector::new(x, y));
+
+        let background =
+            Path::rectangle(Point::ORIGIN, Size::new(width, height));
+
+        frame.fill(&background, style.tooltip_background);
+        frame.stroke(
+            &background,
+            Stroke::default().with_color(style.tooltip_border_color),
+        );
+
+        frame.fill_text(Text {
+            content: content.to_string(),
+            position: Point::new(TOOLTIP_PADDING, TOOLTIP_PADDING),
+            color: style.tooltip_text_color,
+            size: text_size,
+            ..Text::default()
+        });
+    });
+}
+
+/// Returns the position of the legend of a cartesian chart.
+pub(crate) fn legend_origin() -> Point {
+    Point::new(MARGIN_LEFT, MARGIN_TOP / 2.0)
+}
+
+/// Returns the point of the given [`Series`] closest to the cursor, as long
+/// as it is near enough to be hovered.
+///
+/// [`Series`]: ../struct.Series.html
+pub(crate) fn hovered_point<'a>(
+    series: &'a [Series],
+    plane: &Plane,
+    cursor: Point,
+) -> Option<(&'a Series, (f32, f32), Point)> {
+    const HOVER_DISTANCE: f32 = 8.0;
+
+    if !plane.area.contains(cursor) {
+        return None;
+    }
+
+    series
+        .iter()
+        .flat_map(|series| {
+            series
+                .points
+                .iter()
+                .map(move |point| (series, *point, plane.project(*point)))
+        })
+        .map(|(series, point, position)| {
+            (series, point, position, position.distance(cursor))
+        })
+        .filter(|(_, _, _, distance)| *distance <= HOVER_DISTANCE)
+        .min_by(|a, b| {
+            a.3.partial_cmp(&b.3).unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(series, point, position, _)| (series, point, position))
+}
+
+/// Returns the contents of the tooltip of a hovered point.
+pub(crate) fn point_tooltip(series: &Series, (x, y): (f32, f32)) -> String {
+    format!("{}\nx: {}\ny: {}", series.label, x, y)
+}
+
+/// Returns the mouse interaction of a cartesian chart.
+pub(crate) fn mouse_interaction(
+    state: &State,
+    bounds: Rectangle,
+    cursor: Cursor,
+    style: &Style,
+) -> mouse::Interaction {
+    if state.is_panning() {
+        mouse::Interaction::Grabbing
+    } else if cursor
+        .position_in(&bounds)
+        .map(|position| area(bounds.size(), style).contains(position))
+        .unwrap_or(false)
+    {
+        mouse::Interaction::Crosshair
+    } else {
+        mouse::Interaction::default()
+    }
+}
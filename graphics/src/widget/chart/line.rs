@@ -0,0 +1,191 @@
+use crate::backend::{self, Backend};
+use crate::canvas::{
+    Canvas, Cursor, Event, Frame, Geometry, Measurer, Path, Program, Stroke,
+};
+use crate::chart::{cartesian, Series, State, StyleSheet};
+use crate::Renderer;
+use iced_native::{mouse, Element, Length, Rectangle, Vector};
+
+/// A chart plotting each [`Series`] as a line connecting its points.
+///
+/// It can be zoomed and panned, and shows the coordinates of the point under
+/// the cursor in a tooltip.
+///
+/// [`Series`]: struct.Series.html
+#[allow(missing_debug_implementations)]
+pub struct LineChart<'a> {
+    state: &'a mut State,
+    series: &'a [Series],
+    width: Length,
+    height: Length,
+    style: Box<dyn StyleSheet>,
+}
+
+impl<'a> LineChart<'a> {
+    /// Creates a new [`LineChart`] plotting the given [`Series`].
+    ///
+    /// [`LineChart`]: struct.LineChart.html
+    /// [`Series`]: struct.Series.html
+    pub fn new(state: &'a mut State, series: &'a [Series]) -> Self {
+        LineChart {
+            state,
+            series,
+            width: Length::Fill,
+            height: Length::Units(300),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`LineChart`].
+    ///
+    /// [`LineChart`]: struct.LineChart.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`LineChart`].
+    ///
+    /// [`LineChart`]: struct.LineChart.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`LineChart`].
+    ///
+    /// [`LineChart`]: struct.LineChart.html
+    pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message> Program<Message> for LineChart<'a> {
+    fn update(
+        &mut self,
+        event: Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<Message> {
+        let area = cartesian::area(bounds.size(), &self.style.style());
+
+        self.state.update(
+            event,
+            bounds,
+            cursor,
+            cartesian::domain(self.series),
+            area,
+        );
+
+        None
+    }
+
+    fn draw_with_measurer(
+        &self,
+        bounds: Rectangle,
+        cursor: Cursor,
+        measurer: &Measurer<'_>,
+    ) -> Vec<Geometry> {
+        let style = self.style.style();
+        let mut frame = Frame::new(bounds.size());
+
+        let area = cartesian::area(bounds.size(), &style);
+        let plane = cartesian::Plane::new(
+            area,
+            self.state.visible(cartesian::domain(self.series)),
+        );
+
+        cartesian::draw_axes(
+            &mut frame,
+            &plane,
+            cartesian::numeric_ticks(
+                plane.x,
+                (area.width / 80.0).max(2.0) as usize,
+            ),
+            &style,
+        );
+
+        let hovered = cursor.position_in(&bounds).and_then(|cursor| {
+            cartesian::hovered_point(self.series, &plane, cursor)
+        });
+
+        frame.with_clip(area, |frame| {
+            frame.translate(Vector::new(-area.x, -area.y));
+
+            for series in self.series {
+                let line = Path::new(|p| {
+                    let mut points =
+                        series.points.iter().map(|point| plane.project(*point));
+
+                    if let Some(first) = points.next() {
+                        p.move_to(first);
+                    }
+
+                    for point in points {
+                        p.line_to(point);
+                    }
+                });
+
+                frame.stroke(
+                    &line,
+                    Stroke::default().with_color(series.color).with_width(2.0),
+                );
+            }
+
+            if let Some((series, _, position)) = hovered {
+                frame.fill(&Path::circle(position, 5.0), series.color);
+            }
+        });
+
+        cartesian::draw_legend(
+            &mut frame,
+            cartesian::legend_origin(),
+            self.series
+                .iter()
+                .map(|series| (series.label.as_str(), series.color)),
+            measurer,
+            &style,
+        );
+
+        if let (Some((series, point, _)), Some(cursor)) =
+            (hovered, cursor.position_in(&bounds))
+        {
+            cartesian::draw_tooltip(
+                &mut frame,
+                cursor,
+                &cartesian::point_tooltip(series, point),
+                measurer,
+                &style,
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        cartesian::mouse_interaction(
+            self.state,
+            bounds,
+            cursor,
+            &self.style.style(),
+        )
+    }
+}
+
+impl<'a, Message, B> From<LineChart<'a>> for Element<'a, Message, Renderer<B>>
+where
+    Message: 'static,
+    B: Backend + backend::Text,
+{
+    fn from(chart: LineChart<'a>) -> Element<'a, Message, Renderer<B>> {
+        let width = chart.width;
+        let height = chart.height;
+
+        Canvas::new(chart).width(width).height(height).into()
+    }
+}
@@ -0,0 +1,247 @@
+use crate::backend::{self, Backend};
+use crate::canvas::{
+    Canvas, Cursor, Frame, Geometry, Measurer, Path, Program, Stroke,
+};
+use crate::chart::{cartesian, Slice, StyleSheet};
+use crate::Renderer;
+use iced_native::{Element, Length, Point, Rectangle, Vector};
+use std::f32::consts::PI;
+
+/// A chart plotting each [`Slice`] as a sector of a circle, proportional to
+/// its value.
+///
+/// It shows the value and the percentage of the [`Slice`] under the cursor
+/// in a tooltip.
+///
+/// [`Slice`]: struct.Slice.html
+#[allow(missing_debug_implementations)]
+pub struct PieChart<'a> {
+    slices: &'a [Slice],
+    inner_radius: f32,
+    width: Length,
+    height: Length,
+    style: Box<dyn StyleSheet>,
+}
+
+impl<'a> PieChart<'a> {
+    /// Creates a new [`PieChart`] plotting the given [`Slice`]s.
+    ///
+    /// [`PieChart`]: struct.PieChart.html
+    /// [`Slice`]: struct.Slice.html
+    pub fn new(slices: &'a [Slice]) -> Self {
+        PieChart {
+            slices,
+            inner_radius: 0.0,
+            width: Length::Fill,
+            height: Length::Units(300),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the inner radius of the [`PieChart`], as a fraction of its
+    /// radius, turning it into a donut chart.
+    ///
+    /// [`PieChart`]: struct.PieChart.html
+    pub fn inner_radius(mut self, inner_radius: f32) -> Self {
+        self.inner_radius = inner_radius;
+        self
+    }
+
+    /// Sets the width of the [`PieChart`].
+    ///
+    /// [`PieChart`]: struct.PieChart.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`PieChart`].
+    ///
+    /// [`PieChart`]: struct.PieChart.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`PieChart`].
+    ///
+    /// [`PieChart`]: struct.PieChart.html
+    pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn total(&self) -> f32 {
+        self.slices.iter().map(|slice| slice.value.max(0.0)).sum()
+    }
+
+    /// Returns the start and end angles of every slice, clockwise from the
+    /// top of the circle.
+    fn sectors(&self) -> Vec<(f32, f32)> {
+        let total = self.total();
+        let mut start = -PI / 2.0;
+
+        self.slices
+            .iter()
+            .map(|slice| {
+                let sweep = if total > 0.0 {
+                    slice.value.max(0.0) / total * 2.0 * PI
+                } else {
+                    0.0
+                };
+
+                let sector = (start, start + sweep);
+                start += sweep;
+
+                sector
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message> Program<Message> for PieChart<'a> {
+    fn draw_with_measurer(
+        &self,
+        bounds: Rectangle,
+        cursor: Cursor,
+        measurer: &Measurer<'_>,
+    ) -> Vec<Geometry> {
+        const HOVER_OFFSET: f32 = 6.0;
+
+        let style = self.style.style();
+        let mut frame = Frame::new(bounds.size());
+
+        let area = cartesian::area(bounds.size(), &style);
+        let center = Point::new(
+            bounds.width / 2.0,
+            area.y + (bounds.height - area.y) / 2.0,
+        );
+        let radius = ((bounds.width.min(bounds.height - area.y)) / 2.0
+            - HOVER_OFFSET
+            - 4.0)
+            .max(1.0);
+        let fraction = self.inner_radius.min(0.95);
+        let inner_radius = radius * fraction.max(0.0);
+
+        let sectors = self.sectors();
+
+        let hovered = cursor.position_in(&bounds).and_then(|position| {
+            let offset = position - center;
+            let distance = (offset.x * offset.x + offset.y * offset.y).sqrt();
+
+            if distance < inner_radius || distance > radius {
+                return None;
+            }
+
+            let mut angle = offset.y.atan2(offset.x);
+
+            if angle < -PI / 2.0 {
+                angle += 2.0 * PI;
+            }
+
+            sectors
+                .iter()
+                .position(|(start, end)| angle >= *start && angle < *end)
+        });
+
+        for (i, (slice, (start, end))) in
+            self.slices.iter().zip(sectors.iter()).enumerate()
+        {
+            if end <= start {
+                continue;
+            }
+
+            let middle = (start + end) / 2.0;
+            let center = if hovered == Some(i) {
+                center
+                    + Vector::new(
+                        middle.cos() * HOVER_OFFSET,
+                        middle.sin() * HOVER_OFFSET,
+                    )
+            } else {
+                center
+            };
+
+            let at = |angle: f32, radius: f32| {
+                Point::new(
+                    center.x + angle.cos() * radius,
+                    center.y + angle.sin() * radius,
+                )
+            };
+
+            let steps = ((end - start) / 0.05).ceil().max(2.0) as usize;
+
+            let sector = Path::new(|p| {
+                p.move_to(at(*start, radius));
+
+                for step in 1..=steps {
+                    let t = step as f32 / steps as f32;
+
+                    p.line_to(at(start + (end - start) * t, radius));
+                }
+
+                if inner_radius > 0.0 {
+                    for step in (0..=steps).rev() {
+                        let t = step as f32 / steps as f32;
+
+                        p.line_to(at(start + (end - start) * t, inner_radius));
+                    }
+                } else {
+                    p.line_to(center);
+                }
+
+                p.close();
+            });
+
+            frame.fill(&sector, slice.color);
+            frame.stroke(
+                &sector,
+                Stroke::default().with_color(style.tooltip_background),
+            );
+        }
+
+        cartesian::draw_legend(
+            &mut frame,
+            cartesian::legend_origin(),
+            self.slices
+                .iter()
+                .map(|slice| (slice.label.as_str(), slice.color)),
+            measurer,
+            &style,
+        );
+
+        if let (Some(i), Some(position)) =
+            (hovered, cursor.position_in(&bounds))
+        {
+            let slice = &self.slices[i];
+
+            cartesian::draw_tooltip(
+                &mut frame,
+                position,
+                &format!(
+                    "{}: {} ({:.1}%)",
+                    slice.label,
+                    slice.value,
+                    slice.value / self.total() * 100.0
+                ),
+                measurer,
+                &style,
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+impl<'a, Message, B> From<PieChart<'a>> for Element<'a, Message, Renderer<B>>
+where
+    Message: 'static,
+    B: Backend + backend::Text,
+{
+    fn from(chart: PieChart<'a>) -> Element<'a, Message, Renderer<B>> {
+        let width = chart.width;
+        let height = chart.height;
+
+        Canvas::new(chart).width(width).height(height).into()
+    }
+}
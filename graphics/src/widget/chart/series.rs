@@ -0,0 +1,101 @@
+use iced_native::Color;
+
+/// A named set of points plotted by a cartesian chart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub(crate) label: String,
+    pub(crate) color: Color,
+    pub(crate) points: Vec<(f32, f32)>,
+}
+
+impl Series {
+    /// Creates a new [`Series`] with the given label, color, and points.
+    ///
+    /// [`Series`]: struct.Series.html
+    pub fn new(
+        label: impl Into<String>,
+        color: Color,
+        points: Vec<(f32, f32)>,
+    ) -> Self {
+        Series {
+            label: label.into(),
+            color,
+            points,
+        }
+    }
+
+    /// Creates a new [`Series`] from a list of values, using the index of
+    /// each value as its horizontal coordinate.
+    ///
+    /// This is the most convenient way to create the [`Series`] of a
+    /// [`BarChart`], where every index is a category.
+    ///
+    /// [`Series`]: struct.Series.html
+    /// [`BarChart`]: struct.BarChart.html
+    pub fn from_values(
+        label: impl Into<String>,
+        color: Color,
+        values: Vec<f32>,
+    ) -> Self {
+        Self::new(
+            label,
+            color,
+            values
+                .into_iter()
+                .enumerate()
+                .map(|(i, value)| (i as f32, value))
+                .collect(),
+        )
+    }
+
+    /// Returns the label of the [`Series`].
+    ///
+    /// [`Series`]: struct.Series.html
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the points of the [`Series`].
+    ///
+    /// [`Series`]: struct.Series.html
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.points
+    }
+}
+
+/// A named value plotted by a [`PieChart`].
+///
+/// [`PieChart`]: struct.PieChart.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slice {
+    pub(crate) label: String,
+    pub(crate) value: f32,
+    pub(crate) color: Color,
+}
+
+impl Slice {
+    /// Creates a new [`Slice`] with the given label, value, and color.
+    ///
+    /// [`Slice`]: struct.Slice.html
+    pub fn new(label: impl Into<String>, value: f32, color: Color) -> Self {
+        Slice {
+            label: label.into(),
+            value,
+            color,
+        }
+    }
+
+    /// Returns the label of the [`Slice`].
+    ///
+    /// [`Slice`]: struct.Slice.html
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the value of the [`Slice`].
+    ///
+    /// [`Slice`]: struct.Slice.html
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
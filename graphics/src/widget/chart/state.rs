@@ -0,0 +1,162 @@
+use crate::canvas::{Cursor, Event};
+use iced_native::{mouse, Point, Rectangle, Vector};
+
+/// The largest zoom factor of a chart.
+const MAX_ZOOM: f32 = 50.0;
+
+/// The local state of a cartesian chart, storing its zoom and pan.
+///
+/// A chart is zoomed with the mouse wheel, around the cursor, and panned by
+/// dragging it with the left mouse button. Double clicking a chart resets
+/// its zoom and pan.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct State {
+    zoom: f32,
+    offset: Vector,
+    drag_origin: Option<Point>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            zoom: 1.0,
+            offset: Vector::new(0.0, 0.0),
+            drag_origin: None,
+        }
+    }
+}
+
+impl State {
+    /// Creates a new [`State`], showing all of the data of a chart.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current zoom factor of the chart.
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Returns whether the chart is being panned.
+    pub fn is_panning(&self) -> bool {
+        self.drag_origin.is_some()
+    }
+
+    /// Resets the zoom and the pan of the chart, showing all of its data.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the visible part of the given domain.
+    pub(crate) fn visible(&self, domain: Domain) -> Domain {
+        let Domain { x, y } = domain;
+
+        let half_width = (x.1 - x.0) / 2.0 / self.zoom;
+        let half_height = (y.1 - y.0) / 2.0 / self.zoom;
+
+        let center_x = (x.0 + x.1) / 2.0 + self.offset.x;
+        let center_y = (y.0 + y.1) / 2.0 + self.offset.y;
+
+        Domain {
+            x: (center_x - half_width, center_x + half_width),
+            y: (center_y - half_height, center_y + half_height),
+        }
+    }
+
+    /// Zooms and pans the chart given an [`Event`].
+    ///
+    /// It receives the full domain of the data and the area of the canvas
+    /// where it is plotted, relative to its bounds.
+    ///
+    /// [`Event`]: ../canvas/enum.Event.html
+    pub(crate) fn update(
+        &mut self,
+        event: Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+        domain: Domain,
+        area: Rectangle,
+    ) {
+        let visible = self.visible(domain);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let position = match cursor.position_in(&bounds) {
+                    Some(position) if area.contains(position) => position,
+                    _ => return,
+                };
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                };
+
+                let zoom = (self.zoom * 1.1_f32.powf(lines)).min(MAX_ZOOM);
+
+                self.zoom = zoom.max(1.0);
+
+                // The data under the cursor stays in place
+                let fx = (position.x - area.x) / area.width;
+                let fy = (area.y + area.height - position.y) / area.height;
+
+                let anchor_x = visible.x.0 + fx * (visible.x.1 - visible.x.0);
+                let anchor_y = visible.y.0 + fy * (visible.y.1 - visible.y.0);
+
+                let width = (domain.x.1 - domain.x.0) / self.zoom;
+                let height = (domain.y.1 - domain.y.0) / self.zoom;
+
+                self.offset = Vector::new(
+                    anchor_x + (0.5 - fx) * width
+                        - (domain.x.0 + domain.x.1) / 2.0,
+                    anchor_y + (0.5 - fy) * height
+                        - (domain.y.0 + domain.y.1) / 2.0,
+                );
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_in(&bounds) {
+                    if area.contains(position) {
+                        self.drag_origin = Some(position);
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let (Some(origin), Some(position)) =
+                    (self.drag_origin, cursor.position_from(bounds.position()))
+                {
+                    let delta = position - origin;
+
+                    self.offset = Vector::new(
+                        self.offset.x
+                            - delta.x * (visible.x.1 - visible.x.0)
+                                / area.width,
+                        self.offset.y
+                            + delta.y * (visible.y.1 - visible.y.0)
+                                / area.height,
+                    );
+
+                    self.drag_origin = Some(position);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.drag_origin = None;
+            }
+            Event::Click {
+                button: mouse::Button::Left,
+                kind: mouse::click::Kind::Double,
+                ..
+            } if cursor.is_over(&bounds) => {
+                self.reset();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The horizontal and vertical ranges of some data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Domain {
+    pub x: (f32, f32),
+    pub y: (f32, f32),
+}
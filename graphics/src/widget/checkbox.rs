@@ -5,13 +5,14 @@ use iced_native::checkbox;
 use iced_native::mouse;
 use iced_native::{HorizontalAlignment, Rectangle, VerticalAlignment};
 
+pub use iced_native::checkbox::State;
 pub use iced_style::checkbox::{Style, StyleSheet};
 
 /// A box that can be checked.
 ///
 /// This is an alias of an `iced_native` checkbox with an `iced_wgpu::Renderer`.
-pub type Checkbox<Message, Backend> =
-    iced_native::Checkbox<Message, Renderer<Backend>>;
+pub type Checkbox<'a, Message, Backend> =
+    iced_native::Checkbox<'a, Message, Renderer<Backend>>;
 
 impl<B> checkbox::Renderer for Renderer<B>
 where
@@ -27,13 +28,16 @@ where
         bounds: Rectangle,
         is_checked: bool,
         is_mouse_over: bool,
+        is_disabled: bool,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
-            style_sheet.hovered(is_checked)
+        let style = if is_disabled {
+            style_sheet.disabled(self.theme(), is_checked)
+        } else if is_mouse_over {
+            style_sheet.hovered(self.theme(), is_checked)
         } else {
-            style_sheet.active(is_checked)
+            style_sheet.active(self.theme(), is_checked)
         };
 
         let checkbox = Primitive::Quad {
@@ -66,7 +70,7 @@ where
                     vec![checkbox, label]
                 },
             },
-            if is_mouse_over {
+            if is_mouse_over && !is_disabled {
                 mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::default()
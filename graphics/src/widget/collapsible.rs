@@ -0,0 +1,137 @@
+//! Show and hide content below a clickable header.
+//!
+//! A [`Collapsible`] has some local [`State`].
+//!
+//! [`Collapsible`]: type.Collapsible.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::defaults::Defaults;
+use crate::widget::tree_view::expander;
+use crate::{Primitive, Renderer};
+use iced_native::{
+    collapsible, mouse, Color, Element, Font, HorizontalAlignment, Layout,
+    Point, Rectangle, Vector, VerticalAlignment,
+};
+
+pub use iced_native::collapsible::{Group, State};
+pub use iced_style::collapsible::{Style, StyleSheet};
+
+/// A section with a clickable header that expands and collapses its content.
+///
+/// This is an alias of an `iced_native` collapsible with an
+/// `iced_wgpu::Renderer`.
+pub type Collapsible<'a, Message, Backend> =
+    iced_native::Collapsible<'a, Message, Renderer<Backend>>;
+
+impl<B> collapsible::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: u16 = 10;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        header: Rectangle,
+        title: &str,
+        is_expanded: bool,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        style_sheet: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let is_header_hovered = header.contains(cursor_position);
+
+        let style = if is_header_hovered {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let padding = f32::from(padding);
+        let size = f32::from(text_size);
+
+        let mut primitives = Vec::new();
+
+        if let Some(background) = style.background {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background,
+                border_radius: style.border_radius,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        let mut mouse_interaction = if is_header_hovered {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+
+        if bounds.height > header.height {
+            let (content, content_interaction) =
+                content.draw(self, defaults, content_layout, cursor_position);
+
+            primitives.push(Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(content),
+            });
+
+            if !is_header_hovered {
+                mouse_interaction = content_interaction;
+            }
+        }
+
+        let color = style.expander_color;
+
+        primitives.extend(vec![
+            Primitive::Quad {
+                bounds: header,
+                background: style.header_background,
+                border_radius: style.border_radius,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            },
+            expander(
+                Rectangle {
+                    x: header.x + padding,
+                    width: size,
+                    ..header
+                },
+                size,
+                is_expanded,
+                [color.r, color.g, color.b, color.a],
+            ),
+            Primitive::Text {
+                content: title.to_string(),
+                bounds: Rectangle {
+                    x: header.x + size + 2.0 * padding,
+                    y: header.center_y(),
+                    ..header
+                },
+                size,
+                font,
+                color: style.header_text_color,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            },
+            Primitive::Quad {
+                bounds,
+                background: Color::TRANSPARENT.into(),
+                border_radius: style.border_radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            },
+        ]);
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
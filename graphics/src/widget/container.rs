@@ -2,7 +2,9 @@
 use crate::container;
 use crate::defaults::{self, Defaults};
 use crate::{Backend, Primitive, Renderer};
-use iced_native::{Background, Color, Element, Layout, Point, Rectangle};
+use iced_native::{
+    Background, Borders, Color, Element, Layout, Point, Rectangle,
+};
 
 pub use iced_style::container::{Style, StyleSheet};
 
@@ -28,7 +30,7 @@ where
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output {
-        let style = style_sheet.style();
+        let style = style_sheet.style(self.theme());
 
         let defaults = Defaults {
             text: defaults::Text {
@@ -39,15 +41,22 @@ where
         let (content, mouse_interaction) =
             content.draw(self, &defaults, content_layout, cursor_position);
 
-        if style.background.is_some() || style.border_width > 0 {
-            let quad = Primitive::Quad {
+        let borders = style.borders.unwrap_or_else(|| {
+            Borders::uniform(style.border_width, style.border_color)
+        });
+
+        if style.background.is_some()
+            || borders.is_visible()
+            || style.shadow.is_some()
+        {
+            let quad = Primitive::Card {
                 bounds,
                 background: style
                     .background
                     .unwrap_or(Background::Color(Color::TRANSPARENT)),
                 border_radius: style.border_radius,
-                border_width: style.border_width,
-                border_color: style.border_color,
+                borders,
+                shadow: style.shadow,
             };
 
             (
@@ -0,0 +1,195 @@
+//! Show a menu of actions when a widget is right clicked.
+//!
+//! A [`ContextMenu`] has some local [`State`].
+//!
+//! [`ContextMenu`]: type.ContextMenu.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::triangle::{Mesh2D, Vertex2D};
+use crate::{Primitive, Renderer};
+use iced_native::context_menu::{self, Panel, Row};
+use iced_native::{
+    mouse, Color, Font, HorizontalAlignment, Point, Rectangle, Size, Vector,
+    VerticalAlignment,
+};
+
+pub use iced_native::context_menu::{Entry, State};
+pub use iced_style::context_menu::{Style, StyleSheet};
+
+/// A widget opening a menu at the cursor position when its content is right
+/// clicked.
+///
+/// This is an alias of an `iced_native` context menu with an `iced_wgpu::Renderer`.
+pub type ContextMenu<'a, Message, Backend> =
+    iced_native::ContextMenu<'a, Message, Renderer<Backend>>;
+
+impl<B> context_menu::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: u16 = 5;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        panels: &[Panel<'_>],
+        cursor_position: Point,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style.style(self.theme());
+        let mut mouse_interaction = mouse::Interaction::default();
+        let mut primitives = Vec::new();
+
+        for panel in panels {
+            primitives.push(Primitive::Quad {
+                bounds: panel.bounds,
+                background: style.background,
+                border_radius: 0,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            });
+
+            for row in &panel.rows {
+                let (bounds, label, shortcut, is_enabled, is_highlighted) =
+                    match *row {
+                        Row::Item {
+                            bounds,
+                            label,
+                            shortcut,
+                            is_enabled,
+                            is_highlighted,
+                        } => (
+                            bounds,
+                            label,
+                            shortcut,
+                            is_enabled,
+                            is_highlighted,
+                        ),
+                        Row::Submenu {
+                            bounds,
+                            label,
+                            is_highlighted,
+                        } => (bounds, label, None, true, is_highlighted),
+                        Row::Separator { bounds } => {
+                            primitives.push(Primitive::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + f32::from(padding),
+                                    y: bounds.center_y().floor(),
+                                    width: bounds.width
+                                        - f32::from(padding * 2),
+                                    height: 1.0,
+                                },
+                                background: style.border_color.into(),
+                                border_radius: 0,
+                                border_width: 0,
+                                border_color: Color::TRANSPARENT,
+                            });
+
+                            continue;
+                        }
+                    };
+
+                let is_submenu = matches!(row, Row::Submenu { .. });
+
+                if is_enabled && bounds.contains(cursor_position) {
+                    mouse_interaction = mouse::Interaction::Pointer;
+                }
+
+                let text_color = if is_highlighted {
+                    primitives.push(Primitive::Quad {
+                        bounds,
+                        background: style.selected_background,
+                        border_radius: 0,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
+                    });
+
+                    style.selected_text_color
+                } else if is_enabled {
+                    style.text_color
+                } else {
+                    style.disabled_text_color
+                };
+
+                primitives.push(Primitive::Text {
+                    content: label.to_string(),
+                    bounds: Rectangle {
+                        x: bounds.x + f32::from(padding),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    size: f32::from(text_size),
+                    font,
+                    color: text_color,
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+
+                if let Some(shortcut) = shortcut {
+                    primitives.push(Primitive::Text {
+                        content: shortcut.to_string(),
+                        bounds: Rectangle {
+                            x: bounds.x + bounds.width - f32::from(padding),
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        size: f32::from(text_size),
+                        font,
+                        color: text_color,
+                        horizontal_alignment: HorizontalAlignment::Right,
+                        vertical_alignment: VerticalAlignment::Center,
+                    });
+                }
+
+                if is_submenu {
+                    primitives.push(arrow(
+                        bounds,
+                        padding,
+                        text_size,
+                        text_color.into_linear(),
+                    ));
+                }
+            }
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
+
+/// Draws a triangle pointing right at the right side of a submenu row.
+fn arrow(
+    bounds: Rectangle,
+    padding: u16,
+    text_size: u16,
+    color: [f32; 4],
+) -> Primitive {
+    let height = f32::from(text_size) / 2.0;
+    let width = height / 2.0;
+
+    let vertex = |x, y| Vertex2D {
+        position: [x, y],
+        color,
+    };
+
+    Primitive::Translate {
+        translation: Vector::new(
+            bounds.x + bounds.width - f32::from(padding) - width,
+            bounds.center_y() - height / 2.0,
+        ),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D {
+                vertices: vec![
+                    vertex(0.0, 0.0),
+                    vertex(width, height / 2.0),
+                    vertex(0.0, height),
+                ],
+                indices: vec![0, 1, 2],
+            },
+            size: Size::new(width, height),
+        }),
+    }
+}
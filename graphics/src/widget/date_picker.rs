@@ -0,0 +1,207 @@
+//! Let your users pick a date from a calendar.
+//!
+//! A [`DatePicker`] has some local [`State`].
+//!
+//! [`DatePicker`]: type.DatePicker.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    date_picker, mouse, Color, Font, HorizontalAlignment, Point, Rectangle,
+    VerticalAlignment,
+};
+
+pub use iced_native::date_picker::{Cell, Date, State, Weekday};
+pub use iced_style::date_picker::{Calendar, Style, StyleSheet};
+
+/// A field showing a date that opens a calendar when clicked.
+///
+/// This is an alias of an `iced_native` date picker with an
+/// `iced_wgpu::Renderer`.
+pub type DatePicker<'a, Message, Backend> =
+    iced_native::DatePicker<'a, Message, Renderer<Backend>>;
+
+impl<B> date_picker::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: u16 = 5;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        selected: Option<String>,
+        placeholder: &str,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style.hovered()
+        } else {
+            style.active()
+        };
+
+        let (content, color) = match selected {
+            Some(selected) => (selected, style.text_color),
+            None => (placeholder.to_string(), style.placeholder_color),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds,
+                        background: style.background,
+                        border_radius: style.border_radius,
+                        border_width: style.border_width,
+                        border_color: style.border_color,
+                    },
+                    Primitive::Text {
+                        content,
+                        size: f32::from(text_size),
+                        font,
+                        color,
+                        bounds: Rectangle {
+                            x: bounds.x + f32::from(padding),
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        horizontal_alignment: HorizontalAlignment::Left,
+                        vertical_alignment: VerticalAlignment::Center,
+                    },
+                ],
+            },
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+
+    fn draw_calendar(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        title: &Cell,
+        navigation: &[Cell],
+        weekdays: &[Cell],
+        days: &[Cell],
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style.calendar();
+
+        let mut primitives = vec![
+            background(bounds, &style),
+            text(title, style.text_color, text_size, font),
+        ];
+
+        for cell in weekdays {
+            primitives.push(text(cell, style.weekday_color, text_size, font));
+        }
+
+        let mouse_interaction = cells(
+            navigation.iter().chain(days),
+            cursor_position,
+            text_size,
+            font,
+            &style,
+            &mut primitives,
+        );
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
+
+/// Produces the background of a popup of a picker.
+pub(crate) fn background(bounds: Rectangle, style: &Calendar) -> Primitive {
+    Primitive::Quad {
+        bounds,
+        background: style.background,
+        border_radius: 0,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    }
+}
+
+/// Produces the label of a [`Cell`], centered in its bounds.
+///
+/// [`Cell`]: struct.Cell.html
+fn text(cell: &Cell, color: Color, text_size: u16, font: Font) -> Primitive {
+    Primitive::Text {
+        content: cell.label.clone(),
+        size: f32::from(text_size),
+        font,
+        color,
+        bounds: Rectangle {
+            x: cell.bounds.center_x(),
+            y: cell.bounds.center_y(),
+            ..cell.bounds
+        },
+        horizontal_alignment: HorizontalAlignment::Center,
+        vertical_alignment: VerticalAlignment::Center,
+    }
+}
+
+/// Produces the interactive cells of a popup of a picker, returning the
+/// resulting mouse interaction.
+pub(crate) fn cells<'a>(
+    cells: impl Iterator<Item = &'a Cell>,
+    cursor_position: Point,
+    text_size: u16,
+    font: Font,
+    style: &Calendar,
+    primitives: &mut Vec<Primitive>,
+) -> mouse::Interaction {
+    let mut mouse_interaction = mouse::Interaction::default();
+
+    for cell in cells {
+        let is_hovered =
+            cell.is_enabled && cell.bounds.contains(cursor_position);
+
+        let background = if cell.is_selected {
+            Some(style.selected_background)
+        } else if is_hovered {
+            Some(style.hovered_background)
+        } else {
+            None
+        };
+
+        if let Some(background) = background {
+            primitives.push(Primitive::Quad {
+                bounds: cell.bounds,
+                background,
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        if is_hovered {
+            mouse_interaction = mouse::Interaction::Pointer;
+        }
+
+        let color = if !cell.is_enabled {
+            style.disabled_text_color
+        } else if cell.is_selected {
+            style.selected_text_color
+        } else if cell.is_outside {
+            style.outside_text_color
+        } else {
+            style.text_color
+        };
+
+        primitives.push(text(cell, color, text_size, font));
+    }
+
+    mouse_interaction
+}
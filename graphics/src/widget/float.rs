@@ -0,0 +1,53 @@
+use crate::{Backend, Primitive, Renderer};
+use iced_native::float;
+use iced_native::{Element, Layout, Point, Vector};
+
+/// A container that floats some elements over its content.
+pub type Float<'a, Message, Backend> =
+    iced_native::Float<'a, Message, Renderer<Backend>>;
+
+impl<B> float::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &Element<'_, Message, Self>,
+        floating: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_positions: &[Point],
+    ) -> Self::Output {
+        let mut children = layout.children();
+
+        let (content, mut mouse_interaction) = content.draw(
+            self,
+            defaults,
+            children.next().unwrap(),
+            cursor_positions[0],
+        );
+
+        let mut primitives = vec![content];
+
+        for ((element, layout), cursor_position) in
+            floating.iter().zip(children).zip(&cursor_positions[1..])
+        {
+            let (primitive, new_mouse_interaction) =
+                element.draw(self, defaults, layout, *cursor_position);
+
+            if new_mouse_interaction > mouse_interaction {
+                mouse_interaction = new_mouse_interaction;
+            }
+
+            // Every floating element is drawn in a new layer, so it covers
+            // the text of the content
+            primitives.push(Primitive::Clip {
+                bounds: layout.bounds(),
+                offset: Vector::new(0, 0),
+                content: Box::new(primitive),
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -0,0 +1,215 @@
+//! Display a value from a range of values on a radial dial.
+//!
+//! A [`Gauge`] has a range of possible values and a current value, as well
+//! as tick marks, value labels, a size, and a style.
+//!
+//! [`Gauge`]: type.Gauge.html
+use crate::triangle::{Mesh2D, Vertex2D};
+use crate::widget::progress_circle::arc;
+use crate::{Backend, Primitive, Renderer};
+use iced_native::gauge;
+use iced_native::mouse;
+use iced_native::{
+    Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
+    Vector, VerticalAlignment,
+};
+
+use std::f32::consts::PI;
+
+pub use iced_style::gauge::{Style, StyleSheet};
+
+/// A radial dial with a needle pointing at a value from a range of values.
+///
+/// This is an alias of an `iced_native` gauge with an `iced_wgpu::Renderer`.
+pub type Gauge<Backend> = iced_native::Gauge<Renderer<Backend>>;
+
+/// The angle where the dials of gauges and knobs start, clockwise from the
+/// top.
+pub(crate) const START_ANGLE: f32 = -0.75 * PI;
+
+/// The angle covered by the dials of gauges and knobs.
+pub(crate) const SWEEP: f32 = 1.5 * PI;
+
+const TICK_LENGTH: f32 = 6.0;
+const HUB_RADIUS: f32 = 5.0;
+
+impl<B> gauge::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 160;
+    const DEFAULT_TEXT_SIZE: u16 = 12;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        range: std::ops::RangeInclusive<f32>,
+        value: f32,
+        value_label: &str,
+        ticks: &[f32],
+        labels: &[(f32, String)],
+        text_size: u16,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+        let thickness = f32::from(style.thickness);
+        let text_size = f32::from(text_size);
+
+        let center = bounds.center();
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let tick_radius = radius - thickness - 2.0;
+        let label_radius = tick_radius - TICK_LENGTH - text_size;
+
+        let angle = angle_of(&range, value);
+
+        let mut primitives =
+            vec![arc(bounds, thickness, START_ANGLE, SWEEP, style.track)];
+
+        if angle > START_ANGLE {
+            primitives.push(arc(
+                bounds,
+                thickness,
+                START_ANGLE,
+                angle - START_ANGLE,
+                style.bar,
+            ));
+        }
+
+        primitives.extend(ticks.iter().map(|tick| {
+            needle(
+                center,
+                angle_of(&range, *tick),
+                tick_radius - TICK_LENGTH,
+                tick_radius,
+                1.0,
+                style.tick_color,
+            )
+        }));
+
+        primitives.extend(labels.iter().map(|(value, label)| {
+            let (sin, cos) = angle_of(&range, *value).sin_cos();
+
+            Primitive::Text {
+                content: label.clone(),
+                bounds: Rectangle {
+                    x: center.x + sin * label_radius,
+                    y: center.y - cos * label_radius,
+                    width: f32::INFINITY,
+                    height: f32::INFINITY,
+                },
+                color: style.label_color,
+                size: text_size,
+                font: Font::Default,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            }
+        }));
+
+        primitives.push(Primitive::Text {
+            content: value_label.to_string(),
+            bounds: Rectangle {
+                x: center.x,
+                y: center.y + radius * 0.5,
+                width: f32::INFINITY,
+                height: f32::INFINITY,
+            },
+            color: style.value_color,
+            size: text_size * 2.0,
+            font: Font::Default,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+        });
+
+        primitives.push(needle(
+            center,
+            angle,
+            -HUB_RADIUS * 2.0,
+            tick_radius - TICK_LENGTH / 2.0,
+            3.0,
+            style.needle,
+        ));
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                x: center.x - HUB_RADIUS,
+                y: center.y - HUB_RADIUS,
+                width: HUB_RADIUS * 2.0,
+                height: HUB_RADIUS * 2.0,
+            },
+            background: Background::Color(style.needle),
+            border_radius: HUB_RADIUS as u16,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Returns the angle of the dial pointing at the given value, clockwise from
+/// the top.
+pub(crate) fn angle_of(
+    range: &std::ops::RangeInclusive<f32>,
+    value: f32,
+) -> f32 {
+    let (start, end) = (*range.start(), *range.end());
+
+    let progress = if end > start {
+        ((value - start) / (end - start)).min(1.0)
+    } else {
+        0.0
+    };
+
+    START_ANGLE + SWEEP * progress.max(0.0)
+}
+
+/// Produces a mesh of a radial segment starting at the given center.
+///
+/// The angle is in radians and goes clockwise, starting at the top. The
+/// segment spans from the `from` distance to the `to` distance from the
+/// center.
+pub(crate) fn needle(
+    center: Point,
+    angle: f32,
+    from: f32,
+    to: f32,
+    width: f32,
+    color: Color,
+) -> Primitive {
+    let (sin, cos) = angle.sin_cos();
+    let direction = Vector::new(sin, -cos);
+    let normal = Vector::new(cos * width / 2.0, sin * width / 2.0);
+
+    let reach = from.abs().max(to.abs()) + width;
+    let origin = Point::new(reach, reach);
+    let color = [color.r, color.g, color.b, color.a];
+
+    let vertex = |distance: f32, side: f32| Vertex2D {
+        position: [
+            origin.x + direction.x * distance + normal.x * side,
+            origin.y + direction.y * distance + normal.y * side,
+        ],
+        color,
+    };
+
+    Primitive::Translate {
+        translation: Vector::new(center.x - reach, center.y - reach),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D {
+                vertices: vec![
+                    vertex(from, -1.0),
+                    vertex(from, 1.0),
+                    vertex(to, 1.0),
+                    vertex(to, -1.0),
+                ],
+                indices: vec![0, 1, 2, 0, 2, 3],
+            },
+            size: Size::new(reach * 2.0, reach * 2.0),
+        }),
+    }
+}
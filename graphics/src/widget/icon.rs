@@ -0,0 +1,41 @@
+//! Display icons from the icon font bundled with the renderer.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::icon;
+use iced_native::mouse;
+use iced_native::{Color, HorizontalAlignment, Rectangle, VerticalAlignment};
+
+pub use iced_native::icon::{Glyph, Icon};
+
+impl<B> icon::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_SIZE: u16 = 20;
+
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        glyph: Glyph,
+        size: u16,
+        color: Option<Color>,
+    ) -> Self::Output {
+        (
+            Primitive::Text {
+                content: glyph.codepoint().to_string(),
+                size: f32::from(size),
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                color: color.unwrap_or(defaults.text.color),
+                font: B::ICON_FONT,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
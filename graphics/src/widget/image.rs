@@ -1,11 +1,16 @@
 //! Display images in your user interface.
+pub mod viewer;
+
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::image;
 use iced_native::mouse;
-use iced_native::Layout;
+use iced_native::spinner;
+use iced_native::{Rectangle, Vector};
+
+use std::time::Duration;
 
-pub use iced_native::image::{Handle, Image};
+pub use iced_native::image::{Handle, Image, Playback, Viewer};
 
 impl<B> image::Renderer for Renderer<B>
 where
@@ -15,15 +20,52 @@ where
         self.backend().dimensions(handle)
     }
 
+    fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration> {
+        self.backend().frame_delays(handle)
+    }
+
+    fn draw_loading(&mut self, bounds: Rectangle) -> Self::Output {
+        let size = f32::from(<Self as spinner::Renderer>::DEFAULT_SIZE)
+            .min(bounds.width)
+            .min(bounds.height);
+
+        let spinner = Rectangle {
+            x: bounds.center_x() - size / 2.0,
+            y: bounds.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        };
+
+        // The indicator stays still, as the image only triggers a redraw
+        // once it is loaded
+        spinner::Renderer::draw(self, spinner, 0.0, &Default::default())
+    }
+
     fn draw(
         &mut self,
         handle: image::Handle,
-        layout: Layout<'_>,
+        frame: usize,
+        bounds: Rectangle,
+        image_bounds: Rectangle,
     ) -> Self::Output {
+        let image = Primitive::Image {
+            handle,
+            bounds: image_bounds,
+            frame,
+        };
+
+        let overflows = image_bounds.width > bounds.width
+            || image_bounds.height > bounds.height;
+
         (
-            Primitive::Image {
-                handle,
-                bounds: layout.bounds(),
+            if overflows {
+                Primitive::Clip {
+                    bounds,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(image),
+                }
+            } else {
+                image
             },
             mouse::Interaction::default(),
         )
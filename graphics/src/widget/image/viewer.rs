@@ -0,0 +1,45 @@
+//! Zoom and pan on an image.
+//!
+//! A [`Viewer`] has some local [`State`].
+//!
+//! [`Viewer`]: struct.Viewer.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::image::{self, viewer};
+use iced_native::{mouse, Rectangle, Vector};
+
+pub use iced_native::image::viewer::{Mode, State, Viewer};
+
+impl<B> viewer::Renderer for Renderer<B>
+where
+    B: Backend + backend::Image,
+{
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        image_bounds: Rectangle,
+        handle: image::Handle,
+        is_dragging: bool,
+        is_mouse_over: bool,
+    ) -> Self::Output {
+        (
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Image {
+                    handle,
+                    bounds: image_bounds,
+                    frame: 0,
+                }),
+            },
+            if is_dragging {
+                mouse::Interaction::Grabbing
+            } else if is_mouse_over {
+                mouse::Interaction::Grab
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
@@ -0,0 +1,135 @@
+//! Display an interactive rotary selector of a single value from a range of
+//! values.
+//!
+//! A [`Knob`] has some local [`State`].
+//!
+//! [`Knob`]: type.Knob.html
+//! [`State`]: struct.State.html
+use crate::widget::gauge::{angle_of, needle, START_ANGLE, SWEEP};
+use crate::widget::progress_circle::arc;
+use crate::{Backend, Primitive, Renderer};
+use iced_native::knob;
+use iced_native::mouse;
+use iced_native::{Background, Point, Rectangle};
+
+pub use iced_native::knob::State;
+pub use iced_style::knob::{Style, StyleSheet};
+
+/// A rotary control that selects a single value from a range of values.
+///
+/// This is an alias of an `iced_native` knob with an `iced_wgpu::Renderer`.
+pub type Knob<'a, Message, Backend> =
+    iced_native::Knob<'a, Message, Renderer<Backend>>;
+
+const TICK_LENGTH: f32 = 3.0;
+const GAP: f32 = 3.0;
+
+impl<B> knob::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 48;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        range: std::ops::RangeInclusive<f32>,
+        value: f32,
+        is_dragging: bool,
+        ticks: &[f32],
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let thickness = f32::from(style.thickness);
+        let center = bounds.center();
+        let radius = bounds.width.min(bounds.height) / 2.0;
+
+        let tick_space = if ticks.is_empty() {
+            0.0
+        } else {
+            TICK_LENGTH + 1.0
+        };
+
+        let dial = Rectangle {
+            x: center.x - radius + tick_space,
+            y: center.y - radius + tick_space,
+            width: (radius - tick_space) * 2.0,
+            height: (radius - tick_space) * 2.0,
+        };
+
+        let angle = angle_of(&range, value);
+
+        let mut primitives: Vec<_> = ticks
+            .iter()
+            .map(|tick| {
+                needle(
+                    center,
+                    angle_of(&range, *tick),
+                    radius - TICK_LENGTH,
+                    radius,
+                    1.0,
+                    style.tick_color,
+                )
+            })
+            .collect();
+
+        primitives.push(arc(dial, thickness, START_ANGLE, SWEEP, style.track));
+
+        if angle > START_ANGLE {
+            primitives.push(arc(
+                dial,
+                thickness,
+                START_ANGLE,
+                angle - START_ANGLE,
+                style.bar,
+            ));
+        }
+
+        let body_radius = (radius - tick_space - thickness - GAP).max(0.0);
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                x: center.x - body_radius,
+                y: center.y - body_radius,
+                width: body_radius * 2.0,
+                height: body_radius * 2.0,
+            },
+            background: Background::Color(style.background),
+            border_radius: body_radius as u16,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        });
+
+        primitives.push(needle(
+            center,
+            angle,
+            body_radius * 0.3,
+            body_radius * 0.85,
+            2.0,
+            style.indicator,
+        ));
+
+        (
+            Primitive::Group { primitives },
+            if is_dragging {
+                mouse::Interaction::Grabbing
+            } else if is_mouse_over {
+                mouse::Interaction::Grab
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
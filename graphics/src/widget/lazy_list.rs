@@ -0,0 +1,17 @@
+//! Display a large amount of rows by only building the visible ones.
+//!
+//! A [`LazyList`] has some local [`State`].
+//!
+//! [`LazyList`]: type.LazyList.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::lazy_list::State;
+pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
+
+/// A scrollable list of rows with a fixed height that only builds and lays
+/// out the rows in the visible region.
+///
+/// This is an alias of an `iced_native` lazy list with an `iced_wgpu::Renderer`.
+pub type LazyList<'a, Message, Backend> =
+    iced_native::LazyList<'a, Message, Renderer<Backend>>;
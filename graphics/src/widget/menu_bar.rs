@@ -0,0 +1,92 @@
+//! Build desktop-style menu bars with dropdown menus.
+//!
+//! A [`MenuBar`] has some local [`State`].
+//!
+//! [`MenuBar`]: type.MenuBar.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    menu_bar, mouse, Color, Font, HorizontalAlignment, Rectangle,
+    VerticalAlignment,
+};
+
+pub use iced_native::menu_bar::{Menu, State};
+pub use iced_style::menu;
+pub use iced_style::menu_bar::{Style, StyleSheet};
+
+/// A horizontal bar of menus, each one opening a dropdown when clicked.
+///
+/// This is an alias of an `iced_native` menu bar with an `iced_wgpu::Renderer`.
+pub type MenuBar<'a, Message, Backend> =
+    iced_native::MenuBar<'a, Message, Renderer<Backend>>;
+
+impl<B> menu_bar::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn menu_style(
+        style: &Box<dyn StyleSheet>,
+    ) -> Box<dyn iced_style::context_menu::StyleSheet> {
+        Box::new(style.menu())
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        titles: &[(Rectangle, &str)],
+        highlighted_title: Option<usize>,
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style.style();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: 0,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        }];
+
+        for (index, (title, label)) in titles.iter().enumerate() {
+            let is_highlighted = highlighted_title == Some(index);
+
+            if is_highlighted {
+                mouse_interaction = mouse::Interaction::Pointer;
+
+                primitives.push(Primitive::Quad {
+                    bounds: *title,
+                    background: style.selected_background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            primitives.push(Primitive::Text {
+                content: label.to_string(),
+                bounds: Rectangle {
+                    x: title.center_x(),
+                    y: title.center_y(),
+                    ..*title
+                },
+                size: f32::from(text_size),
+                font,
+                color: if is_highlighted {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -0,0 +1,50 @@
+//! Show content on top of a dimmed user interface.
+use crate::defaults::Defaults;
+use crate::{Backend, Primitive, Renderer};
+use iced_native::{Color, Element, Layout, Point, Rectangle};
+
+pub use iced_style::modal::{Style, StyleSheet};
+
+/// A widget showing some content centered on top of the rest of the user
+/// interface.
+///
+/// This is an alias of an `iced_native` modal with a default `Renderer`.
+pub type Modal<'a, Message, Backend> =
+    iced_native::Modal<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::modal::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        style_sheet: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let (content, mouse_interaction) =
+            content.draw(self, defaults, content_layout, cursor_position);
+
+        let backdrop = Primitive::Quad {
+            bounds,
+            background: style.backdrop,
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![backdrop, content],
+            },
+            mouse_interaction,
+        )
+    }
+}
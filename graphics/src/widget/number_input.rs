@@ -0,0 +1,16 @@
+//! Let your users edit numbers with a field and a couple of step buttons.
+//!
+//! A [`NumberInput`] has some local [`State`].
+//!
+//! [`NumberInput`]: struct.NumberInput.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::number_input::{Number, State};
+pub use iced_style::text_input::{Style, StyleSheet};
+
+/// A field that can be filled with a number.
+///
+/// This is an alias of an `iced_native` number input with an `iced_wgpu::Renderer`.
+pub type NumberInput<'a, T, Message, Backend> =
+    iced_native::NumberInput<'a, T, Message, Renderer<Backend>>;
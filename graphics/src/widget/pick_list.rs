@@ -0,0 +1,135 @@
+//! Display a dropdown list of selectable values.
+//!
+//! A [`PickList`] has some local [`State`].
+//!
+//! [`PickList`]: type.PickList.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::triangle::{Mesh2D, Vertex2D};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    mouse, pick_list, Font, HorizontalAlignment, Point, Rectangle, Size,
+    Vector, VerticalAlignment,
+};
+
+pub use iced_native::pick_list::State;
+pub use iced_style::menu;
+pub use iced_style::pick_list::{Style, StyleSheet};
+
+/// A widget allowing the selection of a single value from a list of options.
+///
+/// This is an alias of an `iced_native` pick list with an `iced_wgpu::Renderer`.
+pub type PickList<'a, T, Message, Backend> =
+    iced_native::PickList<'a, T, Message, Renderer<Backend>>;
+
+impl<B> pick_list::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: u16 = 5;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn menu_style(style: &Box<dyn StyleSheet>) -> Option<menu::Style> {
+        style.menu()
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        selected: Option<String>,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style.hovered(self.theme())
+        } else {
+            style.active(self.theme())
+        };
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        }];
+
+        if let Some(label) = selected {
+            primitives.push(Primitive::Text {
+                content: label,
+                size: f32::from(text_size),
+                font,
+                color: style.text_color,
+                bounds: Rectangle {
+                    x: bounds.x + f32::from(padding),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        primitives.push(arrow(
+            bounds,
+            padding,
+            text_size,
+            style.icon_size,
+            style.text_color.into_linear(),
+        ));
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
+
+/// Draws a triangle pointing down at the right side of the bounds.
+fn arrow(
+    bounds: Rectangle,
+    padding: u16,
+    text_size: u16,
+    icon_size: f32,
+    color: [f32; 4],
+) -> Primitive {
+    let width = f32::from(text_size) * icon_size;
+    let height = width / 2.0;
+
+    let center_x = bounds.x + bounds.width
+        - f32::from(padding)
+        - f32::from(text_size) / 2.0;
+
+    let vertex = |x, y| Vertex2D {
+        position: [x, y],
+        color,
+    };
+
+    Primitive::Translate {
+        translation: Vector::new(
+            center_x - width / 2.0,
+            bounds.center_y() - height / 2.0,
+        ),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D {
+                vertices: vec![
+                    vertex(0.0, 0.0),
+                    vertex(width, 0.0),
+                    vertex(width / 2.0, height),
+                ],
+                indices: vec![0, 1, 2],
+            },
+            size: Size::new(width, height),
+        }),
+    }
+}
@@ -32,7 +32,7 @@ where
         value: f32,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = style_sheet.style();
+        let style = style_sheet.style(self.theme());
 
         let (range_start, range_end) = range.into_inner();
         let active_progress_width = bounds.width
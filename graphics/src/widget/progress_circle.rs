@@ -0,0 +1,129 @@
+//! Provide circular progress feedback to your users.
+//!
+//! A [`ProgressCircle`] has a range of possible values and a current value,
+//! as well as a size and style.
+//!
+//! [`ProgressCircle`]: type.ProgressCircle.html
+use crate::triangle::{Mesh2D, Vertex2D};
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::progress_circle;
+use iced_native::{Color, Rectangle, Size, Vector};
+
+use std::f32::consts::PI;
+
+pub use iced_style::progress_circle::{Style, StyleSheet};
+
+/// A ring that fills clockwise to display progress.
+///
+/// This is an alias of an `iced_native` progress circle with an
+/// `iced_wgpu::Renderer`.
+pub type ProgressCircle<Backend> =
+    iced_native::ProgressCircle<Renderer<Backend>>;
+
+impl<B> progress_circle::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 40;
+
+    fn draw(
+        &self,
+        bounds: Rectangle,
+        range: std::ops::RangeInclusive<f32>,
+        value: f32,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+        let thickness = f32::from(style.thickness);
+
+        let (range_start, range_end) = range.into_inner();
+        let progress = if range_end > range_start {
+            (value - range_start) / (range_end - range_start)
+        } else {
+            0.0
+        };
+
+        let mut primitives =
+            vec![arc(bounds, thickness, 0.0, 2.0 * PI, style.track)];
+
+        if progress > 0.0 {
+            primitives.push(arc(
+                bounds,
+                thickness,
+                0.0,
+                2.0 * PI * progress,
+                style.bar,
+            ));
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Produces a mesh of an arc of a ring centered in the given bounds.
+///
+/// The angles are in radians and go clockwise, starting at the top.
+pub(crate) fn arc(
+    bounds: Rectangle,
+    thickness: f32,
+    start: f32,
+    sweep: f32,
+    color: Color,
+) -> Primitive {
+    let radius = bounds.width.min(bounds.height) / 2.0;
+    let inner_radius = (radius - thickness).max(0.0);
+
+    let segments = ((sweep.abs() / (2.0 * PI)) * 64.0).ceil().max(1.0) as u32;
+    let color = [color.r, color.g, color.b, color.a];
+
+    let mut vertices = Vec::with_capacity(2 * segments as usize + 2);
+    let mut indices = Vec::with_capacity(6 * segments as usize);
+
+    for i in 0..=segments {
+        let angle = start + sweep * i as f32 / segments as f32 - PI / 2.0;
+        let (sin, cos) = angle.sin_cos();
+
+        vertices.push(Vertex2D {
+            position: [radius + cos * radius, radius + sin * radius],
+            color,
+        });
+
+        vertices.push(Vertex2D {
+            position: [
+                radius + cos * inner_radius,
+                radius + sin * inner_radius,
+            ],
+            color,
+        });
+    }
+
+    for i in 0..segments {
+        let outer = 2 * i;
+
+        indices.extend_from_slice(&[
+            outer,
+            outer + 1,
+            outer + 2,
+            outer + 1,
+            outer + 3,
+            outer + 2,
+        ]);
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(
+            bounds.center_x() - radius,
+            bounds.center_y() - radius,
+        ),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D { vertices, indices },
+            size: Size::new(radius * 2.0, radius * 2.0),
+        }),
+    }
+}
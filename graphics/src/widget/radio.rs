@@ -4,14 +4,15 @@ use iced_native::mouse;
 use iced_native::radio;
 use iced_native::{Background, Color, Rectangle};
 
+pub use iced_native::radio::State;
 pub use iced_style::radio::{Style, StyleSheet};
 
 /// A circular button representing a choice.
 ///
 /// This is an alias of an `iced_native` radio button with an
 /// `iced_wgpu::Renderer`.
-pub type Radio<Message, Backend> =
-    iced_native::Radio<Message, Renderer<Backend>>;
+pub type Radio<'a, Message, Backend> =
+    iced_native::Radio<'a, Message, Renderer<Backend>>;
 
 const SIZE: f32 = 28.0;
 const DOT_SIZE: f32 = SIZE / 2.0;
@@ -30,13 +31,16 @@ where
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
+        is_disabled: bool,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
-            style_sheet.hovered()
+        let style = if is_disabled {
+            style_sheet.disabled(self.theme())
+        } else if is_mouse_over {
+            style_sheet.hovered(self.theme())
         } else {
-            style_sheet.active()
+            style_sheet.active(self.theme())
         };
 
         let radio = Primitive::Quad {
@@ -68,7 +72,7 @@ where
                     vec![radio, label]
                 },
             },
-            if is_mouse_over {
+            if is_mouse_over && !is_disabled {
                 mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::default()
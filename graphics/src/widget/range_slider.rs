@@ -0,0 +1,59 @@
+//! Display an interactive selector of an interval from a range of values.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+//!
+//! [`RangeSlider`]: struct.RangeSlider.html
+//! [`State`]: struct.State.html
+use crate::widget::slider;
+use crate::{Backend, Renderer};
+use iced_native::range_slider;
+use iced_native::{Point, Rectangle};
+
+pub use iced_native::range_slider::State;
+pub use iced_native::slider::Orientation;
+pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
+
+/// A bar with two handles that selects an interval from a range of values.
+///
+/// This is an alias of an `iced_native` range slider with an
+/// `iced_wgpu::Renderer`.
+pub type RangeSlider<'a, Message, Backend> =
+    iced_native::RangeSlider<'a, Message, Renderer<Backend>>;
+
+impl<B> range_slider::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        range: std::ops::RangeInclusive<f32>,
+        values: (f32, f32),
+        is_dragging: bool,
+        orientation: Orientation,
+        ticks: &[f32],
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let (style, mouse_interaction) = slider::appearance(
+            bounds,
+            cursor_position,
+            is_dragging,
+            false,
+            style_sheet.as_ref(),
+            self.theme(),
+        );
+
+        (
+            slider::draw(
+                bounds,
+                range,
+                &[values.0, values.1],
+                orientation,
+                ticks,
+                &style,
+            ),
+            mouse_interaction,
+        )
+    }
+}
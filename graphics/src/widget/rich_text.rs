@@ -0,0 +1,118 @@
+//! Write a paragraph of text made of differently styled spans.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    mouse, rich_text, Background, Color, Font, HorizontalAlignment, Point,
+    Rectangle, Size, VerticalAlignment,
+};
+
+pub use iced_native::rich_text::{Fragment, Run, Span};
+
+/// A paragraph of text made of multiple spans, each one with its own style.
+///
+/// This is an alias of an `iced_native` rich text with an
+/// `iced_wgpu::Renderer`.
+pub type RichText<Message, Backend> =
+    iced_native::RichText<Message, Renderer<Backend>>;
+
+/// The color of a link without an explicit color.
+const LINK_COLOR: Color = Color {
+    r: 0.2,
+    g: 0.4,
+    b: 0.8,
+    a: 1.0,
+};
+
+impl<B> rich_text::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn layout(
+        &self,
+        fragments: &[Fragment<'_, Font>],
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+    ) -> Vec<Run> {
+        self.backend()
+            .layout(fragments, bounds, horizontal_alignment)
+    }
+
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        fragments: &[Fragment<'_, Font>],
+        runs: &[Run],
+    ) -> Self::Output {
+        let position = Point::new(
+            cursor_position.x - bounds.x,
+            cursor_position.y - bounds.y,
+        );
+
+        let hovered_link = runs
+            .iter()
+            .find(|run| run.bounds.contains(position))
+            .map(|run| run.fragment)
+            .filter(|fragment| fragments[*fragment].is_link);
+
+        let mut primitives = Vec::new();
+
+        for run in runs {
+            let fragment = &fragments[run.fragment];
+            let size = f32::from(fragment.size);
+
+            let color = fragment.color.unwrap_or(if fragment.is_link {
+                LINK_COLOR
+            } else {
+                defaults.text.color
+            });
+
+            primitives.push(Primitive::Text {
+                content: fragment.content[run.range.clone()].to_string(),
+                bounds: Rectangle {
+                    x: bounds.x + run.bounds.x,
+                    y: bounds.y + run.bounds.y,
+                    width: f32::INFINITY,
+                    height: f32::INFINITY,
+                },
+                size,
+                color,
+                font: fragment.font,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            });
+
+            let thickness = (size / 14.0).max(1.0);
+
+            let line = |offset: f32| Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + run.bounds.x,
+                    y: bounds.y + run.baseline + offset,
+                    width: run.bounds.width,
+                    height: thickness,
+                },
+                background: Background::Color(color),
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            };
+
+            if fragment.is_underlined || hovered_link == Some(run.fragment) {
+                primitives.push(line(thickness));
+            }
+
+            if fragment.is_struck_through {
+                primitives.push(line(-size * 0.3));
+            }
+        }
+
+        let mouse_interaction = if hovered_link.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
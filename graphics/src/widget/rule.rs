@@ -0,0 +1,60 @@
+//! Display a horizontal or vertical rule for dividing content.
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::rule;
+use iced_native::{Background, Color, Rectangle};
+
+pub use iced_style::rule::{FillMode, Style, StyleSheet};
+
+/// Display a horizontal or vertical rule for dividing content.
+///
+/// This is an alias of an `iced_native` rule with an `iced_graphics::Renderer`.
+pub type Rule<Backend> = iced_native::Rule<Renderer<Backend>>;
+
+impl<B> rule::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        style_sheet: &Self::Style,
+        is_horizontal: bool,
+    ) -> Self::Output {
+        let style = style_sheet.style(self.theme());
+        let width = f32::from(style.width);
+
+        let line = if is_horizontal {
+            let (offset, length) = style.fill_mode.fill(bounds.width);
+
+            Rectangle {
+                x: bounds.x + offset,
+                y: (bounds.center_y() - width / 2.0).round(),
+                width: length,
+                height: width,
+            }
+        } else {
+            let (offset, length) = style.fill_mode.fill(bounds.height);
+
+            Rectangle {
+                x: (bounds.center_x() - width / 2.0).round(),
+                y: bounds.y + offset,
+                width,
+                height: length,
+            }
+        };
+
+        (
+            Primitive::Quad {
+                bounds: line,
+                background: Background::Color(style.color),
+                border_radius: style.radius,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -2,22 +2,19 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::scrollable;
-use iced_native::{Background, Color, Rectangle, Vector};
+use iced_native::{Background, Color, Gradient, Rectangle, Vector};
 
-pub use iced_native::scrollable::State;
+pub use iced_native::scrollable::{Direction, State, Viewport};
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
-/// A widget that can vertically display an infinite amount of content
-/// with a scrollbar.
+/// A widget that can display an infinite amount of content with
+/// scrollbars.
 ///
 /// This is an alias of an `iced_native` scrollable with a default
 /// `Renderer`.
 pub type Scrollable<'a, Message, Backend> =
     iced_native::Scrollable<'a, Message, Renderer<Backend>>;
 
-const SCROLLBAR_WIDTH: u16 = 10;
-const SCROLLBAR_MARGIN: u16 = 2;
-
 impl<B> scrollable::Renderer for Renderer<B>
 where
     B: Backend,
@@ -29,13 +26,17 @@ where
         bounds: Rectangle,
         content_bounds: Rectangle,
         offset: u32,
+        style_sheet: &Self::Style,
     ) -> Option<scrollable::Scrollbar> {
         if content_bounds.height > bounds.height {
+            let style = style_sheet.active(self.theme());
+            let margin = f32::from(style.margin);
+            let thickness = f32::from(style.width) + 2.0 * margin;
+
             let scrollbar_bounds = Rectangle {
-                x: bounds.x + bounds.width
-                    - f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
+                x: bounds.x + bounds.width - thickness,
                 y: bounds.y,
-                width: f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
+                width: thickness,
                 height: bounds.height,
             };
 
@@ -44,9 +45,9 @@ where
             let y_offset = offset as f32 * ratio;
 
             let scroller_bounds = Rectangle {
-                x: scrollbar_bounds.x + f32::from(SCROLLBAR_MARGIN),
+                x: scrollbar_bounds.x + margin,
                 y: scrollbar_bounds.y + y_offset,
-                width: scrollbar_bounds.width - f32::from(2 * SCROLLBAR_MARGIN),
+                width: scrollbar_bounds.width - 2.0 * margin,
                 height: scrollbar_height,
             };
 
@@ -61,6 +62,54 @@ where
         }
     }
 
+    fn horizontal_scrollbar(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        offset: u32,
+        style_sheet: &Self::Style,
+    ) -> Option<scrollable::Scrollbar> {
+        if content_bounds.width > bounds.width {
+            let style = style_sheet.active(self.theme());
+            let margin = f32::from(style.margin);
+            let thickness = f32::from(style.width) + 2.0 * margin;
+
+            // Leave the corner to a vertical scrollbar
+            let length = if content_bounds.height > bounds.height {
+                bounds.width - thickness
+            } else {
+                bounds.width
+            };
+
+            let scrollbar_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height - thickness,
+                width: length,
+                height: thickness,
+            };
+
+            let scroller_width = length * bounds.width / content_bounds.width;
+            let x_offset = (length - scroller_width) * offset as f32
+                / (content_bounds.width - bounds.width);
+
+            let scroller_bounds = Rectangle {
+                x: scrollbar_bounds.x + x_offset,
+                y: scrollbar_bounds.y + margin,
+                width: scroller_width,
+                height: thickness - 2.0 * margin,
+            };
+
+            Some(scrollable::Scrollbar {
+                bounds: scrollbar_bounds,
+                scroller: scrollable::Scroller {
+                    bounds: scroller_bounds,
+                },
+            })
+        } else {
+            None
+        }
+    }
+
     fn draw(
         &mut self,
         state: &scrollable::State,
@@ -69,66 +118,105 @@ where
         is_mouse_over: bool,
         is_mouse_over_scrollbar: bool,
         scrollbar: Option<scrollable::Scrollbar>,
-        offset: u32,
+        horizontal_scrollbar: Option<scrollable::Scrollbar>,
+        offset: Vector<u32>,
         style_sheet: &Self::Style,
         (content, mouse_interaction): Self::Output,
     ) -> Self::Output {
         (
-            if let Some(scrollbar) = scrollbar {
+            if scrollbar.is_some() || horizontal_scrollbar.is_some() {
                 let clip = Primitive::Clip {
                     bounds,
-                    offset: Vector::new(0, offset),
+                    offset,
                     content: Box::new(content),
                 };
 
                 let style = if state.is_scroller_grabbed() {
-                    style_sheet.dragging()
+                    style_sheet.dragging(self.theme())
                 } else if is_mouse_over_scrollbar {
-                    style_sheet.hovered()
+                    style_sheet.hovered(self.theme())
                 } else {
-                    style_sheet.active()
+                    style_sheet.active(self.theme())
                 };
 
                 let is_scrollbar_visible =
                     style.background.is_some() || style.border_width > 0;
 
-                let scroller = if is_mouse_over
+                let is_scroller_visible = is_mouse_over
                     || state.is_scroller_grabbed()
-                    || is_scrollbar_visible
+                    || is_scrollbar_visible;
+
+                let visibility = state.scrollbar_visibility();
+                let fade = |color: Color| Color {
+                    a: color.a * visibility,
+                    ..color
+                };
+
+                let mut primitives = vec![clip];
+
+                for (scrollbar, is_horizontal) in
+                    scrollbar.iter().map(|scrollbar| (scrollbar, false)).chain(
+                        horizontal_scrollbar
+                            .iter()
+                            .map(|scrollbar| (scrollbar, true)),
+                    )
                 {
-                    Primitive::Quad {
-                        bounds: scrollbar.scroller.bounds,
-                        background: Background::Color(style.scroller.color),
-                        border_radius: style.scroller.border_radius,
-                        border_width: style.scroller.border_width,
-                        border_color: style.scroller.border_color,
+                    if visibility <= 0.0 {
+                        continue;
                     }
-                } else {
-                    Primitive::None
-                };
 
-                let scrollbar = if is_scrollbar_visible {
-                    Primitive::Quad {
-                        bounds: Rectangle {
-                            x: scrollbar.bounds.x + f32::from(SCROLLBAR_MARGIN),
-                            width: scrollbar.bounds.width
-                                - f32::from(2 * SCROLLBAR_MARGIN),
-                            ..scrollbar.bounds
-                        },
-                        background: style
-                            .background
-                            .unwrap_or(Background::Color(Color::TRANSPARENT)),
-                        border_radius: style.border_radius,
-                        border_width: style.border_width,
-                        border_color: style.border_color,
+                    if is_scrollbar_visible {
+                        let margin = f32::from(style.margin);
+
+                        let track = if is_horizontal {
+                            Rectangle {
+                                y: scrollbar.bounds.y + margin,
+                                height: scrollbar.bounds.height - 2.0 * margin,
+                                ..scrollbar.bounds
+                            }
+                        } else {
+                            Rectangle {
+                                x: scrollbar.bounds.x + margin,
+                                width: scrollbar.bounds.width - 2.0 * margin,
+                                ..scrollbar.bounds
+                            }
+                        };
+
+                        primitives.push(Primitive::Quad {
+                            bounds: track,
+                            background: match style.background {
+                                Some(Background::Color(color)) => {
+                                    Background::Color(fade(color))
+                                }
+                                Some(Background::Gradient(gradient)) => {
+                                    Background::Gradient(Gradient {
+                                        start: fade(gradient.start),
+                                        end: fade(gradient.end),
+                                        ..gradient
+                                    })
+                                }
+                                None => Background::Color(Color::TRANSPARENT),
+                            },
+                            border_radius: style.border_radius,
+                            border_width: style.border_width,
+                            border_color: fade(style.border_color),
+                        });
                     }
-                } else {
-                    Primitive::None
-                };
 
-                Primitive::Group {
-                    primitives: vec![clip, scrollbar, scroller],
+                    if is_scroller_visible {
+                        primitives.push(Primitive::Quad {
+                            bounds: scrollbar.scroller.bounds,
+                            background: Background::Color(fade(
+                                style.scroller.color,
+                            )),
+                            border_radius: style.scroller.border_radius,
+                            border_width: style.scroller.border_width,
+                            border_color: fade(style.scroller.border_color),
+                        });
+                    }
                 }
+
+                Primitive::Group { primitives }
             } else {
                 content
             },
@@ -4,22 +4,22 @@
 //!
 //! [`Slider`]: struct.Slider.html
 //! [`State`]: struct.State.html
-use crate::{Backend, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer, Theme};
 use iced_native::mouse;
 use iced_native::slider;
 use iced_native::{Background, Color, Point, Rectangle};
 
-pub use iced_native::slider::State;
+pub use iced_native::slider::{Orientation, State};
 pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
 
-/// An horizontal bar and a handle that selects a single value from a range of
-/// values.
+/// A bar and a handle that selects a single value from a range of values.
 ///
 /// This is an alias of an `iced_native` slider with an `iced_wgpu::Renderer`.
 pub type Slider<'a, Message, Backend> =
     iced_native::Slider<'a, Message, Renderer<Backend>>;
 
 const HANDLE_HEIGHT: f32 = 22.0;
+const TICK_LENGTH: f32 = 4.0;
 
 impl<B> slider::Renderer for Renderer<B>
 where
@@ -38,87 +38,156 @@ where
         range: std::ops::RangeInclusive<f32>,
         value: f32,
         is_dragging: bool,
+        is_disabled: bool,
+        orientation: Orientation,
+        ticks: &[f32],
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let is_mouse_over = bounds.contains(cursor_position);
-
-        let style = if is_dragging {
-            style_sheet.dragging()
-        } else if is_mouse_over {
-            style_sheet.hovered()
-        } else {
-            style_sheet.active()
-        };
+        let (style, mouse_interaction) = appearance(
+            bounds,
+            cursor_position,
+            is_dragging,
+            is_disabled,
+            style_sheet.as_ref(),
+            self.theme(),
+        );
 
-        let rail_y = bounds.y + (bounds.height / 2.0).round();
-
-        let (rail_top, rail_bottom) = (
-            Primitive::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y,
-                    width: bounds.width,
-                    height: 2.0,
-                },
-                background: Background::Color(style.rail_colors.0),
-                border_radius: 0,
-                border_width: 0,
-                border_color: Color::TRANSPARENT,
+        (
+            draw(bounds, range, &[value], orientation, ticks, &style),
+            mouse_interaction,
+        )
+    }
+}
+
+/// Produces the style and the mouse interaction of a slider.
+pub(crate) fn appearance(
+    bounds: Rectangle,
+    cursor_position: Point,
+    is_dragging: bool,
+    is_disabled: bool,
+    style_sheet: &dyn StyleSheet,
+    theme: &Theme,
+) -> (Style, mouse::Interaction) {
+    let is_mouse_over = bounds.contains(cursor_position);
+
+    if is_disabled {
+        (style_sheet.disabled(theme), mouse::Interaction::default())
+    } else if is_dragging {
+        (style_sheet.dragging(theme), mouse::Interaction::Grabbing)
+    } else if is_mouse_over {
+        (style_sheet.hovered(theme), mouse::Interaction::Grab)
+    } else {
+        (style_sheet.active(theme), mouse::Interaction::default())
+    }
+}
+
+/// Draws the rail, the tick marks, and the handles of a slider.
+///
+/// When there are two values, the interval between them is highlighted.
+pub(crate) fn draw(
+    bounds: Rectangle,
+    range: std::ops::RangeInclusive<f32>,
+    values: &[f32],
+    orientation: Orientation,
+    ticks: &[f32],
+    style: &Style,
+) -> Primitive {
+    // Every shape is computed along the main axis of the slider and then
+    // mapped to the bounds, with values growing upwards when vertical.
+    let (length, rail) = match orientation {
+        Orientation::Horizontal => {
+            (bounds.width, bounds.y + (bounds.height / 2.0).round())
+        }
+        Orientation::Vertical => {
+            (bounds.height, bounds.x + (bounds.width / 2.0).round())
+        }
+    };
+
+    let rectangle = |offset: f32, size: f32, cross: f32, thickness: f32| {
+        match orientation {
+            Orientation::Horizontal => Rectangle {
+                x: bounds.x + offset,
+                y: cross,
+                width: size,
+                height: thickness,
             },
-            Primitive::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y + 2.0,
-                    width: bounds.width,
-                    height: 2.0,
-                },
-                background: Background::Color(style.rail_colors.1),
-                border_radius: 0,
-                border_width: 0,
-                border_color: Color::TRANSPARENT,
+            Orientation::Vertical => Rectangle {
+                x: cross,
+                y: bounds.y + bounds.height - offset - size,
+                width: thickness,
+                height: size,
             },
-        );
+        }
+    };
 
-        let (range_start, range_end) = range.into_inner();
-
-        let (handle_width, handle_height, handle_border_radius) =
-            match style.handle.shape {
-                HandleShape::Circle { radius } => {
-                    (f32::from(radius * 2), f32::from(radius * 2), radius)
-                }
-                HandleShape::Rectangle {
-                    width,
-                    border_radius,
-                } => (f32::from(width), HANDLE_HEIGHT, border_radius),
-            };
-
-        let handle_offset = (bounds.width - handle_width)
-            * ((value - range_start) / (range_end - range_start).max(1.0));
-
-        let handle = Primitive::Quad {
-            bounds: Rectangle {
-                x: bounds.x + handle_offset.round(),
-                y: rail_y - handle_height / 2.0,
-                width: handle_width,
-                height: handle_height,
-            },
+    let quad = |bounds, color| Primitive::Quad {
+        bounds,
+        background: Background::Color(color),
+        border_radius: 0,
+        border_width: 0,
+        border_color: Color::TRANSPARENT,
+    };
+
+    let mut primitives = vec![
+        quad(rectangle(0.0, length, rail, 2.0), style.rail_colors.0),
+        quad(rectangle(0.0, length, rail + 2.0, 2.0), style.rail_colors.1),
+    ];
+
+    let (range_start, range_end) = range.into_inner();
+
+    let (handle_width, handle_height, handle_border_radius) =
+        match style.handle.shape {
+            HandleShape::Circle { radius } => {
+                (f32::from(radius * 2), f32::from(radius * 2), radius)
+            }
+            HandleShape::Rectangle {
+                width,
+                border_radius,
+            } => (f32::from(width), HANDLE_HEIGHT, border_radius),
+        };
+
+    let handle_offset = |value: f32| {
+        ((length - handle_width)
+            * ((value - range_start) / (range_end - range_start).max(1.0)))
+        .round()
+    };
+
+    for &tick in ticks {
+        primitives.push(quad(
+            rectangle(
+                handle_offset(tick) + (handle_width / 2.0).floor(),
+                1.0,
+                rail + 6.0,
+                TICK_LENGTH,
+            ),
+            style.rail_colors.0,
+        ));
+    }
+
+    if let [start, end] = values {
+        let start = handle_offset(*start) + handle_width / 2.0;
+        let end = handle_offset(*end) + handle_width / 2.0;
+
+        primitives.push(quad(
+            rectangle(start, end - start, rail, 4.0),
+            style.handle.border_color,
+        ));
+    }
+
+    for &value in values {
+        primitives.push(Primitive::Quad {
+            bounds: rectangle(
+                handle_offset(value),
+                handle_width,
+                rail - handle_height / 2.0,
+                handle_height,
+            ),
             background: Background::Color(style.handle.color),
             border_radius: handle_border_radius,
             border_width: style.handle.border_width,
             border_color: style.handle.border_color,
-        };
-
-        (
-            Primitive::Group {
-                primitives: vec![rail_top, rail_bottom, handle],
-            },
-            if is_dragging {
-                mouse::Interaction::Grabbing
-            } else if is_mouse_over {
-                mouse::Interaction::Grab
-            } else {
-                mouse::Interaction::default()
-            },
-        )
+        });
     }
+
+    Primitive::Group { primitives }
 }
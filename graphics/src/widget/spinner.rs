@@ -0,0 +1,61 @@
+//! Show an indeterminate loading indicator.
+//!
+//! A [`Spinner`] animates on its own while it is displayed.
+//!
+//! [`Spinner`]: type.Spinner.html
+use crate::widget::progress_circle::arc;
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::spinner;
+use iced_native::Rectangle;
+
+use std::f32::consts::PI;
+
+pub use iced_style::spinner::{Style, StyleSheet};
+
+/// A spinning arc that indicates that some work is in progress.
+///
+/// This is an alias of an `iced_native` spinner with an
+/// `iced_wgpu::Renderer`.
+pub type Spinner<Backend> = iced_native::Spinner<Renderer<Backend>>;
+
+impl<B> spinner::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 32;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        phase: f32,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+        let thickness = f32::from(style.thickness);
+
+        let mut primitives = Vec::with_capacity(2);
+
+        if let Some(track) = style.track {
+            primitives.push(arc(bounds, thickness, 0.0, 2.0 * PI, track));
+        }
+
+        // The arc grows and shrinks once per turn
+        let sweep = (0.25 + 0.5 * (phase * PI).sin()) * 2.0 * PI;
+
+        primitives.push(arc(
+            bounds,
+            thickness,
+            phase * 2.0 * PI,
+            sweep,
+            style.color,
+        ));
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::stack;
+use iced_native::{Element, Layout, Point, Vector};
+
+/// A container that layers its contents on top of each other.
+pub type Stack<'a, Message, Backend> =
+    iced_native::Stack<'a, Message, Renderer<Backend>>;
+
+impl<B> stack::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_positions: &[Point],
+    ) -> Self::Output {
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        (
+            Primitive::Group {
+                primitives: children
+                    .iter()
+                    .zip(layout.children())
+                    .zip(cursor_positions)
+                    .enumerate()
+                    .map(|(i, ((child, layout), cursor_position))| {
+                        let (primitive, new_mouse_interaction) = child.draw(
+                            self,
+                            defaults,
+                            layout,
+                            *cursor_position,
+                        );
+
+                        if new_mouse_interaction > mouse_interaction {
+                            mouse_interaction = new_mouse_interaction;
+                        }
+
+                        if i == 0 {
+                            primitive
+                        } else {
+                            // Every child is drawn in a new layer, so it
+                            // covers the text of the previous ones
+                            Primitive::Clip {
+                                bounds: layout.bounds(),
+                                offset: Vector::new(0, 0),
+                                content: Box::new(primitive),
+                            }
+                        }
+                    })
+                    .collect(),
+            },
+            mouse_interaction,
+        )
+    }
+}
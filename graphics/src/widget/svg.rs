@@ -3,7 +3,7 @@ use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::{mouse, svg, Layout};
 
-pub use iced_native::svg::{Handle, Svg};
+pub use iced_native::svg::{Appearance, Handle, Svg};
 
 impl<B> svg::Renderer for Renderer<B>
 where
@@ -16,12 +16,14 @@ where
     fn draw(
         &mut self,
         handle: svg::Handle,
+        appearance: svg::Appearance,
         layout: Layout<'_>,
     ) -> Self::Output {
         (
             Primitive::Svg {
                 handle,
                 bounds: layout.bounds(),
+                appearance,
             },
             mouse::Interaction::default(),
         )
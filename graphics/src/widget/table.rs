@@ -0,0 +1,231 @@
+//! Display data in rows and columns with a fixed header.
+//!
+//! A [`Table`] has some local [`State`].
+//!
+//! [`Table`]: type.Table.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::defaults::Defaults;
+use crate::triangle::{Mesh2D, Vertex2D};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    mouse, table, Background, Color, Element, Font, HorizontalAlignment,
+    Layout, Point, Rectangle, Size, Vector, VerticalAlignment,
+};
+
+pub use iced_native::table::{Column, Header, SortOrder, State};
+pub use iced_style::table::{Style, StyleSheet};
+
+/// A table with a fixed header row, resizable and sortable columns, and
+/// selectable rows.
+///
+/// This is an alias of an `iced_native` table with an `iced_wgpu::Renderer`.
+pub type Table<'a, Message, Backend> =
+    iced_native::Table<'a, Message, Renderer<Backend>>;
+
+impl<B> table::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: u16 = 5;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Defaults,
+        header_layout: Layout<'_>,
+        headers: &[Header<'_>],
+        viewport: Rectangle,
+        body_layout: Layout<'_>,
+        cells: &[Vec<Element<'_, Message, Self>>],
+        offset: f32,
+        selected: Option<usize>,
+        is_resizing: bool,
+        cursor_position: Point,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+        let header_bounds = header_layout.bounds();
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let cursor = if viewport.contains(cursor_position) {
+            Point::new(cursor_position.x, cursor_position.y + offset)
+        } else {
+            Point::new(cursor_position.x, -1.0)
+        };
+
+        let mut rows = Vec::new();
+
+        for (index, (row, row_layout)) in
+            cells.iter().zip(body_layout.children()).enumerate()
+        {
+            let bounds = Rectangle {
+                width: row_layout.bounds().width.max(viewport.width),
+                ..row_layout.bounds()
+            };
+
+            // Rows outside of the viewport are not drawn
+            if bounds.y + bounds.height < viewport.y + offset
+                || bounds.y > viewport.y + viewport.height + offset
+            {
+                continue;
+            }
+
+            let background = if selected == Some(index) {
+                Some(style.selected_background)
+            } else if bounds.contains(cursor) {
+                Some(style.hovered_background)
+            } else if index % 2 == 1 {
+                style.alternate_background.or(style.background)
+            } else {
+                style.background
+            };
+
+            if let Some(background) = background {
+                rows.push(Primitive::Quad {
+                    bounds,
+                    background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            for (cell, layout) in row.iter().zip(row_layout.children()) {
+                let (primitive, new_mouse_interaction) =
+                    cell.draw(self, defaults, layout, cursor);
+
+                if new_mouse_interaction > mouse_interaction {
+                    mouse_interaction = new_mouse_interaction;
+                }
+
+                rows.push(primitive);
+            }
+        }
+
+        let mut primitives = vec![Primitive::Clip {
+            bounds: viewport,
+            offset: Vector::new(0, offset as u32),
+            content: Box::new(Primitive::Group { primitives: rows }),
+        }];
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                width: header_bounds.width.max(viewport.width),
+                ..header_bounds
+            },
+            background: style.header_background,
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        let color = style.header_text_color;
+
+        for (header, layout) in headers.iter().zip(header_layout.children()) {
+            let bounds = layout.bounds();
+
+            primitives.push(Primitive::Text {
+                content: header.title.to_string(),
+                bounds: Rectangle {
+                    x: bounds.x + f32::from(padding),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: f32::from(text_size),
+                font,
+                color,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+
+            if let Some(order) = header.sort {
+                primitives.push(arrow(
+                    bounds,
+                    padding,
+                    text_size,
+                    order,
+                    [color.r, color.g, color.b, color.a],
+                ));
+            }
+
+            // Column separator
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + bounds.width - 1.0,
+                    y: bounds.y,
+                    width: 1.0,
+                    height: bounds.height + viewport.height,
+                },
+                background: Background::Color(style.separator_color),
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        let is_over_header = header_bounds.contains(cursor_position);
+
+        if is_resizing {
+            mouse_interaction = mouse::Interaction::ResizingHorizontally;
+        } else if is_over_header && !headers.is_empty() {
+            mouse_interaction = mouse::Interaction::Pointer;
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
+
+/// Draws a triangle at the right side of the bounds, pointing up for an
+/// ascending order and down for a descending one.
+fn arrow(
+    bounds: Rectangle,
+    padding: u16,
+    text_size: u16,
+    order: SortOrder,
+    color: [f32; 4],
+) -> Primitive {
+    let width = f32::from(text_size) * 0.5;
+    let height = width / 2.0;
+
+    let center_x = bounds.x + bounds.width
+        - f32::from(padding)
+        - f32::from(text_size) / 2.0;
+
+    let vertex = |x, y| Vertex2D {
+        position: [x, y],
+        color,
+    };
+
+    let vertices = match order {
+        SortOrder::Ascending => vec![
+            vertex(width / 2.0, 0.0),
+            vertex(width, height),
+            vertex(0.0, height),
+        ],
+        SortOrder::Descending => vec![
+            vertex(0.0, 0.0),
+            vertex(width, 0.0),
+            vertex(width / 2.0, height),
+        ],
+    };
+
+    Primitive::Translate {
+        translation: Vector::new(
+            center_x - width / 2.0,
+            bounds.center_y() - height / 2.0,
+        ),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D {
+                vertices,
+                indices: vec![0, 1, 2],
+            },
+            size: Size::new(width, height),
+        }),
+    }
+}
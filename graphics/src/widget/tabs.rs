@@ -0,0 +1,193 @@
+//! Switch between different views with a strip of tabs.
+//!
+//! A [`Tabs`] widget has some local [`State`].
+//!
+//! [`Tabs`]: type.Tabs.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::defaults::Defaults;
+use crate::{Primitive, Renderer};
+use iced_native::{
+    mouse, tabs, Background, Color, Element, Font, HorizontalAlignment, Layout,
+    Point, Rectangle, Vector, VerticalAlignment,
+};
+
+pub use iced_native::tabs::{Header, State, Tab};
+pub use iced_style::tabs::{Style, StyleSheet};
+
+/// A strip of tabs on top of the content of the active one.
+///
+/// This is an alias of an `iced_native` tabs widget with an
+/// `iced_wgpu::Renderer`.
+pub type Tabs<'a, Message, Backend> =
+    iced_native::Tabs<'a, Message, Renderer<Backend>>;
+
+impl<B> tabs::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: u16 = 5;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Defaults,
+        strip_bounds: Rectangle,
+        headers: &[Header<'_>],
+        cursor_position: Point,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        icon_font: Font,
+        style_sheet: &Box<dyn StyleSheet>,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+        let padding = f32::from(padding);
+        let size = f32::from(text_size);
+
+        let (content, content_interaction) =
+            content.draw(self, defaults, content_layout, cursor_position);
+
+        let mut mouse_interaction = mouse::Interaction::default();
+        let mut tabs = Vec::with_capacity(headers.len());
+        let mut dragged = None;
+
+        for header in headers {
+            let bounds = header.bounds;
+
+            let background = if header.is_active || header.is_dragged {
+                Some(style.active_tab_background)
+            } else if header.is_hovered {
+                Some(style.hovered_tab_background)
+            } else {
+                style.tab_background
+            };
+
+            let text_color = if header.is_active {
+                style.active_text_color
+            } else {
+                style.text_color
+            };
+
+            let mut primitives = vec![Primitive::Quad {
+                bounds,
+                background: background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                border_radius: 0,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            }];
+
+            let mut x = bounds.x + padding;
+
+            if let Some(icon) = header.icon {
+                primitives.push(Primitive::Text {
+                    content: icon.to_string(),
+                    bounds: Rectangle {
+                        x: x + size / 2.0,
+                        y: bounds.center_y(),
+                        width: size,
+                        height: bounds.height,
+                    },
+                    size,
+                    font: icon_font,
+                    color: text_color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+
+                x += size + padding;
+            }
+
+            primitives.push(Primitive::Text {
+                content: header.label.to_string(),
+                bounds: Rectangle {
+                    x,
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size,
+                font,
+                color: text_color,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+
+            if let Some(close_bounds) = header.close_bounds {
+                if close_bounds.contains(cursor_position) {
+                    primitives.push(Primitive::Quad {
+                        bounds: close_bounds,
+                        background: style.hovered_tab_background,
+                        border_radius: 2,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
+                    });
+                }
+
+                primitives.push(Primitive::Text {
+                    content: String::from("\u{00D7}"),
+                    bounds: Rectangle {
+                        x: close_bounds.center_x(),
+                        y: close_bounds.center_y(),
+                        ..close_bounds
+                    },
+                    size,
+                    font,
+                    color: text_color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+
+            if header.is_dragged {
+                mouse_interaction = mouse::Interaction::Grabbing;
+            } else if header.is_hovered {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            let tab = Primitive::Group { primitives };
+
+            // The dragged tab is drawn on top of the others
+            if header.is_dragged {
+                dragged = Some(tab);
+            } else {
+                tabs.push(tab);
+            }
+        }
+
+        tabs.extend(dragged);
+
+        let strip = Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
+                    bounds: strip_bounds,
+                    background: style.strip_background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Primitive::Clip {
+                    bounds: strip_bounds,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(Primitive::Group { primitives: tabs }),
+                },
+            ],
+        };
+
+        let is_dragging = mouse_interaction == mouse::Interaction::Grabbing;
+
+        if !is_dragging && !strip_bounds.contains(cursor_position) {
+            mouse_interaction = content_interaction;
+        }
+
+        (
+            Primitive::Group {
+                primitives: vec![strip, content],
+            },
+            mouse_interaction,
+        )
+    }
+}
@@ -0,0 +1,173 @@
+//! Type entries that become removable tags.
+//!
+//! A [`TagInput`] has some local [`State`].
+//!
+//! [`TagInput`]: type.TagInput.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer, Theme};
+use iced_native::mouse;
+use iced_native::tag_input::{self, Tag};
+use iced_native::{
+    Background, Color, Font, HorizontalAlignment, Point, Rectangle,
+    VerticalAlignment,
+};
+use iced_style::text_input;
+
+pub use iced_native::tag_input::State;
+pub use iced_style::menu;
+pub use iced_style::tag_input::{Style, StyleSheet};
+
+/// A field where typed entries become tags, which can be removed.
+///
+/// This is an alias of an `iced_native` tag input with an `iced_wgpu::Renderer`.
+pub type TagInput<'a, Message, Backend> =
+    iced_native::TagInput<'a, Message, Renderer<Backend>>;
+
+impl<B> tag_input::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 5;
+    const DEFAULT_SPACING: u16 = 5;
+    const TAG_PADDING: u16 = 3;
+
+    fn input_style(
+        style: &Box<dyn StyleSheet>,
+    ) -> Box<dyn text_input::StyleSheet> {
+        Box::new(Field {
+            placeholder_color: style.placeholder_color(),
+            value_color: style.value_color(),
+            selection_color: style.selection_color(),
+        })
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        is_focused: bool,
+        tags: &[Tag<'_>],
+        text_size: u16,
+        (input, input_interaction): Self::Output,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_focused {
+            style_sheet.focused()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        }];
+
+        let mut mouse_interaction = if is_mouse_over {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        };
+
+        for tag in tags {
+            primitives.push(Primitive::Quad {
+                bounds: tag.bounds,
+                background: style.tag_background,
+                border_radius: style.tag_border_radius,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            primitives.push(Primitive::Text {
+                content: tag.label.to_string(),
+                bounds: tag.label_bounds,
+                color: style.tag_text_color,
+                size: f32::from(text_size),
+                font: Font::Default,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            });
+
+            let is_remove_hovered = tag.remove.contains(cursor_position);
+
+            if is_remove_hovered {
+                mouse_interaction = mouse::Interaction::Pointer;
+            } else if tag.bounds.contains(cursor_position) {
+                mouse_interaction = mouse::Interaction::default();
+            }
+
+            primitives.push(Primitive::Text {
+                content: String::from("×"),
+                bounds: Rectangle {
+                    x: tag.remove.center_x(),
+                    y: tag.remove.center_y(),
+                    ..tag.remove
+                },
+                color: if is_remove_hovered {
+                    style.hovered_remove_color
+                } else {
+                    style.remove_color
+                },
+                size: f32::from(text_size),
+                font: Font::Default,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        primitives.push(input);
+
+        (
+            Primitive::Group { primitives },
+            if input_interaction == mouse::Interaction::default() {
+                mouse_interaction
+            } else {
+                input_interaction
+            },
+        )
+    }
+}
+
+/// The style of the text field of a tag input, which is drawn without a
+/// background or a border.
+struct Field {
+    placeholder_color: Color,
+    value_color: Color,
+    selection_color: Color,
+}
+
+impl text_input::StyleSheet for Field {
+    fn active(&self, _theme: &Theme) -> text_input::Style {
+        text_input::Style {
+            background: Background::Color(Color::TRANSPARENT),
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+
+    fn focused(&self, theme: &Theme) -> text_input::Style {
+        self.active(theme)
+    }
+
+    fn placeholder_color(&self, _theme: &Theme) -> Color {
+        self.placeholder_color
+    }
+
+    fn value_color(&self, _theme: &Theme) -> Color {
+        self.value_color
+    }
+
+    fn selection_color(&self, _theme: &Theme) -> Color {
+        self.selection_color
+    }
+}
@@ -0,0 +1,207 @@
+//! Display multi-line fields that can be filled with text.
+//!
+//! A [`TextEditor`] has some local [`State`].
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::text_editor::{self, Line};
+use iced_native::text_input::{cursor, Value};
+use iced_native::{
+    Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
+    Vector, VerticalAlignment,
+};
+use std::f32;
+
+pub use iced_native::text_editor::State;
+pub use iced_style::text_input::{Style, StyleSheet};
+
+/// A multi-line field that can be filled with text.
+///
+/// A [`TextEditor`] shares its style with a [`TextInput`].
+///
+/// This is an alias of an `iced_native` text editor with an `iced_wgpu::Renderer`.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+/// [`TextInput`]: ../text_input/type.TextInput.html
+pub type TextEditor<'a, Message, Backend> =
+    iced_native::TextEditor<'a, Message, Renderer<Backend>>;
+
+impl<B> text_editor::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Font = Font;
+    type Style = Box<dyn StyleSheet>;
+
+    fn default_size(&self) -> u16 {
+        20
+    }
+
+    fn measure_value(&self, value: &str, size: u16, font: Font) -> f32 {
+        let (width, _) = self.backend().measure(
+            value,
+            f32::from(size),
+            font,
+            Size::INFINITY,
+        );
+
+        width
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        text_bounds: Rectangle,
+        cursor_position: Point,
+        font: Font,
+        size: u16,
+        placeholder: &str,
+        value: &Value,
+        lines: &[Line],
+        state: &State,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if state.is_focused() {
+            style_sheet.focused(self.theme())
+        } else if is_mouse_over {
+            style_sheet.hovered(self.theme())
+        } else {
+            style_sheet.active(self.theme())
+        };
+
+        let editor = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let line_height = f32::from(size);
+        let scroll_offset = state.scroll_offset();
+
+        let measure = |line: &Line, end: usize| {
+            self.measure_value(
+                &value.select(line.start(), end).to_string(),
+                size,
+                font,
+            )
+        };
+
+        let line_bounds = |index: usize, x: f32, width: f32| Rectangle {
+            x: text_bounds.x + x,
+            y: text_bounds.y + index as f32 * line_height,
+            width,
+            height: line_height,
+        };
+
+        let highlight = |bounds: Rectangle, color: Color| Primitive::Quad {
+            bounds,
+            background: Background::Color(color),
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        let mut primitives = Vec::new();
+
+        if state.is_focused() {
+            match state.cursor().state(value) {
+                cursor::State::Index(position) => {
+                    let index = find_line(lines, position);
+                    let x = measure(&lines[index], position);
+
+                    primitives.push(highlight(
+                        line_bounds(index, x, 1.0),
+                        style_sheet.value_color(self.theme()),
+                    ));
+                }
+                cursor::State::Selection { start, end } => {
+                    let (left, right) = (start.min(end), start.max(end));
+
+                    let first = find_line(lines, left);
+                    let last = find_line(lines, right);
+
+                    for (index, line) in
+                        lines.iter().enumerate().take(last + 1).skip(first)
+                    {
+                        let from = left.max(line.start());
+                        let to = right.min(line.end());
+
+                        let x = measure(line, from);
+                        let mut width = measure(line, to) - x;
+
+                        // Selected line breaks are shown as a bit of space
+                        if right > line.end() && to == line.end() {
+                            width += line_height / 4.0;
+                        }
+
+                        primitives.push(highlight(
+                            line_bounds(index, x, width),
+                            style_sheet.selection_color(self.theme()),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if value.len() == 0 {
+            primitives.push(Primitive::Text {
+                content: placeholder.to_string(),
+                color: style_sheet.placeholder_color(self.theme()),
+                font,
+                bounds: text_bounds,
+                size: line_height,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            });
+        } else {
+            let first = (scroll_offset / line_height).floor() as usize;
+            let visible =
+                (text_bounds.height / line_height).ceil() as usize + 1;
+
+            for (index, line) in
+                lines.iter().enumerate().skip(first).take(visible)
+            {
+                primitives.push(Primitive::Text {
+                    content: value.select(line.start(), line.end()).to_string(),
+                    color: style_sheet.value_color(self.theme()),
+                    font,
+                    bounds: line_bounds(index, 0.0, f32::INFINITY),
+                    size: line_height,
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Top,
+                });
+            }
+        }
+
+        let contents = Primitive::Clip {
+            bounds: text_bounds,
+            offset: Vector::new(0, scroll_offset as u32),
+            content: Box::new(Primitive::Group { primitives }),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![editor, contents],
+            },
+            if is_mouse_over {
+                mouse::Interaction::Text
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
+
+fn find_line(lines: &[Line], position: usize) -> usize {
+    lines
+        .iter()
+        .rposition(|line| line.start() <= position)
+        .unwrap_or(0)
+}
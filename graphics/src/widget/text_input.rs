@@ -79,22 +79,26 @@ where
         &mut self,
         bounds: Rectangle,
         text_bounds: Rectangle,
+        toggle_bounds: Option<Rectangle>,
         cursor_position: Point,
         font: Font,
         size: u16,
         placeholder: &str,
         value: &text_input::Value,
         state: &text_input::State,
+        is_disabled: bool,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if state.is_focused() {
-            style_sheet.focused()
+        let style = if is_disabled {
+            style_sheet.disabled(self.theme())
+        } else if state.is_focused() {
+            style_sheet.focused(self.theme())
         } else if is_mouse_over {
-            style_sheet.hovered()
+            style_sheet.hovered(self.theme())
         } else {
-            style_sheet.active()
+            style_sheet.active(self.theme())
         };
 
         let input = Primitive::Quad {
@@ -114,9 +118,11 @@ where
                 text.clone()
             },
             color: if text.is_empty() {
-                style_sheet.placeholder_color()
+                style_sheet.placeholder_color(self.theme())
+            } else if is_disabled {
+                style_sheet.disabled_color(self.theme())
             } else {
-                style_sheet.value_color()
+                style_sheet.value_color(self.theme())
             },
             font,
             bounds: Rectangle {
@@ -153,7 +159,7 @@ where
                                 height: text_bounds.height,
                             },
                             background: Background::Color(
-                                style_sheet.value_color(),
+                                style_sheet.value_color(self.theme()),
                             ),
                             border_radius: 0,
                             border_width: 0,
@@ -197,7 +203,7 @@ where
                                 height: text_bounds.height,
                             },
                             background: Background::Color(
-                                style_sheet.selection_color(),
+                                style_sheet.selection_color(self.theme()),
                             ),
                             border_radius: 0,
                             border_width: 0,
@@ -238,11 +244,30 @@ where
             contents_primitive
         };
 
+        let mut primitives = vec![input, contents];
+
+        let is_mouse_over_toggle = !is_disabled
+            && toggle_bounds
+                .map(|bounds| bounds.contains(cursor_position))
+                .unwrap_or(false);
+
+        if let Some(toggle_bounds) = toggle_bounds {
+            primitives.push(reveal_toggle(
+                toggle_bounds,
+                state.is_revealed(),
+                if is_mouse_over_toggle {
+                    style_sheet.value_color(self.theme())
+                } else {
+                    style_sheet.placeholder_color(self.theme())
+                },
+            ));
+        }
+
         (
-            Primitive::Group {
-                primitives: vec![input, contents],
-            },
-            if is_mouse_over {
+            Primitive::Group { primitives },
+            if is_mouse_over_toggle {
+                mouse::Interaction::Pointer
+            } else if is_mouse_over && !is_disabled {
                 mouse::Interaction::Text
             } else {
                 mouse::Interaction::default()
@@ -251,6 +276,63 @@ where
     }
 }
 
+/// Draws an eye, open if the value is revealed and closed otherwise.
+fn reveal_toggle(
+    bounds: Rectangle,
+    is_revealed: bool,
+    color: Color,
+) -> Primitive {
+    let width = bounds.width * 0.8;
+    let height = bounds.height * 0.5;
+
+    let eye = Rectangle {
+        x: bounds.center_x() - width / 2.0,
+        y: bounds.center_y() - height / 2.0,
+        width,
+        height,
+    };
+
+    if is_revealed {
+        let pupil = height * 0.5;
+
+        Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
+                    bounds: eye,
+                    background: Background::Color(Color::TRANSPARENT),
+                    border_radius: (height / 2.0) as u16,
+                    border_width: 1,
+                    border_color: color,
+                },
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        x: bounds.center_x() - pupil / 2.0,
+                        y: bounds.center_y() - pupil / 2.0,
+                        width: pupil,
+                        height: pupil,
+                    },
+                    background: Background::Color(color),
+                    border_radius: (pupil / 2.0) as u16,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                },
+            ],
+        }
+    } else {
+        Primitive::Quad {
+            bounds: Rectangle {
+                y: bounds.center_y(),
+                height: 1.0,
+                ..eye
+            },
+            background: Background::Color(color),
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
 fn measure_cursor_and_scroll_offset<B>(
     renderer: &Renderer<B>,
     text_bounds: Rectangle,
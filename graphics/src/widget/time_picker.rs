@@ -0,0 +1,51 @@
+//! Let your users pick a time of the day.
+//!
+//! A [`TimePicker`] has some local [`State`].
+//!
+//! [`TimePicker`]: type.TimePicker.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::widget::date_picker::{background, cells};
+use crate::{Primitive, Renderer};
+use iced_native::{time_picker, Font, Point, Rectangle};
+
+pub use iced_native::date_picker::Cell;
+pub use iced_native::time_picker::{HourFormat, State, Time};
+pub use iced_style::date_picker::{Calendar, Style, StyleSheet};
+
+/// A field showing a time that opens a set of spinners when clicked.
+///
+/// This is an alias of an `iced_native` time picker with an
+/// `iced_wgpu::Renderer`.
+pub type TimePicker<'a, Message, Backend> =
+    iced_native::TimePicker<'a, Message, Renderer<Backend>>;
+
+impl<B> time_picker::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw_spinners(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        spinners: &[Cell],
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style.calendar();
+
+        let mut primitives = vec![background(bounds, &style)];
+
+        let mouse_interaction = cells(
+            spinners.iter(),
+            cursor_position,
+            text_size,
+            font,
+            &style,
+            &mut primitives,
+        );
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -0,0 +1,130 @@
+//! Show transient notifications on top of the user interface.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    mouse, toast, Color, Font, HorizontalAlignment, Point, Rectangle,
+    VerticalAlignment,
+};
+
+pub use iced_native::toast::{Banner, Corner, Notification, Severity};
+pub use iced_style::toast::{Style, StyleSheet};
+
+/// A widget that stacks notification banners in a corner of the window, on
+/// top of its content.
+///
+/// This is an alias of an `iced_native` toast with an `iced_wgpu::Renderer`.
+pub type Toast<'a, Message, Backend> =
+    iced_native::Toast<'a, Message, Renderer<Backend>>;
+
+/// The width of the accent stripe on the left side of a notification.
+const ACCENT_WIDTH: f32 = 4.0;
+
+impl<B> toast::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: u16 = 10;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        banners: &[Banner<'_>],
+        text_size: u16,
+        font: Font,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let mut primitives = Vec::new();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let size = f32::from(text_size);
+
+        for banner in banners {
+            let style = match banner.severity {
+                Severity::Info => style_sheet.info(),
+                Severity::Success => style_sheet.success(),
+                Severity::Warning => style_sheet.warning(),
+                Severity::Error => style_sheet.error(),
+            };
+
+            primitives.push(Primitive::Quad {
+                bounds: banner.bounds,
+                background: style.background,
+                border_radius: style.border_radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            });
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    width: ACCENT_WIDTH,
+                    ..banner.bounds
+                },
+                background: style.accent.into(),
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            primitives.push(Primitive::Text {
+                content: banner.title.to_string(),
+                bounds: banner.title_bounds,
+                size,
+                font,
+                color: style.title_color,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            });
+
+            if let Some(body) = banner.body {
+                primitives.push(Primitive::Text {
+                    content: body.to_string(),
+                    bounds: banner.body_bounds,
+                    size,
+                    font,
+                    color: style.text_color,
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Top,
+                });
+            }
+
+            let buttons =
+                std::iter::once(("×", banner.close, style.text_color)).chain(
+                    banner.actions.iter().map(|(label, bounds)| {
+                        (*label, *bounds, style.action_color)
+                    }),
+                );
+
+            for (label, bounds, color) in buttons {
+                if bounds.contains(cursor_position) {
+                    primitives.push(Primitive::Quad {
+                        bounds,
+                        background: style.hovered_background,
+                        border_radius: style.border_radius,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
+                    });
+
+                    mouse_interaction = mouse::Interaction::Pointer;
+                }
+
+                primitives.push(Primitive::Text {
+                    content: label.to_string(),
+                    bounds: Rectangle {
+                        x: bounds.center_x(),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    size,
+                    font,
+                    color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
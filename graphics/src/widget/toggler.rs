@@ -0,0 +1,92 @@
+//! Show toggle controls using togglers.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::toggler;
+use iced_native::{Color, Rectangle};
+
+pub use iced_native::toggler::State;
+pub use iced_style::toggler::{Style, StyleSheet};
+
+/// A switch that can be toggled on and off, next to a label.
+///
+/// This is an alias of an `iced_native` toggler with an `iced_wgpu::Renderer`.
+pub type Toggler<'a, Message, Backend> =
+    iced_native::Toggler<'a, Message, Renderer<Backend>>;
+
+impl<B> toggler::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 20;
+    const DEFAULT_SPACING: u16 = 15;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        is_active: bool,
+        thumb_position: f32,
+        is_mouse_over: bool,
+        is_disabled: bool,
+        (label, _): Self::Output,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = if is_disabled {
+            style_sheet.disabled(self.theme(), is_active)
+        } else if is_mouse_over {
+            style_sheet.hovered(self.theme(), is_active)
+        } else {
+            style_sheet.active(self.theme(), is_active)
+        };
+
+        let border_radius = (bounds.height / 2.0) as u16;
+        let space = (bounds.height / 10.0).round();
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius,
+            border_width: if style.background_border.is_some() {
+                1
+            } else {
+                0
+            },
+            border_color: style.background_border.unwrap_or(Color::TRANSPARENT),
+        };
+
+        let thumb_size = bounds.height - space * 2.0;
+
+        let foreground = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x
+                    + space
+                    + (bounds.width - space * 2.0 - thumb_size)
+                        * thumb_position,
+                y: bounds.y + space,
+                width: thumb_size,
+                height: thumb_size,
+            },
+            background: style.foreground,
+            border_radius: (thumb_size / 2.0) as u16,
+            border_width: if style.foreground_border.is_some() {
+                1
+            } else {
+                0
+            },
+            border_color: style.foreground_border.unwrap_or(Color::TRANSPARENT),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![label, background, foreground],
+            },
+            if is_mouse_over && !is_disabled {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
@@ -0,0 +1,69 @@
+//! Show a label next to a widget when it is hovered.
+//!
+//! A [`Tooltip`] has some local [`State`].
+//!
+//! [`Tooltip`]: type.Tooltip.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    mouse, tooltip, Font, HorizontalAlignment, Rectangle, VerticalAlignment,
+};
+
+pub use iced_native::tooltip::{Position, State};
+pub use iced_style::tooltip::{Style, StyleSheet};
+
+/// A widget showing a label on top of the user interface while its content
+/// is hovered.
+///
+/// This is an alias of an `iced_native` tooltip with an `iced_wgpu::Renderer`.
+pub type Tooltip<'a, Message, Backend> =
+    iced_native::Tooltip<'a, Message, Renderer<Backend>>;
+
+impl<B> tooltip::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: u16 = 5;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        label: &str,
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style.style(self.theme());
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds,
+                        background: style.background,
+                        border_radius: style.border_radius,
+                        border_width: style.border_width,
+                        border_color: style.border_color,
+                    },
+                    Primitive::Text {
+                        content: label.to_string(),
+                        bounds: Rectangle {
+                            x: bounds.center_x(),
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        size: f32::from(text_size),
+                        font,
+                        color: style.text_color,
+                        horizontal_alignment: HorizontalAlignment::Center,
+                        vertical_alignment: VerticalAlignment::Center,
+                    },
+                ],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -0,0 +1,219 @@
+//! Display hierarchical data with expandable nodes.
+//!
+//! A [`TreeView`] has some local [`State`].
+//!
+//! [`TreeView`]: type.TreeView.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::triangle::{Mesh2D, Vertex2D};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    mouse, tree_view, Background, Color, Font, HorizontalAlignment, Rectangle,
+    Size, Vector, VerticalAlignment,
+};
+
+pub use iced_native::tree_view::{Node, Row, State};
+pub use iced_style::tree_view::{Style, StyleSheet};
+
+/// A widget displaying a tree of nodes that can be expanded, collapsed, and
+/// selected.
+///
+/// This is an alias of an `iced_native` tree view with an `iced_wgpu::Renderer`.
+pub type TreeView<'a, T, Message, Backend> =
+    iced_native::TreeView<'a, T, Message, Renderer<Backend>>;
+
+impl<B> tree_view::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: u16 = 3;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        rows: &[Row<'_>],
+        indent: u16,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        icon_font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style.style();
+
+        let indent = f32::from(indent);
+        let padding = f32::from(padding);
+        let size = f32::from(text_size);
+
+        let mut primitives = Vec::new();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        for row in rows {
+            let bounds = row.bounds;
+
+            let background = if row.is_selected {
+                Some(style.selected_background)
+            } else if row.is_hovered {
+                style.hovered_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                primitives.push(Primitive::Quad {
+                    bounds,
+                    background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            if row.is_hovered {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            // Indentation guides
+            for level in 0..row.depth {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: (bounds.x
+                            + level as f32 * indent
+                            + padding
+                            + size / 2.0)
+                            .round(),
+                        width: 1.0,
+                        ..bounds
+                    },
+                    background: Background::Color(style.guide_color),
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            let text_color = if row.is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            let mut x = bounds.x + row.depth as f32 * indent + padding;
+
+            if row.is_expandable {
+                let color = if row.is_selected {
+                    text_color
+                } else {
+                    style.expander_color
+                };
+
+                primitives.push(expander(
+                    Rectangle {
+                        x,
+                        width: size,
+                        ..bounds
+                    },
+                    size,
+                    row.is_expanded,
+                    [color.r, color.g, color.b, color.a],
+                ));
+            }
+
+            x += size + padding;
+
+            if let Some(icon) = row.icon {
+                primitives.push(Primitive::Text {
+                    content: icon.to_string(),
+                    bounds: Rectangle {
+                        x: x + size / 2.0,
+                        y: bounds.center_y(),
+                        width: size,
+                        height: bounds.height,
+                    },
+                    size,
+                    font: icon_font,
+                    color: text_color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+
+                x += size + padding;
+            }
+
+            let label = if row.is_loading {
+                format!("{} \u{2026}", row.label)
+            } else {
+                row.label.to_string()
+            };
+
+            primitives.push(Primitive::Text {
+                content: label,
+                bounds: Rectangle {
+                    x,
+                    y: bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..bounds
+                },
+                size,
+                font,
+                color: text_color,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
+
+/// Draws a triangle centered in the bounds, pointing down when expanded and
+/// to the right otherwise.
+pub(crate) fn expander(
+    bounds: Rectangle,
+    text_size: f32,
+    is_expanded: bool,
+    color: [f32; 4],
+) -> Primitive {
+    let side = text_size * 0.5;
+
+    let vertex = |x, y| Vertex2D {
+        position: [x, y],
+        color,
+    };
+
+    let (vertices, size) = if is_expanded {
+        (
+            vec![
+                vertex(0.0, 0.0),
+                vertex(side, 0.0),
+                vertex(side / 2.0, side / 2.0),
+            ],
+            Size::new(side, side / 2.0),
+        )
+    } else {
+        (
+            vec![
+                vertex(0.0, 0.0),
+                vertex(side / 2.0, side / 2.0),
+                vertex(0.0, side),
+            ],
+            Size::new(side / 2.0, side),
+        )
+    };
+
+    Primitive::Translate {
+        translation: Vector::new(
+            bounds.center_x() - size.width / 2.0,
+            bounds.center_y() - size.height / 2.0,
+        ),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D {
+                vertices,
+                indices: vec![0, 1, 2],
+            },
+            size,
+        }),
+    }
+}
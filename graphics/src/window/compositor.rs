@@ -1,4 +1,4 @@
-use crate::Viewport;
+use crate::{Theme, Viewport};
 use iced_native::mouse;
 use raw_window_handle::HasRawWindowHandle;
 
@@ -40,6 +40,12 @@ pub trait Compositor: Sized {
         height: u32,
     ) -> Self::SwapChain;
 
+    /// Sets the [`Theme`] the given renderer draws the built-in widgets and
+    /// the background of windows with.
+    ///
+    /// [`Theme`]: ../theme/enum.Theme.html
+    fn set_theme(&mut self, renderer: &mut Self::Renderer, theme: Theme);
+
     /// Draws the output primitives to the next frame of the given [`SwapChain`].
     ///
     /// [`SwapChain`]: #associatedtype.SwapChain
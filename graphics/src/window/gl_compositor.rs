@@ -1,4 +1,4 @@
-use crate::{Size, Viewport};
+use crate::{Size, Theme, Viewport};
 use iced_native::mouse;
 
 use core::ffi::c_void;
@@ -54,6 +54,12 @@ pub trait GLCompositor: Sized {
     /// [`Compositor`]: trait.Compositor.html
     fn resize_viewport(&mut self, physical_size: Size<u32>);
 
+    /// Sets the [`Theme`] the given renderer draws the built-in widgets and
+    /// the background of windows with.
+    ///
+    /// [`Theme`]: ../theme/enum.Theme.html
+    fn set_theme(&mut self, renderer: &mut Self::Renderer, theme: Theme);
+
     /// Draws the provided output with the given [`Renderer`].
     ///
     /// [`Compositor`]: trait.Compositor.html
@@ -1,3 +1,8 @@
+//! Access the clipboard of the system.
+pub use iced_core::clipboard::{Action, Flavor};
+
+use crate::Command;
+
 /// A buffer for short-term storage and transfer within and between
 /// applications.
 pub trait Clipboard {
@@ -5,4 +10,94 @@ pub trait Clipboard {
     ///
     /// [`Clipboard`]: trait.Clipboard.html
     fn content(&self) -> Option<String>;
+
+    /// Writes the given text contents to the [`Clipboard`].
+    ///
+    /// By default, this does nothing.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    fn write(&self, _contents: String) {}
+
+    /// Reads the current content of the [`Clipboard`] in the given
+    /// [`Flavor`], calling the given function with them once they are
+    /// available.
+    ///
+    /// The function may be called from another thread, after this method
+    /// returns.
+    ///
+    /// By default, only [`Flavor::Text`] is supported, and the function is
+    /// called right away.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    /// [`Flavor`]: enum.Flavor.html
+    /// [`Flavor::Text`]: enum.Flavor.html#variant.Text
+    fn content_as(
+        &self,
+        flavor: &Flavor,
+        on_read: Box<dyn FnOnce(Option<Vec<u8>>) + Send>,
+    ) {
+        on_read(match flavor {
+            Flavor::Text => self.content().map(String::into_bytes),
+            _ => None,
+        })
+    }
+
+    /// Writes the given contents to the [`Clipboard`] in the given
+    /// [`Flavor`].
+    ///
+    /// By default, only [`Flavor::Text`] is supported.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    /// [`Flavor`]: enum.Flavor.html
+    /// [`Flavor::Text`]: enum.Flavor.html#variant.Text
+    fn write_as(&self, flavor: &Flavor, contents: Vec<u8>) {
+        if let Flavor::Text = flavor {
+            if let Ok(text) = String::from_utf8(contents) {
+                self.write(text);
+            }
+        }
+    }
+}
+
+/// Returns a [`Command`] that reads the text contents of the clipboard and
+/// produces a message with them, if available.
+///
+/// [`Command`]: ../struct.Command.html
+pub fn read<Message>(
+    f: impl Fn(Option<String>) -> Message + 'static + Send,
+) -> Command<Message> {
+    read_as(Flavor::Text, move |contents| {
+        f(contents.and_then(|contents| String::from_utf8(contents).ok()))
+    })
+}
+
+/// Returns a [`Command`] that writes the given text to the clipboard.
+///
+/// [`Command`]: ../struct.Command.html
+pub fn write<Message>(contents: String) -> Command<Message> {
+    write_as(Flavor::Text, contents.into_bytes())
+}
+
+/// Returns a [`Command`] that reads the contents of the clipboard in the given
+/// [`Flavor`] and produces a message with them, if available.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Flavor`]: enum.Flavor.html
+pub fn read_as<Message>(
+    flavor: Flavor,
+    f: impl Fn(Option<Vec<u8>>) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::clipboard(Action::Read(flavor, Box::new(f)))
+}
+
+/// Returns a [`Command`] that writes the given contents to the clipboard in
+/// the given [`Flavor`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Flavor`]: enum.Flavor.html
+pub fn write_as<Message>(
+    flavor: Flavor,
+    contents: Vec<u8>,
+) -> Command<Message> {
+    Command::clipboard(Action::Write(flavor, contents))
 }
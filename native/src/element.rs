@@ -1,5 +1,6 @@
 use crate::{
-    layout, Clipboard, Color, Event, Hasher, Layout, Length, Point, Widget,
+    focus, keyboard, layout, overlay, Clipboard, Color, Event, Hasher, Layout,
+    Length, Point, Widget,
 };
 
 /// A generic [`Widget`].
@@ -270,6 +271,47 @@ where
     pub fn hash_layout(&self, state: &mut Hasher) {
         self.widget.hash_layout(state);
     }
+
+    /// Returns the [`Overlay`] of the [`Element`], if there is any.
+    ///
+    /// [`Overlay`]: overlay/trait.Overlay.html
+    /// [`Element`]: struct.Element.html
+    pub fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.widget.overlay(layout)
+    }
+
+    /// Collects the focus [`Target`]s of the [`Element`], in layout order.
+    ///
+    /// [`Target`]: focus/struct.Target.html
+    /// [`Element`]: struct.Element.html
+    pub fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.widget.focus_targets(layout, targets);
+    }
+
+    /// Processes a keyboard event while the [`Element`] or one of its
+    /// children is focused.
+    ///
+    /// It returns whether the event was captured.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.widget
+            .on_focused_event(event, layout, messages, renderer, clipboard)
+    }
 }
 
 struct Map<'a, A, B, Renderer> {
@@ -351,6 +393,50 @@ where
     fn hash_layout(&self, state: &mut Hasher) {
         self.widget.hash_layout(state);
     }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, B, Renderer>> {
+        let mapper = &*self.mapper;
+
+        self.widget
+            .overlay(layout)
+            .map(move |overlay| overlay.map(mapper))
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.widget.focus_targets(layout, targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<B>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        let mut original_messages = Vec::new();
+
+        let is_captured = self.widget.on_focused_event(
+            event,
+            layout,
+            &mut original_messages,
+            renderer,
+            clipboard,
+        );
+
+        original_messages
+            .drain(..)
+            .for_each(|message| messages.push((self.mapper)(message)));
+
+        is_captured
+    }
 }
 
 struct Explain<'a, Message, Renderer: crate::Renderer> {
@@ -426,4 +512,31 @@ where
     fn hash_layout(&self, state: &mut Hasher) {
         self.element.widget.hash_layout(state);
     }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.element.overlay(layout)
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.element.focus_targets(layout, targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.element
+            .on_focused_event(event, layout, messages, renderer, clipboard)
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{keyboard, mouse, window};
+use crate::{keyboard, mouse, stylus, touch, window};
 
 /// A user interface event.
 ///
@@ -16,4 +16,10 @@ pub enum Event {
 
     /// A window event
     Window(window::Event),
+
+    /// A touch event
+    Touch(touch::Event),
+
+    /// A stylus event
+    Stylus(stylus::Event),
 }
@@ -0,0 +1,224 @@
+//! Move the keyboard focus between widgets.
+//!
+//! Widgets that can be focused expose their [`Focusable`] state as a
+//! [`Target`]. A [`UserInterface`] collects the targets of its widgets to
+//! move the focus when `Tab` or `Shift+Tab` is pressed.
+//!
+//! [`Focusable`]: trait.Focusable.html
+//! [`Target`]: struct.Target.html
+//! [`UserInterface`]: ../struct.UserInterface.html
+use crate::{Id, Rectangle};
+
+/// The local state of a widget that can take the keyboard focus.
+pub trait Focusable {
+    /// Returns whether the widget is focused.
+    fn is_focused(&self) -> bool;
+
+    /// Focuses the widget.
+    fn focus(&mut self);
+
+    /// Unfocuses the widget.
+    fn unfocus(&mut self);
+
+    /// Returns whether the widget takes text input while focused.
+    ///
+    /// By default, it returns `false`.
+    fn accepts_text(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the widget handles `Tab` itself while focused, instead
+    /// of moving the focus to the next widget.
+    ///
+    /// By default, it returns `false`.
+    fn handles_tab(&self) -> bool {
+        false
+    }
+}
+
+/// A widget that can take the keyboard focus.
+#[allow(missing_debug_implementations)]
+pub struct Target<'a> {
+    /// The bounds of the widget.
+    pub bounds: Rectangle,
+
+    /// The explicit position of the widget in the focus order, if any.
+    ///
+    /// Targets with a tab index are focused first, by increasing index. The
+    /// rest follow in layout order.
+    pub tab_index: Option<u16>,
+
+    /// The [`Id`] of the widget, if any.
+    ///
+    /// [`Id`]: ../struct.Id.html
+    pub id: Option<Id>,
+
+    /// The [`Focusable`] state of the widget.
+    ///
+    /// [`Focusable`]: trait.Focusable.html
+    pub state: &'a mut dyn Focusable,
+}
+
+impl<'a> Target<'a> {
+    /// Creates a new [`Target`] for the given state and bounds.
+    ///
+    /// [`Target`]: struct.Target.html
+    pub fn new(
+        state: &'a mut dyn Focusable,
+        bounds: Rectangle,
+        tab_index: Option<u16>,
+        id: Option<Id>,
+    ) -> Self {
+        Target {
+            bounds,
+            tab_index,
+            id,
+            state,
+        }
+    }
+}
+
+/// Moves the focus to the next [`Target`], or to the previous one when
+/// `backwards` is `true`.
+///
+/// The focus wraps around both ends of the focus order. It returns the
+/// index of the newly focused [`Target`], if there is any.
+///
+/// [`Target`]: struct.Target.html
+pub fn traverse(targets: &mut [Target<'_>], backwards: bool) -> Option<usize> {
+    if targets.is_empty() {
+        return None;
+    }
+
+    let mut order: Vec<usize> = (0..targets.len()).collect();
+
+    // The sort is stable, so layout order is kept for equal tab indices
+    order.sort_by_key(|&i| match targets[i].tab_index {
+        Some(tab_index) => (0, tab_index),
+        None => (1, 0),
+    });
+
+    let current = order.iter().position(|&i| targets[i].state.is_focused());
+
+    let next = match (current, backwards) {
+        (None, false) => 0,
+        (None, true) => order.len() - 1,
+        (Some(current), false) => (current + 1) % order.len(),
+        (Some(current), true) => (current + order.len() - 1) % order.len(),
+    };
+
+    for target in targets.iter_mut() {
+        if target.state.is_focused() {
+            target.state.unfocus();
+        }
+    }
+
+    let next = order[next];
+    targets[next].state.focus();
+
+    Some(next)
+}
+
+/// Moves the focus to the [`Target`] with the given [`Id`].
+///
+/// It returns the index of the newly focused [`Target`], if there is any.
+/// The focus is left unchanged when no [`Target`] has the [`Id`].
+///
+/// [`Target`]: struct.Target.html
+/// [`Id`]: ../struct.Id.html
+pub fn focus(targets: &mut [Target<'_>], id: &Id) -> Option<usize> {
+    let index = targets
+        .iter()
+        .position(|target| target.id.as_ref() == Some(id))?;
+
+    for target in targets.iter_mut() {
+        if target.state.is_focused() {
+            target.state.unfocus();
+        }
+    }
+
+    targets[index].state.focus();
+
+    Some(index)
+}
+
+/// Returns the bounds of the focused [`Target`], if there is any.
+///
+/// [`Target`]: struct.Target.html
+pub fn focused(targets: &[Target<'_>]) -> Option<Rectangle> {
+    targets
+        .iter()
+        .find(|target| target.state.is_focused())
+        .map(|target| target.bounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct State(bool);
+
+    impl Focusable for State {
+        fn is_focused(&self) -> bool {
+            self.0
+        }
+
+        fn focus(&mut self) {
+            self.0 = true;
+        }
+
+        fn unfocus(&mut self) {
+            self.0 = false;
+        }
+    }
+
+    fn traverse_all(
+        states: &mut [State],
+        tab_indices: &[Option<u16>],
+        backwards: bool,
+    ) -> Option<usize> {
+        let mut targets: Vec<_> = states
+            .iter_mut()
+            .zip(tab_indices)
+            .map(|(state, &tab_index)| {
+                Target::new(state, Rectangle::default(), tab_index, None)
+            })
+            .collect();
+
+        traverse(&mut targets, backwards)
+    }
+
+    #[test]
+    fn tab_indices_come_first() {
+        let mut states: Vec<State> = (0..3).map(|_| State::default()).collect();
+        let tab_indices = [None, Some(2), Some(1)];
+
+        assert_eq!(traverse_all(&mut states, &tab_indices, false), Some(2));
+        assert_eq!(traverse_all(&mut states, &tab_indices, false), Some(1));
+        assert_eq!(traverse_all(&mut states, &tab_indices, false), Some(0));
+        assert_eq!(traverse_all(&mut states, &tab_indices, false), Some(2));
+        assert_eq!(traverse_all(&mut states, &tab_indices, true), Some(0));
+
+        assert_eq!(states.iter().filter(|state| state.0).count(), 1);
+    }
+
+    #[test]
+    fn focus_by_id() {
+        let mut states: Vec<State> = (0..2).map(|_| State::default()).collect();
+        states[0].0 = true;
+
+        let mut targets: Vec<_> = states
+            .iter_mut()
+            .zip(vec![None, Some(Id::new("search"))])
+            .map(|(state, id)| {
+                Target::new(state, Rectangle::default(), None, id)
+            })
+            .collect();
+
+        assert_eq!(focus(&mut targets, &Id::new("search")), Some(1));
+        assert_eq!(focus(&mut targets, &Id::new("missing")), None);
+        assert!(!targets[0].state.is_focused());
+        assert!(targets[1].state.is_focused());
+    }
+}
@@ -0,0 +1,34 @@
+//! Load fonts at runtime.
+//!
+//! Loaded fonts are registered by their family name, which can then be used
+//! to reference them with [`Font::Family`].
+//!
+//! [`Font::Family`]: enum.Font.html#variant.Family
+pub use iced_core::font::{Action, Error, Font};
+
+use crate::Command;
+
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+/// Returns a [`Command`] that registers the font contained in the given bytes
+/// and produces a message with the result.
+///
+/// [`Command`]: ../struct.Command.html
+pub fn load<Message>(
+    bytes: impl Into<Cow<'static, [u8]>>,
+    f: impl Fn(Result<(), Error>) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::font(Action::Load(bytes.into(), Box::new(f)))
+}
+
+/// Returns a [`Command`] that reads and registers the font file at the given
+/// path and produces a message with the result.
+///
+/// [`Command`]: ../struct.Command.html
+pub fn load_from_path<Message>(
+    path: impl Into<PathBuf>,
+    f: impl Fn(Result<(), Error>) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::font(Action::LoadFromPath(path.into(), Box::new(f)))
+}
@@ -0,0 +1,104 @@
+//! Listen to gamepad events.
+//!
+//! Gamepads are tracked using [`gilrs`]. This module is only available when
+//! the `gamepad` feature is enabled.
+//!
+//! [`gilrs`]: https://gitlab.com/gilrs-project/gilrs
+mod events;
+
+use crate::Subscription;
+
+/// Returns a [`Subscription`] to the events of every gamepad connected.
+///
+/// Gamepad events are awaited in a background thread while subscribed.
+///
+/// [`Subscription`]: ../subscription/type.Subscription.html
+pub fn events() -> Subscription<Event> {
+    Subscription::from_recipe(events::Events)
+}
+
+/// A gamepad event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// A gamepad was connected.
+    Connected(Id),
+
+    /// A gamepad was disconnected.
+    Disconnected(Id),
+
+    /// A button of a gamepad was pressed.
+    ButtonPressed {
+        /// The gamepad
+        id: Id,
+
+        /// The button pressed
+        button: Button,
+    },
+
+    /// A button of a gamepad was released.
+    ButtonReleased {
+        /// The gamepad
+        id: Id,
+
+        /// The button released
+        button: Button,
+    },
+
+    /// An axis of a gamepad changed its value.
+    AxisChanged {
+        /// The gamepad
+        id: Id,
+
+        /// The axis changed
+        axis: Axis,
+
+        /// The new value of the axis, from `-1.0` to `1.0`
+        value: f32,
+    },
+}
+
+/// The identifier of a connected gamepad.
+///
+/// The identifier of a gamepad is only guaranteed to be unique until it is
+/// disconnected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(pub usize);
+
+/// A gamepad button, named after its position in a standard layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Button {
+    South,
+    East,
+    North,
+    West,
+    LeftTrigger,
+    LeftTrigger2,
+    RightTrigger,
+    RightTrigger2,
+    Select,
+    Start,
+    Mode,
+    LeftThumb,
+    RightThumb,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    Other,
+}
+
+/// A gamepad axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Axis {
+    LeftStickX,
+    LeftStickY,
+    LeftZ,
+    RightStickX,
+    RightStickY,
+    RightZ,
+    DPadX,
+    DPadY,
+    Other,
+}
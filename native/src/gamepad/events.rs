@@ -0,0 +1,154 @@
+use crate::{
+    gamepad::{Axis, Button, Event, Id},
+    subscription::{EventStream, Recipe},
+    Hasher,
+};
+use iced_futures::futures::{channel::mpsc, StreamExt};
+use iced_futures::BoxStream;
+
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_millis(100);
+
+pub struct Events;
+
+impl Recipe<Hasher, crate::Event> for Events {
+    type Output = Event;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: EventStream) -> BoxStream<Self::Output> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        // `gilrs` may not be sent across threads
+        let _ = std::thread::spawn(move || {
+            let mut gilrs = match gilrs::Gilrs::new() {
+                Ok(gilrs) => gilrs,
+                // Gamepads are not supported, so the stream ends right away
+                Err(_) => return,
+            };
+
+            // The timeout lets the thread notice a dropped subscription
+            while !sender.is_closed() {
+                let event = match gilrs.next_event_blocking(Some(TIMEOUT)) {
+                    Some(event) => event,
+                    None => continue,
+                };
+
+                if let Some(event) = convert(Id(event.id.into()), event.event) {
+                    // The subscription was dropped
+                    if sender.unbounded_send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        receiver.boxed()
+    }
+}
+
+fn convert(id: Id, event: gilrs::EventType) -> Option<Event> {
+    use gilrs::EventType;
+
+    match event {
+        EventType::Connected => Some(Event::Connected(id)),
+        EventType::Disconnected => Some(Event::Disconnected(id)),
+        EventType::ButtonPressed(button, _) => Some(Event::ButtonPressed {
+            id,
+            button: self::button(button),
+        }),
+        EventType::ButtonReleased(button, _) => Some(Event::ButtonReleased {
+            id,
+            button: self::button(button),
+        }),
+        EventType::AxisChanged(axis, value, _) => Some(Event::AxisChanged {
+            id,
+            axis: self::axis(axis),
+            value,
+        }),
+        _ => None,
+    }
+}
+
+fn button(button: gilrs::Button) -> Button {
+    match button {
+        gilrs::Button::South => Button::South,
+        gilrs::Button::East => Button::East,
+        gilrs::Button::North => Button::North,
+        gilrs::Button::West => Button::West,
+        gilrs::Button::LeftTrigger => Button::LeftTrigger,
+        gilrs::Button::LeftTrigger2 => Button::LeftTrigger2,
+        gilrs::Button::RightTrigger => Button::RightTrigger,
+        gilrs::Button::RightTrigger2 => Button::RightTrigger2,
+        gilrs::Button::Select => Button::Select,
+        gilrs::Button::Start => Button::Start,
+        gilrs::Button::Mode => Button::Mode,
+        gilrs::Button::LeftThumb => Button::LeftThumb,
+        gilrs::Button::RightThumb => Button::RightThumb,
+        gilrs::Button::DPadUp => Button::DPadUp,
+        gilrs::Button::DPadDown => Button::DPadDown,
+        gilrs::Button::DPadLeft => Button::DPadLeft,
+        gilrs::Button::DPadRight => Button::DPadRight,
+        _ => Button::Other,
+    }
+}
+
+fn axis(axis: gilrs::Axis) -> Axis {
+    match axis {
+        gilrs::Axis::LeftStickX => Axis::LeftStickX,
+        gilrs::Axis::LeftStickY => Axis::LeftStickY,
+        gilrs::Axis::LeftZ => Axis::LeftZ,
+        gilrs::Axis::RightStickX => Axis::RightStickX,
+        gilrs::Axis::RightStickY => Axis::RightStickY,
+        gilrs::Axis::RightZ => Axis::RightZ,
+        gilrs::Axis::DPadX => Axis::DPadX,
+        gilrs::Axis::DPadY => Axis::DPadY,
+        _ => Axis::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_connections() {
+        let id = Id(3);
+
+        assert_eq!(
+            convert(id, gilrs::EventType::Connected),
+            Some(Event::Connected(id))
+        );
+        assert_eq!(
+            convert(id, gilrs::EventType::Disconnected),
+            Some(Event::Disconnected(id))
+        );
+    }
+
+    #[test]
+    fn ignores_dropped_events() {
+        assert_eq!(convert(Id(0), gilrs::EventType::Dropped), None);
+    }
+
+    #[test]
+    fn maps_buttons_by_position() {
+        assert_eq!(button(gilrs::Button::South), Button::South);
+        assert_eq!(button(gilrs::Button::RightTrigger2), Button::RightTrigger2);
+        assert_eq!(button(gilrs::Button::DPadLeft), Button::DPadLeft);
+        assert_eq!(button(gilrs::Button::C), Button::Other);
+        assert_eq!(button(gilrs::Button::Unknown), Button::Other);
+    }
+
+    #[test]
+    fn maps_axes() {
+        assert_eq!(axis(gilrs::Axis::LeftStickX), Axis::LeftStickX);
+        assert_eq!(axis(gilrs::Axis::RightZ), Axis::RightZ);
+        assert_eq!(axis(gilrs::Axis::DPadY), Axis::DPadY);
+        assert_eq!(axis(gilrs::Axis::Unknown), Axis::Other);
+    }
+}
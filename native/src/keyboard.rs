@@ -1,2 +1,16 @@
 //! Track keyboard events.
+mod events;
+
 pub use iced_core::keyboard::*;
+
+use crate::Subscription;
+
+/// Returns a [`Subscription`] to the keyboard events handled by the runtime.
+///
+/// It notifies your application of every key press and release, as well as
+/// every character received, regardless of the focused widget.
+///
+/// [`Subscription`]: ../subscription/type.Subscription.html
+pub fn events() -> Subscription<Event> {
+    Subscription::from_recipe(events::Events)
+}
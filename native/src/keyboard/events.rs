@@ -0,0 +1,33 @@
+use crate::{
+    keyboard,
+    subscription::{EventStream, Recipe},
+    Event, Hasher,
+};
+use iced_futures::futures::{future, StreamExt};
+use iced_futures::BoxStream;
+
+pub struct Events;
+
+impl Recipe<Hasher, Event> for Events {
+    type Output = keyboard::Event;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<Self::Output> {
+        event_stream
+            .filter_map(|event| {
+                future::ready(match event {
+                    Event::Keyboard(keyboard_event) => Some(keyboard_event),
+                    _ => None,
+                })
+            })
+            .boxed()
+    }
+}
@@ -18,7 +18,7 @@
 // limitations under the License.
 use crate::{
     layout::{Limits, Node},
-    Align, Element, Point, Size,
+    Align, Element, Length, Point, Size,
 };
 
 /// The main axis of a flex layout.
@@ -84,14 +84,23 @@ where
     nodes.resize(items.len(), Node::default());
 
     for (i, child) in items.iter().enumerate() {
-        let fill_factor = match axis {
+        let length = match axis {
             Axis::Horizontal => child.width(),
             Axis::Vertical => child.height(),
-        }
-        .fill_factor();
+        };
+
+        let fill_factor = length.fill_factor();
 
         if fill_factor == 0 {
-            let (max_width, max_height) = axis.pack(available, max_cross);
+            // Percentages are relative to the whole container
+            let max_main = match length {
+                Length::Percent(_) | Length::Clamped { .. } => {
+                    axis.main(limits.max())
+                }
+                _ => available,
+            };
+
+            let (max_width, max_height) = axis.pack(max_main, max_cross);
 
             let child_limits =
                 Limits::new(Size::ZERO, Size::new(max_width, max_height));
@@ -63,13 +63,20 @@ impl Limits {
             Length::Fill | Length::FillPortion(_) => {
                 self.fill.width = self.fill.width.min(self.max.width);
             }
-            Length::Units(units) => {
-                let new_width =
-                    (units as f32).min(self.max.width).max(self.min.width);
-
-                self.min.width = new_width;
-                self.max.width = new_width;
-                self.fill.width = new_width;
+            Length::Units(_) | Length::Percent(_) | Length::Clamped { .. } => {
+                match width.fixed(self.max.width) {
+                    Some(width) => {
+                        let new_width =
+                            width.min(self.max.width).max(self.min.width);
+
+                        self.min.width = new_width;
+                        self.max.width = new_width;
+                        self.fill.width = new_width;
+                    }
+                    None => {
+                        self.fill.width = self.min.width;
+                    }
+                }
             }
         }
 
@@ -87,13 +94,20 @@ impl Limits {
             Length::Fill | Length::FillPortion(_) => {
                 self.fill.height = self.fill.height.min(self.max.height);
             }
-            Length::Units(units) => {
-                let new_height =
-                    (units as f32).min(self.max.height).max(self.min.height);
-
-                self.min.height = new_height;
-                self.max.height = new_height;
-                self.fill.height = new_height;
+            Length::Units(_) | Length::Percent(_) | Length::Clamped { .. } => {
+                match height.fixed(self.max.height) {
+                    Some(height) => {
+                        let new_height =
+                            height.min(self.max.height).max(self.min.height);
+
+                        self.min.height = new_height;
+                        self.max.height = new_height;
+                        self.fill.height = new_height;
+                    }
+                    None => {
+                        self.fill.height = self.min.height;
+                    }
+                }
             }
         }
 
@@ -35,16 +35,25 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod clipboard;
+pub mod focus;
+pub mod font;
+#[cfg(feature = "gamepad")]
+pub mod gamepad;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
+pub mod notification;
+pub mod overlay;
 pub mod program;
 pub mod renderer;
 pub mod subscription;
+pub mod system;
+pub mod touch;
+pub mod tray;
 pub mod widget;
 pub mod window;
 
-mod clipboard;
 mod element;
 mod event;
 mod hasher;
@@ -61,8 +70,9 @@ mod debug;
 mod debug;
 
 pub use iced_core::{
-    Align, Background, Color, Font, HorizontalAlignment, Length, Point,
-    Rectangle, Size, Vector, VerticalAlignment,
+    stylus, Align, Background, BorderRadius, BorderSide, Borders, Color,
+    ContentFit, Font, Gradient, HorizontalAlignment, Id, Length, Point,
+    Rectangle, Shadow, Size, Vector, VerticalAlignment,
 };
 pub use iced_futures::{executor, futures, Command};
 
@@ -75,6 +85,7 @@ pub use element::Element;
 pub use event::Event;
 pub use hasher::Hasher;
 pub use layout::Layout;
+pub use overlay::Overlay;
 pub use program::Program;
 pub use renderer::Renderer;
 pub use runtime::Runtime;
@@ -1,6 +1,49 @@
 //! Track mouse clicks.
+use crate::mouse::DoubleClick;
 use crate::Point;
-use std::time::Instant;
+
+use std::sync::atomic::{self, AtomicUsize};
+use std::time::{Duration, Instant};
+
+// Until the runtime configures them, the defaults of `DoubleClick` are used
+const UNSET: usize = usize::MAX;
+
+static THRESHOLD: AtomicUsize = AtomicUsize::new(UNSET);
+static DISTANCE: AtomicUsize = AtomicUsize::new(UNSET);
+
+/// Configures how consecutive clicks are recognized by every widget.
+///
+/// Runtimes call this on startup with the [`DoubleClick`] settings of the
+/// application.
+///
+/// [`DoubleClick`]: ../struct.DoubleClick.html
+pub fn configure(double_click: DoubleClick) {
+    let threshold = double_click.threshold.as_millis() as usize;
+
+    THRESHOLD.store(threshold, atomic::Ordering::Relaxed);
+    DISTANCE.store(
+        usize::from(double_click.distance),
+        atomic::Ordering::Relaxed,
+    );
+}
+
+/// Returns the [`DoubleClick`] settings used to recognize consecutive
+/// clicks.
+///
+/// [`DoubleClick`]: ../struct.DoubleClick.html
+pub fn double_click() -> DoubleClick {
+    let threshold = THRESHOLD.load(atomic::Ordering::Relaxed);
+    let distance = DISTANCE.load(atomic::Ordering::Relaxed);
+
+    if threshold == UNSET || distance == UNSET {
+        return DoubleClick::default();
+    }
+
+    DoubleClick {
+        threshold: Duration::from_millis(threshold as u64),
+        distance: distance as u16,
+    }
+}
 
 /// A mouse click.
 #[derive(Debug, Clone, Copy)]
@@ -11,7 +54,7 @@ pub struct Click {
 }
 
 /// The kind of mouse click.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Kind {
     /// A single click
     Single,
@@ -67,10 +110,32 @@ impl Click {
     }
 
     fn is_consecutive(&self, new_position: Point, time: Instant) -> bool {
-        self.position == new_position
+        let double_click = double_click();
+
+        self.position.distance(new_position) <= f32::from(double_click.distance)
             && time
                 .checked_duration_since(self.time)
-                .map(|duration| duration.as_millis() <= 300)
+                .map(|duration| duration <= double_click.threshold)
                 .unwrap_or(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearby_clicks_are_consecutive() {
+        let first = Click::new(Point::new(10.0, 10.0), None);
+        let second = Click::new(Point::new(12.0, 11.0), Some(first));
+        let third = Click::new(Point::new(40.0, 40.0), Some(second));
+
+        assert_eq!(second.kind(), Kind::Double);
+        assert_eq!(third.kind(), Kind::Single);
+    }
+
+    #[test]
+    fn defaults_are_used_until_configured() {
+        assert_eq!(double_click(), DoubleClick::default());
+    }
+}
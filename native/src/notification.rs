@@ -0,0 +1,29 @@
+//! Notify the user through the desktop.
+pub use iced_core::notification::{Action, Error, Notification};
+
+use crate::Command;
+
+/// Returns a [`Command`] that shows a [`Notification`] with the given title,
+/// body, and icon, and produces a message with the result.
+///
+/// Notifications are displayed even if the window of the application is not
+/// focused, which makes them a good fit to report the end of long-running
+/// tasks.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Notification`]: struct.Notification.html
+pub fn show<Message>(
+    title: impl Into<String>,
+    body: impl Into<String>,
+    icon: Option<String>,
+    f: impl Fn(Result<(), Error>) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::notify(Action::Show(
+        Notification {
+            title: title.into(),
+            body: body.into(),
+            icon,
+        },
+        Box::new(f),
+    ))
+}
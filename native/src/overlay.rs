@@ -0,0 +1,80 @@
+//! Display interactive elements on top of other widgets.
+//!
+//! An [`Overlay`] is produced by a [`Widget`] and is drawn on top of the
+//! whole user interface, outside of the bounds of its widget. For instance, a
+//! [`PickList`] opens a [`Menu`] with its options.
+//!
+//! [`Overlay`]: trait.Overlay.html
+//! [`Widget`]: ../widget/trait.Widget.html
+//! [`PickList`]: ../widget/pick_list/struct.PickList.html
+//! [`Menu`]: menu/struct.Menu.html
+mod element;
+
+pub mod menu;
+
+pub(crate) mod popup;
+
+pub use element::Element;
+pub use menu::Menu;
+
+use crate::{layout, Clipboard, Event, Layout, Point, Size};
+
+/// An interactive component that can be displayed on top of other widgets.
+pub trait Overlay<Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Returns the layout [`Node`] of the [`Overlay`].
+    ///
+    /// It receives the bounds of the viewport and the position requested by
+    /// the widget that produced the [`Overlay`].
+    ///
+    /// [`Node`]: ../layout/struct.Node.html
+    /// [`Overlay`]: trait.Overlay.html
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node;
+
+    /// Draws the [`Overlay`] using the associated `Renderer`.
+    ///
+    /// [`Overlay`]: trait.Overlay.html
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output;
+
+    /// Processes a runtime [`Event`].
+    ///
+    /// The [`Overlay`] receives every event before the rest of the user
+    /// interface. By default, it does nothing.
+    ///
+    /// [`Event`]: ../enum.Event.html
+    /// [`Overlay`]: trait.Overlay.html
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+    }
+
+    /// Returns whether the cursor is over the [`Overlay`].
+    ///
+    /// The widgets below an [`Overlay`] cannot be interacted with while the
+    /// cursor is over it. By default, it returns whether the cursor is inside
+    /// the bounds of the [`Overlay`].
+    ///
+    /// [`Overlay`]: trait.Overlay.html
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+}
@@ -0,0 +1,513 @@
+//! Build and show dropdown menus.
+use crate::{
+    keyboard, layout, mouse, overlay, text, Clipboard, Event, Layout, Point,
+    Rectangle, Size,
+};
+
+use std::time::{Duration, Instant};
+
+/// The time after which the type-ahead search of a [`Menu`] starts over.
+///
+/// [`Menu`]: struct.Menu.html
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A list of selectable options shown as an [`Overlay`].
+///
+/// The options can be hovered and selected with the mouse, navigated with
+/// the arrow keys, and searched by typing the beginning of their label.
+///
+/// [`Overlay`]: ../trait.Overlay.html
+#[allow(missing_debug_implementations)]
+pub struct Menu<'a, T, Renderer: self::Renderer> {
+    state: &'a mut State,
+    options: &'a [T],
+    last_selection: &'a mut Option<T>,
+    width: u16,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+    is_type_ahead_enabled: bool,
+}
+
+impl<'a, T, Renderer> Menu<'a, T, Renderer>
+where
+    T: ToString + Clone,
+    Renderer: self::Renderer + 'a,
+{
+    /// Creates a new [`Menu`] with the given [`State`] and options.
+    ///
+    /// When an option is selected, it is stored in `last_selection` and the
+    /// [`Menu`] is closed.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    /// [`State`]: struct.State.html
+    pub fn new(
+        state: &'a mut State,
+        options: &'a [T],
+        last_selection: &'a mut Option<T>,
+    ) -> Self {
+        Menu {
+            state,
+            options,
+            last_selection,
+            width: 0,
+            padding: 0,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+            is_type_ahead_enabled: true,
+        }
+    }
+
+    /// Sets the width of the [`Menu`].
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the options of the [`Menu`].
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`Menu`].
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`Menu`].
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Menu`].
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets whether the [`Menu`] handles typed characters and the `Home` and
+    /// `End` keys.
+    ///
+    /// It is enabled by default. Disable it when the [`Menu`] is attached to a
+    /// text field, which handles those keys itself.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn type_ahead(mut self, enabled: bool) -> Self {
+        self.is_type_ahead_enabled = enabled;
+        self
+    }
+
+    /// Turns the [`Menu`] into an overlay [`Element`] for a target widget at
+    /// the given position.
+    ///
+    /// The [`Menu`] is shown below the target, or above it if there is not
+    /// enough space.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    /// [`Element`]: ../struct.Element.html
+    pub fn overlay<Message: 'a>(
+        self,
+        position: Point,
+        target_height: f32,
+    ) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(
+            position,
+            Box::new(Overlay {
+                menu: self,
+                target_height,
+            }),
+        )
+    }
+
+    fn option_height(&self) -> f32 {
+        let text_size = self.text_size.unwrap_or(Renderer::DEFAULT_SIZE);
+
+        f32::from(text_size + self.padding * 2)
+    }
+
+    fn select(&mut self, index: usize) {
+        if let Some(option) = self.options.get(index) {
+            *self.last_selection = Some(option.clone());
+        }
+
+        self.state.close();
+    }
+
+    /// Moves the hovered option and scrolls it into view.
+    fn hover(&mut self, index: usize, visible_options: usize) {
+        if self.options.is_empty() {
+            return;
+        }
+
+        let index = index.min(self.options.len() - 1);
+
+        self.state.hovered_option = Some(index);
+
+        if index < self.state.first_option {
+            self.state.first_option = index;
+        } else if index >= self.state.first_option + visible_options {
+            self.state.first_option = index + 1 - visible_options;
+        }
+    }
+
+    /// Hovers the next option starting with the characters typed so far.
+    fn search(&mut self, c: char, visible_options: usize) {
+        let now = Instant::now();
+
+        if self
+            .state
+            .last_typed
+            .map(|last| now.duration_since(last) > TYPE_AHEAD_TIMEOUT)
+            .unwrap_or(true)
+        {
+            self.state.typed.clear();
+        }
+
+        self.state.typed.extend(c.to_lowercase());
+        self.state.last_typed = Some(now);
+
+        // Typing the same letter repeatedly cycles through the options
+        // starting with it
+        let start = match self.state.hovered_option {
+            Some(hovered) if self.state.typed.chars().count() == 1 => {
+                hovered + 1
+            }
+            Some(hovered) => hovered,
+            None => 0,
+        };
+
+        let total = self.options.len();
+
+        let found = (0..total).map(|i| (start + i) % total).find(|i| {
+            self.options[*i]
+                .to_string()
+                .to_lowercase()
+                .starts_with(&self.state.typed)
+        });
+
+        if let Some(index) = found {
+            self.hover(index, visible_options);
+        }
+    }
+}
+
+/// The local state of a [`Menu`].
+///
+/// [`Menu`]: struct.Menu.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    is_open: bool,
+    hovered_option: Option<usize>,
+    first_option: usize,
+    typed: String,
+    last_typed: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new, closed [`State`] for a [`Menu`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Menu`]: struct.Menu.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`Menu`] is open or not.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Opens the [`Menu`], hovering the given option.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn open(&mut self, hovered_option: Option<usize>) {
+        self.is_open = true;
+        self.hovered_option = hovered_option;
+        self.first_option = hovered_option.unwrap_or(0);
+        self.typed.clear();
+    }
+
+    /// Closes the [`Menu`].
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Returns the option currently hovered in the [`Menu`], if any.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn hovered_option(&self) -> Option<usize> {
+        self.hovered_option
+    }
+}
+
+struct Overlay<'a, T, Renderer: self::Renderer> {
+    menu: Menu<'a, T, Renderer>,
+    target_height: f32,
+}
+
+impl<'a, T, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, T, Renderer>
+where
+    T: ToString + Clone,
+    Renderer: self::Renderer + 'a,
+{
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let option_height = self.menu.option_height();
+        let total_height = option_height * self.menu.options.len() as f32;
+
+        let space_below = bounds.height - (position.y + self.target_height);
+        let space_above = position.y;
+
+        let is_below =
+            total_height <= space_below || space_below >= space_above;
+
+        let available = if is_below { space_below } else { space_above };
+
+        // Only whole options are shown
+        let height = total_height
+            .min((available / option_height).floor().max(1.0) * option_height);
+
+        let y = if is_below {
+            position.y + self.target_height
+        } else {
+            position.y - height
+        };
+
+        let mut node =
+            layout::Node::new(Size::new(f32::from(self.menu.width), height));
+
+        node.move_to(Point::new(position.x, y));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let option_height = self.menu.option_height();
+        let visible_options =
+            ((bounds.height / option_height).round() as usize).max(1);
+
+        self.menu.state.first_option = self
+            .menu
+            .state
+            .first_option
+            .min(self.menu.options.len().saturating_sub(visible_options));
+
+        let option_at = |state: &State, position: Point| {
+            if bounds.contains(position) {
+                Some(
+                    state.first_option
+                        + ((position.y - bounds.y) / option_height) as usize,
+                )
+            } else {
+                None
+            }
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = option_at(self.menu.state, cursor_position)
+                {
+                    if index < self.menu.options.len() {
+                        self.menu.state.hovered_option = Some(index);
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(index) = option_at(self.menu.state, cursor_position)
+                {
+                    self.menu.select(index);
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / option_height,
+                };
+
+                let last =
+                    self.menu.options.len().saturating_sub(visible_options);
+                let first = self.menu.state.first_option as f32 - lines.round();
+
+                self.menu.state.first_option =
+                    (first.max(0.0) as usize).min(last);
+
+                if let Some(index) = option_at(self.menu.state, cursor_position)
+                {
+                    if index < self.menu.options.len() {
+                        self.menu.state.hovered_option = Some(index);
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let hovered = self.menu.state.hovered_option;
+                let last = self.menu.options.len().saturating_sub(1);
+
+                match key_code {
+                    keyboard::KeyCode::Up => {
+                        let index = hovered
+                            .map(|index| index.saturating_sub(1))
+                            .unwrap_or(last);
+
+                        self.menu.hover(index, visible_options);
+                    }
+                    keyboard::KeyCode::Down => {
+                        let index = hovered.map(|index| index + 1).unwrap_or(0);
+
+                        self.menu.hover(index, visible_options);
+                    }
+                    keyboard::KeyCode::PageUp => {
+                        let index = hovered
+                            .unwrap_or(0)
+                            .saturating_sub(visible_options);
+
+                        self.menu.hover(index, visible_options);
+                    }
+                    keyboard::KeyCode::PageDown => {
+                        let index = hovered.unwrap_or(0) + visible_options;
+
+                        self.menu.hover(index, visible_options);
+                    }
+                    keyboard::KeyCode::Home
+                        if self.menu.is_type_ahead_enabled =>
+                    {
+                        self.menu.hover(0, visible_options);
+                    }
+                    keyboard::KeyCode::End
+                        if self.menu.is_type_ahead_enabled =>
+                    {
+                        self.menu.hover(last, visible_options);
+                    }
+                    keyboard::KeyCode::Enter => {
+                        if let Some(index) = hovered {
+                            self.menu.select(index);
+                        }
+                    }
+                    keyboard::KeyCode::Escape => {
+                        self.menu.state.close();
+                    }
+                    _ => {}
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.menu.is_type_ahead_enabled && !c.is_control() =>
+            {
+                self.menu.search(c, visible_options);
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let option_height = self.menu.option_height();
+
+        let visible = ((bounds.height / option_height).round() as usize).max(1);
+        let first = self
+            .menu
+            .state
+            .first_option
+            .min(self.menu.options.len().saturating_sub(visible));
+        let last = (first + visible).min(self.menu.options.len());
+
+        let hovered_option = self
+            .menu
+            .state
+            .hovered_option
+            .and_then(|index| index.checked_sub(first));
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            cursor_position,
+            &self.menu.options[first..last],
+            hovered_option,
+            self.menu.padding,
+            self.menu.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+            self.menu.font,
+            &self.menu.style,
+        )
+    }
+}
+
+/// The renderer of a [`Menu`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Menu`] in your user interface.
+///
+/// [`Menu`]: struct.Menu.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The [`Menu`] style supported by this renderer.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    type Style: Default + Clone;
+
+    /// Draws the visible options of a [`Menu`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`Menu`]
+    /// - the cursor position
+    /// - the visible options
+    /// - the index of the hovered option among the visible ones, if any
+    /// - the padding, text size, and font of the options
+    /// - the style of the [`Menu`]
+    ///
+    /// [`Menu`]: struct.Menu.html
+    fn draw<T: ToString>(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        options: &[T],
+        hovered_option: Option<usize>,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
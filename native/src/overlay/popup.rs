@@ -0,0 +1,54 @@
+//! Place and dismiss overlays anchored to a widget, like the calendar of a
+//! date picker.
+use crate::{keyboard, layout, mouse, Event, Point, Rectangle, Size};
+
+/// Lays out a popup of the given size anchored to the bounds of its target.
+///
+/// The popup is placed below the target, unless it only fits above it. It is
+/// always kept horizontally inside the viewport.
+pub(crate) fn layout(
+    size: Size,
+    target: Rectangle,
+    bounds: Size,
+    position: Point,
+) -> layout::Node {
+    let below = position.y + target.height;
+
+    let y = if below + size.height > bounds.height
+        && position.y - size.height >= 0.0
+    {
+        position.y - size.height
+    } else {
+        below
+    };
+
+    let x = position.x.min(bounds.width - size.width).max(0.0);
+
+    let mut node = layout::Node::new(size);
+    node.move_to(Point::new(x, y));
+
+    node
+}
+
+/// Returns whether the given event dismisses a popup.
+///
+/// A popup is dismissed when pressing escape or clicking outside of it. Clicks
+/// on its target are left to the target, which usually toggles the popup.
+pub(crate) fn is_dismissed(
+    event: &Event,
+    popup: Rectangle,
+    target: Rectangle,
+    cursor_position: Point,
+) -> bool {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+            !popup.contains(cursor_position)
+                && !target.contains(cursor_position)
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Escape,
+            ..
+        }) => true,
+        _ => false,
+    }
+}
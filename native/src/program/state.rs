@@ -88,6 +88,34 @@ where
         self.queued_messages.push(message);
     }
 
+    /// Lays out and redraws the widgets of the linked [`Program`] with the
+    /// given logical bounds, without processing any events or messages.
+    ///
+    /// This is useful when the bounds change outside of the event loop, for
+    /// instance when the user interface is scaled.
+    ///
+    /// [`Program`]: trait.Program.html
+    pub fn relayout(
+        &mut self,
+        bounds: Size,
+        renderer: &mut P::Renderer,
+        debug: &mut Debug,
+    ) {
+        let user_interface = build_user_interface(
+            &mut self.program,
+            self.cache.take().unwrap(),
+            renderer,
+            bounds,
+            debug,
+        );
+
+        debug.draw_started();
+        self.primitive = user_interface.draw(renderer);
+        debug.draw_finished();
+
+        self.cache = Some(user_interface.into_cache());
+    }
+
     /// Processes all the queued events and messages, rebuilding and redrawing
     /// the widgets of the linked [`Program`] if necessary.
     ///
@@ -136,7 +164,7 @@ where
             // for now :^)
             let temp_cache = user_interface.into_cache();
 
-            let commands =
+            let mut commands =
                 Command::batch(messages.into_iter().map(|message| {
                     debug.log_message(&message);
 
@@ -147,7 +175,7 @@ where
                     command
                 }));
 
-            let user_interface = build_user_interface(
+            let mut user_interface = build_user_interface(
                 &mut self.program,
                 temp_cache,
                 renderer,
@@ -155,6 +183,10 @@ where
                 debug,
             );
 
+            if let Some(id) = commands.take_focus() {
+                let _ = user_interface.focus(&id);
+            }
+
             debug.draw_started();
             self.primitive = user_interface.draw(renderer);
             debug.draw_finished();
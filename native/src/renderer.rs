@@ -25,7 +25,10 @@ mod null;
 #[cfg(debug_assertions)]
 pub use null::Null;
 
-use crate::{layout, Element};
+use crate::{font, layout, Element, Rectangle};
+
+use std::borrow::Cow;
+use std::time::Instant;
 
 /// A component that can take the state of a user interface and produce an
 /// output for its users.
@@ -45,6 +48,16 @@ pub trait Renderer: Sized {
     /// [`Renderer`]: trait.Renderer.html
     type Defaults: Default;
 
+    /// Returns the styling attributes the root of a user interface inherits.
+    ///
+    /// By default, it returns the [`Default`] of [`Defaults`].
+    ///
+    /// [`Defaults`]: #associatedtype.Defaults
+    /// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+    fn defaults(&self) -> Self::Defaults {
+        Self::Defaults::default()
+    }
+
     /// Lays out the elements of a user interface.
     ///
     /// You should override this if you need to perform any operations before or
@@ -56,4 +69,85 @@ pub trait Renderer: Sized {
     ) -> layout::Node {
         element.layout(self, limits)
     }
+
+    /// Draws an overlay on top of some base output.
+    ///
+    /// The overlay must be drawn above everything in the base output, in the
+    /// given bounds.
+    ///
+    /// By default, it returns the base output unchanged, dropping the overlay.
+    /// Renderers need to override this to draw widgets with overlays, like a
+    /// [`PickList`].
+    ///
+    /// [`PickList`]: ../widget/pick_list/struct.PickList.html
+    fn overlay(
+        &mut self,
+        base: Self::Output,
+        _overlay: Self::Output,
+        _overlay_bounds: Rectangle,
+    ) -> Self::Output {
+        base
+    }
+
+    /// Draws a focus ring around the given bounds, over some base output.
+    ///
+    /// It is used to show which widget is focused after moving the focus with
+    /// the keyboard.
+    ///
+    /// By default, it returns the base output unchanged.
+    fn focus_ring(
+        &mut self,
+        base: Self::Output,
+        _bounds: Rectangle,
+    ) -> Self::Output {
+        base
+    }
+
+    /// Requests a new frame to be drawn right after the current one.
+    ///
+    /// Widgets with built-in animations can call this while drawing to keep
+    /// the runtime redrawing the user interface. The widgets of the next frame
+    /// will receive a [`window::Event::RedrawRequested`] event.
+    ///
+    /// By default, this does nothing.
+    ///
+    /// [`window::Event::RedrawRequested`]: ../window/enum.Event.html#variant.RedrawRequested
+    fn request_redraw(&mut self) {}
+
+    /// Requests a new frame to be drawn at the given [`Instant`].
+    ///
+    /// Widgets that only change at a known point in time, like the frames of
+    /// an animated image, can call this while drawing instead of redrawing
+    /// continuously. When several frames are requested, the earliest wins.
+    ///
+    /// By default, this does nothing.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    fn request_redraw_at(&mut self, _at: Instant) {}
+
+    /// Returns the earliest [`Instant`] a new frame was requested at since
+    /// the last call, if any.
+    ///
+    /// By default, this always returns `None`.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    fn take_redraw_request(&mut self) -> Option<Instant> {
+        None
+    }
+
+    /// Registers the font contained in the given bytes, making it available
+    /// by its family name with [`Font::Family`].
+    ///
+    /// Loading a family that is already registered does nothing.
+    ///
+    /// By default, this fails with [`font::Error::Unsupported`].
+    ///
+    /// [`Font::Family`]: ../enum.Font.html#variant.Family
+    /// [`font::Error::Unsupported`]: ../font/enum.Error.html#variant.Unsupported
+    fn load_font(
+        &mut self,
+        _bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        Err(font::Error::Unsupported)
+    }
 }
@@ -1,7 +1,11 @@
 use crate::{
-    button, checkbox, column, progress_bar, radio, row, scrollable, slider,
-    text, text_input, Color, Element, Font, HorizontalAlignment, Layout, Point,
-    Rectangle, Renderer, Size, VerticalAlignment,
+    button, checkbox, collapsible, column, context_menu, date_picker, float,
+    gauge, grid, icon, knob, menu_bar, modal, overlay::menu, pick_list,
+    progress_bar, progress_circle, radio, range_slider, rich_text, row, rule,
+    scrollable, slider, spinner, stack, table, tabs, tag_input, text,
+    text_editor, text_input, time_picker, toast, toggler, tooltip, tree_view,
+    wrap, Color, Element, Font, HorizontalAlignment, Layout, Point, Rectangle,
+    Renderer, Size, Vector, VerticalAlignment,
 };
 
 /// A renderer that does nothing.
@@ -35,6 +39,51 @@ impl column::Renderer for Null {
     }
 }
 
+impl grid::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+    }
+}
+
+impl float::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &Element<'_, Message, Self>,
+        _floating: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_positions: &[Point],
+    ) {
+    }
+}
+
+impl stack::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _children: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_positions: &[Point],
+    ) {
+    }
+}
+
+impl wrap::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+    }
+}
+
 impl row::Renderer for Null {
     fn draw<Message>(
         &mut self,
@@ -75,6 +124,41 @@ impl text::Renderer for Null {
     }
 }
 
+impl icon::Renderer for Null {
+    const DEFAULT_SIZE: u16 = 20;
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _glyph: icon::Glyph,
+        _size: u16,
+        _color: Option<Color>,
+    ) {
+    }
+}
+
+impl rich_text::Renderer for Null {
+    fn layout(
+        &self,
+        _fragments: &[rich_text::Fragment<'_, Font>],
+        _bounds: Size,
+        _horizontal_alignment: HorizontalAlignment,
+    ) -> Vec<rich_text::Run> {
+        Vec::new()
+    }
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _fragments: &[rich_text::Fragment<'_, Font>],
+        _runs: &[rich_text::Run],
+    ) {
+    }
+}
+
 impl scrollable::Renderer for Null {
     type Style = ();
 
@@ -83,6 +167,17 @@ impl scrollable::Renderer for Null {
         _bounds: Rectangle,
         _content_bounds: Rectangle,
         _offset: u32,
+        _style: &Self::Style,
+    ) -> Option<scrollable::Scrollbar> {
+        None
+    }
+
+    fn horizontal_scrollbar(
+        &self,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _offset: u32,
+        _style: &Self::Style,
     ) -> Option<scrollable::Scrollbar> {
         None
     }
@@ -95,7 +190,8 @@ impl scrollable::Renderer for Null {
         _is_mouse_over: bool,
         _is_mouse_over_scrollbar: bool,
         _scrollbar: Option<scrollable::Scrollbar>,
-        _offset: u32,
+        _horizontal_scrollbar: Option<scrollable::Scrollbar>,
+        _offset: Vector<u32>,
         _style: &Self::Style,
         _content: Self::Output,
     ) {
@@ -129,17 +225,85 @@ impl text_input::Renderer for Null {
         &mut self,
         _bounds: Rectangle,
         _text_bounds: Rectangle,
+        _toggle_bounds: Option<Rectangle>,
         _cursor_position: Point,
         _font: Font,
         _size: u16,
         _placeholder: &str,
         _value: &text_input::Value,
         _state: &text_input::State,
+        _is_disabled: bool,
+        _style: &Self::Style,
+    ) -> Self::Output {
+    }
+}
+
+impl text_editor::Renderer for Null {
+    type Font = Font;
+    type Style = ();
+
+    fn default_size(&self) -> u16 {
+        20
+    }
+
+    fn measure_value(&self, _value: &str, _size: u16, _font: Font) -> f32 {
+        0.0
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _text_bounds: Rectangle,
+        _cursor_position: Point,
+        _font: Font,
+        _size: u16,
+        _placeholder: &str,
+        _value: &text_input::Value,
+        _lines: &[text_editor::Line],
+        _state: &text_editor::State,
         _style: &Self::Style,
     ) -> Self::Output {
     }
 }
 
+impl modal::Renderer for Null {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _style: &Self::Style,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
+
+impl collapsible::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _header: Rectangle,
+        _title: &str,
+        _is_expanded: bool,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
+
 impl button::Renderer for Null {
     const DEFAULT_PADDING: u16 = 0;
 
@@ -152,6 +316,7 @@ impl button::Renderer for Null {
         _cursor_position: Point,
         _is_disabled: bool,
         _is_pressed: bool,
+        _is_focused: bool,
         _style: &Self::Style,
         _content: &Element<'_, Message, Self>,
         _content_layout: Layout<'_>,
@@ -170,6 +335,7 @@ impl radio::Renderer for Null {
         _bounds: Rectangle,
         _is_selected: bool,
         _is_mouse_over: bool,
+        _is_disabled: bool,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -187,6 +353,26 @@ impl checkbox::Renderer for Null {
         _bounds: Rectangle,
         _is_checked: bool,
         _is_mouse_over: bool,
+        _is_disabled: bool,
+        _label: Self::Output,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl toggler::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 20;
+    const DEFAULT_SPACING: u16 = 15;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _is_active: bool,
+        _thumb_position: f32,
+        _is_mouse_over: bool,
+        _is_disabled: bool,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -207,11 +393,87 @@ impl slider::Renderer for Null {
         _range: std::ops::RangeInclusive<f32>,
         _value: f32,
         _is_dragging: bool,
+        _is_disabled: bool,
+        _orientation: slider::Orientation,
+        _ticks: &[f32],
         _style_sheet: &Self::Style,
     ) {
     }
 }
 
+impl range_slider::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _range: std::ops::RangeInclusive<f32>,
+        _values: (f32, f32),
+        _is_dragging: bool,
+        _orientation: slider::Orientation,
+        _ticks: &[f32],
+        _style_sheet: &(),
+    ) {
+    }
+}
+
+impl date_picker::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _selected: Option<String>,
+        _placeholder: &str,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+
+    fn draw_calendar(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _title: &date_picker::Cell,
+        _navigation: &[date_picker::Cell],
+        _weekdays: &[date_picker::Cell],
+        _days: &[date_picker::Cell],
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl time_picker::Renderer for Null {
+    fn draw_spinners(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _cells: &[date_picker::Cell],
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl rule::Renderer for Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _style: &Self::Style,
+        _is_horizontal: bool,
+    ) {
+    }
+}
+
 impl progress_bar::Renderer for Null {
     type Style = ();
 
@@ -226,3 +488,255 @@ impl progress_bar::Renderer for Null {
     ) {
     }
 }
+
+impl progress_circle::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 40;
+
+    fn draw(
+        &self,
+        _bounds: Rectangle,
+        _range: std::ops::RangeInclusive<f32>,
+        _value: f32,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl knob::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 48;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _range: std::ops::RangeInclusive<f32>,
+        _value: f32,
+        _is_dragging: bool,
+        _ticks: &[f32],
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl gauge::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 160;
+    const DEFAULT_TEXT_SIZE: u16 = 12;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _range: std::ops::RangeInclusive<f32>,
+        _value: f32,
+        _value_label: &str,
+        _ticks: &[f32],
+        _labels: &[(f32, String)],
+        _text_size: u16,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl tag_input::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 5;
+    const DEFAULT_SPACING: u16 = 5;
+    const TAG_PADDING: u16 = 3;
+
+    fn input_style(_style: &()) {}
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _is_focused: bool,
+        _tags: &[tag_input::Tag<'_>],
+        _text_size: u16,
+        _input: (),
+        _style: &(),
+    ) {
+    }
+}
+
+impl spinner::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 32;
+
+    fn draw(&mut self, _bounds: Rectangle, _phase: f32, _style: &Self::Style) {}
+}
+
+impl menu::Renderer for Null {
+    type Style = ();
+
+    fn draw<T: ToString>(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _options: &[T],
+        _hovered_option: Option<usize>,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl pick_list::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = ();
+
+    fn menu_style(_style: &()) {}
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _selected: Option<String>,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl tooltip::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _label: &str,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl toast::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _cursor_position: Point,
+        _banners: &[toast::Banner<'_>],
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl context_menu::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _panels: &[context_menu::Panel<'_>],
+        _cursor_position: Point,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl menu_bar::Renderer for Null {
+    type Style = ();
+
+    fn menu_style(_style: &()) {}
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _titles: &[(Rectangle, &str)],
+        _highlighted_title: Option<usize>,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl tabs::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _strip_bounds: Rectangle,
+        _headers: &[tabs::Header<'_>],
+        _cursor_position: Point,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _icon_font: Font,
+        _style: &(),
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
+
+impl table::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _header_layout: Layout<'_>,
+        _headers: &[table::Header<'_>],
+        _viewport: Rectangle,
+        _body_layout: Layout<'_>,
+        _cells: &[Vec<Element<'_, Message, Self>>],
+        _offset: f32,
+        _selected: Option<usize>,
+        _is_resizing: bool,
+        _cursor_position: Point,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl tree_view::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 0;
+
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _rows: &[tree_view::Row<'_>],
+        _indent: u16,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _icon_font: Font,
+        _style: &(),
+    ) {
+    }
+}
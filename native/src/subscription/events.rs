@@ -1,7 +1,8 @@
 use crate::{
     subscription::{EventStream, Recipe},
-    Event, Hasher,
+    window, Event, Hasher,
 };
+use iced_futures::futures::{future, StreamExt};
 use iced_futures::BoxStream;
 
 pub struct Events;
@@ -19,6 +20,15 @@ impl Recipe<Hasher, Event> for Events {
         self: Box<Self>,
         event_stream: EventStream,
     ) -> BoxStream<Self::Output> {
+        // Redraws are only meant to drive `window::frames`. Forwarding them
+        // would make any application listening to every event redraw forever.
         event_stream
+            .filter(|event| {
+                future::ready(!matches!(
+                    event,
+                    Event::Window(window::Event::RedrawRequested(_))
+                ))
+            })
+            .boxed()
     }
 }
@@ -0,0 +1,23 @@
+//! Query the system the application runs on.
+mod appearance;
+
+pub use iced_core::system::Appearance;
+
+use crate::Subscription;
+
+/// Returns a [`Subscription`] that produces the [`Appearance`] of the system.
+///
+/// The [`Appearance`] detected when the application starts is produced
+/// first, followed by any change made by the user while the application runs.
+/// It allows the theme of the application to follow the system.
+///
+/// On Windows, the window reports the [`Appearance`] and its changes. On
+/// macOS and Linux, the settings of the desktop are read once and then
+/// watched in a background thread. A light appearance is assumed when they
+/// cannot be read.
+///
+/// [`Subscription`]: ../subscription/type.Subscription.html
+/// [`Appearance`]: enum.Appearance.html
+pub fn appearance() -> Subscription<Appearance> {
+    Subscription::from_recipe(appearance::Changes)
+}
@@ -0,0 +1,199 @@
+use crate::{
+    subscription::{EventStream, Recipe},
+    system, Event, Hasher,
+};
+use iced_futures::futures::{future, StreamExt};
+use iced_futures::BoxStream;
+
+pub struct Changes;
+
+impl Recipe<Hasher, Event> for Changes {
+    type Output = system::Appearance;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    // Windows reports the appearance through the events of the window
+    #[cfg(any(target_os = "windows", target_arch = "wasm32"))]
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<Self::Output> {
+        event_stream
+            .filter_map(|event| {
+                future::ready(match event {
+                    Event::Window(crate::window::Event::AppearanceChanged(
+                        appearance,
+                    )) => Some(appearance),
+                    _ => None,
+                })
+            })
+            .boxed()
+    }
+
+    // Elsewhere, the settings of the desktop are watched in their own thread
+    #[cfg(not(any(target_os = "windows", target_arch = "wasm32")))]
+    fn stream(
+        self: Box<Self>,
+        _event_stream: EventStream,
+    ) -> BoxStream<Self::Output> {
+        use iced_futures::futures::channel::mpsc;
+
+        let (sender, receiver) = mpsc::unbounded();
+
+        let _ = std::thread::spawn(move || {
+            watch(|appearance| sender.unbounded_send(appearance).is_ok())
+        });
+
+        // Only changes are produced after the first appearance
+        let mut last = None;
+
+        receiver
+            .filter(move |appearance| {
+                let is_new = last != Some(*appearance);
+                last = Some(*appearance);
+
+                future::ready(is_new)
+            })
+            .boxed()
+    }
+}
+
+/// Calls `notify` with the detected appearance and then with every change,
+/// until it returns `false`.
+///
+/// A light appearance is assumed when the settings cannot be read.
+#[cfg(target_os = "macos")]
+fn watch(notify: impl Fn(system::Appearance) -> bool) {
+    use std::time::Duration;
+
+    // Changes are only broadcast through the Objective-C runtime, so the
+    // setting is read again every few seconds instead
+    const INTERVAL: Duration = Duration::from_secs(2);
+
+    loop {
+        // The key is only set when the dark appearance is enabled
+        let appearance =
+            match output("defaults", &["read", "-g", "AppleInterfaceStyle"]) {
+                Some(style) if style.trim() == "Dark" => {
+                    system::Appearance::Dark
+                }
+                _ => system::Appearance::Light,
+            };
+
+        if !notify(appearance) {
+            return;
+        }
+
+        std::thread::sleep(INTERVAL);
+    }
+}
+
+/// Calls `notify` with the detected appearance and then with every change,
+/// until it returns `false`.
+///
+/// A light appearance is assumed when the settings cannot be read.
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_arch = "wasm32"
+)))]
+fn watch(notify: impl Fn(system::Appearance) -> bool) {
+    use std::io::{BufRead, BufReader};
+    use std::process;
+
+    const SCHEMA: &str = "org.gnome.desktop.interface";
+
+    // GTK applications can be forced into a variant, like `Adwaita:dark`,
+    // which cannot change while the application runs
+    if let Ok(theme) = std::env::var("GTK_THEME") {
+        let _ = notify(if theme.to_lowercase().ends_with(":dark") {
+            system::Appearance::Dark
+        } else {
+            system::Appearance::Light
+        });
+
+        return;
+    }
+
+    // Newer desktops store the preference explicitly, while older ones only
+    // tell us the name of the theme in use
+    let mut color_scheme =
+        output("gsettings", &["get", SCHEMA, "color-scheme"])
+            .unwrap_or_default();
+    let mut gtk_theme =
+        output("gsettings", &["get", SCHEMA, "gtk-theme"]).unwrap_or_default();
+
+    let appearance = |color_scheme: &str, gtk_theme: &str| {
+        if color_scheme.contains("prefer-dark")
+            || (!color_scheme.contains("prefer-light")
+                && gtk_theme.to_lowercase().contains("dark"))
+        {
+            system::Appearance::Dark
+        } else {
+            system::Appearance::Light
+        }
+    };
+
+    if !notify(appearance(&color_scheme, &gtk_theme)) {
+        return;
+    }
+
+    // `gsettings monitor` prints a `key: value` line for every change
+    let mut monitor = match process::Command::new("gsettings")
+        .arg("monitor")
+        .arg(SCHEMA)
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::null())
+        .spawn()
+    {
+        Ok(monitor) => monitor,
+        Err(_) => return,
+    };
+
+    if let Some(stdout) = monitor.stdout.take() {
+        for line in BufReader::new(stdout).lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(_) => break,
+            };
+
+            let mut parts = line.splitn(2, ':');
+
+            match (parts.next(), parts.next()) {
+                (Some("color-scheme"), Some(value)) => {
+                    color_scheme = value.to_owned();
+                }
+                (Some("gtk-theme"), Some(value)) => {
+                    gtk_theme = value.to_owned();
+                }
+                _ => continue,
+            }
+
+            if !notify(appearance(&color_scheme, &gtk_theme)) {
+                break;
+            }
+        }
+    }
+
+    let _ = monitor.kill();
+    let _ = monitor.wait();
+}
+
+#[cfg(not(any(target_os = "windows", target_arch = "wasm32")))]
+fn output(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
+}
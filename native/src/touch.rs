@@ -0,0 +1,5 @@
+//! Track touch events and recognize gestures.
+pub mod gesture;
+
+pub use gesture::Gesture;
+pub use iced_core::touch::*;
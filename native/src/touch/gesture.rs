@@ -0,0 +1,330 @@
+//! Recognize gestures out of touch events.
+use crate::touch::{Event, Finger};
+use crate::{Point, Vector};
+
+use std::time::{Duration, Instant};
+
+/// A gesture performed with one or two fingers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// A finger touched the screen and was lifted without moving.
+    Tap {
+        /// The position of the tap
+        position: Point,
+    },
+
+    /// A finger touched the screen and was held still for a while.
+    LongPress {
+        /// The position of the press
+        position: Point,
+    },
+
+    /// Two fingers moved closer together or further apart.
+    Pinch {
+        /// The point between both fingers
+        center: Point,
+
+        /// The ratio between the new and the previous distance of the
+        /// fingers
+        ///
+        /// It is greater than `1.0` when zooming in and lower than `1.0` when
+        /// zooming out.
+        scale: f32,
+    },
+
+    /// Two fingers moved together.
+    Pan {
+        /// The movement of the point between both fingers
+        translation: Vector,
+    },
+}
+
+/// Recognizes [`Gesture`]s out of a sequence of touch events.
+///
+/// A [`Recognizer`] is meant to live in the local state of a widget.
+///
+/// [`Gesture`]: enum.Gesture.html
+/// [`Recognizer`]: struct.Recognizer.html
+#[derive(Debug, Clone, Default)]
+pub struct Recognizer {
+    fingers: Vec<(Finger, Point)>,
+    press: Option<Press>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    finger: Finger,
+    origin: Point,
+    time: Instant,
+    is_long: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pair {
+    center: Point,
+    distance: f32,
+}
+
+impl Recognizer {
+    /// The time a finger needs to be held still to produce a
+    /// [`Gesture::LongPress`].
+    ///
+    /// [`Gesture::LongPress`]: enum.Gesture.html#variant.LongPress
+    pub const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+    /// The distance a finger can move before a press is not considered a
+    /// tap anymore.
+    pub const TAP_SLOP: f32 = 10.0;
+
+    /// Creates a new [`Recognizer`].
+    ///
+    /// [`Recognizer`]: struct.Recognizer.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processes a touch event that happened at the given [`Instant`] and
+    /// returns the recognized [`Gesture`]s.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`Gesture`]: enum.Gesture.html
+    pub fn update(&mut self, event: Event, now: Instant) -> Vec<Gesture> {
+        let mut gestures: Vec<Gesture> = self.tick(now).into_iter().collect();
+
+        match event {
+            Event::FingerPressed { id, position } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+                self.fingers.push((id, position));
+
+                // Taps and long presses are performed with a single finger
+                self.press = if self.fingers.len() == 1 {
+                    Some(Press {
+                        finger: id,
+                        origin: position,
+                        time: now,
+                        is_long: false,
+                    })
+                } else {
+                    None
+                };
+            }
+            Event::FingerMoved { id, position } => {
+                let before = self.pair();
+
+                if let Some((_, current)) =
+                    self.fingers.iter_mut().find(|(finger, _)| *finger == id)
+                {
+                    *current = position;
+                }
+
+                let is_slipping = matches!(
+                    self.press,
+                    Some(press) if press.finger == id
+                        && press.origin.distance(position) > Self::TAP_SLOP
+                );
+
+                if is_slipping {
+                    self.press = None;
+                }
+
+                if let (Some(before), Some(after)) = (before, self.pair()) {
+                    let translation = after.center - before.center;
+
+                    if translation.x != 0.0 || translation.y != 0.0 {
+                        gestures.push(Gesture::Pan { translation });
+                    }
+
+                    if before.distance > 0.0
+                        && (after.distance - before.distance).abs()
+                            > f32::EPSILON
+                    {
+                        gestures.push(Gesture::Pinch {
+                            center: after.center,
+                            scale: after.distance / before.distance,
+                        });
+                    }
+                }
+            }
+            Event::FingerLifted { id, .. } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+
+                match self.press {
+                    Some(press) if press.finger == id => {
+                        if !press.is_long {
+                            gestures.push(Gesture::Tap {
+                                position: press.origin,
+                            });
+                        }
+
+                        self.press = None;
+                    }
+                    _ => {}
+                }
+            }
+            Event::FingerLost { id, .. } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+
+                if matches!(self.press, Some(press) if press.finger == id) {
+                    self.press = None;
+                }
+            }
+        }
+
+        gestures
+    }
+
+    /// Notifies the [`Recognizer`] that time has passed, returning a
+    /// [`Gesture::LongPress`] if a finger has been held still long enough.
+    ///
+    /// [`Recognizer`]: struct.Recognizer.html
+    /// [`Gesture::LongPress`]: enum.Gesture.html#variant.LongPress
+    pub fn tick(&mut self, now: Instant) -> Option<Gesture> {
+        let press = self.press.as_mut()?;
+
+        if press.is_long
+            || now.duration_since(press.time) < Self::LONG_PRESS_DURATION
+        {
+            return None;
+        }
+
+        press.is_long = true;
+
+        Some(Gesture::LongPress {
+            position: press.origin,
+        })
+    }
+
+    /// Returns whether the [`Recognizer`] is waiting for a finger to be held
+    /// long enough to produce a [`Gesture::LongPress`].
+    ///
+    /// While waiting, [`tick`] should be called regularly. For instance, a
+    /// widget can request a redraw and call [`tick`] when the next frame is
+    /// requested.
+    ///
+    /// [`Recognizer`]: struct.Recognizer.html
+    /// [`Gesture::LongPress`]: enum.Gesture.html#variant.LongPress
+    /// [`tick`]: #method.tick
+    pub fn is_waiting(&self) -> bool {
+        matches!(self.press, Some(Press { is_long: false, .. }))
+    }
+
+    fn pair(&self) -> Option<Pair> {
+        match self.fingers.as_slice() {
+            [(_, a), (_, b)] => Some(Pair {
+                center: Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0),
+                distance: a.distance(*b),
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn taps_and_long_presses() {
+        let mut recognizer = Recognizer::new();
+        let start = Instant::now();
+        let position = Point::new(10.0, 10.0);
+
+        let _ = recognizer.update(
+            Event::FingerPressed {
+                id: Finger(0),
+                position,
+            },
+            start,
+        );
+
+        assert_eq!(
+            recognizer.update(
+                Event::FingerLifted {
+                    id: Finger(0),
+                    position,
+                },
+                start + Duration::from_millis(100),
+            ),
+            vec![Gesture::Tap { position }]
+        );
+
+        let _ = recognizer.update(
+            Event::FingerPressed {
+                id: Finger(1),
+                position,
+            },
+            start,
+        );
+
+        assert!(recognizer.is_waiting());
+        assert_eq!(
+            recognizer.tick(start + Recognizer::LONG_PRESS_DURATION),
+            Some(Gesture::LongPress { position })
+        );
+        assert!(!recognizer.is_waiting());
+        assert_eq!(
+            recognizer.update(
+                Event::FingerLifted {
+                    id: Finger(1),
+                    position,
+                },
+                start + Duration::from_secs(1),
+            ),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn pinches_and_pans() {
+        let mut recognizer = Recognizer::new();
+        let now = Instant::now();
+
+        for (id, x) in &[(0, 0.0), (1, 10.0)] {
+            let _ = recognizer.update(
+                Event::FingerPressed {
+                    id: Finger(*id),
+                    position: Point::new(*x, 0.0),
+                },
+                now,
+            );
+        }
+
+        assert_eq!(
+            recognizer.update(
+                Event::FingerMoved {
+                    id: Finger(1),
+                    position: Point::new(20.0, 0.0),
+                },
+                now,
+            ),
+            vec![
+                Gesture::Pan {
+                    translation: Vector::new(5.0, 0.0)
+                },
+                Gesture::Pinch {
+                    center: Point::new(10.0, 0.0),
+                    scale: 2.0
+                }
+            ]
+        );
+
+        let _ = recognizer.update(
+            Event::FingerLifted {
+                id: Finger(1),
+                position: Point::new(20.0, 0.0),
+            },
+            now,
+        );
+
+        assert_eq!(
+            recognizer.update(
+                Event::FingerLifted {
+                    id: Finger(0),
+                    position: Point::ORIGIN,
+                },
+                now,
+            ),
+            Vec::new()
+        );
+    }
+}
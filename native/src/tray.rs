@@ -0,0 +1,21 @@
+//! Show an icon in the system tray.
+pub use iced_core::tray::{Action, Item, Tray};
+
+use crate::Command;
+
+/// Returns a [`Command`] that shows the given [`Tray`], replacing the current
+/// one, if any.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Tray`]: struct.Tray.html
+pub fn show<Message>(tray: Tray<Message>) -> Command<Message> {
+    Command::tray(Action::Show(tray))
+}
+
+/// Returns a [`Command`] that removes the current [`Tray`], if any.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Tray`]: struct.Tray.html
+pub fn remove<Message>() -> Command<Message> {
+    Command::tray(Action::Remove)
+}
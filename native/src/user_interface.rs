@@ -1,5 +1,9 @@
-use crate::{layout, mouse, Clipboard, Element, Event, Layout, Point, Size};
+use crate::{
+    focus, keyboard, layout, mouse, window, Clipboard, Element, Event, Id,
+    Layout, Point, Rectangle, Size,
+};
 
+use std::cell::RefCell;
 use std::hash::Hasher;
 
 /// A set of interactive graphical elements with a specific [`Layout`].
@@ -20,10 +24,15 @@ use std::hash::Hasher;
 #[allow(missing_debug_implementations)]
 pub struct UserInterface<'a, Message, Renderer> {
     hash: u64,
-    root: Element<'a, Message, Renderer>,
+    // Overlays are built from a mutable reference to the root, even when
+    // only drawing
+    root: RefCell<Element<'a, Message, Renderer>>,
     layout: layout::Node,
     bounds: Size,
     cursor_position: Point,
+    is_focus_visible: bool,
+    focus_ring: Option<Rectangle>,
+    capture: Capture,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -110,13 +119,20 @@ where
             renderer.layout(&root, &layout::Limits::new(Size::ZERO, bounds))
         };
 
-        UserInterface {
+        let mut user_interface = UserInterface {
             hash,
-            root,
+            root: RefCell::new(root),
             layout,
             bounds,
             cursor_position: cache.cursor_position,
-        }
+            is_focus_visible: cache.is_focus_visible,
+            focus_ring: None,
+            capture: cache.capture,
+        };
+
+        user_interface.update_focus_ring();
+
+        user_interface
     }
 
     /// Updates the [`UserInterface`] by processing each provided [`Event`].
@@ -124,6 +140,11 @@ where
     /// It returns __messages__ that may have been produced as a result of user
     /// interactions. You should feed these to your __update logic__.
     ///
+    /// While a mouse button is held, the mouse is captured by the layer where
+    /// the press started. Its widgets keep receiving the cursor position, even
+    /// if an overlay is opened on top of them, until every button is released.
+    /// Pressed buttons are released when the window loses the focus.
+    ///
     /// [`UserInterface`]: struct.UserInterface.html
     /// [`Event`]: enum.Event.html
     ///
@@ -188,20 +209,29 @@ where
         let mut messages = Vec::new();
 
         for event in events {
-            if let Event::Mouse(mouse::Event::CursorMoved { x, y }) = event {
-                self.cursor_position = Point::new(x, y);
+            // Losing the focus releases the mouse buttons, as their release
+            // will not be reported
+            if let Event::Window(window::Event::Unfocused) = event {
+                let pressed_buttons =
+                    std::mem::take(&mut self.capture.pressed_buttons);
+
+                for button in pressed_buttons {
+                    self.dispatch(
+                        Event::Mouse(mouse::Event::ButtonReleased(button)),
+                        &mut messages,
+                        clipboard,
+                        renderer,
+                    );
+                }
+
+                self.capture.layer = None;
             }
 
-            self.root.widget.on_event(
-                event,
-                Layout::new(&self.layout),
-                self.cursor_position,
-                &mut messages,
-                renderer,
-                clipboard,
-            );
+            self.dispatch(event, &mut messages, clipboard, renderer);
         }
 
+        self.update_focus_ring();
+
         messages
     }
 
@@ -269,12 +299,213 @@ where
     /// }
     /// ```
     pub fn draw(&self, renderer: &mut Renderer) -> Renderer::Output {
-        self.root.widget.draw(
-            renderer,
-            &Renderer::Defaults::default(),
+        let defaults = renderer.defaults();
+        let mut root = self.root.borrow_mut();
+
+        let overlay =
+            if let Some(overlay) = root.overlay(Layout::new(&self.layout)) {
+                let layout = overlay.layout(renderer, self.bounds);
+
+                let output = overlay.draw(
+                    renderer,
+                    &defaults,
+                    Layout::new(&layout),
+                    self.cursor_position,
+                );
+
+                let is_over =
+                    overlay.is_over(Layout::new(&layout), self.cursor_position);
+
+                Some((output, layout.bounds(), is_over))
+            } else {
+                None
+            };
+
+        match overlay {
+            Some((overlay, overlay_bounds, is_over)) => {
+                let base_cursor = self.base_cursor(is_over);
+
+                let base = root.widget.draw(
+                    renderer,
+                    &defaults,
+                    Layout::new(&self.layout),
+                    base_cursor,
+                );
+
+                let base = match self.focus_ring {
+                    Some(bounds) => renderer.focus_ring(base, bounds),
+                    None => base,
+                };
+
+                renderer.overlay(base, overlay, overlay_bounds)
+            }
+            None => {
+                let base = root.widget.draw(
+                    renderer,
+                    &defaults,
+                    Layout::new(&self.layout),
+                    self.cursor_position,
+                );
+
+                match self.focus_ring {
+                    Some(bounds) => renderer.focus_ring(base, bounds),
+                    None => base,
+                }
+            }
+        }
+    }
+
+    fn dispatch(
+        &mut self,
+        event: Event,
+        messages: &mut Vec<Message>,
+        clipboard: Option<&dyn Clipboard>,
+        renderer: &Renderer,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                self.cursor_position = Point::new(x, y);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                self.is_focus_visible = false;
+            }
+            // Shortcuts like `Ctrl+Tab` or `Alt+Tab` do not move the focus
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }) if !modifiers.control && !modifiers.alt && !modifiers.logo => {
+                let mut targets = Vec::new();
+
+                self.root
+                    .get_mut()
+                    .focus_targets(Layout::new(&self.layout), &mut targets);
+
+                let handles_tab = targets.iter().any(|target| {
+                    target.state.is_focused() && target.state.handles_tab()
+                });
+
+                // Moving the focus consumes the event
+                if !handles_tab
+                    && focus::traverse(&mut targets, modifiers.shift).is_some()
+                {
+                    self.is_focus_visible = true;
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        let is_over_overlay = if let Some(mut overlay) =
+            self.root.get_mut().overlay(Layout::new(&self.layout))
+        {
+            let layout = overlay.layout(renderer, self.bounds);
+
+            overlay.on_event(
+                event.clone(),
+                Layout::new(&layout),
+                self.cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+
+            overlay.is_over(Layout::new(&layout), self.cursor_position)
+        } else {
+            false
+        };
+
+        // Besides overlays, the focused widget handles keyboard events before
+        // any other
+        if let Event::Keyboard(keyboard_event) = &event {
+            if self.root.get_mut().on_focused_event(
+                *keyboard_event,
+                Layout::new(&self.layout),
+                messages,
+                renderer,
+                clipboard,
+            ) {
+                return;
+            }
+        }
+
+        // The layer where a press starts captures the mouse until every
+        // button is released
+        if let Event::Mouse(mouse::Event::ButtonPressed(button)) = event {
+            if self.capture.pressed_buttons.is_empty() {
+                self.capture.layer = Some(if is_over_overlay {
+                    Layer::Overlay
+                } else {
+                    Layer::Base
+                });
+            }
+
+            self.capture.pressed_buttons.push(button);
+        }
+
+        let base_cursor = self.base_cursor(is_over_overlay);
+
+        self.root.get_mut().widget.on_event(
+            event.clone(),
             Layout::new(&self.layout),
-            self.cursor_position,
-        )
+            base_cursor,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        if let Event::Mouse(mouse::Event::ButtonReleased(button)) = event {
+            self.capture
+                .pressed_buttons
+                .retain(|pressed| *pressed != button);
+
+            if self.capture.pressed_buttons.is_empty() {
+                self.capture.layer = None;
+            }
+        }
+    }
+
+    fn base_cursor(&self, is_over_overlay: bool) -> Point {
+        // The base layer cannot be interacted with through an overlay, unless
+        // it captured the mouse
+        match self.capture.layer {
+            Some(Layer::Base) => self.cursor_position,
+            Some(Layer::Overlay) => Point::new(-1.0, -1.0),
+            None if is_over_overlay => Point::new(-1.0, -1.0),
+            None => self.cursor_position,
+        }
+    }
+
+    /// Focuses the widget with the given [`Id`], unfocusing any other.
+    ///
+    /// It returns whether a focusable widget with the [`Id`] was found.
+    ///
+    /// [`Id`]: struct.Id.html
+    pub fn focus(&mut self, id: &Id) -> bool {
+        let mut targets = Vec::new();
+
+        self.root
+            .get_mut()
+            .focus_targets(Layout::new(&self.layout), &mut targets);
+
+        let is_focused = focus::focus(&mut targets, id).is_some();
+
+        self.update_focus_ring();
+
+        is_focused
+    }
+
+    fn update_focus_ring(&mut self) {
+        self.focus_ring = if self.is_focus_visible {
+            let mut targets = Vec::new();
+
+            self.root
+                .get_mut()
+                .focus_targets(Layout::new(&self.layout), &mut targets);
+
+            focus::focused(&targets)
+        } else {
+            None
+        };
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
@@ -288,6 +519,8 @@ where
             layout: self.layout,
             bounds: self.bounds,
             cursor_position: self.cursor_position,
+            is_focus_visible: self.is_focus_visible,
+            capture: self.capture,
         }
     }
 }
@@ -301,6 +534,8 @@ pub struct Cache {
     layout: layout::Node,
     bounds: Size,
     cursor_position: Point,
+    is_focus_visible: bool,
+    capture: Capture,
 }
 
 impl Cache {
@@ -317,6 +552,8 @@ impl Cache {
             layout: layout::Node::new(Size::new(0.0, 0.0)),
             bounds: Size::ZERO,
             cursor_position: Point::new(-1.0, -1.0),
+            is_focus_visible: false,
+            capture: Capture::default(),
         }
     }
 }
@@ -334,3 +571,218 @@ impl PartialEq for Cache {
 }
 
 impl Eq for Cache {}
+
+/// The mouse buttons being held and the layer that captured the mouse when
+/// they were pressed.
+#[derive(Debug, Clone, Default)]
+struct Capture {
+    pressed_buttons: Vec<mouse::Button>,
+    layer: Option<Layer>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layer {
+    Base,
+    Overlay,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        button, checkbox, renderer::Null, scrollable, slider, Button, Checkbox,
+        Column, Row, Scrollable, Slider, Text,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Pressed,
+        Toggled(bool),
+        Slid(f32),
+    }
+
+    #[derive(Default)]
+    struct States {
+        first: button::State,
+        checkbox: checkbox::State,
+        scrollable: scrollable::State,
+        slider: slider::State,
+        last: button::State,
+    }
+
+    impl States {
+        fn focused(&self) -> Vec<bool> {
+            vec![
+                self.first.is_focused(),
+                focus::Focusable::is_focused(&self.checkbox),
+                focus::Focusable::is_focused(&self.slider),
+                self.last.is_focused(),
+            ]
+        }
+    }
+
+    fn press(key_code: keyboard::KeyCode, shift: bool) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::ModifiersState {
+                shift,
+                ..keyboard::ModifiersState::NONE
+            },
+        })
+    }
+
+    fn update(
+        states: &mut States,
+        slider_tab_index: Option<u16>,
+        events: Vec<Event>,
+    ) -> Vec<Message> {
+        let mut slider =
+            Slider::new(&mut states.slider, 0.0..=10.0, 5.0, Message::Slid)
+                .step(1.0);
+
+        if let Some(tab_index) = slider_tab_index {
+            slider = slider.tab_index(tab_index);
+        }
+
+        let root = Row::new()
+            .push(
+                Column::new()
+                    .push(
+                        Button::new(&mut states.first, Text::new("First"))
+                            .on_press(Message::Pressed),
+                    )
+                    .push(Checkbox::new(
+                        &mut states.checkbox,
+                        false,
+                        "Checkbox",
+                        Message::Toggled,
+                    )),
+            )
+            .push(
+                Scrollable::new(&mut states.scrollable)
+                    .push(Row::new().push(slider)),
+            )
+            .push(
+                Button::new(&mut states.last, Text::new("Last"))
+                    .on_press(Message::Pressed),
+            );
+
+        let mut renderer = Null::new();
+
+        let mut user_interface = UserInterface::build(
+            root,
+            Size::new(500.0, 500.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        user_interface.update(events, None, &renderer)
+    }
+
+    #[test]
+    fn tab_traverses_nested_containers_in_layout_order() {
+        let mut states = States::default();
+
+        let expected = [
+            vec![true, false, false, false],
+            vec![false, true, false, false],
+            vec![false, false, true, false],
+            vec![false, false, false, true],
+            vec![true, false, false, false],
+        ];
+
+        for focused in expected.iter() {
+            let _ = update(
+                &mut states,
+                None,
+                vec![press(keyboard::KeyCode::Tab, false)],
+            );
+
+            assert_eq!(&states.focused(), focused);
+        }
+
+        let _ = update(
+            &mut states,
+            None,
+            vec![press(keyboard::KeyCode::Tab, true)],
+        );
+
+        assert_eq!(states.focused(), vec![false, false, false, true]);
+    }
+
+    #[test]
+    fn tab_index_comes_before_layout_order() {
+        let mut states = States::default();
+
+        let _ = update(
+            &mut states,
+            Some(1),
+            vec![press(keyboard::KeyCode::Tab, false)],
+        );
+
+        assert_eq!(states.focused(), vec![false, false, true, false]);
+
+        let _ = update(
+            &mut states,
+            Some(1),
+            vec![press(keyboard::KeyCode::Tab, false)],
+        );
+
+        assert_eq!(states.focused(), vec![true, false, false, false]);
+    }
+
+    #[test]
+    fn tab_with_other_modifiers_keeps_the_focus() {
+        let mut states = States::default();
+
+        let _ = update(
+            &mut states,
+            None,
+            vec![press(keyboard::KeyCode::Tab, false)],
+        );
+
+        for modifiers in [
+            keyboard::ModifiersState {
+                control: true,
+                ..keyboard::ModifiersState::NONE
+            },
+            keyboard::ModifiersState {
+                alt: true,
+                shift: true,
+                ..keyboard::ModifiersState::NONE
+            },
+        ]
+        .iter()
+        {
+            let _ = update(
+                &mut states,
+                None,
+                vec![Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Tab,
+                    modifiers: *modifiers,
+                })],
+            );
+
+            assert_eq!(states.focused(), vec![true, false, false, false]);
+        }
+    }
+
+    #[test]
+    fn focused_widget_handles_keys() {
+        let mut states = States::default();
+
+        let messages = update(
+            &mut states,
+            None,
+            vec![
+                press(keyboard::KeyCode::Tab, false),
+                press(keyboard::KeyCode::Tab, false),
+                press(keyboard::KeyCode::Space, false),
+                press(keyboard::KeyCode::Tab, false),
+                press(keyboard::KeyCode::Right, false),
+            ],
+        );
+
+        assert_eq!(messages, vec![Message::Toggled(true), Message::Slid(6.0)]);
+    }
+}
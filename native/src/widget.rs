@@ -20,54 +20,175 @@
 //!
 //! [`Widget`]: trait.Widget.html
 //! [renderer]: ../renderer/index.html
+pub mod aspect_ratio;
+pub mod autocomplete;
 pub mod button;
 pub mod checkbox;
+pub mod collapsible;
 pub mod column;
 pub mod container;
+pub mod context_menu;
+pub mod date_picker;
+pub mod draggable;
+pub mod drop_target;
+pub mod file_drop;
+pub mod float;
+pub mod gauge;
+pub mod grid;
+pub mod icon;
 pub mod image;
+pub mod knob;
+pub mod lazy_list;
+pub mod menu_bar;
+pub mod modal;
+pub mod number_input;
 pub mod pane_grid;
+pub mod pick_list;
 pub mod progress_bar;
+pub mod progress_circle;
 pub mod radio;
+pub mod range_slider;
+pub mod responsive;
+pub mod rich_text;
 pub mod row;
+pub mod rule;
 pub mod scrollable;
+pub mod shortcuts;
 pub mod slider;
 pub mod space;
+pub mod spinner;
+pub mod stack;
 pub mod svg;
+pub mod table;
+pub mod tabs;
+pub mod tag_input;
 pub mod text;
+pub mod text_editor;
 pub mod text_input;
+pub mod time_picker;
+pub mod toast;
+pub mod toggler;
+pub mod tooltip;
+pub mod tree_view;
+pub mod wrap;
 
+#[doc(no_inline)]
+pub use aspect_ratio::AspectRatio;
+#[doc(no_inline)]
+pub use autocomplete::Autocomplete;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use collapsible::Collapsible;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_target::DropTarget;
+#[doc(no_inline)]
+pub use file_drop::FileDrop;
+#[doc(no_inline)]
+pub use float::Float;
+#[doc(no_inline)]
+pub use gauge::Gauge;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use icon::Icon;
+#[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use knob::Knob;
+#[doc(no_inline)]
+pub use lazy_list::LazyList;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
+pub use pick_list::PickList;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
+pub use progress_circle::ProgressCircle;
+#[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
+pub use rule::Rule;
+#[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use shortcuts::Shortcuts;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
+pub use tag_input::TagInput;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
+pub use toast::Toast;
+#[doc(no_inline)]
+pub use toggler::Toggler;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
+pub use wrap::Wrap;
 
-use crate::{layout, Clipboard, Event, Hasher, Layout, Length, Point};
+use crate::{
+    keyboard, layout, overlay, Clipboard, Command, Event, Hasher, Id, Layout,
+    Length, Point,
+};
+
+/// Produces a [`Command`] that focuses the widget with the given [`Id`].
+///
+/// The focus is left unchanged if no focusable widget has the [`Id`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: ../struct.Id.html
+pub fn focus<Message>(id: Id) -> Command<Message> {
+    Command::focus(id)
+}
 
 /// A component that displays information and allows interaction.
 ///
@@ -159,6 +280,10 @@ where
     ///   * the `Renderer`
     ///   * a [`Clipboard`], if available
     ///
+    /// Mouse events keep being delivered while a button is held, even if the
+    /// cursor leaves the bounds of the [`Widget`] or the window. This allows
+    /// a [`Widget`] to track a drag until the button is released.
+    ///
     /// By default, it does nothing.
     ///
     /// [`Event`]: ../enum.Event.html
@@ -175,4 +300,68 @@ where
         _clipboard: Option<&dyn Clipboard>,
     ) {
     }
+
+    /// Returns the [`Overlay`] of the [`Widget`], if there is any.
+    ///
+    /// It receives the [`Layout`] of the [`Widget`], which can be used to
+    /// position the [`Overlay`].
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Overlay`]: ../overlay/trait.Overlay.html
+    /// [`Widget`]: trait.Widget.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn overlay(
+        &mut self,
+        _layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        None
+    }
+
+    /// Collects the focus [`Target`]s of the [`Widget`] and its children,
+    /// in layout order.
+    ///
+    /// Widgets that can take the keyboard focus should push a [`Target`]
+    /// with their [`Focusable`] state, while widgets with children should
+    /// collect their targets.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Target`]: ../focus/struct.Target.html
+    /// [`Focusable`]: ../focus/trait.Focusable.html
+    /// [`Widget`]: trait.Widget.html
+    fn focus_targets<'b>(
+        &'b mut self,
+        _layout: Layout<'_>,
+        _targets: &mut Vec<crate::focus::Target<'b>>,
+    ) {
+    }
+
+    /// Processes a keyboard event while the [`Widget`] or one of its
+    /// children is focused.
+    ///
+    /// A [`UserInterface`] delivers keyboard events to the focused widget
+    /// through this method first. Only the events that are not captured reach
+    /// the rest of the widgets through [`on_event`].
+    ///
+    /// Widgets that can take the keyboard focus should handle the event and
+    /// return `true` while focused, while widgets with children should
+    /// forward it to them, like with [`focus_targets`].
+    ///
+    /// By default, it does nothing and returns `false`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`UserInterface`]: ../struct.UserInterface.html
+    /// [`on_event`]: #method.on_event
+    /// [`focus_targets`]: #method.focus_targets
+    fn on_focused_event(
+        &mut self,
+        _event: keyboard::Event,
+        _layout: Layout<'_>,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        false
+    }
 }
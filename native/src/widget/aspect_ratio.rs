@@ -0,0 +1,256 @@
+//! Keep some content at a fixed aspect ratio.
+use std::hash::Hash;
+
+use crate::{
+    focus, keyboard, layout, overlay, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Size, Widget,
+};
+
+/// A container that sizes its content to a fixed width to height ratio.
+///
+/// The [`AspectRatio`] takes the biggest size with the given ratio that fits
+/// in the available space, and its content is laid out to fill it. It is
+/// useful for video thumbnails or square cells in a grid.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type AspectRatio<'a, Message> =
+/// #     iced_native::AspectRatio<'a, Message, Null>;
+/// let thumbnail = AspectRatio::<()>::new(16.0 / 9.0, Text::new("Video"))
+///     .max_width(320);
+/// ```
+///
+/// [`AspectRatio`]: struct.AspectRatio.html
+#[allow(missing_debug_implementations)]
+pub struct AspectRatio<'a, Message, Renderer> {
+    ratio: f32,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> AspectRatio<'a, Message, Renderer> {
+    /// Creates a new [`AspectRatio`] with the given width to height ratio
+    /// and content.
+    ///
+    /// [`AspectRatio`]: struct.AspectRatio.html
+    pub fn new<T>(ratio: f32, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        AspectRatio {
+            ratio: ratio.max(f32::EPSILON),
+            width: Length::Fill,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the width of the [`AspectRatio`].
+    ///
+    /// The final width may be smaller, to keep the ratio in the available
+    /// height.
+    ///
+    /// [`AspectRatio`]: struct.AspectRatio.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`AspectRatio`].
+    ///
+    /// The final height may be smaller, to keep the ratio in the available
+    /// width.
+    ///
+    /// [`AspectRatio`]: struct.AspectRatio.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`AspectRatio`].
+    ///
+    /// [`AspectRatio`]: struct.AspectRatio.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`AspectRatio`].
+    ///
+    /// [`AspectRatio`]: struct.AspectRatio.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for AspectRatio<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let max = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height)
+            .max();
+
+        let width = max.width.min(max.height * self.ratio);
+        let size = Size::new(width, width / self.ratio);
+
+        let content = self
+            .content
+            .layout(renderer, &layout::Limits::new(size, size));
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.content.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content.draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.ratio.to_bits().hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.content
+            .focus_targets(layout.children().next().unwrap(), targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.content.on_focused_event(
+            event,
+            layout.children().next().unwrap(),
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<AspectRatio<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        aspect_ratio: AspectRatio<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(aspect_ratio)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Row};
+
+    fn layout(aspect_ratio: &AspectRatio<'_, (), Null>, max: Size) -> Size {
+        Widget::<(), Null>::layout(
+            aspect_ratio,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, max),
+        )
+        .size()
+    }
+
+    #[test]
+    fn ratio_fits_in_available_space() {
+        let square = AspectRatio::new(1.0, Row::<(), Null>::new());
+        let wide = AspectRatio::new(2.0, Row::<(), Null>::new());
+
+        assert_eq!(
+            layout(&square, Size::new(100.0, 40.0)),
+            Size::new(40.0, 40.0)
+        );
+        assert_eq!(
+            layout(&wide, Size::new(100.0, 80.0)),
+            Size::new(100.0, 50.0)
+        );
+        assert_eq!(
+            layout(&wide, Size::new(100.0, f32::INFINITY)),
+            Size::new(100.0, 50.0)
+        );
+    }
+}
@@ -0,0 +1,416 @@
+//! Suggest values while your users type in a text field.
+//!
+//! An [`Autocomplete`] has some local [`State`].
+//!
+//! [`Autocomplete`]: struct.Autocomplete.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse,
+    overlay::{self, menu, Menu},
+    text_input, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    TextInput, Widget,
+};
+
+/// A [`TextInput`] showing the suggestions matching its value in a [`Menu`].
+///
+/// The suggestions containing the typed text, ignoring case, are shown below
+/// the field as the user types. They can be picked with the mouse, or with
+/// the arrow keys and `Enter`.
+///
+/// # Example
+/// ```
+/// # use iced_native::{autocomplete, renderer::Null};
+/// #
+/// # pub type Autocomplete<'a, T, Message> =
+/// #     iced_native::Autocomplete<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CountryChanged(String),
+///     CountrySelected(&'static str),
+/// }
+///
+/// const COUNTRIES: [&str; 3] = ["France", "Finland", "Spain"];
+///
+/// let mut state = autocomplete::State::new();
+/// let value = "f";
+///
+/// let autocomplete = Autocomplete::new(
+///     &mut state,
+///     "Country",
+///     value,
+///     &COUNTRIES[..],
+///     Message::CountryChanged,
+///     Message::CountrySelected,
+/// );
+/// ```
+///
+/// [`TextInput`]: ../text_input/struct.TextInput.html
+/// [`Menu`]: ../../overlay/menu/struct.Menu.html
+#[allow(missing_debug_implementations)]
+pub struct Autocomplete<'a, T, Message, Renderer: self::Renderer> {
+    input: TextInput<'a, Message, Renderer>,
+    menu: &'a mut menu::State,
+    last_selection: &'a mut Option<T>,
+    suggestions: Vec<T>,
+    on_selected: Box<dyn Fn(T) -> Message>,
+    padding: u16,
+    size: Option<u16>,
+    menu_style: <Renderer as menu::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> Autocomplete<'a, T, Message, Renderer>
+where
+    T: ToString + Clone,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Autocomplete`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - a placeholder
+    /// - the current value
+    /// - the list of suggestions
+    /// - a function that produces a message when the value changes
+    /// - a function that produces a message when a suggestion is selected
+    ///
+    /// [`Autocomplete`]: struct.Autocomplete.html
+    /// [`State`]: struct.State.html
+    pub fn new<F, G>(
+        state: &'a mut State<T>,
+        placeholder: &str,
+        value: &str,
+        suggestions: &[T],
+        on_change: F,
+        on_selected: G,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+        G: 'static + Fn(T) -> Message,
+    {
+        let State {
+            input,
+            menu,
+            last_selection,
+        } = state;
+
+        Autocomplete {
+            input: TextInput::new(input, placeholder, value, on_change),
+            menu,
+            last_selection,
+            suggestions: matching(suggestions, value),
+            on_selected: Box::new(on_selected),
+            padding: 0,
+            size: None,
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`Autocomplete`].
+    ///
+    /// [`Autocomplete`]: struct.Autocomplete.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    /// Sets the padding of the field and the suggestions of the
+    /// [`Autocomplete`].
+    ///
+    /// [`Autocomplete`]: struct.Autocomplete.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.input = self.input.padding(units);
+        self.padding = units;
+        self
+    }
+
+    /// Sets the text size of the [`Autocomplete`].
+    ///
+    /// [`Autocomplete`]: struct.Autocomplete.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.input = self.input.size(size);
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the message that should be produced when the field is submitted
+    /// while no suggestion is hovered.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.input = self.input.on_submit(message);
+        self
+    }
+
+    /// Sets the style of the field of the [`Autocomplete`].
+    ///
+    /// [`Autocomplete`]: struct.Autocomplete.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as text_input::Renderer>::Style>,
+    ) -> Self {
+        self.input = self.input.style(style);
+        self
+    }
+
+    /// Sets the style of the suggestions of the [`Autocomplete`].
+    ///
+    /// [`Autocomplete`]: struct.Autocomplete.html
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer as menu::Renderer>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+}
+
+/// Returns the suggestions containing the given value, ignoring case.
+pub(crate) fn matching<T: ToString + Clone>(
+    suggestions: &[T],
+    value: &str,
+) -> Vec<T> {
+    if value.is_empty() {
+        return Vec::new();
+    }
+
+    let value = value.to_lowercase();
+
+    suggestions
+        .iter()
+        .filter(|suggestion| {
+            suggestion.to_string().to_lowercase().contains(&value)
+        })
+        .cloned()
+        .collect()
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for Autocomplete<'a, T, Message, Renderer>
+where
+    T: ToString + Clone,
+    Message: Clone,
+    Renderer: self::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        Widget::<Message, Renderer>::width(&self.input)
+    }
+
+    fn height(&self) -> Length {
+        Widget::<Message, Renderer>::height(&self.input)
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.input.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        // The menu handles events first, so the event that selected a
+        // suggestion is not processed by the field
+        if let Some(selection) = self.last_selection.take() {
+            messages.push((self.on_selected)(selection));
+            return;
+        }
+
+        let is_click = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        );
+
+        let is_submit = matches!(
+            event,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                ..
+            })
+        );
+
+        let previous_messages = messages.len();
+
+        self.input.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        if is_submit || (is_click && !layout.bounds().contains(cursor_position))
+        {
+            self.menu.close();
+        } else if messages.len() > previous_messages {
+            // The value has changed, so the suggestions have too
+            self.menu.open(None);
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.input.draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        Widget::<Message, Renderer>::hash_layout(&self.input, state)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.menu.is_open() || self.suggestions.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut menu =
+            Menu::new(self.menu, &self.suggestions, self.last_selection)
+                .width(bounds.width.round() as u16)
+                .padding(self.padding)
+                .type_ahead(false)
+                .style(self.menu_style.clone());
+
+        if let Some(size) = self.size {
+            menu = menu.text_size(size);
+        }
+
+        Some(menu.overlay(Point::new(bounds.x, bounds.y), bounds.height))
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        Widget::<Message, Renderer>::focus_targets(
+            &mut self.input,
+            layout,
+            targets,
+        );
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.input.is_focused() {
+            return false;
+        }
+
+        self.on_event(
+            Event::Keyboard(event),
+            layout,
+            Point::new(-1.0, -1.0),
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        true
+    }
+}
+
+/// The state of an [`Autocomplete`].
+///
+/// [`Autocomplete`]: struct.Autocomplete.html
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    input: text_input::State,
+    menu: menu::State,
+    last_selection: Option<T>,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`], representing an unfocused [`Autocomplete`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Autocomplete`]: struct.Autocomplete.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the field of the [`Autocomplete`] is currently
+    /// focused or not.
+    ///
+    /// [`Autocomplete`]: struct.Autocomplete.html
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+
+    /// Returns whether the suggestions of the [`Autocomplete`] are shown or
+    /// not.
+    ///
+    /// [`Autocomplete`]: struct.Autocomplete.html
+    pub fn is_open(&self) -> bool {
+        self.menu.is_open()
+    }
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            input: text_input::State::default(),
+            menu: menu::State::default(),
+            last_selection: None,
+        }
+    }
+}
+
+/// The renderer of an [`Autocomplete`].
+///
+/// It is implemented for every renderer supporting its [`TextInput`] and
+/// [`Menu`].
+///
+/// [`Autocomplete`]: struct.Autocomplete.html
+/// [`TextInput`]: ../text_input/struct.TextInput.html
+/// [`Menu`]: ../../overlay/menu/struct.Menu.html
+pub trait Renderer: text_input::Renderer + menu::Renderer {}
+
+impl<T> Renderer for T where T: text_input::Renderer + menu::Renderer {}
+
+impl<'a, T, Message, Renderer> From<Autocomplete<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + ToString + Clone,
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        autocomplete: Autocomplete<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(autocomplete)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggestions_contain_the_value_ignoring_case() {
+        let suggestions = ["France", "Finland", "Spain"];
+
+        assert_eq!(matching(&suggestions, "an"), ["France", "Finland"]);
+        assert_eq!(matching(&suggestions, "SP"), ["Spain"]);
+        assert!(matching(&suggestions, "").is_empty());
+    }
+}
@@ -5,8 +5,8 @@
 //! [`Button`]: struct.Button.html
 //! [`State`]: struct.State.html
 use crate::{
-    layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Widget,
+    focus, keyboard, layout, mouse, touch, Clipboard, Element, Event, Hasher,
+    Id, Layout, Length, Point, Rectangle, Widget,
 };
 use std::hash::Hash;
 
@@ -36,6 +36,8 @@ pub struct Button<'a, Message, Renderer: self::Renderer> {
     min_width: u32,
     min_height: u32,
     padding: u16,
+    tab_index: Option<u16>,
+    id: Option<Id>,
     style: Renderer::Style,
 }
 
@@ -61,6 +63,8 @@ where
             min_width: 0,
             min_height: 0,
             padding: Renderer::DEFAULT_PADDING,
+            tab_index: None,
+            id: None,
             style: Renderer::Style::default(),
         }
     }
@@ -105,6 +109,24 @@ where
         self
     }
 
+    /// Sets the position of the [`Button`] in the focus order.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Button`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`Button`]: struct.Button.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the message that will be produced when the [`Button`] is pressed.
     ///
     /// [`Button`]: struct.Button.html
@@ -122,12 +144,46 @@ where
     }
 }
 
+impl<'a, Message, Renderer> Button<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn press(&mut self, bounds: Rectangle, position: Point) {
+        if self.on_press.is_some() {
+            self.state.is_pressed = bounds.contains(position);
+        }
+
+        if !bounds.contains(position) {
+            self.state.is_focused = false;
+        }
+    }
+
+    fn release(
+        &mut self,
+        bounds: Rectangle,
+        position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        if let Some(on_press) = self.on_press.clone() {
+            let is_clicked = self.state.is_pressed && bounds.contains(position);
+
+            self.state.is_pressed = false;
+
+            if is_clicked {
+                messages.push(on_press);
+            }
+        }
+    }
+}
+
 /// The local state of a [`Button`].
 ///
 /// [`Button`]: struct.Button.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -137,6 +193,29 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Button`] is currently focused or not.
+    ///
+    /// A focused [`Button`] can be pressed with `Enter` or `Space`.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -185,25 +264,19 @@ where
     ) {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                if self.on_press.is_some() {
-                    let bounds = layout.bounds();
-
-                    self.state.is_pressed = bounds.contains(cursor_position);
-                }
+                self.press(layout.bounds(), cursor_position);
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+                self.press(layout.bounds(), position);
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                if let Some(on_press) = self.on_press.clone() {
-                    let bounds = layout.bounds();
-
-                    let is_clicked = self.state.is_pressed
-                        && bounds.contains(cursor_position);
-
-                    self.state.is_pressed = false;
-
-                    if is_clicked {
-                        messages.push(on_press);
-                    }
-                }
+                self.release(layout.bounds(), cursor_position, messages);
+            }
+            Event::Touch(touch::Event::FingerLifted { position, .. }) => {
+                self.release(layout.bounds(), position, messages);
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.is_pressed = false;
             }
             _ => {}
         }
@@ -222,6 +295,7 @@ where
             cursor_position,
             self.on_press.is_none(),
             self.state.is_pressed,
+            self.state.is_focused,
             &self.style,
             &self.content,
             layout.children().next().unwrap(),
@@ -235,6 +309,53 @@ where
         self.width.hash(state);
         self.content.hash_layout(state);
     }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        // Disabled buttons cannot be focused
+        if self.on_press.is_some() {
+            targets.push(focus::Target::new(
+                self.state,
+                layout.bounds(),
+                self.tab_index,
+                self.id.clone(),
+            ));
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.state.is_focused {
+            return false;
+        }
+
+        match event {
+            keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                ..
+            }
+            | keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            } => {
+                if let Some(on_press) = self.on_press.clone() {
+                    messages.push(on_press);
+                }
+
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 /// The renderer of a [`Button`].
@@ -263,6 +384,7 @@ pub trait Renderer: crate::Renderer + Sized {
         cursor_position: Point,
         is_disabled: bool,
         is_pressed: bool,
+        is_focused: bool,
         style: &Self::Style,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
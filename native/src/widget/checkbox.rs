@@ -1,10 +1,15 @@
 //! Show toggle controls using checkboxes.
+//!
+//! A [`Checkbox`] has some local [`State`].
+//!
+//! [`Checkbox`]: struct.Checkbox.html
+//! [`State`]: struct.State.html
 use std::hash::Hash;
 
 use crate::{
-    layout, mouse, row, text, Align, Clipboard, Element, Event, Hasher,
-    HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
-    VerticalAlignment, Widget,
+    focus, keyboard, layout, mouse, row, text, Align, Clipboard, Element,
+    Event, Hasher, HorizontalAlignment, Id, Layout, Length, Point, Rectangle,
+    Row, Text, VerticalAlignment, Widget,
 };
 
 /// A box that can be checked.
@@ -12,36 +17,43 @@ use crate::{
 /// # Example
 ///
 /// ```
-/// # type Checkbox<Message> = iced_native::Checkbox<Message, iced_native::renderer::Null>;
+/// # use iced_native::checkbox;
+/// # type Checkbox<'a, Message> = iced_native::Checkbox<'a, Message, iced_native::renderer::Null>;
 /// #
 /// pub enum Message {
 ///     CheckboxToggled(bool),
 /// }
 ///
+/// let mut state = checkbox::State::new();
 /// let is_checked = true;
 ///
-/// Checkbox::new(is_checked, "Toggle me!", Message::CheckboxToggled);
+/// Checkbox::new(&mut state, is_checked, "Toggle me!", Message::CheckboxToggled);
 /// ```
 ///
 /// ![Checkbox drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/checkbox.png?raw=true)
 #[allow(missing_debug_implementations)]
-pub struct Checkbox<Message, Renderer: self::Renderer + text::Renderer> {
+pub struct Checkbox<'a, Message, Renderer: self::Renderer + text::Renderer> {
+    state: &'a mut State,
     is_checked: bool,
+    is_enabled: bool,
     on_toggle: Box<dyn Fn(bool) -> Message>,
     label: String,
     width: Length,
     size: u16,
     spacing: u16,
     text_size: u16,
+    tab_index: Option<u16>,
+    id: Option<Id>,
     style: Renderer::Style,
 }
 
-impl<Message, Renderer: self::Renderer + text::Renderer>
-    Checkbox<Message, Renderer>
+impl<'a, Message, Renderer: self::Renderer + text::Renderer>
+    Checkbox<'a, Message, Renderer>
 {
     /// Creates a new [`Checkbox`].
     ///
     /// It expects:
+    ///   * the local [`State`] of the [`Checkbox`]
     ///   * a boolean describing whether the [`Checkbox`] is checked or not
     ///   * the label of the [`Checkbox`]
     ///   * a function that will be called when the [`Checkbox`] is toggled. It
@@ -49,18 +61,28 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
     ///     `Message`.
     ///
     /// [`Checkbox`]: struct.Checkbox.html
-    pub fn new<F>(is_checked: bool, label: impl Into<String>, f: F) -> Self
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        is_checked: bool,
+        label: impl Into<String>,
+        f: F,
+    ) -> Self
     where
         F: 'static + Fn(bool) -> Message,
     {
         Checkbox {
+            state,
             is_checked,
+            is_enabled: true,
             on_toggle: Box::new(f),
             label: label.into(),
             width: Length::Shrink,
             size: <Renderer as self::Renderer>::DEFAULT_SIZE,
             spacing: Renderer::DEFAULT_SPACING,
             text_size: <Renderer as text::Renderer>::DEFAULT_SIZE,
+            tab_index: None,
+            id: None,
             style: Renderer::Style::default(),
         }
     }
@@ -97,6 +119,32 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self
     }
 
+    /// Disables the [`Checkbox`], which is then shown but cannot be toggled.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn disabled(mut self) -> Self {
+        self.is_enabled = false;
+        self
+    }
+
+    /// Sets the position of the [`Checkbox`] in the focus order.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Checkbox`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`Checkbox`].
     ///
     /// [`Checkbox`]: struct.Checkbox.html
@@ -106,8 +154,39 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
     }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer>
-    for Checkbox<Message, Renderer>
+/// The local state of a [`Checkbox`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Checkbox<'a, Message, Renderer>
 where
     Renderer: self::Renderer + text::Renderer + row::Renderer,
 {
@@ -154,7 +233,7 @@ where
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let mouse_over = layout.bounds().contains(cursor_position);
 
-                if mouse_over {
+                if mouse_over && self.is_enabled {
                     messages.push((self.on_toggle)(!self.is_checked));
                 }
             }
@@ -195,6 +274,7 @@ where
             checkbox_bounds,
             self.is_checked,
             is_mouse_over,
+            !self.is_enabled,
             label,
             &self.style,
         )
@@ -206,6 +286,47 @@ where
 
         self.label.hash(state);
     }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        // Disabled checkboxes cannot be focused
+        if self.is_enabled {
+            targets.push(focus::Target::new(
+                self.state,
+                layout.bounds(),
+                self.tab_index,
+                self.id.clone(),
+            ));
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.state.is_focused {
+            return false;
+        }
+
+        match event {
+            keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            } => {
+                messages.push((self.on_toggle)(!self.is_checked));
+
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 /// The renderer of a [`Checkbox`].
@@ -235,6 +356,7 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Checkbox`]
     ///   * whether the [`Checkbox`] is selected or not
     ///   * whether the mouse is over the [`Checkbox`] or not
+    ///   * whether the [`Checkbox`] is disabled or not
     ///   * the drawn label of the [`Checkbox`]
     ///
     /// [`Checkbox`]: struct.Checkbox.html
@@ -243,19 +365,20 @@ pub trait Renderer: crate::Renderer {
         bounds: Rectangle,
         is_checked: bool,
         is_mouse_over: bool,
+        is_disabled: bool,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
 }
 
-impl<'a, Message, Renderer> From<Checkbox<Message, Renderer>>
+impl<'a, Message, Renderer> From<Checkbox<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + self::Renderer + text::Renderer + row::Renderer,
     Message: 'a,
 {
     fn from(
-        checkbox: Checkbox<Message, Renderer>,
+        checkbox: Checkbox<'a, Message, Renderer>,
     ) -> Element<'a, Message, Renderer> {
         Element::new(checkbox)
     }
@@ -0,0 +1,516 @@
+//! Show and hide content below a clickable header.
+//!
+//! A [`Collapsible`] has some local [`State`].
+//!
+//! [`Collapsible`]: struct.Collapsible.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse, overlay, text, Clipboard, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// A section with a clickable header that expands and collapses its content
+/// with an animated transition.
+///
+/// Whether a [`Collapsible`] is expanded is decided by the application, which
+/// is notified when the header is pressed. A [`Group`] can keep track of the
+/// expanded sections of a settings page or a FAQ, optionally allowing only
+/// one of them to be expanded at a time.
+///
+/// # Example
+/// ```
+/// # use iced_native::{collapsible, renderer::Null, Text};
+/// #
+/// # pub type Collapsible<'a, Message> =
+/// #     iced_native::Collapsible<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(usize, bool),
+/// }
+///
+/// let group = collapsible::Group::exclusive();
+/// let mut state = collapsible::State::new();
+///
+/// let section = Collapsible::new(
+///     &mut state,
+///     "How do I reset my password?",
+///     group.is_expanded(0),
+///     Text::new("Use the link in the login screen."),
+///     |expanded| Message::Toggled(0, expanded),
+/// );
+/// ```
+///
+/// [`Collapsible`]: struct.Collapsible.html
+/// [`Group`]: struct.Group.html
+#[allow(missing_debug_implementations)]
+pub struct Collapsible<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    title: String,
+    is_expanded: bool,
+    content: Element<'a, Message, Renderer>,
+    on_toggle: Box<dyn Fn(bool) -> Message>,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Collapsible<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Collapsible`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Collapsible`]
+    ///   * the title shown in its header
+    ///   * whether the [`Collapsible`] is expanded
+    ///   * the content shown when expanded
+    ///   * a function that will be called when the header is pressed. It
+    ///     receives whether the [`Collapsible`] should be expanded and must
+    ///     produce a `Message`.
+    ///
+    /// [`Collapsible`]: struct.Collapsible.html
+    /// [`State`]: struct.State.html
+    pub fn new<E, F>(
+        state: &'a mut State,
+        title: impl Into<String>,
+        is_expanded: bool,
+        content: E,
+        on_toggle: F,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+        F: 'static + Fn(bool) -> Message,
+    {
+        state.transition_to(is_expanded, Instant::now());
+
+        Collapsible {
+            state,
+            title: title.into(),
+            is_expanded,
+            content: content.into(),
+            on_toggle: Box::new(on_toggle),
+            width: Length::Fill,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`Collapsible`].
+    ///
+    /// [`Collapsible`]: struct.Collapsible.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the header and the content of the
+    /// [`Collapsible`].
+    ///
+    /// [`Collapsible`]: struct.Collapsible.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the header of the [`Collapsible`].
+    ///
+    /// [`Collapsible`]: struct.Collapsible.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the header of the [`Collapsible`].
+    ///
+    /// [`Collapsible`]: struct.Collapsible.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Collapsible`].
+    ///
+    /// [`Collapsible`]: struct.Collapsible.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn header_height(&self) -> f32 {
+        f32::from(self.text_size.unwrap_or(Renderer::DEFAULT_SIZE))
+            + 2.0 * f32::from(self.padding)
+    }
+}
+
+/// The local state of a [`Collapsible`].
+///
+/// [`Collapsible`]: struct.Collapsible.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_expanded: Option<bool>,
+    transition: Option<(Instant, f32)>,
+    progress: f32,
+}
+
+/// The duration of the transition of a [`Collapsible`].
+///
+/// [`Collapsible`]: struct.Collapsible.html
+const TRANSITION_DURATION: Duration = Duration::from_millis(200);
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`Collapsible`] is currently expanding or
+    /// collapsing.
+    ///
+    /// [`Collapsible`]: struct.Collapsible.html
+    pub fn is_animating(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Updates the visible fraction of the content, starting a transition if
+    /// the [`Collapsible`] was toggled.
+    ///
+    /// The first time a [`Collapsible`] is shown, it does not animate.
+    ///
+    /// [`Collapsible`]: struct.Collapsible.html
+    fn transition_to(&mut self, is_expanded: bool, now: Instant) {
+        let target = if is_expanded { 1.0 } else { 0.0 };
+
+        match self.is_expanded {
+            None => {
+                self.progress = target;
+            }
+            Some(was_expanded) if was_expanded != is_expanded => {
+                self.transition = Some((now, self.progress));
+            }
+            Some(_) => {}
+        }
+
+        self.is_expanded = Some(is_expanded);
+
+        if let Some((started_at, start)) = self.transition {
+            let elapsed = now.duration_since(started_at).as_secs_f32();
+            let t = elapsed / TRANSITION_DURATION.as_secs_f32();
+
+            if t >= 1.0 {
+                self.progress = target;
+                self.transition = None;
+            } else {
+                // Ease out, slowing down towards the end of the transition
+                let eased = 1.0 - (1.0 - t) * (1.0 - t);
+
+                self.progress = start + (target - start) * eased;
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Collapsible<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let header_height = self.header_height();
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let width = limits.max().width;
+
+        let mut content = self.content.layout(
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(width - 2.0 * padding, f32::INFINITY),
+            ),
+        );
+        content.move_to(Point::new(padding, header_height + padding));
+
+        let content_height = content.size().height + 2.0 * padding;
+
+        let size = limits.resolve(Size::new(
+            width,
+            header_height + content_height * self.state.progress,
+        ));
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        let header = Rectangle {
+            height: self.header_height(),
+            ..bounds
+        };
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if header.contains(cursor_position) {
+                messages.push((self.on_toggle)(!self.is_expanded));
+                return;
+            }
+        }
+
+        if self.state.progress == 0.0 {
+            return;
+        }
+
+        // The hidden part of the content cannot be interacted with
+        let cursor_position = if bounds.contains(cursor_position) {
+            cursor_position
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        self.content.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        if self.state.is_animating() {
+            renderer.request_redraw();
+        }
+
+        let bounds = layout.bounds();
+
+        let header = Rectangle {
+            height: self.header_height(),
+            ..bounds
+        };
+
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            bounds,
+            cursor_position,
+            header,
+            &self.title,
+            self.is_expanded,
+            self.padding,
+            self.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+            self.font,
+            &self.style,
+            &self.content,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.state.progress.to_bits().hash(state);
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.is_expanded {
+            return None;
+        }
+
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        if self.is_expanded {
+            self.content
+                .focus_targets(layout.children().next().unwrap(), targets);
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.is_expanded
+            && self.content.on_focused_event(
+                event,
+                layout.children().next().unwrap(),
+                messages,
+                renderer,
+                clipboard,
+            )
+    }
+}
+
+/// The expanded sections of a list of [`Collapsible`] widgets.
+///
+/// An exclusive [`Group`] allows only one section to be expanded at a time,
+/// like an accordion.
+///
+/// [`Collapsible`]: struct.Collapsible.html
+/// [`Group`]: struct.Group.html
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Group {
+    expanded: Vec<usize>,
+    is_exclusive: bool,
+}
+
+impl Group {
+    /// Creates a new [`Group`] where any number of sections can be expanded.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`Group`] where only one section can be expanded at a
+    /// time.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn exclusive() -> Self {
+        Group {
+            expanded: Vec::new(),
+            is_exclusive: true,
+        }
+    }
+
+    /// Returns whether the section with the given index is expanded.
+    pub fn is_expanded(&self, index: usize) -> bool {
+        self.expanded.contains(&index)
+    }
+
+    /// Expands or collapses the section with the given index.
+    ///
+    /// In an exclusive [`Group`], expanding a section collapses the others.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn toggle(&mut self, index: usize, is_expanded: bool) {
+        if is_expanded {
+            if self.is_exclusive {
+                self.expanded.clear();
+            }
+
+            if !self.expanded.contains(&index) {
+                self.expanded.push(index);
+            }
+        } else {
+            self.expanded.retain(|expanded| *expanded != index);
+        }
+    }
+}
+
+/// The renderer of a [`Collapsible`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Collapsible`] in your user interface.
+///
+/// [`Collapsible`]: struct.Collapsible.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The default padding of a [`Collapsible`].
+    ///
+    /// [`Collapsible`]: struct.Collapsible.html
+    const DEFAULT_PADDING: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Collapsible`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Collapsible`], covering its visible part
+    ///   * the cursor position
+    ///   * the bounds of the header
+    ///   * the title of the header
+    ///   * whether the [`Collapsible`] is expanded
+    ///   * the padding, the text size and the font of the header
+    ///   * the style of the [`Collapsible`]
+    ///   * the content of the [`Collapsible`] and its layout, which must be
+    ///     clipped to the bounds of the [`Collapsible`]
+    ///
+    /// [`Collapsible`]: struct.Collapsible.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        header: Rectangle,
+        title: &str,
+        is_expanded: bool,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as self::Renderer>::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Collapsible<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        collapsible: Collapsible<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(collapsible)
+    }
+}
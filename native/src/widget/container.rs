@@ -2,8 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Widget,
+    focus, keyboard, layout, overlay, Align, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Widget,
 };
 
 use std::u32;
@@ -214,6 +214,39 @@ where
 
         self.content.hash_layout(state);
     }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.content
+            .focus_targets(layout.children().next().unwrap(), targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.content.on_focused_event(
+            event,
+            layout.children().next().unwrap(),
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
 }
 
 /// The renderer of a [`Container`].
@@ -0,0 +1,847 @@
+//! Show a menu of actions when a widget is right clicked.
+//!
+//! A [`ContextMenu`] has some local [`State`].
+//!
+//! [`ContextMenu`]: struct.ContextMenu.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse, overlay, text, Clipboard, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+/// A widget opening a menu at the cursor position when its content is right
+/// clicked.
+///
+/// The menu is made of [`Entry`] values. Clicking an item produces its
+/// message, and hovering a submenu opens it next to the menu.
+///
+/// # Example
+/// ```
+/// # use iced_native::{context_menu, renderer::Null, Text};
+/// #
+/// # pub type ContextMenu<'a, Message> =
+/// #     iced_native::ContextMenu<'a, Message, Null>;
+/// use iced_native::context_menu::Entry;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+///     Paste,
+///     Sort(bool),
+/// }
+///
+/// let mut state = context_menu::State::new();
+///
+/// let context_menu = ContextMenu::new(
+///     &mut state,
+///     Text::new("Right click me!"),
+///     vec![
+///         Entry::item("Copy", Message::Copy),
+///         Entry::item("Paste", Message::Paste),
+///         Entry::Separator,
+///         Entry::submenu(
+///             "Sort",
+///             vec![
+///                 Entry::item("Ascending", Message::Sort(true)),
+///                 Entry::item("Descending", Message::Sort(false)),
+///             ],
+///         ),
+///     ],
+/// );
+/// ```
+///
+/// [`Entry`]: enum.Entry.html
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    entries: Vec<Entry<Message>>,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> ContextMenu<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`ContextMenu`] with the given [`State`], content, and
+    /// menu entries.
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    /// [`State`]: struct.State.html
+    pub fn new<E>(
+        state: &'a mut State,
+        content: E,
+        entries: Vec<Entry<Message>>,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        ContextMenu {
+            state,
+            content: content.into(),
+            entries,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the padding of the entries of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the entries of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the entries of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// An entry of a [`ContextMenu`].
+///
+/// [`ContextMenu`]: struct.ContextMenu.html
+#[derive(Debug, Clone)]
+pub enum Entry<Message> {
+    /// An item producing a message when clicked.
+    Item {
+        /// The label of the item.
+        label: String,
+
+        /// The message produced when the item is clicked.
+        message: Message,
+
+        /// The keyboard shortcut shown next to the label, if any.
+        shortcut: Option<String>,
+
+        /// Whether the item can be clicked or not.
+        is_enabled: bool,
+    },
+
+    /// A nested menu, opened when hovered.
+    Submenu {
+        /// The label of the submenu.
+        label: String,
+
+        /// The entries of the submenu.
+        entries: Vec<Entry<Message>>,
+    },
+
+    /// A line separating groups of entries.
+    Separator,
+}
+
+impl<Message> Entry<Message> {
+    /// Creates a new item [`Entry`] with the given label, producing the given
+    /// message when clicked.
+    ///
+    /// [`Entry`]: enum.Entry.html
+    pub fn item(label: impl Into<String>, message: Message) -> Self {
+        Entry::Item {
+            label: label.into(),
+            message,
+            shortcut: None,
+            is_enabled: true,
+        }
+    }
+
+    /// Sets the keyboard shortcut shown next to the label of an item
+    /// [`Entry`].
+    ///
+    /// The shortcut is only a label: producing the message when the keys are
+    /// pressed is up to the application. It has no effect on other entries.
+    ///
+    /// [`Entry`]: enum.Entry.html
+    pub fn shortcut(mut self, keys: impl Into<String>) -> Self {
+        if let Entry::Item { shortcut, .. } = &mut self {
+            *shortcut = Some(keys.into());
+        }
+
+        self
+    }
+
+    /// Disables an item [`Entry`], which is then shown but cannot be clicked.
+    ///
+    /// It has no effect on other entries.
+    ///
+    /// [`Entry`]: enum.Entry.html
+    pub fn disabled(mut self) -> Self {
+        if let Entry::Item { is_enabled, .. } = &mut self {
+            *is_enabled = false;
+        }
+
+        self
+    }
+
+    /// Creates a new submenu [`Entry`] with the given label and entries.
+    ///
+    /// [`Entry`]: enum.Entry.html
+    pub fn submenu(
+        label: impl Into<String>,
+        entries: Vec<Entry<Message>>,
+    ) -> Self {
+        Entry::Submenu {
+            label: label.into(),
+            entries,
+        }
+    }
+
+    fn label(&self) -> Option<&str> {
+        match self {
+            Entry::Item { label, .. } | Entry::Submenu { label, .. } => {
+                Some(label)
+            }
+            Entry::Separator => None,
+        }
+    }
+}
+
+/// The local state of a [`ContextMenu`].
+///
+/// [`ContextMenu`]: struct.ContextMenu.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    position: Option<Point>,
+    open_submenus: Vec<usize>,
+}
+
+impl State {
+    /// Creates a new, closed [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the menu of the [`ContextMenu`] is open or not.
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn is_open(&self) -> bool {
+        self.position.is_some()
+    }
+
+    /// Closes the menu of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn close(&mut self) {
+        self.position = None;
+        self.open_submenus.clear();
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ContextMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) =
+            event
+        {
+            if layout.bounds().contains(cursor_position) {
+                self.state.position = Some(cursor_position);
+                self.state.open_submenus.clear();
+            }
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if let Some(overlay) = self.content.overlay(layout) {
+            return Some(overlay);
+        }
+
+        let State {
+            position,
+            open_submenus,
+        } = &mut *self.state;
+
+        let origin = (*position)?;
+
+        Some(overlay::Element::new(
+            origin,
+            Box::new(Overlay {
+                panels: Panels {
+                    open_submenus,
+                    entries: &self.entries,
+                    padding: self.padding,
+                    text_size: self.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+                    font: self.font,
+                },
+                position,
+                style: &self.style,
+            }),
+        ))
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.content.focus_targets(layout, targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.content
+            .on_focused_event(event, layout, messages, renderer, clipboard)
+    }
+}
+
+/// The open menu of a [`ContextMenu`] and its submenus.
+///
+/// It covers the whole viewport, so clicking outside of the menu closes it
+/// without reaching the widgets below.
+///
+/// [`ContextMenu`]: struct.ContextMenu.html
+struct Overlay<'a, Message, Renderer: self::Renderer> {
+    panels: Panels<'a, Message, Renderer>,
+    position: &'a mut Option<Point>,
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        layout::Node::with_children(
+            bounds,
+            self.panels.layout(renderer, bounds, position),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        match self
+            .panels
+            .on_event(event, layout.children(), cursor_position)
+        {
+            Some(Status::Selected(message)) => {
+                messages.push(message);
+                *self.position = None;
+            }
+            Some(Status::Dismissed) => {
+                *self.position = None;
+            }
+            None => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.panels.draw(
+            renderer,
+            layout.children(),
+            cursor_position,
+            self.style,
+        )
+    }
+}
+
+/// The outcome of an event processed by some [`Panels`].
+///
+/// [`Panels`]: struct.Panels.html
+pub(crate) enum Status<Message> {
+    /// An item was clicked.
+    Selected(Message),
+
+    /// The panels should be closed.
+    Dismissed,
+}
+
+/// The panels of an open menu: its entries and the submenus opened from
+/// them.
+///
+/// It is shared by the widgets showing [`Entry`] values in an overlay.
+///
+/// [`Entry`]: enum.Entry.html
+pub(crate) struct Panels<'a, Message, Renderer: self::Renderer> {
+    pub(crate) open_submenus: &'a mut Vec<usize>,
+    pub(crate) entries: &'a [Entry<Message>],
+    pub(crate) padding: u16,
+    pub(crate) text_size: u16,
+    pub(crate) font: Renderer::Font,
+}
+
+impl<'a, Message, Renderer> Panels<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    /// Returns the entries of every open panel, starting with the root menu.
+    fn panels(&self) -> Vec<&'a [Entry<Message>]> {
+        let mut panels = vec![self.entries];
+
+        for &index in self.open_submenus.iter() {
+            match panels.last().and_then(|entries| entries.get(index)) {
+                Some(Entry::Submenu { entries, .. }) => panels.push(entries),
+                _ => break,
+            }
+        }
+
+        panels
+    }
+
+    fn entry_height(&self, entry: &Entry<Message>) -> f32 {
+        match entry {
+            Entry::Separator => f32::from(self.padding) + 1.0,
+            _ => f32::from(self.text_size + self.padding * 2),
+        }
+    }
+
+    fn panel_size(
+        &self,
+        renderer: &Renderer,
+        entries: &[Entry<Message>],
+    ) -> Size {
+        let measure = |text: &str| {
+            renderer.measure_text(text, self.text_size, self.font).width
+        };
+
+        let label_width = entries
+            .iter()
+            .filter_map(Entry::label)
+            .map(measure)
+            .fold(0.0, f32::max);
+
+        let shortcut_width = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Item {
+                    shortcut: Some(shortcut),
+                    ..
+                } => Some(measure(shortcut) + f32::from(self.text_size)),
+                _ => None,
+            })
+            .fold(0.0, f32::max);
+
+        let has_submenus = entries
+            .iter()
+            .any(|entry| matches!(entry, Entry::Submenu { .. }));
+
+        let arrow_width = if has_submenus {
+            f32::from(self.text_size)
+        } else {
+            0.0
+        };
+
+        Size::new(
+            label_width
+                + shortcut_width
+                + arrow_width
+                + f32::from(self.padding * 2),
+            entries.iter().map(|entry| self.entry_height(entry)).sum(),
+        )
+    }
+
+    /// Lays out the open panels, placing the root menu at the given position.
+    pub(crate) fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> Vec<layout::Node> {
+        let mut nodes: Vec<layout::Node> = Vec::new();
+        let mut parent: Option<Rectangle> = None;
+
+        for (level, entries) in self.panels().into_iter().enumerate() {
+            let size = self.panel_size(renderer, entries);
+
+            let (x, y) = match parent {
+                None => (position.x, position.y),
+                Some(row) => {
+                    let panel = nodes[level - 1].bounds();
+
+                    // Submenus open to the right, unless there is no room
+                    if panel.x + panel.width + size.width <= bounds.width {
+                        (panel.x + panel.width, row.y)
+                    } else {
+                        (panel.x - size.width, row.y)
+                    }
+                }
+            };
+
+            // Keep the panel inside the viewport
+            let x = x.min(bounds.width - size.width).max(0.0);
+            let y = y.min(bounds.height - size.height).max(0.0);
+
+            let mut offset = 0.0;
+
+            let rows = entries
+                .iter()
+                .map(|entry| {
+                    let height = self.entry_height(entry);
+
+                    let mut row =
+                        layout::Node::new(Size::new(size.width, height));
+                    row.move_to(Point::new(0.0, offset));
+
+                    offset += height;
+
+                    row
+                })
+                .collect();
+
+            let mut panel = layout::Node::with_children(size, rows);
+            panel.move_to(Point::new(x, y));
+
+            parent = self.open_submenus.get(level).and_then(|index| {
+                panel.children().get(*index).map(|row| {
+                    let bounds = row.bounds();
+
+                    Rectangle {
+                        x: bounds.x + x,
+                        y: bounds.y + y,
+                        ..bounds
+                    }
+                })
+            });
+
+            nodes.push(panel);
+        }
+
+        nodes
+    }
+
+    /// Processes an event, given the layouts of the open panels.
+    pub(crate) fn on_event<'b>(
+        &mut self,
+        event: Event,
+        layouts: impl Iterator<Item = Layout<'b>>,
+        cursor_position: Point,
+    ) -> Option<Status<Message>> {
+        let panels = self.panels();
+
+        // Deeper panels are drawn on top, so they are checked first
+        let hovered = layouts
+            .zip(panels.iter())
+            .enumerate()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .find(|(_, (panel, _))| panel.bounds().contains(cursor_position))
+            .map(|(level, (panel, entries))| {
+                let entry = panel
+                    .children()
+                    .position(|row| row.bounds().contains(cursor_position))
+                    .and_then(|index| {
+                        entries.get(index).map(|entry| (index, entry))
+                    });
+
+                (level, entry)
+            });
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((level, Some((index, entry)))) = hovered {
+                    self.open_submenus.truncate(level);
+
+                    if let Entry::Submenu { .. } = entry {
+                        self.open_submenus.push(index);
+                    }
+                }
+
+                None
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => match hovered {
+                Some((
+                    _,
+                    Some((
+                        _,
+                        Entry::Item {
+                            message,
+                            is_enabled,
+                            ..
+                        },
+                    )),
+                )) => {
+                    if *is_enabled {
+                        Some(Status::Selected(message.clone()))
+                    } else {
+                        None
+                    }
+                }
+                Some((level, Some((index, Entry::Submenu { .. })))) => {
+                    self.open_submenus.truncate(level);
+                    self.open_submenus.push(index);
+
+                    None
+                }
+                Some(_) => None,
+                None => Some(Status::Dismissed),
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => Some(Status::Dismissed),
+            _ => None,
+        }
+    }
+
+    /// Draws the open panels, given their layouts.
+    pub(crate) fn draw<'b>(
+        &self,
+        renderer: &mut Renderer,
+        layouts: impl Iterator<Item = Layout<'b>>,
+        cursor_position: Point,
+        style: &<Renderer as self::Renderer>::Style,
+    ) -> Renderer::Output {
+        let panels: Vec<_> = layouts
+            .zip(self.panels())
+            .enumerate()
+            .map(|(level, (panel, entries))| {
+                let open_submenu = self.open_submenus.get(level);
+
+                Panel {
+                    bounds: panel.bounds(),
+                    rows: panel
+                        .children()
+                        .zip(entries)
+                        .enumerate()
+                        .map(|(index, (row, entry))| {
+                            let bounds = row.bounds();
+
+                            match entry {
+                                Entry::Item {
+                                    label,
+                                    shortcut,
+                                    is_enabled,
+                                    ..
+                                } => Row::Item {
+                                    bounds,
+                                    label,
+                                    shortcut: shortcut.as_deref(),
+                                    is_enabled: *is_enabled,
+                                    is_highlighted: *is_enabled
+                                        && bounds.contains(cursor_position),
+                                },
+                                Entry::Submenu { label, .. } => Row::Submenu {
+                                    bounds,
+                                    label,
+                                    is_highlighted: open_submenu
+                                        == Some(&index),
+                                },
+                                Entry::Separator => Row::Separator { bounds },
+                            }
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            &panels,
+            cursor_position,
+            self.padding,
+            self.text_size,
+            self.font,
+            style,
+        )
+    }
+}
+
+/// A menu or submenu of an open [`ContextMenu`], ready to be drawn.
+///
+/// [`ContextMenu`]: struct.ContextMenu.html
+#[derive(Debug, Clone)]
+pub struct Panel<'a> {
+    /// The bounds of the panel.
+    pub bounds: Rectangle,
+
+    /// The rows of the panel, one per [`Entry`].
+    ///
+    /// [`Entry`]: enum.Entry.html
+    pub rows: Vec<Row<'a>>,
+}
+
+/// A row of a [`Panel`], ready to be drawn.
+///
+/// [`Panel`]: struct.Panel.html
+#[derive(Debug, Clone, Copy)]
+pub enum Row<'a> {
+    /// An item, highlighted when hovered and enabled.
+    Item {
+        /// The bounds of the row.
+        bounds: Rectangle,
+
+        /// The label of the item.
+        label: &'a str,
+
+        /// The keyboard shortcut of the item, if any.
+        shortcut: Option<&'a str>,
+
+        /// Whether the item can be clicked or not.
+        is_enabled: bool,
+
+        /// Whether the item is highlighted or not.
+        is_highlighted: bool,
+    },
+
+    /// A submenu, highlighted while it is open.
+    Submenu {
+        /// The bounds of the row.
+        bounds: Rectangle,
+
+        /// The label of the submenu.
+        label: &'a str,
+
+        /// Whether the submenu is highlighted or not.
+        is_highlighted: bool,
+    },
+
+    /// A separator.
+    Separator {
+        /// The bounds of the row.
+        bounds: Rectangle,
+    },
+}
+
+/// The renderer of a [`ContextMenu`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`ContextMenu`] in your user interface.
+///
+/// [`ContextMenu`]: struct.ContextMenu.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The default padding of the entries of a [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    const DEFAULT_PADDING: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the open panels of a [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    fn draw(
+        &mut self,
+        panels: &[Panel<'_>],
+        cursor_position: Point,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ContextMenu<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        context_menu: ContextMenu<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(context_menu)
+    }
+}
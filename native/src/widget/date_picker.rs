@@ -0,0 +1,992 @@
+//! Let your users pick a date from a calendar.
+//!
+//! A [`DatePicker`] has some local [`State`].
+//!
+//! [`DatePicker`]: struct.DatePicker.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse,
+    overlay::{self, popup},
+    text, Clipboard, Element, Event, Hasher, Id, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use std::fmt;
+use std::hash::Hash;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A field showing a date that opens a calendar when clicked.
+///
+/// The calendar can be navigated by month and by year, and dates outside of
+/// the [`min`] and [`max`] bounds cannot be picked.
+///
+/// # Example
+/// ```
+/// # use iced_native::{date_picker, renderer::Null};
+/// #
+/// # pub type DatePicker<'a, Message> =
+/// #     iced_native::DatePicker<'a, Message, Null>;
+/// use date_picker::{Date, Weekday};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DatePicked(Date),
+/// }
+///
+/// let mut state = date_picker::State::new();
+///
+/// let date_picker = DatePicker::new(
+///     &mut state,
+///     Some(Date::new(2020, 7, 15)),
+///     Message::DatePicked,
+/// )
+/// .first_weekday(Weekday::Sunday)
+/// .min(Date::new(2020, 1, 1));
+/// ```
+///
+/// [`min`]: #method.min
+/// [`max`]: #method.max
+/// [`DatePicker`]: struct.DatePicker.html
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    selected: Option<Date>,
+    on_select: Box<dyn Fn(Date) -> Message>,
+    placeholder: String,
+    first_weekday: Weekday,
+    min: Option<Date>,
+    max: Option<Date>,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    tab_index: Option<u16>,
+    id: Option<Id>,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> DatePicker<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`DatePicker`] with the given [`State`], the selected
+    /// [`Date`], and the message to produce when a [`Date`] is picked.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    /// [`State`]: struct.State.html
+    /// [`Date`]: struct.Date.html
+    pub fn new(
+        state: &'a mut State,
+        selected: Option<Date>,
+        on_select: impl Fn(Date) -> Message + 'static,
+    ) -> Self {
+        DatePicker {
+            state,
+            selected,
+            on_select: Box::new(on_select),
+            placeholder: String::new(),
+            first_weekday: Weekday::Monday,
+            min: None,
+            max: None,
+            width: Length::Shrink,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            tab_index: None,
+            id: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the text shown by the [`DatePicker`] when no date is selected.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the [`Weekday`] shown in the first column of the calendar.
+    ///
+    /// By default, weeks start on Monday.
+    ///
+    /// [`Weekday`]: enum.Weekday.html
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Sets the earliest [`Date`] that can be picked.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn min(mut self, date: Date) -> Self {
+        self.min = Some(date);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be picked.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn max(mut self, date: Date) -> Self {
+        self.max = Some(date);
+        self
+    }
+
+    /// Sets the width of the [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`DatePicker`] and the cells of its calendar.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the position of the [`DatePicker`] in the focus order.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`DatePicker`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn toggle(&mut self) {
+        if self.state.is_open {
+            self.state.is_open = false;
+        } else {
+            let date = self.selected.unwrap_or_else(Date::today);
+            let date = match (self.min, self.max) {
+                (Some(min), _) if date < min => min,
+                (_, Some(max)) if date > max => max,
+                _ => date,
+            };
+
+            self.state.is_open = true;
+            self.state.month = Some((date.year, date.month));
+        }
+    }
+}
+
+/// A day in the proleptic Gregorian calendar.
+///
+/// Dates are ordered chronologically and displayed as `YYYY-MM-DD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a new [`Date`].
+    ///
+    /// The month is clamped between 1 and 12, and the day between 1 and the
+    /// amount of days of the month.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        let month = match month {
+            0 => 1,
+            1..=12 => month,
+            _ => 12,
+        };
+        let day = day.max(1).min(days_in_month(year, month));
+
+        Date { year, month, day }
+    }
+
+    /// Returns the current [`Date`] in UTC.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() / 86_400)
+            .unwrap_or(0);
+
+        // Converts days since 1970-01-01 to a civil date, using the algorithm
+        // described in http://howardhinnant.github.io/date_algorithms.html
+        let z = days as i64 + 719_468;
+        let era = z / 146_097;
+        let day_of_era = z - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1_460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Date::new(year as i32, month as u32, day as u32)
+    }
+
+    /// Returns the year of the [`Date`].
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], starting at 1.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at 1.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the [`Weekday`] of the [`Date`].
+    ///
+    /// ```
+    /// use iced_native::date_picker::{Date, Weekday};
+    ///
+    /// assert_eq!(Date::new(2020, 7, 15).weekday(), Weekday::Wednesday);
+    /// assert_eq!(Date::new(2000, 2, 29).weekday(), Weekday::Tuesday);
+    /// ```
+    ///
+    /// [`Weekday`]: enum.Weekday.html
+    /// [`Date`]: struct.Date.html
+    pub fn weekday(&self) -> Weekday {
+        const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+
+        let year = if self.month < 3 {
+            self.year - 1
+        } else {
+            self.year
+        };
+
+        let days_from_sunday = (year + year.div_euclid(4)
+            - year.div_euclid(100)
+            + year.div_euclid(400)
+            + OFFSETS[self.month as usize - 1]
+            + self.day as i32)
+            .rem_euclid(7);
+
+        Weekday::ALL[(days_from_sunday as usize + 6) % 7]
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// All the days of the week, starting on Monday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Returns the abbreviated name of the [`Weekday`].
+    ///
+    /// [`Weekday`]: enum.Weekday.html
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Mo",
+            Weekday::Tuesday => "Tu",
+            Weekday::Wednesday => "We",
+            Weekday::Thursday => "Th",
+            Weekday::Friday => "Fr",
+            Weekday::Saturday => "Sa",
+            Weekday::Sunday => "Su",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A month of a specific year, as `(year, month)`.
+type Month = (i32, u32);
+
+fn previous_month((year, month): Month) -> Month {
+    if month == 1 {
+        (year - 1, 12)
+    } else {
+        (year, month - 1)
+    }
+}
+
+fn next_month((year, month): Month) -> Month {
+    if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    }
+}
+
+/// The local state of a [`DatePicker`].
+///
+/// [`DatePicker`]: struct.DatePicker.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_open: bool,
+    is_focused: bool,
+    month: Option<Month>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing a closed [`DatePicker`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the calendar of the [`DatePicker`] is open or not.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DatePicker<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let text_size = self.text_size.unwrap_or(Renderer::DEFAULT_SIZE);
+
+        let limits =
+            limits.width(self.width).height(Length::Shrink).pad(padding);
+
+        let (width, _) = renderer.measure(
+            "0000-00-00",
+            text_size,
+            self.font,
+            Size::INFINITY,
+        );
+
+        let (placeholder_width, _) = renderer.measure(
+            &self.placeholder,
+            text_size,
+            self.font,
+            Size::INFINITY,
+        );
+
+        let intrinsic =
+            Size::new(width.max(placeholder_width), f32::from(text_size));
+
+        layout::Node::new(limits.resolve(intrinsic).pad(padding))
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.placeholder.hash(state);
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if layout.bounds().contains(cursor_position) {
+                self.toggle();
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            self.selected.as_ref().map(ToString::to_string),
+            &self.placeholder,
+            self.padding,
+            self.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        targets.push(focus::Target::new(
+            self.state,
+            layout.bounds(),
+            self.tab_index,
+            self.id.clone(),
+        ));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.state.is_focused {
+            return false;
+        }
+
+        match event {
+            keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                ..
+            }
+            | keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            } => {
+                self.toggle();
+
+                true
+            }
+            keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            } if self.state.is_open => {
+                self.state.is_open = false;
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(
+            Point::new(bounds.x, bounds.y),
+            Box::new(Calendar {
+                state: self.state,
+                target: bounds,
+                selected: self.selected,
+                on_select: &self.on_select,
+                first_weekday: self.first_weekday,
+                min: self.min,
+                max: self.max,
+                padding: self.padding,
+                text_size: self.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+                font: self.font,
+                style: &self.style,
+            }),
+        ))
+    }
+}
+
+/// The calendar of a [`DatePicker`], shown as an overlay.
+///
+/// [`DatePicker`]: struct.DatePicker.html
+struct Calendar<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    target: Rectangle,
+    selected: Option<Date>,
+    on_select: &'a dyn Fn(Date) -> Message,
+    first_weekday: Weekday,
+    min: Option<Date>,
+    max: Option<Date>,
+    padding: u16,
+    text_size: u16,
+    font: Renderer::Font,
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+/// Produces the month shown after navigating from another.
+type Navigate = fn(Month) -> Month;
+
+/// The navigation buttons of a [`Calendar`], in the order they are shown.
+const NAVIGATION: [(&str, Navigate); 4] = [
+    ("\u{ab}", |(year, month)| (year - 1, month)),
+    ("\u{2039}", previous_month),
+    ("\u{203a}", next_month),
+    ("\u{bb}", |(year, month)| (year + 1, month)),
+];
+
+impl<'a, Message, Renderer> Calendar<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn month(&self) -> Month {
+        self.state
+            .month
+            .unwrap_or_else(|| (Date::today().year, Date::today().month))
+    }
+
+    fn is_enabled(&self, date: Date) -> bool {
+        self.min.map(|min| date >= min).unwrap_or(true)
+            && self.max.map(|max| date <= max).unwrap_or(true)
+    }
+
+    /// Moves the given month inside the bounds of the [`Calendar`], or returns
+    /// `None` if none of its days can be picked.
+    fn clamp(&self, month: Month) -> Option<Month> {
+        let min = self.min.map(|min| (min.year, min.month));
+        let max = self.max.map(|max| (max.year, max.month));
+
+        match (min, max) {
+            (Some(min), _) if month < min => {
+                if month.0 == min.0 {
+                    Some(min)
+                } else {
+                    None
+                }
+            }
+            (_, Some(max)) if month > max => {
+                if month.0 == max.0 {
+                    Some(max)
+                } else {
+                    None
+                }
+            }
+            _ => Some(month),
+        }
+    }
+
+    /// Returns the month shown after pressing the navigation button at the
+    /// given index, if enabled.
+    fn navigate(&self, index: usize) -> Option<Month> {
+        let (_, navigate) = NAVIGATION[index];
+
+        self.clamp(navigate(self.month()))
+    }
+
+    /// Returns the dates shown in the grid of the [`Calendar`], starting at
+    /// the first weekday on or before the first day of the month.
+    fn dates(&self) -> Vec<Date> {
+        let (year, month) = self.month();
+        let first = Date::new(year, month, 1);
+
+        let offset =
+            (first.weekday().index() + 7 - self.first_weekday.index()) % 7;
+
+        let previous = previous_month((year, month));
+        let previous_length = days_in_month(previous.0, previous.1);
+        let length = days_in_month(year, month);
+        let next = next_month((year, month));
+
+        (0..42)
+            .map(|index: i32| {
+                let day = index - offset as i32 + 1;
+
+                if day < 1 {
+                    Date::new(
+                        previous.0,
+                        previous.1,
+                        (previous_length as i32 + day) as u32,
+                    )
+                } else if day as u32 > length {
+                    Date::new(next.0, next.1, day as u32 - length)
+                } else {
+                    Date::new(year, month, day as u32)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the bounds of the cell at the given column and row of a calendar.
+fn cell(bounds: Rectangle, column: usize, row: usize) -> Rectangle {
+    let width = bounds.width / 7.0;
+    let height = bounds.height / 8.0;
+
+    Rectangle {
+        x: bounds.x + column as f32 * width,
+        y: bounds.y + row as f32 * height,
+        width,
+        height,
+    }
+}
+
+/// Returns the column of each navigation button of a calendar.
+const NAVIGATION_COLUMNS: [usize; 4] = [0, 1, 5, 6];
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Calendar<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let cell = f32::from(self.text_size + self.padding * 2);
+        let size = Size::new(cell * 7.0, cell * 8.0);
+
+        popup::layout(size, self.target, bounds, position)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        if popup::is_dismissed(&event, bounds, self.target, cursor_position) {
+            self.state.is_open = false;
+            return;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if !bounds.contains(cursor_position) {
+                return;
+            }
+
+            for (index, column) in NAVIGATION_COLUMNS.iter().enumerate() {
+                if cell(bounds, *column, 0).contains(cursor_position) {
+                    if let Some(month) = self.navigate(index) {
+                        self.state.month = Some(month);
+                    }
+
+                    return;
+                }
+            }
+
+            for (index, date) in self.dates().into_iter().enumerate() {
+                if cell(bounds, index % 7, index / 7 + 2)
+                    .contains(cursor_position)
+                {
+                    if self.is_enabled(date) {
+                        messages.push((self.on_select)(date));
+                        self.state.is_open = false;
+                    }
+
+                    return;
+                }
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let (year, month) = self.month();
+
+        let title = Cell {
+            bounds: Rectangle {
+                width: cell(bounds, 0, 0).width * 3.0,
+                ..cell(bounds, 2, 0)
+            },
+            label: format!("{} {}", MONTHS[month as usize - 1], year),
+            is_enabled: true,
+            is_selected: false,
+            is_outside: false,
+        };
+
+        let navigation: Vec<Cell> = NAVIGATION
+            .iter()
+            .zip(NAVIGATION_COLUMNS.iter())
+            .enumerate()
+            .map(|(index, ((label, _), column))| Cell {
+                bounds: cell(bounds, *column, 0),
+                label: String::from(*label),
+                is_enabled: self.navigate(index).is_some(),
+                is_selected: false,
+                is_outside: false,
+            })
+            .collect();
+
+        let weekdays: Vec<Cell> = (0..7)
+            .map(|column| {
+                let weekday =
+                    Weekday::ALL[(self.first_weekday.index() + column) % 7];
+
+                Cell {
+                    bounds: cell(bounds, column, 1),
+                    label: String::from(weekday.abbreviation()),
+                    is_enabled: true,
+                    is_selected: false,
+                    is_outside: false,
+                }
+            })
+            .collect();
+
+        let days: Vec<Cell> = self
+            .dates()
+            .into_iter()
+            .enumerate()
+            .map(|(index, date)| Cell {
+                bounds: cell(bounds, index % 7, index / 7 + 2),
+                label: date.day.to_string(),
+                is_enabled: self.is_enabled(date),
+                is_selected: Some(date) == self.selected,
+                is_outside: date.month != month,
+            })
+            .collect();
+
+        self::Renderer::draw_calendar(
+            renderer,
+            bounds,
+            cursor_position,
+            &title,
+            &navigation,
+            &weekdays,
+            &days,
+            self.text_size,
+            self.font,
+            self.style,
+        )
+    }
+}
+
+/// A cell of the calendar of a [`DatePicker`].
+///
+/// [`DatePicker`]: struct.DatePicker.html
+#[derive(Debug, Clone)]
+pub struct Cell {
+    /// The bounds of the cell.
+    pub bounds: Rectangle,
+
+    /// The text of the cell.
+    pub label: String,
+
+    /// Whether the cell can be interacted with.
+    pub is_enabled: bool,
+
+    /// Whether the cell is the selected date.
+    pub is_selected: bool,
+
+    /// Whether the cell is a day of an adjacent month.
+    pub is_outside: bool,
+}
+
+/// The renderer of a [`DatePicker`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`DatePicker`] in your user interface.
+///
+/// [`DatePicker`]: struct.DatePicker.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The default padding of a [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    const DEFAULT_PADDING: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the field of a [`DatePicker`].
+    ///
+    /// It receives:
+    /// - the bounds of the field and the cursor position
+    /// - the selected date, formatted, or the placeholder
+    /// - its padding, text size, and font
+    /// - the style of the [`DatePicker`]
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        selected: Option<String>,
+        placeholder: &str,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+
+    /// Draws the calendar of a [`DatePicker`].
+    ///
+    /// It receives:
+    /// - the bounds of the calendar and the cursor position
+    /// - the title of the shown month
+    /// - the navigation buttons, the weekday names, and the days of the grid
+    /// - the text size and font
+    /// - the style of the [`DatePicker`]
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    fn draw_calendar(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        title: &Cell,
+        navigation: &[Cell],
+        weekdays: &[Cell],
+        days: &[Cell],
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<DatePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        date_picker: DatePicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(date_picker)
+    }
+}
@@ -0,0 +1,340 @@
+//! Drag elements around the user interface and drop them into a
+//! [`DropTarget`].
+//!
+//! A [`Draggable`] has some local [`State`].
+//!
+//! [`Draggable`]: struct.Draggable.html
+//! [`DropTarget`]: ../drop_target/struct.DropTarget.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+
+use crate::{
+    keyboard, layout, mouse, overlay, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Size, Vector, Widget,
+};
+
+/// A container that can be dragged around, carrying a payload.
+///
+/// A drag starts when the content is pressed and the cursor moves past a
+/// small threshold. While dragging, a ghost of the content follows the
+/// cursor. The drag ends when the mouse button is released or `Escape` is
+/// pressed.
+///
+/// The payload is handed to the application when the drag starts. Then, it
+/// can be given to every [`DropTarget`] accepting it.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{draggable, renderer::Null, Text};
+/// #
+/// # pub type Draggable<'a, Payload, Message> =
+/// #     iced_native::Draggable<'a, Payload, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CardPicked(usize),
+///     CardReleased,
+/// }
+///
+/// let mut state = draggable::State::new();
+///
+/// let card = Draggable::new(&mut state, Text::new("Write docs"), 42)
+///     .on_drag(Message::CardPicked)
+///     .on_release(Message::CardReleased);
+/// ```
+///
+/// [`DropTarget`]: ../drop_target/struct.DropTarget.html
+#[allow(missing_debug_implementations)]
+pub struct Draggable<'a, Payload, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    ghost: Option<Element<'a, Message, Renderer>>,
+    payload: Payload,
+    on_drag: Option<Box<dyn Fn(Payload) -> Message + 'a>>,
+    on_release: Option<Message>,
+}
+
+impl<'a, Payload, Message, Renderer> Draggable<'a, Payload, Message, Renderer> {
+    /// The distance the cursor needs to move, while pressed, to start a drag.
+    pub const THRESHOLD: f32 = 4.0;
+
+    /// Creates a new [`Draggable`] with some local [`State`], the given
+    /// content, and the payload it carries.
+    ///
+    /// [`Draggable`]: struct.Draggable.html
+    /// [`State`]: struct.State.html
+    pub fn new<T>(state: &'a mut State, content: T, payload: Payload) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Draggable {
+            state,
+            content: content.into(),
+            ghost: None,
+            payload,
+            on_drag: None,
+            on_release: None,
+        }
+    }
+
+    /// Sets the element that follows the cursor while dragging the
+    /// [`Draggable`].
+    ///
+    /// By default, the content of the [`Draggable`] is used.
+    ///
+    /// [`Draggable`]: struct.Draggable.html
+    pub fn ghost<T>(mut self, ghost: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        self.ghost = Some(ghost.into());
+        self
+    }
+
+    /// Sets the message that will be produced with the payload when a drag
+    /// starts.
+    pub fn on_drag<F>(mut self, on_drag: F) -> Self
+    where
+        F: 'a + Fn(Payload) -> Message,
+    {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    /// Sets the message that will be produced when a drag ends, either by
+    /// dropping the payload or by cancelling the drag.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+}
+
+/// The local state of a [`Draggable`].
+///
+/// [`Draggable`]: struct.Draggable.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    pressed_at: Option<Point>,
+    grab: Option<Vector>,
+    cursor_position: Point,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`Draggable`] is currently being dragged.
+    ///
+    /// [`Draggable`]: struct.Draggable.html
+    pub fn is_dragging(&self) -> bool {
+        self.grab.is_some()
+    }
+}
+
+impl<'a, Payload, Message, Renderer> Widget<Message, Renderer>
+    for Draggable<'a, Payload, Message, Renderer>
+where
+    Payload: Clone,
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if bounds.contains(cursor_position) =>
+            {
+                self.state.pressed_at = Some(cursor_position);
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                self.state.cursor_position = cursor_position;
+
+                match self.state.pressed_at {
+                    Some(pressed_at)
+                        if !self.state.is_dragging()
+                            && pressed_at.distance(cursor_position)
+                                > Self::THRESHOLD =>
+                    {
+                        self.state.grab =
+                            Some(pressed_at - Point::new(bounds.x, bounds.y));
+
+                        if let Some(on_drag) = &self.on_drag {
+                            messages.push(on_drag(self.payload.clone()));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                let was_dragging = self.state.is_dragging();
+
+                self.state.pressed_at = None;
+                self.state.grab = None;
+
+                if was_dragging {
+                    if let Some(on_release) = &self.on_release {
+                        messages.push(on_release.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let grab = match self.state.grab {
+            Some(grab) => grab,
+            None => return self.content.overlay(layout),
+        };
+
+        Some(overlay::Element::new(
+            self.state.cursor_position - grab,
+            Box::new(Ghost {
+                content: self.ghost.as_ref().unwrap_or(&self.content),
+                size: layout.bounds().size(),
+            }),
+        ))
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<crate::focus::Target<'b>>,
+    ) {
+        self.content.focus_targets(layout, targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.content
+            .on_focused_event(event, layout, messages, renderer, clipboard)
+    }
+}
+
+/// The ghost of a [`Draggable`] following the cursor.
+///
+/// [`Draggable`]: struct.Draggable.html
+struct Ghost<'a, 'b, Message, Renderer> {
+    content: &'b Element<'a, Message, Renderer>,
+    size: Size,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Ghost<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        _bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, self.size);
+
+        let mut node = self.content.layout(renderer, &limits);
+        node.move_to(position);
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _cursor_position: Point) -> bool {
+        // The ghost lets the cursor reach the drop targets below it
+        false
+    }
+}
+
+impl<'a, Payload, Message, Renderer>
+    From<Draggable<'a, Payload, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Payload: 'a + Clone,
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        draggable: Draggable<'a, Payload, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(draggable)
+    }
+}
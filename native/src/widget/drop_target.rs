@@ -0,0 +1,332 @@
+//! Receive the payloads of a [`Draggable`].
+//!
+//! A [`DropTarget`] has some local [`State`].
+//!
+//! [`Draggable`]: ../draggable/struct.Draggable.html
+//! [`DropTarget`]: struct.DropTarget.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+
+use crate::{
+    keyboard, layout, mouse, overlay, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Widget,
+};
+
+/// A container that receives the payloads dragged over it.
+///
+/// A [`DropTarget`] is given the payload being dragged, if any. It is
+/// typically the payload produced by the `on_drag` message of a
+/// [`Draggable`] and stored in the state of the application. Only payloads of
+/// the type of the [`DropTarget`] can be dropped into it.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{drop_target, renderer::Null, Text};
+/// #
+/// # pub type DropTarget<'a, Payload, Message> =
+/// #     iced_native::DropTarget<'a, Payload, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CardEntered(usize),
+///     CardLeft,
+///     CardDropped(usize),
+/// }
+///
+/// let mut state = drop_target::State::new();
+/// let dragged_card: Option<usize> = Some(42);
+///
+/// let column = DropTarget::new(&mut state, Text::new("Done"), dragged_card)
+///     .on_enter(Message::CardEntered)
+///     .on_leave(Message::CardLeft)
+///     .on_drop(Message::CardDropped);
+/// ```
+///
+/// [`DropTarget`]: struct.DropTarget.html
+/// [`Draggable`]: ../draggable/struct.Draggable.html
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<'a, Payload, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    payload: Option<Payload>,
+    on_enter: Option<Box<dyn Fn(Payload) -> Message + 'a>>,
+    on_leave: Option<Message>,
+    on_drop: Option<Box<dyn Fn(Payload) -> Message + 'a>>,
+}
+
+impl<'a, Payload, Message, Renderer>
+    DropTarget<'a, Payload, Message, Renderer>
+{
+    /// Creates a new [`DropTarget`] with some local [`State`], the given
+    /// content, and the payload currently being dragged, if any.
+    ///
+    /// [`DropTarget`]: struct.DropTarget.html
+    /// [`State`]: struct.State.html
+    pub fn new<T>(
+        state: &'a mut State,
+        content: T,
+        payload: Option<Payload>,
+    ) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        DropTarget {
+            state,
+            content: content.into(),
+            payload,
+            on_enter: None,
+            on_leave: None,
+            on_drop: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the dragged payload enters
+    /// the [`DropTarget`].
+    ///
+    /// [`DropTarget`]: struct.DropTarget.html
+    pub fn on_enter<F>(mut self, on_enter: F) -> Self
+    where
+        F: 'a + Fn(Payload) -> Message,
+    {
+        self.on_enter = Some(Box::new(on_enter));
+        self
+    }
+
+    /// Sets the message that will be produced when the dragged payload leaves
+    /// the [`DropTarget`] without being dropped.
+    ///
+    /// [`DropTarget`]: struct.DropTarget.html
+    pub fn on_leave(mut self, on_leave: Message) -> Self {
+        self.on_leave = Some(on_leave);
+        self
+    }
+
+    /// Sets the message that will be produced when the dragged payload is
+    /// dropped into the [`DropTarget`].
+    ///
+    /// [`DropTarget`]: struct.DropTarget.html
+    pub fn on_drop<F>(mut self, on_drop: F) -> Self
+    where
+        F: 'a + Fn(Payload) -> Message,
+    {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+}
+
+/// The local state of a [`DropTarget`].
+///
+/// [`DropTarget`]: struct.DropTarget.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_hovered: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether a payload is being dragged over the [`DropTarget`].
+    ///
+    /// [`DropTarget`]: struct.DropTarget.html
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+}
+
+impl<'a, Payload, Message, Renderer> Widget<Message, Renderer>
+    for DropTarget<'a, Payload, Message, Renderer>
+where
+    Payload: Clone,
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        match &self.payload {
+            Some(payload) => {
+                let is_mouse_over = layout.bounds().contains(cursor_position);
+
+                match event {
+                    Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                        if is_mouse_over && !self.state.is_hovered {
+                            if let Some(on_enter) = &self.on_enter {
+                                messages.push(on_enter(payload.clone()));
+                            }
+                        } else if !is_mouse_over && self.state.is_hovered {
+                            if let Some(on_leave) = &self.on_leave {
+                                messages.push(on_leave.clone());
+                            }
+                        }
+
+                        self.state.is_hovered = is_mouse_over;
+                    }
+                    Event::Mouse(mouse::Event::ButtonReleased(
+                        mouse::Button::Left,
+                    )) => {
+                        if is_mouse_over {
+                            if let Some(on_drop) = &self.on_drop {
+                                messages.push(on_drop(payload.clone()));
+                            }
+                        }
+
+                        self.state.is_hovered = false;
+                    }
+                    _ => {}
+                }
+            }
+            None => {
+                self.state.is_hovered = false;
+            }
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout)
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<crate::focus::Target<'b>>,
+    ) {
+        self.content.focus_targets(layout, targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.content
+            .on_focused_event(event, layout, messages, renderer, clipboard)
+    }
+}
+
+impl<'a, Payload, Message, Renderer>
+    From<DropTarget<'a, Payload, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Payload: 'a + Clone,
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        drop_target: DropTarget<'a, Payload, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drop_target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Column, Size};
+
+    #[test]
+    fn payloads_enter_and_drop() {
+        let mut state = State::new();
+        let renderer = Null::new();
+        let mut messages = Vec::new();
+
+        let events = [
+            Event::Mouse(mouse::Event::CursorMoved { x: 500.0, y: 500.0 }),
+            Event::Mouse(mouse::Event::CursorMoved { x: 5.0, y: 5.0 }),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ];
+
+        for (event, cursor_position) in events.iter().zip(&[
+            Point::new(500.0, 500.0),
+            Point::new(5.0, 5.0),
+            Point::new(5.0, 5.0),
+        ]) {
+            let mut target = DropTarget::<_, _, Null>::new(
+                &mut state,
+                Column::new().width(Length::Fill).height(Length::Fill),
+                Some(7u8),
+            )
+            .on_enter(|payload| payload + 10)
+            .on_leave(0)
+            .on_drop(|payload| payload + 20);
+
+            let node = Widget::<u8, Null>::layout(
+                &target,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            );
+
+            Widget::<u8, Null>::on_event(
+                &mut target,
+                event.clone(),
+                Layout::new(&node),
+                *cursor_position,
+                &mut messages,
+                &renderer,
+                None,
+            );
+        }
+
+        assert_eq!(messages, vec![17, 27]);
+        assert!(!state.is_hovered());
+    }
+}
@@ -0,0 +1,386 @@
+//! Accept files dropped into an area of the window.
+use std::hash::Hash;
+use std::path::PathBuf;
+
+use crate::{
+    keyboard, layout, overlay, window, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Widget,
+};
+
+/// A container that produces messages when files are hovered over or dropped
+/// into it.
+///
+/// The files are matched against the position of the cursor when they enter
+/// the window, so only the [`FileDrop`] under the cursor is notified.
+///
+/// Most platforms do not report the position of the cursor while files are
+/// being dragged. Therefore, the hovered [`FileDrop`] is chosen with the last
+/// known position of the cursor, and it stays hovered until the files are
+/// dropped or leave the window.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{file_drop, renderer::Null, Text};
+/// # use std::path::PathBuf;
+/// #
+/// # pub type FileDrop<'a, Message> =
+/// #     iced_native::FileDrop<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DocumentHovered(PathBuf),
+///     DocumentLeft,
+///     DocumentDropped(PathBuf),
+/// }
+///
+/// let mut state = file_drop::State::new();
+///
+/// let file_drop = FileDrop::new(&mut state, Text::new("Drop a document here"))
+///     .on_hover(Message::DocumentHovered)
+///     .on_leave(Message::DocumentLeft)
+///     .on_drop(Message::DocumentDropped);
+/// ```
+///
+/// [`FileDrop`]: struct.FileDrop.html
+#[allow(missing_debug_implementations)]
+pub struct FileDrop<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    on_hover: Option<Box<dyn Fn(PathBuf) -> Message + 'a>>,
+    on_leave: Option<Message>,
+    on_drop: Option<Box<dyn Fn(PathBuf) -> Message + 'a>>,
+}
+
+impl<'a, Message, Renderer> FileDrop<'a, Message, Renderer> {
+    /// Creates a [`FileDrop`] with the given content.
+    ///
+    /// [`FileDrop`]: struct.FileDrop.html
+    pub fn new<T>(state: &'a mut State, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        FileDrop {
+            state,
+            content: content.into(),
+            on_hover: None,
+            on_leave: None,
+            on_drop: None,
+        }
+    }
+
+    /// Sets the message that will be produced for every file hovered over the
+    /// [`FileDrop`].
+    ///
+    /// [`FileDrop`]: struct.FileDrop.html
+    pub fn on_hover<F>(mut self, on_hover: F) -> Self
+    where
+        F: 'a + Fn(PathBuf) -> Message,
+    {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    /// Sets the message that will be produced when the files hovering the
+    /// [`FileDrop`] leave the window or are dropped.
+    ///
+    /// It is produced once, even if multiple files were hovered.
+    ///
+    /// [`FileDrop`]: struct.FileDrop.html
+    pub fn on_leave(mut self, on_leave: Message) -> Self {
+        self.on_leave = Some(on_leave);
+        self
+    }
+
+    /// Sets the message that will be produced for every file dropped into the
+    /// [`FileDrop`].
+    ///
+    /// [`FileDrop`]: struct.FileDrop.html
+    pub fn on_drop<F>(mut self, on_drop: F) -> Self
+    where
+        F: 'a + Fn(PathBuf) -> Message,
+    {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for FileDrop<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let is_mouse_over = layout.bounds().contains(cursor_position);
+
+        match &event {
+            Event::Window(window::Event::FileHovered(path)) => {
+                // Every file of a new drag is hovered before any is dropped
+                if self.state.is_dropping {
+                    self.state.is_dropping = false;
+                    self.state.is_hovered = false;
+                }
+
+                if is_mouse_over {
+                    self.state.is_hovered = true;
+
+                    if let Some(on_hover) = &self.on_hover {
+                        messages.push(on_hover(path.clone()));
+                    }
+                }
+            }
+            Event::Window(window::Event::FileDropped(path)) => {
+                if self.state.is_hovered && !self.state.is_dropping {
+                    self.state.is_dropping = true;
+
+                    if let Some(on_leave) = &self.on_leave {
+                        messages.push(on_leave.clone());
+                    }
+                }
+
+                // Some platforms drop files without hovering them first
+                if self.state.is_hovered || is_mouse_over {
+                    if let Some(on_drop) = &self.on_drop {
+                        messages.push(on_drop(path.clone()));
+                    }
+                }
+            }
+            Event::Window(window::Event::FilesHoveredLeft) => {
+                if self.state.is_hovered && !self.state.is_dropping {
+                    if let Some(on_leave) = &self.on_leave {
+                        messages.push(on_leave.clone());
+                    }
+                }
+
+                *self.state = State::default();
+            }
+            Event::Mouse(_) if self.state.is_dropping => {
+                *self.state = State::default();
+            }
+            _ => {}
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout)
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<crate::focus::Target<'b>>,
+    ) {
+        self.content.focus_targets(layout, targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.content
+            .on_focused_event(event, layout, messages, renderer, clipboard)
+    }
+}
+
+/// The local state of a [`FileDrop`].
+///
+/// [`FileDrop`]: struct.FileDrop.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_hovered: bool,
+    is_dropping: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether files are currently hovering the [`FileDrop`].
+    ///
+    /// [`FileDrop`]: struct.FileDrop.html
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered && !self.is_dropping
+    }
+}
+
+impl<'a, Message, Renderer> From<FileDrop<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        file_drop: FileDrop<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(file_drop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Column, Size};
+
+    fn run(
+        state: &mut State,
+        events: &[(Event, Point)],
+        messages: &mut Vec<String>,
+    ) {
+        let renderer = Null::new();
+
+        for (event, cursor_position) in events {
+            let mut file_drop = FileDrop::<_, Null>::new(
+                state,
+                Column::new().width(Length::Fill).height(Length::Fill),
+            )
+            .on_hover(|path: PathBuf| format!("hover {}", path.display()))
+            .on_leave(String::from("leave"))
+            .on_drop(|path: PathBuf| format!("drop {}", path.display()));
+
+            let node = Widget::<String, Null>::layout(
+                &file_drop,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            );
+
+            Widget::<String, Null>::on_event(
+                &mut file_drop,
+                event.clone(),
+                Layout::new(&node),
+                *cursor_position,
+                messages,
+                &renderer,
+                None,
+            );
+        }
+    }
+
+    #[test]
+    fn drops_every_file_and_leaves_once() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        let inside = Point::new(5.0, 5.0);
+
+        run(
+            &mut state,
+            &[
+                (
+                    Event::Window(window::Event::FileHovered("a".into())),
+                    inside,
+                ),
+                (
+                    Event::Window(window::Event::FileHovered("b".into())),
+                    inside,
+                ),
+                (
+                    Event::Window(window::Event::FileDropped("a".into())),
+                    inside,
+                ),
+                (
+                    Event::Window(window::Event::FileDropped("b".into())),
+                    inside,
+                ),
+            ],
+            &mut messages,
+        );
+
+        assert_eq!(
+            messages,
+            vec!["hover a", "hover b", "leave", "drop a", "drop b"]
+        );
+        assert!(!state.is_hovered());
+    }
+
+    #[test]
+    fn ignores_files_hovering_elsewhere() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        let outside = Point::new(500.0, 500.0);
+
+        run(
+            &mut state,
+            &[
+                (
+                    Event::Window(window::Event::FileHovered("a".into())),
+                    outside,
+                ),
+                (Event::Window(window::Event::FilesHoveredLeft), outside),
+                (
+                    Event::Window(window::Event::FileHovered("a".into())),
+                    outside,
+                ),
+                (
+                    Event::Window(window::Event::FileDropped("a".into())),
+                    outside,
+                ),
+            ],
+            &mut messages,
+        );
+
+        assert!(messages.is_empty());
+    }
+}
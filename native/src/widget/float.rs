@@ -0,0 +1,345 @@
+//! Float elements over some content, independently of its scrolling.
+use std::hash::Hash;
+
+use crate::stack::Anchor;
+use crate::{
+    focus, keyboard, layout, overlay, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Size, Widget,
+};
+
+/// A container that floats some elements over its content.
+///
+/// Floating elements are positioned with an [`Anchor`] relative to the bounds
+/// of the [`Float`], and take no part in the layout of its content. Wrapping
+/// a `Scrollable` keeps them in place while the content scrolls, and wrapping
+/// the whole view of an application anchors them to the window.
+///
+/// Floating elements are drawn over the content and receive events before
+/// it.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, stack::Anchor, Text};
+/// #
+/// # pub type Float<'a, Message> = iced_native::Float<'a, Message, Null>;
+/// let float = Float::<()>::new(Text::new("A long list of items..."))
+///     .padding(16)
+///     .push(Text::new("+"), Anchor::BOTTOM_RIGHT);
+/// ```
+///
+/// [`Float`]: struct.Float.html
+/// [`Anchor`]: stack/struct.Anchor.html
+#[allow(missing_debug_implementations)]
+pub struct Float<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    padding: u16,
+    anchors: Vec<Anchor>,
+    floating: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Float<'a, Message, Renderer> {
+    /// Creates a [`Float`] with the given content and no floating elements.
+    ///
+    /// [`Float`]: struct.Float.html
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Float {
+            content: content.into(),
+            padding: 0,
+            anchors: Vec::new(),
+            floating: Vec::new(),
+        }
+    }
+
+    /// Sets the space kept between the floating elements and the sides of
+    /// the [`Float`].
+    ///
+    /// [`Float`]: struct.Float.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Floats an [`Element`] at the given [`Anchor`], over the content and
+    /// the previous floating elements.
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Anchor`]: stack/struct.Anchor.html
+    pub fn push<E>(mut self, element: E, anchor: Anchor) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.anchors.push(anchor);
+        self.floating.push(element.into());
+        self
+    }
+
+    /// Returns the cursor position seen by the content and every floating
+    /// element of the [`Float`].
+    ///
+    /// Elements covered by a floating one under the cursor do not see it.
+    ///
+    /// [`Float`]: struct.Float.html
+    fn cursor_positions(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Vec<Point> {
+        let mut is_covered = false;
+
+        let mut positions: Vec<Point> = layout
+            .children()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .map(|layout| {
+                if is_covered {
+                    Point::new(-1.0, -1.0)
+                } else {
+                    is_covered = layout.bounds().contains(cursor_position);
+
+                    cursor_position
+                }
+            })
+            .collect();
+
+        positions.reverse();
+        positions
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Float<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+        let size = content.size();
+
+        let padding = f32::from(self.padding);
+        let area = Size::new(
+            (size.width - padding * 2.0).max(0.0),
+            (size.height - padding * 2.0).max(0.0),
+        );
+
+        let floating_limits = layout::Limits::new(Size::ZERO, area);
+
+        let nodes = std::iter::once(content).chain(
+            self.floating
+                .iter()
+                .zip(&self.anchors)
+                .map(|(element, anchor)| {
+                    let mut node = element.layout(renderer, &floating_limits);
+
+                    node.move_to(Point::new(padding, padding) + anchor.offset);
+                    node.align(anchor.horizontal, anchor.vertical, area);
+
+                    node
+                }),
+        );
+
+        layout::Node::with_children(size, nodes.collect())
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let cursor_positions = self.cursor_positions(layout, cursor_position);
+
+        // Floating elements receive events first
+        std::iter::once(&mut self.content)
+            .chain(self.floating.iter_mut())
+            .zip(layout.children())
+            .zip(cursor_positions)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .for_each(|((element, layout), cursor_position)| {
+                element.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            });
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let cursor_positions = self.cursor_positions(layout, cursor_position);
+
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            &self.content,
+            &self.floating,
+            layout,
+            &cursor_positions,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.padding.hash(state);
+        self.anchors.hash(state);
+        self.content.hash_layout(state);
+
+        for element in &self.floating {
+            element.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        std::iter::once(&mut self.content)
+            .chain(self.floating.iter_mut())
+            .zip(layout.children())
+            .filter_map(|(element, layout)| element.overlay(layout))
+            .next()
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        std::iter::once(&mut self.content)
+            .chain(self.floating.iter_mut())
+            .zip(layout.children())
+            .for_each(|(element, layout)| {
+                element.focus_targets(layout, targets)
+            });
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        std::iter::once(&mut self.content)
+            .chain(self.floating.iter_mut())
+            .zip(layout.children())
+            .any(|(element, layout)| {
+                element.on_focused_event(
+                    event, layout, messages, renderer, clipboard,
+                )
+            })
+    }
+}
+
+/// The renderer of a [`Float`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Float`] in your user interface.
+///
+/// [`Float`]: struct.Float.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Float`].
+    ///
+    /// It receives:
+    /// - the content of the [`Float`]
+    /// - the floating elements, from bottom to top
+    /// - the [`Layout`] of the [`Float`], whose children are the layouts of
+    ///   the content and the floating elements
+    /// - the cursor position seen by the content and every floating element
+    ///
+    /// Floating elements should be drawn over the content, outside of any
+    /// transformation applied to it.
+    ///
+    /// [`Float`]: struct.Float.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &Element<'_, Message, Self>,
+        floating: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_positions: &[Point],
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Float<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        float: Float<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(float)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Row};
+
+    #[test]
+    fn floating_elements_do_not_affect_layout() {
+        let item = |width, height| {
+            Row::<(), Null>::new()
+                .width(Length::Units(width))
+                .height(Length::Units(height))
+        };
+
+        let float = Float::new(item(100, 80))
+            .padding(10)
+            .push(item(20, 20), Anchor::BOTTOM_RIGHT)
+            .push(item(200, 200), Anchor::TOP_LEFT);
+
+        let node = Widget::<(), Null>::layout(
+            &float,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(500.0, 500.0)),
+        );
+
+        let bounds: Vec<_> =
+            node.children().iter().map(layout::Node::bounds).collect();
+
+        assert_eq!(node.size(), Size::new(100.0, 80.0));
+        assert_eq!(
+            Point::new(bounds[1].x, bounds[1].y),
+            Point::new(70.0, 50.0)
+        );
+        assert_eq!(bounds[2].size(), Size::new(80.0, 60.0));
+    }
+}
@@ -0,0 +1,227 @@
+//! Display a value from a range of values on a radial dial.
+use crate::{
+    layout, slider, Element, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::{hash::Hash, ops::RangeInclusive};
+
+/// A radial dial with a needle pointing at a value from a range of values.
+///
+/// A [`Gauge`] can show tick marks and value labels along its dial, using
+/// [`ticks`] and [`labels`]. Its current value is displayed in its center.
+///
+/// [`Gauge`]: struct.Gauge.html
+/// [`ticks`]: #method.ticks
+/// [`labels`]: #method.labels
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Gauge = iced_native::Gauge<Null>;
+/// let rpm = 3200.0;
+///
+/// Gauge::new(0.0..=8000.0, rpm)
+///     .ticks(500.0)
+///     .labels(1000.0)
+///     .size(200);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Gauge<Renderer: self::Renderer> {
+    range: RangeInclusive<f32>,
+    value: f32,
+    ticks: Vec<f32>,
+    labels: Vec<f32>,
+    precision: usize,
+    size: Option<u16>,
+    text_size: Option<u16>,
+    style: Renderer::Style,
+}
+
+impl<Renderer: self::Renderer> Gauge<Renderer> {
+    /// Creates a new [`Gauge`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`Gauge`]
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Gauge {
+            value: value.max(*range.start()).min(*range.end()),
+            range,
+            ticks: Vec::new(),
+            labels: Vec::new(),
+            precision: 0,
+            size: None,
+            text_size: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Shows tick marks along the dial of the [`Gauge`] every `step` units,
+    /// counting from the start of its range.
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    pub fn ticks(mut self, step: f32) -> Self {
+        self.ticks = slider::ticks(&self.range, step);
+        self
+    }
+
+    /// Shows value labels along the dial of the [`Gauge`] every `step`
+    /// units, counting from the start of its range.
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    pub fn labels(mut self, step: f32) -> Self {
+        self.labels = slider::ticks(&self.range, step);
+        self
+    }
+
+    /// Sets the number of decimals of the values displayed by the [`Gauge`].
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the diameter of the [`Gauge`].
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the text size of the value labels of the [`Gauge`].
+    ///
+    /// The current value is displayed at twice this size.
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`Gauge`].
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Gauge<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE))
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE))
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE));
+        let limits = limits.width(size).height(size);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        let labels: Vec<_> = self
+            .labels
+            .iter()
+            .map(|label| (*label, format!("{:.*}", self.precision, label)))
+            .collect();
+
+        renderer.draw(
+            layout.bounds(),
+            self.range.clone(),
+            self.value,
+            &format!("{:.*}", self.precision, self.value),
+            &self.ticks,
+            &labels,
+            self.text_size.unwrap_or(Renderer::DEFAULT_TEXT_SIZE),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of a [`Gauge`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Gauge`] in your user interface.
+///
+/// [`Gauge`]: struct.Gauge.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default diameter of a [`Gauge`].
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    const DEFAULT_SIZE: u16;
+
+    /// The default text size of the value labels of a [`Gauge`].
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    const DEFAULT_TEXT_SIZE: u16;
+
+    /// Draws a [`Gauge`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Gauge`]
+    ///   * the range of values of the [`Gauge`]
+    ///   * the current value of the [`Gauge`], and its label
+    ///   * the values where tick marks should be drawn
+    ///   * the values where labels should be drawn, and their labels
+    ///   * the text size of the labels
+    ///   * the style of the [`Gauge`]
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        range: RangeInclusive<f32>,
+        value: f32,
+        value_label: &str,
+        ticks: &[f32],
+        labels: &[(f32, String)],
+        text_size: u16,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Gauge<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(gauge: Gauge<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(gauge)
+    }
+}
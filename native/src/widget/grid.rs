@@ -0,0 +1,505 @@
+//! Arrange content in rows and columns.
+use std::hash::Hash;
+
+use crate::{
+    focus, keyboard, layout, overlay, Align, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Size, Widget,
+};
+
+/// A container that arranges its contents in a grid.
+///
+/// Every cell of a column shares the same width, and every cell of a row the
+/// same height, even if their contents have different sizes. The contents
+/// are then aligned inside their cell.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, Align, Text};
+/// #
+/// # pub type Grid<'a, Message> = iced_native::Grid<'a, Message, Null>;
+/// let grid = Grid::<()>::new()
+///     .columns(3)
+///     .spacing(10)
+///     .align_x(Align::Center)
+///     .push(Text::new("Name"))
+///     .push(Text::new("Kind"))
+///     .push(Text::new("Size"));
+/// ```
+///
+/// [`Grid`]: struct.Grid.html
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    columns: Columns,
+    column_spacing: u16,
+    row_spacing: u16,
+    padding: u16,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    align_x: Align,
+    align_y: Align,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+/// The way a [`Grid`] decides its amount of columns.
+///
+/// [`Grid`]: struct.Grid.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Columns {
+    Fixed(u16),
+    MinWidth(u16),
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Grid`] with the given elements.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Grid {
+            columns: Columns::Fixed(1),
+            column_spacing: 0,
+            row_spacing: 0,
+            padding: 0,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            align_x: Align::Start,
+            align_y: Align::Start,
+            children,
+        }
+    }
+
+    /// Sets the amount of columns of the [`Grid`].
+    ///
+    /// By default, a [`Grid`] has a single column.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn columns(mut self, columns: u16) -> Self {
+        self.columns = Columns::Fixed(columns.max(1));
+        self
+    }
+
+    /// Makes the [`Grid`] fit as many columns as possible in its width,
+    /// without making them narrower than the given width.
+    ///
+    /// The columns then share the width of the [`Grid`] equally.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn min_column_width(mut self, units: u16) -> Self {
+        self.columns = Columns::MinWidth(units.max(1));
+        self
+    }
+
+    /// Sets both the horizontal and vertical spacing _between_ cells.
+    pub fn spacing(self, units: u16) -> Self {
+        self.column_spacing(units).row_spacing(units)
+    }
+
+    /// Sets the horizontal spacing _between_ columns.
+    pub fn column_spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn row_spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the padding of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    ///
+    /// When it is not [`Length::Shrink`], the columns share the width of the
+    /// [`Grid`] equally. Otherwise, every column is as wide as its widest
+    /// cell.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Length::Shrink`]: ../../enum.Length.html#variant.Shrink
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of every cell of the
+    /// [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn align_x(mut self, align: Align) -> Self {
+        self.align_x = align;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of every cell of the
+    /// [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn align_y(mut self, align: Align) -> Self {
+        self.align_y = align;
+        self
+    }
+
+    /// Adds an [`Element`] to the next cell of the [`Grid`].
+    ///
+    /// Cells are filled row by row.
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Grid`]: struct.Grid.html
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Grid<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let column_spacing = f32::from(self.column_spacing);
+        let row_spacing = f32::from(self.row_spacing);
+
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height)
+            .pad(padding);
+
+        let max = limits.max();
+
+        let columns = match self.columns {
+            Columns::Fixed(columns) => usize::from(columns),
+            Columns::MinWidth(min_width) if max.width.is_finite() => {
+                let columns = (max.width + column_spacing)
+                    / (f32::from(min_width) + column_spacing);
+
+                (columns.floor() as usize).max(1)
+            }
+            Columns::MinWidth(_) => self.children.len().max(1),
+        };
+
+        let rows = self.children.chunks(columns).len();
+
+        let is_uniform = max.width.is_finite()
+            && (self.width != Length::Shrink
+                || matches!(self.columns, Columns::MinWidth(_)));
+
+        // Columns share the available width or wrap their widest cell
+        let widths = if is_uniform {
+            let spacing = column_spacing * (columns - 1) as f32;
+
+            vec![((max.width - spacing) / columns as f32).max(0.0); columns]
+        } else {
+            let mut widths = vec![0.0f32; columns];
+            let cell_limits = layout::Limits::new(Size::ZERO, max);
+
+            for (i, child) in self.children.iter().enumerate() {
+                if child.width().fill_factor() == 0 {
+                    let size = child.layout(renderer, &cell_limits).size();
+
+                    widths[i % columns] = widths[i % columns].max(size.width);
+                }
+            }
+
+            widths
+        };
+
+        // Rows are as tall as their tallest cell
+        let mut heights = vec![0.0f32; rows];
+
+        for (i, child) in self.children.iter().enumerate() {
+            if child.height().fill_factor() == 0 {
+                let cell_limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(widths[i % columns], max.height),
+                );
+
+                let size = child.layout(renderer, &cell_limits).size();
+
+                heights[i / columns] = heights[i / columns].max(size.height);
+            }
+        }
+
+        let mut nodes = Vec::with_capacity(self.children.len());
+        let mut y = padding;
+
+        for (row, height) in heights.iter().enumerate() {
+            let mut x = padding;
+
+            for (column, width) in widths.iter().enumerate() {
+                let child = match self.children.get(row * columns + column) {
+                    Some(child) => child,
+                    None => break,
+                };
+
+                let cell = Size::new(*width, *height);
+
+                let mut node = child
+                    .layout(renderer, &layout::Limits::new(Size::ZERO, cell));
+
+                node.move_to(Point::new(x, y));
+                node.align(self.align_x, self.align_y, cell);
+
+                nodes.push(node);
+
+                x += width + column_spacing;
+            }
+
+            y += height + row_spacing;
+        }
+
+        let width =
+            widths.iter().sum::<f32>() + column_spacing * (columns - 1) as f32;
+
+        let height = heights.iter().sum::<f32>()
+            + row_spacing * rows.saturating_sub(1) as f32;
+
+        let size = limits.resolve(Size::new(width, height));
+
+        layout::Node::with_children(size.pad(padding), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.children.iter_mut().zip(layout.children()).for_each(
+            |(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            },
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(defaults, &self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.columns.hash(state);
+        self.column_spacing.hash(state);
+        self.row_spacing.hash(state);
+        self.padding.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.align_x.hash(state);
+        self.align_y.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .for_each(|(child, layout)| child.focus_targets(layout, targets));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.children.iter_mut().zip(layout.children()).any(
+            |(child, layout)| {
+                child.on_focused_event(
+                    event, layout, messages, renderer, clipboard,
+                )
+            },
+        )
+    }
+}
+
+/// The renderer of a [`Grid`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Grid`] in your user interface.
+///
+/// [`Grid`]: struct.Grid.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Grid`].
+    ///
+    /// It receives:
+    /// - the children of the [`Grid`]
+    /// - the [`Layout`] of the [`Grid`] and its children
+    /// - the cursor position
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        grid: Grid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Rectangle, Row};
+
+    #[test]
+    fn cells_are_aligned_in_rows_and_columns() {
+        let space = |width, height| {
+            Row::<(), Null>::new()
+                .width(Length::Units(width))
+                .height(Length::Units(height))
+        };
+
+        let grid: Grid<'_, (), Null> = Grid::new()
+            .columns(2)
+            .spacing(1)
+            .align_x(Align::End)
+            .push(space(10, 20))
+            .push(space(30, 5))
+            .push(space(5, 5));
+
+        let node = Widget::<(), Null>::layout(
+            &grid,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::INFINITY),
+        );
+
+        let bounds: Vec<Rectangle> =
+            node.children().iter().map(layout::Node::bounds).collect();
+
+        assert_eq!(node.size(), Size::new(41.0, 26.0));
+        assert_eq!(
+            bounds[0],
+            Rectangle::new(Point::ORIGIN, Size::new(10.0, 20.0))
+        );
+        assert_eq!(
+            bounds[1],
+            Rectangle::new(Point::new(11.0, 0.0), Size::new(30.0, 5.0))
+        );
+        assert_eq!(
+            bounds[2],
+            Rectangle::new(Point::new(5.0, 21.0), Size::new(5.0, 5.0))
+        );
+    }
+}
@@ -0,0 +1,526 @@
+//! Display icons from the icon font bundled with the renderer.
+use crate::{
+    layout, Color, Element, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::hash::Hash;
+
+/// A glyph of the icon font, sized and colored like [`Text`].
+///
+/// Icons are square and as tall as a line of [`Text`] with the same size, so
+/// they can be placed next to labels inside of buttons, menus, and lists.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::icon::{Glyph, Icon};
+/// #
+/// Icon::new(Glyph::Search)
+///     .size(24)
+///     .color([0.5, 0.5, 0.5]);
+/// ```
+///
+/// [`Text`]: ../text/struct.Text.html
+#[derive(Debug, Clone, Copy)]
+pub struct Icon {
+    glyph: Glyph,
+    size: Option<u16>,
+    color: Option<Color>,
+}
+
+impl Icon {
+    /// Creates a new [`Icon`] displaying the given [`Glyph`].
+    ///
+    /// [`Icon`]: struct.Icon.html
+    /// [`Glyph`]: enum.Glyph.html
+    pub fn new(glyph: Glyph) -> Self {
+        Icon {
+            glyph,
+            size: None,
+            color: None,
+        }
+    }
+
+    /// Sets the size of the [`Icon`].
+    ///
+    /// [`Icon`]: struct.Icon.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Icon`].
+    ///
+    /// By default, icons use the color of text.
+    ///
+    /// [`Icon`]: struct.Icon.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Icon
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = f32::from(self.size.unwrap_or(Renderer::DEFAULT_SIZE));
+
+        layout::Node::new(limits.resolve(Size::new(size, size)))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            layout.bounds(),
+            self.glyph,
+            self.size.unwrap_or(Renderer::DEFAULT_SIZE),
+            self.color,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of an [`Icon`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use an [`Icon`] in your user interface.
+///
+/// [`Icon`]: struct.Icon.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The default size of an [`Icon`].
+    ///
+    /// [`Icon`]: struct.Icon.html
+    const DEFAULT_SIZE: u16;
+
+    /// Draws an [`Icon`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Icon`]
+    ///   * the [`Glyph`] to display
+    ///   * the size of the [`Icon`]
+    ///   * the color of the [`Icon`], if any
+    ///
+    /// [`Icon`]: struct.Icon.html
+    /// [`Glyph`]: enum.Glyph.html
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        glyph: Glyph,
+        size: u16,
+        color: Option<Color>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Icon> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'a,
+{
+    fn from(icon: Icon) -> Element<'a, Message, Renderer> {
+        Element::new(icon)
+    }
+}
+
+/// A glyph of the icon font bundled with the built-in renderers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Glyph {
+    /// A check mark.
+    Check,
+
+    /// A cross, used to close or dismiss content.
+    Close,
+
+    /// A plus sign.
+    Plus,
+
+    /// A minus sign.
+    Minus,
+
+    /// An arrow pointing up.
+    ArrowUp,
+
+    /// An arrow pointing down.
+    ArrowDown,
+
+    /// An arrow pointing left.
+    ArrowLeft,
+
+    /// An arrow pointing right.
+    ArrowRight,
+
+    /// A chevron pointing up.
+    ChevronUp,
+
+    /// A chevron pointing down.
+    ChevronDown,
+
+    /// A chevron pointing left.
+    ChevronLeft,
+
+    /// A chevron pointing right.
+    ChevronRight,
+
+    /// A triangle pointing up.
+    CaretUp,
+
+    /// A triangle pointing down.
+    CaretDown,
+
+    /// A triangle pointing left.
+    CaretLeft,
+
+    /// A triangle pointing right.
+    CaretRight,
+
+    /// Three horizontal bars, usually opening a menu.
+    Menu,
+
+    /// Three dots in a row.
+    MoreHorizontal,
+
+    /// Three dots in a column.
+    MoreVertical,
+
+    /// A grid of dots, used as a drag handle.
+    Grip,
+
+    /// A magnifying glass.
+    Search,
+
+    /// A magnifying glass with a plus sign.
+    ZoomIn,
+
+    /// A magnifying glass with a minus sign.
+    ZoomOut,
+
+    /// A house.
+    Home,
+
+    /// A gear.
+    Settings,
+
+    /// The silhouette of a person.
+    User,
+
+    /// A heart.
+    Heart,
+
+    /// A star.
+    Star,
+
+    /// A trash can.
+    Trash,
+
+    /// A pencil.
+    Edit,
+
+    /// Two overlapping pages.
+    Copy,
+
+    /// A floppy disk.
+    Save,
+
+    /// A folder.
+    Folder,
+
+    /// A page with a folded corner.
+    File,
+
+    /// An arrow pointing down to a tray.
+    Download,
+
+    /// An arrow pointing up from a tray.
+    Upload,
+
+    /// A circular arrow.
+    Refresh,
+
+    /// An arrow turning back to the left.
+    Undo,
+
+    /// An arrow turning back to the right.
+    Redo,
+
+    /// A closed padlock.
+    Lock,
+
+    /// An open padlock.
+    Unlock,
+
+    /// An eye.
+    Eye,
+
+    /// A bell.
+    Bell,
+
+    /// An envelope.
+    Mail,
+
+    /// A calendar.
+    Calendar,
+
+    /// A clock.
+    Clock,
+
+    /// The letter i in a circle.
+    Info,
+
+    /// An exclamation mark in a triangle.
+    Warning,
+
+    /// A cross in a circle.
+    Error,
+
+    /// A question mark in a circle.
+    Help,
+
+    /// A check mark in a circle.
+    CheckCircle,
+
+    /// A triangle pointing right, used to play media.
+    Play,
+
+    /// Two vertical bars, used to pause media.
+    Pause,
+
+    /// A square, used to stop media.
+    Stop,
+
+    /// A filled circle.
+    Circle,
+
+    /// A funnel.
+    Filter,
+
+    /// A bookmark.
+    Bookmark,
+
+    /// A flag.
+    Flag,
+
+    /// A power symbol.
+    Power,
+
+    /// Four corners, used to expand content.
+    Expand,
+
+    /// Four squares in a grid.
+    Grid,
+
+    /// A bulleted list.
+    List,
+
+    /// A picture of mountains.
+    Image,
+
+    /// A paper plane.
+    Send,
+
+    /// A speaker emitting sound.
+    VolumeUp,
+
+    /// A crossed out speaker.
+    VolumeOff,
+
+    /// A sun.
+    Sun,
+
+    /// A crescent moon.
+    Moon,
+
+    /// A map pin.
+    Location,
+
+    /// Two angle brackets around a slash.
+    Code,
+}
+
+impl Glyph {
+    /// All the available glyphs.
+    pub const ALL: &'static [Glyph] = &[
+        Glyph::Check,
+        Glyph::Close,
+        Glyph::Plus,
+        Glyph::Minus,
+        Glyph::ArrowUp,
+        Glyph::ArrowDown,
+        Glyph::ArrowLeft,
+        Glyph::ArrowRight,
+        Glyph::ChevronUp,
+        Glyph::ChevronDown,
+        Glyph::ChevronLeft,
+        Glyph::ChevronRight,
+        Glyph::CaretUp,
+        Glyph::CaretDown,
+        Glyph::CaretLeft,
+        Glyph::CaretRight,
+        Glyph::Menu,
+        Glyph::MoreHorizontal,
+        Glyph::MoreVertical,
+        Glyph::Grip,
+        Glyph::Search,
+        Glyph::ZoomIn,
+        Glyph::ZoomOut,
+        Glyph::Home,
+        Glyph::Settings,
+        Glyph::User,
+        Glyph::Heart,
+        Glyph::Star,
+        Glyph::Trash,
+        Glyph::Edit,
+        Glyph::Copy,
+        Glyph::Save,
+        Glyph::Folder,
+        Glyph::File,
+        Glyph::Download,
+        Glyph::Upload,
+        Glyph::Refresh,
+        Glyph::Undo,
+        Glyph::Redo,
+        Glyph::Lock,
+        Glyph::Unlock,
+        Glyph::Eye,
+        Glyph::Bell,
+        Glyph::Mail,
+        Glyph::Calendar,
+        Glyph::Clock,
+        Glyph::Info,
+        Glyph::Warning,
+        Glyph::Error,
+        Glyph::Help,
+        Glyph::CheckCircle,
+        Glyph::Play,
+        Glyph::Pause,
+        Glyph::Stop,
+        Glyph::Circle,
+        Glyph::Filter,
+        Glyph::Bookmark,
+        Glyph::Flag,
+        Glyph::Power,
+        Glyph::Expand,
+        Glyph::Grid,
+        Glyph::List,
+        Glyph::Image,
+        Glyph::Send,
+        Glyph::VolumeUp,
+        Glyph::VolumeOff,
+        Glyph::Sun,
+        Glyph::Moon,
+        Glyph::Location,
+        Glyph::Code,
+    ];
+
+    /// Returns the `char` representing the [`Glyph`] in the icon font.
+    ///
+    /// Codepoints belong to the private use area. [`Glyph::Check`] keeps the
+    /// `U+F00C` codepoint of Font Awesome, while the rest of the glyphs are
+    /// numbered in order from `U+F100`. The font is built from the sources in
+    /// `graphics/fonts/icons`, which list every codepoint.
+    ///
+    /// [`Glyph`]: enum.Glyph.html
+    /// [`Glyph::Check`]: enum.Glyph.html#variant.Check
+    pub fn codepoint(self) -> char {
+        match self {
+            Glyph::Check => '\u{F00C}',
+            Glyph::Close => '\u{F100}',
+            Glyph::Plus => '\u{F101}',
+            Glyph::Minus => '\u{F102}',
+            Glyph::ArrowUp => '\u{F103}',
+            Glyph::ArrowDown => '\u{F104}',
+            Glyph::ArrowLeft => '\u{F105}',
+            Glyph::ArrowRight => '\u{F106}',
+            Glyph::ChevronUp => '\u{F107}',
+            Glyph::ChevronDown => '\u{F108}',
+            Glyph::ChevronLeft => '\u{F109}',
+            Glyph::ChevronRight => '\u{F10A}',
+            Glyph::CaretUp => '\u{F10B}',
+            Glyph::CaretDown => '\u{F10C}',
+            Glyph::CaretLeft => '\u{F10D}',
+            Glyph::CaretRight => '\u{F10E}',
+            Glyph::Menu => '\u{F10F}',
+            Glyph::MoreHorizontal => '\u{F110}',
+            Glyph::MoreVertical => '\u{F111}',
+            Glyph::Grip => '\u{F112}',
+            Glyph::Search => '\u{F113}',
+            Glyph::ZoomIn => '\u{F114}',
+            Glyph::ZoomOut => '\u{F115}',
+            Glyph::Home => '\u{F116}',
+            Glyph::Settings => '\u{F117}',
+            Glyph::User => '\u{F118}',
+            Glyph::Heart => '\u{F119}',
+            Glyph::Star => '\u{F11A}',
+            Glyph::Trash => '\u{F11B}',
+            Glyph::Edit => '\u{F11C}',
+            Glyph::Copy => '\u{F11D}',
+            Glyph::Save => '\u{F11E}',
+            Glyph::Folder => '\u{F11F}',
+            Glyph::File => '\u{F120}',
+            Glyph::Download => '\u{F121}',
+            Glyph::Upload => '\u{F122}',
+            Glyph::Refresh => '\u{F123}',
+            Glyph::Undo => '\u{F124}',
+            Glyph::Redo => '\u{F125}',
+            Glyph::Lock => '\u{F126}',
+            Glyph::Unlock => '\u{F127}',
+            Glyph::Eye => '\u{F128}',
+            Glyph::Bell => '\u{F129}',
+            Glyph::Mail => '\u{F12A}',
+            Glyph::Calendar => '\u{F12B}',
+            Glyph::Clock => '\u{F12C}',
+            Glyph::Info => '\u{F12D}',
+            Glyph::Warning => '\u{F12E}',
+            Glyph::Error => '\u{F12F}',
+            Glyph::Help => '\u{F130}',
+            Glyph::CheckCircle => '\u{F131}',
+            Glyph::Play => '\u{F132}',
+            Glyph::Pause => '\u{F133}',
+            Glyph::Stop => '\u{F134}',
+            Glyph::Circle => '\u{F135}',
+            Glyph::Filter => '\u{F136}',
+            Glyph::Bookmark => '\u{F137}',
+            Glyph::Flag => '\u{F138}',
+            Glyph::Power => '\u{F139}',
+            Glyph::Expand => '\u{F13A}',
+            Glyph::Grid => '\u{F13B}',
+            Glyph::List => '\u{F13C}',
+            Glyph::Image => '\u{F13D}',
+            Glyph::Send => '\u{F13E}',
+            Glyph::VolumeUp => '\u{F13F}',
+            Glyph::VolumeOff => '\u{F140}',
+            Glyph::Sun => '\u{F141}',
+            Glyph::Moon => '\u{F142}',
+            Glyph::Location => '\u{F143}',
+            Glyph::Code => '\u{F144}',
+        }
+    }
+}
@@ -1,28 +1,70 @@
 //! Display images in your user interface.
-use crate::{layout, Element, Hasher, Layout, Length, Point, Size, Widget};
+pub mod viewer;
+
+#[cfg(feature = "image_url")]
+pub mod remote;
+
+pub use viewer::Viewer;
+
+use crate::{
+    layout, Align, ContentFit, Element, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
 
 use std::{
     hash::{Hash, Hasher as _},
     path::PathBuf,
     sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+#[cfg(feature = "image_url")]
+thread_local! {
+    // Displayed by an `Image` loading from a URL without a placeholder
+    static EMPTY: Handle = Handle::from_pixels(1, 1, vec![0; 4]);
+}
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
+/// The image is scaled to its bounds with a [`ContentFit`], and aligned in
+/// them when it does not fill them completely.
+///
+/// Animated GIF and APNG images are played on their own, with the timing of
+/// their frames. A [`Playback`] can be used to pause and resume them.
+///
+/// With the `image_url` feature, an [`Image`] can also be loaded from a URL
+/// in the background with [`Image::from_url`].
+///
 /// # Example
 ///
 /// ```
-/// # use iced_native::Image;
+/// # use iced_native::{ContentFit, Image, Length};
 /// #
 /// let image = Image::new("resources/ferris.png");
+///
+/// let banner = Image::new("resources/ferris.png")
+///     .width(Length::Fill)
+///     .height(Length::Units(100))
+///     .content_fit(ContentFit::Cover);
 /// ```
 ///
 /// <img src="https://github.com/hecrj/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
+///
+/// [`ContentFit`]: ../../enum.ContentFit.html
+/// [`Playback`]: struct.Playback.html
+/// [`Image`]: struct.Image.html
+/// [`Image::from_url`]: struct.Image.html#method.from_url
 #[derive(Debug, Hash)]
 pub struct Image {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    horizontal_alignment: Align,
+    vertical_alignment: Align,
+    playback: Option<Playback>,
+    #[cfg(feature = "image_url")]
+    remote: Remote,
 }
 
 impl Image {
@@ -34,9 +76,70 @@ impl Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            horizontal_alignment: Align::Center,
+            vertical_alignment: Align::Center,
+            playback: None,
+            #[cfg(feature = "image_url")]
+            remote: Remote::Ready,
         }
     }
 
+    /// Creates a new [`Image`] that displays the image at the given URL.
+    ///
+    /// The image is downloaded and decoded in the background, the first time
+    /// its URL is requested, while your application listens to
+    /// [`remote::subscription`]. Meanwhile, a loading indicator or the
+    /// [`placeholder`] of the [`Image`] is displayed.
+    ///
+    /// As the size of the image is unknown until it is loaded, you may want
+    /// to give the [`Image`] a fixed size.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`remote::subscription`]: remote/fn.subscription.html
+    /// [`placeholder`]: #method.placeholder
+    #[cfg(feature = "image_url")]
+    pub fn from_url(url: &str) -> Self {
+        let empty = || EMPTY.with(Handle::clone);
+
+        match remote::load(url) {
+            remote::Status::Loaded(handle) => Image::new(handle),
+            remote::Status::Loading => Image {
+                remote: Remote::Loading {
+                    has_placeholder: false,
+                },
+                ..Image::new(empty())
+            },
+            remote::Status::Failed => Image {
+                remote: Remote::Failed,
+                ..Image::new(empty())
+            },
+        }
+    }
+
+    /// Sets the image displayed instead of the one of an [`Image`] created
+    /// with [`from_url`], while it is loading or if it fails to load.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`from_url`]: #method.from_url
+    #[cfg(feature = "image_url")]
+    pub fn placeholder(mut self, placeholder: impl Into<Handle>) -> Self {
+        match self.remote {
+            Remote::Ready => {}
+            Remote::Loading { .. } => {
+                self.handle = placeholder.into();
+                self.remote = Remote::Loading {
+                    has_placeholder: true,
+                };
+            }
+            Remote::Failed => {
+                self.handle = placeholder.into();
+            }
+        }
+
+        self
+    }
+
     /// Sets the width of the [`Image`] boundaries.
     ///
     /// [`Image`]: struct.Image.html
@@ -52,6 +155,217 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    ///
+    /// [`ContentFit`]: ../../enum.ContentFit.html
+    /// [`ContentFit::Contain`]: ../../enum.ContentFit.html#variant.Contain
+    /// [`Image`]: struct.Image.html
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the horizontal alignment of the image inside the [`Image`]
+    /// boundaries.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn align_x(mut self, alignment: Align) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the image inside the [`Image`]
+    /// boundaries.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn align_y(mut self, alignment: Align) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`Playback`] of the [`Image`], if it is animated.
+    ///
+    /// By default, animated images play forever.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    /// [`Image`]: struct.Image.html
+    pub fn playback(mut self, playback: Playback) -> Self {
+        self.playback = Some(playback);
+        self
+    }
+
+    /// Returns the index of the frame of the [`Image`] to draw, requesting a
+    /// redraw for the next frame while an animation is playing.
+    ///
+    /// [`Image`]: struct.Image.html
+    fn frame<Renderer>(&self, renderer: &mut Renderer) -> usize
+    where
+        Renderer: self::Renderer,
+    {
+        let delays = renderer.frame_delays(&self.handle);
+
+        if delays.len() < 2 {
+            return 0;
+        }
+
+        let (elapsed, is_playing) = match self.playback {
+            Some(playback) => (playback.elapsed(), playback.is_playing()),
+            None => (
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default(),
+                true,
+            ),
+        };
+
+        let (frame, remaining) = frame_at(&delays, elapsed);
+
+        if let (true, Some(remaining)) = (is_playing, remaining) {
+            renderer.request_redraw_at(Instant::now() + remaining);
+        }
+
+        frame
+    }
+}
+
+/// The status of the image of an [`Image`] created from a URL.
+///
+/// [`Image`]: struct.Image.html
+#[cfg(feature = "image_url")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Remote {
+    Ready,
+    Loading { has_placeholder: bool },
+    Failed,
+}
+
+/// The playback of an animated [`Image`].
+///
+/// Keep a [`Playback`] in your application state and give it to
+/// [`Image::playback`] to pause, resume or restart an animation.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::image::{Image, Playback};
+/// #
+/// let mut playback = Playback::new();
+/// playback.pause();
+///
+/// let image = Image::new("resources/ferris.gif").playback(playback);
+/// ```
+///
+/// [`Image`]: struct.Image.html
+/// [`Playback`]: struct.Playback.html
+/// [`Image::playback`]: struct.Image.html#method.playback
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Playback {
+    start: Instant,
+    paused_at: Option<Duration>,
+}
+
+impl Playback {
+    /// Creates a new [`Playback`] that starts playing right away.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn new() -> Self {
+        Playback {
+            start: Instant::now(),
+            paused_at: None,
+        }
+    }
+
+    /// Returns whether the [`Playback`] is playing.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn is_playing(&self) -> bool {
+        self.paused_at.is_none()
+    }
+
+    /// Resumes the [`Playback`] from the frame where it was paused.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn play(&mut self) {
+        if let Some(elapsed) = self.paused_at.take() {
+            self.start = Instant::now() - elapsed;
+        }
+    }
+
+    /// Pauses the [`Playback`] on its current frame.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn pause(&mut self) {
+        if self.paused_at.is_none() {
+            self.paused_at = Some(self.start.elapsed());
+        }
+    }
+
+    /// Pauses the [`Playback`] if it is playing, or resumes it otherwise.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn toggle(&mut self) {
+        if self.is_playing() {
+            self.pause();
+        } else {
+            self.play();
+        }
+    }
+
+    /// Goes back to the first frame, without pausing or resuming the
+    /// [`Playback`].
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn restart(&mut self) {
+        self.start = Instant::now();
+
+        if self.paused_at.is_some() {
+            self.paused_at = Some(Duration::from_secs(0));
+        }
+    }
+
+    /// Returns the time the [`Playback`] has been playing for.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn elapsed(&self) -> Duration {
+        self.paused_at.unwrap_or_else(|| self.start.elapsed())
+    }
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the index of the frame shown after the given time, looping over
+/// frames with the given delays, along with the time left until the next
+/// frame is shown.
+fn frame_at(
+    delays: &[Duration],
+    elapsed: Duration,
+) -> (usize, Option<Duration>) {
+    let total: u128 = delays.iter().map(Duration::as_millis).sum();
+
+    if total == 0 {
+        return (0, None);
+    }
+
+    let mut time = elapsed.as_millis() % total;
+
+    for (i, delay) in delays.iter().enumerate() {
+        let delay = delay.as_millis();
+
+        if time < delay {
+            return (i, Some(Duration::from_millis((delay - time) as u64)));
+        }
+
+        time -= delay;
+    }
+
+    (0, None)
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Image
@@ -72,23 +386,23 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let (width, height) = renderer.dimensions(&self.handle);
+        let image = Size::new(width as f32, height as f32);
 
-        let aspect_ratio = width as f32 / height as f32;
-
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
+        let size = limits.width(self.width).height(self.height).resolve(image);
+        let fitted = self.content_fit.fit(image, size);
 
-        let viewport_aspect_ratio = size.width / size.height;
+        // Shrinking sides wrap the fitted image
+        let width = match self.width {
+            Length::Shrink => size.width.min(fitted.width),
+            _ => size.width,
+        };
 
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
+        let height = match self.height {
+            Length::Shrink => size.height.min(fitted.height),
+            _ => size.height,
+        };
 
-        layout::Node::new(size)
+        layout::Node::new(Size::new(width, height))
     }
 
     fn draw(
@@ -98,7 +412,41 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        let bounds = layout.bounds();
+
+        #[cfg(feature = "image_url")]
+        {
+            // The user interface is rebuilt once the image is loaded
+            if let Remote::Loading { has_placeholder } = self.remote {
+                if !has_placeholder {
+                    return renderer.draw_loading(bounds);
+                }
+            }
+        }
+
+        let (width, height) = renderer.dimensions(&self.handle);
+        let image = self
+            .content_fit
+            .fit(Size::new(width as f32, height as f32), bounds.size());
+
+        let offset = |alignment, space: f32| match alignment {
+            Align::Start => 0.0,
+            Align::Center => space / 2.0,
+            Align::End => space,
+        };
+
+        let image_bounds = Rectangle {
+            x: bounds.x
+                + offset(self.horizontal_alignment, bounds.width - image.width),
+            y: bounds.y
+                + offset(self.vertical_alignment, bounds.height - image.height),
+            width: image.width,
+            height: image.height,
+        };
+
+        let frame = self.frame(renderer);
+
+        renderer.draw(self.handle.clone(), frame, bounds, image_bounds)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -244,10 +592,37 @@ pub trait Renderer: crate::Renderer {
     /// [`Image`]: struct.Image.html
     fn dimensions(&self, handle: &Handle) -> (u32, u32);
 
-    /// Draws an [`Image`].
+    /// Returns the delays of the frames of an animated [`Image`].
+    ///
+    /// Images that are not animated have no frame delays.
     ///
     /// [`Image`]: struct.Image.html
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    fn frame_delays(&self, handle: &Handle) -> Vec<Duration>;
+
+    /// Draws the loading indicator of an [`Image`] whose image is not ready
+    /// yet.
+    ///
+    /// [`Image`]: struct.Image.html
+    fn draw_loading(&mut self, bounds: Rectangle) -> Self::Output;
+
+    /// Draws the given frame of an [`Image`].
+    ///
+    /// It receives:
+    ///   * the [`Handle`] of the image
+    ///   * the frame of the image to draw, if it is animated
+    ///   * the bounds of the [`Image`]
+    ///   * the bounds of the fitted image, which should be clipped to the
+    ///     bounds of the [`Image`]
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Handle`]: struct.Handle.html
+    fn draw(
+        &mut self,
+        handle: Handle,
+        frame: usize,
+        bounds: Rectangle,
+        image_bounds: Rectangle,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Image> for Element<'a, Message, Renderer>
@@ -258,3 +633,25 @@ where
         Element::new(image)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_at_loops_over_delays() {
+        let delays = [
+            Duration::from_millis(100),
+            Duration::from_millis(50),
+            Duration::from_millis(200),
+        ];
+
+        let frame = |elapsed| frame_at(&delays, Duration::from_millis(elapsed));
+
+        assert_eq!(frame(0), (0, Some(Duration::from_millis(100))));
+        assert_eq!(frame(120), (1, Some(Duration::from_millis(30))));
+        assert_eq!(frame(150), (2, Some(Duration::from_millis(200))));
+        assert_eq!(frame(360), (0, Some(Duration::from_millis(90))));
+        assert_eq!(frame(460), (1, Some(Duration::from_millis(40))));
+    }
+}
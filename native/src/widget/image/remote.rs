@@ -0,0 +1,307 @@
+//! Load images from URLs in the background.
+//!
+//! Images are downloaded and decoded one at a time, in the background, while
+//! your application listens to the [`subscription`] of this module. Loaded
+//! images are cached by URL, keeping the [`CAPACITY`] most recently used
+//! ones.
+//!
+//! [`subscription`]: fn.subscription.html
+//! [`CAPACITY`]: constant.CAPACITY.html
+use crate::image::Handle;
+use crate::subscription::{EventStream, Recipe};
+use crate::{Event, Hasher, Subscription};
+
+use iced_futures::futures::{channel::mpsc as channel, StreamExt};
+use iced_futures::BoxStream;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+
+/// The maximum number of images kept in the cache.
+///
+/// When the cache is full, the least recently used image is evicted and
+/// downloaded again the next time its URL is requested.
+pub const CAPACITY: usize = 64;
+
+thread_local! {
+    static CACHE: RefCell<Cache> = RefCell::new(Cache::default());
+}
+
+/// The status of an image loaded from a URL.
+#[derive(Debug, Clone)]
+pub enum Status {
+    /// The image is being downloaded or decoded.
+    Loading,
+
+    /// The image is ready to be displayed.
+    Loaded(Handle),
+
+    /// The image could not be downloaded or decoded.
+    Failed,
+}
+
+/// Returns the [`Status`] of the image at the given URL, queueing it for
+/// download if it has not been requested yet.
+///
+/// [`Status`]: enum.Status.html
+pub fn load(url: &str) -> Status {
+    CACHE.with(|cache| cache.borrow_mut().load(url))
+}
+
+/// Returns a [`Subscription`] that downloads the images requested with
+/// [`load`], producing the URL of every image once it is loaded or fails to
+/// load.
+///
+/// Images are only downloaded while your application listens to this
+/// [`Subscription`]. Updating your application when it produces a URL
+/// rebuilds the user interface and displays the image.
+///
+/// [`Subscription`]: ../../type.Subscription.html
+/// [`load`]: fn.load.html
+pub fn subscription() -> Subscription<String> {
+    Subscription::from_recipe(Downloads)
+}
+
+#[derive(Default)]
+struct Cache {
+    entries: HashMap<String, Entry>,
+    // Incremented on every access, to find the least recently used entry
+    clock: u64,
+    // Downloads waiting for the subscription to start
+    pending: Vec<Download>,
+    downloader: Option<mpsc::Sender<Download>>,
+}
+
+struct Entry {
+    state: State,
+    last_used: u64,
+}
+
+enum State {
+    Loading(mpsc::Receiver<Option<Handle>>),
+    Loaded(Handle),
+    Failed,
+}
+
+struct Download {
+    url: String,
+    result: mpsc::Sender<Option<Handle>>,
+}
+
+impl Cache {
+    fn load(&mut self, url: &str) -> Status {
+        self.clock += 1;
+
+        if !self.entries.contains_key(url) {
+            let receiver = self.queue(url);
+            self.evict();
+
+            let _ = self.entries.insert(
+                url.to_owned(),
+                Entry {
+                    state: State::Loading(receiver),
+                    last_used: self.clock,
+                },
+            );
+        }
+
+        let entry = self.entries.get_mut(url).expect("Get cache entry");
+        entry.last_used = self.clock;
+
+        if let State::Loading(receiver) = &entry.state {
+            match receiver.try_recv() {
+                Ok(Some(handle)) => entry.state = State::Loaded(handle),
+                Ok(None) => entry.state = State::Failed,
+                Err(mpsc::TryRecvError::Empty) => {}
+                // The subscription was dropped before downloading the image,
+                // so it is downloaded by the next one
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    let receiver = self.queue(url);
+
+                    self.entries.get_mut(url).expect("Get cache entry").state =
+                        State::Loading(receiver);
+                }
+            }
+        }
+
+        let entry = &self.entries[url];
+
+        match &entry.state {
+            State::Loading(_) => Status::Loading,
+            State::Loaded(handle) => Status::Loaded(handle.clone()),
+            State::Failed => Status::Failed,
+        }
+    }
+
+    fn queue(&mut self, url: &str) -> mpsc::Receiver<Option<Handle>> {
+        let (result, receiver) = mpsc::channel();
+
+        self.pending.push(Download {
+            url: url.to_owned(),
+            result,
+        });
+        self.start_downloads();
+
+        receiver
+    }
+
+    fn start_downloads(&mut self) {
+        let downloader = match &self.downloader {
+            Some(downloader) => downloader,
+            None => return,
+        };
+
+        for download in std::mem::take(&mut self.pending) {
+            // The subscription was dropped, so the download waits for the
+            // next one
+            if let Err(mpsc::SendError(download)) = downloader.send(download) {
+                self.pending.push(download);
+                self.downloader = None;
+                return;
+            }
+        }
+    }
+
+    fn evict(&mut self) {
+        while self.entries.len() >= CAPACITY {
+            // Images still loading are never evicted
+            let oldest = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| !matches!(entry.state, State::Loading(_)))
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(url, _)| url.clone());
+
+            match oldest {
+                Some(url) => {
+                    let _ = self.entries.remove(&url);
+                }
+                None => return,
+            }
+        }
+    }
+}
+
+struct Downloads;
+
+impl Recipe<Hasher, Event> for Downloads {
+    type Output = String;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: EventStream) -> BoxStream<Self::Output> {
+        let (downloader, downloads) = mpsc::channel::<Download>();
+        let (sender, receiver) = channel::unbounded();
+
+        // Streams are started by the runtime in the thread that owns the cache
+        CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+
+            cache.downloader = Some(downloader);
+            cache.start_downloads();
+        });
+
+        // A single thread downloads every image, in order
+        let _ = thread::spawn(move || {
+            for Download { url, result } in downloads {
+                let _ = result.send(fetch(&url));
+
+                // The subscription was dropped
+                if sender.unbounded_send(url).is_err() {
+                    return;
+                }
+            }
+        });
+
+        receiver.boxed()
+    }
+}
+
+fn fetch(url: &str) -> Option<Handle> {
+    let bytes = reqwest::blocking::get(url)
+        .and_then(reqwest::blocking::Response::error_for_status)
+        .and_then(reqwest::blocking::Response::bytes)
+        .ok()?;
+
+    let image = ::image::load_from_memory(&bytes).ok()?.to_bgra8();
+    let (width, height) = image.dimensions();
+
+    Some(Handle::from_pixels(width, height, image.into_raw()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loaded(cache: &mut Cache, url: &str) {
+        let _ = cache.entries.insert(
+            url.to_owned(),
+            Entry {
+                state: State::Loaded(Handle::from_pixels(1, 1, vec![0; 4])),
+                last_used: cache.clock,
+            },
+        );
+    }
+
+    #[test]
+    fn evicts_least_recently_used_images() {
+        let mut cache = Cache::default();
+
+        for i in 0..CAPACITY {
+            cache.clock += 1;
+            loaded(&mut cache, &i.to_string());
+        }
+
+        // Using the first image makes the second one the oldest
+        let _ = cache.load("0");
+        let _ = cache.load("new");
+
+        assert_eq!(cache.entries.len(), CAPACITY);
+        assert!(cache.entries.contains_key("0"));
+        assert!(!cache.entries.contains_key("1"));
+        assert!(matches!(cache.load("new"), Status::Loading));
+    }
+
+    #[test]
+    fn queues_downloads_until_subscribed() {
+        let mut cache = Cache::default();
+
+        let _ = cache.load("a");
+        let _ = cache.load("a");
+
+        assert_eq!(cache.pending.len(), 1);
+
+        let (downloader, downloads) = mpsc::channel();
+        cache.downloader = Some(downloader);
+        cache.start_downloads();
+
+        assert!(cache.pending.is_empty());
+        assert_eq!(
+            downloads.try_recv().map(|download| download.url).ok(),
+            Some(String::from("a"))
+        );
+    }
+
+    #[test]
+    fn retries_downloads_dropped_with_the_subscription() {
+        let mut cache = Cache::default();
+
+        let (downloader, downloads) = mpsc::channel();
+        cache.downloader = Some(downloader);
+
+        let _ = cache.load("a");
+
+        // The subscription stops before downloading the image
+        drop(downloads);
+
+        assert!(matches!(cache.load("a"), Status::Loading));
+        assert_eq!(cache.pending.len(), 1);
+        assert!(cache.downloader.is_none());
+    }
+}
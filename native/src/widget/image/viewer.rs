@@ -0,0 +1,387 @@
+//! Zoom and pan on an image.
+//!
+//! A [`Viewer`] has some local [`State`].
+//!
+//! [`Viewer`]: struct.Viewer.html
+//! [`State`]: struct.State.html
+use crate::image::{self, Handle};
+use crate::{
+    layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+
+/// A frame that displays an image that can be zoomed with the mouse wheel
+/// and panned by dragging it.
+///
+/// The zoom of a [`Viewer`] is relative to the size of its image once fitted
+/// in its bounds with a [`Mode`], and is kept between a minimum and a maximum
+/// scale.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::image::{viewer, Viewer};
+/// #
+/// let mut state = viewer::State::new();
+///
+/// let viewer = Viewer::new(&mut state, "resources/ferris.png")
+///     .mode(viewer::Mode::Fill)
+///     .max_scale(20.0);
+/// ```
+///
+/// [`Viewer`]: struct.Viewer.html
+/// [`Mode`]: enum.Mode.html
+#[derive(Debug)]
+pub struct Viewer<'a> {
+    state: &'a mut State,
+    handle: Handle,
+    width: Length,
+    height: Length,
+    mode: Mode,
+    min_scale: f32,
+    max_scale: f32,
+    scale_step: f32,
+}
+
+impl<'a> Viewer<'a> {
+    /// Creates a new [`Viewer`] with the given [`State`] and image.
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    /// [`State`]: struct.State.html
+    pub fn new<T: Into<Handle>>(state: &'a mut State, handle: T) -> Self {
+        Viewer {
+            state,
+            handle: handle.into(),
+            width: Length::Fill,
+            height: Length::Fill,
+            mode: Mode::Fit,
+            min_scale: 0.25,
+            max_scale: 10.0,
+            scale_step: 0.10,
+        }
+    }
+
+    /// Sets the width of the [`Viewer`].
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Viewer`].
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Mode`] used to fit the image in the bounds of the
+    /// [`Viewer`], when it is not zoomed.
+    ///
+    /// [`Mode`]: enum.Mode.html
+    /// [`Viewer`]: struct.Viewer.html
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the minimum scale of the [`Viewer`].
+    ///
+    /// Defaults to `0.25`.
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    pub fn min_scale(mut self, min_scale: f32) -> Self {
+        self.min_scale = min_scale;
+        self
+    }
+
+    /// Sets the maximum scale of the [`Viewer`].
+    ///
+    /// Defaults to `10.0`.
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    pub fn max_scale(mut self, max_scale: f32) -> Self {
+        self.max_scale = max_scale;
+        self
+    }
+
+    /// Sets the fraction the scale of the [`Viewer`] changes for every line
+    /// scrolled with the mouse wheel.
+    ///
+    /// Defaults to `0.10`.
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    pub fn scale_step(mut self, scale_step: f32) -> Self {
+        self.scale_step = scale_step;
+        self
+    }
+
+    /// Returns the size of the image once fitted in the given bounds and
+    /// zoomed.
+    fn image_size<Renderer>(&self, renderer: &Renderer, bounds: Size) -> Size
+    where
+        Renderer: self::Renderer,
+    {
+        let (width, height) = renderer.dimensions(&self.handle);
+        let (width, height) = (width as f32, height as f32);
+
+        let horizontal = bounds.width / width;
+        let vertical = bounds.height / height;
+
+        let fitted = match self.mode {
+            Mode::Fit => horizontal.min(vertical),
+            Mode::Fill => horizontal.max(vertical),
+        };
+
+        let scale = fitted * self.state.scale;
+
+        Size::new(width * scale, height * scale)
+    }
+
+    /// Keeps the image covering as much of the given bounds as possible.
+    fn clamp_offset(&mut self, bounds: Size, image: Size) {
+        let max_x = ((image.width - bounds.width) / 2.0).max(0.0);
+        let max_y = ((image.height - bounds.height) / 2.0).max(0.0);
+
+        let x = self.state.offset.x.min(max_x);
+        let y = self.state.offset.y.min(max_y);
+
+        self.state.offset = Vector::new(x.max(-max_x), y.max(-max_y));
+    }
+}
+
+/// The way an image is fitted in the bounds of a [`Viewer`].
+///
+/// [`Viewer`]: struct.Viewer.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// The whole image is shown, leaving empty space around it if the aspect
+    /// ratios of the image and the [`Viewer`] differ.
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    Fit,
+
+    /// The image covers the whole [`Viewer`], cropping it if the aspect
+    /// ratios of both differ.
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    Fill,
+}
+
+/// The local state of a [`Viewer`].
+///
+/// [`Viewer`]: struct.Viewer.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct State {
+    scale: f32,
+    offset: Vector,
+    drag_origin: Option<(Point, Vector)>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            scale: 1.0,
+            offset: Vector::new(0.0, 0.0),
+            drag_origin: None,
+        }
+    }
+}
+
+impl State {
+    /// Creates a new [`State`], showing the whole image.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the current scale of the [`Viewer`].
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Returns whether the image of the [`Viewer`] is being dragged.
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    pub fn is_dragging(&self) -> bool {
+        self.drag_origin.is_some()
+    }
+
+    /// Resets the zoom and the pan of the [`Viewer`].
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    pub fn reset(&mut self) {
+        *self = State::default();
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Viewer<'a>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(Size::new(width as f32, height as f32));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if is_mouse_over =>
+            {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                };
+
+                let previous_scale = self.state.scale;
+                let scale = (previous_scale
+                    * (1.0 + self.scale_step).powf(lines))
+                .min(self.max_scale);
+
+                self.state.scale = scale.max(self.min_scale);
+
+                // The point of the image under the cursor stays in place
+                let ratio = self.state.scale / previous_scale;
+                let cursor = cursor_position - bounds.center();
+
+                self.state.offset = Vector::new(
+                    cursor.x - (cursor.x - self.state.offset.x) * ratio,
+                    cursor.y - (cursor.y - self.state.offset.y) * ratio,
+                );
+
+                let image = self.image_size(renderer, bounds.size());
+                self.clamp_offset(bounds.size(), image);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if is_mouse_over =>
+            {
+                self.state.drag_origin =
+                    Some((cursor_position, self.state.offset));
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.state.drag_origin = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((origin, offset)) = self.state.drag_origin {
+                    self.state.offset = offset + (cursor_position - origin);
+
+                    let image = self.image_size(renderer, bounds.size());
+                    self.clamp_offset(bounds.size(), image);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let image = self.image_size(renderer, bounds.size());
+
+        let image_bounds = Rectangle {
+            x: bounds.center_x() + self.state.offset.x - image.width / 2.0,
+            y: bounds.center_y() + self.state.offset.y - image.height / 2.0,
+            width: image.width,
+            height: image.height,
+        };
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            image_bounds,
+            self.handle.clone(),
+            self.state.is_dragging(),
+            bounds.contains(cursor_position),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.handle.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of an image [`Viewer`].
+///
+/// Your [renderer] will need to implement this trait before being able to
+/// use a [`Viewer`] in your user interface.
+///
+/// [`Viewer`]: struct.Viewer.html
+/// [renderer]: ../../../renderer/index.html
+pub trait Renderer: image::Renderer {
+    /// Draws an image [`Viewer`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Viewer`]
+    ///   * the bounds of its zoomed and panned image, which should be
+    ///     clipped to the bounds of the [`Viewer`]
+    ///   * the [`Handle`] of the image
+    ///   * whether the image is being dragged
+    ///   * whether the mouse is over the [`Viewer`]
+    ///
+    /// [`Viewer`]: struct.Viewer.html
+    /// [`Handle`]: ../struct.Handle.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        image_bounds: Rectangle,
+        handle: Handle,
+        is_dragging: bool,
+        is_mouse_over: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Viewer<'a>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+{
+    fn from(viewer: Viewer<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(viewer)
+    }
+}
@@ -0,0 +1,423 @@
+//! Display an interactive rotary selector of a single value from a range of
+//! values.
+//!
+//! A [`Knob`] has some local [`State`].
+//!
+//! [`Knob`]: struct.Knob.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse, slider, Clipboard, Element, Event, Hasher,
+    Id, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::{hash::Hash, ops::RangeInclusive};
+
+/// A rotary control that selects a single value from a range of values.
+///
+/// A [`Knob`] is turned by dragging it vertically: dragging it up increases
+/// its value. It can also be turned with the mouse wheel while hovered.
+///
+/// Its value is displayed by an arc around it, and tick marks can be shown
+/// along the arc using [`ticks`].
+///
+/// [`Knob`]: struct.Knob.html
+/// [`ticks`]: #method.ticks
+///
+/// # Example
+/// ```
+/// # use iced_native::{knob, renderer::Null};
+/// #
+/// # pub type Knob<'a, Message> = iced_native::Knob<'a, Message, Null>;
+/// pub enum Message {
+///     GainChanged(f32),
+/// }
+///
+/// let state = &mut knob::State::new();
+/// let gain = -6.0;
+///
+/// Knob::new(state, -60.0..=12.0, gain, Message::GainChanged)
+///     .step(0.5)
+///     .ticks(12.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Knob<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    range: RangeInclusive<f32>,
+    value: f32,
+    on_change: Box<dyn Fn(f32) -> Message>,
+    on_release: Option<Message>,
+    step: Option<f32>,
+    ticks: Vec<f32>,
+    size: Option<u16>,
+    tab_index: Option<u16>,
+    id: Option<Id>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
+    /// The distance, in pixels, the cursor has to be dragged to turn a
+    /// [`Knob`] from one end of its range to the other.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    const DRAG_DISTANCE: f32 = 200.0;
+
+    /// Creates a new [`Knob`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Knob`]
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`Knob`]
+    ///   * a function that will be called when the [`Knob`] is turned.
+    ///     It receives the new value of the [`Knob`] and must produce a
+    ///     `Message`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        Knob {
+            state,
+            value: value.max(*range.start()).min(*range.end()),
+            range,
+            on_change: Box::new(on_change),
+            on_release: None,
+            step: None,
+            ticks: Vec::new(),
+            size: None,
+            tab_index: None,
+            id: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the release message of the [`Knob`].
+    /// This is called when the mouse is released from the knob.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Snaps the value of the [`Knob`] to multiples of the given step,
+    /// counting from the start of its range.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Shows tick marks along the arc of the [`Knob`] every `step` units,
+    /// counting from the start of its range.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn ticks(mut self, step: f32) -> Self {
+        self.ticks = slider::ticks(&self.range, step);
+        self
+    }
+
+    /// Sets the diameter of the [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the position of the [`Knob`] in the focus order.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Knob`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`Knob`]: struct.Knob.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        slider::snap(value, &self.range, self.step)
+    }
+}
+
+/// The local state of a [`Knob`].
+///
+/// [`Knob`]: struct.Knob.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    drag_origin: Option<(f32, f32)>,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`Knob`] is currently being dragged.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn is_dragging(&self) -> bool {
+        self.drag_origin.is_some()
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Knob<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE))
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE))
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE));
+        let limits = limits.width(size).height(size);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let span = *self.range.end() - *self.range.start();
+
+        let new_value = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if bounds.contains(cursor_position) =>
+            {
+                self.state.drag_origin = Some((cursor_position.y, self.value));
+
+                None
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if self.state.drag_origin.is_some() =>
+            {
+                self.state.drag_origin = None;
+
+                if let Some(on_release) = self.on_release.clone() {
+                    messages.push(on_release);
+                }
+
+                None
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                self.state.drag_origin.map(|(origin, value)| {
+                    let delta = (origin - cursor_position.y)
+                        / Self::DRAG_DISTANCE
+                        * span;
+
+                    value + delta
+                })
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                };
+
+                let increment = match self.step {
+                    Some(step) if step > 0.0 => step,
+                    _ => span / 100.0,
+                };
+
+                Some(self.value + lines * increment)
+            }
+            _ => None,
+        };
+
+        if let Some(new_value) = new_value.map(|value| self.snap(value)) {
+            if (new_value - self.value).abs() > f32::EPSILON {
+                messages.push((self.on_change)(new_value));
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            self.range.clone(),
+            self.value,
+            self.state.is_dragging(),
+            &self.ticks,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        targets.push(focus::Target::new(
+            self.state,
+            layout.bounds(),
+            self.tab_index,
+            self.id.clone(),
+        ));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.state.is_focused {
+            return false;
+        }
+
+        let key_code = match event {
+            keyboard::Event::KeyPressed { key_code, .. } => key_code,
+            _ => return false,
+        };
+
+        match slider::value_after_key(
+            self.value,
+            &self.range,
+            self.step,
+            key_code,
+        ) {
+            Some(value) => {
+                if (value - self.value).abs() > f32::EPSILON {
+                    messages.push((self.on_change)(value));
+
+                    if let Some(on_release) = self.on_release.clone() {
+                        messages.push(on_release);
+                    }
+                }
+
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The renderer of a [`Knob`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Knob`] in your user interface.
+///
+/// [`Knob`]: struct.Knob.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default diameter of a [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    const DEFAULT_SIZE: u16;
+
+    /// Draws a [`Knob`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Knob`]
+    ///   * the current cursor position
+    ///   * the range of values of the [`Knob`]
+    ///   * the current value of the [`Knob`]
+    ///   * whether the [`Knob`] is being dragged
+    ///   * the values where tick marks should be drawn
+    ///   * the style of the [`Knob`]
+    ///
+    /// [`Knob`]: struct.Knob.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        range: RangeInclusive<f32>,
+        value: f32,
+        is_dragging: bool,
+        ticks: &[f32],
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Knob<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        knob: Knob<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(knob)
+    }
+}
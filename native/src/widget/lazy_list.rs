@@ -0,0 +1,465 @@
+//! Display a large amount of rows by only building the visible ones.
+//!
+//! A [`LazyList`] has some local [`State`].
+//!
+//! [`LazyList`]: struct.LazyList.html
+//! [`State`]: struct.State.html
+use crate::{
+    column, focus, keyboard, layout, mouse, scrollable, Clipboard, Element,
+    Event, Hasher, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+};
+
+use std::cell::RefCell;
+use std::hash::Hash;
+
+/// A scrollable list of rows with a fixed height that only builds and lays
+/// out the rows in the visible region.
+///
+/// The rows are produced on demand by a function receiving the index of the
+/// row, so a [`LazyList`] can display a huge amount of rows without building
+/// all of them. Rows that are just outside of the visible region are built as
+/// well, controlled by the [`overscan`].
+///
+/// # Example
+/// ```
+/// # use iced_native::{lazy_list, renderer::Null, Text};
+/// #
+/// # pub type LazyList<'a, Message> = iced_native::LazyList<'a, Message, Null>;
+/// let mut state = lazy_list::State::new();
+///
+/// let list = LazyList::<()>::new(&mut state, 50_000, 20, |index| {
+///     Text::new(format!("Row {}", index)).into()
+/// });
+/// ```
+///
+/// [`LazyList`]: struct.LazyList.html
+/// [`overscan`]: #method.overscan
+#[allow(missing_debug_implementations)]
+pub struct LazyList<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    rows: usize,
+    row_height: u16,
+    overscan: usize,
+    width: Length,
+    height: Length,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    laid_out: RefCell<Vec<Element<'a, Message, Renderer>>>,
+    style: <Renderer as scrollable::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> LazyList<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`LazyList`] with the given amount of rows, the height
+    /// of every row, and a function that produces the row at an index.
+    ///
+    /// [`LazyList`]: struct.LazyList.html
+    pub fn new<F>(
+        state: &'a mut State,
+        rows: usize,
+        row_height: u16,
+        view: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize) -> Element<'a, Message, Renderer>,
+    {
+        LazyList {
+            state,
+            rows,
+            row_height,
+            overscan: 5,
+            width: Length::Fill,
+            height: Length::Fill,
+            view: Box::new(view),
+            laid_out: RefCell::new(Vec::new()),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the amount of rows built above and below the visible region of
+    /// the [`LazyList`].
+    ///
+    /// By default, it is 5.
+    ///
+    /// [`LazyList`]: struct.LazyList.html
+    pub fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = rows;
+        self
+    }
+
+    /// Sets the width of the [`LazyList`].
+    ///
+    /// [`LazyList`]: struct.LazyList.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`LazyList`].
+    ///
+    /// [`LazyList`]: struct.LazyList.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`LazyList`].
+    ///
+    /// [`LazyList`]: struct.LazyList.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as scrollable::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Builds and lays out the rows that are visible with the given offset.
+    ///
+    /// The returned [`Node`] is relative to the content of the [`LazyList`].
+    ///
+    /// [`Node`]: ../../layout/struct.Node.html
+    /// [`LazyList`]: struct.LazyList.html
+    fn visible_rows(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        offset: u32,
+    ) -> (Vec<Element<'a, Message, Renderer>>, layout::Node) {
+        let row_height = f32::from(self.row_height.max(1));
+        let offset = offset as f32;
+
+        let first = (offset / row_height) as usize;
+        let last = ((offset + bounds.height) / row_height).ceil() as usize;
+
+        let size = Size::new(bounds.width, row_height);
+        let limits = layout::Limits::new(size, size);
+
+        let (rows, nodes) = (first.saturating_sub(self.overscan)
+            ..last.saturating_add(self.overscan).min(self.rows))
+            .map(|index| {
+                let row = (self.view)(index);
+
+                let mut node = row.layout(renderer, &limits);
+                node.move_to(Point::new(0.0, index as f32 * row_height));
+
+                (row, node)
+            })
+            .unzip();
+
+        (
+            rows,
+            layout::Node::with_children(
+                Size::new(bounds.width, self.content_height()),
+                nodes,
+            ),
+        )
+    }
+
+    /// Makes sure the rows laid out in the given content [`Layout`] are
+    /// built.
+    ///
+    /// The rows built during layout are reused. They are only built again
+    /// when the [`Layout`] was cached and this [`LazyList`] was never laid out.
+    ///
+    /// [`Layout`]: ../../layout/struct.Layout.html
+    /// [`LazyList`]: struct.LazyList.html
+    fn build_laid_out_rows(&self, content: Layout<'_>) {
+        let mut rows = self.laid_out.borrow_mut();
+
+        if rows.len() != content.children().count() {
+            let row_height = f32::from(self.row_height.max(1));
+            let top = content.bounds().y;
+
+            *rows = content
+                .children()
+                .map(|row| {
+                    let index = ((row.bounds().y - top) / row_height).round();
+
+                    (self.view)(index as usize)
+                })
+                .collect();
+        }
+    }
+
+    /// Returns the rows laid out in the given content [`Layout`].
+    ///
+    /// [`Layout`]: ../../layout/struct.Layout.html
+    fn laid_out_rows(
+        &mut self,
+        content: Layout<'_>,
+    ) -> &mut Vec<Element<'a, Message, Renderer>> {
+        self.build_laid_out_rows(content);
+        self.laid_out.get_mut()
+    }
+
+    fn content_height(&self) -> f32 {
+        self.rows as f32 * f32::from(self.row_height)
+    }
+}
+
+/// The local state of a [`LazyList`].
+///
+/// [`LazyList`]: struct.LazyList.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+}
+
+impl State {
+    /// Creates a new [`State`] with the scrollbar located at the top.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for LazyList<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::new(0.0, self.content_height()));
+
+        let bounds = Rectangle::new(Point::ORIGIN, size);
+        let offset = self.state.scrollable.offset(
+            bounds,
+            Rectangle::new(
+                Point::ORIGIN,
+                Size::new(size.width, self.content_height()),
+            ),
+        );
+
+        // Only the visible rows are laid out, so their widgets can be focused
+        let (rows, content) = self.visible_rows(renderer, bounds, offset);
+        let _ = self.laid_out.replace(rows);
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.rows.hash(state);
+        self.row_height.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+
+        // The visible rows change when scrolling
+        self.state
+            .scrollable
+            .offset(
+                Rectangle::default(),
+                Rectangle::new(
+                    Point::ORIGIN,
+                    Size::new(0.0, self.content_height()),
+                ),
+            )
+            .hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+        let content_bounds = content.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let state = &mut self.state.scrollable;
+
+        if is_mouse_over {
+            if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+                state.scroll_with_wheel(
+                    delta,
+                    scrollable::Direction::Vertical,
+                    bounds,
+                    content_bounds,
+                );
+            }
+        }
+
+        let offset = state.offset(bounds, content_bounds);
+        let scrollbar =
+            renderer.scrollbar(bounds, content_bounds, offset, &self.style);
+
+        let is_mouse_over_scrollbar = state.drag_scrollers(
+            &event,
+            cursor_position,
+            scrollbar.as_ref(),
+            None,
+            bounds,
+            content_bounds,
+        );
+
+        let offset = state.offset(bounds, content_bounds);
+
+        let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+            Point::new(cursor_position.x, cursor_position.y + offset as f32)
+        } else {
+            Point::new(cursor_position.x, -1.0)
+        };
+
+        // The rows keep the layout of this event, even if it scrolls
+        for (row, layout) in self
+            .laid_out_rows(content)
+            .iter_mut()
+            .zip(content.children())
+        {
+            row.on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let content_bounds = content_layout.bounds();
+
+        let offset = self.state.scrollable.offset(bounds, content_bounds);
+        let scrollbar =
+            renderer.scrollbar(bounds, content_bounds, offset, &self.style);
+
+        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over_scrollbar = scrollbar
+            .as_ref()
+            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+            .unwrap_or(false);
+
+        let content = {
+            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+                Point::new(cursor_position.x, cursor_position.y + offset as f32)
+            } else {
+                Point::new(cursor_position.x, -1.0)
+            };
+
+            self.build_laid_out_rows(content_layout);
+
+            column::Renderer::draw(
+                renderer,
+                defaults,
+                &self.laid_out.borrow(),
+                content_layout,
+                cursor_position,
+            )
+        };
+
+        scrollable::Renderer::draw(
+            renderer,
+            &self.state.scrollable,
+            bounds,
+            content_bounds,
+            is_mouse_over,
+            is_mouse_over_scrollbar,
+            scrollbar,
+            None,
+            Vector::new(0, offset),
+            &self.style,
+            content,
+        )
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+        let offset = self.state.scrollable.offset(bounds, content.bounds());
+        let translation = Vector::new(0.0, -(offset as f32));
+
+        let start = targets.len();
+
+        self.laid_out_rows(content)
+            .iter_mut()
+            .zip(content.children())
+            .for_each(|(row, layout)| row.focus_targets(layout, targets));
+
+        for target in &mut targets[start..] {
+            target.bounds = target.bounds + translation;
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        let content = layout.children().next().unwrap();
+        let rows = self.laid_out_rows(content);
+
+        let is_captured =
+            rows.iter_mut()
+                .zip(content.children())
+                .any(|(row, layout)| {
+                    row.on_focused_event(
+                        event, layout, messages, renderer, clipboard,
+                    )
+                });
+
+        is_captured
+    }
+}
+
+/// The renderer of a [`LazyList`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`LazyList`] in your user interface.
+///
+/// [`LazyList`]: struct.LazyList.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: scrollable::Renderer + column::Renderer {}
+
+impl<T> Renderer for T where T: scrollable::Renderer + column::Renderer {}
+
+impl<'a, Message, Renderer> From<LazyList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        lazy_list: LazyList<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(lazy_list)
+    }
+}
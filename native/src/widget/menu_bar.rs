@@ -0,0 +1,630 @@
+//! Build desktop-style menu bars with dropdown menus.
+//!
+//! A [`MenuBar`] has some local [`State`].
+//!
+//! [`MenuBar`]: struct.MenuBar.html
+//! [`State`]: struct.State.html
+use crate::context_menu::{self, Entry, Panels, Status};
+use crate::{
+    focus, keyboard, layout, mouse, overlay, Clipboard, Element, Event, Hasher,
+    Id, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A horizontal bar of menus, each one opening a dropdown of [`Entry`]
+/// values when clicked.
+///
+/// While a menu is open, hovering another title of the [`MenuBar`] opens its
+/// menu instead.
+///
+/// # Example
+/// ```
+/// # use iced_native::{menu_bar, renderer::Null};
+/// #
+/// # pub type MenuBar<'a, Message> = iced_native::MenuBar<'a, Message, Null>;
+/// use iced_native::context_menu::Entry;
+/// use iced_native::menu_bar::Menu;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Open,
+///     Save,
+///     Quit,
+///     Undo,
+/// }
+///
+/// let mut state = menu_bar::State::new();
+///
+/// let menu_bar = MenuBar::new(
+///     &mut state,
+///     vec![
+///         Menu::new(
+///             "File",
+///             vec![
+///                 Entry::item("Open", Message::Open).shortcut("Ctrl+O"),
+///                 Entry::item("Save", Message::Save)
+///                     .shortcut("Ctrl+S")
+///                     .disabled(),
+///                 Entry::Separator,
+///                 Entry::item("Quit", Message::Quit),
+///             ],
+///         ),
+///         Menu::new("Edit", vec![Entry::item("Undo", Message::Undo)]),
+///     ],
+/// );
+/// ```
+///
+/// [`Entry`]: ../context_menu/enum.Entry.html
+/// [`MenuBar`]: struct.MenuBar.html
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    tab_index: Option<u16>,
+    id: Option<Id>,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> MenuBar<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`MenuBar`] with the given [`State`] and menus.
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    /// [`State`]: struct.State.html
+    pub fn new(state: &'a mut State, menus: Vec<Menu<Message>>) -> Self {
+        MenuBar {
+            state,
+            menus,
+            width: Length::Fill,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            tab_index: None,
+            id: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the titles and the entries of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the position of the [`MenuBar`] in the focus order.
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`MenuBar`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// A menu of a [`MenuBar`]: a title and the entries shown when it is open.
+///
+/// [`MenuBar`]: struct.MenuBar.html
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    title: String,
+    entries: Vec<Entry<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates a new [`Menu`] with the given title and entries.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn new(title: impl Into<String>, entries: Vec<Entry<Message>>) -> Self {
+        Menu {
+            title: title.into(),
+            entries,
+        }
+    }
+}
+
+/// The local state of a [`MenuBar`].
+///
+/// [`MenuBar`]: struct.MenuBar.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    open_menu: Option<usize>,
+    open_submenus: Vec<usize>,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], with every menu closed.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the open menu of the [`MenuBar`], if any.
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn open_menu(&self) -> Option<usize> {
+        self.open_menu
+    }
+
+    /// Closes the open menu of the [`MenuBar`], if any.
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn close(&mut self) {
+        self.open_menu = None;
+        self.open_submenus.clear();
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MenuBar<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(Renderer::DEFAULT_SIZE);
+        let padding = f32::from(self.padding);
+        let height = f32::from(text_size + self.padding * 2);
+
+        let limits = limits
+            .width(self.width)
+            .height(Length::Units(text_size + self.padding * 2));
+
+        let mut x = 0.0;
+
+        let titles = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let width = renderer
+                    .measure_text(&menu.title, text_size, self.font)
+                    .width
+                    + padding * 2.0;
+
+                let mut title = layout::Node::new(Size::new(width, height));
+                title.move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                title
+            })
+            .collect();
+
+        layout::Node::with_children(
+            limits.resolve(Size::new(x, height)),
+            titles,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.menus.iter().for_each(|menu| menu.title.hash(state));
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        // Once a menu is open, its overlay handles the events of the titles
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if let Some(index) = layout
+                .children()
+                .position(|title| title.bounds().contains(cursor_position))
+            {
+                self.state.open_menu = Some(index);
+                self.state.open_submenus.clear();
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let titles: Vec<_> = layout
+            .children()
+            .zip(&self.menus)
+            .map(|(title, menu)| (title.bounds(), menu.title.as_str()))
+            .collect();
+
+        let highlighted_title = self.state.open_menu.or_else(|| {
+            titles
+                .iter()
+                .position(|(bounds, _)| bounds.contains(cursor_position))
+        });
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            &titles,
+            highlighted_title,
+            self.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        if !self.menus.is_empty() {
+            targets.push(focus::Target::new(
+                self.state,
+                layout.bounds(),
+                self.tab_index,
+                self.id.clone(),
+            ));
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.state.is_focused {
+            return false;
+        }
+
+        let key_code = match event {
+            keyboard::Event::KeyPressed { key_code, .. } => key_code,
+            _ => return false,
+        };
+
+        let menus = self.menus.len();
+
+        let open_menu = match (self.state.open_menu, key_code) {
+            (None, keyboard::KeyCode::Enter)
+            | (None, keyboard::KeyCode::Space)
+            | (None, keyboard::KeyCode::Down) => Some(0),
+            (Some(index), keyboard::KeyCode::Left) => {
+                Some((index + menus - 1) % menus)
+            }
+            (Some(index), keyboard::KeyCode::Right) => {
+                Some((index + 1) % menus)
+            }
+            (Some(_), keyboard::KeyCode::Escape) => None,
+            _ => return false,
+        };
+
+        self.state.open_menu = open_menu;
+        self.state.open_submenus.clear();
+
+        true
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let State {
+            open_menu,
+            open_submenus,
+            ..
+        } = &mut *self.state;
+
+        let menu = self.menus.get((*open_menu)?)?;
+
+        let bounds = layout.bounds();
+        let origin = Point::new(bounds.x, bounds.y);
+
+        let titles = layout
+            .children()
+            .map(|title| {
+                let title = title.bounds();
+
+                Rectangle {
+                    x: title.x - bounds.x,
+                    y: title.y - bounds.y,
+                    ..title
+                }
+            })
+            .collect();
+
+        Some(overlay::Element::new(
+            origin,
+            Box::new(Overlay {
+                panels: Panels {
+                    open_submenus,
+                    entries: &menu.entries,
+                    padding: self.padding,
+                    text_size: self.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+                    font: self.font,
+                },
+                open_menu,
+                bar_size: bounds.size(),
+                titles,
+                style: Renderer::menu_style(&self.style),
+            }),
+        ))
+    }
+}
+
+/// The open menu of a [`MenuBar`] and its submenus.
+///
+/// It covers the whole viewport, so clicking outside of the menu closes it
+/// without reaching the widgets below. It also keeps track of the titles of
+/// the [`MenuBar`], in order to switch menus when they are hovered.
+///
+/// [`MenuBar`]: struct.MenuBar.html
+struct Overlay<'a, Message, Renderer: self::Renderer> {
+    panels: Panels<'a, Message, Renderer>,
+    open_menu: &'a mut Option<usize>,
+    bar_size: Size,
+    titles: Vec<Rectangle>,
+    style: <Renderer as context_menu::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn open(&mut self, menu: Option<usize>) {
+        *self.open_menu = menu;
+        self.panels.open_submenus.clear();
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let titles = self
+            .titles
+            .iter()
+            .map(|title| {
+                let mut node = layout::Node::new(title.size());
+                node.move_to(Point::new(title.x, title.y));
+
+                node
+            })
+            .collect();
+
+        let mut bar = layout::Node::with_children(self.bar_size, titles);
+        bar.move_to(position);
+
+        let open_title = self
+            .open_menu
+            .and_then(|index| self.titles.get(index))
+            .map(|title| title.x)
+            .unwrap_or(0.0);
+
+        let mut nodes = vec![bar];
+
+        nodes.extend(self.panels.layout(
+            renderer,
+            bounds,
+            Point::new(
+                position.x + open_title,
+                position.y + self.bar_size.height,
+            ),
+        ));
+
+        layout::Node::with_children(bounds, nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+
+        let hovered_title = bar
+            .children()
+            .position(|title| title.bounds().contains(cursor_position));
+
+        if let Some(index) = hovered_title {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                    if *self.open_menu != Some(index) =>
+                {
+                    self.open(Some(index));
+                }
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                )) => {
+                    if *self.open_menu == Some(index) {
+                        self.open(None);
+                    } else {
+                        self.open(Some(index));
+                    }
+                }
+                _ => {}
+            }
+
+            return;
+        }
+
+        match self.panels.on_event(event, children, cursor_position) {
+            Some(Status::Selected(message)) => {
+                messages.push(message);
+                self.open(None);
+            }
+            Some(Status::Dismissed) => {
+                self.open(None);
+            }
+            None => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.panels.draw(
+            renderer,
+            layout.children().skip(1),
+            cursor_position,
+            &self.style,
+        )
+    }
+}
+
+/// The renderer of a [`MenuBar`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`MenuBar`] in your user interface.
+///
+/// [`MenuBar`]: struct.MenuBar.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: context_menu::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Returns the style of the open menus of a [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    fn menu_style(
+        style: &<Self as Renderer>::Style,
+    ) -> <Self as context_menu::Renderer>::Style;
+
+    /// Draws a [`MenuBar`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`MenuBar`]
+    /// - the bounds and the label of its titles
+    /// - the title that is hovered or whose menu is open, if any
+    /// - the text size and font of the titles
+    /// - the style of the [`MenuBar`]
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        titles: &[(Rectangle, &str)],
+        highlighted_title: Option<usize>,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<MenuBar<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        menu_bar: MenuBar<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(menu_bar)
+    }
+}
@@ -0,0 +1,315 @@
+//! Show content on top of a dimmed user interface.
+use crate::{
+    focus, keyboard, layout, mouse, overlay, Align, Clipboard, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+/// A widget showing some content centered on top of the rest of the user
+/// interface, which is dimmed by a backdrop.
+///
+/// While a [`Modal`] is open, the widgets below it do not receive any event.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Modal<'a, Message> = iced_native::Modal<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DialogClosed,
+/// }
+///
+/// let is_dialog_open = true;
+///
+/// let modal = Modal::new(
+///     is_dialog_open,
+///     Text::new("Some content"),
+///     Text::new("Are you sure?"),
+/// )
+/// .on_dismiss(Message::DialogClosed);
+/// ```
+///
+/// [`Modal`]: struct.Modal.html
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message, Renderer: self::Renderer> {
+    is_open: bool,
+    underlay: Element<'a, Message, Renderer>,
+    content: Element<'a, Message, Renderer>,
+    on_dismiss: Option<Message>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Modal`] showing the given content on top of the
+    /// underlay while it is open.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn new<U, C>(is_open: bool, underlay: U, content: C) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        Modal {
+            is_open,
+            underlay: underlay.into(),
+            content: content.into(),
+            on_dismiss: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Modal`] is
+    /// dismissed, either by pressing `Escape` or by clicking the backdrop.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Modal`].
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Modal<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.underlay.width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.underlay.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        // The underlay is blocked while the content is shown
+        if self.is_open {
+            return;
+        }
+
+        self.underlay.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.underlay
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.underlay.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.is_open {
+            return self.underlay.overlay(layout);
+        }
+
+        Some(overlay::Element::new(
+            Point::ORIGIN,
+            Box::new(Dialog {
+                content: &mut self.content,
+                on_dismiss: &self.on_dismiss,
+                style: &self.style,
+            }),
+        ))
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        // The dialog traps the focus while it is open
+        if !self.is_open {
+            self.underlay.focus_targets(layout, targets);
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        !self.is_open
+            && self
+                .underlay
+                .on_focused_event(event, layout, messages, renderer, clipboard)
+    }
+}
+
+/// The content of an open [`Modal`], covering the whole viewport.
+///
+/// [`Modal`]: struct.Modal.html
+struct Dialog<'a, 'b, Message, Renderer: self::Renderer> {
+    content: &'b mut Element<'a, Message, Renderer>,
+    on_dismiss: &'b Option<Message>,
+    style: &'b Renderer::Style,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Dialog<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        let mut content = self.content.layout(renderer, &limits);
+        content.align(Align::Center, Align::Center, bounds);
+
+        layout::Node::with_children(bounds, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let content_layout = layout.children().next().unwrap();
+
+        let is_dismissed = match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => true,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                !content_layout.bounds().contains(cursor_position)
+            }
+            _ => false,
+        };
+
+        if is_dismissed {
+            if let Some(on_dismiss) = self.on_dismiss {
+                messages.push(on_dismiss.clone());
+            }
+        }
+
+        self.content.on_event(
+            event,
+            content_layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            self.style,
+            self.content,
+            layout.children().next().unwrap(),
+        )
+    }
+}
+
+/// The renderer of a [`Modal`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Modal`] in your user interface.
+///
+/// [`Modal`]: struct.Modal.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the backdrop and the content of an open [`Modal`].
+    ///
+    /// It receives:
+    /// - the bounds of the backdrop, covering the whole viewport
+    /// - the cursor position
+    /// - the style of the [`Modal`]
+    /// - the content of the [`Modal`] and its layout
+    ///
+    /// [`Modal`]: struct.Modal.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        style: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        modal: Modal<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(modal)
+    }
+}
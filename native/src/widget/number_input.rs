@@ -0,0 +1,585 @@
+//! Let your users edit numbers with a field and a couple of step buttons.
+//!
+//! A [`NumberInput`] has some local [`State`].
+//!
+//! [`NumberInput`]: struct.NumberInput.html
+//! [`State`]: struct.State.html
+mod number;
+
+pub use number::Number;
+
+use crate::{
+    button, focus, keyboard, layout, mouse, row, text, text_input, Align,
+    Button, Clipboard, Element, Event, Hasher, Layout, Length, Point, Row,
+    Text, TextInput, Widget,
+};
+
+/// A field that can be filled with a number.
+///
+/// A [`NumberInput`] is made of a [`TextInput`] and two buttons that
+/// decrement and increment its value. It only produces valid numbers: a
+/// value out of bounds can be typed, but it is clamped once the field is
+/// submitted or loses focus.
+///
+/// # Example
+/// ```
+/// # use iced_native::{number_input, renderer::Null};
+/// #
+/// # pub type NumberInput<'a, T, Message> =
+/// #     iced_native::NumberInput<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     VolumeChanged(f32),
+/// }
+///
+/// let mut state = number_input::State::new();
+///
+/// let input = NumberInput::new(&mut state, 0.5, Message::VolumeChanged)
+///     .min(0.0)
+///     .max(1.0)
+///     .step(0.1)
+///     .decimal_separator(',');
+/// ```
+///
+/// [`NumberInput`]: struct.NumberInput.html
+/// [`TextInput`]: ../text_input/struct.TextInput.html
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    value: T,
+    constraints: Constraints<T>,
+    width: Length,
+    padding: u16,
+    size: Option<u16>,
+    on_change: Box<dyn Fn(T) -> Message>,
+    style: <Renderer as text_input::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Number,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - the current value
+    /// - a function that produces a message when the value changes
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(state: &'a mut State, value: T, on_change: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        NumberInput {
+            state,
+            value,
+            constraints: Constraints {
+                min: None,
+                max: None,
+                step: T::ONE,
+                decimal_separator: '.',
+            },
+            width: Length::Fill,
+            padding: 0,
+            size: None,
+            on_change: Box::new(on_change),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn min(mut self, min: T) -> Self {
+        self.constraints.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn max(mut self, max: T) -> Self {
+        self.constraints.max = Some(max);
+        self
+    }
+
+    /// Sets the amount added or subtracted by the step buttons of the
+    /// [`NumberInput`].
+    ///
+    /// By default, it is one.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn step(mut self, step: T) -> Self {
+        self.constraints.step = step;
+        self
+    }
+
+    /// Sets the character separating the integer and fractional parts of
+    /// the [`NumberInput`].
+    ///
+    /// By default, it is a dot ('.'). Use a comma (',') for locales that
+    /// expect it.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.constraints.decimal_separator = separator;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the field and the buttons of the
+    /// [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the field of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as text_input::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The state of a [`NumberInput`].
+///
+/// [`NumberInput`]: struct.NumberInput.html
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    input: text_input::State,
+    decrement: button::State,
+    increment: button::State,
+    text: Option<String>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`NumberInput`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`NumberInput`] is currently focused or not.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+}
+
+/// The renderer of a [`NumberInput`].
+///
+/// It is implemented for every renderer supporting its [`TextInput`] and
+/// step buttons.
+///
+/// [`NumberInput`]: struct.NumberInput.html
+/// [`TextInput`]: ../text_input/struct.TextInput.html
+pub trait Renderer:
+    text_input::Renderer + button::Renderer + row::Renderer + text::Renderer
+{
+}
+
+impl<T> Renderer for T where
+    T: text_input::Renderer + button::Renderer + row::Renderer + text::Renderer
+{
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Number,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        let NumberInput {
+            state,
+            value,
+            constraints,
+            width,
+            padding,
+            size,
+            on_change,
+            style,
+        } = number_input;
+
+        let State {
+            input,
+            decrement,
+            increment,
+            text,
+        } = state;
+
+        let contents =
+            text.clone().unwrap_or_else(|| constraints.format(value));
+
+        let mut input = TextInput::new(input, "", &contents, Internal::Changed)
+            .on_submit(Internal::Submitted)
+            .padding(padding)
+            .style(style);
+
+        let mut decrement =
+            Button::new(decrement, label("-", size)).padding(padding);
+
+        let mut increment =
+            Button::new(increment, label("+", size)).padding(padding);
+
+        if let Some(size) = size {
+            input = input.size(size);
+        }
+
+        if constraints.min.map(|min| value > min).unwrap_or(true) {
+            decrement = decrement.on_press(Internal::Decremented);
+        }
+
+        if constraints.max.map(|max| value < max).unwrap_or(true) {
+            increment = increment.on_press(Internal::Incremented);
+        }
+
+        let content = Row::new()
+            .width(width)
+            .spacing(5)
+            .align_items(Align::Center)
+            .push(input)
+            .push(decrement)
+            .push(increment);
+
+        Element::new(Content {
+            content: content.into(),
+            text,
+            value,
+            constraints,
+            on_change,
+        })
+    }
+}
+
+fn label<'a, Renderer>(
+    label: &str,
+    size: Option<u16>,
+) -> Element<'a, Internal, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+{
+    let text = Text::new(label);
+
+    match size {
+        Some(size) => text.size(size).into(),
+        None => text.into(),
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Internal {
+    Changed(String),
+    Submitted,
+    Decremented,
+    Incremented,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Constraints<T> {
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    decimal_separator: char,
+}
+
+impl<T: Number> Constraints<T> {
+    fn format(&self, value: T) -> String {
+        let value = value.to_string();
+
+        if T::IS_INTEGER {
+            value
+        } else {
+            value.replace('.', &self.decimal_separator.to_string())
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<T> {
+        text.replace(self.decimal_separator, ".").parse().ok()
+    }
+
+    /// Returns whether the given text could become a number after some
+    /// more typing.
+    fn accepts(&self, text: &str) -> bool {
+        let mut has_separator = false;
+
+        text.chars().enumerate().all(|(i, c)| {
+            if c.is_ascii_digit() {
+                true
+            } else if c == '-' {
+                i == 0 && T::IS_SIGNED
+            } else if c == self.decimal_separator
+                && !T::IS_INTEGER
+                && !has_separator
+            {
+                has_separator = true;
+                true
+            } else {
+                false
+            }
+        })
+    }
+
+    fn contains(&self, value: T) -> bool {
+        self.min.map(|min| value >= min).unwrap_or(true)
+            && self.max.map(|max| value <= max).unwrap_or(true)
+    }
+
+    fn clamp(&self, value: T) -> T {
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        }
+    }
+}
+
+struct Content<'a, T, Message, Renderer> {
+    content: Element<'a, Internal, Renderer>,
+    text: &'a mut Option<String>,
+    value: T,
+    constraints: Constraints<T>,
+    on_change: Box<dyn Fn(T) -> Message>,
+}
+
+impl<'a, T, Message, Renderer> Content<'a, T, Message, Renderer>
+where
+    T: Number,
+{
+    fn change(&mut self, value: T, messages: &mut Vec<Message>) {
+        self.value = value;
+
+        messages.push((self.on_change)(value));
+    }
+
+    /// Replaces the text being edited with the closest valid value.
+    fn commit(&mut self, messages: &mut Vec<Message>) {
+        if let Some(text) = self.text.take() {
+            if let Some(value) = self.constraints.parse(&text) {
+                let value = self.constraints.clamp(value);
+
+                if value != self.value {
+                    self.change(value, messages);
+                }
+            }
+        }
+    }
+
+    /// Processes the messages of the inner widgets.
+    fn process(
+        &mut self,
+        internal: Vec<Internal>,
+        messages: &mut Vec<Message>,
+    ) {
+        for message in internal {
+            match message {
+                Internal::Changed(text) => {
+                    if !self.constraints.accepts(&text) {
+                        continue;
+                    }
+
+                    if let Some(value) = self.constraints.parse(&text) {
+                        if self.constraints.contains(value) {
+                            self.change(value, messages);
+                        }
+                    }
+
+                    *self.text = Some(text);
+                }
+                Internal::Submitted => {
+                    self.commit(messages);
+                }
+                Internal::Decremented => {
+                    self.commit(messages);
+
+                    let value = self.constraints.clamp(
+                        self.value.saturating_sub(self.constraints.step),
+                    );
+
+                    self.change(value, messages);
+                }
+                Internal::Incremented => {
+                    self.commit(messages);
+
+                    let value = self.constraints.clamp(
+                        self.value.saturating_add(self.constraints.step),
+                    );
+
+                    self.change(value, messages);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for Content<'a, T, Message, Renderer>
+where
+    T: Number,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            let input = layout.children().next().unwrap();
+
+            if !input.bounds().contains(cursor_position) {
+                self.commit(messages);
+            }
+        }
+
+        let mut internal = Vec::new();
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            &mut internal,
+            renderer,
+            clipboard,
+        );
+
+        self.process(internal, messages);
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.content.hash_layout(state);
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.content.focus_targets(layout, targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        let mut internal = Vec::new();
+
+        let is_captured = self.content.on_focused_event(
+            event,
+            layout,
+            &mut internal,
+            renderer,
+            clipboard,
+        );
+
+        self.process(internal, messages);
+
+        is_captured
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_separator_is_used_to_parse_and_format() {
+        let constraints = Constraints {
+            min: Some(-1.0),
+            max: Some(10.0),
+            step: 0.5,
+            decimal_separator: ',',
+        };
+
+        assert_eq!(constraints.format(2.5), "2,5");
+        assert_eq!(constraints.parse("2,5"), Some(2.5));
+        assert_eq!(constraints.clamp(20.0), 10.0);
+
+        assert!(constraints.accepts("-0,"));
+        assert!(!constraints.accepts("0,5,"));
+        assert!(!constraints.accepts("1.5"));
+        assert!(!constraints.accepts("1-"));
+    }
+
+    #[test]
+    fn integers_reject_separators_and_signs() {
+        let constraints = Constraints {
+            min: None,
+            max: None,
+            step: 1u8,
+            decimal_separator: '.',
+        };
+
+        assert!(constraints.accepts("42"));
+        assert!(!constraints.accepts("4.2"));
+        assert!(!constraints.accepts("-4"));
+        assert_eq!(constraints.parse("300"), None);
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A number that can be edited with a [`NumberInput`].
+///
+/// It is implemented for all the primitive integer and floating point types.
+///
+/// [`NumberInput`]: struct.NumberInput.html
+pub trait Number: Copy + PartialOrd + fmt::Display + FromStr {
+    /// Whether the number can only hold integer values.
+    const IS_INTEGER: bool;
+
+    /// Whether the number can hold negative values.
+    const IS_SIGNED: bool;
+
+    /// The default amount added or subtracted by the step buttons.
+    const ONE: Self;
+
+    /// Adds two numbers, saturating at the numeric bounds.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Subtracts two numbers, saturating at the numeric bounds.
+    fn saturating_sub(self, other: Self) -> Self;
+}
+
+macro_rules! integer {
+    ($signed:expr, $($type:ty),*) => {
+        $(
+            impl Number for $type {
+                const IS_INTEGER: bool = true;
+                const IS_SIGNED: bool = $signed;
+                const ONE: Self = 1;
+
+                fn saturating_add(self, other: Self) -> Self {
+                    <$type>::saturating_add(self, other)
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$type>::saturating_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float {
+    ($($type:ty),*) => {
+        $(
+            impl Number for $type {
+                const IS_INTEGER: bool = false;
+                const IS_SIGNED: bool = true;
+                const ONE: Self = 1.0;
+
+                fn saturating_add(self, other: Self) -> Self {
+                    self + other
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    self - other
+                }
+            }
+        )*
+    };
+}
+
+integer!(true, i8, i16, i32, i64, i128, isize);
+integer!(false, u8, u16, u32, u64, u128, usize);
+float!(f32, f64);
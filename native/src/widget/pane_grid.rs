@@ -25,8 +25,8 @@ pub use split::Split;
 pub use state::{Focus, State};
 
 use crate::{
-    keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length,
-    Point, Size, Widget,
+    focus, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Size, Widget,
 };
 
 /// A collection of panes distributed using either vertical or horizontal splits
@@ -44,6 +44,7 @@ use crate::{
 /// * Tracking of the last active pane
 /// * Mouse-based resizing
 /// * Drag and drop to reorganize panes
+/// * Maximizing and restoring a pane
 /// * Hotkey support
 /// * Configurable modifier keys
 /// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -99,7 +100,7 @@ impl<'a, Message, Renderer> PaneGrid<'a, Message, Renderer> {
     /// Creates a [`PaneGrid`] with the given [`State`] and view function.
     ///
     /// The view function will be called to display each [`Pane`] present in the
-    /// [`State`].
+    /// [`State`]. If a [`Pane`] is maximized, only that one will be displayed.
     ///
     /// [`PaneGrid`]: struct.PaneGrid.html
     /// [`State`]: struct.State.html
@@ -115,10 +116,16 @@ impl<'a, Message, Renderer> PaneGrid<'a, Message, Renderer> {
         let elements = {
             let action = state.internal.action();
             let current_focus = action.focus();
+            let maximized = state.internal.maximized();
 
             state
                 .panes
                 .iter_mut()
+                .filter(move |(pane, _)| {
+                    maximized
+                        .map(|maximized| **pane == maximized)
+                        .unwrap_or(true)
+                })
                 .map(move |(pane, pane_state)| {
                     let focus = match current_focus {
                         Some((focused_pane, focus))
@@ -539,36 +546,32 @@ where
                 }
                 _ => {}
             },
-            Event::Keyboard(keyboard_event) => {
-                match keyboard_event {
-                    keyboard::Event::KeyPressed {
-                        modifiers,
-                        key_code,
-                    } => {
-                        if let Some(on_key_press) = &self.on_key_press {
-                            // TODO: Discard when event is captured
-                            if let Some(_) = self.state.active_pane() {
-                                if modifiers.matches(self.modifier_keys) {
-                                    if let Some(message) =
-                                        on_key_press(KeyPressEvent {
-                                            key_code,
-                                            modifiers,
-                                        })
-                                    {
-                                        messages.push(message);
-                                    }
-                                }
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed {
+                    modifiers,
+                    key_code,
+                } => {
+                    if let Some(on_key_press) = &self.on_key_press {
+                        if self.state.active_pane().is_some()
+                            && modifiers.matches(self.modifier_keys)
+                        {
+                            if let Some(message) = on_key_press(KeyPressEvent {
+                                key_code,
+                                modifiers,
+                            }) {
+                                messages.push(message);
                             }
                         }
-
-                        *self.pressed_modifiers = modifiers;
-                    }
-                    keyboard::Event::KeyReleased { modifiers, .. } => {
-                        *self.pressed_modifiers = modifiers;
                     }
-                    _ => {}
+
+                    *self.pressed_modifiers = modifiers;
                 }
-            }
+                keyboard::Event::KeyReleased { modifiers, .. }
+                | keyboard::Event::ModifiersChanged(modifiers) => {
+                    *self.pressed_modifiers = modifiers;
+                }
+                _ => {}
+            },
             _ => {}
         }
 
@@ -620,6 +623,33 @@ where
             element.hash_layout(state);
         }
     }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.elements.iter_mut().zip(layout.children()).for_each(
+            |((_, pane), layout)| pane.focus_targets(layout, targets),
+        );
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.elements.iter_mut().zip(layout.children()).any(
+            |((_, pane), layout)| {
+                pane.on_focused_event(
+                    event, layout, messages, renderer, clipboard,
+                )
+            },
+        )
+    }
 }
 
 /// The renderer of a [`PaneGrid`].
@@ -72,6 +72,7 @@ impl<T> State<T> {
                 layout,
                 last_id,
                 action: Action::Idle { focus: None },
+                maximized: None,
             },
             modifiers: keyboard::ModifiersState::default(),
         }
@@ -194,6 +195,8 @@ impl<T> State<T> {
     /// Splits the given [`Pane`] into two in the given [`Axis`] and
     /// initializing the new [`Pane`] with the provided internal state.
     ///
+    /// If a [`Pane`] is maximized, the layout is restored.
+    ///
     /// [`Pane`]: struct.Pane.html
     /// [`Axis`]: enum.Axis.html
     pub fn split(
@@ -218,6 +221,8 @@ impl<T> State<T> {
 
         node.split(new_split, axis, new_pane);
 
+        self.restore();
+
         let _ = self.panes.insert(new_pane, state);
         self.focus(&new_pane);
 
@@ -260,10 +265,48 @@ impl<T> State<T> {
         let _ = self.internal.layout.resize(split, ratio);
     }
 
+    /// Maximizes the given [`Pane`], making it fill the whole [`PaneGrid`]
+    /// and hiding the rest of panes until [`restore`] is called.
+    ///
+    /// The maximized [`Pane`] is focused.
+    ///
+    /// [`Pane`]: struct.Pane.html
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    /// [`restore`]: #method.restore
+    pub fn maximize(&mut self, pane: &Pane) {
+        if self.panes.contains_key(pane) {
+            self.internal.maximized = Some(*pane);
+            self.focus(pane);
+        }
+    }
+
+    /// Restores the layout of the [`State`] after a [`Pane`] has been
+    /// maximized.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Pane`]: struct.Pane.html
+    pub fn restore(&mut self) {
+        self.internal.maximized = None;
+    }
+
+    /// Returns the maximized [`Pane`] of the [`State`], if there is one.
+    ///
+    /// [`Pane`]: struct.Pane.html
+    /// [`State`]: struct.State.html
+    pub fn maximized(&self) -> Option<Pane> {
+        self.internal.maximized
+    }
+
     /// Closes the given [`Pane`] and returns its internal state, if it exists.
     ///
+    /// If the [`Pane`] is maximized, the layout is restored.
+    ///
     /// [`Pane`]: struct.Pane.html
     pub fn close(&mut self, pane: &Pane) -> Option<T> {
+        if self.internal.maximized == Some(*pane) {
+            self.restore();
+        }
+
         if let Some(sibling) = self.internal.layout.remove(pane) {
             self.focus(&sibling);
             self.panes.remove(pane)
@@ -308,6 +351,7 @@ pub struct Internal {
     layout: Node,
     last_id: usize,
     action: Action,
+    maximized: Option<Pane>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -362,12 +406,32 @@ impl Internal {
         }
     }
 
+    pub fn maximized(&self) -> Option<Pane> {
+        self.maximized
+    }
+
     pub fn regions(
         &self,
         spacing: f32,
         size: Size,
     ) -> HashMap<Pane, Rectangle> {
-        self.layout.regions(spacing, size)
+        match self.maximized {
+            Some(pane) => {
+                let mut regions = HashMap::new();
+                let _ = regions.insert(
+                    pane,
+                    Rectangle {
+                        x: 0.0,
+                        y: 0.0,
+                        width: size.width,
+                        height: size.height,
+                    },
+                );
+
+                regions
+            }
+            None => self.layout.regions(spacing, size),
+        }
     }
 
     pub fn splits(
@@ -375,6 +439,11 @@ impl Internal {
         spacing: f32,
         size: Size,
     ) -> HashMap<Split, (Axis, Rectangle, f32)> {
+        // Splits are hidden while a pane is maximized
+        if self.maximized.is_some() {
+            return HashMap::new();
+        }
+
         self.layout.splits(spacing, size)
     }
 
@@ -419,5 +488,6 @@ impl Internal {
         use std::hash::Hash;
 
         self.layout.hash(hasher);
+        self.maximized.hash(hasher);
     }
 }
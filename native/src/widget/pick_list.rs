@@ -0,0 +1,494 @@
+//! Display a dropdown list of selectable values.
+//!
+//! A [`PickList`] has some local [`State`].
+//!
+//! [`PickList`]: struct.PickList.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse,
+    overlay::{self, menu, Menu},
+    text, Clipboard, Element, Event, Hasher, Id, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+use std::borrow::Cow;
+use std::hash::Hash;
+
+/// A widget for selecting a single value from a list of options.
+///
+/// The selected value is shown in the [`PickList`], and clicking it opens a
+/// [`Menu`] with every option. The options can also be navigated with the
+/// arrow keys and searched by typing while the [`Menu`] is open.
+///
+/// # Example
+/// ```
+/// # use iced_native::{pick_list, renderer::Null};
+/// #
+/// # pub type PickList<'a, T, Message> =
+/// #     iced_native::PickList<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LanguagePicked(&'static str),
+/// }
+///
+/// const LANGUAGES: [&str; 3] = ["Rust", "Elm", "Haskell"];
+///
+/// let mut state = pick_list::State::default();
+///
+/// let pick_list = PickList::new(
+///     &mut state,
+///     &LANGUAGES[..],
+///     Some("Rust"),
+///     Message::LanguagePicked,
+/// );
+/// ```
+///
+/// [`PickList`]: struct.PickList.html
+/// [`Menu`]: ../../overlay/menu/struct.Menu.html
+#[allow(missing_debug_implementations)]
+pub struct PickList<'a, T, Message, Renderer: self::Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    menu: &'a mut menu::State,
+    last_selection: &'a mut Option<T>,
+    focus: &'a mut Focus,
+    on_selected: Box<dyn Fn(T) -> Message>,
+    options: Cow<'a, [T]>,
+    selected: Option<T>,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    tab_index: Option<u16>,
+    id: Option<Id>,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+/// The local state of a [`PickList`].
+///
+/// [`PickList`]: struct.PickList.html
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    menu: menu::State,
+    last_selection: Option<T>,
+    focus: Focus,
+}
+
+/// The focus state of a [`PickList`].
+///
+/// [`PickList`]: struct.PickList.html
+#[derive(Debug, Clone, Copy, Default)]
+struct Focus {
+    is_focused: bool,
+}
+
+impl focus::Focusable for Focus {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<T> State<T> {
+    /// Returns whether the [`Menu`] of the [`PickList`] is open or not.
+    ///
+    /// [`Menu`]: ../../overlay/menu/struct.Menu.html
+    /// [`PickList`]: struct.PickList.html
+    pub fn is_open(&self) -> bool {
+        self.menu.is_open()
+    }
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            menu: menu::State::default(),
+            last_selection: None,
+            focus: Focus::default(),
+        }
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer: self::Renderer>
+    PickList<'a, T, Message, Renderer>
+where
+    T: ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    /// Creates a new [`PickList`] with the given [`State`], a list of options,
+    /// the current selected value, and the message to produce when an option
+    /// is selected.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`State`]: struct.State.html
+    pub fn new(
+        state: &'a mut State<T>,
+        options: impl Into<Cow<'a, [T]>>,
+        selected: Option<T>,
+        on_selected: impl Fn(T) -> Message + 'static,
+    ) -> Self {
+        let State {
+            menu,
+            last_selection,
+            focus,
+        } = state;
+
+        Self {
+            menu,
+            last_selection,
+            focus,
+            on_selected: Box::new(on_selected),
+            options: options.into(),
+            selected,
+            width: Length::Shrink,
+            text_size: None,
+            padding: Renderer::DEFAULT_PADDING,
+            font: Default::default(),
+            tab_index: None,
+            id: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`PickList`] and its options.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the position of the [`PickList`] in the focus order.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`PickList`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`PickList`]: struct.PickList.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
+    for PickList<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: self::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(Length::Shrink)
+            .pad(f32::from(self.padding));
+
+        let text_size = self.text_size.unwrap_or(Renderer::DEFAULT_SIZE);
+
+        let max_width = match self.width {
+            Length::Shrink => self
+                .options
+                .iter()
+                .map(|option| {
+                    let (width, _) = renderer.measure(
+                        &option.to_string(),
+                        text_size,
+                        self.font,
+                        Size::INFINITY,
+                    );
+
+                    width.round() as u32
+                })
+                .max()
+                .unwrap_or(100),
+            _ => 0,
+        };
+
+        let size = {
+            let intrinsic = Size::new(
+                max_width as f32
+                    + f32::from(text_size)
+                    + f32::from(self.padding),
+                f32::from(text_size),
+            );
+
+            limits.resolve(intrinsic).pad(f32::from(self.padding))
+        };
+
+        layout::Node::new(size)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        match self.width {
+            Length::Shrink => {
+                self.options
+                    .iter()
+                    .map(ToString::to_string)
+                    .for_each(|label| label.hash(state));
+            }
+            _ => {
+                self.width.hash(state);
+            }
+        }
+
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if self.menu.is_open() {
+                // Clicks on the options are processed by the menu first, so
+                // any other click closes it
+                self.menu.close();
+            } else if layout.bounds().contains(cursor_position) {
+                let selected = self.selected.as_ref();
+
+                self.menu.open(
+                    self.options
+                        .iter()
+                        .position(|option| Some(option) == selected),
+                );
+            }
+        }
+
+        if let Some(selection) = self.last_selection.take() {
+            messages.push((self.on_selected)(selection));
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            self.selected.as_ref().map(ToString::to_string),
+            self.padding,
+            self.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        targets.push(focus::Target::new(
+            self.focus,
+            layout.bounds(),
+            self.tab_index,
+            self.id.clone(),
+        ));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        // The menu handles the keyboard by itself while it is open
+        if !self.focus.is_focused || self.menu.is_open() {
+            return false;
+        }
+
+        let key_code = match event {
+            keyboard::Event::KeyPressed { key_code, .. } => key_code,
+            _ => return false,
+        };
+
+        let selected = self.selected.as_ref();
+        let current = self
+            .options
+            .iter()
+            .position(|option| Some(option) == selected);
+
+        match key_code {
+            keyboard::KeyCode::Enter | keyboard::KeyCode::Space => {
+                self.menu.open(current);
+
+                true
+            }
+            keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                let next = match (current, key_code) {
+                    (Some(0), keyboard::KeyCode::Up) => None,
+                    (Some(current), keyboard::KeyCode::Up) => Some(current - 1),
+                    (Some(current), _) => Some(current + 1),
+                    (None, _) => Some(0),
+                };
+
+                if let Some(option) =
+                    next.and_then(|next| self.options.get(next))
+                {
+                    messages.push((self.on_selected)(option.clone()));
+                }
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.menu.is_open() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut menu = Menu::new(self.menu, &self.options, self.last_selection)
+            .width(bounds.width.round() as u16)
+            .padding(self.padding)
+            .font(self.font)
+            .style(Renderer::menu_style(&self.style));
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(Point::new(bounds.x, bounds.y), bounds.height))
+    }
+}
+
+/// The renderer of a [`PickList`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`PickList`] in your user interface.
+///
+/// [`PickList`]: struct.PickList.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer + menu::Renderer {
+    /// The default padding of a [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    const DEFAULT_PADDING: u16;
+
+    /// The [`PickList`] style supported by this renderer.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    type Style: Default;
+
+    /// Returns the style of the [`Menu`] of the [`PickList`].
+    ///
+    /// [`Menu`]: ../../overlay/menu/struct.Menu.html
+    /// [`PickList`]: struct.PickList.html
+    fn menu_style(
+        style: &<Self as Renderer>::Style,
+    ) -> <Self as menu::Renderer>::Style;
+
+    /// Draws a [`PickList`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        selected: Option<String>,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, T: 'a, Message, Renderer> From<PickList<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a,
+    Renderer: self::Renderer + 'a,
+{
+    fn from(
+        pick_list: PickList<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(pick_list)
+    }
+}
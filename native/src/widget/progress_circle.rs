@@ -0,0 +1,152 @@
+//! Provide circular progress feedback to your users.
+use crate::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::{hash::Hash, ops::RangeInclusive};
+
+/// A ring that fills clockwise to display progress.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type ProgressCircle = iced_native::ProgressCircle<Null>;
+/// let value = 50.0;
+///
+/// ProgressCircle::new(0.0..=100.0, value).size(64);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ProgressCircle<Renderer: self::Renderer> {
+    range: RangeInclusive<f32>,
+    value: f32,
+    size: Option<u16>,
+    style: Renderer::Style,
+}
+
+impl<Renderer: self::Renderer> ProgressCircle<Renderer> {
+    /// Creates a new [`ProgressCircle`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`ProgressCircle`]
+    ///
+    /// [`ProgressCircle`]: struct.ProgressCircle.html
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        ProgressCircle {
+            value: value.max(*range.start()).min(*range.end()),
+            range,
+            size: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the diameter of the [`ProgressCircle`].
+    ///
+    /// [`ProgressCircle`]: struct.ProgressCircle.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`ProgressCircle`].
+    ///
+    /// [`ProgressCircle`]: struct.ProgressCircle.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for ProgressCircle<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE))
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE))
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE));
+        let limits = limits.width(size).height(size);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            self.range.clone(),
+            self.value,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of a [`ProgressCircle`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`ProgressCircle`] in your user interface.
+///
+/// [`ProgressCircle`]: struct.ProgressCircle.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default diameter of a [`ProgressCircle`].
+    ///
+    /// [`ProgressCircle`]: struct.ProgressCircle.html
+    const DEFAULT_SIZE: u16;
+
+    /// Draws a [`ProgressCircle`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ProgressCircle`]
+    ///   * the range of values of the [`ProgressCircle`]
+    ///   * the current value of the [`ProgressCircle`]
+    ///   * the style of the [`ProgressCircle`]
+    ///
+    /// [`ProgressCircle`]: struct.ProgressCircle.html
+    fn draw(
+        &self,
+        bounds: Rectangle,
+        range: RangeInclusive<f32>,
+        value: f32,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ProgressCircle<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        progress_circle: ProgressCircle<Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(progress_circle)
+    }
+}
@@ -1,8 +1,13 @@
 //! Create choices using radio buttons.
+//!
+//! A [`Radio`] has some local [`State`].
+//!
+//! [`Radio`]: struct.Radio.html
+//! [`State`]: struct.State.html
 use crate::{
-    layout, mouse, row, text, Align, Clipboard, Element, Event, Hasher,
-    HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
-    VerticalAlignment, Widget,
+    focus, keyboard, layout, mouse, row, text, Align, Clipboard, Element,
+    Event, Hasher, HorizontalAlignment, Id, Layout, Length, Point, Rectangle,
+    Row, Text, VerticalAlignment, Widget,
 };
 
 use std::hash::Hash;
@@ -11,8 +16,9 @@ use std::hash::Hash;
 ///
 /// # Example
 /// ```
-/// # type Radio<Message> =
-/// #     iced_native::Radio<Message, iced_native::renderer::Null>;
+/// # use iced_native::radio;
+/// # type Radio<'a, Message> =
+/// #     iced_native::Radio<'a, Message, iced_native::renderer::Null>;
 /// #
 /// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// pub enum Choice {
@@ -25,32 +31,39 @@ use std::hash::Hash;
 ///     RadioSelected(Choice),
 /// }
 ///
+/// let mut a = radio::State::new();
+/// let mut b = radio::State::new();
 /// let selected_choice = Some(Choice::A);
 ///
-/// Radio::new(Choice::A, "This is A", selected_choice, Message::RadioSelected);
+/// Radio::new(&mut a, Choice::A, "This is A", selected_choice, Message::RadioSelected);
 ///
-/// Radio::new(Choice::B, "This is B", selected_choice, Message::RadioSelected);
+/// Radio::new(&mut b, Choice::B, "This is B", selected_choice, Message::RadioSelected);
 /// ```
 ///
 /// ![Radio buttons drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/radio.png?raw=true)
 #[allow(missing_debug_implementations)]
-pub struct Radio<Message, Renderer: self::Renderer + text::Renderer> {
+pub struct Radio<'a, Message, Renderer: self::Renderer + text::Renderer> {
+    state: &'a mut State,
     is_selected: bool,
+    is_enabled: bool,
     on_click: Message,
     label: String,
     width: Length,
     size: u16,
     spacing: u16,
     text_size: u16,
+    tab_index: Option<u16>,
+    id: Option<Id>,
     style: Renderer::Style,
 }
 
-impl<Message, Renderer: self::Renderer + text::Renderer>
-    Radio<Message, Renderer>
+impl<'a, Message, Renderer: self::Renderer + text::Renderer>
+    Radio<'a, Message, Renderer>
 {
     /// Creates a new [`Radio`] button.
     ///
     /// It expects:
+    ///   * the local [`State`] of the [`Radio`] button
     ///   * the value related to the [`Radio`] button
     ///   * the label of the [`Radio`] button
     ///   * the current selected value
@@ -58,7 +71,9 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
     ///   receives the value of the radio and must produce a `Message`.
     ///
     /// [`Radio`]: struct.Radio.html
+    /// [`State`]: struct.State.html
     pub fn new<F, V>(
+        state: &'a mut State,
         value: V,
         label: impl Into<String>,
         selected: Option<V>,
@@ -69,13 +84,17 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         F: 'static + Fn(V) -> Message,
     {
         Radio {
+            state,
             is_selected: Some(value) == selected,
+            is_enabled: true,
             on_click: f(value),
             label: label.into(),
             width: Length::Shrink,
             size: <Renderer as self::Renderer>::DEFAULT_SIZE,
             spacing: Renderer::DEFAULT_SPACING, //15
             text_size: <Renderer as text::Renderer>::DEFAULT_SIZE,
+            tab_index: None,
+            id: None,
             style: Renderer::Style::default(),
         }
     }
@@ -112,6 +131,33 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self
     }
 
+    /// Disables the [`Radio`] button, which is then shown but cannot be
+    /// selected.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn disabled(mut self) -> Self {
+        self.is_enabled = false;
+        self
+    }
+
+    /// Sets the position of the [`Radio`] in the focus order.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Radio`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`Radio`]: struct.Radio.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`Radio`] button.
     ///
     /// [`Radio`]: struct.Radio.html
@@ -121,7 +167,39 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
     }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message, Renderer>
+/// The local state of a [`Radio`].
+///
+/// [`Radio`]: struct.Radio.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Radio<'a, Message, Renderer>
 where
     Renderer: self::Renderer + text::Renderer + row::Renderer,
     Message: Clone,
@@ -165,6 +243,10 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) {
+        if !self.is_enabled {
+            return;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if layout.bounds().contains(cursor_position) {
@@ -208,6 +290,7 @@ where
             radio_bounds,
             self.is_selected,
             is_mouse_over,
+            !self.is_enabled,
             label,
             &self.style,
         )
@@ -219,6 +302,47 @@ where
 
         self.label.hash(state);
     }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        // Disabled radio buttons cannot be focused
+        if self.is_enabled {
+            targets.push(focus::Target::new(
+                self.state,
+                layout.bounds(),
+                self.tab_index,
+                self.id.clone(),
+            ));
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.state.is_focused {
+            return false;
+        }
+
+        match event {
+            keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            } => {
+                messages.push(self.on_click.clone());
+
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 /// The renderer of a [`Radio`] button.
@@ -248,6 +372,7 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Radio`]
     ///   * whether the [`Radio`] is selected or not
     ///   * whether the mouse is over the [`Radio`] or not
+    ///   * whether the [`Radio`] is disabled or not
     ///   * the drawn label of the [`Radio`]
     ///
     /// [`Radio`]: struct.Radio.html
@@ -256,18 +381,21 @@ pub trait Renderer: crate::Renderer {
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
+        is_disabled: bool,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
 }
 
-impl<'a, Message, Renderer> From<Radio<Message, Renderer>>
+impl<'a, Message, Renderer> From<Radio<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + self::Renderer + row::Renderer + text::Renderer,
     Message: 'a + Clone,
 {
-    fn from(radio: Radio<Message, Renderer>) -> Element<'a, Message, Renderer> {
+    fn from(
+        radio: Radio<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
         Element::new(radio)
     }
 }
@@ -0,0 +1,518 @@
+//! Display an interactive selector of an interval from a range of values.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+//!
+//! [`RangeSlider`]: struct.RangeSlider.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse,
+    slider::{self, Orientation},
+    Clipboard, Element, Event, Hasher, Id, Layout, Length, Point, Rectangle,
+    Widget,
+};
+
+use std::{hash::Hash, ops::RangeInclusive};
+
+/// A bar with two handles that selects an interval from a range of values.
+///
+/// Pressing the bar grabs the closest handle. A handle cannot be dragged past
+/// the other one.
+///
+/// Like a [`Slider`], a [`RangeSlider`] can be vertical, snap its values to
+/// discrete steps, and show tick marks.
+///
+/// # Example
+/// ```
+/// # use iced_native::{range_slider, renderer::Null};
+/// #
+/// # pub type RangeSlider<'a, Message> =
+/// #     iced_native::RangeSlider<'a, Message, Null>;
+/// pub enum Message {
+///     FrequenciesChanged(f32, f32),
+/// }
+///
+/// let state = &mut range_slider::State::new();
+///
+/// RangeSlider::new(
+///     state,
+///     20.0..=20_000.0,
+///     (200.0, 4_000.0),
+///     |(low, high)| Message::FrequenciesChanged(low, high),
+/// )
+/// .step(10.0);
+/// ```
+///
+/// [`Slider`]: ../slider/struct.Slider.html
+/// [`RangeSlider`]: struct.RangeSlider.html
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    range: RangeInclusive<f32>,
+    values: (f32, f32),
+    on_change: Box<dyn Fn((f32, f32)) -> Message>,
+    on_release: Option<Message>,
+    step: Option<f32>,
+    ticks: Vec<f32>,
+    orientation: Orientation,
+    width: Length,
+    height: Length,
+    tab_index: Option<u16>,
+    id: Option<Id>,
+    style: <Renderer as slider::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> RangeSlider<'a, Message, Renderer> {
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`RangeSlider`]
+    ///   * an inclusive range of possible values
+    ///   * the start and the end of the current interval
+    ///   * a function that will be called when a handle of the
+    ///     [`RangeSlider`] is dragged. It receives the new interval and must
+    ///     produce a `Message`.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<f32>,
+        values: (f32, f32),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn((f32, f32)) -> Message,
+    {
+        let clamp = |value: f32| value.max(*range.start()).min(*range.end());
+        let values = (clamp(values.0), clamp(values.1.max(values.0)));
+
+        RangeSlider {
+            state,
+            values,
+            range,
+            on_change: Box::new(on_change),
+            on_release: None,
+            step: None,
+            ticks: Vec::new(),
+            orientation: Orientation::Horizontal,
+            width: Length::Fill,
+            height: Length::Fill,
+            tab_index: None,
+            id: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when the mouse is released from a handle.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Snaps the values of the [`RangeSlider`] to multiples of the given
+    /// step, counting from the start of its range.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Shows tick marks along the rail of the [`RangeSlider`] every `step`
+    /// units, counting from the start of its range.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn ticks(mut self, step: f32) -> Self {
+        self.ticks = slider::ticks(&self.range, step);
+        self
+    }
+
+    /// Sets the [`Orientation`] of the [`RangeSlider`].
+    ///
+    /// [`Orientation`]: ../slider/enum.Orientation.html
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the width of an horizontal [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of a vertical [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the position of the handles of the [`RangeSlider`] in the focus
+    /// order.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`RangeSlider`], which allows focusing its
+    /// start handle with a command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as slider::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`RangeSlider`].
+///
+/// [`RangeSlider`]: struct.RangeSlider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    dragging: Option<Thumb>,
+    start: Handle,
+    end: Handle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Thumb {
+    Start,
+    End,
+}
+
+/// The focus state of a handle of a [`RangeSlider`].
+///
+/// Each handle takes the focus on its own, so it can be moved with the
+/// keyboard.
+///
+/// [`RangeSlider`]: struct.RangeSlider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Handle {
+    is_focused: bool,
+}
+
+impl focus::Focusable for Handle {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether one of the handles of the [`RangeSlider`] is currently
+    /// being dragged.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn is_dragging(&self) -> bool {
+        self.dragging.is_some()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for RangeSlider<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        match self.orientation {
+            Orientation::Horizontal => self.width,
+            Orientation::Vertical => Length::Shrink,
+        }
+    }
+
+    fn height(&self) -> Length {
+        match self.orientation {
+            Orientation::Horizontal => Length::Shrink,
+            Orientation::Vertical => self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        slider::layout(
+            renderer,
+            limits,
+            self.orientation,
+            self.width,
+            self.height,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        let value = slider::value_at(
+            bounds,
+            cursor_position,
+            &self.range,
+            self.step,
+            self.orientation,
+        );
+
+        let (start, end) = self.values;
+
+        let change = |thumb| match thumb {
+            Thumb::Start => (value.min(end), end),
+            Thumb::End => (start, value.max(start)),
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if bounds.contains(cursor_position) =>
+            {
+                let thumb = if value < start
+                    || (value - start).abs() < (value - end).abs()
+                {
+                    Thumb::Start
+                } else {
+                    Thumb::End
+                };
+
+                messages.push((self.on_change)(change(thumb)));
+                self.state.dragging = Some(thumb);
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if self.state.dragging.is_some() =>
+            {
+                if let Some(on_release) = self.on_release.clone() {
+                    messages.push(on_release);
+                }
+
+                self.state.dragging = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(thumb) = self.state.dragging {
+                    messages.push((self.on_change)(change(thumb)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            self.range.clone(),
+            self.values,
+            self.state.is_dragging(),
+            self.orientation,
+            &self.ticks,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.orientation.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        let bounds = layout.bounds();
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        let handle = |value: f32| {
+            let percent = if end > start {
+                (value - start) / (end - start)
+            } else {
+                0.0
+            };
+
+            match self.orientation {
+                Orientation::Horizontal => Rectangle {
+                    x: bounds.x + percent * (bounds.width - bounds.height),
+                    width: bounds.height,
+                    ..bounds
+                },
+                Orientation::Vertical => Rectangle {
+                    y: bounds.y
+                        + (1.0 - percent) * (bounds.height - bounds.width),
+                    height: bounds.width,
+                    ..bounds
+                },
+            }
+        };
+
+        let start_bounds = handle(self.values.0);
+        let end_bounds = handle(self.values.1);
+
+        targets.push(focus::Target::new(
+            &mut self.state.start,
+            start_bounds,
+            self.tab_index,
+            self.id.clone(),
+        ));
+
+        targets.push(focus::Target::new(
+            &mut self.state.end,
+            end_bounds,
+            self.tab_index,
+            None,
+        ));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        let thumb = if self.state.start.is_focused {
+            Thumb::Start
+        } else if self.state.end.is_focused {
+            Thumb::End
+        } else {
+            return false;
+        };
+
+        let key_code = match event {
+            keyboard::Event::KeyPressed { key_code, .. } => key_code,
+            _ => return false,
+        };
+
+        let (start, end) = self.values;
+
+        let current = match thumb {
+            Thumb::Start => start,
+            Thumb::End => end,
+        };
+
+        let value = match slider::value_after_key(
+            current,
+            &self.range,
+            self.step,
+            key_code,
+        ) {
+            Some(value) => value,
+            None => return false,
+        };
+
+        // A handle cannot be moved past the other one
+        let values = match thumb {
+            Thumb::Start => (value.min(end), end),
+            Thumb::End => (start, value.max(start)),
+        };
+
+        if values != self.values {
+            messages.push((self.on_change)(values));
+
+            if let Some(on_release) = self.on_release.clone() {
+                messages.push(on_release);
+            }
+        }
+
+        true
+    }
+}
+
+/// The renderer of a [`RangeSlider`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`RangeSlider`] in your user interface.
+///
+/// [`RangeSlider`]: struct.RangeSlider.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: slider::Renderer {
+    /// Draws a [`RangeSlider`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`RangeSlider`]
+    ///   * the current cursor position
+    ///   * the range of values of the [`RangeSlider`]
+    ///   * the start and the end of the selected interval
+    ///   * whether a handle is being dragged
+    ///   * the [`Orientation`] of the [`RangeSlider`]
+    ///   * the values where tick marks should be drawn
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    /// [`Orientation`]: ../slider/enum.Orientation.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        range: RangeInclusive<f32>,
+        values: (f32, f32),
+        is_dragging: bool,
+        orientation: Orientation,
+        ticks: &[f32],
+        style: &<Self as slider::Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<RangeSlider<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        range_slider: RangeSlider<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(range_slider)
+    }
+}
@@ -0,0 +1,319 @@
+//! Build content depending on the available space.
+//!
+//! A [`Responsive`] has some local [`State`].
+//!
+//! [`Responsive`]: struct.Responsive.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, overlay, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Size, Widget,
+};
+
+use std::cell::{Cell, RefCell};
+use std::hash::Hash;
+
+/// A widget that builds its content from the space available to it.
+///
+/// The view of a [`Responsive`] receives the available [`Size`] at layout
+/// time, which allows switching between wide and narrow layouts without
+/// storing the size of the window in the application state.
+///
+/// The available [`Size`] is remembered in the [`State`] of the
+/// [`Responsive`]. When it changes, the content built for the old one is
+/// shown for a single frame, until a redraw builds it again.
+///
+/// # Example
+/// ```
+/// # use iced_native::{responsive, renderer::Null, Column, Row, Text};
+/// #
+/// # pub type Responsive<'a, Message> =
+/// #     iced_native::Responsive<'a, Message, Null>;
+/// let mut state = responsive::State::new();
+///
+/// let responsive = Responsive::<()>::new(&mut state, |size| {
+///     if size.width > 600.0 {
+///         Row::new()
+///             .push(Text::new("Sidebar"))
+///             .push(Text::new("Content"))
+///             .into()
+///     } else {
+///         Column::new().push(Text::new("Content")).into()
+///     }
+/// });
+/// ```
+///
+/// [`Responsive`]: struct.Responsive.html
+/// [`Size`]: ../../struct.Size.html
+/// [`State`]: struct.State.html
+#[allow(missing_debug_implementations)]
+pub struct Responsive<'a, Message, Renderer> {
+    state: &'a mut State,
+    width: Length,
+    height: Length,
+    view: RefCell<Option<View<'a, Message, Renderer>>>,
+    content: RefCell<Option<(Size, Element<'a, Message, Renderer>)>>,
+}
+
+type View<'a, Message, Renderer> =
+    Box<dyn FnOnce(Size) -> Element<'a, Message, Renderer> + 'a>;
+
+impl<'a, Message, Renderer> Responsive<'a, Message, Renderer> {
+    /// Creates a new [`Responsive`] with the given [`State`] and view.
+    ///
+    /// The view receives the [`Size`] available to the [`Responsive`], which
+    /// can be infinite inside of a `Scrollable`.
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    /// [`State`]: struct.State.html
+    /// [`Size`]: ../../struct.Size.html
+    pub fn new<F>(state: &'a mut State, view: F) -> Self
+    where
+        F: 'a + FnOnce(Size) -> Element<'a, Message, Renderer>,
+    {
+        Responsive {
+            state,
+            width: Length::Fill,
+            height: Length::Fill,
+            view: RefCell::new(Some(Box::new(view))),
+            content: RefCell::new(None),
+        }
+    }
+
+    /// Sets the width of the [`Responsive`].
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Responsive`].
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Builds the content of the [`Responsive`] for the given available
+    /// [`Size`], unless it is already built.
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    /// [`Size`]: ../../struct.Size.html
+    fn build(&self, size: Size) {
+        if self.content.borrow().is_some() {
+            return;
+        }
+
+        if let Some(view) = self.view.borrow_mut().take() {
+            *self.content.borrow_mut() = Some((size, view(size)));
+        }
+    }
+}
+
+/// The local state of a [`Responsive`].
+///
+/// [`Responsive`]: struct.Responsive.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    size: Cell<Option<Size>>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the last [`Size`] available to the [`Responsive`], if it has
+    /// been laid out already.
+    ///
+    /// [`Size`]: ../../struct.Size.html
+    /// [`Responsive`]: struct.Responsive.html
+    pub fn size(&self) -> Option<Size> {
+        self.size.get()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Responsive<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.max();
+
+        self.state.size.set(Some(size));
+        self.build(size);
+
+        let content = self.content.borrow();
+        let (_, element) = content.as_ref().expect("Responsive content");
+
+        let node = element.layout(renderer, &limits);
+
+        layout::Node::with_children(limits.resolve(node.size()), vec![node])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.build(layout.bounds().size());
+
+        if let Some((_, element)) = self.content.get_mut() {
+            element.on_event(
+                event,
+                layout.children().next().unwrap(),
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.build(layout.bounds().size());
+
+        let content = self.content.borrow();
+        let (size, element) = content.as_ref().expect("Responsive content");
+
+        // The content was built for an outdated size
+        if self.state.size.get() != Some(*size) {
+            renderer.request_redraw();
+        }
+
+        element.draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+
+        // The content can only be known once the available size is
+        if let Some(size) = self.state.size.get() {
+            self.build(size);
+
+            if let Some((_, element)) = self.content.borrow().as_ref() {
+                element.hash_layout(state);
+            }
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let (_, element) = self.content.get_mut().as_mut()?;
+
+        element.overlay(layout.children().next().unwrap())
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        if let Some((_, element)) = self.content.get_mut() {
+            element.focus_targets(layout.children().next().unwrap(), targets);
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        match self.content.get_mut() {
+            Some((_, element)) => element.on_focused_event(
+                event,
+                layout.children().next().unwrap(),
+                messages,
+                renderer,
+                clipboard,
+            ),
+            None => false,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Responsive<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        responsive: Responsive<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(responsive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Row};
+
+    #[test]
+    fn view_receives_available_size() {
+        let mut state = State::new();
+
+        let node = {
+            let responsive: Responsive<'_, (), Null> =
+                Responsive::new(&mut state, |size| {
+                    let width = if size.width > 50.0 { 40 } else { 20 };
+
+                    Row::new().width(Length::Units(width)).into()
+                })
+                .height(Length::Shrink);
+
+            Widget::<(), Null>::layout(
+                &responsive,
+                &Null::new(),
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            )
+        };
+
+        assert_eq!(node.children()[0].size().width, 40.0);
+        assert_eq!(node.size(), Size::new(100.0, 0.0));
+        assert_eq!(state.size(), Some(Size::new(100.0, 100.0)));
+    }
+}
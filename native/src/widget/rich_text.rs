@@ -0,0 +1,471 @@
+//! Write a paragraph of text made of differently styled spans.
+use crate::{
+    layout, mouse, text, Clipboard, Color, Element, Event, Hasher,
+    HorizontalAlignment, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::ops::Range;
+
+/// A paragraph of text made of multiple [`Span`]s, each one with its own
+/// color, font, size, and decorations.
+///
+/// Lines are wrapped across spans, like a single [`Text`]. A [`Span`] can be
+/// a link, producing a message when clicked.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type RichText<Message> = iced_native::RichText<Message, Null>;
+/// use iced_native::rich_text::Span;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     OpenDocumentation,
+/// }
+///
+/// RichText::new(vec![
+///     Span::new("Rich text is "),
+///     Span::new("great").color([1.0, 0.0, 0.0]).underline(),
+///     Span::new(". Read the "),
+///     Span::new("documentation").link(Message::OpenDocumentation),
+///     Span::new("!").size(30),
+/// ]);
+/// ```
+///
+/// [`Span`]: struct.Span.html
+/// [`Text`]: ../text/struct.Text.html
+#[allow(missing_debug_implementations)]
+pub struct RichText<Message, Renderer: self::Renderer> {
+    spans: Vec<Span<Message, Renderer::Font>>,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+}
+
+impl<Message, Renderer: self::Renderer> RichText<Message, Renderer> {
+    /// Creates a new [`RichText`] with the given spans.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn new(spans: Vec<Span<Message, Renderer::Font>>) -> Self {
+        RichText {
+            spans,
+            size: None,
+            color: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: HorizontalAlignment::Left,
+        }
+    }
+
+    /// Adds a [`Span`] to the end of the [`RichText`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn push(mut self, span: Span<Message, Renderer::Font>) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Sets the default size of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the default [`Color`] of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the default font of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    fn fragments(&self) -> Vec<Fragment<'_, Renderer::Font>> {
+        let size = self.size.unwrap_or(Renderer::DEFAULT_SIZE);
+
+        self.spans
+            .iter()
+            .map(|span| Fragment {
+                content: &span.content,
+                size: span.size.unwrap_or(size),
+                font: span.font.unwrap_or(self.font),
+                color: span.color.or(self.color),
+                is_underlined: span.is_underlined,
+                is_struck_through: span.is_struck_through,
+                is_link: span.link.is_some(),
+            })
+            .collect()
+    }
+}
+
+/// A piece of a [`RichText`] with its own style.
+///
+/// By default, a [`Span`] uses the size, the color, and the font of its
+/// [`RichText`].
+///
+/// [`RichText`]: struct.RichText.html
+/// [`Span`]: struct.Span.html
+#[derive(Debug, Clone)]
+pub struct Span<Message, Font> {
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Option<Font>,
+    is_underlined: bool,
+    is_struck_through: bool,
+    link: Option<Message>,
+}
+
+impl<Message, Font> Span<Message, Font> {
+    /// Creates a new [`Span`] with the given contents.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn new(content: impl Into<String>) -> Self {
+        Span {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: None,
+            is_underlined: false,
+            is_struck_through: false,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Draws a line below the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn underline(mut self) -> Self {
+        self.is_underlined = true;
+        self
+    }
+
+    /// Draws a line through the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn strikethrough(mut self) -> Self {
+        self.is_struck_through = true;
+        self
+    }
+
+    /// Turns the [`Span`] into a link producing the given message when
+    /// clicked.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn link(mut self, message: Message) -> Self {
+        self.link = Some(message);
+        self
+    }
+}
+
+/// A [`Span`] with its style resolved, ready to be laid out and drawn.
+///
+/// [`Span`]: struct.Span.html
+#[derive(Debug, Clone, Copy)]
+pub struct Fragment<'a, Font> {
+    /// The contents of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub content: &'a str,
+
+    /// The size of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub size: u16,
+
+    /// The font of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub font: Font,
+
+    /// The color of the [`Span`], if any.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub color: Option<Color>,
+
+    /// Whether the [`Span`] is underlined.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub is_underlined: bool,
+
+    /// Whether the [`Span`] is struck through.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub is_struck_through: bool,
+
+    /// Whether the [`Span`] is a link.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub is_link: bool,
+}
+
+/// The part of a [`Fragment`] laid out on a single line.
+///
+/// [`Fragment`]: struct.Fragment.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    /// The index of the [`Fragment`].
+    ///
+    /// [`Fragment`]: struct.Fragment.html
+    pub fragment: usize,
+
+    /// The byte range of the contents of the [`Fragment`] in this line.
+    ///
+    /// [`Fragment`]: struct.Fragment.html
+    pub range: Range<usize>,
+
+    /// The bounds of the [`Run`], relative to the paragraph. They span from
+    /// the ascent to the descent of its font.
+    ///
+    /// [`Run`]: struct.Run.html
+    pub bounds: Rectangle,
+
+    /// The vertical position of the baseline of the line, relative to the
+    /// paragraph.
+    pub baseline: f32,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for RichText<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let runs = self::Renderer::layout(
+            renderer,
+            &self.fragments(),
+            limits.max(),
+            self.horizontal_alignment,
+        );
+
+        let (width, height) = runs.iter().fold((0.0, 0.0), |(w, h), run| {
+            let bounds = run.bounds;
+
+            (
+                f32::max(w, bounds.x + bounds.width),
+                f32::max(h, bounds.y + bounds.height),
+            )
+        });
+
+        let size = limits.resolve(Size::new(width, height));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            let bounds = layout.bounds();
+
+            if !bounds.contains(cursor_position) {
+                return;
+            }
+
+            let runs = self::Renderer::layout(
+                renderer,
+                &self.fragments(),
+                bounds.size(),
+                self.horizontal_alignment,
+            );
+
+            let position = Point::new(
+                cursor_position.x - bounds.x,
+                cursor_position.y - bounds.y,
+            );
+
+            let link = runs
+                .iter()
+                .find(|run| run.bounds.contains(position))
+                .and_then(|run| self.spans[run.fragment].link.clone());
+
+            if let Some(link) = link {
+                messages.push(link);
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let fragments = self.fragments();
+
+        let runs = self::Renderer::layout(
+            renderer,
+            &fragments,
+            bounds.size(),
+            self.horizontal_alignment,
+        );
+
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            bounds,
+            cursor_position,
+            &fragments,
+            &runs,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        for span in &self.spans {
+            span.content.hash(state);
+            span.size.hash(state);
+        }
+
+        self.size.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`RichText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`RichText`] in your user interface.
+///
+/// [`RichText`]: struct.RichText.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Lays out the given fragments as a single paragraph wrapped in the
+    /// given bounds, returning the resulting runs.
+    fn layout(
+        &self,
+        fragments: &[Fragment<'_, Self::Font>],
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+    ) -> Vec<Run>;
+
+    /// Draws a [`RichText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`RichText`]
+    ///   * the cursor position
+    ///   * the fragments of the [`RichText`]
+    ///   * the runs of the fragments, as returned by [`layout`]
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`layout`]: #tymethod.layout
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        fragments: &[Fragment<'_, Self::Font>],
+        runs: &[Run],
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<RichText<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        rich_text: RichText<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
@@ -2,8 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Widget,
+    focus, keyboard, layout, overlay, Align, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Widget,
 };
 
 use std::u32;
@@ -206,6 +206,45 @@ where
             child.widget.hash_layout(state);
         }
     }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .for_each(|(child, layout)| child.focus_targets(layout, targets));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.children.iter_mut().zip(layout.children()).any(
+            |(child, layout)| {
+                child.on_focused_event(
+                    event, layout, messages, renderer, clipboard,
+                )
+            },
+        )
+    }
 }
 
 /// The renderer of a [`Row`].
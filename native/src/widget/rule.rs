@@ -0,0 +1,138 @@
+//! Separate content with horizontal or vertical lines.
+use crate::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A horizontal or vertical line dividing some content.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Rule = iced_native::Rule<Null>;
+/// let divider = Rule::horizontal(20);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Rule<Renderer: self::Renderer> {
+    width: Length,
+    height: Length,
+    is_horizontal: bool,
+    style: Renderer::Style,
+}
+
+impl<Renderer: self::Renderer> Rule<Renderer> {
+    /// Creates a horizontal [`Rule`] filling the available width, with the
+    /// given amount of vertical space around its line.
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub fn horizontal(spacing: u16) -> Self {
+        Rule {
+            width: Length::Fill,
+            height: Length::Units(spacing),
+            is_horizontal: true,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Creates a vertical [`Rule`] filling the available height, with the
+    /// given amount of horizontal space around its line.
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub fn vertical(spacing: u16) -> Self {
+        Rule {
+            width: Length::Units(spacing),
+            height: Length::Fill,
+            is_horizontal: false,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the style of the [`Rule`].
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Rule<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(layout.bounds(), &self.style, self.is_horizontal)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`Rule`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Rule`] in your user interface.
+///
+/// [`Rule`]: struct.Rule.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Rule`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Rule`]
+    ///   * the style of the [`Rule`]
+    ///   * whether the [`Rule`] is horizontal or vertical
+    ///
+    /// [`Rule`]: struct.Rule.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        style: &Self::Style,
+        is_horizontal: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Rule<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(rule: Rule<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(rule)
+    }
+}
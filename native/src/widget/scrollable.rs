@@ -1,19 +1,31 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::{
-    column, layout, mouse, Align, Clipboard, Column, Element, Event, Hasher,
-    Layout, Length, Point, Rectangle, Size, Widget,
+    column, focus, keyboard, layout, mouse, overlay, window, Align, Clipboard,
+    Column, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
+    Vector, Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::{f32, hash::Hash, u32};
 
-/// A widget that can vertically display an infinite amount of content with a
-/// scrollbar.
+/// A widget that can display an infinite amount of content with scrollbars.
+///
+/// A [`Scrollable`] scrolls vertically by default. It can scroll
+/// horizontally, or in both directions, by changing its [`Direction`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+/// [`Direction`]: enum.Direction.html
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     height: Length,
     max_height: u32,
+    direction: Direction,
     content: Column<'a, Message, Renderer>,
+    on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    on_reach_start: Option<Reach<'a, Message>>,
+    on_reach_end: Option<Reach<'a, Message>>,
+    autohide: Option<Duration>,
     style: Renderer::Style,
 }
 
@@ -27,7 +39,12 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
             state,
             height: Length::Shrink,
             max_height: u32::MAX,
+            direction: Direction::Vertical,
             content: Column::new(),
+            on_scroll: None,
+            on_reach_start: None,
+            on_reach_end: None,
+            autohide: None,
             style: Renderer::Style::default(),
         }
     }
@@ -90,6 +107,30 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the [`Direction`] of the [`Scrollable`].
+    ///
+    /// When scrolling horizontally, the content is laid out with an unbounded
+    /// width.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Hides the scrollbars of the [`Scrollable`] after the given duration
+    /// without scrolling or moving the mouse over it.
+    ///
+    /// The scrollbars fade out following the frames of the runtime, and show
+    /// up again as soon as the user interacts with the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn autohide(mut self, delay: Duration) -> Self {
+        self.autohide = Some(delay);
+        self
+    }
+
     /// Sets the style of the [`Scrollable`] .
     ///
     /// [`Scrollable`]: struct.Scrollable.html
@@ -108,6 +149,171 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self.content = self.content.push(child);
         self
     }
+
+    /// Sets the message that should be produced when the user scrolls the
+    /// [`Scrollable`].
+    ///
+    /// The message receives the new [`Viewport`] of the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn on_scroll<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Viewport) -> Message,
+    {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when the user scrolls to
+    /// the given distance, in pixels, from the start of the contents of the
+    /// [`Scrollable`].
+    ///
+    /// The message is produced once every time the distance is reached.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn on_reach_start(mut self, threshold: u16, message: Message) -> Self
+    where
+        Message: 'a + Clone,
+    {
+        self.on_reach_start = Some(Reach::new(threshold, message));
+        self
+    }
+
+    /// Sets the message that should be produced when the user scrolls to
+    /// the given distance, in pixels, from the end of the contents of the
+    /// [`Scrollable`].
+    ///
+    /// The message is produced once every time the distance is reached,
+    /// which allows paginated lists to load more items before the user
+    /// reaches the last one.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn on_reach_end(mut self, threshold: u16, message: Message) -> Self
+    where
+        Message: 'a + Clone,
+    {
+        self.on_reach_end = Some(Reach::new(threshold, message));
+        self
+    }
+
+    /// Returns the distances from the start and the end of the contents
+    /// of the [`Scrollable`] to its visible part, along its main scrolling
+    /// axis.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    fn distances(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        offset: Vector<u32>,
+    ) -> (f32, f32) {
+        let (offset, hidden) = if self.direction == Direction::Horizontal {
+            (offset.x as f32, content_bounds.width - bounds.width)
+        } else {
+            (offset.y as f32, content_bounds.height - bounds.height)
+        };
+
+        (offset, (hidden - offset).max(0.0))
+    }
+
+    /// Returns the horizontal and vertical scrolling offsets of the
+    /// [`Scrollable`], given its bounds and the layout of its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    fn offset(&self, bounds: Rectangle, content: Layout<'_>) -> Vector<u32> {
+        Vector::new(
+            self.state.horizontal_offset(bounds, content.bounds()),
+            self.state.layout_offset(bounds, content),
+        )
+    }
+
+    /// Returns the vertical and horizontal [`Scrollbar`] of the
+    /// [`Scrollable`], if it can scroll in their direction.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    fn scrollbars(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        content: Layout<'_>,
+    ) -> (Option<Scrollbar>, Option<Scrollbar>) {
+        let content_bounds = content.bounds();
+        let offset = self.offset(bounds, content);
+
+        let vertical = if self.direction.is_vertical() {
+            renderer.scrollbar(bounds, content_bounds, offset.y, &self.style)
+        } else {
+            None
+        };
+
+        let horizontal = if self.direction.is_horizontal() {
+            renderer.horizontal_scrollbar(
+                bounds,
+                content_bounds,
+                offset.x,
+                &self.style,
+            )
+        } else {
+            None
+        };
+
+        (vertical, horizontal)
+    }
+}
+
+/// A message produced when scrolling close to an end of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+struct Reach<'a, Message> {
+    threshold: f32,
+    message: Box<dyn Fn() -> Message + 'a>,
+}
+
+impl<'a, Message> Reach<'a, Message> {
+    fn new(threshold: u16, message: Message) -> Self
+    where
+        Message: 'a + Clone,
+    {
+        Reach {
+            threshold: f32::from(threshold),
+            message: Box::new(move || message.clone()),
+        }
+    }
+
+    /// Returns whether scrolling crossed the threshold of the [`Reach`],
+    /// given the distances to its end before and after scrolling.
+    ///
+    /// [`Reach`]: struct.Reach.html
+    fn is_reached(&self, before: f32, after: f32) -> bool {
+        before > self.threshold && after <= self.threshold
+    }
+}
+
+/// The directions in which a [`Scrollable`] can scroll.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Scroll vertically
+    Vertical,
+
+    /// Scroll horizontally
+    Horizontal,
+
+    /// Scroll both vertically and horizontally
+    Both,
+}
+
+impl Direction {
+    fn is_vertical(self) -> bool {
+        self != Direction::Horizontal
+    }
+
+    fn is_horizontal(self) -> bool {
+        self != Direction::Vertical
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -133,12 +339,46 @@ where
             .width(Widget::<Message, Renderer>::width(&self.content))
             .height(self.height);
 
+        let (min_width, max_width) = if self.direction.is_horizontal() {
+            (0.0, f32::INFINITY)
+        } else {
+            (limits.min().width, limits.max().width)
+        };
+
+        let (min_height, max_height) = if self.direction.is_vertical() {
+            (0.0, f32::INFINITY)
+        } else {
+            (limits.min().height, limits.max().height)
+        };
+
         let child_limits = layout::Limits::new(
-            Size::new(limits.min().width, 0.0),
-            Size::new(limits.max().width, f32::INFINITY),
+            Size::new(min_width, min_height),
+            Size::new(max_width, max_height),
         );
 
-        let content = self.content.layout(renderer, &child_limits);
+        // An unbounded width cannot be filled, so the content shrinks to fit
+        let child_limits = if self.direction.is_horizontal() {
+            child_limits.width(Length::Shrink)
+        } else {
+            child_limits
+        };
+
+        let mut content = self.content.layout(renderer, &child_limits);
+
+        // Children filling an unbounded width grow up to the maximum width
+        // of a `Column`, so the content is laid out in the available width
+        // instead
+        if self.direction.is_horizontal()
+            && content.size().width >= u32::MAX as f32
+        {
+            let child_limits = layout::Limits::new(
+                Size::new(limits.min().width, min_height),
+                Size::new(limits.max().width, max_height),
+            );
+
+            content = self.content.layout(renderer, &child_limits);
+        }
+
         let size = limits.resolve(content.size());
 
         layout::Node::with_children(size, vec![content])
@@ -159,87 +399,107 @@ where
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        // TODO: Event capture. Nested scrollables should capture scroll events.
-        if is_mouse_over {
+        // Keep the child to scroll to in place while scrolling from it
+        let offset = self.offset(bounds, content);
+        self.state.offset = offset.y as f32;
+
+        if let Some(delay) = self.autohide {
             match event {
-                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
-                        }
-                    }
+                Event::Window(window::Event::RedrawRequested(now)) => {
+                    self.state.fade_scrollbars(delay, now);
+                }
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Mouse(mouse::Event::WheelScrolled { .. })
+                    if is_mouse_over || self.state.is_scroller_grabbed() =>
+                {
+                    self.state.show_scrollbars(Instant::now());
                 }
                 _ => {}
             }
         }
 
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::LShift,
+                ..
+            })
+            | Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::RShift,
+                ..
+            }) => {
+                self.state.is_shift_pressed = true;
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code: keyboard::KeyCode::LShift,
+                ..
+            })
+            | Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code: keyboard::KeyCode::RShift,
+                ..
+            }) => {
+                self.state.is_shift_pressed = false;
+            }
+            _ => {}
+        }
 
-        if self.state.is_scroller_grabbed() {
-            match event {
-                Event::Mouse(mouse::Event::ButtonReleased(
-                    mouse::Button::Left,
-                )) => {
-                    self.state.scroller_grabbed_at = None;
-                }
-                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    if let (Some(scrollbar), Some(scroller_grabbed_at)) =
-                        (scrollbar, self.state.scroller_grabbed_at)
-                    {
-                        self.state.scroll_to(
-                            scrollbar.scroll_percentage(
-                                scroller_grabbed_at,
-                                cursor_position,
-                            ),
-                            bounds,
-                            content_bounds,
-                        );
-                    }
+        // TODO: Event capture. Nested scrollables should capture scroll events.
+        if is_mouse_over {
+            if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+                self.state.scroll_with_wheel(
+                    delta,
+                    self.direction,
+                    bounds,
+                    content_bounds,
+                );
+            }
+        }
+
+        let (scrollbar, horizontal_scrollbar) =
+            self.scrollbars(renderer, bounds, content);
+
+        let is_mouse_over_scrollbar = self.state.drag_scrollers(
+            &event,
+            cursor_position,
+            scrollbar.as_ref(),
+            horizontal_scrollbar.as_ref(),
+            bounds,
+            content_bounds,
+        );
+
+        let new_offset = self.offset(bounds, content);
+
+        if new_offset != offset {
+            if let Some(on_scroll) = &self.on_scroll {
+                messages.push(on_scroll(Viewport {
+                    offset: Vector::new(
+                        new_offset.x as f32,
+                        new_offset.y as f32,
+                    ),
+                    bounds,
+                    content_bounds,
+                }));
+            }
+
+            let (start, end) = self.distances(bounds, content_bounds, offset);
+            let (new_start, new_end) =
+                self.distances(bounds, content_bounds, new_offset);
+
+            if let Some(reach) = &self.on_reach_start {
+                if reach.is_reached(start, new_start) {
+                    messages.push((reach.message)());
                 }
-                _ => {}
             }
-        } else if is_mouse_over_scrollbar {
-            match event {
-                Event::Mouse(mouse::Event::ButtonPressed(
-                    mouse::Button::Left,
-                )) => {
-                    if let Some(scrollbar) = scrollbar {
-                        if let Some(scroller_grabbed_at) =
-                            scrollbar.grab_scroller(cursor_position)
-                        {
-                            self.state.scroll_to(
-                                scrollbar.scroll_percentage(
-                                    scroller_grabbed_at,
-                                    cursor_position,
-                                ),
-                                bounds,
-                                content_bounds,
-                            );
-
-                            self.state.scroller_grabbed_at =
-                                Some(scroller_grabbed_at);
-                        }
-                    }
+
+            if let Some(reach) = &self.on_reach_end {
+                if reach.is_reached(end, new_end) {
+                    messages.push((reach.message)());
                 }
-                _ => {}
             }
         }
 
         let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-            Point::new(
-                cursor_position.x,
-                cursor_position.y
-                    + self.state.offset(bounds, content_bounds) as f32,
-            )
+            cursor_position
+                + Vector::new(new_offset.x as f32, new_offset.y as f32)
         } else {
             // TODO: Make `cursor_position` an `Option<Point>` so we can encode
             // cursor availability.
@@ -267,19 +527,24 @@ where
     ) -> Renderer::Output {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
-        let content_bounds = content_layout.bounds();
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
+        let offset = self.offset(bounds, content_layout);
+
+        if self.autohide.is_some() && self.state.scrollbar_visibility() > 0.0 {
+            renderer.request_redraw();
+        }
+
+        let (scrollbar, horizontal_scrollbar) =
+            self.scrollbars(renderer, bounds, content_layout);
 
         let is_mouse_over = bounds.contains(cursor_position);
         let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+            .iter()
+            .chain(horizontal_scrollbar.iter())
+            .any(|scrollbar| scrollbar.is_mouse_over(cursor_position));
 
         let content = {
             let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(cursor_position.x, cursor_position.y + offset as f32)
+                cursor_position + Vector::new(offset.x as f32, offset.y as f32)
             } else {
                 Point::new(cursor_position.x, -1.0)
             };
@@ -300,6 +565,7 @@ where
             is_mouse_over,
             is_mouse_over_scrollbar,
             scrollbar,
+            horizontal_scrollbar,
             offset,
             &self.style,
             content,
@@ -312,9 +578,60 @@ where
 
         self.height.hash(state);
         self.max_height.hash(state);
+        self.direction.hash(state);
 
         self.content.hash_layout(state)
     }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let offset = self.offset(bounds, content_layout);
+
+        self.content.overlay(content_layout).map(|overlay| {
+            overlay
+                .translate(Vector::new(-(offset.x as f32), -(offset.y as f32)))
+        })
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let offset = self.offset(bounds, content_layout);
+        let translation = Vector::new(-(offset.x as f32), -(offset.y as f32));
+
+        let start = targets.len();
+
+        self.content.focus_targets(content_layout, targets);
+
+        for target in &mut targets[start..] {
+            target.bounds = target.bounds + translation;
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.content.on_focused_event(
+            event,
+            layout.children().next().unwrap(),
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
 }
 
 /// The local state of a [`Scrollable`].
@@ -323,7 +640,23 @@ where
 #[derive(Debug, Clone, Copy, Default)]
 pub struct State {
     scroller_grabbed_at: Option<f32>,
+    horizontal_scroller_grabbed_at: Option<f32>,
     offset: f32,
+    horizontal_offset: f32,
+    is_shift_pressed: bool,
+    target: Option<Target>,
+    last_activity: Option<Instant>,
+    visibility: Option<f32>,
+}
+
+/// The duration of the fade out of hidden scrollbars.
+const FADE_DURATION: Duration = Duration::from_millis(300);
+
+/// A vertical scroll position that must be kept while the content changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    Relative(f32),
+    Child(usize),
 }
 
 impl State {
@@ -349,6 +682,9 @@ impl State {
             return;
         }
 
+        self.offset = self.offset(bounds, content_bounds) as f32;
+        self.target = None;
+
         self.offset = (self.offset - delta_y)
             .max(0.0)
             .min((content_bounds.height - bounds.height) as f32);
@@ -370,6 +706,44 @@ impl State {
     ) {
         self.offset =
             ((content_bounds.height - bounds.height) * percentage).max(0.0);
+        self.target = None;
+    }
+
+    /// Moves the scroll position to the given offset, in pixels from the top
+    /// of the contents.
+    ///
+    /// The offset is limited to the hidden contents once the [`Scrollable`]
+    /// is laid out.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_to_offset(&mut self, offset: f32) {
+        self.offset = offset.max(0.0);
+        self.target = None;
+    }
+
+    /// Keeps the scroll position at a relative amount, even when the contents
+    /// of the [`Scrollable`] change, until the user scrolls.
+    ///
+    /// `0` represents scrollbar at the top, while `1` represents scrollbar at
+    /// the bottom. Snapping to `1` keeps the newest items of a growing list in
+    /// sight.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn snap_to(&mut self, percentage: f32) {
+        let percentage = percentage.max(0.0);
+
+        self.target = Some(Target::Relative(percentage.min(1.0)));
+    }
+
+    /// Moves the scroll position to show the child of the [`Scrollable`] with
+    /// the given index at the top, as soon as it is laid out.
+    ///
+    /// The position is kept, even when the contents change, until the user
+    /// scrolls. Nothing happens if the child does not exist.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_to_child(&mut self, index: usize) {
+        self.target = Some(Target::Child(index));
     }
 
     /// Returns the current scrolling offset of the [`State`], given the bounds
@@ -381,12 +755,340 @@ impl State {
         let hidden_content =
             (content_bounds.height - bounds.height).max(0.0).round() as u32;
 
-        self.offset.min(hidden_content as f32) as u32
+        let offset = match self.target {
+            Some(Target::Relative(percentage)) => {
+                hidden_content as f32 * percentage
+            }
+            _ => self.offset,
+        };
+
+        offset.min(hidden_content as f32) as u32
     }
 
-    /// Returns whether the scroller is currently grabbed or not.
+    /// Returns the current scrolling offset of the [`State`], given the bounds
+    /// of the [`Scrollable`] and the layout of its contents.
+    ///
+    /// Unlike [`offset`], it can locate the child to scroll to.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`offset`]: #method.offset
+    fn layout_offset(&self, bounds: Rectangle, content: Layout<'_>) -> u32 {
+        let content_bounds = content.bounds();
+
+        let child = match self.target {
+            Some(Target::Child(index)) => content.children().nth(index),
+            _ => None,
+        };
+
+        match child {
+            Some(child) => {
+                let hidden_content =
+                    (content_bounds.height - bounds.height).max(0.0).round();
+
+                (child.bounds().y - content_bounds.y)
+                    .max(0.0)
+                    .min(hidden_content) as u32
+            }
+            None => self.offset(bounds, content_bounds),
+        }
+    }
+
+    /// Apply a horizontal scrolling offset to the current [`State`], given
+    /// the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn scroll_horizontally(
+        &mut self,
+        delta_x: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        if bounds.width >= content_bounds.width {
+            return;
+        }
+
+        self.horizontal_offset = (self.horizontal_offset - delta_x)
+            .max(0.0)
+            .min(content_bounds.width - bounds.width);
+    }
+
+    /// Moves the horizontal scroll position to a relative amount, given the
+    /// bounds of the [`Scrollable`] and its contents.
+    ///
+    /// `0` represents scrollbar at the left, while `1` represents scrollbar
+    /// at the right.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_horizontally_to(
+        &mut self,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.horizontal_offset =
+            ((content_bounds.width - bounds.width) * percentage).max(0.0);
+    }
+
+    /// Returns the current horizontal scrolling offset of the [`State`],
+    /// given the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn horizontal_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> u32 {
+        let hidden_content =
+            (content_bounds.width - bounds.width).max(0.0).round() as u32;
+
+        self.horizontal_offset.min(hidden_content as f32) as u32
+    }
+
+    /// Returns the visibility of the scrollbars, from `0` when they are hidden
+    /// to `1` when they are fully visible.
+    ///
+    /// Scrollbars are always visible, unless the [`Scrollable`] hides them.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scrollbar_visibility(&self) -> f32 {
+        self.visibility.unwrap_or(1.0)
+    }
+
+    /// Shows the scrollbars after some activity at the given [`Instant`].
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    fn show_scrollbars(&mut self, now: Instant) {
+        self.last_activity = Some(now);
+        self.visibility = Some(1.0);
+    }
+
+    /// Fades the scrollbars out once the given delay has passed since the
+    /// last activity, at the given [`Instant`].
+    ///
+    /// The first frame counts as activity, so scrollbars show up briefly
+    /// to hint that the content can be scrolled.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    fn fade_scrollbars(&mut self, delay: Duration, now: Instant) {
+        let last_activity = *self.last_activity.get_or_insert(now);
+        let idle = now.duration_since(last_activity);
+
+        let visibility = if idle <= delay || self.is_scroller_grabbed() {
+            1.0
+        } else {
+            let fading = (idle - delay).as_secs_f32();
+
+            (1.0 - fading / FADE_DURATION.as_secs_f32()).max(0.0)
+        };
+
+        self.visibility = Some(visibility);
+    }
+
+    /// Scrolls the contents with the given delta of the mouse wheel, in the
+    /// given [`Direction`].
+    ///
+    /// [`Direction`]: enum.Direction.html
+    pub(crate) fn scroll_with_wheel(
+        &mut self,
+        delta: mouse::ScrollDelta,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        // TODO: Configurable speed (?)
+        let (x, y) = match delta {
+            mouse::ScrollDelta::Lines { x, y } => (x * 60.0, y * 60.0),
+            mouse::ScrollDelta::Pixels { x, y } => (x, y),
+        };
+
+        // Holding shift scrolls horizontally with a vertical wheel
+        let (x, y) = if self.is_shift_pressed && direction.is_horizontal() {
+            (y, x)
+        } else {
+            (x, y)
+        };
+
+        match direction {
+            Direction::Vertical => {
+                self.scroll(y, bounds, content_bounds);
+            }
+            Direction::Horizontal => {
+                let x = if x == 0.0 { y } else { x };
+
+                self.scroll_horizontally(x, bounds, content_bounds);
+            }
+            Direction::Both => {
+                self.scroll(y, bounds, content_bounds);
+                self.scroll_horizontally(x, bounds, content_bounds);
+            }
+        }
+    }
+
+    /// Grabs, drags, and releases the scrollers of the given scrollbars with
+    /// the mouse.
+    ///
+    /// Returns whether the mouse is over any of the scrollbars.
+    pub(crate) fn drag_scrollers(
+        &mut self,
+        event: &Event,
+        cursor_position: Point,
+        scrollbar: Option<&Scrollbar>,
+        horizontal_scrollbar: Option<&Scrollbar>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> bool {
+        let is_mouse_over_scrollbar = scrollbar
+            .into_iter()
+            .chain(horizontal_scrollbar)
+            .any(|scrollbar| scrollbar.is_mouse_over(cursor_position));
+
+        if self.is_scroller_grabbed() {
+            match event {
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) => {
+                    self.scroller_grabbed_at = None;
+                    self.horizontal_scroller_grabbed_at = None;
+                }
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    if let (Some(scrollbar), Some(scroller_grabbed_at)) =
+                        (scrollbar, self.scroller_grabbed_at)
+                    {
+                        self.scroll_to(
+                            scrollbar.scroll_percentage(
+                                scroller_grabbed_at,
+                                cursor_position,
+                            ),
+                            bounds,
+                            content_bounds,
+                        );
+                    }
+
+                    if let (Some(scrollbar), Some(scroller_grabbed_at)) = (
+                        horizontal_scrollbar,
+                        self.horizontal_scroller_grabbed_at,
+                    ) {
+                        self.scroll_horizontally_to(
+                            scrollbar.horizontal_scroll_percentage(
+                                scroller_grabbed_at,
+                                cursor_position,
+                            ),
+                            bounds,
+                            content_bounds,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        } else if is_mouse_over_scrollbar {
+            if let Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            )) = event
+            {
+                if let Some(scrollbar) = scrollbar {
+                    if let Some(scroller_grabbed_at) =
+                        scrollbar.grab_scroller(cursor_position)
+                    {
+                        self.scroll_to(
+                            scrollbar.scroll_percentage(
+                                scroller_grabbed_at,
+                                cursor_position,
+                            ),
+                            bounds,
+                            content_bounds,
+                        );
+
+                        self.scroller_grabbed_at = Some(scroller_grabbed_at);
+                    }
+                }
+
+                if let Some(scrollbar) = horizontal_scrollbar {
+                    if let Some(scroller_grabbed_at) =
+                        scrollbar.grab_horizontal_scroller(cursor_position)
+                    {
+                        self.scroll_horizontally_to(
+                            scrollbar.horizontal_scroll_percentage(
+                                scroller_grabbed_at,
+                                cursor_position,
+                            ),
+                            bounds,
+                            content_bounds,
+                        );
+
+                        self.horizontal_scroller_grabbed_at =
+                            Some(scroller_grabbed_at);
+                    }
+                }
+            }
+        }
+
+        is_mouse_over_scrollbar
+    }
+
+    /// Returns whether a scroller is currently grabbed or not.
     pub fn is_scroller_grabbed(&self) -> bool {
         self.scroller_grabbed_at.is_some()
+            || self.horizontal_scroller_grabbed_at.is_some()
+    }
+}
+
+/// The visible part of the contents of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The horizontal and vertical scrolling offsets, in pixels.
+    pub offset: Vector,
+
+    /// The bounds of the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub bounds: Rectangle,
+
+    /// The bounds of the contents of the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub content_bounds: Rectangle,
+}
+
+impl Viewport {
+    /// Returns the horizontal and vertical scrolling offsets of the
+    /// [`Viewport`] as relative amounts.
+    ///
+    /// `0` represents the start of the contents, while `1` represents their
+    /// end. Contents that fit in the [`Viewport`] are at the start.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn relative_offset(&self) -> Vector {
+        let relative = |offset: f32, hidden: f32| {
+            if hidden > 0.0 {
+                (offset / hidden).min(1.0)
+            } else {
+                0.0
+            }
+        };
+
+        Vector::new(
+            relative(
+                self.offset.x,
+                self.content_bounds.width - self.bounds.width,
+            ),
+            relative(
+                self.offset.y,
+                self.content_bounds.height - self.bounds.height,
+            ),
+        )
+    }
+
+    /// Returns whether the [`Viewport`] shows the end of the contents
+    /// vertically, which is useful to stick to the bottom of a growing list.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn is_at_bottom(&self) -> bool {
+        self.offset.y + self.bounds.height >= self.content_bounds.height
     }
 }
 
@@ -407,11 +1109,11 @@ pub struct Scrollbar {
 }
 
 impl Scrollbar {
-    fn is_mouse_over(&self, cursor_position: Point) -> bool {
+    pub(crate) fn is_mouse_over(&self, cursor_position: Point) -> bool {
         self.bounds.contains(cursor_position)
     }
 
-    fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
+    pub(crate) fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
         if self.bounds.contains(cursor_position) {
             Some(if self.scroller.bounds.contains(cursor_position) {
                 (cursor_position.y - self.scroller.bounds.y)
@@ -424,7 +1126,7 @@ impl Scrollbar {
         }
     }
 
-    fn scroll_percentage(
+    pub(crate) fn scroll_percentage(
         &self,
         grabbed_at: f32,
         cursor_position: Point,
@@ -434,6 +1136,33 @@ impl Scrollbar {
             - self.scroller.bounds.height * grabbed_at)
             / (self.bounds.height - self.scroller.bounds.height)
     }
+
+    pub(crate) fn grab_horizontal_scroller(
+        &self,
+        cursor_position: Point,
+    ) -> Option<f32> {
+        if self.bounds.contains(cursor_position) {
+            Some(if self.scroller.bounds.contains(cursor_position) {
+                (cursor_position.x - self.scroller.bounds.x)
+                    / self.scroller.bounds.width
+            } else {
+                0.5
+            })
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn horizontal_scroll_percentage(
+        &self,
+        grabbed_at: f32,
+        cursor_position: Point,
+    ) -> f32 {
+        (cursor_position.x
+            - self.bounds.x
+            - self.scroller.bounds.width * grabbed_at)
+            / (self.bounds.width - self.scroller.bounds.width)
+    }
 }
 
 /// The handle of a [`Scrollbar`].
@@ -458,8 +1187,8 @@ pub trait Renderer: crate::Renderer + Sized {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Returns the [`Scrollbar`] given the bounds and content bounds of a
-    /// [`Scrollable`].
+    /// Returns the vertical [`Scrollbar`] given the bounds and content
+    /// bounds of a [`Scrollable`], its offset and its style.
     ///
     /// [`Scrollbar`]: struct.Scrollbar.html
     /// [`Scrollable`]: struct.Scrollable.html
@@ -468,6 +1197,20 @@ pub trait Renderer: crate::Renderer + Sized {
         bounds: Rectangle,
         content_bounds: Rectangle,
         offset: u32,
+        style: &Self::Style,
+    ) -> Option<Scrollbar>;
+
+    /// Returns the horizontal [`Scrollbar`] given the bounds and content
+    /// bounds of a [`Scrollable`], its horizontal offset and its style.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    fn horizontal_scrollbar(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        offset: u32,
+        style: &Self::Style,
     ) -> Option<Scrollbar>;
 
     /// Draws the [`Scrollable`].
@@ -477,9 +1220,10 @@ pub trait Renderer: crate::Renderer + Sized {
     /// - the bounds of the [`Scrollable`] widget
     /// - the bounds of the [`Scrollable`] content
     /// - whether the mouse is over the [`Scrollable`] or not
-    /// - whether the mouse is over the [`Scrollbar`] or not
-    /// - a optional [`Scrollbar`] to be rendered
-    /// - the scrolling offset
+    /// - whether the mouse is over a [`Scrollbar`] or not
+    /// - an optional vertical [`Scrollbar`] to be rendered
+    /// - an optional horizontal [`Scrollbar`] to be rendered
+    /// - the horizontal and vertical scrolling offsets
     /// - the drawn content
     ///
     /// [`Scrollbar`]: struct.Scrollbar.html
@@ -493,7 +1237,8 @@ pub trait Renderer: crate::Renderer + Sized {
         is_mouse_over: bool,
         is_mouse_over_scrollbar: bool,
         scrollbar: Option<Scrollbar>,
-        offset: u32,
+        horizontal_scrollbar: Option<Scrollbar>,
+        offset: Vector<u32>,
         style: &Self::Style,
         content: Self::Output,
     ) -> Self::Output;
@@ -511,3 +1256,108 @@ where
         Element::new(scrollable)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Row};
+
+    #[test]
+    fn content_overflows_horizontally() {
+        let mut state = State::new();
+
+        let node = {
+            let scrollable: Scrollable<'_, (), Null> =
+                Scrollable::new(&mut state)
+                    .width(Length::Fill)
+                    .direction(Direction::Horizontal)
+                    .push(Row::new().width(Length::Units(300)));
+
+            Widget::<(), Null>::layout(
+                &scrollable,
+                &Null::new(),
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 50.0)),
+            )
+        };
+
+        let bounds = node.bounds();
+        let content_bounds = node.children()[0].bounds();
+
+        assert_eq!(bounds.width, 100.0);
+        assert_eq!(content_bounds.width, 300.0);
+
+        state.scroll_horizontally(-500.0, bounds, content_bounds);
+
+        assert_eq!(state.horizontal_offset(bounds, content_bounds), 200);
+        assert_eq!(state.offset(bounds, content_bounds), 0);
+    }
+
+    #[test]
+    fn content_filling_the_width_does_not_overflow() {
+        let mut state = State::new();
+
+        let scrollable: Scrollable<'_, (), Null> = Scrollable::new(&mut state)
+            .width(Length::Fill)
+            .direction(Direction::Both)
+            .push(Row::new().width(Length::Fill).height(Length::Units(300)));
+
+        let node = Widget::<(), Null>::layout(
+            &scrollable,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 50.0)),
+        );
+
+        assert_eq!(node.bounds().width, 100.0);
+        assert_eq!(node.children()[0].bounds().width, 100.0);
+        assert_eq!(node.children()[0].bounds().height, 300.0);
+    }
+
+    #[test]
+    fn snapping_follows_growing_content() {
+        let mut state = State::new();
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let content =
+            |height| Rectangle::new(Point::ORIGIN, Size::new(100.0, height));
+
+        state.snap_to(1.0);
+
+        assert_eq!(state.offset(bounds, content(300.0)), 200);
+        assert_eq!(state.offset(bounds, content(500.0)), 400);
+
+        state.scroll(100.0, bounds, content(500.0));
+
+        assert_eq!(state.offset(bounds, content(800.0)), 300);
+    }
+
+    #[test]
+    fn reach_is_produced_when_crossing_threshold() {
+        let reach = Reach::new(50, ());
+
+        assert!(reach.is_reached(100.0, 50.0));
+        assert!(reach.is_reached(60.0, 0.0));
+        assert!(!reach.is_reached(100.0, 60.0));
+        assert!(!reach.is_reached(40.0, 0.0));
+    }
+
+    #[test]
+    fn scrollbars_fade_out_after_inactivity() {
+        let mut state = State::new();
+        let delay = Duration::from_secs(1);
+        let start = Instant::now();
+
+        assert_eq!(state.scrollbar_visibility(), 1.0);
+
+        state.fade_scrollbars(delay, start);
+        state.fade_scrollbars(delay, start + delay);
+        assert_eq!(state.scrollbar_visibility(), 1.0);
+
+        state.fade_scrollbars(delay, start + delay + FADE_DURATION / 2);
+        assert!((state.scrollbar_visibility() - 0.5).abs() < 0.01);
+
+        state.fade_scrollbars(delay, start + delay + FADE_DURATION);
+        assert_eq!(state.scrollbar_visibility(), 0.0);
+
+        state.show_scrollbars(start + delay * 2);
+        assert_eq!(state.scrollbar_visibility(), 1.0);
+    }
+}
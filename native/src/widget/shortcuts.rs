@@ -0,0 +1,402 @@
+//! Produce messages with keyboard shortcuts.
+use std::hash::Hash;
+
+use crate::keyboard::{self, KeyCode, ModifiersState};
+use crate::text_input::platform;
+use crate::{
+    layout, overlay, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Widget,
+};
+
+/// A container that produces messages when some keyboard shortcuts are
+/// pressed.
+///
+/// Wrapping the whole view of an application registers application-level
+/// shortcuts. A pressed shortcut produces its message and is not seen by
+/// the content.
+///
+/// While a widget of the content is focused, shortcuts without the control,
+/// alt, or logo modifiers are given to it first, so they do not get in the
+/// way of typing. So are the shortcuts a text field uses for editing, like
+/// `Ctrl+C` to copy or `Ctrl+Z` to undo.
+///
+/// A shortcut registered twice in the same [`Shortcuts`] is logged as a
+/// conflict, and the first registration wins. When nesting [`Shortcuts`], the
+/// outermost one wins.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{keyboard::{KeyCode, ModifiersState}, renderer::Null};
+/// # use iced_native::Text;
+/// #
+/// # pub type Shortcuts<'a, Message> =
+/// #     iced_native::Shortcuts<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+///     Search,
+/// }
+///
+/// let shortcuts = Shortcuts::new(Text::new("An editor"))
+///     .on_key_press(KeyCode::S, ModifiersState::CONTROL, Message::Save)
+///     .on_key_press(KeyCode::Slash, ModifiersState::NONE, Message::Search);
+/// ```
+///
+/// [`Shortcuts`]: struct.Shortcuts.html
+#[allow(missing_debug_implementations)]
+pub struct Shortcuts<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    shortcuts: Vec<(Shortcut, Message)>,
+}
+
+impl<'a, Message, Renderer> Shortcuts<'a, Message, Renderer> {
+    /// Creates a [`Shortcuts`] with the given content and no shortcuts.
+    ///
+    /// [`Shortcuts`]: struct.Shortcuts.html
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Shortcuts {
+            content: content.into(),
+            shortcuts: Vec::new(),
+        }
+    }
+
+    /// Registers a shortcut that produces the given message when the key is
+    /// pressed with exactly the given modifiers.
+    ///
+    /// If the shortcut is already registered, the conflict is logged and the
+    /// first registration wins.
+    pub fn on_key_press(
+        self,
+        key_code: KeyCode,
+        modifiers: ModifiersState,
+        message: Message,
+    ) -> Self {
+        self.push(Shortcut::new(key_code, modifiers), message)
+    }
+
+    /// Registers a [`Shortcut`] that produces the given message.
+    ///
+    /// If the [`Shortcut`] is already registered, the conflict is logged and
+    /// the first registration wins.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn push(mut self, shortcut: Shortcut, message: Message) -> Self {
+        if self.shortcuts.iter().any(|(other, _)| *other == shortcut) {
+            log::warn!("Shortcut {:?} is registered twice", shortcut);
+        } else {
+            self.shortcuts.push((shortcut, message));
+        }
+
+        self
+    }
+}
+
+/// A key pressed together with some modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shortcut {
+    /// The pressed key.
+    pub key_code: KeyCode,
+
+    /// The modifiers that must be pressed with the key.
+    pub modifiers: ModifiersState,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] for the given key and modifiers.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn new(key_code: KeyCode, modifiers: ModifiersState) -> Self {
+        Shortcut {
+            key_code,
+            modifiers,
+        }
+    }
+
+    /// Returns whether the [`Shortcut`] could be typed in a text field.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    fn is_typed(&self) -> bool {
+        !(self.modifiers.control || self.modifiers.alt || self.modifiers.logo)
+    }
+
+    /// Returns whether the [`Shortcut`] is used by a text field for editing,
+    /// like selecting everything, using the clipboard, undoing, or jumping
+    /// over words.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    fn is_editing(&self) -> bool {
+        match self.key_code {
+            KeyCode::A
+            | KeyCode::C
+            | KeyCode::V
+            | KeyCode::X
+            | KeyCode::Y
+            | KeyCode::Z => {
+                platform::is_copy_paste_modifier_pressed(self.modifiers)
+            }
+            KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::Backspace
+            | KeyCode::Delete => {
+                platform::is_jump_modifier_pressed(self.modifiers)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Shortcuts<'a, Message, Renderer> {
+    /// Finds the shortcut pressed by the given event, if any.
+    fn find(&self, event: &keyboard::Event) -> Option<&(Shortcut, Message)> {
+        match event {
+            keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            } => {
+                let pressed = Shortcut::new(*key_code, *modifiers);
+
+                self.shortcuts
+                    .iter()
+                    .find(|(shortcut, _)| *shortcut == pressed)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Shortcuts<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        // The keyboard event was not captured by a focused widget
+        if let Event::Keyboard(keyboard_event) = &event {
+            if let Some((_, message)) = self.find(keyboard_event) {
+                messages.push(message.clone());
+                return;
+            }
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout)
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<crate::focus::Target<'b>>,
+    ) {
+        self.content.focus_targets(layout, targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        let (shortcut, message) = match self.find(&event) {
+            Some((shortcut, message)) => (*shortcut, message.clone()),
+            None => {
+                return self.content.on_focused_event(
+                    event, layout, messages, renderer, clipboard,
+                )
+            }
+        };
+
+        // Shortcuts take precedence over the focused widget, unless they
+        // could be used for typing
+        if (shortcut.is_typed() || shortcut.is_editing())
+            && self
+                .content
+                .on_focused_event(event, layout, messages, renderer, clipboard)
+        {
+            return true;
+        }
+
+        messages.push(message);
+
+        true
+    }
+}
+
+impl<'a, Message, Renderer> From<Shortcuts<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        shortcuts: Shortcuts<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(shortcuts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, text_input, Column, Size, TextInput};
+
+    fn press(
+        shortcuts: &mut Shortcuts<'_, u8, Null>,
+        key_code: KeyCode,
+        modifiers: ModifiersState,
+    ) -> Vec<u8> {
+        let renderer = Null::new();
+        let node = Widget::<u8, Null>::layout(
+            shortcuts,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let event = keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        };
+        let mut messages = Vec::new();
+
+        // Like a `UserInterface`, the focused widget sees the event first
+        if !Widget::<u8, Null>::on_focused_event(
+            shortcuts,
+            event,
+            Layout::new(&node),
+            &mut messages,
+            &renderer,
+            None,
+        ) {
+            Widget::<u8, Null>::on_event(
+                shortcuts,
+                Event::Keyboard(event),
+                Layout::new(&node),
+                Point::ORIGIN,
+                &mut messages,
+                &renderer,
+                None,
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn typed_shortcuts_are_ignored_while_typing() {
+        let mut state = text_input::State::focused();
+
+        let mut shortcuts = Shortcuts::new(Column::new().push(TextInput::new(
+            &mut state,
+            "",
+            "",
+            |_| 0,
+        )))
+        .on_key_press(KeyCode::S, ModifiersState::CONTROL, 1)
+        .on_key_press(KeyCode::Slash, ModifiersState::NONE, 2);
+
+        assert_eq!(
+            press(&mut shortcuts, KeyCode::S, ModifiersState::CONTROL),
+            vec![1]
+        );
+        assert_eq!(
+            press(&mut shortcuts, KeyCode::Slash, ModifiersState::NONE),
+            Vec::<u8>::new()
+        );
+        assert_eq!(
+            press(
+                &mut shortcuts,
+                KeyCode::S,
+                ModifiersState::CONTROL | ModifiersState::SHIFT
+            ),
+            Vec::<u8>::new()
+        );
+    }
+
+    #[test]
+    fn editing_shortcuts_are_ignored_while_typing() {
+        let command = if cfg!(target_os = "macos") {
+            ModifiersState::LOGO
+        } else {
+            ModifiersState::CONTROL
+        };
+
+        let mut focused = text_input::State::focused();
+        let mut unfocused = text_input::State::new();
+
+        for (state, expected) in
+            [(&mut focused, vec![]), (&mut unfocused, vec![1, 2])]
+        {
+            let mut shortcuts = Shortcuts::new(
+                Column::new().push(TextInput::new(state, "", "", |_| 0)),
+            )
+            .on_key_press(KeyCode::Z, command, 1)
+            .on_key_press(KeyCode::C, command, 2);
+
+            let mut messages = press(&mut shortcuts, KeyCode::Z, command);
+            messages.extend(press(&mut shortcuts, KeyCode::C, command));
+
+            assert_eq!(messages, expected);
+        }
+    }
+}
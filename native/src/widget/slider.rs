@@ -5,18 +5,24 @@
 //! [`Slider`]: struct.Slider.html
 //! [`State`]: struct.State.html
 use crate::{
-    layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Size, Widget,
+    focus, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Id,
+    Layout, Length, Point, Rectangle, Size, Widget,
 };
 
 use std::{hash::Hash, ops::RangeInclusive};
 
-/// An horizontal bar and a handle that selects a single value from a range of
-/// values.
+/// A bar and a handle that selects a single value from a range of values.
 ///
-/// A [`Slider`] will try to fill the horizontal space of its container.
+/// A [`Slider`] is horizontal by default and will try to fill the horizontal
+/// space of its container. A vertical [`Slider`] fills the vertical space
+/// instead, with its lowest value at the bottom.
+///
+/// The value of a [`Slider`] can be snapped to discrete [`step`]s, and tick
+/// marks can be shown along its rail using [`ticks`].
 ///
 /// [`Slider`]: struct.Slider.html
+/// [`step`]: #method.step
+/// [`ticks`]: #method.ticks
 ///
 /// # Example
 /// ```
@@ -30,7 +36,9 @@ use std::{hash::Hash, ops::RangeInclusive};
 /// let state = &mut slider::State::new();
 /// let value = 50.0;
 ///
-/// Slider::new(state, 0.0..=100.0, value, Message::SliderChanged);
+/// Slider::new(state, 0.0..=100.0, value, Message::SliderChanged)
+///     .step(5.0)
+///     .ticks(25.0);
 /// ```
 ///
 /// ![Slider drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/slider.png?raw=true)
@@ -41,7 +49,14 @@ pub struct Slider<'a, Message, Renderer: self::Renderer> {
     value: f32,
     on_change: Box<dyn Fn(f32) -> Message>,
     on_release: Option<Message>,
+    is_enabled: bool,
+    step: Option<f32>,
+    ticks: Vec<f32>,
+    orientation: Orientation,
     width: Length,
+    height: Length,
+    tab_index: Option<u16>,
+    id: Option<Id>,
     style: Renderer::Style,
 }
 
@@ -73,7 +88,14 @@ impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
             range,
             on_change: Box::new(on_change),
             on_release: None,
+            is_enabled: true,
+            step: None,
+            ticks: Vec::new(),
+            orientation: Orientation::Horizontal,
             width: Length::Fill,
+            height: Length::Fill,
+            tab_index: None,
+            id: None,
             style: Renderer::Style::default(),
         }
     }
@@ -91,7 +113,34 @@ impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
         self
     }
 
-    /// Sets the width of the [`Slider`].
+    /// Snaps the value of the [`Slider`] to multiples of the given step,
+    /// counting from the start of its range.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Shows tick marks along the rail of the [`Slider`] every `step` units,
+    /// counting from the start of its range.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn ticks(mut self, step: f32) -> Self {
+        self.ticks = ticks(&self.range, step);
+        self
+    }
+
+    /// Sets the [`Orientation`] of the [`Slider`].
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    /// [`Slider`]: struct.Slider.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the width of an horizontal [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
     pub fn width(mut self, width: Length) -> Self {
@@ -99,6 +148,40 @@ impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the height of a vertical [`Slider`].
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Disables the [`Slider`], which is then shown but cannot be dragged.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn disabled(mut self) -> Self {
+        self.is_enabled = false;
+        self
+    }
+
+    /// Sets the position of the [`Slider`] in the focus order.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Slider`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`Slider`]: struct.Slider.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
@@ -108,12 +191,29 @@ impl<'a, Message, Renderer: self::Renderer> Slider<'a, Message, Renderer> {
     }
 }
 
+/// The orientation of a [`Slider`].
+///
+/// [`Slider`]: struct.Slider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The [`Slider`] goes from left to right.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    Horizontal,
+
+    /// The [`Slider`] goes from bottom to top.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    Vertical,
+}
+
 /// The local state of a [`Slider`].
 ///
 /// [`Slider`]: struct.Slider.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -125,6 +225,20 @@ impl State {
     }
 }
 
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Slider<'a, Message, Renderer>
 where
@@ -132,11 +246,17 @@ where
     Message: Clone,
 {
     fn width(&self) -> Length {
-        self.width
+        match self.orientation {
+            Orientation::Horizontal => self.width,
+            Orientation::Vertical => Length::Shrink,
+        }
     }
 
     fn height(&self) -> Length {
-        Length::Shrink
+        match self.orientation {
+            Orientation::Horizontal => Length::Shrink,
+            Orientation::Vertical => self.height,
+        }
     }
 
     fn layout(
@@ -144,13 +264,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let limits = limits
-            .width(self.width)
-            .height(Length::Units(renderer.height() as u16));
-
-        let size = limits.resolve(Size::ZERO);
-
-        layout::Node::new(size)
+        layout(renderer, limits, self.orientation, self.width, self.height)
     }
 
     fn on_event(
@@ -162,20 +276,22 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) {
+        if !self.is_enabled {
+            self.state.is_dragging = false;
+
+            return;
+        }
+
         let mut change = || {
-            let bounds = layout.bounds();
-
-            if cursor_position.x <= bounds.x {
-                messages.push((self.on_change)(*self.range.start()));
-            } else if cursor_position.x >= bounds.x + bounds.width {
-                messages.push((self.on_change)(*self.range.end()));
-            } else {
-                let percent = (cursor_position.x - bounds.x) / bounds.width;
-                let value = (self.range.end() - self.range.start()) * percent
-                    + self.range.start();
-
-                messages.push((self.on_change)(value));
-            }
+            let value = value_at(
+                layout.bounds(),
+                cursor_position,
+                &self.range,
+                self.step,
+                self.orientation,
+            );
+
+            messages.push((self.on_change)(value));
         };
 
         match event {
@@ -218,6 +334,9 @@ where
             self.range.clone(),
             self.value,
             self.state.is_dragging,
+            !self.is_enabled,
+            self.orientation,
+            &self.ticks,
             &self.style,
         )
     }
@@ -226,8 +345,172 @@ where
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
+        self.orientation.hash(state);
         self.width.hash(state);
+        self.height.hash(state);
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        // Disabled sliders cannot be focused
+        if self.is_enabled {
+            targets.push(focus::Target::new(
+                self.state,
+                layout.bounds(),
+                self.tab_index,
+                self.id.clone(),
+            ));
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.state.is_focused {
+            return false;
+        }
+
+        let key_code = match event {
+            keyboard::Event::KeyPressed { key_code, .. } => key_code,
+            _ => return false,
+        };
+
+        match value_after_key(self.value, &self.range, self.step, key_code) {
+            Some(value) => {
+                if (value - self.value).abs() > f32::EPSILON {
+                    messages.push((self.on_change)(value));
+
+                    if let Some(on_release) = self.on_release.clone() {
+                        messages.push(on_release);
+                    }
+                }
+
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Lays out a slider with the given [`Orientation`].
+///
+/// [`Orientation`]: enum.Orientation.html
+pub(crate) fn layout<Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    orientation: Orientation,
+    width: Length,
+    height: Length,
+) -> layout::Node
+where
+    Renderer: self::Renderer,
+{
+    let thickness = Length::Units(renderer.height() as u16);
+
+    let limits = match orientation {
+        Orientation::Horizontal => limits.width(width).height(thickness),
+        Orientation::Vertical => limits.width(thickness).height(height),
+    };
+
+    layout::Node::new(limits.resolve(Size::ZERO))
+}
+
+/// Computes the value of a slider under the cursor, snapped to the given
+/// step.
+pub(crate) fn value_at(
+    bounds: Rectangle,
+    cursor_position: Point,
+    range: &RangeInclusive<f32>,
+    step: Option<f32>,
+    orientation: Orientation,
+) -> f32 {
+    let percent = match orientation {
+        Orientation::Horizontal => {
+            (cursor_position.x - bounds.x) / bounds.width
+        }
+        Orientation::Vertical => {
+            (bounds.y + bounds.height - cursor_position.y) / bounds.height
+        }
+    };
+
+    let (start, end) = (*range.start(), *range.end());
+
+    let value = if percent <= 0.0 {
+        start
+    } else if percent >= 1.0 {
+        end
+    } else {
+        start + (end - start) * percent
+    };
+
+    snap(value, range, step)
+}
+
+/// Clamps a value to the given range and snaps it to the given step.
+pub(crate) fn snap(
+    value: f32,
+    range: &RangeInclusive<f32>,
+    step: Option<f32>,
+) -> f32 {
+    let (start, end) = (*range.start(), *range.end());
+    let value = value.max(start).min(end);
+
+    match step {
+        Some(step) if step > 0.0 => {
+            (start + ((value - start) / step).round() * step).min(end)
+        }
+        _ => value,
+    }
+}
+
+/// Computes the value of a focused slider after the given key is pressed, if
+/// the key changes it.
+///
+/// The arrow keys move the value by the given step, or by a hundredth of the
+/// range when there is none. `Home` and `End` move it to the ends of the range.
+pub(crate) fn value_after_key(
+    value: f32,
+    range: &RangeInclusive<f32>,
+    step: Option<f32>,
+    key_code: keyboard::KeyCode,
+) -> Option<f32> {
+    let (start, end) = (*range.start(), *range.end());
+
+    let increment = match step {
+        Some(step) if step > 0.0 => step,
+        _ => (end - start) / 100.0,
+    };
+
+    let value = match key_code {
+        keyboard::KeyCode::Right | keyboard::KeyCode::Up => value + increment,
+        keyboard::KeyCode::Left | keyboard::KeyCode::Down => value - increment,
+        keyboard::KeyCode::Home => start,
+        keyboard::KeyCode::End => end,
+        _ => return None,
+    };
+
+    Some(snap(value, range, step))
+}
+
+/// Produces the values of the tick marks of a slider, placed every `step`
+/// units from the start of the range.
+pub(crate) fn ticks(range: &RangeInclusive<f32>, step: f32) -> Vec<f32> {
+    if step.is_nan() || step <= 0.0 {
+        return Vec::new();
     }
+
+    let (start, end) = (*range.start(), *range.end());
+    let count = ((end - start) / step).floor().max(0.0) as usize;
+
+    (0..=count).map(|i| start + i as f32 * step).collect()
 }
 
 /// The renderer of a [`Slider`].
@@ -241,7 +524,8 @@ pub trait Renderer: crate::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Returns the height of the [`Slider`].
+    /// Returns the thickness of the [`Slider`], which is its height when
+    /// horizontal and its width when vertical.
     ///
     /// [`Slider`]: struct.Slider.html
     fn height(&self) -> u32;
@@ -254,10 +538,13 @@ pub trait Renderer: crate::Renderer {
     ///   * the local state of the [`Slider`]
     ///   * the range of values of the [`Slider`]
     ///   * the current value of the [`Slider`]
+    ///   * whether the [`Slider`] is disabled or not
+    ///   * the [`Orientation`] of the [`Slider`]
+    ///   * the values where tick marks should be drawn
     ///
     /// [`Slider`]: struct.Slider.html
     /// [`State`]: struct.State.html
-    /// [`Class`]: enum.Class.html
+    /// [`Orientation`]: enum.Orientation.html
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -265,6 +552,9 @@ pub trait Renderer: crate::Renderer {
         range: RangeInclusive<f32>,
         value: f32,
         is_dragging: bool,
+        is_disabled: bool,
+        orientation: Orientation,
+        ticks: &[f32],
         style: &Self::Style,
     ) -> Self::Output;
 }
@@ -0,0 +1,175 @@
+//! Show an indeterminate loading indicator.
+use crate::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A spinning arc that indicates that some work is in progress.
+///
+/// A [`Spinner`] animates on its own: it requests new frames from the runtime
+/// while it is displayed, so no subscription is needed.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Spinner = iced_native::Spinner<Null>;
+/// use std::time::Duration;
+///
+/// Spinner::new().size(24).cycle_duration(Duration::from_millis(800));
+/// ```
+///
+/// [`Spinner`]: struct.Spinner.html
+#[allow(missing_debug_implementations)]
+pub struct Spinner<Renderer: self::Renderer> {
+    size: Option<u16>,
+    cycle_duration: Duration,
+    style: Renderer::Style,
+}
+
+impl<Renderer: self::Renderer> Spinner<Renderer> {
+    /// Creates a new [`Spinner`].
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn new() -> Self {
+        Spinner {
+            size: None,
+            cycle_duration: Duration::from_secs(1),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the diameter of the [`Spinner`].
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the time the [`Spinner`] takes to complete a turn.
+    ///
+    /// By default, it is one second.
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn cycle_duration(mut self, cycle_duration: Duration) -> Self {
+        self.cycle_duration = cycle_duration;
+        self
+    }
+
+    /// Sets the style of the [`Spinner`].
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the progress of the current turn, between 0 and 1.
+    fn phase(&self) -> f32 {
+        let cycle = self.cycle_duration.as_millis().max(1);
+
+        let elapsed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+
+        (elapsed % cycle) as f32 / cycle as f32
+    }
+}
+
+impl<Renderer: self::Renderer> Default for Spinner<Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Spinner<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE))
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE))
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE));
+        let limits = limits.width(size).height(size);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.request_redraw();
+
+        renderer.draw(layout.bounds(), self.phase(), &self.style)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of a [`Spinner`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Spinner`] in your user interface. The [`Spinner`] will
+/// only animate if the renderer supports [`request_redraw`].
+///
+/// [`Spinner`]: struct.Spinner.html
+/// [renderer]: ../../renderer/index.html
+/// [`request_redraw`]: ../../trait.Renderer.html#method.request_redraw
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default diameter of a [`Spinner`].
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    const DEFAULT_SIZE: u16;
+
+    /// Draws a [`Spinner`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Spinner`]
+    ///   * the progress of the current turn, between 0 and 1
+    ///   * the style of the [`Spinner`]
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        phase: f32,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Spinner<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(spinner: Spinner<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(spinner)
+    }
+}
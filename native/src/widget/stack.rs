@@ -0,0 +1,460 @@
+//! Layer content on top of each other.
+use std::hash::Hash;
+
+use crate::{
+    focus, keyboard, layout, overlay, Align, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Size, Vector, Widget,
+};
+
+/// A container that layers its contents on top of each other.
+///
+/// Every element of a [`Stack`] is positioned with an [`Anchor`], and drawn
+/// over the elements pushed before it. It is useful to place badges,
+/// watermarks or floating buttons over some content.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, stack::Anchor, Length, Text};
+/// #
+/// # pub type Stack<'a, Message> = iced_native::Stack<'a, Message, Null>;
+/// let stack = Stack::<()>::new()
+///     .width(Length::Fill)
+///     .push(Text::new("Inbox"))
+///     .push_anchored(Text::new("3"), Anchor::TOP_RIGHT.offset(-4.0, 4.0));
+/// ```
+///
+/// [`Stack`]: struct.Stack.html
+/// [`Anchor`]: struct.Anchor.html
+#[allow(missing_debug_implementations)]
+pub struct Stack<'a, Message, Renderer> {
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    anchors: Vec<Anchor>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    /// Creates an empty [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn new() -> Self {
+        Stack {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            anchors: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Adds an [`Element`] to the top-left corner of the [`Stack`], over the
+    /// previous ones.
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Stack`]: struct.Stack.html
+    pub fn push<E>(self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_anchored(child, Anchor::TOP_LEFT)
+    }
+
+    /// Adds an [`Element`] to the [`Stack`] at the given [`Anchor`], over
+    /// the previous ones.
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Stack`]: struct.Stack.html
+    /// [`Anchor`]: struct.Anchor.html
+    pub fn push_anchored<E>(mut self, child: E, anchor: Anchor) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.anchors.push(anchor);
+        self.children.push(child.into());
+        self
+    }
+
+    /// Returns the cursor position seen by every child of the [`Stack`].
+    ///
+    /// Children covered by another one under the cursor do not see it.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    fn cursor_positions(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Vec<Point> {
+        let mut is_covered = false;
+
+        let mut positions: Vec<Point> = layout
+            .children()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .map(|layout| {
+                if is_covered {
+                    Point::new(-1.0, -1.0)
+                } else {
+                    is_covered = layout.bounds().contains(cursor_position);
+
+                    cursor_position
+                }
+            })
+            .collect();
+
+        positions.reverse();
+        positions
+    }
+}
+
+impl<'a, Message, Renderer> Default for Stack<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The position of an element inside of a [`Stack`].
+///
+/// An [`Anchor`] aligns an element to a side, a corner or the center of the
+/// [`Stack`], and then moves it by an absolute offset.
+///
+/// [`Stack`]: struct.Stack.html
+/// [`Anchor`]: struct.Anchor.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anchor {
+    pub(crate) horizontal: Align,
+    pub(crate) vertical: Align,
+    pub(crate) offset: Vector,
+}
+
+impl Anchor {
+    /// The top-left corner.
+    pub const TOP_LEFT: Anchor = Anchor::new(Align::Start, Align::Start);
+
+    /// The center of the top side.
+    pub const TOP: Anchor = Anchor::new(Align::Center, Align::Start);
+
+    /// The top-right corner.
+    pub const TOP_RIGHT: Anchor = Anchor::new(Align::End, Align::Start);
+
+    /// The center of the left side.
+    pub const LEFT: Anchor = Anchor::new(Align::Start, Align::Center);
+
+    /// The center.
+    pub const CENTER: Anchor = Anchor::new(Align::Center, Align::Center);
+
+    /// The center of the right side.
+    pub const RIGHT: Anchor = Anchor::new(Align::End, Align::Center);
+
+    /// The bottom-left corner.
+    pub const BOTTOM_LEFT: Anchor = Anchor::new(Align::Start, Align::End);
+
+    /// The center of the bottom side.
+    pub const BOTTOM: Anchor = Anchor::new(Align::Center, Align::End);
+
+    /// The bottom-right corner.
+    pub const BOTTOM_RIGHT: Anchor = Anchor::new(Align::End, Align::End);
+
+    /// Creates a new [`Anchor`] with the given horizontal and vertical
+    /// alignments.
+    ///
+    /// [`Anchor`]: struct.Anchor.html
+    pub const fn new(horizontal: Align, vertical: Align) -> Self {
+        Anchor {
+            horizontal,
+            vertical,
+            offset: Vector::new(0.0, 0.0),
+        }
+    }
+
+    /// Creates a new [`Anchor`] at an absolute position from the top-left
+    /// corner.
+    ///
+    /// [`Anchor`]: struct.Anchor.html
+    pub fn absolute(x: f32, y: f32) -> Self {
+        Anchor::TOP_LEFT.offset(x, y)
+    }
+
+    /// Moves the element of the [`Anchor`] by the given offset, once it is
+    /// aligned.
+    ///
+    /// [`Anchor`]: struct.Anchor.html
+    pub fn offset(self, x: f32, y: f32) -> Self {
+        Anchor {
+            offset: Vector::new(x, y),
+            ..self
+        }
+    }
+}
+
+impl Hash for Anchor {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.horizontal.hash(state);
+        self.vertical.hash(state);
+        self.offset.x.to_bits().hash(state);
+        self.offset.y.to_bits().hash(state);
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        let child_limits = layout::Limits::new(Size::ZERO, limits.max());
+
+        let mut nodes: Vec<layout::Node> = self
+            .children
+            .iter()
+            .map(|child| child.layout(renderer, &child_limits))
+            .collect();
+
+        let intrinsic = nodes.iter().fold(Size::ZERO, |size, node| {
+            Size::new(
+                size.width.max(node.size().width),
+                size.height.max(node.size().height),
+            )
+        });
+
+        let size = limits.resolve(intrinsic);
+
+        for (node, anchor) in nodes.iter_mut().zip(&self.anchors) {
+            node.move_to(Point::ORIGIN + anchor.offset);
+            node.align(anchor.horizontal, anchor.vertical, size);
+        }
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let cursor_positions = self.cursor_positions(layout, cursor_position);
+
+        // The topmost children receive events first
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .zip(cursor_positions)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .for_each(|((child, layout), cursor_position)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            });
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let cursor_positions = self.cursor_positions(layout, cursor_position);
+
+        renderer.draw(defaults, &self.children, layout, &cursor_positions)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.anchors.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .for_each(|(child, layout)| child.focus_targets(layout, targets));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.children.iter_mut().zip(layout.children()).any(
+            |(child, layout)| {
+                child.on_focused_event(
+                    event, layout, messages, renderer, clipboard,
+                )
+            },
+        )
+    }
+}
+
+/// The renderer of a [`Stack`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Stack`] in your user interface.
+///
+/// [`Stack`]: struct.Stack.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Stack`].
+    ///
+    /// It receives:
+    /// - the children of the [`Stack`], from bottom to top
+    /// - the [`Layout`] of the [`Stack`] and its children
+    /// - the cursor position seen by every child, which is moved away from
+    ///   the children covered by another one
+    ///
+    /// Every child should be drawn over the previous ones.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_positions: &[Point],
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        stack: Stack<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(stack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Rectangle, Row};
+
+    #[test]
+    fn children_are_anchored() {
+        let item = |width, height| {
+            Row::<(), Null>::new()
+                .width(Length::Units(width))
+                .height(Length::Units(height))
+        };
+
+        let stack: Stack<'_, (), Null> = Stack::new()
+            .push(item(40, 30))
+            .push_anchored(item(10, 10), Anchor::BOTTOM_RIGHT)
+            .push_anchored(item(10, 10), Anchor::CENTER.offset(2.0, -3.0))
+            .push_anchored(item(5, 5), Anchor::absolute(4.0, 6.0));
+
+        let node = Widget::<(), Null>::layout(
+            &stack,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let bounds: Vec<Rectangle> =
+            node.children().iter().map(layout::Node::bounds).collect();
+
+        assert_eq!(node.size(), Size::new(40.0, 30.0));
+        assert_eq!(
+            Point::new(bounds[1].x, bounds[1].y),
+            Point::new(30.0, 20.0)
+        );
+        assert_eq!(Point::new(bounds[2].x, bounds[2].y), Point::new(17.0, 7.0));
+        assert_eq!(Point::new(bounds[3].x, bounds[3].y), Point::new(4.0, 6.0));
+    }
+}
@@ -1,5 +1,7 @@
 //! Display vector graphics in your application.
-use crate::{layout, Element, Hasher, Layout, Length, Point, Size, Widget};
+use crate::{
+    layout, Color, Element, Hasher, Layout, Length, Point, Size, Widget,
+};
 
 use std::{
     hash::{Hash, Hasher as _},
@@ -14,12 +16,26 @@ use std::{
 /// [`Svg`] images can have a considerable rendering cost when resized,
 /// specially when they are complex.
 ///
+/// The colors of an [`Svg`] can be overridden when it is drawn, which is
+/// useful to make monochrome icons follow the colors of your theme.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{Color, Svg};
+/// #
+/// let icon = Svg::from_path("resources/tiger.svg")
+///     .color(Color::from_rgb(0.3, 0.3, 0.8))
+///     .opacity(0.5);
+/// ```
+///
 /// [`Svg`]: struct.Svg.html
 #[derive(Debug, Clone)]
 pub struct Svg {
     handle: Handle,
     width: Length,
     height: Length,
+    appearance: Appearance,
 }
 
 impl Svg {
@@ -32,6 +48,7 @@ impl Svg {
             handle: handle.into(),
             width: Length::Fill,
             height: Length::Shrink,
+            appearance: Appearance::default(),
         }
     }
 
@@ -58,6 +75,98 @@ impl Svg {
         self.height = height;
         self
     }
+
+    /// Paints both the fill and the stroke of every shape of the [`Svg`]
+    /// with the given [`Color`].
+    ///
+    /// [`Svg`]: struct.Svg.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color(self, color: Color) -> Self {
+        self.fill(color).stroke(color)
+    }
+
+    /// Paints the fill of every shape of the [`Svg`] with the given
+    /// [`Color`], instead of the one in its file.
+    ///
+    /// [`Svg`]: struct.Svg.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn fill(mut self, color: Color) -> Self {
+        self.appearance.fill = Some(color);
+        self
+    }
+
+    /// Paints the stroke of every shape of the [`Svg`] with the given
+    /// [`Color`], instead of the one in its file.
+    ///
+    /// [`Svg`]: struct.Svg.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn stroke(mut self, color: Color) -> Self {
+        self.appearance.stroke = Some(color);
+        self
+    }
+
+    /// Sets the opacity of the [`Svg`], from `0.0` (transparent) to `1.0`
+    /// (opaque).
+    ///
+    /// [`Svg`]: struct.Svg.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        let opacity = opacity.min(1.0);
+
+        self.appearance.opacity = opacity.max(0.0);
+        self
+    }
+}
+
+/// The way an [`Svg`] is painted.
+///
+/// [`Svg`]: struct.Svg.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The [`Color`] replacing the fill of every shape, if any.
+    ///
+    /// [`Color`]: ../../struct.Color.html
+    pub fill: Option<Color>,
+
+    /// The [`Color`] replacing the stroke of every shape, if any.
+    ///
+    /// [`Color`]: ../../struct.Color.html
+    pub stroke: Option<Color>,
+
+    /// The opacity of the whole image.
+    pub opacity: f32,
+}
+
+impl Appearance {
+    /// Returns whether the [`Appearance`] leaves the image untouched.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    pub fn is_original(&self) -> bool {
+        self.fill.is_none() && self.stroke.is_none() && self.opacity >= 1.0
+    }
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            fill: None,
+            stroke: None,
+            opacity: 1.0,
+        }
+    }
+}
+
+impl Hash for Appearance {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for color in &[self.fill, self.stroke] {
+            color
+                .map(|c| {
+                    [c.r.to_bits(), c.g.to_bits(), c.b.to_bits(), c.a.to_bits()]
+                })
+                .hash(state);
+        }
+
+        self.opacity.to_bits().hash(state);
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Svg
@@ -104,7 +213,7 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        renderer.draw(self.handle.clone(), self.appearance, layout)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -213,10 +322,16 @@ pub trait Renderer: crate::Renderer {
     /// [`Handle`]: struct.Handle.html
     fn dimensions(&self, handle: &Handle) -> (u32, u32);
 
-    /// Draws an [`Svg`].
+    /// Draws an [`Svg`] with the given [`Appearance`].
     ///
     /// [`Svg`]: struct.Svg.html
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    /// [`Appearance`]: struct.Appearance.html
+    fn draw(
+        &mut self,
+        handle: Handle,
+        appearance: Appearance,
+        layout: Layout<'_>,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Svg> for Element<'a, Message, Renderer>
@@ -0,0 +1,735 @@
+//! Display data in rows and columns with a fixed header.
+//!
+//! A [`Table`] has some local [`State`].
+//!
+//! [`Table`]: struct.Table.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse, text, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+
+/// The distance to the edge of a column header where the column can be
+/// resized.
+const RESIZE_HANDLE: f32 = 4.0;
+
+/// The minimum width of a column when resized.
+const MIN_COLUMN_WIDTH: f32 = 20.0;
+
+/// A table with a fixed header row, resizable and sortable columns, and
+/// selectable rows.
+///
+/// The cells of a [`Table`] are produced by the [`Column`]s, which receive the
+/// index of the row to display. The [`Table`] does not sort any data itself.
+/// Instead, it produces a message when the user sorts a column, so the
+/// application can sort its data accordingly.
+///
+/// # Example
+/// ```
+/// # use iced_native::{table, renderer::Null, Text};
+/// #
+/// # pub type Table<'a, Message> = iced_native::Table<'a, Message, Null>;
+/// use iced_native::table::{Column, SortOrder};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RowSelected(usize),
+///     Sorted(usize, SortOrder),
+/// }
+///
+/// let people = vec![("Alice", 32), ("Bob", 27)];
+/// let mut state = table::State::new();
+///
+/// let table = Table::new(
+///     &mut state,
+///     people.len(),
+///     vec![
+///         Column::new("Name", |row| Text::new(people[row].0).into()),
+///         Column::new("Age", |row| Text::new(people[row].1.to_string()).into())
+///             .width(50),
+///     ],
+/// )
+/// .on_select(Message::RowSelected)
+/// .on_sort(Message::Sorted);
+/// ```
+///
+/// [`Table`]: struct.Table.html
+/// [`Column`]: struct.Column.html
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    titles: Vec<String>,
+    cells: Vec<Vec<Element<'a, Message, Renderer>>>,
+    selected: Option<usize>,
+    on_select: Option<Box<dyn Fn(usize) -> Message>>,
+    on_sort: Option<Box<dyn Fn(usize, SortOrder) -> Message>>,
+    width: Length,
+    height: Length,
+    padding: u16,
+    text_size: u16,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Table`] with the given amount of rows and [`Column`]s.
+    ///
+    /// The widths of the columns are reset in the [`State`] whenever the
+    /// amount of columns changes.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`Column`]: struct.Column.html
+    /// [`State`]: struct.State.html
+    pub fn new(
+        state: &'a mut State,
+        rows: usize,
+        columns: Vec<Column<'a, Message, Renderer>>,
+    ) -> Self {
+        if state.widths.len() != columns.len() {
+            state.widths = columns
+                .iter()
+                .map(|column| f32::from(column.width))
+                .collect();
+        }
+
+        let cells = (0..rows)
+            .map(|row| {
+                columns.iter().map(|column| (column.cell)(row)).collect()
+            })
+            .collect();
+
+        Table {
+            state,
+            titles: columns.into_iter().map(|column| column.title).collect(),
+            cells,
+            selected: None,
+            on_select: None,
+            on_sort: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: Renderer::DEFAULT_SIZE,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the selected row of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn selected(mut self, row: Option<usize>) -> Self {
+        self.selected = row;
+        self
+    }
+
+    /// Sets the message that will be produced when a row of the [`Table`] is
+    /// clicked.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Makes the columns of the [`Table`] sortable, producing the given
+    /// message with the index of the column and the new [`SortOrder`] when a
+    /// column header is clicked.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`SortOrder`]: enum.SortOrder.html
+    pub fn on_sort<F>(mut self, on_sort: F) -> Self
+    where
+        F: 'static + Fn(usize, SortOrder) -> Message,
+    {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the width of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of the cells of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the header of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the header of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the visible region of the rows of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    fn viewport(layout: Layout<'_>) -> Rectangle {
+        let bounds = layout.bounds();
+        let header = layout.children().next().unwrap().bounds();
+
+        Rectangle {
+            y: bounds.y + header.height,
+            height: (bounds.height - header.height).max(0.0),
+            ..bounds
+        }
+    }
+
+    fn max_offset(layout: Layout<'_>) -> f32 {
+        let body = layout.children().nth(1).unwrap().bounds();
+
+        (body.height - Self::viewport(layout).height).max(0.0)
+    }
+}
+
+/// A column of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, Message, Renderer> {
+    title: String,
+    width: u16,
+    cell: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+}
+
+impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
+    /// Creates a new [`Column`] with the given title and a function that
+    /// produces the cell of the [`Column`] for a row index.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn new<F>(title: impl Into<String>, cell: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Element<'a, Message, Renderer>,
+    {
+        Column {
+            title: title.into(),
+            width: 100,
+            cell: Box::new(cell),
+        }
+    }
+
+    /// Sets the initial width of the [`Column`], in pixels.
+    ///
+    /// By default, it is 100 pixels.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+/// The order in which a column of a [`Table`] is sorted.
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// From the lowest to the highest value.
+    Ascending,
+
+    /// From the highest to the lowest value.
+    Descending,
+}
+
+/// The local state of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    widths: Vec<f32>,
+    offset: f32,
+    sort: Option<(usize, SortOrder)>,
+    resizing: Option<Resizing>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the sorted column of the [`Table`] and its [`SortOrder`], if
+    /// any.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`SortOrder`]: enum.SortOrder.html
+    pub fn sort(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Returns the current widths of the columns of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn widths(&self) -> &[f32] {
+        &self.widths
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let header_height = f32::from(self.text_size) + padding * 2.0;
+        let total_width: f32 = self.state.widths.iter().sum();
+
+        let limits = limits.width(self.width).height(self.height);
+
+        let mut x = 0.0;
+
+        let header = self
+            .state
+            .widths
+            .iter()
+            .map(|width| {
+                let mut node =
+                    layout::Node::new(Size::new(*width, header_height));
+                node.move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let header = layout::Node::with_children(
+            Size::new(total_width, header_height),
+            header,
+        );
+
+        let mut y = 0.0;
+
+        let rows = self
+            .cells
+            .iter()
+            .map(|cells| {
+                let mut x = 0.0;
+                let mut height: f32 = 0.0;
+
+                let mut cells: Vec<_> = cells
+                    .iter()
+                    .zip(&self.state.widths)
+                    .map(|(cell, width)| {
+                        let limits = layout::Limits::new(
+                            Size::ZERO,
+                            Size::new(
+                                (width - padding * 2.0).max(0.0),
+                                f32::INFINITY,
+                            ),
+                        );
+
+                        let mut node = cell.layout(renderer, &limits);
+                        node.move_to(Point::new(x + padding, padding));
+
+                        x += width;
+                        height = height.max(node.size().height);
+
+                        node
+                    })
+                    .collect();
+
+                // Center the cells vertically in the row
+                for cell in &mut cells {
+                    let bounds = cell.bounds();
+
+                    cell.move_to(Point::new(
+                        bounds.x,
+                        padding + (height - bounds.height) / 2.0,
+                    ));
+                }
+
+                let mut row = layout::Node::with_children(
+                    Size::new(total_width, height + padding * 2.0),
+                    cells,
+                );
+                row.move_to(Point::new(0.0, y));
+
+                y += row.size().height;
+
+                row
+            })
+            .collect();
+
+        let mut body =
+            layout::Node::with_children(Size::new(total_width, y), rows);
+        body.move_to(Point::new(0.0, header_height));
+
+        let size = limits.resolve(Size::new(total_width, header_height + y));
+
+        layout::Node::with_children(size, vec![header, body])
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        for width in &self.state.widths {
+            width.to_bits().hash(state);
+        }
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+
+        for row in &self.cells {
+            for cell in row {
+                cell.hash_layout(state);
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let body = children.next().unwrap();
+
+        let viewport = Self::viewport(layout);
+        let max_offset = Self::max_offset(layout);
+        self.state.offset = self.state.offset.min(max_offset);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(resizing) = self.state.resizing {
+                    self.state.widths[resizing.column] =
+                        (resizing.width + cursor_position.x - resizing.origin)
+                            .max(MIN_COLUMN_WIDTH);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if header.bounds().contains(cursor_position) {
+                    if let Some(column) = resize_handle(header, cursor_position)
+                    {
+                        self.state.resizing = Some(Resizing {
+                            column,
+                            origin: cursor_position.x,
+                            width: self.state.widths[column],
+                        });
+                    } else if let Some(on_sort) = &self.on_sort {
+                        let column = header.children().position(|title| {
+                            title.bounds().contains(cursor_position)
+                        });
+
+                        if let Some(column) = column {
+                            let order = match self.state.sort {
+                                Some((sorted, SortOrder::Ascending))
+                                    if sorted == column =>
+                                {
+                                    SortOrder::Descending
+                                }
+                                _ => SortOrder::Ascending,
+                            };
+
+                            self.state.sort = Some((column, order));
+                            messages.push(on_sort(column, order));
+                        }
+                    }
+                } else if viewport.contains(cursor_position) {
+                    if let Some(on_select) = &self.on_select {
+                        let cursor = Point::new(
+                            cursor_position.x,
+                            cursor_position.y + self.state.offset,
+                        );
+
+                        let row = body
+                            .children()
+                            .position(|row| row.bounds().contains(cursor));
+
+                        if let Some(row) = row {
+                            messages.push(on_select(row));
+                        }
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.state.resizing = None;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if viewport.contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * 60.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                self.state.offset =
+                    (self.state.offset - delta).max(0.0).min(max_offset);
+            }
+            _ => {}
+        }
+
+        let cursor_position = if viewport.contains(cursor_position) {
+            Point::new(cursor_position.x, cursor_position.y + self.state.offset)
+        } else {
+            Point::new(cursor_position.x, -1.0)
+        };
+
+        for (cells, row) in self.cells.iter_mut().zip(body.children()) {
+            for (cell, layout) in cells.iter_mut().zip(row.children()) {
+                cell.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                );
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let body = children.next().unwrap();
+
+        let offset = self.state.offset.min(Self::max_offset(layout));
+
+        let headers: Vec<_> = self
+            .titles
+            .iter()
+            .enumerate()
+            .map(|(index, title)| Header {
+                title,
+                sort: self
+                    .state
+                    .sort
+                    .filter(|(column, _)| *column == index)
+                    .map(|(_, order)| order),
+            })
+            .collect();
+
+        let is_resizing = self.state.resizing.is_some()
+            || (header.bounds().contains(cursor_position)
+                && resize_handle(header, cursor_position).is_some());
+
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            header,
+            &headers,
+            Self::viewport(layout),
+            body,
+            &self.cells,
+            offset,
+            self.selected,
+            is_resizing,
+            cursor_position,
+            self.padding,
+            self.text_size,
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        let body = layout.children().nth(1).unwrap();
+        let translation = Vector::new(0.0, -self.state.offset);
+
+        let start = targets.len();
+
+        for (cells, row) in self.cells.iter_mut().zip(body.children()) {
+            for (cell, layout) in cells.iter_mut().zip(row.children()) {
+                cell.focus_targets(layout, targets);
+            }
+        }
+
+        for target in &mut targets[start..] {
+            target.bounds = target.bounds + translation;
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        let body = layout.children().nth(1).unwrap();
+
+        for (cells, row) in self.cells.iter_mut().zip(body.children()) {
+            for (cell, layout) in cells.iter_mut().zip(row.children()) {
+                if cell.on_focused_event(
+                    event, layout, messages, renderer, clipboard,
+                ) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+/// Returns the column whose right edge is under the cursor, if any.
+fn resize_handle(header: Layout<'_>, cursor_position: Point) -> Option<usize> {
+    header.children().position(|title| {
+        let bounds = title.bounds();
+
+        (bounds.x + bounds.width - cursor_position.x).abs() <= RESIZE_HANDLE
+    })
+}
+
+/// The header of a column of a [`Table`], ready to be drawn.
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy)]
+pub struct Header<'a> {
+    /// The title of the column.
+    pub title: &'a str,
+
+    /// The [`SortOrder`] of the column, if it is sorted.
+    ///
+    /// [`SortOrder`]: enum.SortOrder.html
+    pub sort: Option<SortOrder>,
+}
+
+/// The renderer of a [`Table`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Table`] in your user interface.
+///
+/// [`Table`]: struct.Table.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer + Sized {
+    /// The default padding of the cells of a [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    const DEFAULT_PADDING: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Table`].
+    ///
+    /// It receives:
+    /// - the [`Layout`] of the header and the [`Header`] of every column
+    /// - the visible region of the rows
+    /// - the [`Layout`] of the rows and their cells
+    /// - the vertical scroll offset of the rows
+    /// - the selected row, if any
+    /// - whether a column is being resized, or can be resized, or not
+    /// - the cursor position
+    /// - the padding, the text size, and the font of the header
+    /// - the style of the [`Table`]
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    /// [`Header`]: struct.Header.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        header_layout: Layout<'_>,
+        headers: &[Header<'_>],
+        viewport: Rectangle,
+        body_layout: Layout<'_>,
+        cells: &[Vec<Element<'_, Message, Self>>],
+        offset: f32,
+        selected: Option<usize>,
+        is_resizing: bool,
+        cursor_position: Point,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        table: Table<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(table)
+    }
+}
@@ -0,0 +1,660 @@
+//! Switch between different views with a strip of tabs.
+//!
+//! A [`Tabs`] widget has some local [`State`].
+//!
+//! [`Tabs`]: struct.Tabs.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse, overlay, text, Clipboard, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+
+/// The distance the cursor needs to travel before a pressed tab is dragged.
+const DRAG_DEADBAND: f32 = 5.0;
+
+/// A strip of tabs on top of the content of the active one.
+///
+/// Tabs can show an icon and a close button. They can be reordered by
+/// dragging them, and the strip can be scrolled horizontally with the mouse
+/// wheel when the tabs do not fit.
+///
+/// # Example
+/// ```
+/// # use iced_native::{tabs, renderer::Null, Text};
+/// #
+/// # pub type Tabs<'a, Message> = iced_native::Tabs<'a, Message, Null>;
+/// use iced_native::tabs::Tab;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TabSelected(usize),
+///     TabClosed(usize),
+///     TabMoved(usize, usize),
+/// }
+///
+/// let mut state = tabs::State::new();
+///
+/// let tabs = Tabs::new(
+///     &mut state,
+///     vec![Tab::new("main.rs"), Tab::new("lib.rs").icon('\u{1F4C4}')],
+///     0,
+///     Text::new("fn main() {}"),
+///     Message::TabSelected,
+/// )
+/// .on_close(Message::TabClosed)
+/// .on_reorder(Message::TabMoved);
+/// ```
+///
+/// [`Tabs`]: struct.Tabs.html
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    tabs: Vec<Tab>,
+    active: usize,
+    content: Element<'a, Message, Renderer>,
+    on_select: Box<dyn Fn(usize) -> Message>,
+    on_close: Option<Box<dyn Fn(usize) -> Message>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message>>,
+    width: Length,
+    height: Length,
+    padding: u16,
+    text_size: u16,
+    font: Renderer::Font,
+    icon_font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Tabs`] widget.
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - the tabs to show in the strip
+    /// - the index of the active tab
+    /// - the content of the active tab
+    /// - a function that produces a message when a tab is selected
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`State`]: struct.State.html
+    pub fn new<E, F>(
+        state: &'a mut State,
+        tabs: Vec<Tab>,
+        active: usize,
+        content: E,
+        on_select: F,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+        F: 'static + Fn(usize) -> Message,
+    {
+        Tabs {
+            state,
+            tabs,
+            active,
+            content: content.into(),
+            on_select: Box::new(on_select),
+            on_close: None,
+            on_reorder: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: Renderer::DEFAULT_SIZE,
+            font: Default::default(),
+            icon_font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Shows a close button in every tab, producing the given message with
+    /// the index of the tab when clicked.
+    pub fn on_close<F>(mut self, on_close: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Allows the tabs to be reordered by dragging them.
+    ///
+    /// The message is produced with the index of the dragged tab and the index
+    /// it was dropped at.
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of the tabs.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the tabs.
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels of the tabs.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the font used to draw the icons of the tabs.
+    pub fn icon_font(mut self, font: Renderer::Font) -> Self {
+        self.icon_font = font;
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn strip_height(&self) -> f32 {
+        f32::from(self.text_size + self.padding * 2)
+    }
+
+    /// Returns the bounds of the close button of a tab, if any.
+    fn close_bounds(&self, tab: Rectangle) -> Option<Rectangle> {
+        let size = f32::from(self.text_size);
+        let padding = f32::from(self.padding);
+
+        self.on_close.as_ref().map(|_| Rectangle {
+            x: tab.x + tab.width - padding - size,
+            y: tab.y + padding,
+            width: size,
+            height: size,
+        })
+    }
+
+    fn max_scroll(&self, strip: Layout<'_>) -> f32 {
+        let tabs_width: f32 =
+            strip.children().map(|tab| tab.bounds().width).sum();
+
+        (tabs_width - strip.bounds().width).max(0.0)
+    }
+}
+
+/// A tab of a [`Tabs`] widget.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    label: String,
+    icon: Option<char>,
+}
+
+impl Tab {
+    /// Creates a new [`Tab`] with the given label.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn new(label: impl Into<String>) -> Self {
+        Tab {
+            label: label.into(),
+            icon: None,
+        }
+    }
+
+    /// Sets the icon of the [`Tab`], shown before its label.
+    ///
+    /// The icon is drawn with the icon font of the [`Tabs`] widget.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn icon(mut self, icon: char) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
+/// The local state of a [`Tabs`] widget.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    scroll_offset: f32,
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    position: f32,
+    is_dragging: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether a tab is being dragged or not.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.map(|drag| drag.is_dragging).unwrap_or(false)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size;
+        let padding = f32::from(self.padding);
+        let strip_height = self.strip_height();
+
+        let limits = limits.width(self.width).height(self.height);
+
+        let mut content = self.content.layout(
+            renderer,
+            &limits.shrink(Size::new(0.0, strip_height)).loose(),
+        );
+        content.move_to(Point::new(0.0, strip_height));
+
+        let size = limits.resolve(Size::new(
+            content.size().width,
+            content.size().height + strip_height,
+        ));
+
+        let mut x = 0.0;
+
+        let tabs = self
+            .tabs
+            .iter()
+            .map(|tab| {
+                let mut width = renderer
+                    .measure_text(&tab.label, text_size, self.font)
+                    .width
+                    + padding * 2.0;
+
+                if tab.icon.is_some() {
+                    width += f32::from(text_size) + padding;
+                }
+
+                if self.on_close.is_some() {
+                    width += f32::from(text_size) + padding;
+                }
+
+                let mut node =
+                    layout::Node::new(Size::new(width, strip_height));
+                node.move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let strip = layout::Node::with_children(
+            Size::new(size.width, strip_height),
+            tabs,
+        );
+
+        layout::Node::with_children(size, vec![strip, content])
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        for tab in &self.tabs {
+            tab.label.hash(state);
+            tab.icon.hash(state);
+        }
+
+        self.on_close.is_some().hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.content.hash_layout(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mut children = layout.children();
+        let strip = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let max_scroll = self.max_scroll(strip);
+        self.state.scroll_offset = self.state.scroll_offset.min(max_scroll);
+
+        let is_over_strip = strip.bounds().contains(cursor_position);
+        let scrolled_cursor =
+            cursor_position + Vector::new(self.state.scroll_offset, 0.0);
+
+        let hovered_tab = if is_over_strip {
+            strip
+                .children()
+                .position(|tab| tab.bounds().contains(scrolled_cursor))
+        } else {
+            None
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(index) = hovered_tab {
+                    let tab = strip.children().nth(index).unwrap().bounds();
+
+                    let is_close_clicked = self
+                        .close_bounds(tab)
+                        .map(|close| close.contains(scrolled_cursor))
+                        .unwrap_or(false);
+
+                    if let (true, Some(on_close)) =
+                        (is_close_clicked, &self.on_close)
+                    {
+                        messages.push(on_close(index));
+                    } else {
+                        messages.push((self.on_select)(index));
+
+                        self.state.drag = Some(Drag {
+                            index,
+                            origin: cursor_position.x,
+                            position: cursor_position.x,
+                            is_dragging: false,
+                        });
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(drag) = &mut self.state.drag {
+                    drag.position = cursor_position.x;
+
+                    if self.on_reorder.is_some()
+                        && (drag.position - drag.origin).abs() > DRAG_DEADBAND
+                    {
+                        drag.is_dragging = true;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(drag) = self.state.drag.take() {
+                    let target = strip.children().position(|tab| {
+                        let bounds = tab.bounds();
+
+                        scrolled_cursor.x < bounds.x + bounds.width
+                    });
+
+                    let target = target
+                        .unwrap_or_else(|| self.tabs.len().saturating_sub(1));
+
+                    if let (true, Some(on_reorder)) =
+                        (drag.is_dragging, &self.on_reorder)
+                    {
+                        if target != drag.index {
+                            messages.push(on_reorder(drag.index, target));
+                        }
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if is_over_strip =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        // Vertical wheels scroll the strip horizontally
+                        let lines = if x.abs() > y.abs() { x } else { y };
+
+                        lines * 60.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x.abs() > y.abs() {
+                            x
+                        } else {
+                            y
+                        }
+                    }
+                };
+
+                self.state.scroll_offset =
+                    (self.state.scroll_offset - delta).max(0.0).min(max_scroll);
+            }
+            _ => {}
+        }
+
+        self.content.on_event(
+            event,
+            content,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let strip = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let scroll_offset =
+            self.state.scroll_offset.min(self.max_scroll(strip));
+
+        let is_over_strip = strip.bounds().contains(cursor_position);
+        let drag = self.state.drag.filter(|drag| drag.is_dragging);
+
+        let headers: Vec<_> = strip
+            .children()
+            .zip(&self.tabs)
+            .enumerate()
+            .map(|(index, (layout, tab))| {
+                let mut bounds = layout.bounds();
+                bounds.x -= scroll_offset;
+
+                let is_dragged = match drag {
+                    Some(drag) if drag.index == index => {
+                        bounds.x += drag.position - drag.origin;
+                        true
+                    }
+                    _ => false,
+                };
+
+                Header {
+                    bounds,
+                    label: &tab.label,
+                    icon: tab.icon,
+                    close_bounds: self.close_bounds(bounds),
+                    is_active: index == self.active,
+                    is_hovered: is_over_strip
+                        && drag.is_none()
+                        && bounds.contains(cursor_position),
+                    is_dragged,
+                }
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            strip.bounds(),
+            &headers,
+            cursor_position,
+            self.padding,
+            self.text_size,
+            self.font,
+            self.icon_font,
+            &self.style,
+            &self.content,
+            content,
+        )
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().nth(1).unwrap())
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.content
+            .focus_targets(layout.children().nth(1).unwrap(), targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.content.on_focused_event(
+            event,
+            layout.children().nth(1).unwrap(),
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+}
+
+/// A tab of a [`Tabs`] widget, ready to be drawn.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy)]
+pub struct Header<'a> {
+    /// The bounds of the tab, after scrolling and dragging.
+    pub bounds: Rectangle,
+
+    /// The label of the tab.
+    pub label: &'a str,
+
+    /// The icon of the tab, if any.
+    pub icon: Option<char>,
+
+    /// The bounds of the close button of the tab, if any.
+    pub close_bounds: Option<Rectangle>,
+
+    /// Whether the tab is the active one or not.
+    pub is_active: bool,
+
+    /// Whether the tab is hovered or not.
+    pub is_hovered: bool,
+
+    /// Whether the tab is being dragged or not.
+    pub is_dragged: bool,
+}
+
+/// The renderer of a [`Tabs`] widget.
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use [`Tabs`] in your user interface.
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The default padding of the tabs.
+    const DEFAULT_PADDING: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Tabs`] widget.
+    ///
+    /// It receives:
+    /// - the bounds of the strip, which the tabs must be clipped to
+    /// - the [`Header`] of every tab
+    /// - the cursor position
+    /// - the padding, the text size, the font, and the icon font of the tabs
+    /// - the style of the [`Tabs`]
+    /// - the content of the active tab and its layout
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`Header`]: struct.Header.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        strip_bounds: Rectangle,
+        headers: &[Header<'_>],
+        cursor_position: Point,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        icon_font: Self::Font,
+        style: &<Self as Renderer>::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Tabs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        tabs: Tabs<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tabs)
+    }
+}
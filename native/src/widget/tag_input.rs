@@ -0,0 +1,667 @@
+//! Type entries that become removable tags.
+//!
+//! A [`TagInput`] has some local [`State`].
+//!
+//! [`TagInput`]: struct.TagInput.html
+//! [`State`]: struct.State.html
+use crate::{
+    autocomplete, focus, keyboard, layout, mouse,
+    overlay::{self, menu, Menu},
+    text_input, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, TextInput, Widget,
+};
+
+use std::hash::Hash;
+
+/// A field where typed entries become tags, which can be removed.
+///
+/// An entry becomes a tag when `Enter` or a comma is pressed, or when one of
+/// the suggestions matching the typed text is picked. Entries that are
+/// already tags, ignoring case, are not added again.
+///
+/// A tag is removed by clicking its remove button, or by pressing
+/// `Backspace` when the field is empty, which removes the last tag.
+///
+/// The application is expected to clear the value of the field when it
+/// adds a tag.
+///
+/// # Example
+/// ```
+/// # use iced_native::{tag_input, renderer::Null};
+/// #
+/// # pub type TagInput<'a, Message> = iced_native::TagInput<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RecipientChanged(String),
+///     RecipientAdded(String),
+///     RecipientRemoved(usize),
+/// }
+///
+/// let mut state = tag_input::State::new();
+/// let value = "al";
+/// let recipients = vec![String::from("bob@example.com")];
+/// let contacts = vec![String::from("alice@example.com")];
+///
+/// let tag_input = TagInput::new(
+///     &mut state,
+///     "Add recipients...",
+///     value,
+///     &recipients,
+///     Message::RecipientChanged,
+///     Message::RecipientAdded,
+///     Message::RecipientRemoved,
+/// )
+/// .suggestions(&contacts);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TagInput<'a, Message, Renderer: self::Renderer> {
+    input: TextInput<'a, Message, Renderer>,
+    menu: &'a mut menu::State,
+    last_selection: &'a mut Option<String>,
+    value: String,
+    tags: &'a [String],
+    suggestions: Vec<String>,
+    on_add: Box<dyn Fn(String) -> Message>,
+    on_remove: Box<dyn Fn(usize) -> Message>,
+    width: Length,
+    padding: u16,
+    spacing: u16,
+    size: Option<u16>,
+    style: <Renderer as self::Renderer>::Style,
+    menu_style: <Renderer as menu::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> TagInput<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    const MIN_INPUT_WIDTH: f32 = 80.0;
+
+    /// Creates a new [`TagInput`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - a placeholder
+    /// - the current value of the field
+    /// - the current tags
+    /// - a function that produces a message when the value changes
+    /// - a function that produces a message when a tag is added
+    /// - a function that produces a message when a tag is removed
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    /// [`State`]: struct.State.html
+    pub fn new<F, G, H>(
+        state: &'a mut State,
+        placeholder: &str,
+        value: &str,
+        tags: &'a [String],
+        on_change: F,
+        on_add: G,
+        on_remove: H,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+        G: 'static + Fn(String) -> Message,
+        H: 'static + Fn(usize) -> Message,
+    {
+        let State {
+            input,
+            menu,
+            last_selection,
+        } = state;
+
+        let style = <Renderer as self::Renderer>::Style::default();
+
+        TagInput {
+            input: TextInput::new(input, placeholder, value, on_change)
+                .width(Length::Fill)
+                .padding(Renderer::TAG_PADDING)
+                .style(Renderer::input_style(&style)),
+            menu,
+            last_selection,
+            value: String::from(value),
+            tags,
+            suggestions: Vec::new(),
+            on_add: Box::new(on_add),
+            on_remove: Box::new(on_remove),
+            width: Length::Fill,
+            padding: Renderer::DEFAULT_PADDING,
+            spacing: Renderer::DEFAULT_SPACING,
+            size: None,
+            style,
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the suggestions of the [`TagInput`].
+    ///
+    /// The suggestions containing the typed text, ignoring case, are shown
+    /// below the field, except the ones that are already tags.
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    pub fn suggestions(mut self, suggestions: &[String]) -> Self {
+        let tags = self.tags;
+
+        self.suggestions = autocomplete::matching(suggestions, &self.value)
+            .into_iter()
+            .filter(|suggestion| !contains(tags, suggestion))
+            .collect();
+
+        self
+    }
+
+    /// Sets the width of the [`TagInput`].
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`TagInput`].
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the spacing between the tags of the [`TagInput`].
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the text size of the [`TagInput`].
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.input = self.input.size(size);
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`TagInput`].
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self.input = self.input.style(Renderer::input_style(&self.style));
+        self
+    }
+
+    /// Sets the style of the suggestions of the [`TagInput`].
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer as menu::Renderer>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+
+    /// Turns the given entry into a tag, unless it is empty or it is already
+    /// a tag.
+    fn add(&self, entry: &str, messages: &mut Vec<Message>) {
+        let entry = entry.trim();
+
+        if entry.is_empty() || contains(self.tags, entry) {
+            return;
+        }
+
+        messages.push((self.on_add)(String::from(entry)));
+    }
+}
+
+/// Returns whether the given tags contain the given entry, ignoring case.
+fn contains(tags: &[String], entry: &str) -> bool {
+    let entry = entry.to_lowercase();
+
+    tags.iter().any(|tag| tag.to_lowercase() == entry)
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TagInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let spacing = f32::from(self.spacing);
+        let tag_padding = f32::from(Renderer::TAG_PADDING);
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let limits = limits.width(self.width).pad(padding);
+        let max_width = limits.max().width;
+
+        let tag_height = f32::from(text_size) + tag_padding * 2.0;
+        let remove_size = f32::from(text_size);
+
+        let mut children = Vec::with_capacity(self.tags.len() + 1);
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let mut content_width: f32 = 0.0;
+
+        for tag in self.tags {
+            let label_width =
+                renderer.measure_value(tag, text_size, Default::default());
+
+            let width = label_width + remove_size + tag_padding * 3.0;
+
+            if x > 0.0 && x + width > max_width {
+                x = 0.0;
+                y += tag_height + spacing;
+            }
+
+            let mut label =
+                layout::Node::new(Size::new(label_width, f32::from(text_size)));
+            label.move_to(Point::new(tag_padding, tag_padding));
+
+            let mut remove =
+                layout::Node::new(Size::new(remove_size, remove_size));
+            remove.move_to(Point::new(
+                tag_padding * 2.0 + label_width,
+                tag_padding,
+            ));
+
+            let mut node = layout::Node::with_children(
+                Size::new(width, tag_height),
+                vec![label, remove],
+            );
+            node.move_to(Point::new(padding + x, padding + y));
+
+            children.push(node);
+
+            x += width + spacing;
+            content_width = content_width.max(x - spacing);
+        }
+
+        if x > 0.0 && x + Self::MIN_INPUT_WIDTH > max_width {
+            x = 0.0;
+            y += tag_height + spacing;
+        }
+
+        let input_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new((max_width - x).max(Self::MIN_INPUT_WIDTH), tag_height),
+        );
+
+        let mut input = self.input.layout(renderer, &input_limits);
+        input.move_to(Point::new(padding + x, padding + y));
+
+        content_width = content_width.max(x + input.size().width);
+
+        let size = limits.resolve(Size::new(content_width, y + tag_height));
+
+        children.push(input);
+
+        layout::Node::with_children(size.pad(padding), children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        // The menu handles events first, so the event that selected a
+        // suggestion is not processed by the field
+        if let Some(selection) = self.last_selection.take() {
+            self.add(&selection, messages);
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let input_layout = layout.children().last().unwrap();
+
+        let is_focused = self.input.is_focused();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let removed =
+                    layout.children().take(self.tags.len()).position(|tag| {
+                        tag.children()
+                            .nth(1)
+                            .map(|remove| {
+                                remove.bounds().contains(cursor_position)
+                            })
+                            .unwrap_or(false)
+                    });
+
+                if let Some(index) = removed {
+                    messages.push((self.on_remove)(index));
+                    return;
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(','))
+                if is_focused =>
+            {
+                self.add(&self.value, messages);
+                return;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                ..
+            }) if is_focused => {
+                self.add(&self.value, messages);
+                self.menu.close();
+                return;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Backspace,
+                ..
+            }) if is_focused && self.value.is_empty() => {
+                if !self.tags.is_empty() {
+                    messages.push((self.on_remove)(self.tags.len() - 1));
+                }
+
+                return;
+            }
+            _ => {}
+        }
+
+        let is_click = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        );
+
+        let previous_messages = messages.len();
+
+        self.input.on_event(
+            event,
+            input_layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        if is_click {
+            if bounds.contains(cursor_position) {
+                if !input_layout.bounds().contains(cursor_position) {
+                    self.input.focus();
+                }
+            } else {
+                self.menu.close();
+            }
+        } else if messages.len() > previous_messages {
+            // The value has changed, so the suggestions have too
+            self.menu.open(None);
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let input = self.input.draw(
+            renderer,
+            defaults,
+            layout.children().last().unwrap(),
+            cursor_position,
+        );
+
+        let tags: Vec<_> = self
+            .tags
+            .iter()
+            .zip(layout.children())
+            .map(|(label, layout)| {
+                let mut children = layout.children();
+
+                Tag {
+                    label,
+                    bounds: layout.bounds(),
+                    label_bounds: children.next().unwrap().bounds(),
+                    remove: children.next().unwrap().bounds(),
+                }
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            self.input.is_focused(),
+            &tags,
+            self.size.unwrap_or(renderer.default_size()),
+            input,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.tags.hash(state);
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.spacing.hash(state);
+        self.size.hash(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.menu.is_open() || self.suggestions.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut menu =
+            Menu::new(self.menu, &self.suggestions, self.last_selection)
+                .width(bounds.width.round() as u16)
+                .padding(self.padding)
+                .type_ahead(false)
+                .style(self.menu_style.clone());
+
+        if let Some(size) = self.size {
+            menu = menu.text_size(size);
+        }
+
+        Some(menu.overlay(Point::new(bounds.x, bounds.y), bounds.height))
+    }
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        Widget::<Message, Renderer>::focus_targets(
+            &mut self.input,
+            layout.children().last().unwrap(),
+            targets,
+        );
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.input.is_focused() {
+            return false;
+        }
+
+        self.on_event(
+            Event::Keyboard(event),
+            layout,
+            Point::new(-1.0, -1.0),
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        true
+    }
+}
+
+/// A tag of a [`TagInput`], ready to be drawn.
+///
+/// [`TagInput`]: struct.TagInput.html
+#[derive(Debug, Clone, Copy)]
+pub struct Tag<'a> {
+    /// The label of the [`Tag`].
+    ///
+    /// [`Tag`]: struct.Tag.html
+    pub label: &'a str,
+
+    /// The bounds of the [`Tag`].
+    ///
+    /// [`Tag`]: struct.Tag.html
+    pub bounds: Rectangle,
+
+    /// The bounds of the label of the [`Tag`].
+    ///
+    /// [`Tag`]: struct.Tag.html
+    pub label_bounds: Rectangle,
+
+    /// The bounds of the remove button of the [`Tag`].
+    ///
+    /// [`Tag`]: struct.Tag.html
+    pub remove: Rectangle,
+}
+
+/// The state of a [`TagInput`].
+///
+/// [`TagInput`]: struct.TagInput.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    input: text_input::State,
+    menu: menu::State,
+    last_selection: Option<String>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`TagInput`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TagInput`]: struct.TagInput.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the field of the [`TagInput`] is currently focused or
+    /// not.
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+}
+
+/// The renderer of a [`TagInput`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TagInput`] in your user interface.
+///
+/// [`TagInput`]: struct.TagInput.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text_input::Renderer + menu::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of a [`TagInput`].
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    const DEFAULT_PADDING: u16;
+
+    /// The default spacing between the tags of a [`TagInput`].
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    const DEFAULT_SPACING: u16;
+
+    /// The padding of every [`Tag`], which is also the padding of the text
+    /// field, so both are aligned.
+    ///
+    /// [`Tag`]: struct.Tag.html
+    const TAG_PADDING: u16;
+
+    /// Produces the style of the text field of a [`TagInput`], which is
+    /// drawn inside its own field.
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    fn input_style(
+        style: &<Self as Renderer>::Style,
+    ) -> <Self as text_input::Renderer>::Style;
+
+    /// Draws a [`TagInput`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`TagInput`]
+    ///   * the current cursor position
+    ///   * whether the [`TagInput`] is focused or not
+    ///   * the [`Tag`]s of the [`TagInput`]
+    ///   * the text size of the [`TagInput`]
+    ///   * the output of its text field
+    ///   * the style of the [`TagInput`]
+    ///
+    /// [`TagInput`]: struct.TagInput.html
+    /// [`Tag`]: struct.Tag.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        is_focused: bool,
+        tags: &[Tag<'_>],
+        text_size: u16,
+        input: Self::Output,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<TagInput<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        tag_input: TagInput<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tag_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicates_are_detected_ignoring_case() {
+        let tags = [String::from("Rust"), String::from("iced")];
+
+        assert!(contains(&tags, "rust"));
+        assert!(contains(&tags, "ICED"));
+        assert!(!contains(&tags, "wgpu"));
+    }
+}
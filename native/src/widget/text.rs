@@ -8,6 +8,8 @@ use std::hash::Hash;
 
 /// A paragraph of text.
 ///
+/// A paragraph mixing different styles can be written with a [`RichText`].
+///
 /// # Example
 ///
 /// ```
@@ -19,6 +21,8 @@ use std::hash::Hash;
 /// ```
 ///
 /// ![Text drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/text.png?raw=true)
+///
+/// [`RichText`]: ../rich_text/struct.RichText.html
 #[derive(Debug)]
 pub struct Text<Renderer: self::Renderer> {
     content: String,
@@ -204,6 +208,19 @@ pub trait Renderer: crate::Renderer {
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Measures the given text contents without any wrapping and returns
+    /// their [`Size`].
+    ///
+    /// This can be useful to position labels in custom widgets based on the
+    /// actual metrics of the glyphs.
+    ///
+    /// [`Size`]: ../../struct.Size.html
+    fn measure_text(&self, content: &str, size: u16, font: Self::Font) -> Size {
+        let (width, height) = self.measure(content, size, font, Size::INFINITY);
+
+        Size::new(width, height)
+    }
+
     /// Draws a [`Text`] fragment.
     ///
     /// It receives:
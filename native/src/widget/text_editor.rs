@@ -0,0 +1,990 @@
+//! Display multi-line fields that can be filled with text.
+//!
+//! A [`TextEditor`] has some local [`State`].
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+mod line;
+
+pub use line::Line;
+
+use crate::text_input::{editor::Editor, platform, Cursor, Value};
+use crate::{
+    focus, keyboard, layout,
+    mouse::{self, click},
+    Clipboard, Element, Event, Hasher, Id, Layout, Length, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::cell::RefCell;
+
+/// A multi-line field that can be filled with text.
+///
+/// Lines are wrapped at word boundaries to fit the width of the
+/// [`TextEditor`]. When its contents do not fit its height, the
+/// [`TextEditor`] can be scrolled vertically.
+///
+/// While focused, a [`TextEditor`] indents its contents when `Tab` is pressed,
+/// instead of moving the focus to the next widget.
+///
+/// # Example
+/// ```
+/// # use iced_native::{text_editor, renderer::Null};
+/// #
+/// # pub type TextEditor<'a, Message> = iced_native::TextEditor<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     NotesChanged(String),
+/// }
+///
+/// let mut state = text_editor::State::new();
+/// let notes = "Some notes\nspanning multiple lines";
+///
+/// let editor = TextEditor::new(
+///     &mut state,
+///     "Write something...",
+///     notes,
+///     Message::NotesChanged,
+/// )
+/// .padding(10);
+/// ```
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[allow(missing_debug_implementations)]
+pub struct TextEditor<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    placeholder: String,
+    value: Value,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    padding: u16,
+    size: Option<u16>,
+    on_change: Box<dyn Fn(String) -> Message>,
+    tab_index: Option<u16>,
+    id: Option<Id>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> TextEditor<'a, Message, Renderer> {
+    /// Creates a new [`TextEditor`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - a placeholder
+    /// - the current value
+    /// - a function that produces a message when the [`TextEditor`] changes
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        placeholder: &str,
+        value: &str,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        TextEditor {
+            state,
+            placeholder: String::from(placeholder),
+            value: Value::new(value),
+            font: Default::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            padding: 0,
+            size: None,
+            on_change: Box::new(on_change),
+            tab_index: None,
+            id: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Font`] of the [`TextEditor`].
+    ///
+    /// [`Font`]: ../../struct.Font.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TextEditor`].
+    ///
+    /// By default, the [`TextEditor`] grows to fit all of its lines.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the padding of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the text size of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the position of the [`TextEditor`] in the focus order.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`TextEditor`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn text_size(&self, renderer: &Renderer) -> u16 {
+        self.size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn lines(&self, renderer: &Renderer, max_width: f32) -> Vec<Line> {
+        use std::hash::{Hash, Hasher as _};
+
+        let size = self.text_size(renderer);
+
+        let font = {
+            let mut hasher = Hasher::default();
+            self.font.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        let key = line::Key {
+            text: self.value.to_string(),
+            max_width,
+            size,
+            font,
+        };
+
+        self.state.lines.borrow_mut().lines(key, || {
+            line::wrap(&self.value, Some(max_width), |value| {
+                renderer.measure_value(&value.to_string(), size, self.font)
+            })
+        })
+    }
+
+    fn measure(&self, renderer: &Renderer, start: usize, end: usize) -> f32 {
+        renderer.measure_value(
+            &self.value.select(start, end).to_string(),
+            self.text_size(renderer),
+            self.font,
+        )
+    }
+
+    /// Finds the position closest to the given horizontal offset in a line.
+    fn position_in_line(
+        &self,
+        renderer: &Renderer,
+        line: Line,
+        x: f32,
+    ) -> usize {
+        let (mut low, mut high) = (line.start() + 1, line.end() + 1);
+
+        // The first position whose offset is past the target
+        while low < high {
+            let middle = (low + high) / 2;
+
+            if self.measure(renderer, line.start(), middle) <= x {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        let low = low - 1;
+
+        if low < line.end() {
+            let left = self.measure(renderer, line.start(), low);
+            let right = self.measure(renderer, line.start(), low + 1);
+
+            if right - x < x - left {
+                return low + 1;
+            }
+        }
+
+        low
+    }
+
+    fn position_at(
+        &self,
+        renderer: &Renderer,
+        lines: &[Line],
+        text_bounds: Rectangle,
+        point: Point,
+    ) -> usize {
+        let line_height = f32::from(self.text_size(renderer));
+        let y = point.y - text_bounds.y + self.state.scroll_offset;
+
+        let index = ((y / line_height).max(0.0) as usize).min(lines.len() - 1);
+
+        self.position_in_line(renderer, lines[index], point.x - text_bounds.x)
+    }
+
+    fn move_vertically(
+        &mut self,
+        renderer: &Renderer,
+        lines: &[Line],
+        amount: isize,
+        select: bool,
+    ) {
+        let end = self.state.cursor.end(&self.value);
+        let current = line::find(lines, end);
+
+        let target = (current as isize + amount)
+            .max(0)
+            .min(lines.len() as isize - 1) as usize;
+
+        let position = if target == current {
+            if amount < 0 {
+                lines[current].start()
+            } else {
+                lines[current].end()
+            }
+        } else {
+            let x = match self.state.preferred_x {
+                Some(x) => x,
+                None => self.measure(renderer, lines[current].start(), end),
+            };
+
+            self.state.preferred_x = Some(x);
+
+            self.position_in_line(renderer, lines[target], x)
+        };
+
+        self.move_to(position, select);
+    }
+
+    fn move_to(&mut self, position: usize, select: bool) {
+        if select {
+            self.state
+                .cursor
+                .select_range(self.state.cursor.start(&self.value), position);
+        } else {
+            self.state.cursor.move_to(position);
+        }
+    }
+
+    fn scroll_to_cursor(
+        &mut self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+    ) {
+        let lines = self.lines(renderer, text_bounds.width);
+        let line_height = f32::from(self.text_size(renderer));
+
+        let top = line::find(&lines, self.state.cursor.end(&self.value)) as f32
+            * line_height;
+
+        let offset = &mut self.state.scroll_offset;
+
+        if top < *offset {
+            *offset = top;
+        } else if top + line_height > *offset + text_bounds.height {
+            *offset = top + line_height - text_bounds.height;
+        }
+
+        self.state.clamp_scroll_offset(
+            lines.len() as f32 * line_height,
+            text_bounds.height,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextEditor<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = self.padding as f32;
+        let line_height = f32::from(self.text_size(renderer));
+
+        let limits = limits
+            .pad(padding)
+            .width(self.width)
+            .max_width(self.max_width)
+            .height(self.height)
+            .min_height(line_height as u32);
+
+        let lines = self.lines(renderer, limits.max().width);
+
+        let content_width = lines
+            .iter()
+            .map(|line| self.measure(renderer, line.start(), line.end()))
+            .fold(0.0, f32::max);
+
+        let mut text = layout::Node::new(limits.resolve(Size::new(
+            content_width.ceil(),
+            lines.len() as f32 * line_height,
+        )));
+        text.move_to(Point::new(padding, padding));
+
+        layout::Node::with_children(text.size().pad(padding), vec![text])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let mut is_cursor_moved = false;
+        let mut is_value_changed = false;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let is_clicked = bounds.contains(cursor_position);
+
+                if is_clicked {
+                    let lines = self.lines(renderer, text_bounds.width);
+                    let position = self.position_at(
+                        renderer,
+                        &lines,
+                        text_bounds,
+                        cursor_position,
+                    );
+
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    match click.kind() {
+                        click::Kind::Single => {
+                            self.state.cursor.move_to(position);
+                        }
+                        click::Kind::Double => {
+                            self.state.cursor.select_range(
+                                self.value.previous_start_of_word(position),
+                                self.value.next_end_of_word(position),
+                            );
+                        }
+                        click::Kind::Triple => {
+                            let is_break = |i: &usize| {
+                                self.value
+                                    .grapheme(*i)
+                                    .map(line::is_break)
+                                    .unwrap_or(false)
+                            };
+
+                            let start = (0..position)
+                                .rev()
+                                .find(is_break)
+                                .map(|i| i + 1)
+                                .unwrap_or(0);
+
+                            let end = (position..self.value.len())
+                                .find(is_break)
+                                .unwrap_or_else(|| self.value.len());
+
+                            self.state.cursor.select_range(start, end);
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+                    self.state.preferred_x = None;
+                }
+
+                self.state.is_dragging = is_clicked;
+                self.state.is_focused = is_clicked;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { x, y })
+                if self.state.is_dragging =>
+            {
+                let lines = self.lines(renderer, text_bounds.width);
+                let position = self.position_at(
+                    renderer,
+                    &lines,
+                    text_bounds,
+                    Point::new(x, y),
+                );
+
+                self.state.cursor.select_range(
+                    self.state.cursor.start(&self.value),
+                    position,
+                );
+
+                is_cursor_moved = true;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let lines = self.lines(renderer, text_bounds.width);
+                let line_height = f32::from(self.text_size(renderer));
+
+                self.state.scroll_offset -= match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * line_height,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                self.state.clamp_scroll_offset(
+                    lines.len() as f32 * line_height,
+                    text_bounds.height,
+                );
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.state.is_focused
+                    && self.state.is_pasting.is_none()
+                    && !c.is_control() =>
+            {
+                let mut editor =
+                    Editor::new(&mut self.value, &mut self.state.cursor);
+
+                editor.insert(c);
+
+                is_value_changed = true;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.is_focused => {
+                let is_jump = platform::is_jump_modifier_pressed(modifiers);
+
+                if key_code != keyboard::KeyCode::Up
+                    && key_code != keyboard::KeyCode::Down
+                    && key_code != keyboard::KeyCode::PageUp
+                    && key_code != keyboard::KeyCode::PageDown
+                {
+                    self.state.preferred_x = None;
+                }
+
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        editor.insert('\n');
+
+                        is_value_changed = true;
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        if is_jump && self.state.cursor.selection().is_none() {
+                            self.state.cursor.select_left_by_words(&self.value);
+                        }
+
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        editor.backspace();
+
+                        is_value_changed = true;
+                    }
+                    keyboard::KeyCode::Delete => {
+                        if is_jump && self.state.cursor.selection().is_none() {
+                            self.state
+                                .cursor
+                                .select_right_by_words(&self.value);
+                        }
+
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        editor.delete();
+
+                        is_value_changed = true;
+                    }
+                    keyboard::KeyCode::Left => {
+                        if is_jump {
+                            if modifiers.shift {
+                                self.state
+                                    .cursor
+                                    .select_left_by_words(&self.value);
+                            } else {
+                                self.state
+                                    .cursor
+                                    .move_left_by_words(&self.value);
+                            }
+                        } else if modifiers.shift {
+                            self.state.cursor.select_left(&self.value)
+                        } else {
+                            self.state.cursor.move_left(&self.value);
+                        }
+
+                        is_cursor_moved = true;
+                    }
+                    keyboard::KeyCode::Right => {
+                        if is_jump {
+                            if modifiers.shift {
+                                self.state
+                                    .cursor
+                                    .select_right_by_words(&self.value);
+                            } else {
+                                self.state
+                                    .cursor
+                                    .move_right_by_words(&self.value);
+                            }
+                        } else if modifiers.shift {
+                            self.state.cursor.select_right(&self.value)
+                        } else {
+                            self.state.cursor.move_right(&self.value);
+                        }
+
+                        is_cursor_moved = true;
+                    }
+                    keyboard::KeyCode::Up
+                    | keyboard::KeyCode::Down
+                    | keyboard::KeyCode::PageUp
+                    | keyboard::KeyCode::PageDown => {
+                        let lines = self.lines(renderer, text_bounds.width);
+                        let page = (text_bounds.height
+                            / f32::from(self.text_size(renderer)))
+                        .floor()
+                        .max(1.0) as isize;
+
+                        let amount = match key_code {
+                            keyboard::KeyCode::Up => -1,
+                            keyboard::KeyCode::Down => 1,
+                            keyboard::KeyCode::PageUp => -page,
+                            _ => page,
+                        };
+
+                        self.move_vertically(
+                            renderer,
+                            &lines,
+                            amount,
+                            modifiers.shift,
+                        );
+
+                        is_cursor_moved = true;
+                    }
+                    keyboard::KeyCode::Home => {
+                        let position = if is_jump {
+                            0
+                        } else {
+                            let lines = self.lines(renderer, text_bounds.width);
+                            let end = self.state.cursor.end(&self.value);
+
+                            lines[line::find(&lines, end)].start()
+                        };
+
+                        self.move_to(position, modifiers.shift);
+
+                        is_cursor_moved = true;
+                    }
+                    keyboard::KeyCode::End => {
+                        let position = if is_jump {
+                            self.value.len()
+                        } else {
+                            let lines = self.lines(renderer, text_bounds.width);
+                            let end = self.state.cursor.end(&self.value);
+
+                            lines[line::find(&lines, end)].end()
+                        };
+
+                        self.move_to(position, modifiers.shift);
+
+                        is_cursor_moved = true;
+                    }
+                    keyboard::KeyCode::Tab
+                        if !modifiers.control
+                            && !modifiers.alt
+                            && !modifiers.logo =>
+                    {
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        // Tabs are indented with spaces
+                        editor.paste(Value::new("    "));
+
+                        is_value_changed = true;
+                    }
+                    keyboard::KeyCode::V => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            if let Some(clipboard) = clipboard {
+                                let content = match self.state.is_pasting.take()
+                                {
+                                    Some(content) => content,
+                                    None => {
+                                        let content: String = clipboard
+                                            .content()
+                                            .unwrap_or_default()
+                                            .chars()
+                                            .filter(|c| {
+                                                *c == '\n' || !c.is_control()
+                                            })
+                                            .collect();
+
+                                        Value::new(&content)
+                                    }
+                                };
+
+                                let mut editor = Editor::new(
+                                    &mut self.value,
+                                    &mut self.state.cursor,
+                                );
+
+                                editor.paste(content.clone());
+
+                                self.state.is_pasting = Some(content);
+
+                                is_value_changed = true;
+                            }
+                        } else {
+                            self.state.is_pasting = None;
+                        }
+                    }
+                    keyboard::KeyCode::A
+                        if platform::is_copy_paste_modifier_pressed(
+                            modifiers,
+                        ) =>
+                    {
+                        self.state.cursor.select_all(&self.value);
+                    }
+                    keyboard::KeyCode::C | keyboard::KeyCode::X
+                        if platform::is_copy_paste_modifier_pressed(
+                            modifiers,
+                        ) =>
+                    {
+                        if let (Some((start, end)), Some(clipboard)) =
+                            (self.state.cursor.selection(), clipboard)
+                        {
+                            clipboard.write(
+                                self.value.select(start, end).to_string(),
+                            );
+
+                            if key_code == keyboard::KeyCode::X {
+                                let mut editor = Editor::new(
+                                    &mut self.value,
+                                    &mut self.state.cursor,
+                                );
+
+                                editor.delete();
+
+                                is_value_changed = true;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code: keyboard::KeyCode::V,
+                ..
+            }) => {
+                self.state.is_pasting = None;
+            }
+            _ => {}
+        }
+
+        if is_value_changed {
+            messages.push((self.on_change)(self.value.to_string()));
+        }
+
+        if is_value_changed || is_cursor_moved {
+            self.scroll_to_cursor(renderer, text_bounds);
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let lines = self.lines(renderer, text_bounds.width);
+
+        renderer.draw(
+            bounds,
+            text_bounds,
+            cursor_position,
+            self.font,
+            self.text_size(renderer),
+            &self.placeholder,
+            &self.value,
+            &lines,
+            self.state,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::{any::TypeId, hash::Hash};
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.padding.hash(state);
+        self.size.hash(state);
+
+        // The contents only affect the layout when the editor shrinks to fit
+        if self.width == Length::Shrink || self.height == Length::Shrink {
+            self.value.to_string().hash(state);
+        }
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        targets.push(focus::Target::new(
+            self.state,
+            layout.bounds(),
+            self.tab_index,
+            self.id.clone(),
+        ));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        // Every key is captured while typing
+        if !self.state.is_focused {
+            return false;
+        }
+
+        self.on_event(
+            Event::Keyboard(event),
+            layout,
+            Point::new(-1.0, -1.0),
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        true
+    }
+}
+
+/// The renderer of a [`TextEditor`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TextEditor`] in your user interface.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// The font type used for [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    type Font: Default + Copy + std::hash::Hash;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Returns the default size of the text of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    fn default_size(&self) -> u16;
+
+    /// Returns the width of a single line of text of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    fn measure_value(&self, value: &str, size: u16, font: Self::Font) -> f32;
+
+    /// Draws a [`TextEditor`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`TextEditor`]
+    /// - the bounds of the text (i.e. the visible area of the value)
+    /// - the cursor position
+    /// - the placeholder to show when the value is empty
+    /// - the current [`Value`]
+    /// - the visual [`Line`] of the [`Value`], each one `size` units tall
+    /// - the current [`State`]
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Value`]: ../text_input/struct.Value.html
+    /// [`Line`]: struct.Line.html
+    /// [`State`]: struct.State.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        text_bounds: Rectangle,
+        cursor_position: Point,
+        font: Self::Font,
+        size: u16,
+        placeholder: &str,
+        value: &Value,
+        lines: &[Line],
+        state: &State,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<TextEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        text_editor: TextEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_editor)
+    }
+}
+
+/// The state of a [`TextEditor`].
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    is_focused: bool,
+    is_dragging: bool,
+    is_pasting: Option<Value>,
+    last_click: Option<mouse::Click>,
+    cursor: Cursor,
+    scroll_offset: f32,
+    preferred_x: Option<f32>,
+    lines: RefCell<line::Cache>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`TextEditor`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`State`], representing a focused [`TextEditor`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn focused() -> Self {
+        Self {
+            is_focused: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the [`TextEditor`] is currently focused or not.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the [`Cursor`] of the [`TextEditor`].
+    ///
+    /// [`Cursor`]: ../text_input/struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn cursor(&self) -> Cursor {
+        self.cursor
+    }
+
+    /// Returns the current vertical scroll offset of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
+
+    fn clamp_scroll_offset(&mut self, content_height: f32, height: f32) {
+        self.scroll_offset =
+            self.scroll_offset.min(content_height - height).max(0.0);
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_dragging = false;
+    }
+
+    fn accepts_text(&self) -> bool {
+        true
+    }
+
+    fn handles_tab(&self) -> bool {
+        true
+    }
+}
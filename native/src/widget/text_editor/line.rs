@@ -0,0 +1,239 @@
+//! Break the value of a text editor into visual lines.
+use crate::text_input::Value;
+
+/// A visual line of a [`TextEditor`].
+///
+/// A [`Line`] is a range of graphemes of the [`Value`] of the editor, which
+/// does not include the line break that ends it, if any.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+/// [`Line`]: struct.Line.html
+/// [`Value`]: ../text_input/struct.Value.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line {
+    start: usize,
+    end: usize,
+}
+
+impl Line {
+    /// Returns the index of the first grapheme of the [`Line`].
+    ///
+    /// [`Line`]: struct.Line.html
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the index right after the last grapheme of the [`Line`].
+    ///
+    /// [`Line`]: struct.Line.html
+    pub fn end(&self) -> usize {
+        self.end
+    }
+}
+
+/// Returns whether the given grapheme breaks a line.
+pub(crate) fn is_break(grapheme: &str) -> bool {
+    grapheme == "\n" || grapheme == "\r\n"
+}
+
+/// Breaks the given [`Value`] into visual lines.
+///
+/// Lines are broken at every line break and, when a `max_width` is given,
+/// wrapped at word boundaries to fit it. Words that do not fit on a line on
+/// their own are broken at any grapheme.
+///
+/// [`Value`]: ../text_input/struct.Value.html
+pub(crate) fn wrap(
+    value: &Value,
+    max_width: Option<f32>,
+    measure: impl Fn(&Value) -> f32,
+) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+
+    loop {
+        let end = (start..value.len())
+            .find(|i| value.grapheme(*i).map(is_break).unwrap_or(false))
+            .unwrap_or_else(|| value.len());
+
+        match max_width {
+            Some(max_width) => {
+                wrap_paragraph(value, start, end, max_width, &measure, |line| {
+                    lines.push(line)
+                })
+            }
+            None => lines.push(Line { start, end }),
+        }
+
+        if end == value.len() {
+            break;
+        }
+
+        start = end + 1;
+    }
+
+    lines
+}
+
+fn wrap_paragraph(
+    value: &Value,
+    start: usize,
+    end: usize,
+    max_width: f32,
+    measure: impl Fn(&Value) -> f32,
+    mut push: impl FnMut(Line),
+) {
+    let fits = |start: usize, end: usize| {
+        measure(&value.select(start, end)) <= max_width
+    };
+
+    let mut line_start = start;
+
+    while !fits(line_start, end) {
+        // The widest prefix that fits is found with a binary search, as
+        // measuring is expensive. A line always fits at least a grapheme.
+        let mut fitting = line_start + 1;
+        let mut overflowing = end;
+
+        while overflowing - fitting > 1 {
+            let middle = fitting + (overflowing - fitting) / 2;
+
+            if fits(line_start, middle) {
+                fitting = middle;
+            } else {
+                overflowing = middle;
+            }
+        }
+
+        // Lines are broken after the last whitespace that fits, if any
+        let line_end = (line_start..fitting)
+            .rev()
+            .find(|i| {
+                value
+                    .grapheme(*i)
+                    .map(|grapheme| grapheme.chars().all(char::is_whitespace))
+                    .unwrap_or(false)
+            })
+            .map(|i| i + 1)
+            .unwrap_or(fitting);
+
+        push(Line {
+            start: line_start,
+            end: line_end,
+        });
+
+        line_start = line_end;
+    }
+
+    push(Line {
+        start: line_start,
+        end,
+    });
+}
+
+/// The visual lines of a [`Value`], kept until the [`Value`] or the way it
+/// is wrapped changes.
+///
+/// [`Value`]: ../text_input/struct.Value.html
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Cache {
+    key: Option<Key>,
+    lines: Vec<Line>,
+}
+
+/// Everything the wrapping of a [`Value`] depends on.
+///
+/// [`Value`]: ../text_input/struct.Value.html
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Key {
+    pub text: String,
+    pub max_width: f32,
+    pub size: u16,
+    pub font: u64,
+}
+
+impl Cache {
+    /// Returns the cached lines for the given [`Key`], producing them with
+    /// `wrap` if the [`Key`] changed.
+    ///
+    /// [`Key`]: struct.Key.html
+    pub fn lines(
+        &mut self,
+        key: Key,
+        wrap: impl FnOnce() -> Vec<Line>,
+    ) -> Vec<Line> {
+        if self.key.as_ref() != Some(&key) {
+            self.lines = wrap();
+            self.key = Some(key);
+        }
+
+        self.lines.clone()
+    }
+}
+
+/// Returns the index of the visual line containing the given position.
+///
+/// A position at the boundary of two wrapped lines belongs to the latter.
+pub(crate) fn find(lines: &[Line], position: usize) -> usize {
+    lines
+        .iter()
+        .rposition(|line| line.start <= position)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str, max_width: Option<f32>) -> Vec<String> {
+        let value = Value::new(text);
+
+        wrap(&value, max_width, |value| value.len() as f32)
+            .into_iter()
+            .map(|line| value.select(line.start, line.end).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn breaks_lines_and_wraps_words() {
+        assert_eq!(lines("", None), vec![""]);
+        assert_eq!(lines("a\n\nb\n", None), vec!["a", "", "b", ""]);
+
+        assert_eq!(
+            lines("hello world\nfoo bar baz", Some(8.0)),
+            vec!["hello ", "world", "foo bar ", "baz"]
+        );
+
+        assert_eq!(lines("abcdefghij", Some(4.0)), vec!["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn caches_lines_until_the_key_changes() {
+        let key = |text: &str, max_width: f32| Key {
+            text: String::from(text),
+            max_width,
+            size: 20,
+            font: 0,
+        };
+
+        let mut cache = Cache::default();
+        let mut wraps = 0;
+
+        let keys = vec![
+            key("a", 10.0),
+            key("a", 10.0),
+            key("b", 10.0),
+            key("b", 5.0),
+        ];
+
+        for key in keys {
+            let _ = cache.lines(key, || {
+                wraps += 1;
+
+                Vec::new()
+            });
+        }
+
+        assert_eq!(wraps, 3);
+    }
+}
@@ -4,21 +4,24 @@
 //!
 //! [`TextInput`]: struct.TextInput.html
 //! [`State`]: struct.State.html
-mod editor;
+mod history;
 mod value;
 
+pub(crate) mod editor;
+
 pub mod cursor;
 
 pub use cursor::Cursor;
 pub use value::Value;
 
 use editor::Editor;
+use history::{Edit, History};
 
 use crate::{
     keyboard, layout,
     mouse::{self, click},
-    Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
-    Widget,
+    Clipboard, Command, Element, Event, Hasher, Id, Layout, Length, Point,
+    Rectangle, Size, Widget,
 };
 
 use std::u32;
@@ -53,6 +56,9 @@ pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     placeholder: String,
     value: Value,
     is_secure: bool,
+    is_revealable: bool,
+    is_enabled: bool,
+    mask: char,
     font: Renderer::Font,
     width: Length,
     max_width: u32,
@@ -60,6 +66,8 @@ pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     size: Option<u16>,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
+    tab_index: Option<u16>,
+    id: Option<Id>,
     style: Renderer::Style,
 }
 
@@ -88,6 +96,9 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
+            is_revealable: false,
+            is_enabled: true,
+            mask: '•',
             font: Default::default(),
             width: Length::Fill,
             max_width: u32::MAX,
@@ -95,6 +106,8 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
             size: None,
             on_change: Box::new(on_change),
             on_submit: None,
+            tab_index: None,
+            id: None,
             style: Renderer::Style::default(),
         }
     }
@@ -107,6 +120,28 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the character used to hide the value of a password
+    /// [`TextInput`].
+    ///
+    /// By default, a dot ('•') is used.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn mask(mut self, character: char) -> Self {
+        self.mask = character;
+        self
+    }
+
+    /// Shows a toggle at the end of a password [`TextInput`] that reveals
+    /// its value when clicked.
+    ///
+    /// The value can still not be copied while revealed.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn revealable(mut self) -> Self {
+        self.is_revealable = true;
+        self
+    }
+
     /// Sets the [`Font`] of the [`Text`].
     ///
     /// [`Text`]: struct.Text.html
@@ -156,6 +191,33 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the position of the [`TextInput`] in the focus order.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`TextInput`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Disables the [`TextInput`], which is then shown but cannot be focused
+    /// nor edited.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn disabled(mut self) -> Self {
+        self.is_enabled = false;
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
@@ -163,6 +225,51 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         self.style = style.into();
         self
     }
+
+    /// Returns whether the [`TextInput`] is currently focused or not.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub(crate) fn is_focused(&self) -> bool {
+        self.state.is_focused
+    }
+
+    /// Focuses the [`TextInput`], moving its cursor to the end of its value.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub(crate) fn focus(&mut self) {
+        self.state.is_focused = true;
+        self.state.cursor.move_to(self.value.len());
+    }
+
+    fn displayed_value(&self) -> Value {
+        if self.is_secure && !self.state.is_revealed {
+            self.value.mask(self.mask)
+        } else {
+            self.value.clone()
+        }
+    }
+
+    fn edit(
+        &mut self,
+        edit: Edit,
+        messages: &mut Vec<Message>,
+        f: impl FnOnce(&mut Editor<'_>),
+    ) {
+        let value = self.value.to_string();
+        let cursor = self.state.cursor;
+
+        let mut editor = Editor::new(&mut self.value, &mut self.state.cursor);
+
+        f(&mut editor);
+
+        let contents = editor.contents();
+
+        if contents != value {
+            self.state.history.push(edit, value, cursor);
+        }
+
+        messages.push((self.on_change)(contents));
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -196,7 +303,24 @@ where
         let mut text = layout::Node::new(limits.resolve(Size::ZERO));
         text.move_to(Point::new(padding, padding));
 
-        layout::Node::with_children(text.size().pad(padding), vec![text])
+        let size = text.size().pad(padding);
+
+        if self.is_secure && self.is_revealable {
+            let toggle_size = f32::from(text_size);
+            let text_width = (text.size().width - toggle_size).max(0.0);
+
+            let mut toggle =
+                layout::Node::new(Size::new(toggle_size, toggle_size));
+            toggle.move_to(Point::new(padding + text_width, padding));
+
+            let mut text =
+                layout::Node::new(Size::new(text_width, text.size().height));
+            text.move_to(Point::new(padding, padding));
+
+            layout::Node::with_children(size, vec![text, toggle])
+        } else {
+            layout::Node::with_children(size, vec![text])
+        }
     }
 
     fn on_event(
@@ -208,10 +332,31 @@ where
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
     ) {
+        if !self.is_enabled {
+            self.state.is_focused = false;
+            self.state.is_dragging = false;
+
+            return;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let is_clicked = layout.bounds().contains(cursor_position);
 
+                let is_toggle_clicked = layout
+                    .children()
+                    .nth(1)
+                    .map(|toggle| toggle.bounds().contains(cursor_position))
+                    .unwrap_or(false);
+
+                if is_toggle_clicked {
+                    self.state.is_revealed = !self.state.is_revealed;
+                    self.state.is_focused = true;
+                    self.state.is_dragging = false;
+
+                    return;
+                }
+
                 if is_clicked {
                     let text_layout = layout.children().next().unwrap();
                     let target = cursor_position.x - text_layout.bounds().x;
@@ -224,11 +369,7 @@ where
                     match click.kind() {
                         click::Kind::Single => {
                             if target > 0.0 {
-                                let value = if self.is_secure {
-                                    self.value.secure()
-                                } else {
-                                    self.value.clone()
-                                };
+                                let value = self.displayed_value();
 
                                 let position = renderer.find_cursor_position(
                                     text_layout.bounds(),
@@ -269,6 +410,7 @@ where
                     }
 
                     self.state.last_click = Some(click);
+                    self.state.history.seal();
                 }
 
                 self.state.is_dragging = is_clicked;
@@ -283,11 +425,7 @@ where
                     let target = x - text_layout.bounds().x;
 
                     if target > 0.0 {
-                        let value = if self.is_secure {
-                            self.value.secure()
-                        } else {
-                            self.value.clone()
-                        };
+                        let value = self.displayed_value();
 
                         let position = renderer.find_cursor_position(
                             text_layout.bounds(),
@@ -310,13 +448,12 @@ where
                     && self.state.is_pasting.is_none()
                     && !c.is_control() =>
             {
-                let mut editor =
-                    Editor::new(&mut self.value, &mut self.state.cursor);
-
-                editor.insert(c);
+                self.edit(Edit::Insert, messages, |editor| editor.insert(c));
 
-                let message = (self.on_change)(editor.contents());
-                messages.push(message);
+                // Words are undone one at a time
+                if c.is_whitespace() {
+                    self.state.history.seal();
+                }
             }
             Event::Keyboard(keyboard::Event::KeyPressed {
                 key_code,
@@ -339,13 +476,9 @@ where
                         }
                     }
 
-                    let mut editor =
-                        Editor::new(&mut self.value, &mut self.state.cursor);
-
-                    editor.backspace();
-
-                    let message = (self.on_change)(editor.contents());
-                    messages.push(message);
+                    self.edit(Edit::Delete, messages, |editor| {
+                        editor.backspace()
+                    });
                 }
                 keyboard::KeyCode::Delete => {
                     if platform::is_jump_modifier_pressed(modifiers)
@@ -363,15 +496,11 @@ where
                         }
                     }
 
-                    let mut editor =
-                        Editor::new(&mut self.value, &mut self.state.cursor);
-
-                    editor.delete();
-
-                    let message = (self.on_change)(editor.contents());
-                    messages.push(message);
+                    self.edit(Edit::Delete, messages, |editor| editor.delete());
                 }
                 keyboard::KeyCode::Left => {
+                    self.state.history.seal();
+
                     if platform::is_jump_modifier_pressed(modifiers)
                         && !self.is_secure
                     {
@@ -387,6 +516,8 @@ where
                     }
                 }
                 keyboard::KeyCode::Right => {
+                    self.state.history.seal();
+
                     if platform::is_jump_modifier_pressed(modifiers)
                         && !self.is_secure
                     {
@@ -404,6 +535,8 @@ where
                     }
                 }
                 keyboard::KeyCode::Home => {
+                    self.state.history.seal();
+
                     if modifiers.shift {
                         self.state.cursor.select_range(
                             self.state.cursor.start(&self.value),
@@ -414,6 +547,8 @@ where
                     }
                 }
                 keyboard::KeyCode::End => {
+                    self.state.history.seal();
+
                     if modifiers.shift {
                         self.state.cursor.select_range(
                             self.state.cursor.start(&self.value),
@@ -440,15 +575,9 @@ where
                                 }
                             };
 
-                            let mut editor = Editor::new(
-                                &mut self.value,
-                                &mut self.state.cursor,
-                            );
-
-                            editor.paste(content.clone());
-
-                            let message = (self.on_change)(editor.contents());
-                            messages.push(message);
+                            self.edit(Edit::Other, messages, |editor| {
+                                editor.paste(content.clone())
+                            });
 
                             self.state.is_pasting = Some(content);
                         }
@@ -461,6 +590,44 @@ where
                         self.state.cursor.select_all(&self.value);
                     }
                 }
+                keyboard::KeyCode::C | keyboard::KeyCode::X
+                    if platform::is_copy_paste_modifier_pressed(modifiers)
+                        && !self.is_secure =>
+                {
+                    if let (Some((start, end)), Some(clipboard)) =
+                        (self.state.cursor.selection(), clipboard)
+                    {
+                        clipboard
+                            .write(self.value.select(start, end).to_string());
+
+                        if key_code == keyboard::KeyCode::X {
+                            self.edit(Edit::Other, messages, |editor| {
+                                editor.delete()
+                            });
+                        }
+                    }
+                }
+                keyboard::KeyCode::Z | keyboard::KeyCode::Y
+                    if platform::is_copy_paste_modifier_pressed(modifiers) =>
+                {
+                    let value = self.value.to_string();
+                    let cursor = self.state.cursor;
+
+                    let restored = if key_code == keyboard::KeyCode::Y
+                        || modifiers.shift
+                    {
+                        self.state.history.redo(value, cursor)
+                    } else {
+                        self.state.history.undo(value, cursor)
+                    };
+
+                    if let Some((value, cursor)) = restored {
+                        self.value = Value::new(&value);
+                        self.state.cursor = cursor;
+
+                        messages.push((self.on_change)(value));
+                    }
+                }
                 _ => {}
             },
             Event::Keyboard(keyboard::Event::KeyReleased {
@@ -483,33 +650,23 @@ where
         cursor_position: Point,
     ) -> Renderer::Output {
         let bounds = layout.bounds();
-        let text_bounds = layout.children().next().unwrap().bounds();
-
-        if self.is_secure {
-            renderer.draw(
-                bounds,
-                text_bounds,
-                cursor_position,
-                self.font,
-                self.size.unwrap_or(renderer.default_size()),
-                &self.placeholder,
-                &self.value.secure(),
-                &self.state,
-                &self.style,
-            )
-        } else {
-            renderer.draw(
-                bounds,
-                text_bounds,
-                cursor_position,
-                self.font,
-                self.size.unwrap_or(renderer.default_size()),
-                &self.placeholder,
-                &self.value,
-                &self.state,
-                &self.style,
-            )
-        }
+        let mut children = layout.children();
+        let text_bounds = children.next().unwrap().bounds();
+        let toggle_bounds = children.next().map(|toggle| toggle.bounds());
+
+        renderer.draw(
+            bounds,
+            text_bounds,
+            toggle_bounds,
+            cursor_position,
+            self.font,
+            self.size.unwrap_or(renderer.default_size()),
+            &self.placeholder,
+            &self.displayed_value(),
+            self.state,
+            !self.is_enabled,
+            &self.style,
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -521,6 +678,49 @@ where
         self.max_width.hash(state);
         self.padding.hash(state);
         self.size.hash(state);
+        (self.is_secure && self.is_revealable).hash(state);
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<crate::focus::Target<'b>>,
+    ) {
+        if !self.is_enabled {
+            return;
+        }
+
+        targets.push(crate::focus::Target::new(
+            self.state,
+            layout.bounds(),
+            self.tab_index,
+            self.id.clone(),
+        ));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        // Every key is captured while typing
+        if !self.state.is_focused {
+            return false;
+        }
+
+        self.on_event(
+            Event::Keyboard(event),
+            layout,
+            Point::new(-1.0, -1.0),
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        true
     }
 }
 
@@ -572,10 +772,12 @@ pub trait Renderer: crate::Renderer + Sized {
     /// It receives:
     /// - the bounds of the [`TextInput`]
     /// - the bounds of the text (i.e. the current value)
+    /// - the bounds of the reveal toggle of a password input, if any
     /// - the cursor position
     /// - the placeholder to show when the value is empty
     /// - the current [`Value`]
     /// - the current [`State`]
+    /// - whether the [`TextInput`] is disabled or not
     ///
     /// [`TextInput`]: struct.TextInput.html
     /// [`Value`]: struct.Value.html
@@ -584,12 +786,14 @@ pub trait Renderer: crate::Renderer + Sized {
         &mut self,
         bounds: Rectangle,
         text_bounds: Rectangle,
+        toggle_bounds: Option<Rectangle>,
         cursor_position: Point,
         font: Self::Font,
         size: u16,
         placeholder: &str,
         value: &Value,
         state: &State,
+        is_disabled: bool,
         style: &Self::Style,
     ) -> Self::Output;
 
@@ -635,6 +839,16 @@ where
     }
 }
 
+/// Produces a [`Command`] that focuses the [`TextInput`] with the given
+/// [`Id`].
+///
+/// [`Command`]: ../../struct.Command.html
+/// [`TextInput`]: struct.TextInput.html
+/// [`Id`]: ../../struct.Id.html
+pub fn focus<Message>(id: Id) -> Command<Message> {
+    Command::focus(id)
+}
+
 /// The state of a [`TextInput`].
 ///
 /// [`TextInput`]: struct.TextInput.html
@@ -643,8 +857,10 @@ pub struct State {
     is_focused: bool,
     is_dragging: bool,
     is_pasting: Option<Value>,
+    is_revealed: bool,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    history: History,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -664,8 +880,10 @@ impl State {
             is_focused: true,
             is_dragging: false,
             is_pasting: None,
+            is_revealed: false,
             last_click: None,
             cursor: Cursor::default(),
+            history: History::default(),
         }
     }
 
@@ -683,6 +901,44 @@ impl State {
     pub fn cursor(&self) -> Cursor {
         self.cursor
     }
+
+    /// Returns whether the value of a password [`TextInput`] is currently
+    /// revealed or not.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn is_revealed(&self) -> bool {
+        self.is_revealed
+    }
+
+    /// Hides the value of a password [`TextInput`] if it was revealed.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn hide(&mut self) {
+        self.is_revealed = false;
+    }
+}
+
+impl crate::focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`TextInput`], moving its cursor to the end of its value.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.cursor.move_to(usize::MAX);
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_dragging = false;
+    }
+
+    fn accepts_text(&self) -> bool {
+        true
+    }
 }
 
 // TODO: Reduce allocations
@@ -741,7 +997,7 @@ fn find_cursor_position<Renderer: self::Renderer>(
     }
 }
 
-mod platform {
+pub(crate) mod platform {
     use crate::keyboard;
 
     pub fn is_jump_modifier_pressed(
@@ -764,3 +1020,112 @@ mod platform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Size};
+
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct Memory(RefCell<Option<String>>);
+
+    impl Clipboard for Memory {
+        fn content(&self) -> Option<String> {
+            self.0.borrow().clone()
+        }
+
+        fn write(&self, contents: String) {
+            *self.0.borrow_mut() = Some(contents);
+        }
+    }
+
+    fn press(
+        text_input: &mut TextInput<'_, String, Null>,
+        key_code: keyboard::KeyCode,
+        clipboard: &Memory,
+    ) -> Vec<String> {
+        let renderer = Null::new();
+        let node = Widget::<String, Null>::layout(
+            text_input,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let modifiers = keyboard::ModifiersState {
+            control: true,
+            logo: true,
+            ..keyboard::ModifiersState::NONE
+        };
+
+        let mut messages = Vec::new();
+
+        for event in [
+            keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            },
+            keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            },
+        ]
+        .iter()
+        .cloned()
+        {
+            Widget::<String, Null>::on_event(
+                text_input,
+                Event::Keyboard(event),
+                Layout::new(&node),
+                Point::ORIGIN,
+                &mut messages,
+                &renderer,
+                Some(clipboard),
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn copy_and_paste_round_trip_through_the_clipboard() {
+        let clipboard = Memory::default();
+
+        let mut source_state = State::focused();
+        let mut source =
+            TextInput::new(&mut source_state, "", "Hello, world!", |value| {
+                value
+            });
+
+        assert!(press(&mut source, keyboard::KeyCode::A, &clipboard).is_empty());
+        assert!(press(&mut source, keyboard::KeyCode::C, &clipboard).is_empty());
+        assert_eq!(clipboard.content().as_deref(), Some("Hello, world!"));
+
+        let mut target_state = State::focused();
+        let mut target =
+            TextInput::new(&mut target_state, "", "", |value| value);
+
+        assert_eq!(
+            press(&mut target, keyboard::KeyCode::V, &clipboard),
+            vec![String::from("Hello, world!")]
+        );
+    }
+
+    #[test]
+    fn cut_writes_the_selection_to_the_clipboard() {
+        let clipboard = Memory::default();
+
+        let mut state = State::focused();
+        let mut text_input =
+            TextInput::new(&mut state, "", "Hello, world!", |value| value);
+
+        let _ = press(&mut text_input, keyboard::KeyCode::A, &clipboard);
+
+        assert_eq!(
+            press(&mut text_input, keyboard::KeyCode::X, &clipboard),
+            vec![String::new()]
+        );
+        assert_eq!(clipboard.content().as_deref(), Some("Hello, world!"));
+    }
+}
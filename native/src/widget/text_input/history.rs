@@ -0,0 +1,109 @@
+use super::Cursor;
+
+/// The maximum amount of edits that can be undone.
+const LIMIT: usize = 100;
+
+/// The undo and redo stacks of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+    last_edit: Option<Edit>,
+}
+
+/// A kind of edit.
+///
+/// Consecutive edits of the same kind are undone together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    Insert,
+    Delete,
+    Other,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: String,
+    cursor: Cursor,
+}
+
+impl History {
+    /// Records the contents of a text input before an edit.
+    pub fn push(&mut self, edit: Edit, value: String, cursor: Cursor) {
+        let is_grouped = edit != Edit::Other && self.last_edit == Some(edit);
+
+        if !is_grouped {
+            self.undo.push(Entry { value, cursor });
+
+            if self.undo.len() > LIMIT {
+                let _ = self.undo.remove(0);
+            }
+        }
+
+        self.redo.clear();
+        self.last_edit = Some(edit);
+    }
+
+    /// Makes the next edit start a new group.
+    pub fn seal(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Returns the contents before the last group of edits, given the
+    /// current ones.
+    pub fn undo(
+        &mut self,
+        value: String,
+        cursor: Cursor,
+    ) -> Option<(String, Cursor)> {
+        let entry = self.undo.pop()?;
+
+        self.redo.push(Entry { value, cursor });
+        self.last_edit = None;
+
+        Some((entry.value, entry.cursor))
+    }
+
+    /// Returns the contents before the last undo, given the current ones.
+    pub fn redo(
+        &mut self,
+        value: String,
+        cursor: Cursor,
+    ) -> Option<(String, Cursor)> {
+        let entry = self.redo.pop()?;
+
+        self.undo.push(Entry { value, cursor });
+        self.last_edit = None;
+
+        Some((entry.value, entry.cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_edits_are_undone_together() {
+        let mut history = History::default();
+        let cursor = Cursor::default();
+
+        history.push(Edit::Insert, String::from(""), cursor);
+        history.push(Edit::Insert, String::from("a"), cursor);
+        history.push(Edit::Delete, String::from("ab"), cursor);
+        history.push(Edit::Delete, String::from("a"), cursor);
+
+        let (value, _) = history.undo(String::new(), cursor).unwrap();
+        assert_eq!(value, "ab");
+
+        let (value, _) = history.undo(value, cursor).unwrap();
+        assert_eq!(value, "");
+        assert!(history.undo(value.clone(), cursor).is_none());
+
+        let (value, _) = history.redo(value, cursor).unwrap();
+        assert_eq!(value, "ab");
+
+        history.push(Edit::Insert, value, cursor);
+        assert!(history.redo(String::from("abc"), cursor).is_none());
+    }
+}
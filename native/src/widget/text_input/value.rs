@@ -84,6 +84,23 @@ impl Value {
         Self { graphemes }
     }
 
+    /// Returns a new [`Value`] containing the graphemes from `start` to `end`.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn select(&self, start: usize, end: usize) -> Self {
+        let end = end.min(self.len());
+        let graphemes = self.graphemes[start.min(end)..end].to_vec();
+
+        Self { graphemes }
+    }
+
+    /// Returns the grapheme at the given `index`, if any.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn grapheme(&self, index: usize) -> Option<&str> {
+        self.graphemes.get(index).map(String::as_str)
+    }
+
     /// Converts the [`Value`] into a `String`.
     ///
     /// [`Value`]: struct.Value.html
@@ -125,10 +142,16 @@ impl Value {
     ///
     /// [`Value`]: struct.Value.html
     pub fn secure(&self) -> Self {
+        self.mask('•')
+    }
+
+    /// Returns a new [`Value`] with all its graphemes replaced with the
+    /// given character.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn mask(&self, character: char) -> Self {
         Self {
-            graphemes: std::iter::repeat(String::from("•"))
-                .take(self.graphemes.len())
-                .collect(),
+            graphemes: vec![character.to_string(); self.graphemes.len()],
         }
     }
 }
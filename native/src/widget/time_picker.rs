@@ -0,0 +1,781 @@
+//! Let your users pick a time of the day.
+//!
+//! A [`TimePicker`] has some local [`State`].
+//!
+//! [`TimePicker`]: struct.TimePicker.html
+//! [`State`]: struct.State.html
+use crate::{
+    date_picker::{self, Cell},
+    focus, keyboard, layout, mouse,
+    overlay::{self, popup},
+    Clipboard, Element, Event, Hasher, Id, Layout, Length, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::fmt;
+use std::hash::Hash;
+
+/// A field showing a time that opens a set of spinners when clicked.
+///
+/// Every part of the time can be changed with the arrows above and below it,
+/// or by scrolling over it. A message is produced on every change.
+///
+/// A [`TimePicker`] shares its style with a [`DatePicker`].
+///
+/// # Example
+/// ```
+/// # use iced_native::{time_picker, renderer::Null};
+/// #
+/// # pub type TimePicker<'a, Message> =
+/// #     iced_native::TimePicker<'a, Message, Null>;
+/// use time_picker::{HourFormat, Time};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TimeChanged(Time),
+/// }
+///
+/// let mut state = time_picker::State::new();
+///
+/// let time_picker = TimePicker::new(
+///     &mut state,
+///     Some(Time::new(18, 30, 0)),
+///     Message::TimeChanged,
+/// )
+/// .hour_format(HourFormat::Twelve)
+/// .show_seconds(true);
+/// ```
+///
+/// [`TimePicker`]: struct.TimePicker.html
+/// [`DatePicker`]: ../date_picker/struct.DatePicker.html
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    selected: Option<Time>,
+    on_change: Box<dyn Fn(Time) -> Message>,
+    placeholder: String,
+    hour_format: HourFormat,
+    show_seconds: bool,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    tab_index: Option<u16>,
+    id: Option<Id>,
+    style: <Renderer as date_picker::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> TimePicker<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`TimePicker`] with the given [`State`], the selected
+    /// [`Time`], and the message to produce when the [`Time`] changes.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    /// [`State`]: struct.State.html
+    /// [`Time`]: struct.Time.html
+    pub fn new(
+        state: &'a mut State,
+        selected: Option<Time>,
+        on_change: impl Fn(Time) -> Message + 'static,
+    ) -> Self {
+        TimePicker {
+            state,
+            selected,
+            on_change: Box::new(on_change),
+            placeholder: String::new(),
+            hour_format: HourFormat::TwentyFour,
+            show_seconds: false,
+            width: Length::Shrink,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            tab_index: None,
+            id: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the text shown by the [`TimePicker`] when no time is selected.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the [`HourFormat`] of the [`TimePicker`].
+    ///
+    /// By default, hours go from 0 to 23.
+    ///
+    /// [`HourFormat`]: enum.HourFormat.html
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn hour_format(mut self, hour_format: HourFormat) -> Self {
+        self.hour_format = hour_format;
+        self
+    }
+
+    /// Sets whether the seconds are shown and can be picked.
+    ///
+    /// By default, only hours and minutes are shown.
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// Sets the width of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`TimePicker`] and the cells of its spinners.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the position of the [`TimePicker`] in the focus order.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`TimePicker`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as date_picker::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn toggle(&mut self) {
+        self.state.is_open = !self.state.is_open;
+    }
+}
+
+/// A time of the day, with a precision of seconds.
+///
+/// Times are ordered chronologically and displayed as `HH:MM:SS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl Time {
+    /// Creates a new [`Time`].
+    ///
+    /// The hour is clamped to 23, and the minute and the second to 59.
+    ///
+    /// [`Time`]: struct.Time.html
+    pub fn new(hour: u32, minute: u32, second: u32) -> Self {
+        Time {
+            hour: hour.min(23),
+            minute: minute.min(59),
+            second: second.min(59),
+        }
+    }
+
+    /// Returns the hour of the [`Time`], from 0 to 23.
+    ///
+    /// [`Time`]: struct.Time.html
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the minute of the [`Time`].
+    ///
+    /// [`Time`]: struct.Time.html
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// Returns the second of the [`Time`].
+    ///
+    /// [`Time`]: struct.Time.html
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+
+    /// Formats the [`Time`] with the given [`HourFormat`], with or without
+    /// seconds.
+    ///
+    /// ```
+    /// use iced_native::time_picker::{HourFormat, Time};
+    ///
+    /// let time = Time::new(18, 5, 30);
+    ///
+    /// assert_eq!(time.format(HourFormat::TwentyFour, true), "18:05:30");
+    /// assert_eq!(time.format(HourFormat::Twelve, false), "06:05 PM");
+    /// ```
+    ///
+    /// [`Time`]: struct.Time.html
+    /// [`HourFormat`]: enum.HourFormat.html
+    pub fn format(
+        &self,
+        hour_format: HourFormat,
+        show_seconds: bool,
+    ) -> String {
+        let mut parts = vec![
+            Part::Hour.label(*self, hour_format),
+            Part::Minute.label(*self, hour_format),
+        ];
+
+        if show_seconds {
+            parts.push(Part::Second.label(*self, hour_format));
+        }
+
+        let time = parts.join(":");
+
+        match hour_format {
+            HourFormat::TwentyFour => time,
+            HourFormat::Twelve => {
+                format!("{} {}", time, Part::Period.label(*self, hour_format))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+/// The way the hours of a [`TimePicker`] are shown.
+///
+/// [`TimePicker`]: struct.TimePicker.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HourFormat {
+    /// Hours go from 0 to 23.
+    TwentyFour,
+
+    /// Hours go from 1 to 12, followed by AM or PM.
+    Twelve,
+}
+
+/// A part of a [`Time`] that can be changed with a spinner.
+///
+/// [`Time`]: struct.Time.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Hour,
+    Minute,
+    Second,
+    Period,
+}
+
+impl Part {
+    fn label(self, time: Time, hour_format: HourFormat) -> String {
+        match self {
+            Part::Hour => match hour_format {
+                HourFormat::TwentyFour => format!("{:02}", time.hour),
+                HourFormat::Twelve => {
+                    format!("{:02}", (time.hour + 11) % 12 + 1)
+                }
+            },
+            Part::Minute => format!("{:02}", time.minute),
+            Part::Second => format!("{:02}", time.second),
+            Part::Period => {
+                String::from(if time.hour < 12 { "AM" } else { "PM" })
+            }
+        }
+    }
+
+    /// Moves the part of the given [`Time`] by some steps, wrapping around.
+    ///
+    /// [`Time`]: struct.Time.html
+    fn step(self, time: Time, steps: i32) -> Time {
+        let wrap = |value: u32, steps: i32, max: i32| {
+            (value as i32 + steps).rem_euclid(max) as u32
+        };
+
+        match self {
+            Part::Hour => Time {
+                hour: wrap(time.hour, steps, 24),
+                ..time
+            },
+            Part::Minute => Time {
+                minute: wrap(time.minute, steps, 60),
+                ..time
+            },
+            Part::Second => Time {
+                second: wrap(time.second, steps, 60),
+                ..time
+            },
+            Part::Period => Time {
+                hour: wrap(time.hour, steps * 12, 24),
+                ..time
+            },
+        }
+    }
+}
+
+/// The local state of a [`TimePicker`].
+///
+/// [`TimePicker`]: struct.TimePicker.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_open: bool,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], representing a closed [`TimePicker`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the spinners of the [`TimePicker`] are open or not.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TimePicker<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let text_size = self.text_size.unwrap_or(Renderer::DEFAULT_SIZE);
+
+        let limits =
+            limits.width(self.width).height(Length::Shrink).pad(padding);
+
+        let (width, _) = renderer.measure(
+            &Time::new(23, 59, 59).format(self.hour_format, self.show_seconds),
+            text_size,
+            self.font,
+            Size::INFINITY,
+        );
+
+        let (placeholder_width, _) = renderer.measure(
+            &self.placeholder,
+            text_size,
+            self.font,
+            Size::INFINITY,
+        );
+
+        let intrinsic =
+            Size::new(width.max(placeholder_width), f32::from(text_size));
+
+        layout::Node::new(limits.resolve(intrinsic).pad(padding))
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.placeholder.hash(state);
+        self.hour_format.hash(state);
+        self.show_seconds.hash(state);
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if layout.bounds().contains(cursor_position) {
+                self.toggle();
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        date_picker::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            self.selected
+                .map(|time| time.format(self.hour_format, self.show_seconds)),
+            &self.placeholder,
+            self.padding,
+            self.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        targets.push(focus::Target::new(
+            self.state,
+            layout.bounds(),
+            self.tab_index,
+            self.id.clone(),
+        ));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.state.is_focused {
+            return false;
+        }
+
+        match event {
+            keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                ..
+            }
+            | keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            } => {
+                self.toggle();
+
+                true
+            }
+            keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            } if self.state.is_open => {
+                self.state.is_open = false;
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut parts = vec![Part::Hour, Part::Minute];
+
+        if self.show_seconds {
+            parts.push(Part::Second);
+        }
+
+        if self.hour_format == HourFormat::Twelve {
+            parts.push(Part::Period);
+        }
+
+        Some(overlay::Element::new(
+            Point::new(bounds.x, bounds.y),
+            Box::new(Spinners {
+                state: self.state,
+                target: bounds,
+                time: self.selected.unwrap_or_default(),
+                parts,
+                hour_format: self.hour_format,
+                on_change: &self.on_change,
+                padding: self.padding,
+                text_size: self.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+                font: self.font,
+                style: &self.style,
+            }),
+        ))
+    }
+}
+
+/// The spinners of a [`TimePicker`], shown as an overlay.
+///
+/// [`TimePicker`]: struct.TimePicker.html
+struct Spinners<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    target: Rectangle,
+    time: Time,
+    parts: Vec<Part>,
+    hour_format: HourFormat,
+    on_change: &'a dyn Fn(Time) -> Message,
+    padding: u16,
+    text_size: u16,
+    font: Renderer::Font,
+    style: &'a <Renderer as date_picker::Renderer>::Style,
+}
+
+/// Returns the bounds of the cell at the given column and row of the
+/// spinners, where the rows are the up arrow, the value, and the down arrow.
+fn cell_bounds(
+    bounds: Rectangle,
+    columns: usize,
+    column: usize,
+    row: usize,
+) -> Rectangle {
+    let width = bounds.width / columns as f32;
+    let height = bounds.height / 3.0;
+
+    Rectangle {
+        x: bounds.x + column as f32 * width,
+        y: bounds.y + row as f32 * height,
+        width,
+        height,
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Spinners<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let text_size = f32::from(self.text_size);
+        let padding = f32::from(self.padding * 2);
+
+        let size = Size::new(
+            (text_size * 1.5 + padding) * self.parts.len() as f32,
+            (text_size + padding) * 3.0,
+        );
+
+        popup::layout(size, self.target, bounds, position)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        if popup::is_dismissed(&event, bounds, self.target, cursor_position) {
+            self.state.is_open = false;
+            return;
+        }
+
+        if !bounds.contains(cursor_position) {
+            return;
+        }
+
+        let columns = self.parts.len();
+
+        let column = (((cursor_position.x - bounds.x) / bounds.width
+            * columns as f32) as usize)
+            .min(columns - 1);
+
+        let part = self.parts[column];
+
+        let steps = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if cell_bounds(bounds, columns, column, 0)
+                    .contains(cursor_position)
+                {
+                    1
+                } else if cell_bounds(bounds, columns, column, 2)
+                    .contains(cursor_position)
+                {
+                    -1
+                } else {
+                    0
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y > 0.0 {
+                    1
+                } else if y < 0.0 {
+                    -1
+                } else {
+                    0
+                }
+            }
+            _ => 0,
+        };
+
+        if steps != 0 {
+            messages.push((self.on_change)(part.step(self.time, steps)));
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let columns = self.parts.len();
+
+        let cell = |column, row, label: &str| Cell {
+            bounds: cell_bounds(bounds, columns, column, row),
+            label: String::from(label),
+            is_enabled: true,
+            is_selected: false,
+            is_outside: false,
+        };
+
+        let cells: Vec<Cell> = self
+            .parts
+            .iter()
+            .enumerate()
+            .flat_map(|(column, part)| {
+                vec![
+                    cell(column, 0, "\u{25b4}"),
+                    cell(column, 1, &part.label(self.time, self.hour_format)),
+                    cell(column, 2, "\u{25be}"),
+                ]
+            })
+            .collect();
+
+        self::Renderer::draw_spinners(
+            renderer,
+            bounds,
+            cursor_position,
+            &cells,
+            self.text_size,
+            self.font,
+            self.style,
+        )
+    }
+}
+
+/// The renderer of a [`TimePicker`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TimePicker`] in your user interface. The field of a
+/// [`TimePicker`] is drawn like the one of a [`DatePicker`].
+///
+/// [`TimePicker`]: struct.TimePicker.html
+/// [`DatePicker`]: ../date_picker/struct.DatePicker.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: date_picker::Renderer {
+    /// Draws the spinners of a [`TimePicker`].
+    ///
+    /// It receives:
+    /// - the bounds of the spinners and the cursor position
+    /// - the cells of the spinners, column by column, where every value of
+    ///   the time is placed between its two arrows
+    /// - the text size and font
+    /// - the style of the [`TimePicker`]
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    fn draw_spinners(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        cells: &[Cell],
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as date_picker::Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<TimePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        time_picker: TimePicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(time_picker)
+    }
+}
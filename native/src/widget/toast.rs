@@ -0,0 +1,812 @@
+//! Show transient notifications on top of the user interface.
+use crate::{
+    focus, keyboard, layout, mouse, overlay, text, window, Clipboard, Element,
+    Event, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::time::{Duration, Instant};
+
+/// A widget that stacks notification banners in a corner of the window, on
+/// top of its content.
+///
+/// A [`Notification`] is dismissed when its close button is pressed or when
+/// its timeout expires. The runtime wakes up when the earliest timeout of the
+/// shown notifications expires, so no subscription is needed. In both
+/// cases, the [`Toast`] produces a message with the index of the
+/// [`Notification`], which should then be removed from the list.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Toast<'a, Message> = iced_native::Toast<'a, Message, Null>;
+/// use iced_native::toast::{Notification, Severity};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Retry,
+///     Dismissed(usize),
+/// }
+///
+/// let notifications = vec![
+///     Notification::new(Severity::Success, "File saved"),
+///     Notification::new(Severity::Error, "Upload failed")
+///         .body("The server could not be reached.")
+///         .action("Retry", Message::Retry)
+///         .persistent(),
+/// ];
+///
+/// let toast = Toast::new(
+///     Text::new("Some content"),
+///     &notifications,
+///     Message::Dismissed,
+/// );
+/// ```
+///
+/// [`Notification`]: struct.Notification.html
+/// [`Toast`]: struct.Toast.html
+#[allow(missing_debug_implementations)]
+pub struct Toast<'a, Message, Renderer: self::Renderer> {
+    content: Element<'a, Message, Renderer>,
+    notifications: &'a [Notification<Message>],
+    on_dismiss: Box<dyn Fn(usize) -> Message>,
+    corner: Corner,
+    width: u16,
+    spacing: u16,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Toast<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Toast`] showing the given notifications on top of its
+    /// content.
+    ///
+    /// It expects:
+    ///   * the content of the [`Toast`]
+    ///   * the list of notifications to show
+    ///   * a function that will be called when a [`Notification`] is
+    ///     dismissed. It receives its index in the list and must produce a
+    ///     `Message`.
+    ///
+    /// [`Toast`]: struct.Toast.html
+    /// [`Notification`]: struct.Notification.html
+    pub fn new<E, F>(
+        content: E,
+        notifications: &'a [Notification<Message>],
+        on_dismiss: F,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+        F: 'static + Fn(usize) -> Message,
+    {
+        Toast {
+            content: content.into(),
+            notifications,
+            on_dismiss: Box::new(on_dismiss),
+            corner: Corner::BottomRight,
+            width: 300,
+            spacing: 10,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Corner`] of the window where the notifications of the
+    /// [`Toast`] are stacked.
+    ///
+    /// By default, it is the bottom right corner. The first notification is
+    /// placed closest to the corner.
+    ///
+    /// [`Corner`]: enum.Corner.html
+    /// [`Toast`]: struct.Toast.html
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the width of the notifications of the [`Toast`].
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the space between the notifications of the [`Toast`], and
+    /// between them and the borders of the window.
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding of the notifications of the [`Toast`].
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the notifications of the [`Toast`].
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the notifications of the [`Toast`].
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the notifications of the [`Toast`].
+    ///
+    /// [`Toast`]: struct.Toast.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// A transient message shown by a [`Toast`].
+///
+/// [`Toast`]: struct.Toast.html
+#[derive(Debug, Clone)]
+pub struct Notification<Message> {
+    severity: Severity,
+    title: String,
+    body: Option<String>,
+    actions: Vec<(String, Message)>,
+    timeout: Option<Duration>,
+    created_at: Instant,
+}
+
+impl<Message> Notification<Message> {
+    /// Creates a new [`Notification`] with the given [`Severity`] and title.
+    ///
+    /// By default, it is dismissed after five seconds.
+    ///
+    /// [`Notification`]: struct.Notification.html
+    /// [`Severity`]: enum.Severity.html
+    pub fn new(severity: Severity, title: impl Into<String>) -> Self {
+        Notification {
+            severity,
+            title: title.into(),
+            body: None,
+            actions: Vec::new(),
+            timeout: Some(Duration::from_secs(5)),
+            created_at: Instant::now(),
+        }
+    }
+
+    /// Sets the text shown below the title of the [`Notification`].
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Adds a button to the [`Notification`] producing the given message
+    /// when pressed.
+    ///
+    /// Pressing an action does not dismiss the [`Notification`].
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub fn action(
+        mut self,
+        label: impl Into<String>,
+        message: Message,
+    ) -> Self {
+        self.actions.push((label.into(), message));
+        self
+    }
+
+    /// Sets the time the [`Notification`] is shown before being dismissed.
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Keeps the [`Notification`] until its close button is pressed.
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub fn persistent(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+
+    /// Returns the [`Severity`] of the [`Notification`].
+    ///
+    /// [`Notification`]: struct.Notification.html
+    /// [`Severity`]: enum.Severity.html
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns whether the timeout of the [`Notification`] has expired at the
+    /// given [`Instant`].
+    ///
+    /// [`Notification`]: struct.Notification.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at().map(|at| now >= at).unwrap_or(false)
+    }
+
+    /// Returns the [`Instant`] the timeout of the [`Notification`] expires at,
+    /// if it has one.
+    ///
+    /// [`Notification`]: struct.Notification.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn expires_at(&self) -> Option<Instant> {
+        self.timeout.map(|timeout| self.created_at + timeout)
+    }
+}
+
+/// The importance of a [`Notification`], which determines its style.
+///
+/// [`Notification`]: struct.Notification.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// The [`Notification`] informs the user.
+    ///
+    /// [`Notification`]: struct.Notification.html
+    Info,
+
+    /// The [`Notification`] reports a success.
+    ///
+    /// [`Notification`]: struct.Notification.html
+    Success,
+
+    /// The [`Notification`] warns the user.
+    ///
+    /// [`Notification`]: struct.Notification.html
+    Warning,
+
+    /// The [`Notification`] reports an error.
+    ///
+    /// [`Notification`]: struct.Notification.html
+    Error,
+}
+
+/// A corner of the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Corner {
+    /// The top left corner.
+    TopLeft,
+
+    /// The top right corner.
+    TopRight,
+
+    /// The bottom left corner.
+    BottomLeft,
+
+    /// The bottom right corner.
+    BottomRight,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Toast<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        // Timeouts are checked on every frame, from last to first, so that
+        // removing notifications by index in order stays consistent
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            for (i, notification) in self.notifications.iter().enumerate().rev()
+            {
+                if notification.is_expired(now) {
+                    messages.push((self.on_dismiss)(i));
+                }
+            }
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        // A single frame is requested, for the earliest timeout to expire
+        let next_timeout = self
+            .notifications
+            .iter()
+            .filter_map(Notification::expires_at)
+            .min();
+
+        if let Some(at) = next_timeout {
+            renderer.request_redraw_at(at);
+        }
+
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if let Some(overlay) = self.content.overlay(layout) {
+            return Some(overlay);
+        }
+
+        if self.notifications.is_empty() {
+            return None;
+        }
+
+        Some(overlay::Element::new(
+            Point::ORIGIN,
+            Box::new(Stack {
+                notifications: self.notifications,
+                on_dismiss: self.on_dismiss.as_ref(),
+                corner: self.corner,
+                width: f32::from(self.width),
+                spacing: f32::from(self.spacing),
+                padding: f32::from(self.padding),
+                text_size: self.text_size.unwrap_or(Renderer::DEFAULT_SIZE),
+                font: self.font,
+                style: &self.style,
+            }),
+        ))
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.content.focus_targets(layout, targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.content
+            .on_focused_event(event, layout, messages, renderer, clipboard)
+    }
+}
+
+/// The notifications of a [`Toast`], stacked in a corner of the window.
+///
+/// [`Toast`]: struct.Toast.html
+struct Stack<'a, Message, Renderer: self::Renderer> {
+    notifications: &'a [Notification<Message>],
+    on_dismiss: &'a dyn Fn(usize) -> Message,
+    corner: Corner,
+    width: f32,
+    spacing: f32,
+    padding: f32,
+    text_size: u16,
+    font: Renderer::Font,
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Lays out a single notification.
+    ///
+    /// Its children are its title, its close button, its body and its
+    /// actions, in this order.
+    fn banner(
+        &self,
+        renderer: &Renderer,
+        notification: &Notification<Message>,
+    ) -> layout::Node {
+        let padding = self.padding;
+        let line_height = f32::from(self.text_size);
+        let inner_width = self.width - 2.0 * padding;
+
+        let (_, title_height) = renderer.measure(
+            &notification.title,
+            self.text_size,
+            self.font,
+            Size::new(inner_width - line_height - padding, f32::INFINITY),
+        );
+
+        let mut title = layout::Node::new(Size::new(
+            inner_width - line_height - padding,
+            title_height.max(line_height),
+        ));
+        title.move_to(Point::new(padding, padding));
+
+        let mut close = layout::Node::new(Size::new(line_height, line_height));
+        close.move_to(Point::new(self.width - padding - line_height, padding));
+
+        let mut y = padding + title.size().height;
+
+        let body_height = match &notification.body {
+            Some(body) => {
+                y += padding / 2.0;
+
+                renderer
+                    .measure(
+                        body,
+                        self.text_size,
+                        self.font,
+                        Size::new(inner_width, f32::INFINITY),
+                    )
+                    .1
+            }
+            None => 0.0,
+        };
+
+        let mut body = layout::Node::new(Size::new(inner_width, body_height));
+        body.move_to(Point::new(padding, y));
+
+        y += body_height;
+
+        let mut children = vec![title, close, body];
+
+        if !notification.actions.is_empty() {
+            y += padding;
+
+            // Actions are aligned to the right of the banner
+            let mut x = self.width - padding;
+
+            let mut actions: Vec<layout::Node> = notification
+                .actions
+                .iter()
+                .rev()
+                .map(|(label, _)| {
+                    let size = renderer
+                        .measure_text(label, self.text_size, self.font)
+                        .pad(padding / 2.0);
+
+                    x -= size.width;
+
+                    let mut action = layout::Node::new(size);
+                    action.move_to(Point::new(x, y));
+
+                    x -= padding / 2.0;
+
+                    action
+                })
+                .collect();
+
+            actions.reverse();
+
+            y += line_height + padding;
+
+            children.extend(actions);
+        }
+
+        layout::Node::with_children(
+            Size::new(self.width, y + padding),
+            children,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        let mut banners: Vec<layout::Node> = self
+            .notifications
+            .iter()
+            .map(|notification| self.banner(renderer, notification))
+            .collect();
+
+        let height = banners
+            .iter()
+            .map(|banner| banner.size().height)
+            .sum::<f32>()
+            + self.spacing * banners.len().saturating_sub(1) as f32;
+
+        let is_top = match self.corner {
+            Corner::TopLeft | Corner::TopRight => true,
+            Corner::BottomLeft | Corner::BottomRight => false,
+        };
+
+        // The first banner is placed closest to the corner
+        let mut offset = 0.0;
+
+        for banner in banners.iter_mut() {
+            let banner_height = banner.size().height;
+
+            let y = if is_top {
+                offset
+            } else {
+                height - offset - banner_height
+            };
+
+            banner.move_to(Point::new(0.0, y));
+
+            offset += banner_height + self.spacing;
+        }
+
+        let x = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => self.spacing,
+            Corner::TopRight | Corner::BottomRight => {
+                bounds.width - self.width - self.spacing
+            }
+        };
+
+        let y = if is_top {
+            self.spacing
+        } else {
+            bounds.height - height - self.spacing
+        };
+
+        let mut node =
+            layout::Node::with_children(Size::new(self.width, height), banners);
+        node.move_to(Point::new(x, y));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            for (i, (notification, banner)) in
+                self.notifications.iter().zip(layout.children()).enumerate()
+            {
+                let mut children = banner.children().skip(1);
+
+                let close = children.next().unwrap();
+
+                if close.bounds().contains(cursor_position) {
+                    messages.push((self.on_dismiss)(i));
+                    return;
+                }
+
+                let actions = children.skip(1);
+
+                for ((_, message), action) in
+                    notification.actions.iter().zip(actions)
+                {
+                    if action.bounds().contains(cursor_position) {
+                        messages.push(message.clone());
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let banners: Vec<Banner<'_>> = self
+            .notifications
+            .iter()
+            .zip(layout.children())
+            .map(|(notification, layout)| {
+                let mut children = layout.children();
+
+                let title = children.next().unwrap();
+                let close = children.next().unwrap();
+                let body = children.next().unwrap();
+
+                Banner {
+                    bounds: layout.bounds(),
+                    severity: notification.severity,
+                    title: &notification.title,
+                    title_bounds: title.bounds(),
+                    body: notification.body.as_deref(),
+                    body_bounds: body.bounds(),
+                    close: close.bounds(),
+                    actions: notification
+                        .actions
+                        .iter()
+                        .zip(children)
+                        .map(|((label, _), action)| {
+                            (label.as_str(), action.bounds())
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            cursor_position,
+            &banners,
+            self.text_size,
+            self.font,
+            self.style,
+        )
+    }
+}
+
+/// A laid out [`Notification`], ready to be drawn.
+///
+/// [`Notification`]: struct.Notification.html
+#[derive(Debug, Clone)]
+pub struct Banner<'a> {
+    /// The bounds of the banner.
+    pub bounds: Rectangle,
+
+    /// The [`Severity`] of the [`Notification`].
+    ///
+    /// [`Severity`]: enum.Severity.html
+    /// [`Notification`]: struct.Notification.html
+    pub severity: Severity,
+
+    /// The title of the [`Notification`].
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub title: &'a str,
+
+    /// The bounds of the title.
+    pub title_bounds: Rectangle,
+
+    /// The body of the [`Notification`], if any.
+    ///
+    /// [`Notification`]: struct.Notification.html
+    pub body: Option<&'a str>,
+
+    /// The bounds of the body.
+    pub body_bounds: Rectangle,
+
+    /// The bounds of the close button.
+    pub close: Rectangle,
+
+    /// The labels and the bounds of the action buttons.
+    pub actions: Vec<(&'a str, Rectangle)>,
+}
+
+/// The renderer of a [`Toast`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Toast`] in your user interface.
+///
+/// [`Toast`]: struct.Toast.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The default padding of the notifications of a [`Toast`].
+    ///
+    /// [`Toast`]: struct.Toast.html
+    const DEFAULT_PADDING: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the notifications of a [`Toast`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the laid out notifications of the [`Toast`]
+    ///   * the text size and the font of the notifications
+    ///   * the style of the [`Toast`]
+    ///
+    /// [`Toast`]: struct.Toast.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        banners: &[Banner<'_>],
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as self::Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Toast<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        toast: Toast<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(toast)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expires_after_timeout() {
+        let notification: Notification<()> =
+            Notification::new(Severity::Info, "Saved")
+                .timeout(Duration::from_secs(2));
+
+        let expires_at = notification.expires_at().expect("Expiry");
+
+        assert_eq!(
+            expires_at,
+            notification.created_at + Duration::from_secs(2)
+        );
+        assert!(!notification.is_expired(notification.created_at));
+        assert!(notification.is_expired(expires_at));
+    }
+
+    #[test]
+    fn persistent_notifications_never_expire() {
+        let notification: Notification<()> =
+            Notification::new(Severity::Error, "Failed").persistent();
+
+        assert_eq!(notification.expires_at(), None);
+        assert!(!notification.is_expired(Instant::now()));
+    }
+}
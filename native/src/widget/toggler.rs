@@ -0,0 +1,448 @@
+//! Show toggle controls using togglers.
+//!
+//! A [`Toggler`] has some local [`State`].
+//!
+//! [`Toggler`]: struct.Toggler.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use crate::{
+    focus, keyboard, layout, mouse, row, text, Align, Clipboard, Element,
+    Event, Hasher, HorizontalAlignment, Id, Layout, Length, Point, Rectangle,
+    Row, Text, VerticalAlignment, Widget,
+};
+
+/// A switch that can be toggled on and off, next to a label.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::toggler;
+/// # type Toggler<'a, Message> = iced_native::Toggler<'a, Message, iced_native::renderer::Null>;
+/// #
+/// pub enum Message {
+///     TogglerToggled(bool),
+/// }
+///
+/// let mut state = toggler::State::new();
+/// let is_active = true;
+///
+/// Toggler::new(&mut state, is_active, "Toggle me!", Message::TogglerToggled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Toggler<'a, Message, Renderer: self::Renderer + text::Renderer> {
+    state: &'a mut State,
+    is_active: bool,
+    is_enabled: bool,
+    on_toggle: Box<dyn Fn(bool) -> Message>,
+    label: String,
+    width: Length,
+    size: u16,
+    spacing: u16,
+    text_size: u16,
+    tab_index: Option<u16>,
+    id: Option<Id>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer + text::Renderer>
+    Toggler<'a, Message, Renderer>
+{
+    /// Creates a new [`Toggler`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Toggler`]
+    ///   * a boolean describing whether the [`Toggler`] is active or not
+    ///   * the label of the [`Toggler`]
+    ///   * a function that will be called when the [`Toggler`] is toggled. It
+    ///     will receive the new state of the [`Toggler`] and must produce a
+    ///     `Message`.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        is_active: bool,
+        label: impl Into<String>,
+        f: F,
+    ) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        state.transition_to(is_active, Instant::now());
+
+        Toggler {
+            state,
+            is_active,
+            is_enabled: true,
+            on_toggle: Box::new(f),
+            label: label.into(),
+            width: Length::Shrink,
+            size: <Renderer as self::Renderer>::DEFAULT_SIZE,
+            spacing: Renderer::DEFAULT_SPACING,
+            text_size: <Renderer as text::Renderer>::DEFAULT_SIZE,
+            tab_index: None,
+            id: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the height of the [`Toggler`]. Its width is twice its height.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the width of the [`Toggler`], including its label.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the label and the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Disables the [`Toggler`], which is then shown but cannot be toggled.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn disabled(mut self) -> Self {
+        self.is_enabled = false;
+        self
+    }
+
+    /// Sets the position of the [`Toggler`] in the focus order.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Toggler`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`Toggler`].
+///
+/// [`Toggler`]: struct.Toggler.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    is_focused: bool,
+    is_active: Option<bool>,
+    transition: Option<(Instant, f32)>,
+    position: f32,
+}
+
+/// The duration of the sliding of the thumb of a [`Toggler`].
+///
+/// [`Toggler`]: struct.Toggler.html
+const TRANSITION_DURATION: Duration = Duration::from_millis(150);
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the thumb of the [`Toggler`] is currently sliding.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn is_animating(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Updates the position of the thumb, starting a transition if the
+    /// [`Toggler`] was toggled.
+    ///
+    /// The first time a [`Toggler`] is shown, it does not animate.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    fn transition_to(&mut self, is_active: bool, now: Instant) {
+        let target = if is_active { 1.0 } else { 0.0 };
+
+        match self.is_active {
+            None => {
+                self.position = target;
+            }
+            Some(was_active) if was_active != is_active => {
+                self.transition = Some((now, self.position));
+            }
+            Some(_) => {}
+        }
+
+        self.is_active = Some(is_active);
+
+        if let Some((started_at, start)) = self.transition {
+            let elapsed = now.duration_since(started_at).as_secs_f32();
+            let t = elapsed / TRANSITION_DURATION.as_secs_f32();
+
+            if t >= 1.0 {
+                self.position = target;
+                self.transition = None;
+            } else {
+                // Ease out, slowing down towards the end of the transition
+                let eased = 1.0 - (1.0 - t) * (1.0 - t);
+
+                self.position = start + (target - start) * eased;
+            }
+        }
+    }
+}
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Toggler<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer + row::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Row::<(), Renderer>::new()
+            .width(self.width)
+            .spacing(self.spacing)
+            .align_items(Align::Center)
+            .push(
+                Text::new(&self.label)
+                    .width(self.width)
+                    .size(self.text_size),
+            )
+            .push(
+                Row::new()
+                    .width(Length::Units(self.size * 2))
+                    .height(Length::Units(self.size)),
+            )
+            .layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            let mouse_over = layout.bounds().contains(cursor_position);
+
+            if mouse_over && self.is_enabled {
+                messages.push((self.on_toggle)(!self.is_active));
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        if self.state.is_animating() {
+            renderer.request_redraw();
+        }
+
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+
+        let label_layout = children.next().unwrap();
+        let toggler_layout = children.next().unwrap();
+
+        let label = text::Renderer::draw(
+            renderer,
+            defaults,
+            label_layout.bounds(),
+            &self.label,
+            self.text_size,
+            Default::default(),
+            None,
+            HorizontalAlignment::Left,
+            VerticalAlignment::Center,
+        );
+
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        self::Renderer::draw(
+            renderer,
+            toggler_layout.bounds(),
+            self.is_active,
+            self.state.position,
+            is_mouse_over,
+            !self.is_enabled,
+            label,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.label.hash(state);
+        self.width.hash(state);
+        self.size.hash(state);
+        self.spacing.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        // Disabled togglers cannot be focused
+        if self.is_enabled {
+            targets.push(focus::Target::new(
+                self.state,
+                layout.bounds(),
+                self.tab_index,
+                self.id.clone(),
+            ));
+        }
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.state.is_focused {
+            return false;
+        }
+
+        match event {
+            keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            } => {
+                messages.push((self.on_toggle)(!self.is_active));
+
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The renderer of a [`Toggler`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Toggler`] in your user interface.
+///
+/// [`Toggler`]: struct.Toggler.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default height of a [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    const DEFAULT_SIZE: u16;
+
+    /// The default spacing between the label and a [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    const DEFAULT_SPACING: u16;
+
+    /// Draws a [`Toggler`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Toggler`], excluding its label
+    ///   * whether the [`Toggler`] is active or not
+    ///   * the position of the thumb, from `0.0` when inactive to `1.0` when
+    ///     active, which changes over time when the [`Toggler`] is toggled
+    ///   * whether the mouse is over the [`Toggler`] or not
+    ///   * whether the [`Toggler`] is disabled or not
+    ///   * the drawn label of the [`Toggler`]
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        is_active: bool,
+        thumb_position: f32,
+        is_mouse_over: bool,
+        is_disabled: bool,
+        label: Self::Output,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Toggler<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer + text::Renderer + row::Renderer,
+    Message: 'a,
+{
+    fn from(
+        toggler: Toggler<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(toggler)
+    }
+}
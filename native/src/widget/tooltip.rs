@@ -0,0 +1,453 @@
+//! Show a label next to a widget when it is hovered.
+//!
+//! A [`Tooltip`] has some local [`State`].
+//!
+//! [`Tooltip`]: struct.Tooltip.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, overlay, text, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Size, Vector, Widget,
+};
+
+use std::time::{Duration, Instant};
+
+/// A widget showing a label on top of the user interface while its content
+/// is hovered.
+///
+/// The label is shown once the cursor has rested over the content for the
+/// [`delay`] of the [`Tooltip`]. As the user interface is only updated when
+/// an event happens, the label appears on the first event after the delay has
+/// passed.
+///
+/// # Example
+/// ```
+/// # use iced_native::{tooltip, renderer::Null, Text};
+/// #
+/// # pub type Tooltip<'a, Message> = iced_native::Tooltip<'a, Message, Null>;
+/// use iced_native::tooltip::Position;
+///
+/// let mut state = tooltip::State::new();
+///
+/// let tooltip = Tooltip::<()>::new(
+///     &mut state,
+///     Text::new("Save"),
+///     "Save the current file",
+///     Position::Bottom,
+/// );
+/// ```
+///
+/// [`delay`]: #method.delay
+/// [`Tooltip`]: struct.Tooltip.html
+#[allow(missing_debug_implementations)]
+pub struct Tooltip<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    label: String,
+    position: Position,
+    delay: Duration,
+    gap: u16,
+    padding: u16,
+    size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Tooltip`] showing the given label at the given
+    /// [`Position`] when its content is hovered.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`Position`]: enum.Position.html
+    pub fn new<E>(
+        state: &'a mut State,
+        content: E,
+        label: impl Into<String>,
+        position: Position,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Tooltip {
+            state,
+            content: content.into(),
+            label: label.into(),
+            position,
+            delay: Duration::from_millis(500),
+            gap: 5,
+            padding: Renderer::DEFAULT_PADDING,
+            size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the time the cursor needs to rest over the content of the
+    /// [`Tooltip`] before the label is shown.
+    ///
+    /// By default, it is half a second.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the distance between the label of the [`Tooltip`] and its
+    /// content, or the cursor.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the padding of the label of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the font of the label of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the label of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The placement of the label of a [`Tooltip`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// The label is shown above the cursor.
+    FollowCursor,
+
+    /// The label is shown above the content.
+    Top,
+
+    /// The label is shown below the content.
+    Bottom,
+
+    /// The label is shown to the left of the content.
+    Left,
+
+    /// The label is shown to the right of the content.
+    Right,
+}
+
+/// The local state of a [`Tooltip`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    hovered_at: Option<Instant>,
+    cursor_position: Point,
+}
+
+impl State {
+    /// Creates a new [`State`], representing a [`Tooltip`] that is not
+    /// hovered.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the content of the [`Tooltip`] is currently hovered or
+    /// not.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn is_hovered(&self) -> bool {
+        self.hovered_at.is_some()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        if layout.bounds().contains(cursor_position) {
+            if self.state.hovered_at.is_none() {
+                self.state.hovered_at = Some(Instant::now());
+            }
+
+            self.state.cursor_position = cursor_position;
+        } else {
+            self.state.hovered_at = None;
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if let Some(overlay) = self.content.overlay(layout) {
+            return Some(overlay);
+        }
+
+        let delay = self.delay;
+
+        let is_visible = self
+            .state
+            .hovered_at
+            .map(|hovered_at| hovered_at.elapsed() >= delay)
+            .unwrap_or(false);
+
+        if !is_visible {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.x, bounds.y);
+
+        Some(overlay::Element::new(
+            position,
+            Box::new(Label {
+                label: &self.label,
+                position: self.position,
+                target_size: bounds.size(),
+                cursor_offset: self.state.cursor_position - position,
+                gap: f32::from(self.gap),
+                padding: self.padding,
+                size: self.size,
+                font: self.font,
+                style: &self.style,
+            }),
+        ))
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.content.focus_targets(layout, targets);
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.content
+            .on_focused_event(event, layout, messages, renderer, clipboard)
+    }
+}
+
+/// The label of a [`Tooltip`], shown as an overlay.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+struct Label<'a, Renderer: self::Renderer> {
+    label: &'a str,
+    position: Position,
+    target_size: Size,
+    cursor_offset: Vector,
+    gap: f32,
+    padding: u16,
+    size: Option<u16>,
+    font: Renderer::Font,
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Label<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let text_size = self.size.unwrap_or(Renderer::DEFAULT_SIZE);
+
+        let size = renderer
+            .measure_text(self.label, text_size, self.font)
+            .pad(padding);
+
+        let target = self.target_size;
+
+        let (x, y) = match self.position {
+            Position::FollowCursor => {
+                let cursor = position + self.cursor_offset;
+
+                (
+                    cursor.x - size.width / 2.0,
+                    cursor.y - size.height - self.gap,
+                )
+            }
+            Position::Top => (
+                position.x + (target.width - size.width) / 2.0,
+                position.y - size.height - self.gap,
+            ),
+            Position::Bottom => (
+                position.x + (target.width - size.width) / 2.0,
+                position.y + target.height + self.gap,
+            ),
+            Position::Left => (
+                position.x - size.width - self.gap,
+                position.y + (target.height - size.height) / 2.0,
+            ),
+            Position::Right => (
+                position.x + target.width + self.gap,
+                position.y + (target.height - size.height) / 2.0,
+            ),
+        };
+
+        // Keep the label inside the viewport
+        let x = x.min(bounds.width - size.width).max(0.0);
+        let y = y.min(bounds.height - size.height).max(0.0);
+
+        let mut node = layout::Node::new(size);
+        node.move_to(Point::new(x, y));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            self.label,
+            self.size.unwrap_or(Renderer::DEFAULT_SIZE),
+            self.font,
+            self.style,
+        )
+    }
+}
+
+/// The renderer of a [`Tooltip`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Tooltip`] in your user interface.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The default padding of the label of a [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    const DEFAULT_PADDING: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the label of a [`Tooltip`].
+    ///
+    /// It receives:
+    /// - the bounds of the label, including its padding
+    /// - the text of the label
+    /// - its text size and font
+    /// - the style of the [`Tooltip`]
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        label: &str,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        tooltip: Tooltip<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tooltip)
+    }
+}
@@ -0,0 +1,678 @@
+//! Display hierarchical data with expandable nodes.
+//!
+//! A [`TreeView`] has some local [`State`].
+//!
+//! [`TreeView`]: struct.TreeView.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse, text, Clipboard, Element, Event, Hasher,
+    Id, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// A widget displaying a tree of nodes that can be expanded, collapsed, and
+/// selected.
+///
+/// The children of a [`Node`] can be loaded lazily. A [`TreeView`] produces a
+/// message every time a node is expanded, so the application can load the
+/// children of the node when needed.
+///
+/// # Example
+/// ```
+/// # use iced_native::{tree_view, renderer::Null};
+/// #
+/// # pub type TreeView<'a, T, Message> = iced_native::TreeView<'a, T, Message, Null>;
+/// use iced_native::tree_view::Node;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Selected(String),
+///     Expanded(String),
+/// }
+///
+/// let tree = vec![Node::branch(
+///     String::from("src"),
+///     "src",
+///     vec![
+///         Node::leaf(String::from("src/main.rs"), "main.rs"),
+///         Node::unloaded(String::from("src/widget"), "widget"),
+///     ],
+/// )];
+///
+/// let mut state = tree_view::State::new();
+///
+/// let tree_view = TreeView::new(&mut state, &tree, Message::Selected)
+///     .on_expand(Message::Expanded);
+/// ```
+///
+/// [`TreeView`]: struct.TreeView.html
+/// [`Node`]: struct.Node.html
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, T, Message, Renderer: self::Renderer>
+where
+    T: Clone + Eq + Hash,
+{
+    state: &'a mut State<T>,
+    nodes: &'a [Node<T>],
+    selected: Option<T>,
+    on_select: Box<dyn Fn(T) -> Message>,
+    on_expand: Option<Box<dyn Fn(T) -> Message>>,
+    width: Length,
+    indent: u16,
+    padding: u16,
+    text_size: u16,
+    font: Renderer::Font,
+    icon_font: Renderer::Font,
+    tab_index: Option<u16>,
+    id: Option<Id>,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> TreeView<'a, T, Message, Renderer>
+where
+    T: Clone + Eq + Hash,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`TreeView`] displaying the given root nodes, and a
+    /// function that produces a message when a [`Node`] is selected.
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    /// [`Node`]: struct.Node.html
+    pub fn new<F>(
+        state: &'a mut State<T>,
+        nodes: &'a [Node<T>],
+        on_select: F,
+    ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        TreeView {
+            state,
+            nodes,
+            selected: None,
+            on_select: Box::new(on_select),
+            on_expand: None,
+            width: Length::Fill,
+            indent: 16,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: Renderer::DEFAULT_SIZE,
+            font: Default::default(),
+            icon_font: Default::default(),
+            tab_index: None,
+            id: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the selected [`Node`] of the [`TreeView`].
+    ///
+    /// [`Node`]: struct.Node.html
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn selected(mut self, id: Option<T>) -> Self {
+        self.selected = id;
+        self
+    }
+
+    /// Sets the message that will be produced when a [`Node`] is expanded.
+    ///
+    /// You can use it to load the children of the [`Node`].
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn on_expand<F>(mut self, on_expand: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        self.on_expand = Some(Box::new(on_expand));
+        self
+    }
+
+    /// Sets the position of the [`TreeView`] in the focus order.
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn tab_index(mut self, tab_index: u16) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets the [`Id`] of the [`TreeView`], which allows focusing it with a
+    /// command.
+    ///
+    /// [`Id`]: ../../struct.Id.html
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the indentation of every level of the [`TreeView`], in pixels.
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the padding of the rows of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the font used to draw the icons of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn icon_font(mut self, font: Renderer::Font) -> Self {
+        self.icon_font = font;
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn row_height(&self) -> f32 {
+        f32::from(self.text_size + self.padding * 2)
+    }
+
+    /// Returns the visible nodes of the tree alongside their depth.
+    fn visible_nodes(&self) -> Vec<(usize, &'a Node<T>)> {
+        fn visit<'a, T: Clone + Eq + Hash>(
+            nodes: &'a [Node<T>],
+            depth: usize,
+            expanded: &HashSet<T>,
+            visible: &mut Vec<(usize, &'a Node<T>)>,
+        ) {
+            for node in nodes {
+                visible.push((depth, node));
+
+                if let (true, Some(children)) =
+                    (expanded.contains(&node.id), &node.children)
+                {
+                    visit(children, depth + 1, expanded, visible);
+                }
+            }
+        }
+
+        let mut visible = Vec::new();
+        visit(self.nodes, 0, &self.state.expanded, &mut visible);
+
+        visible
+    }
+}
+
+/// A node of a [`TreeView`].
+///
+/// [`TreeView`]: struct.TreeView.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node<T> {
+    id: T,
+    label: String,
+    icon: Option<char>,
+    is_expandable: bool,
+    children: Option<Vec<Node<T>>>,
+}
+
+impl<T> Node<T> {
+    /// Creates a new [`Node`] without children.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn leaf(id: T, label: impl Into<String>) -> Self {
+        Node {
+            id,
+            label: label.into(),
+            icon: None,
+            is_expandable: false,
+            children: Some(Vec::new()),
+        }
+    }
+
+    /// Creates a new [`Node`] with the given children.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn branch(
+        id: T,
+        label: impl Into<String>,
+        children: Vec<Node<T>>,
+    ) -> Self {
+        Node {
+            id,
+            label: label.into(),
+            icon: None,
+            is_expandable: true,
+            children: Some(children),
+        }
+    }
+
+    /// Creates a new [`Node`] whose children have not been loaded yet.
+    ///
+    /// The [`Node`] can be expanded, and it will show its children once they
+    /// are loaded and provided with [`branch`].
+    ///
+    /// [`Node`]: struct.Node.html
+    /// [`branch`]: #method.branch
+    pub fn unloaded(id: T, label: impl Into<String>) -> Self {
+        Node {
+            id,
+            label: label.into(),
+            icon: None,
+            is_expandable: true,
+            children: None,
+        }
+    }
+
+    /// Sets the icon of the [`Node`], shown before its label.
+    ///
+    /// The icon is drawn with the icon font of the [`TreeView`].
+    ///
+    /// [`Node`]: struct.Node.html
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn icon(mut self, icon: char) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Returns the identifier of the [`Node`].
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn id(&self) -> &T {
+        &self.id
+    }
+
+    /// Returns whether the children of the [`Node`] are loaded or not.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn is_loaded(&self) -> bool {
+        self.children.is_some()
+    }
+}
+
+/// The local state of a [`TreeView`].
+///
+/// It keeps track of the expanded nodes.
+///
+/// [`TreeView`]: struct.TreeView.html
+#[derive(Debug, Clone)]
+pub struct State<T: Eq + Hash> {
+    expanded: HashSet<T>,
+    is_focused: bool,
+}
+
+impl<T: Eq + Hash> Default for State<T> {
+    fn default() -> Self {
+        State {
+            expanded: HashSet::new(),
+            is_focused: false,
+        }
+    }
+}
+
+impl<T: Eq + Hash> State<T> {
+    /// Creates a new [`State`] with every node collapsed.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the node with the given identifier is expanded or not.
+    pub fn is_expanded(&self, id: &T) -> bool {
+        self.expanded.contains(id)
+    }
+
+    /// Expands the node with the given identifier.
+    pub fn expand(&mut self, id: T) {
+        let _ = self.expanded.insert(id);
+    }
+
+    /// Collapses the node with the given identifier.
+    pub fn collapse(&mut self, id: &T) {
+        let _ = self.expanded.remove(id);
+    }
+}
+
+impl<T: Eq + Hash> focus::Focusable for State<T> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for TreeView<'a, T, Message, Renderer>
+where
+    T: Clone + Eq + Hash,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let height = self.visible_nodes().len() as f32 * self.row_height();
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let size = limits.resolve(Size::new(0.0, height));
+
+        layout::Node::new(size)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.visible_nodes().len().hash(state);
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if bounds.contains(cursor_position) =>
+            {
+                let index = ((cursor_position.y - bounds.y) / self.row_height())
+                    as usize;
+
+                let (depth, node) = match self.visible_nodes().get(index) {
+                    Some(row) => *row,
+                    None => return,
+                };
+
+                let expander_x = bounds.x
+                    + depth as f32 * f32::from(self.indent)
+                    + f32::from(self.padding);
+
+                let is_expander_clicked = cursor_position.x >= expander_x
+                    && cursor_position.x
+                        <= expander_x + f32::from(self.text_size);
+
+                if node.is_expandable && is_expander_clicked {
+                    if self.state.is_expanded(&node.id) {
+                        self.state.collapse(&node.id);
+                    } else {
+                        self.state.expand(node.id.clone());
+
+                        if let Some(on_expand) = &self.on_expand {
+                            messages.push(on_expand(node.id.clone()));
+                        }
+                    }
+                } else {
+                    messages.push((self.on_select)(node.id.clone()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let row_height = self.row_height();
+
+        let rows: Vec<_> = self
+            .visible_nodes()
+            .into_iter()
+            .enumerate()
+            .map(|(index, (depth, node))| {
+                let bounds = Rectangle {
+                    y: bounds.y + index as f32 * row_height,
+                    height: row_height,
+                    ..bounds
+                };
+
+                let is_expanded = self.state.is_expanded(&node.id);
+
+                Row {
+                    bounds,
+                    depth,
+                    label: &node.label,
+                    icon: node.icon,
+                    is_expandable: node.is_expandable,
+                    is_expanded,
+                    is_loading: is_expanded && node.children.is_none(),
+                    is_selected: self.selected.as_ref() == Some(&node.id),
+                    is_hovered: bounds.contains(cursor_position),
+                }
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            &rows,
+            self.indent,
+            self.padding,
+            self.text_size,
+            self.font,
+            self.icon_font,
+            &self.style,
+        )
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        targets.push(focus::Target::new(
+            self.state,
+            layout.bounds(),
+            self.tab_index,
+            self.id.clone(),
+        ));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        _layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        if !self.state.is_focused {
+            return false;
+        }
+
+        let key_code = match event {
+            keyboard::Event::KeyPressed { key_code, .. } => key_code,
+            _ => return false,
+        };
+
+        let visible_nodes = self.visible_nodes();
+        let current = visible_nodes
+            .iter()
+            .position(|(_, node)| self.selected.as_ref() == Some(&node.id));
+
+        match key_code {
+            keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                let next = match (current, key_code) {
+                    (Some(0), keyboard::KeyCode::Up) => None,
+                    (Some(current), keyboard::KeyCode::Up) => Some(current - 1),
+                    (Some(current), _) => Some(current + 1),
+                    (None, _) => Some(0),
+                };
+
+                if let Some((_, node)) =
+                    next.and_then(|next| visible_nodes.get(next))
+                {
+                    messages.push((self.on_select)(node.id.clone()));
+                }
+
+                true
+            }
+            keyboard::KeyCode::Right | keyboard::KeyCode::Left => {
+                let node = match current.map(|current| visible_nodes[current]) {
+                    Some((_, node)) if node.is_expandable => node,
+                    _ => return true,
+                };
+
+                if key_code == keyboard::KeyCode::Left {
+                    self.state.collapse(&node.id);
+                } else if !self.state.is_expanded(&node.id) {
+                    self.state.expand(node.id.clone());
+
+                    if let Some(on_expand) = &self.on_expand {
+                        messages.push(on_expand(node.id.clone()));
+                    }
+                }
+
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A visible row of a [`TreeView`], ready to be drawn.
+///
+/// [`TreeView`]: struct.TreeView.html
+#[derive(Debug, Clone, Copy)]
+pub struct Row<'a> {
+    /// The bounds of the row.
+    pub bounds: Rectangle,
+
+    /// The depth of the node of the row in the tree.
+    pub depth: usize,
+
+    /// The label of the node.
+    pub label: &'a str,
+
+    /// The icon of the node, if any.
+    pub icon: Option<char>,
+
+    /// Whether the node can be expanded or not.
+    pub is_expandable: bool,
+
+    /// Whether the node is expanded or not.
+    pub is_expanded: bool,
+
+    /// Whether the node is expanded while its children are not loaded yet.
+    pub is_loading: bool,
+
+    /// Whether the node is selected or not.
+    pub is_selected: bool,
+
+    /// Whether the row is hovered or not.
+    pub is_hovered: bool,
+}
+
+/// The renderer of a [`TreeView`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TreeView`] in your user interface.
+///
+/// [`TreeView`]: struct.TreeView.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The default padding of the rows of a [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    const DEFAULT_PADDING: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`TreeView`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`TreeView`]
+    /// - the visible [`Row`]s
+    /// - the indentation of every level of the tree
+    /// - the padding, the text size, the font, and the icon font of the rows
+    /// - the style of the [`TreeView`]
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    /// [`Row`]: struct.Row.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        rows: &[Row<'_>],
+        indent: u16,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        icon_font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, T, Message, Renderer> From<TreeView<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + Eq + Hash,
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        tree_view: TreeView<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tree_view)
+    }
+}
@@ -0,0 +1,445 @@
+//! Distribute content horizontally, wrapping it into new lines.
+use std::hash::Hash;
+
+use crate::{
+    focus, keyboard, layout, overlay, Align, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Size, Widget,
+};
+
+/// A container that distributes its contents horizontally, starting a new
+/// line whenever the current one is full.
+///
+/// A [`Wrap`] is useful to lay out tag clouds, toolbars or groups of buttons
+/// that need to adapt to the available width.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, Align, Text};
+/// #
+/// # pub type Wrap<'a, Message> = iced_native::Wrap<'a, Message, Null>;
+/// let tags = Wrap::<()>::new()
+///     .spacing(5)
+///     .line_spacing(5)
+///     .align_x(Align::Center)
+///     .push(Text::new("rust"))
+///     .push(Text::new("gui"))
+///     .push(Text::new("wgpu"));
+/// ```
+///
+/// [`Wrap`]: struct.Wrap.html
+#[allow(missing_debug_implementations)]
+pub struct Wrap<'a, Message, Renderer> {
+    spacing: u16,
+    line_spacing: u16,
+    padding: u16,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    align_x: Align,
+    align_items: Align,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Wrap<'a, Message, Renderer> {
+    /// Creates an empty [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Wrap`] with the given elements.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Wrap {
+            spacing: 0,
+            line_spacing: 0,
+            padding: 0,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            align_x: Align::Start,
+            align_items: Align::Start,
+            children,
+        }
+    }
+
+    /// Sets the horizontal spacing _between_ elements of a line.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ lines.
+    pub fn line_spacing(mut self, units: u16) -> Self {
+        self.line_spacing = units;
+        self
+    }
+
+    /// Sets the padding of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the width of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the horizontal alignment of every line of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn align_x(mut self, align: Align) -> Self {
+        self.align_x = align;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of every line of the
+    /// [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn align_items(mut self, align: Align) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Wrap`].
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Wrap<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Wrap<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let spacing = f32::from(self.spacing);
+        let line_spacing = f32::from(self.line_spacing);
+
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height)
+            .pad(padding);
+
+        let max = limits.max();
+        let child_limits = layout::Limits::new(Size::ZERO, max);
+
+        let mut nodes: Vec<layout::Node> = self
+            .children
+            .iter()
+            .map(|child| child.layout(renderer, &child_limits))
+            .collect();
+
+        // Break the children into lines that fit the available width
+        let mut lines = Vec::new();
+        let mut start = 0;
+        let mut line_width = 0.0;
+
+        for (i, node) in nodes.iter().enumerate() {
+            let width = node.size().width;
+
+            if i > start && line_width + spacing + width > max.width {
+                lines.push(start..i);
+
+                start = i;
+                line_width = width;
+            } else if i > start {
+                line_width += spacing + width;
+            } else {
+                line_width = width;
+            }
+        }
+
+        if start < nodes.len() {
+            lines.push(start..nodes.len());
+        }
+
+        let lines: Vec<_> = lines
+            .into_iter()
+            .map(|line| {
+                let nodes = &nodes[line.clone()];
+
+                let width =
+                    nodes.iter().map(|node| node.size().width).sum::<f32>()
+                        + spacing * (nodes.len() - 1) as f32;
+
+                let height = nodes
+                    .iter()
+                    .map(|node| node.size().height)
+                    .fold(0.0, f32::max);
+
+                (line, Size::new(width, height))
+            })
+            .collect();
+
+        let width =
+            lines.iter().map(|(_, size)| size.width).fold(0.0, f32::max);
+
+        let height = lines.iter().map(|(_, size)| size.height).sum::<f32>()
+            + line_spacing * lines.len().saturating_sub(1) as f32;
+
+        let size = limits.resolve(Size::new(width, height));
+
+        let mut y = padding;
+
+        for (line, line_size) in lines {
+            let mut x = padding
+                + match self.align_x {
+                    Align::Start => 0.0,
+                    Align::Center => (size.width - line_size.width) / 2.0,
+                    Align::End => size.width - line_size.width,
+                };
+
+            for node in &mut nodes[line] {
+                let width = node.size().width;
+
+                node.move_to(Point::new(x, y));
+                node.align(
+                    Align::Start,
+                    self.align_items,
+                    Size::new(0.0, line_size.height),
+                );
+
+                x += width + spacing;
+            }
+
+            y += line_size.height + line_spacing;
+        }
+
+        layout::Node::with_children(size.pad(padding), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.children.iter_mut().zip(layout.children()).for_each(
+            |(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            },
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(defaults, &self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.spacing.hash(state);
+        self.line_spacing.hash(state);
+        self.padding.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.align_x.hash(state);
+        self.align_items.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+
+    fn focus_targets<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        targets: &mut Vec<focus::Target<'b>>,
+    ) {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .for_each(|(child, layout)| child.focus_targets(layout, targets));
+    }
+
+    fn on_focused_event(
+        &mut self,
+        event: keyboard::Event,
+        layout: Layout<'_>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> bool {
+        self.children.iter_mut().zip(layout.children()).any(
+            |(child, layout)| {
+                child.on_focused_event(
+                    event, layout, messages, renderer, clipboard,
+                )
+            },
+        )
+    }
+}
+
+/// The renderer of a [`Wrap`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Wrap`] in your user interface.
+///
+/// [`Wrap`]: struct.Wrap.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Wrap`].
+    ///
+    /// It receives:
+    /// - the children of the [`Wrap`]
+    /// - the [`Layout`] of the [`Wrap`] and its children
+    /// - the cursor position
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Wrap<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        wrap: Wrap<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(wrap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Rectangle, Row};
+
+    #[test]
+    fn children_wrap_into_new_lines() {
+        let item = |width, height| {
+            Row::<(), Null>::new()
+                .width(Length::Units(width))
+                .height(Length::Units(height))
+        };
+
+        let wrap: Wrap<'_, (), Null> = Wrap::new()
+            .spacing(2)
+            .line_spacing(1)
+            .align_items(Align::End)
+            .push(item(10, 10))
+            .push(item(10, 5))
+            .push(item(10, 10));
+
+        let node = Widget::<(), Null>::layout(
+            &wrap,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(25.0, 100.0)),
+        );
+
+        let bounds: Vec<Rectangle> =
+            node.children().iter().map(layout::Node::bounds).collect();
+
+        assert_eq!(node.size(), Size::new(22.0, 21.0));
+        assert_eq!(bounds[1].x, 12.0);
+        assert_eq!(bounds[1].y, 5.0);
+        assert_eq!(bounds[2].x, 0.0);
+        assert_eq!(bounds[2].y, 11.0);
+    }
+}
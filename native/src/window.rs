@@ -1,4 +1,309 @@
 //! Build window-based GUI applications.
 mod event;
+mod events;
+mod files_dropped;
+mod frames;
 
 pub use event::Event;
+pub use iced_core::window::{
+    Action, Direction, Icon, IconError, Id, Monitor, Position, Settings,
+};
+
+use crate::{Command, Subscription};
+use events::Events;
+use files_dropped::FilesDropped;
+use frames::Frames;
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// Returns a [`Subscription`] that produces a message every time a frame of
+/// the window is drawn, carrying the [`Instant`] of the frame.
+///
+/// While subscribed, the runtime will keep drawing new frames as fast as the
+/// display allows. This is the recommended way to drive animations.
+///
+/// [`Subscription`]: ../subscription/type.Subscription.html
+/// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+pub fn frames() -> Subscription<Instant> {
+    Subscription::from_recipe(Frames)
+}
+
+/// Returns a [`Subscription`] that produces the path of every file dropped
+/// into the window.
+///
+/// When the user drops multiple files at once, a path is produced for each
+/// file separately.
+///
+/// [`Subscription`]: ../subscription/type.Subscription.html
+pub fn files_dropped() -> Subscription<PathBuf> {
+    Subscription::from_recipe(FilesDropped)
+}
+
+/// Returns a [`Subscription`] that produces the changes in the geometry and
+/// the focus of the window.
+///
+/// Only the [`Resized`], [`Moved`], [`Focused`], [`Unfocused`], and
+/// [`ScaleFactorChanged`] events are produced, which makes it easy to persist
+/// the geometry of the window or to pause animations while it is unfocused.
+///
+/// [`Subscription`]: ../subscription/type.Subscription.html
+/// [`Resized`]: enum.Event.html#variant.Resized
+/// [`Moved`]: enum.Event.html#variant.Moved
+/// [`Focused`]: enum.Event.html#variant.Focused
+/// [`Unfocused`]: enum.Event.html#variant.Unfocused
+/// [`ScaleFactorChanged`]: enum.Event.html#variant.ScaleFactorChanged
+pub fn events() -> Subscription<Event> {
+    Subscription::from_recipe(Events)
+}
+
+/// Returns a [`Command`] that queries the monitors connected to the system
+/// and produces a message with them.
+///
+/// The monitors can be used to place windows on a specific monitor.
+///
+/// [`Command`]: ../struct.Command.html
+pub fn fetch_monitors<Message>(
+    f: impl Fn(Vec<Monitor>) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::window(Action::FetchMonitors(Box::new(f)))
+}
+
+/// Returns a [`Command`] that shows or hides the mouse cursor while it is over
+/// the window with the given [`Id`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn set_cursor_visible<Message>(
+    id: Id,
+    is_visible: bool,
+) -> Command<Message> {
+    Command::window(Action::SetCursorVisible(id, is_visible))
+}
+
+/// Returns a [`Command`] that grabs or releases the mouse cursor in the window
+/// with the given [`Id`].
+///
+/// While grabbed, the cursor is confined to the window and the raw motion of
+/// the mouse is reported as a [`mouse::Event::RawMotion`]. Combined with
+/// [`set_cursor_visible`], this allows first-person cameras and infinite
+/// panning.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+/// [`mouse::Event::RawMotion`]: ../mouse/enum.Event.html#variant.RawMotion
+/// [`set_cursor_visible`]: fn.set_cursor_visible.html
+pub fn set_cursor_grab<Message>(id: Id, is_grabbed: bool) -> Command<Message> {
+    Command::window(Action::SetCursorGrab(id, is_grabbed))
+}
+
+/// Returns a [`Command`] that resizes the window with the given [`Id`] to the
+/// given logical size.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn resize<Message>(id: Id, width: u32, height: u32) -> Command<Message> {
+    Command::window(Action::Resize { id, width, height })
+}
+
+/// Returns a [`Command`] that moves the window with the given [`Id`] to the
+/// given logical position on the desktop.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn move_to<Message>(id: Id, x: i32, y: i32) -> Command<Message> {
+    Command::window(Action::Move { id, x, y })
+}
+
+/// Returns a [`Command`] that moves the window with the given [`Id`] to the
+/// center of its current monitor.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn center<Message>(id: Id) -> Command<Message> {
+    Command::window(Action::Center(id))
+}
+
+/// Returns a [`Command`] that starts moving the window with the given [`Id`]
+/// with the cursor, until the left mouse button is released.
+///
+/// Produce it right after the left mouse button is pressed over a custom title
+/// bar to let users move a window without decorations.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn drag<Message>(id: Id) -> Command<Message> {
+    Command::window(Action::Drag(id))
+}
+
+/// Returns a [`Command`] that starts resizing the window with the given [`Id`]
+/// from the given [`Direction`] with the cursor, until the left mouse button is
+/// released.
+///
+/// Use [`Direction::hit_test`] to find out if a mouse press happened over a
+/// resize border of the window.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+/// [`Direction`]: enum.Direction.html
+/// [`Direction::hit_test`]: enum.Direction.html#method.hit_test
+pub fn drag_resize<Message>(id: Id, direction: Direction) -> Command<Message> {
+    Command::window(Action::DragResize(id, direction))
+}
+
+/// Returns a [`Command`] that changes the minimum logical size of the window
+/// with the given [`Id`], if any.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn set_min_size<Message>(
+    id: Id,
+    min_size: Option<(u32, u32)>,
+) -> Command<Message> {
+    Command::window(Action::SetMinSize(id, min_size))
+}
+
+/// Returns a [`Command`] that changes the maximum logical size of the window
+/// with the given [`Id`], if any.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn set_max_size<Message>(
+    id: Id,
+    max_size: Option<(u32, u32)>,
+) -> Command<Message> {
+    Command::window(Action::SetMaxSize(id, max_size))
+}
+
+/// Returns a [`Command`] that makes the window with the given [`Id`]
+/// resizable or not.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn set_resizable<Message>(id: Id, is_resizable: bool) -> Command<Message> {
+    Command::window(Action::SetResizable(id, is_resizable))
+}
+
+/// Returns a [`Command`] that keeps the window with the given [`Id`] on top of
+/// other windows, or not.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn set_always_on_top<Message>(
+    id: Id,
+    is_always_on_top: bool,
+) -> Command<Message> {
+    Command::window(Action::SetAlwaysOnTop(id, is_always_on_top))
+}
+
+/// Returns a [`Command`] that minimizes or restores the window with the given [`Id`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn minimize<Message>(id: Id, is_minimized: bool) -> Command<Message> {
+    Command::window(Action::Minimize(id, is_minimized))
+}
+
+/// Returns a [`Command`] that maximizes or restores the window with the given [`Id`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn maximize<Message>(id: Id, is_maximized: bool) -> Command<Message> {
+    Command::window(Action::Maximize(id, is_maximized))
+}
+
+/// Returns a [`Command`] that makes the window with the given [`Id`] fill its
+/// current monitor without borders, or restores it.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn set_fullscreen<Message>(
+    id: Id,
+    is_fullscreen: bool,
+) -> Command<Message> {
+    Command::window(Action::SetFullscreen(id, is_fullscreen))
+}
+
+/// Returns a [`Command`] that shows or hides the decorations of the window
+/// with the given [`Id`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn set_decorations<Message>(
+    id: Id,
+    has_decorations: bool,
+) -> Command<Message> {
+    Command::window(Action::SetDecorations(id, has_decorations))
+}
+
+/// Returns a [`Command`] that scales the user interface of the window with the
+/// given [`Id`] by the given factor, on top of the scale factor of the system.
+///
+/// The user interface is laid out again and its text is rasterized at the new
+/// scale, which makes it a good fit to implement zooming.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn set_scale_factor<Message>(
+    id: Id,
+    scale_factor: f64,
+) -> Command<Message> {
+    Command::window(Action::SetScaleFactor(id, scale_factor))
+}
+
+/// Returns a [`Command`] that shows or hides the window with the given
+/// [`Id`].
+///
+/// Hiding the window keeps the application running in the background.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn set_visible<Message>(id: Id, is_visible: bool) -> Command<Message> {
+    Command::window(Action::SetVisible(id, is_visible))
+}
+
+/// Returns a [`Command`] that requests the attention of the user to the window
+/// with the given [`Id`].
+///
+/// It is only supported on macOS.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+pub fn request_attention<Message>(id: Id) -> Command<Message> {
+    Command::window(Action::RequestAttention(id))
+}
+
+/// Returns a [`Command`] that opens a new window with the given [`Settings`],
+/// along with the [`Id`] of the new window.
+///
+/// Only multi-window applications can open new windows. Otherwise, or if the
+/// window cannot be opened, a [`SpawnFailed`] event is produced.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Settings`]: struct.Settings.html
+/// [`Id`]: struct.Id.html
+/// [`SpawnFailed`]: enum.Event.html#variant.SpawnFailed
+pub fn spawn<Message>(settings: Settings) -> (Id, Command<Message>) {
+    let id = Id::unique();
+
+    (id, Command::window(Action::Spawn { id, settings }))
+}
+
+/// Returns a [`Command`] that closes the window with the given [`Id`].
+///
+/// Closing the last window of an application exits it. Single-window
+/// applications can close their window using [`Id::MAIN`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: struct.Id.html
+/// [`Id::MAIN`]: struct.Id.html#associatedconstant.MAIN
+pub fn close<Message>(id: Id) -> Command<Message> {
+    Command::window(Action::Close(id))
+}
+
+/// Returns a [`Command`] that exits the application, closing all of its
+/// windows.
+///
+/// [`Command`]: ../struct.Command.html
+pub fn exit<Message>() -> Command<Message> {
+    Command::window(Action::Exit)
+}
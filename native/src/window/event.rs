@@ -1,4 +1,8 @@
+use crate::system::Appearance;
+use crate::window::Id;
+
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
@@ -12,6 +16,42 @@ pub enum Event {
         height: u32,
     },
 
+    /// A window was moved
+    Moved {
+        /// The new logical X coordinate of the window
+        x: i32,
+
+        /// The new logical Y coordinate of the window
+        y: i32,
+    },
+
+    /// The user requested the window to be closed.
+    ///
+    /// It is only produced for windows that do not close on request.
+    CloseRequested,
+
+    /// The window was closed, either by the user or with [`close`].
+    ///
+    /// Once this event is produced, the window does not exist anymore.
+    ///
+    /// [`close`]: fn.close.html
+    Closed,
+
+    /// The window gained the focus.
+    Focused,
+
+    /// The window lost the focus.
+    Unfocused,
+
+    /// The scale factor of the window changed, for instance because it was
+    /// moved to a different monitor.
+    ScaleFactorChanged(f64),
+
+    /// The [`Appearance`] of the system was detected or changed.
+    ///
+    /// [`Appearance`]: ../system/enum.Appearance.html
+    AppearanceChanged(Appearance),
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
@@ -29,4 +69,23 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// A frame of the window was drawn at the given [`Instant`].
+    ///
+    /// This event can be listened to with [`frames`] in order to drive
+    /// animations. It is also delivered to the widgets of a user interface
+    /// while any of them keeps requesting new frames.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`frames`]: fn.frames.html
+    RedrawRequested(Instant),
+
+    /// The window with the given [`Id`] could not be opened by [`spawn`].
+    ///
+    /// It is always produced by applications that only support a single
+    /// window.
+    ///
+    /// [`Id`]: struct.Id.html
+    /// [`spawn`]: fn.spawn.html
+    SpawnFailed(Id),
 }
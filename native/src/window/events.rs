@@ -0,0 +1,39 @@
+use crate::{
+    subscription::{EventStream, Recipe},
+    window, Event, Hasher,
+};
+use iced_futures::futures::{future, StreamExt};
+use iced_futures::BoxStream;
+
+pub struct Events;
+
+impl Recipe<Hasher, Event> for Events {
+    type Output = window::Event;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<Self::Output> {
+        event_stream
+            .filter_map(|event| {
+                future::ready(match event {
+                    Event::Window(event) => match event {
+                        window::Event::Resized { .. }
+                        | window::Event::Moved { .. }
+                        | window::Event::Focused
+                        | window::Event::Unfocused
+                        | window::Event::ScaleFactorChanged(_) => Some(event),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+            })
+            .boxed()
+    }
+}
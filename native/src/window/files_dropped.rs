@@ -0,0 +1,35 @@
+use crate::{
+    subscription::{EventStream, Recipe},
+    window, Event, Hasher,
+};
+use iced_futures::futures::{future, StreamExt};
+use iced_futures::BoxStream;
+use std::path::PathBuf;
+
+pub struct FilesDropped;
+
+impl Recipe<Hasher, Event> for FilesDropped {
+    type Output = PathBuf;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<Self::Output> {
+        event_stream
+            .filter_map(|event| {
+                future::ready(match event {
+                    Event::Window(window::Event::FileDropped(path)) => {
+                        Some(path)
+                    }
+                    _ => None,
+                })
+            })
+            .boxed()
+    }
+}
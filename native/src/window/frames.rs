@@ -0,0 +1,35 @@
+use crate::{
+    subscription::{EventStream, Recipe},
+    window, Event, Hasher,
+};
+use iced_futures::futures::{future, StreamExt};
+use iced_futures::BoxStream;
+use std::time::Instant;
+
+pub struct Frames;
+
+impl Recipe<Hasher, Event> for Frames {
+    type Output = Instant;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<Self::Output> {
+        event_stream
+            .filter_map(|event| {
+                future::ready(match event {
+                    Event::Window(window::Event::RedrawRequested(instant)) => {
+                        Some(instant)
+                    }
+                    _ => None,
+                })
+            })
+            .boxed()
+    }
+}
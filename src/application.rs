@@ -1,4 +1,6 @@
-use crate::{window, Command, Element, Executor, Settings, Subscription};
+use crate::{
+    window, Command, Element, Executor, Settings, Subscription, Theme,
+};
 
 /// An interactive cross-platform application.
 ///
@@ -174,6 +176,36 @@ pub trait Application: Sized {
         window::Mode::Windowed
     }
 
+    /// Returns the current [`Theme`] of the [`Application`].
+    ///
+    /// The built-in widgets pick their default colors from the [`Palette`]
+    /// of the theme. Return a different theme to switch it while the
+    /// application is running.
+    ///
+    /// By default, it returns [`Theme::Light`].
+    ///
+    /// [`Theme`]: theme/enum.Theme.html
+    /// [`Palette`]: theme/struct.Palette.html
+    /// [`Theme::Light`]: theme/enum.Theme.html#variant.Light
+    /// [`Application`]: trait.Application.html
+    fn theme(&self) -> Theme {
+        Theme::Light
+    }
+
+    /// Returns whether the [`Application`] should exit.
+    ///
+    /// It is checked after every update, which allows exiting once the user
+    /// has been asked to save any unsaved changes.
+    ///
+    /// Currently, it only has an effect in native platforms.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn should_exit(&self) -> bool {
+        false
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -190,6 +222,7 @@ pub trait Application: Sized {
         {
             let renderer_settings = crate::renderer::Settings {
                 default_font: settings.default_font,
+                fallback_fonts: settings.fallback_fonts,
                 antialiasing: if settings.antialiasing {
                     Some(crate::renderer::settings::Antialiasing::MSAAx4)
                 } else {
@@ -253,6 +286,14 @@ where
         }
     }
 
+    fn theme(&self) -> Theme {
+        self.0.theme()
+    }
+
+    fn should_exit(&self) -> bool {
+        self.0.should_exit()
+    }
+
     fn subscription(&self) -> Subscription<Self::Message> {
         self.0.subscription()
     }
@@ -285,6 +326,10 @@ where
         self.0.subscription()
     }
 
+    fn theme(&self) -> Theme {
+        self.0.theme()
+    }
+
     fn view(&mut self) -> Element<'_, Self::Message> {
         self.0.view()
     }
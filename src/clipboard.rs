@@ -0,0 +1,4 @@
+//! Access the clipboard of the system.
+pub use crate::runtime::clipboard::{
+    read, read_as, write, write_as, Action, Flavor,
+};
@@ -0,0 +1,2 @@
+//! Load fonts at runtime.
+pub use crate::runtime::font::{load, load_from_path, Action, Error};
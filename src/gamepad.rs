@@ -0,0 +1,2 @@
+//! Listen to gamepad events.
+pub use crate::runtime::gamepad::{events, Axis, Button, Event, Id};
@@ -1,2 +1,5 @@
 //! Listen and react to keyboard events.
 pub use crate::runtime::keyboard::{Event, KeyCode, ModifiersState};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::keyboard::events;
@@ -183,10 +183,32 @@ mod application;
 mod element;
 mod sandbox;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod clipboard;
 pub mod executor;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod font;
+#[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "gamepad")))]
+pub mod gamepad;
 pub mod keyboard;
 pub mod mouse;
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "glow"),
+    feature = "wgpu"
+))]
+pub mod multi_window;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod notification;
 pub mod settings;
+pub mod stylus;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod system;
+pub mod touch;
+#[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "tray")))]
+pub mod tray;
 pub mod widget;
 pub mod window;
 
@@ -230,6 +252,8 @@ pub use sandbox::Sandbox;
 pub use settings::Settings;
 
 pub use runtime::{
-    futures, Align, Background, Color, Command, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Subscription, Vector, VerticalAlignment,
+    futures, theme, Align, Background, BorderRadius, BorderSide, Borders,
+    Color, Command, ContentFit, Font, Gradient, HorizontalAlignment, Length,
+    Point, Rectangle, Shadow, Size, Subscription, Theme, Vector,
+    VerticalAlignment,
 };
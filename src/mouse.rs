@@ -1,2 +1,4 @@
 //! Listen and react to mouse events.
-pub use crate::runtime::mouse::{Button, Event, Interaction, ScrollDelta};
+pub use crate::runtime::mouse::{
+    Button, DoubleClick, Event, Interaction, ScrollDelta,
+};
@@ -0,0 +1,195 @@
+//! Create applications with multiple windows.
+//!
+//! Only the `wgpu` renderer supports multiple windows.
+use crate::{
+    window, Command, Element, Executor, Settings, Subscription, Theme,
+};
+
+pub use crate::runtime::multi_window::Error;
+pub use crate::runtime::window::spawn;
+pub use crate::runtime::Event;
+
+/// An interactive cross-platform application with multiple windows.
+///
+/// An [`Application`] starts with a single window, identified by
+/// [`window::Id::MAIN`]. Additional windows can be opened with [`spawn`] and
+/// closed with [`window::close`]. Every window has its own title and view.
+///
+/// The application exits once all of its windows are closed.
+///
+/// [`Application`]: trait.Application.html
+/// [`window::Id::MAIN`]: ../window/struct.Id.html#associatedconstant.MAIN
+/// [`spawn`]: fn.spawn.html
+/// [`window::close`]: ../window/fn.close.html
+pub trait Application: Sized {
+    /// The [`Executor`] that will run commands and subscriptions.
+    ///
+    /// [`Executor`]: ../trait.Executor.html
+    type Executor: Executor;
+
+    /// The type of __messages__ your [`Application`] will produce.
+    ///
+    /// [`Application`]: trait.Application.html
+    type Message: std::fmt::Debug + Send;
+
+    /// The data needed to initialize your [`Application`].
+    ///
+    /// [`Application`]: trait.Application.html
+    type Flags;
+
+    /// Initializes the [`Application`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`run`]: #method.run
+    /// [`Settings`]: ../struct.Settings.html
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given [`window::Id`].
+    ///
+    /// [`window::Id`]: ../window/struct.Id.html
+    fn title(&self, window: window::Id) -> String;
+
+    /// Handles a __message__ and updates the state of the [`Application`].
+    ///
+    /// Any [`Command`] returned will be executed immediately in the
+    /// background.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Command`]: ../struct.Command.html
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+
+    /// Returns the event [`Subscription`] for the current state of the
+    /// application.
+    ///
+    /// Subscriptions receive the events of every window.
+    ///
+    /// By default, this method returns an empty [`Subscription`].
+    ///
+    /// [`Subscription`]: ../struct.Subscription.html
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the widgets to display in the window with the given
+    /// [`window::Id`].
+    ///
+    /// [`window::Id`]: ../window/struct.Id.html
+    fn view(&mut self, window: window::Id) -> Element<'_, Self::Message>;
+
+    /// Returns the current [`Theme`] of the [`Application`], shared by all of
+    /// its windows.
+    ///
+    /// By default, it returns [`Theme::Light`].
+    ///
+    /// [`Theme`]: ../theme/enum.Theme.html
+    /// [`Theme::Light`]: ../theme/enum.Theme.html#variant.Light
+    /// [`Application`]: trait.Application.html
+    fn theme(&self) -> Theme {
+        Theme::Light
+    }
+
+    /// Produces a message, if any, for an [`Event`] of the window with the
+    /// given [`window::Id`].
+    ///
+    /// By default, it produces no messages.
+    ///
+    /// [`Event`]: enum.Event.html
+    /// [`window::Id`]: ../window/struct.Id.html
+    fn on_event(
+        &self,
+        _window: window::Id,
+        _event: &Event,
+    ) -> Option<Self::Message> {
+        None
+    }
+
+    /// Returns whether the [`Application`] should exit.
+    ///
+    /// It is checked after every update. By default, it returns `false`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn should_exit(&self) -> bool {
+        false
+    }
+
+    /// Runs the [`Application`], opening its main window with the given
+    /// [`Settings`].
+    ///
+    /// Once the main window is open, this method takes control of the current
+    /// thread and __will NOT return__. Otherwise, an [`Error`] is returned.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Settings`]: ../struct.Settings.html
+    /// [`Error`]: enum.Error.html
+    fn run(settings: Settings<Self::Flags>) -> Result<(), Error>
+    where
+        Self: 'static,
+    {
+        let renderer_settings = crate::renderer::Settings {
+            default_font: settings.default_font,
+            fallback_fonts: settings.fallback_fonts,
+            antialiasing: if settings.antialiasing {
+                Some(crate::renderer::settings::Antialiasing::MSAAx4)
+            } else {
+                None
+            },
+            ..crate::renderer::Settings::default()
+        };
+
+        crate::runtime::multi_window::run::<
+            Instance<Self>,
+            Self::Executor,
+            crate::renderer::window::Compositor,
+        >(settings.into(), renderer_settings)
+    }
+}
+
+struct Instance<A: Application>(A);
+
+impl<A> crate::runtime::multi_window::Application for Instance<A>
+where
+    A: Application,
+{
+    type Renderer = crate::renderer::Renderer;
+    type Message = A::Message;
+    type Flags = A::Flags;
+
+    fn new(flags: Self::Flags) -> (Self, Command<A::Message>) {
+        let (app, command) = A::new(flags);
+
+        (Instance(app), command)
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        self.0.title(window)
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
+        self.0.update(message)
+    }
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        self.0.subscription()
+    }
+
+    fn theme(&self) -> Theme {
+        self.0.theme()
+    }
+
+    fn view(&mut self, window: window::Id) -> Element<'_, Self::Message> {
+        self.0.view(window)
+    }
+
+    fn on_event(
+        &self,
+        window: window::Id,
+        event: &Event,
+    ) -> Option<Self::Message> {
+        self.0.on_event(window, event)
+    }
+
+    fn should_exit(&self) -> bool {
+        self.0.should_exit()
+    }
+}
@@ -0,0 +1,2 @@
+//! Notify the user through the desktop.
+pub use crate::runtime::notification::{show, Error, Notification};
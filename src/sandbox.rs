@@ -1,4 +1,6 @@
-use crate::{executor, Application, Command, Element, Settings, Subscription};
+use crate::{
+    executor, Application, Command, Element, Settings, Subscription, Theme,
+};
 
 /// A sandboxed [`Application`].
 ///
@@ -124,6 +126,16 @@ pub trait Sandbox {
     /// [`Sandbox`]: trait.Sandbox.html
     fn view(&mut self) -> Element<'_, Self::Message>;
 
+    /// Returns the current [`Theme`] of the [`Sandbox`].
+    ///
+    /// By default, it returns the light theme.
+    ///
+    /// [`Theme`]: theme/enum.Theme.html
+    /// [`Sandbox`]: trait.Sandbox.html
+    fn theme(&self) -> Theme {
+        Theme::Light
+    }
+
     /// Runs the [`Sandbox`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -169,4 +181,8 @@ where
     fn view(&mut self) -> Element<'_, T::Message> {
         T::view(self)
     }
+
+    fn theme(&self) -> Theme {
+        T::theme(self)
+    }
 }
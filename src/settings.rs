@@ -1,8 +1,10 @@
 //! Configure your application.
-use crate::window;
+use crate::{mouse, window, Font};
+
+use std::borrow::Cow;
 
 /// The settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Settings<Flags> {
     /// The window settings.
     ///
@@ -22,6 +24,27 @@ pub struct Settings<Flags> {
     // TODO: Add `name` for web compatibility
     pub default_font: Option<&'static [u8]>,
 
+    /// The fonts to register when the application starts.
+    ///
+    /// They can be referenced by their family name with [`Font::Family`].
+    /// More fonts can be registered later with [`font::load`].
+    ///
+    /// They will be ignored on the Web.
+    ///
+    /// [`Font::Family`]: ../enum.Font.html#variant.Family
+    /// [`font::load`]: ../font/fn.load.html
+    pub fonts: Vec<Cow<'static, [u8]>>,
+
+    /// The fonts used to render the characters missing in a font, in order
+    /// of priority.
+    ///
+    /// The default font is tried after them. When the `default_system_font`
+    /// feature is enabled, the renderer also looks for system fonts able to
+    /// render the remaining characters.
+    ///
+    /// They will be ignored on the Web.
+    pub fallback_fonts: &'static [Font],
+
     /// If set to true, the renderer will try to perform antialiasing for some
     /// primitives.
     ///
@@ -32,6 +55,14 @@ pub struct Settings<Flags> {
     ///
     /// [`Canvas`]: ../widget/canvas/struct.Canvas.html
     pub antialiasing: bool,
+
+    /// The settings used to recognize double and triple clicks.
+    ///
+    /// If `None` is provided, the settings of the system will be used when
+    /// possible.
+    ///
+    /// They will be ignored on the Web.
+    pub double_click: Option<mouse::DoubleClick>,
 }
 
 impl<Flags> Settings<Flags> {
@@ -45,7 +76,10 @@ impl<Flags> Settings<Flags> {
             // allow initializing with flags without trait bound on Default
             antialiasing: Default::default(),
             default_font: Default::default(),
+            fonts: Default::default(),
+            fallback_fonts: Default::default(),
             window: Default::default(),
+            double_click: Default::default(),
         }
     }
 }
@@ -54,13 +88,10 @@ impl<Flags> Settings<Flags> {
 impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
     fn from(settings: Settings<Flags>) -> iced_winit::Settings<Flags> {
         iced_winit::Settings {
-            window: iced_winit::settings::Window {
-                size: settings.window.size,
-                resizable: settings.window.resizable,
-                decorations: settings.window.decorations,
-                platform_specific: Default::default(),
-            },
+            window: settings.window.into(),
             flags: settings.flags,
+            double_click: settings.double_click,
+            fonts: settings.fonts,
         }
     }
 }
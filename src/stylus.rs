@@ -0,0 +1,2 @@
+//! Listen and react to stylus events.
+pub use crate::runtime::stylus::{Event, Sample, Tool};
@@ -0,0 +1,2 @@
+//! Query the system the application runs on.
+pub use crate::runtime::system::{appearance, Appearance};
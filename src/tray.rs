@@ -0,0 +1,30 @@
+//! Show an icon in the system tray.
+//!
+//! A [`Tray`] is shown with [`show`] and removed with [`remove`]. Showing a
+//! [`Tray`] again replaces the current one, which keeps its icon in place.
+//!
+//! The system tray is supported on Windows, macOS, and on Linux and BSD
+//! desktops implementing the StatusNotifierItem specification. On other
+//! platforms, showing a [`Tray`] logs a warning.
+//!
+//! # Hiding to the tray
+//! An application can keep running in the tray after its window is closed by
+//! disabling [`close_on_request`] and hiding the window once its close is
+//! requested:
+//!
+//! ```ignore
+//! match message {
+//!     Message::EventOccurred(Event::Window(window::Event::CloseRequested)) => {
+//!         window::set_visible(window::Id::MAIN, false)
+//!     }
+//!     Message::TrayClicked => window::set_visible(window::Id::MAIN, true),
+//!     Message::Quit => window::exit(),
+//!     _ => Command::none(),
+//! }
+//! ```
+//!
+//! [`Tray`]: struct.Tray.html
+//! [`show`]: fn.show.html
+//! [`remove`]: fn.remove.html
+//! [`close_on_request`]: ../window/struct.Settings.html#structfield.close_on_request
+pub use crate::runtime::tray::{remove, show, Item, Tray};
@@ -19,10 +19,18 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, progress_bar, radio,
-        scrollable, slider, text_input, Column, Row, Space, Text,
+        autocomplete, button, checkbox, collapsible, container, context_menu,
+        date_picker, draggable, drop_target, file_drop, gauge, icon, knob,
+        lazy_list, menu_bar, modal, number_input, pane_grid, pick_list,
+        progress_bar, progress_circle, radio, range_slider, rich_text, rule,
+        scrollable, slider, spinner, table, tabs, tag_input, text_editor,
+        text_input, time_picker, toast, toggler, tooltip, tree_view,
+        AspectRatio, Column, FileDrop, Float, Grid, Responsive, Row, Shortcuts,
+        Space, Stack, Text, Wrap,
     };
 
+    pub use crate::runtime::{widget::focus, Id};
+
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
     #[cfg_attr(
         docsrs,
@@ -30,24 +38,54 @@ mod platform {
     )]
     pub use crate::renderer::widget::canvas;
 
+    #[cfg(any(feature = "chart", feature = "glow_chart"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "chart", feature = "glow_chart")))
+    )]
+    pub use crate::renderer::widget::chart;
+
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub mod image {
         //! Display images in your user interface.
-        pub use crate::runtime::image::{Handle, Image};
+        pub use crate::runtime::image::{
+            viewer, Handle, Image, Playback, Viewer,
+        };
+
+        #[cfg(feature = "image_url")]
+        pub use crate::runtime::image::remote;
+    }
+
+    pub mod responsive {
+        //! Build content depending on the available space.
+        pub use crate::runtime::responsive::State;
+    }
+
+    pub mod stack {
+        //! Layer content on top of each other.
+        pub use crate::runtime::stack::Anchor;
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
     pub mod svg {
         //! Display vector graphics in your user interface.
-        pub use crate::runtime::svg::{Handle, Svg};
+        pub use crate::runtime::svg::{Appearance, Handle, Svg};
     }
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
-        pane_grid::PaneGrid, progress_bar::ProgressBar, radio::Radio,
-        scrollable::Scrollable, slider::Slider, svg::Svg,
-        text_input::TextInput,
+        autocomplete::Autocomplete, button::Button, checkbox::Checkbox,
+        collapsible::Collapsible, container::Container,
+        context_menu::ContextMenu, date_picker::DatePicker, gauge::Gauge,
+        icon::Icon, image::Image, knob::Knob, lazy_list::LazyList,
+        menu_bar::MenuBar, modal::Modal, number_input::NumberInput,
+        pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
+        progress_circle::ProgressCircle, radio::Radio,
+        range_slider::RangeSlider, rich_text::RichText, rule::Rule,
+        scrollable::Scrollable, slider::Slider, spinner::Spinner, svg::Svg,
+        table::Table, tabs::Tabs, tag_input::TagInput, text_editor::TextEditor,
+        text_input::TextInput, time_picker::TimePicker, toast::Toast,
+        toggler::Toggler, tooltip::Tooltip, tree_view::TreeView,
     };
 
     #[cfg(feature = "canvas")]
@@ -1,6 +1,16 @@
 //! Configure the window of your application in native platforms.
 mod mode;
-mod settings;
 
+pub use iced_core::window::{
+    Direction, Icon, IconError, Monitor, Position, Settings,
+};
 pub use mode::Mode;
-pub use settings::Settings;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::window::{
+    center, close, drag, drag_resize, events, exit, fetch_monitors,
+    files_dropped, frames, maximize, minimize, move_to, request_attention,
+    resize, set_always_on_top, set_cursor_grab, set_cursor_visible,
+    set_decorations, set_fullscreen, set_max_size, set_min_size, set_resizable,
+    set_scale_factor, set_visible, Action, Id,
+};
@@ -1,5 +1,6 @@
 //! Allow your users to perform actions by pressing a button.
-use iced_core::{Background, Color, Vector};
+use crate::theme::Theme;
+use iced_core::{Background, Borders, Color, Gradient, Vector};
 
 /// The appearance of a button.
 #[derive(Debug)]
@@ -9,6 +10,7 @@ pub struct Style {
     pub border_radius: u16,
     pub border_width: u16,
     pub border_color: Color,
+    pub borders: Option<Borders>,
     pub text_color: Color,
 }
 
@@ -20,17 +22,18 @@ impl std::default::Default for Style {
             border_radius: 0,
             border_width: 0,
             border_color: Color::TRANSPARENT,
-            text_color: Color::BLACK,
+            borders: None,
+            text_color: Theme::Light.palette().text,
         }
     }
 }
 
 /// A set of rules that dictate the style of a button.
 pub trait StyleSheet {
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
-    fn hovered(&self) -> Style {
-        let active = self.active();
+    fn hovered(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             shadow_offset: active.shadow_offset + Vector::new(0.0, 1.0),
@@ -38,15 +41,19 @@ pub trait StyleSheet {
         }
     }
 
-    fn pressed(&self) -> Style {
+    fn pressed(&self, theme: &Theme) -> Style {
         Style {
             shadow_offset: Vector::default(),
-            ..self.active()
+            ..self.active(theme)
         }
     }
 
-    fn disabled(&self) -> Style {
-        let active = self.active();
+    fn focused(&self, theme: &Theme) -> Style {
+        self.hovered(theme)
+    }
+
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             shadow_offset: Vector::default(),
@@ -55,6 +62,19 @@ pub trait StyleSheet {
                     a: color.a * 0.5,
                     ..color
                 }),
+                Background::Gradient(gradient) => {
+                    Background::Gradient(Gradient {
+                        start: Color {
+                            a: gradient.start.a * 0.5,
+                            ..gradient.start
+                        },
+                        end: Color {
+                            a: gradient.end.a * 0.5,
+                            ..gradient.end
+                        },
+                        ..gradient
+                    })
+                }
             }),
             text_color: Color {
                 a: active.text_color.a * 0.5,
@@ -68,14 +88,17 @@ pub trait StyleSheet {
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
+        let palette = theme.palette();
+
         Style {
             shadow_offset: Vector::new(0.0, 0.0),
-            background: Some(Background::Color([0.87, 0.87, 0.87].into())),
+            background: Some(Background::Color(palette.shade(0.13))),
             border_radius: 2,
             border_width: 1,
-            border_color: [0.7, 0.7, 0.7].into(),
-            text_color: Color::BLACK,
+            border_color: palette.shade(0.3),
+            borders: None,
+            text_color: palette.text,
         }
     }
 }
@@ -0,0 +1,57 @@
+//! Plot data in line, bar, scatter, and pie charts.
+use iced_core::Color;
+
+/// The appearance of a chart.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub axis_color: Color,
+    pub grid_color: Color,
+    pub text_color: Color,
+    pub text_size: u16,
+    pub tooltip_background: Color,
+    pub tooltip_border_color: Color,
+    pub tooltip_text_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            axis_color: [0.4, 0.4, 0.4].into(),
+            grid_color: [0.9, 0.9, 0.9].into(),
+            text_color: [0.3, 0.3, 0.3].into(),
+            text_size: 14,
+            tooltip_background: Color::WHITE,
+            tooltip_border_color: [0.7, 0.7, 0.7].into(),
+            tooltip_text_color: Color::BLACK,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a chart.
+pub trait StyleSheet {
+    /// Produces the style of a chart.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
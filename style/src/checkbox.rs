@@ -1,5 +1,6 @@
 //! Show toggle controls using checkboxes.
-use iced_core::{Background, Color};
+use crate::theme::Theme;
+use iced_core::{Background, Color, Gradient};
 
 /// The appearance of a checkbox.
 #[derive(Debug)]
@@ -13,28 +14,65 @@ pub struct Style {
 
 /// A set of rules that dictate the style of a checkbox.
 pub trait StyleSheet {
-    fn active(&self, is_checked: bool) -> Style;
+    fn active(&self, theme: &Theme, is_checked: bool) -> Style;
 
-    fn hovered(&self, is_checked: bool) -> Style;
+    fn hovered(&self, theme: &Theme, is_checked: bool) -> Style;
+
+    fn disabled(&self, theme: &Theme, is_checked: bool) -> Style {
+        let active = self.active(theme, is_checked);
+
+        Style {
+            background: match active.background {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * 0.5,
+                    ..color
+                }),
+                Background::Gradient(gradient) => {
+                    Background::Gradient(Gradient {
+                        start: Color {
+                            a: gradient.start.a * 0.5,
+                            ..gradient.start
+                        },
+                        end: Color {
+                            a: gradient.end.a * 0.5,
+                            ..gradient.end
+                        },
+                        ..gradient
+                    })
+                }
+            },
+            checkmark_color: Color {
+                a: active.checkmark_color.a * 0.5,
+                ..active.checkmark_color
+            },
+            border_color: Color {
+                a: active.border_color.a * 0.5,
+                ..active.border_color
+            },
+            ..active
+        }
+    }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self, _is_checked: bool) -> Style {
+    fn active(&self, theme: &Theme, _is_checked: bool) -> Style {
+        let palette = theme.palette();
+
         Style {
-            background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
-            checkmark_color: Color::from_rgb(0.3, 0.3, 0.3),
+            background: Background::Color(palette.shade(0.05)),
+            checkmark_color: palette.shade(0.7),
             border_radius: 5,
             border_width: 1,
-            border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            border_color: palette.shade(0.4),
         }
     }
 
-    fn hovered(&self, is_checked: bool) -> Style {
+    fn hovered(&self, theme: &Theme, is_checked: bool) -> Style {
         Style {
-            background: Background::Color(Color::from_rgb(0.90, 0.90, 0.90)),
-            ..self.active(is_checked)
+            background: Background::Color(theme.palette().shade(0.1)),
+            ..self.active(theme, is_checked)
         }
     }
 }
@@ -0,0 +1,67 @@
+//! Show and hide content below a clickable header.
+use iced_core::{Background, Color};
+
+/// The appearance of a collapsible section.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub header_background: Background,
+    pub header_text_color: Color,
+    pub expander_color: Color,
+    pub background: Option<Background>,
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            header_background: Background::Color([0.95, 0.95, 0.95].into()),
+            header_text_color: Color::BLACK,
+            expander_color: [0.4, 0.4, 0.4].into(),
+            background: None,
+            border_radius: 0,
+            border_width: 1,
+            border_color: [0.85, 0.85, 0.85].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a collapsible section.
+pub trait StyleSheet {
+    /// Produces the style of a collapsible section.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a collapsible section with a hovered header.
+    fn hovered(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            header_background: Background::Color([0.9, 0.9, 0.9].into()),
+            ..active
+        }
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,14 +1,17 @@
 //! Decorate content and apply alignment.
-use iced_core::{Background, Color};
+use crate::theme::Theme;
+use iced_core::{Background, BorderRadius, Borders, Color, Shadow};
 
 /// The appearance of a container.
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
     pub text_color: Option<Color>,
     pub background: Option<Background>,
-    pub border_radius: u16,
+    pub border_radius: BorderRadius,
     pub border_width: u16,
     pub border_color: Color,
+    pub borders: Option<Borders>,
+    pub shadow: Option<Shadow>,
 }
 
 impl std::default::Default for Style {
@@ -16,9 +19,11 @@ impl std::default::Default for Style {
         Self {
             text_color: None,
             background: None,
-            border_radius: 0,
+            border_radius: BorderRadius::default(),
             border_width: 0,
             border_color: Color::TRANSPARENT,
+            borders: None,
+            shadow: None,
         }
     }
 }
@@ -26,19 +31,21 @@ impl std::default::Default for Style {
 /// A set of rules that dictate the style of a container.
 pub trait StyleSheet {
     /// Produces the style of a container.
-    fn style(&self) -> Style;
+    fn style(&self, theme: &Theme) -> Style;
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn style(&self) -> Style {
+    fn style(&self, _theme: &Theme) -> Style {
         Style {
             text_color: None,
             background: None,
-            border_radius: 0,
+            border_radius: BorderRadius::default(),
             border_width: 0,
             border_color: Color::TRANSPARENT,
+            borders: None,
+            shadow: None,
         }
     }
 }
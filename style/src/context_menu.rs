@@ -0,0 +1,38 @@
+//! Show a menu of actions when a widget is right clicked.
+pub use crate::menu::Style;
+use crate::theme::Theme;
+
+/// A set of rules that dictate the style of a context menu.
+pub trait StyleSheet {
+    /// Produces the style of the panels of a context menu.
+    fn style(&self, theme: &Theme) -> Style;
+}
+
+impl StyleSheet for Style {
+    fn style(&self, _theme: &Theme) -> Style {
+        *self
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self, theme: &Theme) -> Style {
+        Style::from(theme)
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
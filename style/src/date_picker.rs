@@ -0,0 +1,104 @@
+//! Let your users pick a date from a calendar.
+use iced_core::{Background, Color};
+
+/// The appearance of the field of a date picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub placeholder_color: Color,
+    pub background: Background,
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            placeholder_color: [0.6, 0.6, 0.6].into(),
+            background: Background::Color(Color::WHITE),
+            border_radius: 0,
+            border_width: 1,
+            border_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+/// The appearance of the calendar of a date picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Calendar {
+    pub text_color: Color,
+    pub weekday_color: Color,
+    pub outside_text_color: Color,
+    pub disabled_text_color: Color,
+    pub background: Background,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub hovered_background: Background,
+    pub selected_text_color: Color,
+    pub selected_background: Background,
+}
+
+impl std::default::Default for Calendar {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            weekday_color: [0.4, 0.4, 0.4].into(),
+            outside_text_color: [0.6, 0.6, 0.6].into(),
+            disabled_text_color: [0.8, 0.8, 0.8].into(),
+            background: Background::Color(Color::WHITE),
+            border_width: 1,
+            border_color: [0.7, 0.7, 0.7].into(),
+            hovered_background: Background::Color([0.92, 0.92, 0.92].into()),
+            selected_text_color: Color::WHITE,
+            selected_background: Background::Color([0.4, 0.4, 0.8].into()),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a date picker.
+pub trait StyleSheet {
+    /// Produces the style of the calendar of a date picker.
+    fn calendar(&self) -> Calendar;
+
+    /// Produces the style of an active date picker.
+    fn active(&self) -> Style;
+
+    /// Produces the style of an hovered date picker.
+    fn hovered(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn calendar(&self) -> Calendar {
+        Calendar::default()
+    }
+
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            border_color: Color::BLACK,
+            ..self.active()
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
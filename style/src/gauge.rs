@@ -0,0 +1,57 @@
+//! Display a value from a range of values on a radial dial.
+use iced_core::Color;
+
+/// The appearance of a gauge.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub track: Color,
+    pub bar: Color,
+    pub thickness: u16,
+    pub needle: Color,
+    pub tick_color: Color,
+    pub label_color: Color,
+    pub value_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            track: Color::from_rgb(0.85, 0.85, 0.85),
+            bar: Color::from_rgb(0.3, 0.5, 0.9),
+            thickness: 8,
+            needle: Color::from_rgb(0.8, 0.2, 0.2),
+            tick_color: Color::from_rgb(0.5, 0.5, 0.5),
+            label_color: Color::from_rgb(0.4, 0.4, 0.4),
+            value_color: Color::BLACK,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a gauge.
+pub trait StyleSheet {
+    /// Produces the style of a gauge.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,74 @@
+//! Display an interactive rotary selector of a single value from a range of
+//! values.
+use iced_core::Color;
+
+/// The appearance of a knob.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub track: Color,
+    pub bar: Color,
+    pub thickness: u16,
+    pub background: Color,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub indicator: Color,
+    pub tick_color: Color,
+}
+
+/// A set of rules that dictate the style of a knob.
+pub trait StyleSheet {
+    /// Produces the style of an active knob.
+    fn active(&self) -> Style;
+
+    /// Produces the style of an hovered knob.
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a knob that is being dragged.
+    fn dragging(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            track: Color::from_rgb(0.85, 0.85, 0.85),
+            bar: Color::from_rgb(0.3, 0.5, 0.9),
+            thickness: 4,
+            background: Color::from_rgb(0.95, 0.95, 0.95),
+            border_width: 1,
+            border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            indicator: Color::from_rgb(0.3, 0.3, 0.3),
+            tick_color: Color::from_rgb(0.6, 0.6, 0.6),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            background: Color::from_rgb(0.90, 0.90, 0.90),
+            ..self.active()
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        Style {
+            background: Color::from_rgb(0.85, 0.85, 0.85),
+            ..self.active()
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
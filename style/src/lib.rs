@@ -3,10 +3,31 @@
 //! It contains a set of styles and stylesheets for most of the built-in
 //! widgets.
 pub mod button;
+pub mod chart;
 pub mod checkbox;
+pub mod collapsible;
 pub mod container;
+pub mod context_menu;
+pub mod date_picker;
+pub mod gauge;
+pub mod knob;
+pub mod menu;
+pub mod menu_bar;
+pub mod modal;
+pub mod pick_list;
 pub mod progress_bar;
+pub mod progress_circle;
 pub mod radio;
+pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod spinner;
+pub mod table;
+pub mod tabs;
+pub mod tag_input;
 pub mod text_input;
+pub mod theme;
+pub mod toast;
+pub mod toggler;
+pub mod tooltip;
+pub mod tree_view;
@@ -0,0 +1,37 @@
+//! Build and show dropdown menus.
+use crate::theme::Theme;
+use iced_core::{Background, Color};
+
+/// The appearance of a menu.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub background: Background,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub selected_text_color: Color,
+    pub selected_background: Background,
+    pub disabled_text_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self::from(&Theme::Light)
+    }
+}
+
+impl From<&Theme> for Style {
+    fn from(theme: &Theme) -> Self {
+        let palette = theme.palette();
+
+        Self {
+            text_color: palette.text,
+            background: Background::Color(palette.shade(0.13)),
+            border_width: 1,
+            border_color: palette.shade(0.3),
+            selected_text_color: palette.background,
+            selected_background: Background::Color(palette.primary),
+            disabled_text_color: palette.shade(0.4),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Display a dropdown list of selectable values.
+use crate::menu;
+use crate::theme::Theme;
+use iced_core::{Background, Color};
+
+/// The appearance of a pick list.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub background: Background,
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub icon_size: f32,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self::from(&Theme::Light)
+    }
+}
+
+impl From<&Theme> for Style {
+    fn from(theme: &Theme) -> Self {
+        let palette = theme.palette();
+
+        Self {
+            text_color: palette.text,
+            background: Background::Color(palette.shade(0.13)),
+            border_radius: 0,
+            border_width: 1,
+            border_color: palette.shade(0.3),
+            icon_size: 0.7,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a pick list.
+pub trait StyleSheet {
+    /// Produces the style of the menu of a pick list.
+    ///
+    /// The menu takes the colors of the [`Theme`] in use when `None` is
+    /// returned.
+    ///
+    /// [`Theme`]: ../theme/enum.Theme.html
+    fn menu(&self) -> Option<menu::Style>;
+
+    /// Produces the style of an active pick list.
+    fn active(&self, theme: &Theme) -> Style;
+
+    /// Produces the style of an hovered pick list.
+    fn hovered(&self, theme: &Theme) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn menu(&self) -> Option<menu::Style> {
+        None
+    }
+
+    fn active(&self, theme: &Theme) -> Style {
+        Style::from(theme)
+    }
+
+    fn hovered(&self, theme: &Theme) -> Style {
+        Style {
+            border_color: theme.palette().text,
+            ..self.active(theme)
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,5 +1,6 @@
 //! Provide progress feedback to your users.
-use iced_core::{Background, Color};
+use crate::theme::Theme;
+use iced_core::Background;
 
 /// The appearance of a progress bar.
 #[derive(Debug)]
@@ -11,16 +12,18 @@ pub struct Style {
 
 /// A set of rules that dictate the style of a progress bar.
 pub trait StyleSheet {
-    fn style(&self) -> Style;
+    fn style(&self, theme: &Theme) -> Style;
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn style(&self) -> Style {
+    fn style(&self, theme: &Theme) -> Style {
+        let palette = theme.palette();
+
         Style {
-            background: Background::Color(Color::from_rgb(0.6, 0.6, 0.6)),
-            bar: Background::Color(Color::from_rgb(0.3, 0.9, 0.3)),
+            background: Background::Color(palette.shade(0.4)),
+            bar: Background::Color(palette.success),
             border_radius: 5,
         }
     }
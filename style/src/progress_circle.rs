@@ -0,0 +1,49 @@
+//! Provide circular progress feedback to your users.
+use iced_core::Color;
+
+/// The appearance of a progress circle.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub track: Color,
+    pub bar: Color,
+    pub thickness: u16,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            track: Color::from_rgb(0.85, 0.85, 0.85),
+            bar: Color::from_rgb(0.3, 0.9, 0.3),
+            thickness: 4,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a progress circle.
+pub trait StyleSheet {
+    /// Produces the style of a progress circle.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
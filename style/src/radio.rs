@@ -1,5 +1,6 @@
 //! Create choices using radio buttons.
-use iced_core::{Background, Color};
+use crate::theme::Theme;
+use iced_core::{Background, Color, Gradient};
 
 /// The appearance of a radio button.
 #[derive(Debug)]
@@ -12,27 +13,64 @@ pub struct Style {
 
 /// A set of rules that dictate the style of a radio button.
 pub trait StyleSheet {
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
-    fn hovered(&self) -> Style;
+    fn hovered(&self, theme: &Theme) -> Style;
+
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
+
+        Style {
+            background: match active.background {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * 0.5,
+                    ..color
+                }),
+                Background::Gradient(gradient) => {
+                    Background::Gradient(Gradient {
+                        start: Color {
+                            a: gradient.start.a * 0.5,
+                            ..gradient.start
+                        },
+                        end: Color {
+                            a: gradient.end.a * 0.5,
+                            ..gradient.end
+                        },
+                        ..gradient
+                    })
+                }
+            },
+            dot_color: Color {
+                a: active.dot_color.a * 0.5,
+                ..active.dot_color
+            },
+            border_color: Color {
+                a: active.border_color.a * 0.5,
+                ..active.border_color
+            },
+            ..active
+        }
+    }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
+        let palette = theme.palette();
+
         Style {
-            background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
-            dot_color: Color::from_rgb(0.3, 0.3, 0.3),
+            background: Background::Color(palette.shade(0.05)),
+            dot_color: palette.shade(0.7),
             border_width: 1,
-            border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            border_color: palette.shade(0.4),
         }
     }
 
-    fn hovered(&self) -> Style {
+    fn hovered(&self, theme: &Theme) -> Style {
         Style {
-            background: Background::Color(Color::from_rgb(0.90, 0.90, 0.90)),
-            ..self.active()
+            background: Background::Color(theme.palette().shade(0.1)),
+            ..self.active(theme)
         }
     }
 }
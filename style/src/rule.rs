@@ -0,0 +1,87 @@
+//! Display a horizontal or vertical rule for dividing content.
+use crate::theme::Theme;
+use iced_core::Color;
+
+/// The appearance of a rule.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The color of the rule
+    pub color: Color,
+    /// The thickness of the line of the rule
+    pub width: u16,
+    /// The border radius of the line of the rule
+    pub radius: u16,
+    /// How much of the length of the rule is covered by its line
+    pub fill_mode: FillMode,
+}
+
+/// The length of the line of a rule, along its direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FillMode {
+    /// Fill the whole length of the rule
+    Full,
+
+    /// Fill a percentage of the length of the rule, keeping the line centered
+    Percent(f32),
+
+    /// Leave the given amount of units empty at both ends of the rule
+    Padded(u16),
+}
+
+impl FillMode {
+    /// Returns the offset and the length of the line of a rule of the given
+    /// length.
+    pub fn fill(&self, length: f32) -> (f32, f32) {
+        match *self {
+            FillMode::Full => (0.0, length),
+            FillMode::Percent(percent) => {
+                let percent = percent.min(100.0);
+                let fill = length * percent.max(0.0) / 100.0;
+
+                ((length - fill) / 2.0, fill)
+            }
+            FillMode::Padded(padding) => {
+                let padding = f32::from(padding).min(length / 2.0);
+
+                (padding, length - padding * 2.0)
+            }
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a rule.
+pub trait StyleSheet {
+    /// Produces the style of a rule.
+    fn style(&self, theme: &Theme) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self, theme: &Theme) -> Style {
+        Style {
+            color: Color {
+                a: 0.51,
+                ..theme.palette().shade(0.4)
+            },
+            width: 1,
+            radius: 0,
+            fill_mode: FillMode::Full,
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
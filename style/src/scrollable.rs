@@ -1,9 +1,12 @@
 //! Navigate an endless amount of content with a scrollbar.
+use crate::theme::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a scrollable.
 #[derive(Debug, Clone, Copy)]
 pub struct Scrollbar {
+    pub width: u16,
+    pub margin: u16,
     pub background: Option<Background>,
     pub border_radius: u16,
     pub border_width: u16,
@@ -23,28 +26,36 @@ pub struct Scroller {
 /// A set of rules that dictate the style of a scrollable.
 pub trait StyleSheet {
     /// Produces the style of an active scrollbar.
-    fn active(&self) -> Scrollbar;
+    ///
+    /// Its `width` and `margin` are used to lay out the scrollbar in every
+    /// state.
+    fn active(&self, theme: &Theme) -> Scrollbar;
 
     /// Produces the style of an hovered scrollbar.
-    fn hovered(&self) -> Scrollbar;
+    fn hovered(&self, theme: &Theme) -> Scrollbar;
 
     /// Produces the style of a scrollbar that is being dragged.
-    fn dragging(&self) -> Scrollbar {
-        self.hovered()
+    fn dragging(&self, theme: &Theme) -> Scrollbar {
+        self.hovered(theme)
     }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Scrollbar {
+    fn active(&self, theme: &Theme) -> Scrollbar {
         Scrollbar {
+            width: 10,
+            margin: 2,
             background: None,
             border_radius: 5,
             border_width: 0,
             border_color: Color::TRANSPARENT,
             scroller: Scroller {
-                color: [0.0, 0.0, 0.0, 0.7].into(),
+                color: Color {
+                    a: 0.7,
+                    ..theme.palette().text
+                },
                 border_radius: 5,
                 border_width: 0,
                 border_color: Color::TRANSPARENT,
@@ -52,10 +63,13 @@ impl StyleSheet for Default {
         }
     }
 
-    fn hovered(&self) -> Scrollbar {
+    fn hovered(&self, theme: &Theme) -> Scrollbar {
         Scrollbar {
-            background: Some(Background::Color([0.0, 0.0, 0.0, 0.3].into())),
-            ..self.active()
+            background: Some(Background::Color(Color {
+                a: 0.3,
+                ..theme.palette().text
+            })),
+            ..self.active(theme)
         }
     }
 }
@@ -1,4 +1,5 @@
 //! Display an interactive selector of a single value from a range of values.
+use crate::theme::Theme;
 use iced_core::Color;
 
 /// The appearance of a slider.
@@ -27,51 +28,81 @@ pub enum HandleShape {
 /// A set of rules that dictate the style of a slider.
 pub trait StyleSheet {
     /// Produces the style of an active slider.
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
     /// Produces the style of an hovered slider.
-    fn hovered(&self) -> Style;
+    fn hovered(&self, theme: &Theme) -> Style;
 
     /// Produces the style of a slider that is being dragged.
-    fn dragging(&self) -> Style;
+    fn dragging(&self, theme: &Theme) -> Style;
+
+    /// Produces the style of a disabled slider.
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
+
+        let fade = |color: Color| Color {
+            a: color.a * 0.5,
+            ..color
+        };
+
+        Style {
+            rail_colors: (
+                fade(active.rail_colors.0),
+                fade(active.rail_colors.1),
+            ),
+            handle: Handle {
+                color: fade(active.handle.color),
+                border_color: fade(active.handle.border_color),
+                ..active.handle
+            },
+        }
+    }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
+        let palette = theme.palette();
+
         Style {
-            rail_colors: ([0.6, 0.6, 0.6, 0.5].into(), Color::WHITE),
+            rail_colors: (
+                Color {
+                    a: 0.5,
+                    ..palette.shade(0.4)
+                },
+                palette.background,
+            ),
             handle: Handle {
                 shape: HandleShape::Rectangle {
                     width: 8,
                     border_radius: 4,
                 },
-                color: Color::from_rgb(0.95, 0.95, 0.95),
-                border_color: Color::from_rgb(0.6, 0.6, 0.6),
+                color: palette.shade(0.05),
+                border_color: palette.shade(0.4),
                 border_width: 1,
             },
         }
     }
 
-    fn hovered(&self) -> Style {
-        let active = self.active();
+    fn hovered(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             handle: Handle {
-                color: Color::from_rgb(0.90, 0.90, 0.90),
+                color: theme.palette().shade(0.1),
                 ..active.handle
             },
             ..active
         }
     }
 
-    fn dragging(&self) -> Style {
-        let active = self.active();
+    fn dragging(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             handle: Handle {
-                color: Color::from_rgb(0.85, 0.85, 0.85),
+                color: theme.palette().shade(0.15),
                 ..active.handle
             },
             ..active
@@ -0,0 +1,49 @@
+//! Show an indeterminate loading indicator.
+use iced_core::Color;
+
+/// The appearance of a spinner.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub track: Option<Color>,
+    pub color: Color,
+    pub thickness: u16,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            track: None,
+            color: Color::from_rgb(0.5, 0.5, 0.5),
+            thickness: 3,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a spinner.
+pub trait StyleSheet {
+    /// Produces the style of a spinner.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,59 @@
+//! Display data in rows and columns with a fixed header.
+use iced_core::{Background, Color};
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub header_background: Background,
+    pub header_text_color: Color,
+    pub background: Option<Background>,
+    pub alternate_background: Option<Background>,
+    pub hovered_background: Background,
+    pub selected_background: Background,
+    pub separator_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            header_background: Background::Color([0.87, 0.87, 0.87].into()),
+            header_text_color: Color::BLACK,
+            background: None,
+            alternate_background: Some(Background::Color(
+                [0.97, 0.97, 0.97].into(),
+            )),
+            hovered_background: Background::Color([0.92, 0.92, 0.92].into()),
+            selected_background: Background::Color([0.8, 0.85, 0.95].into()),
+            separator_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    /// Produces the style of a table.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,61 @@
+//! Switch between different views with a strip of tabs.
+use iced_core::{Background, Color};
+
+/// The appearance of a tabs widget.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub strip_background: Background,
+    pub tab_background: Option<Background>,
+    pub active_tab_background: Background,
+    pub hovered_tab_background: Background,
+    pub text_color: Color,
+    pub active_text_color: Color,
+    pub border_width: u16,
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            strip_background: Background::Color([0.87, 0.87, 0.87].into()),
+            tab_background: None,
+            active_tab_background: Background::Color(Color::WHITE),
+            hovered_tab_background: Background::Color(
+                [0.92, 0.92, 0.92].into(),
+            ),
+            text_color: [0.4, 0.4, 0.4].into(),
+            active_text_color: Color::BLACK,
+            border_width: 1,
+            border_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a tabs widget.
+pub trait StyleSheet {
+    /// Produces the style of a tabs widget.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
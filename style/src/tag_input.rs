@@ -0,0 +1,94 @@
+//! Type entries that become removable tags.
+use iced_core::{Background, Color};
+
+/// The appearance of a tag input.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub tag_background: Background,
+    pub tag_border_radius: u16,
+    pub tag_text_color: Color,
+    pub remove_color: Color,
+    pub hovered_remove_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::WHITE),
+            border_radius: 5,
+            border_width: 1,
+            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            tag_background: Background::Color(Color::from_rgb(0.88, 0.9, 0.98)),
+            tag_border_radius: 4,
+            tag_text_color: Color::from_rgb(0.2, 0.2, 0.3),
+            remove_color: Color::from_rgb(0.5, 0.5, 0.6),
+            hovered_remove_color: Color::from_rgb(0.8, 0.2, 0.2),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a tag input.
+pub trait StyleSheet {
+    /// Produces the style of an active tag input.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a focused tag input.
+    fn focused(&self) -> Style;
+
+    fn placeholder_color(&self) -> Color;
+
+    fn value_color(&self) -> Color;
+
+    fn selection_color(&self) -> Color;
+
+    /// Produces the style of an hovered tag input.
+    fn hovered(&self) -> Style {
+        self.focused()
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn focused(&self) -> Style {
+        Style {
+            border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            ..self.active()
+        }
+    }
+
+    fn placeholder_color(&self) -> Color {
+        Color::from_rgb(0.7, 0.7, 0.7)
+    }
+
+    fn value_color(&self) -> Color {
+        Color::from_rgb(0.3, 0.3, 0.3)
+    }
+
+    fn selection_color(&self) -> Color {
+        Color::from_rgb(0.8, 0.8, 1.0)
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,5 +1,6 @@
 //! Display fields that can be filled with text.
-use iced_core::{Background, Color};
+use crate::theme::Theme;
+use iced_core::{Background, Color, Gradient};
 
 /// The appearance of a text input.
 #[derive(Debug, Clone, Copy)]
@@ -24,52 +25,91 @@ impl std::default::Default for Style {
 /// A set of rules that dictate the style of a text input.
 pub trait StyleSheet {
     /// Produces the style of an active text input.
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
     /// Produces the style of a focused text input.
-    fn focused(&self) -> Style;
+    fn focused(&self, theme: &Theme) -> Style;
 
-    fn placeholder_color(&self) -> Color;
+    fn placeholder_color(&self, theme: &Theme) -> Color;
 
-    fn value_color(&self) -> Color;
+    fn value_color(&self, theme: &Theme) -> Color;
 
-    fn selection_color(&self) -> Color;
+    fn selection_color(&self, theme: &Theme) -> Color;
 
     /// Produces the style of an hovered text input.
-    fn hovered(&self) -> Style {
-        self.focused()
+    fn hovered(&self, theme: &Theme) -> Style {
+        self.focused(theme)
+    }
+
+    /// Produces the style of a disabled text input.
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
+
+        Style {
+            background: match active.background {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * 0.5,
+                    ..color
+                }),
+                Background::Gradient(gradient) => {
+                    Background::Gradient(Gradient {
+                        start: Color {
+                            a: gradient.start.a * 0.5,
+                            ..gradient.start
+                        },
+                        end: Color {
+                            a: gradient.end.a * 0.5,
+                            ..gradient.end
+                        },
+                        ..gradient
+                    })
+                }
+            },
+            border_color: Color {
+                a: active.border_color.a * 0.5,
+                ..active.border_color
+            },
+            ..active
+        }
+    }
+
+    /// Produces the color of the value of a disabled text input.
+    fn disabled_color(&self, theme: &Theme) -> Color {
+        self.placeholder_color(theme)
     }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
+        let palette = theme.palette();
+
         Style {
-            background: Background::Color(Color::WHITE),
+            background: Background::Color(palette.background),
             border_radius: 5,
             border_width: 1,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            border_color: palette.shade(0.3),
         }
     }
 
-    fn focused(&self) -> Style {
+    fn focused(&self, theme: &Theme) -> Style {
         Style {
-            border_color: Color::from_rgb(0.5, 0.5, 0.5),
-            ..self.active()
+            border_color: theme.palette().shade(0.5),
+            ..self.active(theme)
         }
     }
 
-    fn placeholder_color(&self) -> Color {
-        Color::from_rgb(0.7, 0.7, 0.7)
+    fn placeholder_color(&self, theme: &Theme) -> Color {
+        theme.palette().shade(0.3)
     }
 
-    fn value_color(&self) -> Color {
-        Color::from_rgb(0.3, 0.3, 0.3)
+    fn value_color(&self, theme: &Theme) -> Color {
+        theme.palette().shade(0.7)
     }
 
-    fn selection_color(&self) -> Color {
-        Color::from_rgb(0.8, 0.8, 1.0)
+    fn selection_color(&self, theme: &Theme) -> Color {
+        theme.palette().highlight(1.0 / 3.0)
     }
 }
 
@@ -0,0 +1,139 @@
+//! Pick the colors of the built-in widgets with a theme.
+use iced_core::system::Appearance;
+use iced_core::Color;
+
+/// A set of colors that the built-in widgets use by default.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Theme {
+    /// A light theme, with dark text on a white background.
+    Light,
+
+    /// A dark theme, with light text on a dark background.
+    Dark,
+
+    /// A theme with a custom [`Palette`].
+    ///
+    /// [`Palette`]: struct.Palette.html
+    Custom(Palette),
+}
+
+impl Theme {
+    /// Returns the [`Palette`] of the [`Theme`].
+    ///
+    /// [`Palette`]: struct.Palette.html
+    /// [`Theme`]: enum.Theme.html
+    pub fn palette(&self) -> Palette {
+        match self {
+            Theme::Light => Palette::LIGHT,
+            Theme::Dark => Palette::DARK,
+            Theme::Custom(palette) => *palette,
+        }
+    }
+}
+
+impl From<Appearance> for Theme {
+    fn from(appearance: Appearance) -> Theme {
+        match appearance {
+            Appearance::Light => Theme::Light,
+            Appearance::Dark => Theme::Dark,
+        }
+    }
+}
+
+/// The colors of a [`Theme`].
+///
+/// The built-in widgets derive all of their default colors from it.
+///
+/// [`Theme`]: enum.Theme.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    /// The background color of windows.
+    pub background: Color,
+
+    /// The color of text.
+    pub text: Color,
+
+    /// The color of accents, like selections and active toggles.
+    pub primary: Color,
+
+    /// The color of positive feedback, like the bar of a progress bar.
+    pub success: Color,
+
+    /// The color of negative feedback, like errors.
+    pub danger: Color,
+}
+
+impl Palette {
+    /// The [`Palette`] of the light [`Theme`].
+    ///
+    /// [`Palette`]: struct.Palette.html
+    /// [`Theme`]: enum.Theme.html
+    pub const LIGHT: Palette = Palette {
+        background: Color::WHITE,
+        text: Color::BLACK,
+        primary: Color::from_rgb(0.4, 0.4, 1.0),
+        success: Color::from_rgb(0.3, 0.9, 0.3),
+        danger: Color::from_rgb(0.9, 0.3, 0.3),
+    };
+
+    /// The [`Palette`] of the dark [`Theme`].
+    ///
+    /// [`Palette`]: struct.Palette.html
+    /// [`Theme`]: enum.Theme.html
+    pub const DARK: Palette = Palette {
+        background: Color::from_rgb(0.13, 0.13, 0.15),
+        text: Color::from_rgb(0.92, 0.92, 0.92),
+        primary: Color::from_rgb(0.45, 0.5, 1.0),
+        success: Color::from_rgb(0.3, 0.8, 0.4),
+        danger: Color::from_rgb(0.95, 0.35, 0.35),
+    };
+
+    /// Returns a shade between the background color, for an `amount` of
+    /// `0.0`, and the text color, for an `amount` of `1.0`.
+    ///
+    /// Controls use faint shades as their background and stronger shades as
+    /// their borders.
+    pub fn shade(&self, amount: f32) -> Color {
+        mix(self.background, self.text, amount)
+    }
+
+    /// Returns a mix of the background color and the primary color, which
+    /// is fully primary for an `amount` of `1.0`.
+    pub fn highlight(&self, amount: f32) -> Color {
+        mix(self.background, self.primary, amount)
+    }
+}
+
+fn mix(a: Color, b: Color, amount: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * amount,
+        g: a.g + (b.g - a.g) * amount,
+        b: a.b + (b.b - a.b) * amount,
+        a: a.a + (b.a - a.a) * amount,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shade_mixes_background_and_text() {
+        let palette = Palette::LIGHT;
+
+        assert_eq!(palette.shade(0.0), palette.background);
+        assert_eq!(palette.shade(1.0), palette.text);
+        assert_eq!(palette.shade(0.5), Color::from_rgb(0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn default_style_sheets_follow_the_theme() {
+        let button = <Box<dyn crate::button::StyleSheet>>::default();
+
+        assert_eq!(
+            button.active(&Theme::Light).text_color,
+            Palette::LIGHT.text
+        );
+        assert_eq!(button.active(&Theme::Dark).text_color, Palette::DARK.text);
+    }
+}
@@ -0,0 +1,86 @@
+//! Show transient notifications on top of the user interface.
+use iced_core::{Background, Color};
+
+/// The appearance of a notification.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub accent: Color,
+    pub title_color: Color,
+    pub text_color: Color,
+    pub action_color: Color,
+    pub hovered_background: Background,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::WHITE),
+            border_radius: 4,
+            border_width: 1,
+            border_color: [0.8, 0.8, 0.8].into(),
+            accent: [0.3, 0.5, 0.9].into(),
+            title_color: Color::BLACK,
+            text_color: [0.3, 0.3, 0.3].into(),
+            action_color: [0.2, 0.4, 0.8].into(),
+            hovered_background: Background::Color([0.92, 0.92, 0.92].into()),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a notification, depending on its
+/// severity.
+pub trait StyleSheet {
+    /// Produces the style of an informative notification.
+    fn info(&self) -> Style;
+
+    /// Produces the style of a notification reporting a success.
+    fn success(&self) -> Style {
+        Style {
+            accent: [0.2, 0.7, 0.3].into(),
+            ..self.info()
+        }
+    }
+
+    /// Produces the style of a notification warning the user.
+    fn warning(&self) -> Style {
+        Style {
+            accent: [0.95, 0.65, 0.1].into(),
+            ..self.info()
+        }
+    }
+
+    /// Produces the style of a notification reporting an error.
+    fn error(&self) -> Style {
+        Style {
+            accent: [0.85, 0.2, 0.2].into(),
+            ..self.info()
+        }
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn info(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,88 @@
+//! Show toggle controls using togglers.
+use crate::theme::Theme;
+use iced_core::{Background, Color, Gradient};
+
+/// The appearance of a toggler.
+#[derive(Debug)]
+pub struct Style {
+    pub background: Background,
+    pub background_border: Option<Color>,
+    pub foreground: Background,
+    pub foreground_border: Option<Color>,
+}
+
+/// A set of rules that dictate the style of a toggler.
+pub trait StyleSheet {
+    fn active(&self, theme: &Theme, is_active: bool) -> Style;
+
+    fn hovered(&self, theme: &Theme, is_active: bool) -> Style;
+
+    fn disabled(&self, theme: &Theme, is_active: bool) -> Style {
+        let active = self.active(theme, is_active);
+
+        let fade = |background| match background {
+            Background::Color(color) => Background::Color(Color {
+                a: color.a * 0.5,
+                ..color
+            }),
+            Background::Gradient(gradient) => Background::Gradient(Gradient {
+                start: Color {
+                    a: gradient.start.a * 0.5,
+                    ..gradient.start
+                },
+                end: Color {
+                    a: gradient.end.a * 0.5,
+                    ..gradient.end
+                },
+                ..gradient
+            }),
+        };
+
+        Style {
+            background: fade(active.background),
+            foreground: fade(active.foreground),
+            ..active
+        }
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self, theme: &Theme, is_active: bool) -> Style {
+        let palette = theme.palette();
+
+        Style {
+            background: Background::Color(if is_active {
+                palette.primary
+            } else {
+                palette.shade(0.3)
+            }),
+            background_border: None,
+            foreground: Background::Color(palette.background),
+            foreground_border: None,
+        }
+    }
+
+    fn hovered(&self, theme: &Theme, is_active: bool) -> Style {
+        Style {
+            foreground: Background::Color(theme.palette().shade(0.05)),
+            ..self.active(theme, is_active)
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
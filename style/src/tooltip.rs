@@ -0,0 +1,62 @@
+//! Show a label next to a widget when it is hovered.
+use crate::theme::Theme;
+use iced_core::{Background, Color};
+
+/// The appearance of a tooltip.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub background: Background,
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self::from(&Theme::Light)
+    }
+}
+
+impl From<&Theme> for Style {
+    fn from(theme: &Theme) -> Self {
+        let palette = theme.palette();
+
+        Self {
+            text_color: palette.text,
+            background: Background::Color(palette.shade(0.05)),
+            border_radius: 3,
+            border_width: 1,
+            border_color: palette.shade(0.3),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a tooltip.
+pub trait StyleSheet {
+    /// Produces the style of a tooltip.
+    fn style(&self, theme: &Theme) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self, theme: &Theme) -> Style {
+        Style::from(theme)
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,57 @@
+//! Display hierarchical data with expandable nodes.
+use iced_core::{Background, Color};
+
+/// The appearance of a tree view.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub selected_text_color: Color,
+    pub selected_background: Background,
+    pub hovered_background: Option<Background>,
+    pub guide_color: Color,
+    pub expander_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            selected_text_color: Color::WHITE,
+            selected_background: Background::Color([0.4, 0.4, 0.8].into()),
+            hovered_background: Some(Background::Color(
+                [0.92, 0.92, 0.92].into(),
+            )),
+            guide_color: [0.85, 0.85, 0.85].into(),
+            expander_color: [0.4, 0.4, 0.4].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a tree view.
+pub trait StyleSheet {
+    /// Produces the style of a tree view.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
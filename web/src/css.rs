@@ -1,5 +1,8 @@
 //! Style your widgets.
-use crate::{bumpalo, Align, Background, Color, Length};
+use crate::{
+    bumpalo, Align, Background, BorderRadius, Borders, Color, Length, Shadow,
+    Theme,
+};
 
 use std::collections::BTreeMap;
 
@@ -78,18 +81,28 @@ impl Rule {
 #[derive(Debug)]
 pub struct Css<'a> {
     rules: BTreeMap<String, &'a str>,
+    theme: Theme,
 }
 
 impl<'a> Css<'a> {
-    /// Creates an empty style [`Sheet`].
+    /// Creates an empty style [`Sheet`] for the given [`Theme`].
     ///
     /// [`Sheet`]: struct.Sheet.html
-    pub fn new() -> Self {
+    /// [`Theme`]: ../theme/enum.Theme.html
+    pub fn new(theme: Theme) -> Self {
         Css {
             rules: BTreeMap::new(),
+            theme,
         }
     }
 
+    /// Returns the [`Theme`] the widgets are styled with.
+    ///
+    /// [`Theme`]: ../theme/enum.Theme.html
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
     /// Inserts the [`rule`] in the [`Sheet`], if it was not previously
     /// inserted.
     ///
@@ -140,6 +153,12 @@ pub fn length(length: Length) -> String {
         Length::Shrink => String::from("auto"),
         Length::Units(px) => format!("{}px", px),
         Length::Fill | Length::FillPortion(_) => String::from("100%"),
+        Length::Percent(percent) => format!("{}%", percent),
+        Length::Clamped {
+            min,
+            max,
+            preferred,
+        } => format!("clamp({}px, {}%, {}px)", min, preferred, max),
     }
 }
 
@@ -176,6 +195,59 @@ pub fn color(Color { r, g, b, a }: Color) -> String {
 pub fn background(background: Background) -> String {
     match background {
         Background::Color(c) => color(c),
+        Background::Gradient(gradient) => format!(
+            "linear-gradient({}deg, {}, {})",
+            gradient.angle.to_degrees() + 90.0,
+            color(gradient.start),
+            color(gradient.end)
+        ),
+    }
+}
+
+/// Returns the style value for the given [`BorderRadius`].
+///
+/// [`BorderRadius`]: ../struct.BorderRadius.html
+pub fn border_radius(border_radius: BorderRadius) -> String {
+    format!(
+        "{}px {}px {}px {}px",
+        border_radius.top_left,
+        border_radius.top_right,
+        border_radius.bottom_right,
+        border_radius.bottom_left
+    )
+}
+
+/// Returns the style declarations of the given [`Borders`].
+///
+/// [`Borders`]: ../struct.Borders.html
+pub fn borders(borders: Borders) -> String {
+    format!(
+        "border-style: solid; border-width: {}px {}px {}px {}px; \
+         border-color: {} {} {} {}",
+        borders.top.width,
+        borders.right.width,
+        borders.bottom.width,
+        borders.left.width,
+        color(borders.top.color),
+        color(borders.right.color),
+        color(borders.bottom.color),
+        color(borders.left.color)
+    )
+}
+
+/// Returns the style value for the given [`Shadow`], if any.
+///
+/// [`Shadow`]: ../struct.Shadow.html
+pub fn shadow(shadow: Option<Shadow>) -> String {
+    match shadow {
+        Some(shadow) => format!(
+            "{}px {}px {}px {}",
+            shadow.offset.x,
+            shadow.offset.y,
+            shadow.blur_radius,
+            color(shadow.color)
+        ),
+        None => String::from("none"),
     }
 }
 
@@ -74,10 +74,13 @@ pub use dodrio;
 pub use element::Element;
 pub use hasher::Hasher;
 pub use iced_core::{
-    keyboard, mouse, Align, Background, Color, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Vector, VerticalAlignment,
+    keyboard, mouse, stylus, touch, Align, Background, BorderRadius,
+    BorderSide, Borders, Color, ContentFit, Font, Gradient,
+    HorizontalAlignment, Length, Point, Rectangle, Shadow, Size, Vector,
+    VerticalAlignment,
 };
 pub use iced_futures::{executor, futures, Command};
+pub use iced_style::theme::{self, Theme};
 pub use subscription::Subscription;
 
 #[doc(no_inline)]
@@ -168,6 +171,19 @@ pub trait Application {
         Subscription::none()
     }
 
+    /// Returns the [`Theme`] of the [`Application`].
+    ///
+    /// The built-in widgets take their default colors from its [`Palette`].
+    ///
+    /// By default, it returns the light theme.
+    ///
+    /// [`Theme`]: theme/enum.Theme.html
+    /// [`Palette`]: theme/struct.Palette.html
+    /// [`Application`]: trait.Application.html
+    fn theme(&self) -> Theme {
+        Theme::Light
+    }
+
     /// Runs the [`Application`].
     ///
     /// [`Application`]: trait.Application.html
@@ -252,8 +268,8 @@ where
         use dodrio::builder::*;
 
         let mut ui = self.application.borrow_mut();
+        let mut css = Css::new(ui.theme());
         let element = ui.view();
-        let mut css = Css::new();
 
         let node = element.widget.node(bump, &self.bus, &mut css);
 
@@ -11,31 +11,35 @@ use std::rc::Rc;
 /// # Example
 ///
 /// ```
-/// # use iced_web::Checkbox;
+/// # use iced_web::{checkbox, Checkbox};
 ///
 /// pub enum Message {
 ///     CheckboxToggled(bool),
 /// }
 ///
+/// let mut state = checkbox::State::new();
 /// let is_checked = true;
 ///
-/// Checkbox::new(is_checked, "Toggle me!", Message::CheckboxToggled);
+/// Checkbox::new(&mut state, is_checked, "Toggle me!", Message::CheckboxToggled);
 /// ```
 ///
 /// ![Checkbox drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/checkbox.png?raw=true)
 #[allow(missing_debug_implementations)]
-pub struct Checkbox<Message> {
+pub struct Checkbox<'a, Message> {
+    _state: &'a mut State,
     is_checked: bool,
+    is_enabled: bool,
     on_toggle: Rc<dyn Fn(bool) -> Message>,
     label: String,
     width: Length,
     style: Box<dyn StyleSheet>,
 }
 
-impl<Message> Checkbox<Message> {
+impl<'a, Message> Checkbox<'a, Message> {
     /// Creates a new [`Checkbox`].
     ///
     /// It expects:
+    ///   * the local [`State`] of the [`Checkbox`]
     ///   * a boolean describing whether the [`Checkbox`] is checked or not
     ///   * the label of the [`Checkbox`]
     ///   * a function that will be called when the [`Checkbox`] is toggled. It
@@ -43,12 +47,20 @@ impl<Message> Checkbox<Message> {
     ///     `Message`.
     ///
     /// [`Checkbox`]: struct.Checkbox.html
-    pub fn new<F>(is_checked: bool, label: impl Into<String>, f: F) -> Self
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        is_checked: bool,
+        label: impl Into<String>,
+        f: F,
+    ) -> Self
     where
         F: 'static + Fn(bool) -> Message,
     {
         Checkbox {
+            _state: state,
             is_checked,
+            is_enabled: true,
             on_toggle: Rc::new(f),
             label: label.into(),
             width: Length::Shrink,
@@ -64,6 +76,14 @@ impl<Message> Checkbox<Message> {
         self
     }
 
+    /// Disables the [`Checkbox`], which is then shown but cannot be toggled.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn disabled(mut self) -> Self {
+        self.is_enabled = false;
+        self
+    }
+
     /// Sets the style of the [`Checkbox`].
     ///
     /// [`Checkbox`]: struct.Checkbox.html
@@ -73,7 +93,22 @@ impl<Message> Checkbox<Message> {
     }
 }
 
-impl<Message> Widget<Message> for Checkbox<Message>
+/// The local state of a [`Checkbox`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl<'a, Message> Widget<Message> for Checkbox<'a, Message>
 where
     Message: 'static,
 {
@@ -111,6 +146,7 @@ where
                 input(bump)
                     .attr("type", "checkbox")
                     .bool_attr("checked", self.is_checked)
+                    .bool_attr("disabled", !self.is_enabled)
                     .on("click", move |_root, vdom, _event| {
                         let msg = on_toggle(!is_checked);
                         event_bus.publish(msg);
@@ -125,11 +161,11 @@ where
     }
 }
 
-impl<'a, Message> From<Checkbox<Message>> for Element<'a, Message>
+impl<'a, Message> From<Checkbox<'a, Message>> for Element<'a, Message>
 where
     Message: 'static,
 {
-    fn from(checkbox: Checkbox<Message>) -> Element<'a, Message> {
+    fn from(checkbox: Checkbox<'a, Message>) -> Element<'a, Message> {
         Element::new(checkbox)
     }
 }
@@ -1,5 +1,5 @@
 //! Decorate content and apply alignment.
-use crate::{bumpalo, css, Align, Bus, Css, Element, Length, Widget};
+use crate::{bumpalo, css, Align, Borders, Bus, Css, Element, Length, Widget};
 
 pub use iced_style::container::{Style, StyleSheet};
 
@@ -137,7 +137,7 @@ where
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}px; border-color: {}; border-radius: {}px",
+                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; {}; border-radius: {}; box-shadow: {}",
                     css::length(self.width),
                     css::length(self.height),
                     css::max_length(self.max_width),
@@ -145,9 +145,11 @@ where
                     css::align(self.vertical_alignment),
                     style.background.map(css::background).unwrap_or(String::from("initial")),
                     style.text_color.map(css::color).unwrap_or(String::from("inherit")),
-                    style.border_width,
-                    css::color(style.border_color),
-                    style.border_radius
+                    css::borders(style.borders.unwrap_or_else(|| {
+                        Borders::uniform(style.border_width, style.border_color)
+                    })),
+                    css::border_radius(style.border_radius),
+                    css::shadow(style.shadow)
                 )
                 .into_bump_str(),
             )
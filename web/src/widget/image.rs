@@ -1,5 +1,5 @@
 //! Display images in your user interface.
-use crate::{Bus, Css, Element, Hasher, Length, Widget};
+use crate::{css, Bus, Css, Element, Hasher, Length, Widget};
 
 use dodrio::bumpalo;
 use std::{
@@ -84,6 +84,15 @@ impl<Message> Widget<Message> for Image {
                     bumpalo::format!(in bump, "{}px", px).into_bump_str(),
                 );
             }
+            Length::Percent(_) | Length::Clamped { .. } => {
+                let width = css::length(self.width);
+
+                image = image.attr(
+                    "style",
+                    bumpalo::format!(in bump, "width: {}", width)
+                        .into_bump_str(),
+                );
+            }
         }
 
         // TODO: Complete styling
@@ -9,7 +9,7 @@ use dodrio::bumpalo;
 ///
 /// # Example
 /// ```
-/// # use iced_web::Radio;
+/// # use iced_web::{radio, Radio};
 ///
 /// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// pub enum Choice {
@@ -22,26 +22,31 @@ use dodrio::bumpalo;
 ///     RadioSelected(Choice),
 /// }
 ///
+/// let mut a = radio::State::new();
+/// let mut b = radio::State::new();
 /// let selected_choice = Some(Choice::A);
 ///
-/// Radio::new(Choice::A, "This is A", selected_choice, Message::RadioSelected);
+/// Radio::new(&mut a, Choice::A, "This is A", selected_choice, Message::RadioSelected);
 ///
-/// Radio::new(Choice::B, "This is B", selected_choice, Message::RadioSelected);
+/// Radio::new(&mut b, Choice::B, "This is B", selected_choice, Message::RadioSelected);
 /// ```
 ///
 /// ![Radio buttons drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/radio.png?raw=true)
 #[allow(missing_debug_implementations)]
-pub struct Radio<Message> {
+pub struct Radio<'a, Message> {
+    _state: &'a mut State,
     is_selected: bool,
+    is_enabled: bool,
     on_click: Message,
     label: String,
     style: Box<dyn StyleSheet>,
 }
 
-impl<Message> Radio<Message> {
+impl<'a, Message> Radio<'a, Message> {
     /// Creates a new [`Radio`] button.
     ///
     /// It expects:
+    ///   * the local [`State`] of the [`Radio`] button
     ///   * the value related to the [`Radio`] button
     ///   * the label of the [`Radio`] button
     ///   * the current selected value
@@ -49,7 +54,9 @@ impl<Message> Radio<Message> {
     ///   receives the value of the radio and must produce a `Message`.
     ///
     /// [`Radio`]: struct.Radio.html
+    /// [`State`]: struct.State.html
     pub fn new<F, V>(
+        state: &'a mut State,
         value: V,
         label: impl Into<String>,
         selected: Option<V>,
@@ -60,13 +67,24 @@ impl<Message> Radio<Message> {
         F: 'static + Fn(V) -> Message,
     {
         Radio {
+            _state: state,
             is_selected: Some(value) == selected,
+            is_enabled: true,
             on_click: f(value),
             label: label.into(),
             style: Default::default(),
         }
     }
 
+    /// Disables the [`Radio`] button, which is then shown but cannot be
+    /// selected.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn disabled(mut self) -> Self {
+        self.is_enabled = false;
+        self
+    }
+
     /// Sets the style of the [`Radio`] button.
     ///
     /// [`Radio`]: struct.Radio.html
@@ -76,7 +94,22 @@ impl<Message> Radio<Message> {
     }
 }
 
-impl<Message> Widget<Message> for Radio<Message>
+/// The local state of a [`Radio`].
+///
+/// [`Radio`]: struct.Radio.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl<'a, Message> Widget<Message> for Radio<'a, Message>
 where
     Message: 'static + Clone,
 {
@@ -101,6 +134,7 @@ where
                     .attr("type", "radio")
                     .attr("style", "margin-right: 10px")
                     .bool_attr("checked", self.is_selected)
+                    .bool_attr("disabled", !self.is_enabled)
                     .on("click", move |_root, _vdom, _event| {
                         event_bus.publish(on_click.clone());
                     })
@@ -111,11 +145,11 @@ where
     }
 }
 
-impl<'a, Message> From<Radio<Message>> for Element<'a, Message>
+impl<'a, Message> From<Radio<'a, Message>> for Element<'a, Message>
 where
     Message: 'static + Clone,
 {
-    fn from(radio: Radio<Message>) -> Element<'a, Message> {
+    fn from(radio: Radio<'a, Message>) -> Element<'a, Message> {
         Element::new(radio)
     }
 }
@@ -39,6 +39,7 @@ pub struct Slider<'a, Message> {
     range: RangeInclusive<f32>,
     value: f32,
     on_change: Rc<Box<dyn Fn(f32) -> Message>>,
+    is_enabled: bool,
     width: Length,
     style: Box<dyn StyleSheet>,
 }
@@ -70,6 +71,7 @@ impl<'a, Message> Slider<'a, Message> {
             value: value.max(*range.start()).min(*range.end()),
             range,
             on_change: Rc::new(Box::new(on_change)),
+            is_enabled: true,
             width: Length::Fill,
             style: Default::default(),
         }
@@ -83,6 +85,14 @@ impl<'a, Message> Slider<'a, Message> {
         self
     }
 
+    /// Disables the [`Slider`], which is then shown but cannot be dragged.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn disabled(mut self) -> Self {
+        self.is_enabled = false;
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
@@ -123,6 +133,7 @@ where
             .attr("max", max.into_bump_str())
             .attr("value", value.into_bump_str())
             .attr("style", "width: 100%")
+            .bool_attr("disabled", !self.is_enabled)
             .on("input", move |_root, _vdom, event| {
                 let slider = match event.target().and_then(|t| {
                     t.dyn_into::<web_sys::HtmlInputElement>().ok()
@@ -141,7 +141,7 @@ impl<'a, Message> Widget<Message> for Text {
             text_align,
             match self.font {
                 Font::Default => "inherit",
-                Font::External { name, .. } => name,
+                Font::External { name, .. } | Font::Family(name) => name,
             }
         );
 
@@ -36,6 +36,7 @@ pub struct TextInput<'a, Message> {
     placeholder: String,
     value: String,
     is_secure: bool,
+    is_enabled: bool,
     width: Length,
     max_width: u32,
     padding: u16,
@@ -70,6 +71,7 @@ impl<'a, Message> TextInput<'a, Message> {
             placeholder: String::from(placeholder),
             value: String::from(value),
             is_secure: false,
+            is_enabled: true,
             width: Length::Fill,
             max_width: u32::MAX,
             padding: 0,
@@ -129,6 +131,15 @@ impl<'a, Message> TextInput<'a, Message> {
         self
     }
 
+    /// Disables the [`TextInput`], which is then shown but cannot be focused
+    /// nor edited.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn disabled(mut self) -> Self {
+        self.is_enabled = false;
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
@@ -158,7 +169,7 @@ where
         let on_submit = self.on_submit.clone();
         let input_event_bus = bus.clone();
         let submit_event_bus = bus.clone();
-        let style = self.style_sheet.active();
+        let style = self.style_sheet.active(style_sheet.theme());
 
         input(bump)
             .attr(
@@ -177,7 +188,7 @@ where
                     style.border_width,
                     css::color(style.border_color),
                     style.border_radius,
-                    css::color(self.style_sheet.value_color())
+                    css::color(self.style_sheet.value_color(style_sheet.theme()))
                 )
                 .into_bump_str(),
             )
@@ -194,6 +205,7 @@ where
                 "type",
                 bumpalo::format!(in bump, "{}", if self.is_secure { "password" } else { "text" }).into_bump_str(),
             )
+            .bool_attr("disabled", !self.is_enabled)
             .on("input", move |_root, _vdom, event| {
                 let text_input = match event.target().and_then(|t| {
                     t.dyn_into::<web_sys::HtmlInputElement>().ok()
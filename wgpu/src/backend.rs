@@ -7,8 +7,11 @@ use iced_graphics::font;
 use iced_graphics::layer::Layer;
 use iced_graphics::{Primitive, Viewport};
 use iced_native::mouse;
+use iced_native::rich_text::{Fragment, Run};
 use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
 
+use std::borrow::Cow;
+
 #[cfg(any(feature = "image", feature = "svg"))]
 use crate::image;
 
@@ -31,8 +34,12 @@ impl Backend {
     ///
     /// [`Backend`]: struct.Backend.html
     pub fn new(device: &wgpu::Device, settings: Settings) -> Self {
-        let text_pipeline =
-            text::Pipeline::new(device, settings.format, settings.default_font);
+        let text_pipeline = text::Pipeline::new(
+            device,
+            settings.format,
+            settings.default_font,
+            settings.fallback_fonts,
+        );
         let quad_pipeline = quad::Pipeline::new(device, settings.format);
         let triangle_pipeline = triangle::Pipeline::new(
             device,
@@ -88,6 +95,8 @@ impl Backend {
             );
         }
 
+        self.triangle_pipeline.trim_cache();
+
         #[cfg(any(feature = "image", feature = "svg"))]
         self.image_pipeline.trim_cache();
 
@@ -119,7 +128,7 @@ impl Backend {
             );
         }
 
-        if !layer.meshes.is_empty() {
+        if !layer.meshes.is_empty() || !layer.textured_meshes.is_empty() {
             let scaled = transformation
                 * Transformation::scale(scale_factor, scale_factor);
 
@@ -132,6 +141,7 @@ impl Backend {
                 scaled,
                 scale_factor,
                 &layer.meshes,
+                &layer.textured_meshes,
             );
         }
 
@@ -178,18 +188,23 @@ impl Backend {
                         (text.bounds.width * scale_factor).ceil(),
                         (text.bounds.height * scale_factor).ceil(),
                     ),
-                    text: vec![wgpu_glyph::Text {
-                        text: text.content,
-                        scale: wgpu_glyph::ab_glyph::PxScale {
-                            x: text.size * scale_factor,
-                            y: text.size * scale_factor,
-                        },
-                        font_id: self.text_pipeline.find_font(text.font),
-                        extra: wgpu_glyph::Extra {
-                            color: text.color,
-                            z: 0.0,
-                        },
-                    }],
+                    text: self
+                        .text_pipeline
+                        .segments(text.content, text.font)
+                        .into_iter()
+                        .map(|(range, font_id)| wgpu_glyph::Text {
+                            text: &text.content[range],
+                            scale: wgpu_glyph::ab_glyph::PxScale {
+                                x: text.size * scale_factor,
+                                y: text.size * scale_factor,
+                            },
+                            font_id,
+                            extra: wgpu_glyph::Extra {
+                                color: text.color,
+                                z: 0.0,
+                            },
+                        })
+                        .collect(),
                     layout: wgpu_glyph::Layout::default()
                         .h_align(match text.horizontal_alignment {
                             HorizontalAlignment::Left => {
@@ -239,6 +254,13 @@ impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
         self.text_pipeline.trim_measurement_cache()
     }
+
+    fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), iced_native::font::Error> {
+        self.text_pipeline.load_font(bytes)
+    }
 }
 
 impl backend::Text for Backend {
@@ -254,6 +276,16 @@ impl backend::Text for Backend {
     ) -> (f32, f32) {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
+
+    fn layout(
+        &self,
+        fragments: &[Fragment<'_, Font>],
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+    ) -> Vec<Run> {
+        self.text_pipeline
+            .layout(fragments, bounds, horizontal_alignment)
+    }
 }
 
 #[cfg(feature = "image")]
@@ -261,6 +293,13 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> (u32, u32) {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Vec<std::time::Duration> {
+        self.image_pipeline.frame_delays(handle)
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -266,11 +266,21 @@ impl Pipeline {
     #[cfg(feature = "image")]
     pub fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
         let mut cache = self.raster_cache.borrow_mut();
-        let memory = cache.load(&handle);
+        let memory = cache.load(handle, 0);
 
         memory.dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn frame_delays(
+        &self,
+        handle: &image::Handle,
+    ) -> Vec<std::time::Duration> {
+        let mut cache = self.raster_cache.borrow_mut();
+
+        cache.frame_delays(handle)
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
         let mut cache = self.vector_cache.borrow_mut();
@@ -300,9 +310,14 @@ impl Pipeline {
         for image in images {
             match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    bounds,
+                    frame,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
+                        *frame,
                         device,
                         encoder,
                         &mut self.texture_atlas,
@@ -319,13 +334,17 @@ impl Pipeline {
                 layer::Image::Raster { .. } => {}
 
                 #[cfg(feature = "svg")]
-                layer::Image::Vector { handle, bounds } => {
+                layer::Image::Vector {
+                    handle,
+                    bounds,
+                    appearance,
+                } => {
                     let size = [bounds.width, bounds.height];
 
                     if let Some(atlas_entry) = vector_cache.upload(
                         handle,
-                        size,
-                        _scale,
+                        *appearance,
+                        [size[0] * _scale, size[1] * _scale],
                         device,
                         encoder,
                         &mut self.texture_atlas,
@@ -1,6 +1,10 @@
 use crate::image::atlas::{self, Atlas};
 use iced_native::image;
 use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use ::image::codecs::{gif::GifDecoder, png::PngDecoder};
+use ::image::{AnimationDecoder, ImageFormat};
 
 #[derive(Debug)]
 pub enum Memory {
@@ -21,9 +25,25 @@ impl Memory {
     }
 }
 
+/// A frame of an image, shown for some time if the image is animated.
+#[derive(Debug)]
+pub struct Frame {
+    memory: Memory,
+    delay: Duration,
+}
+
+impl Frame {
+    fn still(memory: Memory) -> Self {
+        Frame {
+            memory,
+            delay: Duration::from_secs(0),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Cache {
-    map: HashMap<u64, Memory>,
+    map: HashMap<u64, Vec<Frame>>,
     hits: HashSet<u64>,
 }
 
@@ -35,55 +55,68 @@ impl Cache {
         }
     }
 
-    pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
-        if self.contains(handle) {
-            return self.get(handle).unwrap();
+    pub fn load(
+        &mut self,
+        handle: &image::Handle,
+        frame: usize,
+    ) -> &mut Memory {
+        if !self.contains(handle) {
+            let frames = match handle.data() {
+                image::Data::Path(path) => std::fs::read(path)
+                    .ok()
+                    .and_then(|bytes| decode(&bytes))
+                    .unwrap_or_else(|| vec![Frame::still(Memory::NotFound)]),
+                image::Data::Bytes(bytes) => decode(bytes)
+                    .unwrap_or_else(|| vec![Frame::still(Memory::Invalid)]),
+                image::Data::Pixels {
+                    width,
+                    height,
+                    pixels,
+                } => {
+                    let memory = if let Some(image) =
+                        ::image::ImageBuffer::from_vec(
+                            *width,
+                            *height,
+                            pixels.to_vec(),
+                        ) {
+                        Memory::Host(image)
+                    } else {
+                        Memory::Invalid
+                    };
+
+                    vec![Frame::still(memory)]
+                }
+            };
+
+            self.insert(handle, frames);
         }
 
-        let memory = match handle.data() {
-            image::Data::Path(path) => {
-                if let Ok(image) = ::image::open(path) {
-                    Memory::Host(image.to_bgra())
-                } else {
-                    Memory::NotFound
-                }
-            }
-            image::Data::Bytes(bytes) => {
-                if let Ok(image) = ::image::load_from_memory(&bytes) {
-                    Memory::Host(image.to_bgra())
-                } else {
-                    Memory::Invalid
-                }
-            }
-            image::Data::Pixels {
-                width,
-                height,
-                pixels,
-            } => {
-                if let Some(image) = ::image::ImageBuffer::from_vec(
-                    *width,
-                    *height,
-                    pixels.to_vec(),
-                ) {
-                    Memory::Host(image)
-                } else {
-                    Memory::Invalid
-                }
-            }
-        };
+        let frames = self.get(handle).unwrap();
+        let last = frames.len() - 1;
+
+        &mut frames[frame.min(last)].memory
+    }
+
+    pub fn frame_delays(&mut self, handle: &image::Handle) -> Vec<Duration> {
+        let _ = self.load(handle, 0);
+        let frames = self.get(handle).unwrap();
+
+        if frames.len() < 2 {
+            return Vec::new();
+        }
 
-        self.insert(handle, memory);
-        self.get(handle).unwrap()
+        frames.iter().map(|frame| frame.delay).collect()
     }
 
     pub fn upload(
         &mut self,
         handle: &image::Handle,
+        frame: usize,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         atlas: &mut Atlas,
     ) -> Option<&atlas::Entry> {
-        let memory = self.load(handle);
+        let memory = self.load(handle, frame);
 
         if let Memory::Host(image) = memory {
             let (width, height) = image.dimensions();
@@ -103,12 +136,14 @@ impl Cache {
     pub fn trim(&mut self, atlas: &mut Atlas) {
         let hits = &self.hits;
 
-        self.map.retain(|k, memory| {
+        self.map.retain(|k, frames| {
             let retain = hits.contains(k);
 
             if !retain {
-                if let Memory::Device(entry) = memory {
-                    atlas.remove(entry);
+                for frame in frames {
+                    if let Memory::Device(entry) = &frame.memory {
+                        atlas.remove(entry);
+                    }
                 }
             }
 
@@ -118,17 +153,72 @@ impl Cache {
         self.hits.clear();
     }
 
-    fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory> {
+    fn get(&mut self, handle: &image::Handle) -> Option<&mut Vec<Frame>> {
         let _ = self.hits.insert(handle.id());
 
         self.map.get_mut(&handle.id())
     }
 
-    fn insert(&mut self, handle: &image::Handle, memory: Memory) {
-        let _ = self.map.insert(handle.id(), memory);
+    fn insert(&mut self, handle: &image::Handle, frames: Vec<Frame>) {
+        let _ = self.map.insert(handle.id(), frames);
     }
 
     fn contains(&self, handle: &image::Handle) -> bool {
         self.map.contains_key(&handle.id())
     }
 }
+
+/// Decodes every frame of an encoded image, returning `None` if it is
+/// invalid.
+fn decode(bytes: &[u8]) -> Option<Vec<Frame>> {
+    let animation = match ::image::guess_format(bytes) {
+        Ok(ImageFormat::Gif) => GifDecoder::new(bytes)
+            .ok()
+            .and_then(|decoder| decoder.into_frames().collect_frames().ok()),
+        Ok(ImageFormat::Png) => PngDecoder::new(bytes)
+            .ok()
+            .filter(PngDecoder::is_apng)
+            .and_then(|decoder| {
+                decoder.apng().into_frames().collect_frames().ok()
+            }),
+        _ => None,
+    };
+
+    match animation {
+        Some(frames) if frames.len() > 1 => Some(
+            frames
+                .into_iter()
+                .map(|frame| {
+                    let delay = delay(frame.delay());
+                    let image =
+                        ::image::DynamicImage::ImageRgba8(frame.into_buffer());
+
+                    Frame {
+                        memory: Memory::Host(image.to_bgra8()),
+                        delay,
+                    }
+                })
+                .collect(),
+        ),
+        _ => {
+            let image = ::image::load_from_memory(bytes).ok()?;
+
+            Some(vec![Frame::still(Memory::Host(image.to_bgra8()))])
+        }
+    }
+}
+
+/// Converts the delay of a frame to a `Duration`.
+///
+/// Like web browsers do, very short delays are slowed down, as many GIFs
+/// rely on it.
+fn delay(delay: ::image::Delay) -> Duration {
+    let (numerator, denominator) = delay.numer_denom_ms();
+    let millis = u64::from(numerator) / u64::from(denominator.max(1));
+
+    if millis < 20 {
+        Duration::from_millis(100)
+    } else {
+        Duration::from_millis(millis)
+    }
+}
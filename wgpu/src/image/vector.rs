@@ -1,6 +1,8 @@
 use crate::image::atlas::{self, Atlas};
-use iced_native::svg;
+use iced_native::{svg, Color};
+use resvg::usvg::NodeKind;
 use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 
 use zerocopy::AsBytes;
 
@@ -69,28 +71,36 @@ impl Cache {
     pub fn upload(
         &mut self,
         handle: &svg::Handle,
+        appearance: svg::Appearance,
         [width, height]: [f32; 2],
-        scale: f32,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         texture_atlas: &mut Atlas,
     ) -> Option<&atlas::Entry> {
         let id = handle.id();
 
-        let (width, height) = (
-            (scale * width).round() as u32,
-            (scale * height).round() as u32,
-        );
+        // Every appearance of the same SVG is rasterized separately
+        let key = if appearance.is_original() {
+            id
+        } else {
+            let mut hasher = iced_native::Hasher::default();
+            id.hash(&mut hasher);
+            appearance.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        let (width, height) = (width.round() as u32, height.round() as u32);
 
         // TODO: Optimize!
         // We currently rerasterize the SVG when its size changes. This is slow
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
         // It would be cool to be able to smooth resize the `svg` example.
-        if self.rasterized.contains_key(&(id, width, height)) {
+        if self.rasterized.contains_key(&(key, width, height)) {
             let _ = self.svg_hits.insert(id);
-            let _ = self.rasterized_hits.insert((id, width, height));
+            let _ = self.rasterized_hits.insert((key, width, height));
 
-            return self.rasterized.get(&(id, width, height));
+            return self.rasterized.get(&(key, width, height));
         }
 
         match self.load(handle) {
@@ -109,6 +119,8 @@ impl Cache {
                 let mut canvas =
                     resvg::raqote::DrawTarget::new(width as i32, height as i32);
 
+                let paints = repaint(tree, appearance);
+
                 resvg::backend_raqote::render_to_canvas(
                     tree,
                     &resvg::Options::default(),
@@ -116,6 +128,22 @@ impl Cache {
                     &mut canvas,
                 );
 
+                restore(paints);
+
+                if appearance.opacity < 1.0 {
+                    // Pixels are premultiplied, so every channel is faded
+                    for pixel in canvas.get_data_mut() {
+                        let channels = pixel.to_le_bytes();
+
+                        *pixel = u32::from_le_bytes([
+                            fade(channels[0], appearance.opacity),
+                            fade(channels[1], appearance.opacity),
+                            fade(channels[2], appearance.opacity),
+                            fade(channels[3], appearance.opacity),
+                        ]);
+                    }
+                }
+
                 let allocation = texture_atlas.upload(
                     width,
                     height,
@@ -125,10 +153,11 @@ impl Cache {
                 )?;
 
                 let _ = self.svg_hits.insert(id);
-                let _ = self.rasterized_hits.insert((id, width, height));
-                let _ = self.rasterized.insert((id, width, height), allocation);
+                let _ = self.rasterized_hits.insert((key, width, height));
+                let _ =
+                    self.rasterized.insert((key, width, height), allocation);
 
-                self.rasterized.get(&(id, width, height))
+                self.rasterized.get(&(key, width, height))
             }
             Svg::NotFound => None,
         }
@@ -153,6 +182,76 @@ impl Cache {
     }
 }
 
+type Paints = Vec<(
+    resvg::usvg::Node,
+    Option<resvg::usvg::Fill>,
+    Option<resvg::usvg::Stroke>,
+)>;
+
+/// Paints the paths of the tree with the colors of the given appearance,
+/// returning their original paints.
+fn repaint(tree: &resvg::usvg::Tree, appearance: svg::Appearance) -> Paints {
+    let mut paints = Vec::new();
+
+    if appearance.fill.is_none() && appearance.stroke.is_none() {
+        return paints;
+    }
+
+    for mut node in tree.root().descendants() {
+        let original = if let NodeKind::Path(ref mut path) = *node.borrow_mut()
+        {
+            let original = (path.fill.clone(), path.stroke.clone());
+
+            if let (Some(fill), Some(color)) = (&mut path.fill, appearance.fill)
+            {
+                fill.paint = paint(color);
+                fill.opacity = f64::from(color.a).into();
+            }
+
+            if let (Some(stroke), Some(color)) =
+                (&mut path.stroke, appearance.stroke)
+            {
+                stroke.paint = paint(color);
+                stroke.opacity = f64::from(color.a).into();
+            }
+
+            Some(original)
+        } else {
+            None
+        };
+
+        if let Some((fill, stroke)) = original {
+            paints.push((node, fill, stroke));
+        }
+    }
+
+    paints
+}
+
+/// Gives back the original paints to the paths returned by `repaint`.
+fn restore(paints: Paints) {
+    for (mut node, fill, stroke) in paints {
+        if let NodeKind::Path(ref mut path) = *node.borrow_mut() {
+            path.fill = fill;
+            path.stroke = stroke;
+        }
+    }
+}
+
+fn paint(color: Color) -> resvg::usvg::Paint {
+    let channel = |c: f32| (c * 255.0).round() as u8;
+
+    resvg::usvg::Paint::Color(resvg::usvg::Color::new(
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+    ))
+}
+
+fn fade(channel: u8, opacity: f32) -> u8 {
+    (f32::from(channel) * opacity).round() as u8
+}
+
 impl std::fmt::Debug for Svg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -36,7 +36,9 @@ mod backend;
 mod quad;
 mod text;
 
-pub use iced_graphics::{Antialiasing, Defaults, Primitive, Viewport};
+pub use iced_graphics::{
+    theme, Antialiasing, Defaults, Primitive, Theme, Viewport,
+};
 pub use wgpu;
 
 pub use backend::Backend;
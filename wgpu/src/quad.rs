@@ -133,13 +133,71 @@ impl Pipeline {
                                 },
                                 wgpu::VertexAttributeDescriptor {
                                     shader_location: 5,
-                                    format: wgpu::VertexFormat::Float,
+                                    format: wgpu::VertexFormat::Float4,
                                     offset: 4 * (2 + 2 + 4 + 4),
                                 },
                                 wgpu::VertexAttributeDescriptor {
                                     shader_location: 6,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * (2 + 2 + 4 + 4 + 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 7,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * (2 + 2 + 4 + 4 + 4 + 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 8,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * (2 + 2 + 4 + 4 * 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 9,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * (2 + 2 + 4 + 4 * 4 + 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 10,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * (2 + 2 + 4 + 4 * 4 + 4 + 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 11,
+                                    format: wgpu::VertexFormat::Float,
+                                    offset: 4 * (2 + 2 + 4 + 4 * 4 + 4 + 4 + 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 12,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4
+                                        * (2 + 2 + 4 + 4 * 4 + 4 + 4 + 4 + 1),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 13,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 4
+                                        * (2 + 2
+                                            + 4
+                                            + 4 * 4
+                                            + 4
+                                            + 4
+                                            + 4
+                                            + 1
+                                            + 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 14,
                                     format: wgpu::VertexFormat::Float,
-                                    offset: 4 * (2 + 2 + 4 + 4 + 1),
+                                    offset: 4
+                                        * (2 + 2
+                                            + 4
+                                            + 4 * 4
+                                            + 4
+                                            + 4
+                                            + 4
+                                            + 1
+                                            + 4
+                                            + 2),
                                 },
                             ],
                         },
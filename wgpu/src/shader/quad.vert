@@ -4,9 +4,17 @@ layout(location = 0) in vec2 v_Pos;
 layout(location = 1) in vec2 i_Pos;
 layout(location = 2) in vec2 i_Scale;
 layout(location = 3) in vec4 i_Color;
-layout(location = 4) in vec4 i_BorderColor;
-layout(location = 5) in float i_BorderRadius;
-layout(location = 6) in float i_BorderWidth;
+layout(location = 4) in vec4 i_BorderColorTop;
+layout(location = 5) in vec4 i_BorderColorRight;
+layout(location = 6) in vec4 i_BorderColorBottom;
+layout(location = 7) in vec4 i_BorderColorLeft;
+layout(location = 8) in vec4 i_BorderRadius;
+layout(location = 9) in vec4 i_BorderWidth;
+layout(location = 10) in vec4 i_GradientColor;
+layout(location = 11) in float i_GradientAngle;
+layout(location = 12) in vec4 i_ShadowColor;
+layout(location = 13) in vec2 i_ShadowOffset;
+layout(location = 14) in float i_ShadowBlur;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
@@ -14,29 +22,56 @@ layout (set = 0, binding = 0) uniform Globals {
 };
 
 layout(location = 0) out vec4 o_Color;
-layout(location = 1) out vec4 o_BorderColor;
-layout(location = 2) out vec2 o_Pos;
-layout(location = 3) out vec2 o_Scale;
-layout(location = 4) out float o_BorderRadius;
-layout(location = 5) out float o_BorderWidth;
+layout(location = 1) out vec4 o_BorderColorTop;
+layout(location = 2) out vec4 o_BorderColorRight;
+layout(location = 3) out vec4 o_BorderColorBottom;
+layout(location = 4) out vec4 o_BorderColorLeft;
+layout(location = 5) out vec2 o_Pos;
+layout(location = 6) out vec2 o_Scale;
+layout(location = 7) out vec4 o_BorderRadius;
+layout(location = 8) out vec4 o_BorderWidth;
+layout(location = 9) out vec4 o_GradientColor;
+layout(location = 10) out float o_GradientAngle;
+layout(location = 11) out vec4 o_ShadowColor;
+layout(location = 12) out vec2 o_ShadowOffset;
+layout(location = 13) out float o_ShadowBlur;
 
 void main() {
     vec2 p_Pos = i_Pos * u_Scale;
     vec2 p_Scale = i_Scale  * u_Scale;
+    vec2 p_ShadowOffset = i_ShadowOffset * u_Scale;
+    float p_ShadowBlur = i_ShadowBlur * u_Scale;
+
+    // Grow the quad to fit its shadow, if it casts any
+    float has_shadow = step(0.001, i_ShadowColor.a);
+    vec2 shadow_min = p_Pos + p_ShadowOffset - vec2(p_ShadowBlur);
+    vec2 shadow_max = p_Pos + p_Scale + p_ShadowOffset + vec2(p_ShadowBlur);
+
+    vec2 q_Min = mix(p_Pos, min(p_Pos, shadow_min), has_shadow);
+    vec2 q_Max = mix(p_Pos + p_Scale, max(p_Pos + p_Scale, shadow_max), has_shadow);
+    vec2 q_Scale = q_Max - q_Min;
 
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(q_Scale.x + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, q_Scale.y + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(q_Min - vec2(0.5, 0.5), 0.0, 1.0)
     );
 
     o_Color = i_Color;
-    o_BorderColor = i_BorderColor;
+    o_BorderColorTop = i_BorderColorTop;
+    o_BorderColorRight = i_BorderColorRight;
+    o_BorderColorBottom = i_BorderColorBottom;
+    o_BorderColorLeft = i_BorderColorLeft;
     o_Pos = p_Pos;
     o_Scale = p_Scale;
     o_BorderRadius = i_BorderRadius * u_Scale;
     o_BorderWidth = i_BorderWidth * u_Scale;
+    o_GradientColor = i_GradientColor;
+    o_GradientAngle = i_GradientAngle;
+    o_ShadowColor = i_ShadowColor;
+    o_ShadowOffset = p_ShadowOffset;
+    o_ShadowBlur = p_ShadowBlur;
 
     gl_Position = u_Transform * i_Transform * vec4(v_Pos, 0.0, 1.0);
 }
@@ -1,13 +1,28 @@
 use crate::Transformation;
 use iced_graphics::font;
-use std::{cell::RefCell, collections::HashMap};
+use iced_native::rich_text::{Fragment, Run};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use wgpu_glyph::ab_glyph;
 
 #[derive(Debug)]
 pub struct Pipeline {
     draw_brush: RefCell<wgpu_glyph::GlyphBrush<()>>,
     draw_font_map: RefCell<HashMap<String, wgpu_glyph::FontId>>,
+    missing_families: RefCell<HashSet<String>>,
     measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    fallback_fonts: &'static [iced_native::Font],
+
+    #[cfg(feature = "default_system_font")]
+    source: font::Source,
+
+    #[cfg(feature = "default_system_font")]
+    system_fallbacks: RefCell<Vec<wgpu_glyph::FontId>>,
+
+    #[cfg(feature = "default_system_font")]
+    missing_glyphs: RefCell<HashSet<char>>,
 }
 
 impl Pipeline {
@@ -15,14 +30,18 @@ impl Pipeline {
         device: &wgpu::Device,
         format: wgpu::TextureFormat,
         default_font: Option<&[u8]>,
+        fallback_fonts: &'static [iced_native::Font],
     ) -> Self {
         let default_font = default_font.map(|slice| slice.to_vec());
 
+        #[cfg(feature = "default_system_font")]
+        let source = font::Source::new();
+
         // TODO: Font customization
         #[cfg(feature = "default_system_font")]
         let default_font = {
             default_font.or_else(|| {
-                font::Source::new()
+                source
                     .load(&[font::Family::SansSerif, font::Family::Serif])
                     .ok()
             })
@@ -54,7 +73,18 @@ impl Pipeline {
         Pipeline {
             draw_brush: RefCell::new(draw_brush),
             draw_font_map: RefCell::new(HashMap::new()),
+            missing_families: RefCell::new(HashSet::new()),
             measure_brush: RefCell::new(measure_brush),
+            fallback_fonts,
+
+            #[cfg(feature = "default_system_font")]
+            source,
+
+            #[cfg(feature = "default_system_font")]
+            system_fallbacks: RefCell::new(Vec::new()),
+
+            #[cfg(feature = "default_system_font")]
+            missing_glyphs: RefCell::new(HashSet::new()),
         }
     }
 
@@ -91,16 +121,18 @@ impl Pipeline {
     ) -> (f32, f32) {
         use wgpu_glyph::GlyphCruncher;
 
-        let wgpu_glyph::FontId(font_id) = self.find_font(font);
-
         let section = wgpu_glyph::Section {
             bounds: (bounds.width, bounds.height),
-            text: vec![wgpu_glyph::Text {
-                text: content,
-                scale: size.into(),
-                font_id: wgpu_glyph::FontId(font_id),
-                extra: wgpu_glyph::Extra::default(),
-            }],
+            text: self
+                .segments(content, font)
+                .into_iter()
+                .map(|(range, font_id)| wgpu_glyph::Text {
+                    text: &content[range],
+                    scale: size.into(),
+                    font_id,
+                    extra: wgpu_glyph::Extra::default(),
+                })
+                .collect(),
             ..Default::default()
         };
 
@@ -113,6 +145,107 @@ impl Pipeline {
         }
     }
 
+    pub fn layout(
+        &self,
+        fragments: &[Fragment<'_, iced_native::Font>],
+        bounds: iced_native::Size,
+        horizontal_alignment: iced_native::HorizontalAlignment,
+    ) -> Vec<Run> {
+        use iced_native::HorizontalAlignment;
+        use wgpu_glyph::ab_glyph::{Font, ScaleFont};
+        use wgpu_glyph::GlyphCruncher;
+
+        // Alignment is only possible with a finite width
+        let (x, h_align) = match horizontal_alignment {
+            _ if bounds.width.is_infinite() => {
+                (0.0, wgpu_glyph::HorizontalAlign::Left)
+            }
+            HorizontalAlignment::Left => {
+                (0.0, wgpu_glyph::HorizontalAlign::Left)
+            }
+            HorizontalAlignment::Center => {
+                (bounds.width / 2.0, wgpu_glyph::HorizontalAlign::Center)
+            }
+            HorizontalAlignment::Right => {
+                (bounds.width, wgpu_glyph::HorizontalAlign::Right)
+            }
+        };
+
+        // Every fragment may be split in multiple segments when falling back
+        // to other fonts, so we keep track of where each segment starts
+        let mut origins = Vec::new();
+        let mut text = Vec::new();
+
+        for (index, fragment) in fragments.iter().enumerate() {
+            for (range, font_id) in
+                self.segments(fragment.content, fragment.font)
+            {
+                origins.push((index, range.start));
+                text.push(wgpu_glyph::Text {
+                    text: &fragment.content[range],
+                    scale: f32::from(fragment.size).into(),
+                    font_id,
+                    extra: wgpu_glyph::Extra::default(),
+                });
+            }
+        }
+
+        let section = wgpu_glyph::Section {
+            screen_position: (x, 0.0),
+            bounds: (bounds.width, bounds.height),
+            text,
+            layout: wgpu_glyph::Layout::default().h_align(h_align),
+        };
+
+        let mut measure_brush = self.measure_brush.borrow_mut();
+        let fonts = measure_brush.fonts().to_vec();
+
+        let mut runs: Vec<Run> = Vec::new();
+
+        for section_glyph in measure_brush.glyphs(section) {
+            let glyph = &section_glyph.glyph;
+            let font = fonts[section_glyph.font_id.0].as_scaled(glyph.scale);
+
+            let (fragment, offset) = origins[section_glyph.section_index];
+
+            let content = fragments[fragment].content;
+            let start = offset + section_glyph.byte_index;
+            let end = start
+                + content[start..]
+                    .chars()
+                    .next()
+                    .map(char::len_utf8)
+                    .unwrap_or(0);
+
+            let baseline = glyph.position.y;
+            let right = glyph.position.x + font.h_advance(glyph.id);
+
+            // Consecutive glyphs of a fragment in the same line form a run
+            match runs.last_mut() {
+                Some(run)
+                    if run.fragment == fragment
+                        && (run.baseline - baseline).abs() < f32::EPSILON =>
+                {
+                    run.range.end = end;
+                    run.bounds.width = right - run.bounds.x;
+                }
+                _ => runs.push(Run {
+                    fragment,
+                    range: start..end,
+                    bounds: iced_native::Rectangle {
+                        x: glyph.position.x,
+                        y: baseline - font.ascent(),
+                        width: right - glyph.position.x,
+                        height: font.ascent() - font.descent(),
+                    },
+                    baseline,
+                }),
+            }
+        }
+
+        runs
+    }
+
     pub fn trim_measurement_cache(&mut self) {
         // TODO: We should probably use a `GlyphCalculator` for this. However,
         // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
@@ -159,6 +292,157 @@ impl Pipeline {
 
                 font_id
             }
+            iced_native::Font::Family(name) => {
+                if let Some(font_id) = self.draw_font_map.borrow().get(name) {
+                    return *font_id;
+                }
+
+                if self.missing_families.borrow_mut().insert(name.to_owned()) {
+                    log::warn!(
+                        "Font family {:?} is not loaded, using the default \
+                        font instead",
+                        name
+                    );
+                }
+
+                wgpu_glyph::FontId(0)
+            }
+        }
+    }
+
+    pub fn load_font(
+        &self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), iced_native::font::Error> {
+        let name = font::family_name(&bytes)
+            .ok_or(iced_native::font::Error::Invalid)?;
+
+        // Fonts cannot be removed from the glyph brushes, so the first font
+        // loaded for a family is kept
+        if self.draw_font_map.borrow().contains_key(&name) {
+            return Ok(());
+        }
+
+        let font = match bytes {
+            Cow::Borrowed(bytes) => ab_glyph::FontArc::try_from_slice(bytes),
+            Cow::Owned(bytes) => ab_glyph::FontArc::try_from_vec(bytes),
+        }
+        .map_err(|_| iced_native::font::Error::Invalid)?;
+
+        let _ = self.measure_brush.borrow_mut().add_font(font.clone());
+
+        let font_id = self.draw_brush.borrow_mut().add_font(font);
+
+        let _ = self.draw_font_map.borrow_mut().insert(name, font_id);
+
+        Ok(())
+    }
+
+    /// Splits the given content in ranges that can be rendered with a
+    /// single font.
+    ///
+    /// The characters missing in the provided font are rendered with the
+    /// first font of the fallback chain that contains them.
+    pub fn segments(
+        &self,
+        content: &str,
+        font: iced_native::Font,
+    ) -> Vec<(Range<usize>, wgpu_glyph::FontId)> {
+        let font_id = self.find_font(font);
+
+        let mut segments = Vec::new();
+        let mut start = 0;
+        let mut current = font_id;
+
+        for (index, c) in content.char_indices() {
+            // Whitespace does not need any glyphs, so it stays in the
+            // current segment
+            let next = if c.is_whitespace() || c.is_control() {
+                current
+            } else if self.has_glyph(font_id, c) {
+                font_id
+            } else {
+                self.fallback(c).unwrap_or(font_id)
+            };
+
+            if next != current {
+                if index > start {
+                    segments.push((start..index, current));
+                }
+
+                start = index;
+                current = next;
+            }
+        }
+
+        if start < content.len() || segments.is_empty() {
+            segments.push((start..content.len(), current));
+        }
+
+        segments
+    }
+
+    fn has_glyph(&self, font_id: wgpu_glyph::FontId, c: char) -> bool {
+        use ab_glyph::Font;
+        use wgpu_glyph::GlyphCruncher;
+
+        self.measure_brush
+            .borrow()
+            .fonts()
+            .get(font_id.0)
+            .map(|font| font.glyph_id(c).0 != 0)
+            .unwrap_or(false)
+    }
+
+    fn fallback(&self, c: char) -> Option<wgpu_glyph::FontId> {
+        let mut chain: Vec<wgpu_glyph::FontId> = self
+            .fallback_fonts
+            .iter()
+            .map(|font| self.find_font(*font))
+            .collect();
+
+        chain.push(wgpu_glyph::FontId(0));
+
+        #[cfg(feature = "default_system_font")]
+        chain.extend(self.system_fallbacks.borrow().iter().copied());
+
+        chain
+            .into_iter()
+            .find(|font_id| self.has_glyph(*font_id, c))
+            .or_else(|| self.discover(c))
+    }
+
+    #[cfg(feature = "default_system_font")]
+    fn discover(&self, c: char) -> Option<wgpu_glyph::FontId> {
+        if self.missing_glyphs.borrow().contains(&c) {
+            return None;
+        }
+
+        let font = self
+            .source
+            .load_fallback(c)
+            .and_then(|bytes| ab_glyph::FontArc::try_from_vec(bytes).ok());
+
+        match font {
+            Some(font) => {
+                let _ = self.measure_brush.borrow_mut().add_font(font.clone());
+
+                let font_id = self.draw_brush.borrow_mut().add_font(font);
+
+                self.system_fallbacks.borrow_mut().push(font_id);
+
+                Some(font_id)
+            }
+            None => {
+                let _ = self.missing_glyphs.borrow_mut().insert(c);
+
+                None
+            }
         }
     }
+
+    #[cfg(not(feature = "default_system_font"))]
+    fn discover(&self, _c: char) -> Option<wgpu_glyph::FontId> {
+        None
+    }
 }
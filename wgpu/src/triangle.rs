@@ -4,9 +4,10 @@ use iced_graphics::layer;
 use std::mem;
 use zerocopy::AsBytes;
 
-pub use iced_graphics::triangle::{Mesh2D, Vertex2D};
+pub use iced_graphics::triangle::{Blend, Mesh2D, Vertex2D};
 
 mod msaa;
+mod textured;
 
 const UNIFORM_BUFFER_SIZE: usize = 100;
 const VERTEX_BUFFER_SIZE: usize = 10_000;
@@ -15,13 +16,42 @@ const INDEX_BUFFER_SIZE: usize = 10_000;
 #[derive(Debug)]
 pub(crate) struct Pipeline {
     pipeline: wgpu::RenderPipeline,
+    premultiplied: BlendPipelines,
     blit: Option<msaa::Blit>,
+    textured: textured::Pipeline,
     constants: wgpu::BindGroup,
     uniforms_buffer: Buffer<Uniforms>,
     vertex_buffer: Buffer<Vertex2D>,
     index_buffer: Buffer<u32>,
 }
 
+#[derive(Debug)]
+struct BlendPipelines {
+    normal: wgpu::RenderPipeline,
+    multiply: wgpu::RenderPipeline,
+    screen: wgpu::RenderPipeline,
+    additive: wgpu::RenderPipeline,
+}
+
+impl BlendPipelines {
+    fn select<'a>(
+        &'a self,
+        straight: &'a wgpu::RenderPipeline,
+        mesh: &layer::Mesh<'_>,
+    ) -> &'a wgpu::RenderPipeline {
+        if !mesh.is_premultiplied() {
+            return straight;
+        }
+
+        match mesh.blend {
+            Blend::Normal => &self.normal,
+            Blend::Multiply => &self.multiply,
+            Blend::Screen => &self.screen,
+            Blend::Additive => &self.additive,
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Buffer<T> {
     raw: wgpu::Buffer,
@@ -69,6 +99,9 @@ impl Pipeline {
         format: wgpu::TextureFormat,
         antialiasing: Option<settings::Antialiasing>,
     ) -> Pipeline {
+        let sample_count =
+            u32::from(antialiasing.map(|a| a.sample_count()).unwrap_or(1));
+
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: None,
@@ -115,7 +148,7 @@ impl Pipeline {
                 .expect("Read triangle fragment shader as SPIR-V"),
         );
 
-        let pipeline =
+        let create_pipeline = |color_blend, alpha_blend| {
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
                 layout: &layout,
                 vertex_stage: wgpu::ProgrammableStageDescriptor {
@@ -136,16 +169,8 @@ impl Pipeline {
                 primitive_topology: wgpu::PrimitiveTopology::TriangleList,
                 color_states: &[wgpu::ColorStateDescriptor {
                     format,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
+                    color_blend,
+                    alpha_blend,
                     write_mask: wgpu::ColorWrite::ALL,
                 }],
                 depth_stencil_state: None,
@@ -170,16 +195,61 @@ impl Pipeline {
                         ],
                     }],
                 },
-                sample_count: u32::from(
-                    antialiasing.map(|a| a.sample_count()).unwrap_or(1),
-                ),
+                sample_count,
                 sample_mask: !0,
                 alpha_to_coverage_enabled: false,
-            });
+            })
+        };
+
+        let blend = |src_factor, dst_factor| wgpu::BlendDescriptor {
+            src_factor,
+            dst_factor,
+            operation: wgpu::BlendOperation::Add,
+        };
+
+        let premultiplied_alpha =
+            blend(wgpu::BlendFactor::One, wgpu::BlendFactor::OneMinusSrcAlpha);
+
+        let pipeline = create_pipeline(
+            blend(
+                wgpu::BlendFactor::SrcAlpha,
+                wgpu::BlendFactor::OneMinusSrcAlpha,
+            ),
+            premultiplied_alpha.clone(),
+        );
+
+        // Meshes that are blended in a special way or that are not fully
+        // opaque are uploaded with premultiplied colors
+        let premultiplied = BlendPipelines {
+            normal: create_pipeline(
+                premultiplied_alpha.clone(),
+                premultiplied_alpha.clone(),
+            ),
+            multiply: create_pipeline(
+                blend(
+                    wgpu::BlendFactor::DstColor,
+                    wgpu::BlendFactor::OneMinusSrcAlpha,
+                ),
+                premultiplied_alpha.clone(),
+            ),
+            screen: create_pipeline(
+                blend(
+                    wgpu::BlendFactor::One,
+                    wgpu::BlendFactor::OneMinusSrcColor,
+                ),
+                premultiplied_alpha.clone(),
+            ),
+            additive: create_pipeline(
+                blend(wgpu::BlendFactor::One, wgpu::BlendFactor::One),
+                premultiplied_alpha,
+            ),
+        };
 
         Pipeline {
             pipeline,
+            premultiplied,
             blit: antialiasing.map(|a| msaa::Blit::new(device, format, a)),
+            textured: textured::Pipeline::new(device, format, sample_count),
             constants: constant_bind_group,
             uniforms_buffer: constants_buffer,
             vertex_buffer: Buffer::new(
@@ -205,6 +275,7 @@ impl Pipeline {
         transformation: Transformation,
         scale_factor: f32,
         meshes: &[layer::Mesh<'_>],
+        textured_meshes: &[layer::TexturedMesh<'_>],
     ) {
         // This looks a bit crazy, but we are just counting how many vertices
         // and indices we will need to handle.
@@ -240,7 +311,7 @@ impl Pipeline {
             .into();
 
             let vertex_buffer = device.create_buffer_with_data(
-                bytemuck::cast_slice(&mesh.buffers.vertices),
+                bytemuck::cast_slice(&mesh.vertices()),
                 wgpu::BufferUsage::COPY_SRC,
             );
 
@@ -278,18 +349,23 @@ impl Pipeline {
             last_index += mesh.buffers.indices.len();
         }
 
-        let uniforms_buffer = device.create_buffer_with_data(
-            uniforms.as_bytes(),
-            wgpu::BufferUsage::COPY_SRC,
-        );
+        if !uniforms.is_empty() {
+            let uniforms_buffer = device.create_buffer_with_data(
+                uniforms.as_bytes(),
+                wgpu::BufferUsage::COPY_SRC,
+            );
 
-        encoder.copy_buffer_to_buffer(
-            &uniforms_buffer,
-            0,
-            &self.uniforms_buffer.raw,
-            0,
-            (std::mem::size_of::<Uniforms>() * uniforms.len()) as u64,
-        );
+            encoder.copy_buffer_to_buffer(
+                &uniforms_buffer,
+                0,
+                &self.uniforms_buffer.raw,
+                0,
+                (std::mem::size_of::<Uniforms>() * uniforms.len()) as u64,
+            );
+        }
+
+        self.textured
+            .prepare(device, encoder, transformation, textured_meshes);
 
         {
             let (attachment, resolve_target, load_op) =
@@ -321,11 +397,13 @@ impl Pipeline {
                     depth_stencil_attachment: None,
                 });
 
-            render_pass.set_pipeline(&self.pipeline);
-
             for (i, (vertex_offset, index_offset, indices)) in
                 offsets.into_iter().enumerate()
             {
+                render_pass.set_pipeline(
+                    self.premultiplied.select(&self.pipeline, &meshes[i]),
+                );
+
                 let clip_bounds = (meshes[i].clip_bounds * scale_factor).snap();
 
                 render_pass.set_scissor_rect(
@@ -356,12 +434,19 @@ impl Pipeline {
 
                 render_pass.draw_indexed(0..indices as u32, 0, 0..1);
             }
+
+            self.textured
+                .draw(&mut render_pass, textured_meshes, scale_factor);
         }
 
         if let Some(blit) = &mut self.blit {
             blit.draw(encoder, target);
         }
     }
+
+    pub fn trim_cache(&mut self) {
+        self.textured.trim_cache();
+    }
 }
 
 #[repr(C)]
@@ -0,0 +1,490 @@
+//! Draw meshes of triangles filled with the repeated tiles of an image.
+use super::{Buffer, Uniforms};
+use crate::Transformation;
+use iced_graphics::layer;
+use iced_graphics::triangle::TexturedVertex2D;
+use iced_native::image;
+use std::collections::{HashMap, HashSet};
+use std::mem;
+use zerocopy::AsBytes;
+
+#[derive(Debug)]
+pub(super) struct Pipeline {
+    pipeline: wgpu::RenderPipeline,
+    constant_layout: wgpu::BindGroupLayout,
+    constants: wgpu::BindGroup,
+    sampler: wgpu::Sampler,
+    uniforms: Buffer<Uniforms>,
+    instance: wgpu::Buffer,
+    texture_layout: wgpu::BindGroupLayout,
+    textures: HashMap<u64, Texture>,
+    hits: HashSet<u64>,
+    uploads: Vec<Option<Upload>>,
+}
+
+impl Pipeline {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+    ) -> Pipeline {
+        // Tiles are repeated by the sampler
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::Repeat,
+            address_mode_v: wgpu::AddressMode::Repeat,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            lod_min_clamp: -100.0,
+            lod_max_clamp: 100.0,
+            compare: wgpu::CompareFunction::Always,
+        });
+
+        let constant_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: None,
+                bindings: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStage::VERTEX,
+                        ty: wgpu::BindingType::UniformBuffer { dynamic: true },
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler { comparison: false },
+                    },
+                ],
+            });
+
+        let uniforms = Buffer::new(
+            device,
+            super::UNIFORM_BUFFER_SIZE,
+            wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+        );
+
+        let constants =
+            constants(device, &constant_layout, &uniforms.raw, &sampler);
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: None,
+                bindings: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::SampledTexture {
+                        dimension: wgpu::TextureViewDimension::D2Array,
+                        component_type: wgpu::TextureComponentType::Float,
+                        multisampled: false,
+                    },
+                }],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                bind_group_layouts: &[&constant_layout, &texture_layout],
+            });
+
+        // The image shaders are reused: every vertex is an instance
+        // position with a scale of zero, and the texture coordinates are
+        // passed as the vertex position of the quad
+        let vs = include_bytes!("../shader/image.vert.spv");
+        let vs_module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(&vs[..]))
+                .expect("Read image vertex shader as SPIR-V"),
+        );
+
+        let fs = include_bytes!("../shader/image.frag.spv");
+        let fs_module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(&fs[..]))
+                .expect("Read image fragment shader as SPIR-V"),
+        );
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                layout: &layout,
+                vertex_stage: wgpu::ProgrammableStageDescriptor {
+                    module: &vs_module,
+                    entry_point: "main",
+                },
+                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                    module: &fs_module,
+                    entry_point: "main",
+                }),
+                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                    front_face: wgpu::FrontFace::Cw,
+                    cull_mode: wgpu::CullMode::None,
+                    depth_bias: 0,
+                    depth_bias_slope_scale: 0.0,
+                    depth_bias_clamp: 0.0,
+                }),
+                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+                color_states: &[wgpu::ColorStateDescriptor {
+                    format,
+                    color_blend: wgpu::BlendDescriptor {
+                        src_factor: wgpu::BlendFactor::SrcAlpha,
+                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                    alpha_blend: wgpu::BlendDescriptor {
+                        src_factor: wgpu::BlendFactor::One,
+                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                    write_mask: wgpu::ColorWrite::ALL,
+                }],
+                depth_stencil_state: None,
+                vertex_state: wgpu::VertexStateDescriptor {
+                    index_format: wgpu::IndexFormat::Uint32,
+                    vertex_buffers: &[
+                        wgpu::VertexBufferDescriptor {
+                            stride: mem::size_of::<TexturedVertex2D>() as u64,
+                            step_mode: wgpu::InputStepMode::Vertex,
+                            attributes: &[
+                                // Texture coordinates
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 0,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 4 * 2,
+                                },
+                                // Position
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 1,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 0,
+                                },
+                            ],
+                        },
+                        wgpu::VertexBufferDescriptor {
+                            stride: mem::size_of::<Instance>() as u64,
+                            step_mode: wgpu::InputStepMode::Instance,
+                            attributes: &[
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 2,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 0,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 3,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 4 * 2,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 4,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 4 * 4,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 5,
+                                    format: wgpu::VertexFormat::Uint,
+                                    offset: 4 * 6,
+                                },
+                            ],
+                        },
+                    ],
+                },
+                sample_count,
+                sample_mask: !0,
+                alpha_to_coverage_enabled: false,
+            });
+
+        let instance = device.create_buffer_with_data(
+            Instance {
+                _scale: [0.0, 0.0],
+                _atlas_position: [0.0, 0.0],
+                _atlas_scale: [1.0, 1.0],
+                _layer: 0,
+            }
+            .as_bytes(),
+            wgpu::BufferUsage::VERTEX,
+        );
+
+        Pipeline {
+            pipeline,
+            constant_layout,
+            constants,
+            sampler,
+            uniforms,
+            instance,
+            texture_layout,
+            textures: HashMap::new(),
+            hits: HashSet::new(),
+            uploads: Vec::new(),
+        }
+    }
+
+    /// Uploads the textures, the vertices, and the transformations of the
+    /// given meshes, before a render pass draws them.
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        transformation: Transformation,
+        meshes: &[layer::TexturedMesh<'_>],
+    ) {
+        self.uploads.clear();
+
+        if meshes.is_empty() {
+            return;
+        }
+
+        if self.uniforms.size < meshes.len() {
+            self.uniforms.ensure_capacity(device, meshes.len());
+
+            self.constants = constants(
+                device,
+                &self.constant_layout,
+                &self.uniforms.raw,
+                &self.sampler,
+            );
+        }
+
+        let uniforms: Vec<Uniforms> = meshes
+            .iter()
+            .map(|mesh| {
+                (transformation
+                    * Transformation::translate(mesh.origin.x, mesh.origin.y))
+                .into()
+            })
+            .collect();
+
+        let uniforms_buffer = device.create_buffer_with_data(
+            uniforms.as_bytes(),
+            wgpu::BufferUsage::COPY_SRC,
+        );
+
+        encoder.copy_buffer_to_buffer(
+            &uniforms_buffer,
+            0,
+            &self.uniforms.raw,
+            0,
+            (mem::size_of::<Uniforms>() * uniforms.len()) as u64,
+        );
+
+        for mesh in meshes {
+            let id = mesh.texture.id();
+
+            if !self.textures.contains_key(&id) {
+                if let Some(texture) = Texture::upload(
+                    device,
+                    encoder,
+                    &self.texture_layout,
+                    mesh.texture,
+                ) {
+                    let _ = self.textures.insert(id, texture);
+                }
+            }
+
+            if !self.textures.contains_key(&id)
+                || mesh.buffers.indices.is_empty()
+            {
+                self.uploads.push(None);
+                continue;
+            }
+
+            let _ = self.hits.insert(id);
+
+            self.uploads.push(Some(Upload {
+                vertices: device.create_buffer_with_data(
+                    bytemuck::cast_slice(&mesh.buffers.vertices),
+                    wgpu::BufferUsage::VERTEX,
+                ),
+                indices: device.create_buffer_with_data(
+                    mesh.buffers.indices.as_bytes(),
+                    wgpu::BufferUsage::INDEX,
+                ),
+                count: mesh.buffers.indices.len() as u32,
+                texture: id,
+            }));
+        }
+    }
+
+    /// Draws the meshes uploaded by the last call to [`prepare`].
+    ///
+    /// [`prepare`]: #method.prepare
+    pub fn draw<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        meshes: &[layer::TexturedMesh<'_>],
+        scale_factor: f32,
+    ) {
+        if self.uploads.iter().all(Option::is_none) {
+            return;
+        }
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_vertex_buffer(1, &self.instance, 0, 0);
+
+        for (i, (mesh, upload)) in meshes.iter().zip(&self.uploads).enumerate()
+        {
+            let upload = match upload {
+                Some(upload) => upload,
+                None => continue,
+            };
+
+            let texture = match self.textures.get(&upload.texture) {
+                Some(texture) => texture,
+                None => continue,
+            };
+
+            let clip_bounds = (mesh.clip_bounds * scale_factor).snap();
+
+            render_pass.set_scissor_rect(
+                clip_bounds.x,
+                clip_bounds.y,
+                clip_bounds.width,
+                clip_bounds.height,
+            );
+
+            render_pass.set_bind_group(
+                0,
+                &self.constants,
+                &[(mem::size_of::<Uniforms>() * i) as u32],
+            );
+            render_pass.set_bind_group(1, &texture.bind_group, &[]);
+
+            render_pass.set_index_buffer(&upload.indices, 0, 0);
+            render_pass.set_vertex_buffer(0, &upload.vertices, 0, 0);
+
+            render_pass.draw_indexed(0..upload.count, 0, 0..1);
+        }
+    }
+
+    /// Drops the textures that were not drawn since the last call.
+    pub fn trim_cache(&mut self) {
+        let hits = &self.hits;
+
+        self.textures.retain(|id, _| hits.contains(id));
+        self.hits.clear();
+    }
+}
+
+fn constants(
+    device: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    uniforms: &wgpu::Buffer,
+    sampler: &wgpu::Sampler,
+) -> wgpu::BindGroup {
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout,
+        bindings: &[
+            wgpu::Binding {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer {
+                    buffer: uniforms,
+                    range: 0..mem::size_of::<Uniforms>() as u64,
+                },
+            },
+            wgpu::Binding {
+                binding: 1,
+                resource: wgpu::BindingResource::Sampler(sampler),
+            },
+        ],
+    })
+}
+
+#[derive(Debug)]
+struct Texture {
+    _raw: wgpu::Texture,
+    _view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+}
+
+impl Texture {
+    fn upload(
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        layout: &wgpu::BindGroupLayout,
+        handle: &image::Handle,
+    ) -> Option<Texture> {
+        let (width, height, pixels) = match handle.data() {
+            image::Data::Pixels {
+                width,
+                height,
+                pixels,
+            } if *width > 0 && *height > 0 => (*width, *height, pixels),
+            _ => return None,
+        };
+
+        let extent = wgpu::Extent3d {
+            width,
+            height,
+            depth: 1,
+        };
+
+        let raw = device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: extent,
+            array_layer_count: 1,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            usage: wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
+        });
+
+        let buffer =
+            device.create_buffer_with_data(pixels, wgpu::BufferUsage::COPY_SRC);
+
+        encoder.copy_buffer_to_texture(
+            wgpu::BufferCopyView {
+                buffer: &buffer,
+                offset: 0,
+                bytes_per_row: 4 * width,
+                rows_per_image: height,
+            },
+            wgpu::TextureCopyView {
+                texture: &raw,
+                array_layer: 0,
+                mip_level: 0,
+                origin: wgpu::Origin3d { x: 0, y: 0, z: 0 },
+            },
+            extent,
+        );
+
+        // The image shader samples from an array of textures
+        let view = raw.create_view(&wgpu::TextureViewDescriptor {
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            dimension: wgpu::TextureViewDimension::D2Array,
+            aspect: wgpu::TextureAspect::All,
+            base_mip_level: 0,
+            level_count: 1,
+            base_array_layer: 0,
+            array_layer_count: 1,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout,
+            bindings: &[wgpu::Binding {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Some(Texture {
+            _raw: raw,
+            _view: view,
+            bind_group,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Upload {
+    vertices: wgpu::Buffer,
+    indices: wgpu::Buffer,
+    count: u32,
+    texture: u64,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, AsBytes)]
+struct Instance {
+    _scale: [f32; 2],
+    _atlas_position: [f32; 2],
+    _atlas_scale: [f32; 2],
+    _layer: u32,
+}
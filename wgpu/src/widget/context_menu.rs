@@ -0,0 +1,16 @@
+//! Show a menu of actions when a widget is right clicked.
+//!
+//! A [`ContextMenu`] has some local [`State`].
+//!
+//! [`ContextMenu`]: type.ContextMenu.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::context_menu::{Entry, State, Style, StyleSheet};
+
+/// A widget opening a menu at the cursor position when its content is right
+/// clicked.
+///
+/// This is an alias of an `iced_native` context menu with an `iced_wgpu::Renderer`.
+pub type ContextMenu<'a, Message> =
+    iced_native::ContextMenu<'a, Message, Renderer>;
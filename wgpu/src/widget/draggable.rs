@@ -0,0 +1,17 @@
+//! Drag elements around the user interface and drop them into a
+//! [`DropTarget`].
+//!
+//! A [`Draggable`] has some local [`State`].
+//!
+//! [`Draggable`]: type.Draggable.html
+//! [`DropTarget`]: ../drop_target/type.DropTarget.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::draggable::State;
+
+/// A container that can be dragged around, carrying a payload.
+///
+/// This is an alias of an `iced_native` draggable with an `iced_wgpu::Renderer`.
+pub type Draggable<'a, Payload, Message> =
+    iced_native::Draggable<'a, Payload, Message, Renderer>;
@@ -0,0 +1,16 @@
+//! Accept files dropped into an area of the window.
+//!
+//! A [`FileDrop`] has some local [`State`].
+//!
+//! [`FileDrop`]: type.FileDrop.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::file_drop::State;
+
+/// A container that produces messages when files are hovered over or
+/// dropped into it.
+///
+/// This is an alias of an `iced_native` file drop with an
+/// `iced_wgpu::Renderer`.
+pub type FileDrop<'a, Message> = iced_native::FileDrop<'a, Message, Renderer>;
@@ -0,0 +1,14 @@
+//! Display a value from a range of values on a radial dial.
+//!
+//! A [`Gauge`] has a range of possible values and a current value, as well
+//! as tick marks, value labels, a size, and a style.
+//!
+//! [`Gauge`]: type.Gauge.html
+use crate::Renderer;
+
+pub use iced_graphics::gauge::{Style, StyleSheet};
+
+/// A radial dial with a needle pointing at a value from a range of values.
+///
+/// This is an alias of an `iced_native` gauge with an `iced_wgpu::Renderer`.
+pub type Gauge = iced_native::Gauge<Renderer>;
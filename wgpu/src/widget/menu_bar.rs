@@ -0,0 +1,14 @@
+//! Build desktop-style menu bars with dropdown menus.
+//!
+//! A [`MenuBar`] has some local [`State`].
+//!
+//! [`MenuBar`]: type.MenuBar.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::menu_bar::{menu, Menu, State, Style, StyleSheet};
+
+/// A horizontal bar of menus, each one opening a dropdown when clicked.
+///
+/// This is an alias of an `iced_native` menu bar with an `iced_wgpu::Renderer`.
+pub type MenuBar<'a, Message> = iced_native::MenuBar<'a, Message, Renderer>;
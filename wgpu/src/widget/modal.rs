@@ -0,0 +1,10 @@
+//! Show content on top of a dimmed user interface.
+use crate::Renderer;
+
+pub use iced_graphics::modal::{Style, StyleSheet};
+
+/// A widget showing some content centered on top of the rest of the user
+/// interface.
+///
+/// This is an alias of an `iced_native` modal with an `iced_wgpu::Renderer`.
+pub type Modal<'a, Message> = iced_native::Modal<'a, Message, Renderer>;
@@ -0,0 +1,15 @@
+//! Display a dropdown list of selectable values.
+//!
+//! A [`PickList`] has some local [`State`].
+//!
+//! [`PickList`]: type.PickList.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::pick_list::{menu, State, Style, StyleSheet};
+
+/// A widget allowing the selection of a single value from a list of options.
+///
+/// This is an alias of an `iced_native` pick list with an `iced_wgpu::Renderer`.
+pub type PickList<'a, T, Message> =
+    iced_native::PickList<'a, T, Message, Renderer>;
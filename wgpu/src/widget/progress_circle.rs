@@ -0,0 +1,15 @@
+//! Provide circular progress feedback to your users.
+//!
+//! A [`ProgressCircle`] has a range of possible values and a current value,
+//! as well as a size and style.
+//!
+//! [`ProgressCircle`]: type.ProgressCircle.html
+use crate::Renderer;
+
+pub use iced_graphics::progress_circle::{Style, StyleSheet};
+
+/// A ring that fills clockwise to display progress.
+///
+/// This is an alias of an `iced_native` progress circle with an
+/// `iced_wgpu::Renderer`.
+pub type ProgressCircle = iced_native::ProgressCircle<Renderer>;
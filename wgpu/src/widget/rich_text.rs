@@ -0,0 +1,10 @@
+//! Write a paragraph of text made of differently styled spans.
+use crate::Renderer;
+
+pub use iced_graphics::rich_text::{Fragment, Run, Span};
+
+/// A paragraph of text made of multiple spans, each one with its own style.
+///
+/// This is an alias of an `iced_native` rich text with an
+/// `iced_wgpu::Renderer`.
+pub type RichText<Message> = iced_native::RichText<Message, Renderer>;
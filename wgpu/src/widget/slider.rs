@@ -7,10 +7,9 @@
 use crate::Renderer;
 
 pub use iced_graphics::slider::{Handle, HandleShape, Style, StyleSheet};
-pub use iced_native::slider::State;
+pub use iced_native::slider::{Orientation, State};
 
-/// An horizontal bar and a handle that selects a single value from a range of
-/// values.
+/// A bar and a handle that selects a single value from a range of values.
 ///
 /// This is an alias of an `iced_native` slider with an `iced_wgpu::Renderer`.
 pub type Slider<'a, Message> = iced_native::Slider<'a, Message, Renderer>;
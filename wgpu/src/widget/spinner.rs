@@ -0,0 +1,13 @@
+//! Show an indeterminate loading indicator.
+//!
+//! A [`Spinner`] animates on its own while it is displayed.
+//!
+//! [`Spinner`]: type.Spinner.html
+use crate::Renderer;
+
+pub use iced_graphics::spinner::{Style, StyleSheet};
+
+/// A spinning arc that indicates that some work is in progress.
+///
+/// This is an alias of an `iced_native` spinner with an `iced_wgpu::Renderer`.
+pub type Spinner = iced_native::Spinner<Renderer>;
@@ -0,0 +1,17 @@
+//! Display data in rows and columns with a fixed header.
+//!
+//! A [`Table`] has some local [`State`].
+//!
+//! [`Table`]: type.Table.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::table::{
+    Column, Header, SortOrder, State, Style, StyleSheet,
+};
+
+/// A table with a fixed header row, resizable and sortable columns, and
+/// selectable rows.
+///
+/// This is an alias of an `iced_native` table with an `iced_wgpu::Renderer`.
+pub type Table<'a, Message> = iced_native::Table<'a, Message, Renderer>;
@@ -0,0 +1,15 @@
+//! Switch between different views with a strip of tabs.
+//!
+//! A [`Tabs`] widget has some local [`State`].
+//!
+//! [`Tabs`]: type.Tabs.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::tabs::{Header, State, Style, StyleSheet, Tab};
+
+/// A strip of tabs on top of the content of the active one.
+///
+/// This is an alias of an `iced_native` tabs widget with an
+/// `iced_wgpu::Renderer`.
+pub type Tabs<'a, Message> = iced_native::Tabs<'a, Message, Renderer>;
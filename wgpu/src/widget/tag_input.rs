@@ -0,0 +1,14 @@
+//! Type entries that become removable tags.
+//!
+//! A [`TagInput`] has some local [`State`].
+//!
+//! [`TagInput`]: type.TagInput.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::tag_input::{menu, State, Style, StyleSheet};
+
+/// A field where typed entries become tags, which can be removed.
+///
+/// This is an alias of an `iced_native` tag input with an `iced_wgpu::Renderer`.
+pub type TagInput<'a, Message> = iced_native::TagInput<'a, Message, Renderer>;
@@ -0,0 +1,12 @@
+//! Show transient notifications on top of the user interface.
+use crate::Renderer;
+
+pub use iced_graphics::toast::{
+    Banner, Corner, Notification, Severity, Style, StyleSheet,
+};
+
+/// A widget that stacks notification banners in a corner of the window, on
+/// top of its content.
+///
+/// This is an alias of an `iced_native` toast with an `iced_wgpu::Renderer`.
+pub type Toast<'a, Message> = iced_native::Toast<'a, Message, Renderer>;
@@ -0,0 +1,15 @@
+//! Show a label next to a widget when it is hovered.
+//!
+//! A [`Tooltip`] has some local [`State`].
+//!
+//! [`Tooltip`]: type.Tooltip.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::tooltip::{Position, State, Style, StyleSheet};
+
+/// A widget showing a label on top of the user interface while its content
+/// is hovered.
+///
+/// This is an alias of an `iced_native` tooltip with an `iced_wgpu::Renderer`.
+pub type Tooltip<'a, Message> = iced_native::Tooltip<'a, Message, Renderer>;
@@ -1,4 +1,6 @@
 //! Display rendering results on windows.
 mod compositor;
+mod screenshot;
 
 pub use compositor::Compositor;
+pub use screenshot::{ReadError, Screenshot};
@@ -1,6 +1,7 @@
+use crate::window::{ReadError, Screenshot};
 use crate::{Backend, Renderer, Settings};
 
-use iced_graphics::Viewport;
+use iced_graphics::{Theme, Viewport};
 use iced_native::{futures, mouse};
 use raw_window_handle::HasRawWindowHandle;
 
@@ -56,6 +57,160 @@ impl Compositor {
     pub fn create_backend(&self) -> Backend {
         Backend::new(&self.device, self.settings)
     }
+
+    /// Draws the given output in an offscreen target and reads back the
+    /// rendered pixels.
+    ///
+    /// The [`Screenshot`] has the physical size of the [`Viewport`]. This can
+    /// be useful to take screenshots, to sample colors, or to write visual
+    /// regression tests.
+    ///
+    /// Reading the pixels waits for the GPU to finish drawing the frame.
+    ///
+    /// [`Screenshot`]: struct.Screenshot.html
+    /// [`Viewport`]: ../struct.Viewport.html
+    pub async fn read_pixels<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Renderer,
+        viewport: &Viewport,
+        output: &<Renderer as iced_native::Renderer>::Output,
+        overlay: &[T],
+    ) -> Result<Screenshot, ReadError> {
+        let size = viewport.physical_size();
+
+        if size.width == 0 || size.height == 0 {
+            return Err(ReadError::EmptyViewport);
+        }
+
+        let extent = wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth: 1,
+        };
+
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: extent,
+            array_layer_count: 1,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.settings.format,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT
+                | wgpu::TextureUsage::COPY_SRC,
+        });
+
+        // Rows copied to a buffer need to be aligned to 256 bytes
+        let row_length = 4 * size.width;
+        let padded_row_length = (row_length + 255) & !255;
+        let buffer_size = u64::from(padded_row_length * size.height);
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: buffer_size,
+            usage: wgpu::BufferUsage::MAP_READ | wgpu::BufferUsage::COPY_DST,
+        });
+
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor { label: None },
+        );
+
+        let _ = self.render(
+            renderer,
+            &mut encoder,
+            &texture.create_default_view(),
+            viewport,
+            output,
+            overlay,
+        );
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TextureCopyView {
+                texture: &texture,
+                mip_level: 0,
+                array_layer: 0,
+                origin: wgpu::Origin3d::ZERO,
+            },
+            wgpu::BufferCopyView {
+                buffer: &buffer,
+                offset: 0,
+                bytes_per_row: padded_row_length,
+                rows_per_image: 0,
+            },
+            extent,
+        );
+
+        self.queue.submit(&[encoder.finish()]);
+
+        let mapping = buffer.map_read(0, buffer_size);
+        self.device.poll(wgpu::Maintain::Wait);
+
+        let mapping = mapping.await.map_err(|_| ReadError::MappingFailed)?;
+
+        let is_bgra = matches!(
+            self.settings.format,
+            wgpu::TextureFormat::Bgra8Unorm
+                | wgpu::TextureFormat::Bgra8UnormSrgb
+        );
+
+        let mut pixels =
+            Vec::with_capacity((row_length * size.height) as usize);
+
+        for row in mapping.as_slice().chunks(padded_row_length as usize) {
+            for pixel in row[..row_length as usize].chunks(4) {
+                if is_bgra {
+                    pixels.extend_from_slice(&[
+                        pixel[2], pixel[1], pixel[0], pixel[3],
+                    ]);
+                } else {
+                    pixels.extend_from_slice(pixel);
+                }
+            }
+        }
+
+        Ok(Screenshot {
+            width: size.width,
+            height: size.height,
+            rgba: pixels,
+        })
+    }
+
+    fn render<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Renderer,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        viewport: &Viewport,
+        output: &<Renderer as iced_native::Renderer>::Output,
+        overlay: &[T],
+    ) -> mouse::Interaction {
+        let background = renderer.theme().palette().background;
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                attachment: target,
+                resolve_target: None,
+                load_op: wgpu::LoadOp::Clear,
+                store_op: wgpu::StoreOp::Store,
+                clear_color: wgpu::Color {
+                    r: f64::from(background.r),
+                    g: f64::from(background.g),
+                    b: f64::from(background.b),
+                    a: f64::from(background.a),
+                },
+            }],
+            depth_stencil_attachment: None,
+        });
+
+        renderer.backend_mut().draw(
+            &self.device,
+            encoder,
+            target,
+            viewport,
+            output,
+            overlay,
+        )
+    }
 }
 
 impl iced_graphics::window::Compositor for Compositor {
@@ -98,6 +253,10 @@ impl iced_graphics::window::Compositor for Compositor {
         )
     }
 
+    fn set_theme(&mut self, renderer: &mut Renderer, theme: Theme) {
+        renderer.set_theme(theme);
+    }
+
     fn draw<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -112,24 +271,8 @@ impl iced_graphics::window::Compositor for Compositor {
             &wgpu::CommandEncoderDescriptor { label: None },
         );
 
-        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
-                attachment: &frame.view,
-                resolve_target: None,
-                load_op: wgpu::LoadOp::Clear,
-                store_op: wgpu::StoreOp::Store,
-                clear_color: wgpu::Color {
-                    r: 1.0,
-                    g: 1.0,
-                    b: 1.0,
-                    a: 1.0,
-                },
-            }],
-            depth_stencil_attachment: None,
-        });
-
-        let mouse_interaction = renderer.backend_mut().draw(
-            &mut self.device,
+        let mouse_interaction = self.render(
+            renderer,
             &mut encoder,
             &frame.view,
             viewport,
@@ -0,0 +1,80 @@
+use iced_native::image;
+
+/// The pixels of a frame read back with [`Compositor::read_pixels`].
+///
+/// [`Compositor::read_pixels`]: struct.Compositor.html#method.read_pixels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screenshot {
+    /// The width of the frame, in physical pixels.
+    pub width: u32,
+
+    /// The height of the frame, in physical pixels.
+    pub height: u32,
+
+    /// The pixels of the frame in RGBA format, row by row.
+    pub rgba: Vec<u8>,
+}
+
+impl From<Screenshot> for image::Handle {
+    fn from(screenshot: Screenshot) -> image::Handle {
+        // Image handles store their pixels in BGRA
+        let mut pixels = screenshot.rgba;
+
+        for pixel in pixels.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+
+        image::Handle::from_pixels(screenshot.width, screenshot.height, pixels)
+    }
+}
+
+/// An error that occurred when reading back the pixels of a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadError {
+    /// The viewport has no pixels to read.
+    EmptyViewport,
+
+    /// The rendered pixels could not be mapped to memory.
+    MappingFailed,
+}
+
+impl std::fmt::Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::EmptyViewport => write!(f, "the viewport is empty"),
+            ReadError::MappingFailed => {
+                write!(f, "the rendered pixels could not be mapped")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_into_bgra_image() {
+        let screenshot = Screenshot {
+            width: 1,
+            height: 1,
+            rgba: vec![1, 2, 3, 4],
+        };
+
+        let handle = image::Handle::from(screenshot);
+
+        match handle.data() {
+            image::Data::Pixels {
+                width,
+                height,
+                pixels,
+            } => {
+                assert_eq!((*width, *height), (1, 1));
+                assert_eq!(pixels, &[3, 2, 1, 4]);
+            }
+            _ => panic!("Expected pixels"),
+        }
+    }
+}
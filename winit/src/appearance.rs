@@ -0,0 +1,29 @@
+use iced_native::system::Appearance;
+
+/// Detects the [`Appearance`] of the system, if the window reports it.
+///
+/// Only Windows reports it, along with its changes. Elsewhere, this returns
+/// `None` and [`system::appearance`] watches the settings of the desktop in a
+/// background thread instead.
+///
+/// [`Appearance`]: ../system/enum.Appearance.html
+/// [`system::appearance`]: ../system/fn.appearance.html
+pub fn detect_appearance(window: &winit::window::Window) -> Option<Appearance> {
+    detect(window)
+}
+
+#[cfg(target_os = "windows")]
+fn detect(window: &winit::window::Window) -> Option<Appearance> {
+    use winit::platform::windows::WindowExtWindows;
+
+    Some(if window.is_dark_mode() {
+        Appearance::Dark
+    } else {
+        Appearance::Light
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+fn detect(_window: &winit::window::Window) -> Option<Appearance> {
+    None
+}
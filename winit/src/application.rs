@@ -1,12 +1,17 @@
 //! Create interactive, native cross-platform applications.
 use crate::{
-    conversion, mouse, Clipboard, Command, Debug, Executor, Mode, Proxy,
-    Runtime, Settings, Size, Subscription,
+    conversion, mouse, Clipboard, Command, Debug, Drag, Executor, Mode, Proxy,
+    Runtime, Settings, Size, Subscription, SystemTray, Theme,
 };
 use iced_graphics::window;
 use iced_graphics::Viewport;
 use iced_native::program::{self, Program};
 
+use std::borrow::Cow;
+
+pub use crate::appearance::detect_appearance;
+pub use crate::notifier::show_notification;
+
 /// An interactive, native cross-platform application.
 ///
 /// This trait is the main entrypoint of Iced. Once implemented, you can run
@@ -73,6 +78,28 @@ pub trait Application: Program {
     fn mode(&self) -> Mode {
         Mode::Windowed
     }
+
+    /// Returns the current [`Theme`] of the [`Application`].
+    ///
+    /// The built-in widgets pick their default colors from it. The runtime
+    /// redraws the user interface if a new theme is returned.
+    ///
+    /// By default, it returns the light theme.
+    ///
+    /// [`Theme`]: theme/enum.Theme.html
+    /// [`Application`]: trait.Application.html
+    fn theme(&self) -> Theme {
+        Theme::Light
+    }
+
+    /// Returns whether the [`Application`] should exit.
+    ///
+    /// It is checked after every update. By default, it returns `false`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn should_exit(&self) -> bool {
+        false
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -95,6 +122,8 @@ pub fn run<A, E, C>(
     let mut debug = Debug::new();
     debug.startup_started();
 
+    mouse::click::configure(settings.resolve_double_click());
+
     let event_loop = EventLoop::with_user_event();
     let mut runtime = {
         let executor = E::new().expect("Create executor");
@@ -104,7 +133,9 @@ pub fn run<A, E, C>(
     };
 
     let flags = settings.flags;
-    let (application, init_command) = runtime.enter(|| A::new(flags));
+    let (application, mut init_command) = runtime.enter(|| A::new(flags));
+    let init_font_actions = init_command.take_font_actions();
+    let mut init_tray_actions = init_command.take_tray_actions();
     runtime.spawn(init_command);
 
     let subscription = application.subscription();
@@ -112,26 +143,52 @@ pub fn run<A, E, C>(
 
     let mut title = application.title();
     let mut mode = application.mode();
+    let mut theme = application.theme();
 
     let window = settings
         .window
+        .clone()
         .into_builder(&title, mode, event_loop.primary_monitor())
         .build(&event_loop)
         .expect("Open window");
 
+    settings.window.place(&window);
+
+    let mut appearance = detect_appearance(&window);
+
+    if let Some(appearance) = appearance {
+        runtime.broadcast(iced_native::Event::Window(
+            iced_native::window::Event::AppearanceChanged(appearance),
+        ));
+    }
+
     let clipboard = Clipboard::new(&window);
+    let proxy = event_loop.create_proxy();
+    let mut system_tray = SystemTray::new(event_loop.create_proxy());
     let mut mouse_interaction = mouse::Interaction::default();
     let mut modifiers = winit::event::ModifiersState::default();
 
     let physical_size = window.inner_size();
     let mut viewport = Viewport::with_physical_size(
         Size::new(physical_size.width, physical_size.height),
-        window.scale_factor(),
+        window.scale_factor() * settings.window.scale_factor,
     );
+    let mut scale_factor = settings.window.scale_factor;
     let mut resized = false;
+    let mut is_cursor_grabbed = false;
+    let mut drag = Drag::new();
+    let close_on_request = settings.window.close_on_request;
 
     let (mut compositor, mut renderer) = C::new(compositor_settings);
 
+    compositor.set_theme(&mut renderer, theme);
+
+    load_fonts(&mut renderer, settings.fonts);
+
+    for action in init_font_actions {
+        let _ = proxy.send_event(handle_font_action(action, &mut renderer));
+    }
+
     let surface = compositor.create_surface(&window);
 
     let mut swap_chain = compositor.create_swap_chain(
@@ -148,7 +205,29 @@ pub fn run<A, E, C>(
     );
     debug.startup_finished();
 
+    let mut redraw_at = None;
+
     event_loop.run(move |event, _, control_flow| match event {
+        event::Event::NewEvents(event::StartCause::Init) => {
+            // Some platforms can only show a tray once the event loop runs
+            for action in init_tray_actions.drain(..) {
+                system_tray.perform(action);
+            }
+        }
+        event::Event::NewEvents(event::StartCause::ResumeTimeReached {
+            ..
+        }) => {
+            // A widget requested a frame for this instant
+            if redraw_at.take().is_some() {
+                state.queue_event(iced_native::Event::Window(
+                    iced_native::window::Event::RedrawRequested(
+                        std::time::Instant::now(),
+                    ),
+                ));
+
+                window.request_redraw();
+            }
+        }
         event::Event::MainEventsCleared => {
             let command = runtime.enter(|| {
                 state.update(
@@ -160,7 +239,70 @@ pub fn run<A, E, C>(
             });
 
             // If the application was updated
-            if let Some(command) = command {
+            if let Some(mut command) = command {
+                for action in command.take_window_actions() {
+                    if !targets_main_window(&action) {
+                        continue;
+                    }
+
+                    if let iced_native::window::Action::SetScaleFactor(
+                        _,
+                        new_scale_factor,
+                    ) = action
+                    {
+                        scale_factor = new_scale_factor;
+
+                        let event = scale_viewport(
+                            &window,
+                            scale_factor,
+                            &mut viewport,
+                            &mut resized,
+                        );
+
+                        state.relayout(
+                            viewport.logical_size(),
+                            &mut renderer,
+                            &mut debug,
+                        );
+
+                        runtime.broadcast(event);
+                        continue;
+                    }
+
+                    if let Some(event) = handle_window_action(
+                        action,
+                        &window,
+                        &proxy,
+                        control_flow,
+                        &mut is_cursor_grabbed,
+                        &mut drag,
+                    ) {
+                        state.queue_event(event.clone());
+                        runtime.broadcast(event);
+                    }
+                }
+
+                for action in command.take_notifications() {
+                    runtime.spawn(show_notification(action));
+                }
+
+                for action in command.take_tray_actions() {
+                    system_tray.perform(action);
+                }
+
+                for action in command.take_clipboard_actions() {
+                    handle_clipboard_action(
+                        action,
+                        clipboard.as_ref(),
+                        proxy.clone(),
+                    );
+                }
+
+                for action in command.take_font_actions() {
+                    let _ = proxy
+                        .send_event(handle_font_action(action, &mut renderer));
+                }
+
                 runtime.spawn(command);
 
                 let program = state.program();
@@ -189,6 +331,25 @@ pub fn run<A, E, C>(
 
                     mode = new_mode;
                 }
+
+                if program.should_exit() {
+                    *control_flow = ControlFlow::Exit;
+                }
+
+                // Update theme
+                let new_theme = program.theme();
+
+                if theme != new_theme {
+                    compositor.set_theme(&mut renderer, new_theme);
+
+                    state.relayout(
+                        viewport.logical_size(),
+                        &mut renderer,
+                        &mut debug,
+                    );
+
+                    theme = new_theme;
+                }
             }
 
             window.request_redraw();
@@ -229,17 +390,52 @@ pub fn run<A, E, C>(
                 mouse_interaction = new_mouse_interaction;
             }
 
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
+            let frame = iced_native::Event::Window(
+                iced_native::window::Event::RedrawRequested(
+                    std::time::Instant::now(),
+                ),
+            );
+
+            // Widgets with built-in animations keep requesting frames while
+            // they are drawn, either right away or at a later instant
+            redraw_at =
+                iced_native::Renderer::take_redraw_request(&mut renderer);
+
+            if matches!(redraw_at, Some(at) if at <= std::time::Instant::now())
+            {
+                redraw_at = None;
+
+                state.queue_event(frame.clone());
+                window.request_redraw();
+            }
+
+            // Subscriptions to `window::frames` will produce a message,
+            // which will trigger another redraw
+            runtime.broadcast(frame);
         }
         event::Event::WindowEvent {
             event: window_event,
             ..
         } => {
+            if let event::WindowEvent::CloseRequested = window_event {
+                if close_on_request {
+                    *control_flow = ControlFlow::Exit;
+                    return;
+                }
+            }
+
+            drag.handle_window_event(&window_event, &window);
+
+            if let Some(event) =
+                handle_appearance(&window_event, &mut appearance)
+            {
+                state.queue_event(event.clone());
+                runtime.broadcast(event);
+            }
+
             handle_window_event(
                 &window_event,
-                &window,
-                control_flow,
+                scale_factor,
                 &mut modifiers,
                 &mut viewport,
                 &mut resized,
@@ -255,35 +451,335 @@ pub fn run<A, E, C>(
                 runtime.broadcast(event);
             }
         }
+        event::Event::DeviceEvent {
+            event: device_event,
+            ..
+        } if is_cursor_grabbed => {
+            if let Some(event) = conversion::device_event(&device_event) {
+                state.queue_event(event.clone());
+                runtime.broadcast(event);
+            }
+        }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            *control_flow = match redraw_at {
+                Some(at) => ControlFlow::WaitUntil(at),
+                None => ControlFlow::Wait,
+            };
         }
     })
 }
 
-/// Handles a `WindowEvent` and mutates the provided control flow, keyboard
-/// modifiers, viewport, and resized flag accordingly.
-pub fn handle_window_event(
+/// Keeps track of the [`Appearance`] of the system, producing an `Event` when
+/// it changes.
+///
+/// Only Windows reports changes through the window. Elsewhere, they are
+/// watched by [`system::appearance`] on its own.
+///
+/// [`Appearance`]: ../system/enum.Appearance.html
+/// [`system::appearance`]: ../system/fn.appearance.html
+pub fn handle_appearance(
     event: &winit::event::WindowEvent<'_>,
+    appearance: &mut Option<iced_native::system::Appearance>,
+) -> Option<iced_native::Event> {
+    let new_appearance = match event {
+        winit::event::WindowEvent::ThemeChanged(theme) => {
+            conversion::appearance(theme)
+        }
+        _ => return None,
+    };
+
+    if *appearance == Some(new_appearance) {
+        return None;
+    }
+
+    *appearance = Some(new_appearance);
+
+    Some(iced_native::Event::Window(
+        iced_native::window::Event::AppearanceChanged(new_appearance),
+    ))
+}
+
+/// Performs a [`window::Action`] on the provided window, keeping track of
+/// whether the cursor is grabbed and starting any [`Drag`].
+///
+/// Queries produce a message with their results, which is sent through the
+/// provided event loop proxy. Failing to spawn a window produces an `Event`
+/// instead, which should be processed like any other event.
+///
+/// The action is performed regardless of the window it targets. Use
+/// [`targets_main_window`] to discard the actions of other windows in
+/// single-window applications.
+///
+/// Closing the window exits the application, while spawning windows is
+/// ignored, as it is only supported by multi-window applications.
+///
+/// [`window::Action`]: ../window/enum.Action.html
+/// [`Drag`]: ../struct.Drag.html
+/// [`targets_main_window`]: fn.targets_main_window.html
+pub fn handle_window_action<Message: 'static>(
+    action: iced_native::window::Action<Message>,
     window: &winit::window::Window,
+    proxy: &winit::event_loop::EventLoopProxy<Message>,
     control_flow: &mut winit::event_loop::ControlFlow,
+    is_cursor_grabbed: &mut bool,
+    drag: &mut Drag,
+) -> Option<iced_native::Event> {
+    use iced_native::window::Action;
+    use winit::event_loop::ControlFlow;
+
+    match action {
+        Action::SetCursorVisible(_, is_visible) => {
+            window.set_cursor_visible(is_visible);
+        }
+        Action::SetCursorGrab(_, is_grabbed) => {
+            // Grabbing is not supported on every platform
+            if window.set_cursor_grab(is_grabbed).is_ok() {
+                *is_cursor_grabbed = is_grabbed;
+            }
+        }
+        Action::Resize { width, height, .. } => {
+            window.set_inner_size(winit::dpi::LogicalSize { width, height });
+        }
+        Action::Move { x, y, .. } => {
+            window.set_outer_position(winit::dpi::LogicalPosition { x, y });
+        }
+        Action::Drag(_) => {
+            drag.start(window, None);
+        }
+        Action::DragResize(_, direction) => {
+            drag.start(window, Some(direction));
+        }
+        Action::Center(_) => {
+            crate::settings::center(window);
+        }
+        Action::SetMinSize(_, min_size) => {
+            window.set_min_inner_size(min_size.map(|(width, height)| {
+                winit::dpi::LogicalSize { width, height }
+            }));
+        }
+        Action::SetMaxSize(_, max_size) => {
+            window.set_max_inner_size(max_size.map(|(width, height)| {
+                winit::dpi::LogicalSize { width, height }
+            }));
+        }
+        Action::SetResizable(_, is_resizable) => {
+            window.set_resizable(is_resizable);
+        }
+        Action::SetAlwaysOnTop(_, is_always_on_top) => {
+            window.set_always_on_top(is_always_on_top);
+        }
+        Action::Minimize(_, is_minimized) => {
+            window.set_minimized(is_minimized);
+        }
+        Action::Maximize(_, is_maximized) => {
+            window.set_maximized(is_maximized);
+        }
+        Action::SetFullscreen(_, is_fullscreen) => {
+            window.set_fullscreen(if is_fullscreen {
+                Some(winit::window::Fullscreen::Borderless(
+                    window.current_monitor(),
+                ))
+            } else {
+                None
+            });
+        }
+        Action::SetDecorations(_, has_decorations) => {
+            window.set_decorations(has_decorations);
+        }
+        Action::SetVisible(_, is_visible) => {
+            window.set_visible(is_visible);
+        }
+        Action::RequestAttention(_) => {
+            #[cfg(target_os = "macos")]
+            {
+                use winit::platform::macos::{
+                    RequestUserAttentionType, WindowExtMacOS,
+                };
+
+                window.request_user_attention(
+                    RequestUserAttentionType::Informational,
+                );
+            }
+        }
+        Action::Close(_) | Action::Exit => {
+            *control_flow = ControlFlow::Exit;
+        }
+        Action::SetScaleFactor(..) => {
+            // Handled by the shell, as it changes the viewport
+        }
+        Action::FetchMonitors(f) => {
+            let current = window.current_monitor();
+            let monitors = window
+                .available_monitors()
+                .map(|monitor| conversion::monitor(&monitor, &current))
+                .collect();
+
+            let _ = proxy.send_event(f(monitors));
+        }
+        Action::Spawn { id, .. } => {
+            log::warn!("Only multi-window applications can open new windows");
+
+            return Some(iced_native::Event::Window(
+                iced_native::window::Event::SpawnFailed(id),
+            ));
+        }
+    }
+
+    None
+}
+
+/// Returns whether the given [`window::Action`] can be performed by a
+/// single-window application.
+///
+/// Actions targeting any window other than [`window::Id::MAIN`] are logged and
+/// discarded.
+///
+/// [`window::Action`]: ../window/enum.Action.html
+/// [`window::Id::MAIN`]: ../window/struct.Id.html#associatedconstant.MAIN
+pub fn targets_main_window<Message>(
+    action: &iced_native::window::Action<Message>,
+) -> bool {
+    match action.window() {
+        Some(id) if id != iced_native::window::Id::MAIN => {
+            log::warn!("Window {:?} does not exist: {:?}", id, action);
+
+            false
+        }
+        _ => true,
+    }
+}
+
+/// Performs a [`clipboard::Action`] with the provided [`Clipboard`], if
+/// available.
+///
+/// Reading the clipboard happens in the background. Once its contents are
+/// available, the message produced with them is sent through the given proxy.
+///
+/// [`clipboard::Action`]: ../clipboard/enum.Action.html
+/// [`Clipboard`]: ../struct.Clipboard.html
+pub fn handle_clipboard_action<Message: Send + 'static>(
+    action: iced_native::clipboard::Action<Message>,
+    clipboard: Option<&Clipboard>,
+    proxy: winit::event_loop::EventLoopProxy<Message>,
+) {
+    use iced_native::clipboard::Action;
+    use iced_native::Clipboard as _;
+
+    match action {
+        Action::Read(flavor, f) => match clipboard {
+            Some(clipboard) => clipboard.read_with(&flavor, move |contents| {
+                let _ = proxy.send_event(f(contents));
+            }),
+            None => {
+                let _ = proxy.send_event(f(None));
+            }
+        },
+        Action::Write(flavor, contents) => {
+            if let Some(clipboard) = clipboard {
+                clipboard.write_as(&flavor, contents);
+            }
+        }
+    }
+}
+
+/// Registers the given fonts with the provided renderer, logging the ones
+/// that fail to load.
+pub fn load_fonts<Renderer>(
+    renderer: &mut Renderer,
+    fonts: Vec<Cow<'static, [u8]>>,
+) where
+    Renderer: iced_native::Renderer,
+{
+    for font in fonts {
+        if let Err(error) = renderer.load_font(font) {
+            log::warn!("Failed to load font: {}", error);
+        }
+    }
+}
+
+/// Performs a [`font::Action`] with the provided renderer.
+///
+/// The result of loading the font produces a message, which is returned.
+///
+/// [`font::Action`]: ../font/enum.Action.html
+pub fn handle_font_action<Message, Renderer>(
+    action: iced_native::font::Action<Message>,
+    renderer: &mut Renderer,
+) -> Message
+where
+    Renderer: iced_native::Renderer,
+{
+    use iced_native::font::{Action, Error};
+
+    match action {
+        Action::Load(bytes, f) => f(renderer.load_font(bytes)),
+        Action::LoadFromPath(path, f) => f(std::fs::read(&path)
+            .map_err(|error| Error::Io(error.kind()))
+            .and_then(|bytes| renderer.load_font(Cow::Owned(bytes)))),
+    }
+}
+
+/// Scales the viewport of a window by the given factor, on top of the scale
+/// factor of the system.
+///
+/// The user interface needs to be laid out again afterwards. The `Resized`
+/// event returned can be used to notify subscriptions of the new logical size.
+pub fn scale_viewport(
+    window: &winit::window::Window,
+    scale_factor: f64,
+    viewport: &mut Viewport,
+    resized: &mut bool,
+) -> iced_native::Event {
+    *viewport = Viewport::with_physical_size(
+        viewport.physical_size(),
+        window.scale_factor() * scale_factor,
+    );
+    *resized = true;
+
+    let logical_size = viewport.logical_size();
+
+    iced_native::Event::Window(iced_native::window::Event::Resized {
+        width: logical_size.width as u32,
+        height: logical_size.height as u32,
+    })
+}
+
+/// Handles a `WindowEvent` and mutates the keyboard modifiers, viewport, and
+/// resized flag accordingly.
+///
+/// The user interface is scaled by the given scale factor, on top of the
+/// scale factor of the system.
+///
+/// Close requests are left to the caller, as they may be intercepted.
+pub fn handle_window_event(
+    event: &winit::event::WindowEvent<'_>,
+    scale_factor: f64,
     modifiers: &mut winit::event::ModifiersState,
     viewport: &mut Viewport,
     resized: &mut bool,
     _debug: &mut Debug,
 ) {
-    use winit::{event::WindowEvent, event_loop::ControlFlow};
+    use winit::event::WindowEvent;
 
     match event {
         WindowEvent::Resized(new_size) => {
             let size = Size::new(new_size.width, new_size.height);
 
             *viewport =
-                Viewport::with_physical_size(size, window.scale_factor());
+                Viewport::with_physical_size(size, viewport.scale_factor());
             *resized = true;
         }
-        WindowEvent::CloseRequested => {
-            *control_flow = ControlFlow::Exit;
+        WindowEvent::ScaleFactorChanged {
+            scale_factor: system_scale_factor,
+            new_inner_size,
+        } => {
+            let size = Size::new(new_inner_size.width, new_inner_size.height);
+
+            *viewport = Viewport::with_physical_size(
+                size,
+                system_scale_factor * scale_factor,
+            );
+            *resized = true;
         }
         WindowEvent::ModifiersChanged(new_modifiers) => {
             *modifiers = *new_modifiers;
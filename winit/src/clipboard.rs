@@ -1,19 +1,226 @@
+//! Access the clipboard of the system.
+pub use iced_native::clipboard::{
+    read, read_as, write, write_as, Action, Flavor,
+};
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::sync::mpsc;
+use std::thread;
+
 /// A buffer for short-term storage and transfer within and between
 /// applications.
+///
+/// The clipboard of the system is accessed through [`arboard`], in a
+/// background thread, so reading it never blocks the event loop.
+///
+/// [`arboard`]: https://github.com/1Password/arboard
 #[allow(missing_debug_implementations)]
-pub struct Clipboard(window_clipboard::Clipboard);
+pub struct Clipboard {
+    requests: mpsc::Sender<Request>,
+}
+
+enum Request {
+    Read(Flavor, Box<dyn FnOnce(Option<Vec<u8>>) + Send>),
+    Write(Flavor, Vec<u8>),
+}
 
 impl Clipboard {
     /// Creates a new [`Clipboard`] for the given window.
     ///
+    /// Returns `None` if the clipboard of the system is not available.
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    pub fn new(_window: &winit::window::Window) -> Option<Clipboard> {
+        let (requests, receiver) = mpsc::channel::<Request>();
+        let (ready, is_ready) = mpsc::channel();
+
+        // The clipboard is created in the thread that uses it, as some
+        // platforms do not allow sharing it across threads
+        let _ = thread::spawn(move || {
+            let mut clipboard = match arboard::Clipboard::new() {
+                Ok(clipboard) => {
+                    let _ = ready.send(Ok(()));
+
+                    clipboard
+                }
+                Err(error) => {
+                    let _ = ready.send(Err(error));
+
+                    return;
+                }
+            };
+
+            // The thread stops once the `Clipboard` is dropped
+            for request in receiver {
+                match request {
+                    Request::Read(flavor, on_read) => on_read(
+                        read_flavor(&mut clipboard, &flavor)
+                            .map_err(|error| {
+                                log::warn!("Clipboard read failed: {}", error)
+                            })
+                            .ok(),
+                    ),
+                    Request::Write(flavor, contents) => {
+                        if let Err(error) =
+                            write_flavor(&mut clipboard, &flavor, contents)
+                        {
+                            log::warn!("Clipboard write failed: {}", error);
+                        }
+                    }
+                }
+            }
+        });
+
+        match is_ready.recv() {
+            Ok(Ok(())) => Some(Clipboard { requests }),
+            Ok(Err(error)) => {
+                log::warn!("Clipboard unavailable: {}", error);
+
+                None
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Reads the contents of the [`Clipboard`] in the given [`Flavor`]
+    /// without blocking, calling the given function once they are available.
+    ///
     /// [`Clipboard`]: struct.Clipboard.html
-    pub fn new(window: &winit::window::Window) -> Option<Clipboard> {
-        window_clipboard::Clipboard::new(window).map(Clipboard).ok()
+    /// [`Flavor`]: enum.Flavor.html
+    pub fn read_with(
+        &self,
+        flavor: &Flavor,
+        on_read: impl FnOnce(Option<Vec<u8>>) + Send + 'static,
+    ) {
+        if let Err(mpsc::SendError(Request::Read(_, on_read))) = self
+            .requests
+            .send(Request::Read(flavor.clone(), Box::new(on_read)))
+        {
+            on_read(None);
+        }
     }
 }
 
 impl iced_native::Clipboard for Clipboard {
     fn content(&self) -> Option<String> {
-        self.0.read().ok()
+        let (sender, receiver) = mpsc::channel();
+
+        // Widgets need the text right away to paste it
+        self.read_with(&Flavor::Text, move |contents| {
+            let _ = sender.send(contents);
+        });
+
+        receiver
+            .recv()
+            .ok()
+            .flatten()
+            .and_then(|contents| String::from_utf8(contents).ok())
+    }
+
+    fn write(&self, contents: String) {
+        self.write_as(&Flavor::Text, contents.into_bytes())
+    }
+
+    fn content_as(
+        &self,
+        flavor: &Flavor,
+        on_read: Box<dyn FnOnce(Option<Vec<u8>>) + Send>,
+    ) {
+        self.read_with(flavor, on_read)
+    }
+
+    fn write_as(&self, flavor: &Flavor, contents: Vec<u8>) {
+        let _ = self.requests.send(Request::Write(flavor.clone(), contents));
+    }
+}
+
+fn read_flavor(
+    clipboard: &mut arboard::Clipboard,
+    flavor: &Flavor,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    match flavor {
+        Flavor::Text => Ok(clipboard.get_text()?.into_bytes()),
+        Flavor::Html => Ok(clipboard.get().html()?.into_bytes()),
+        Flavor::Image => encode_png(clipboard.get_image()?),
+        Flavor::Custom(_) => {
+            Err(format!("{:?} flavor not supported", flavor).into())
+        }
+    }
+}
+
+fn write_flavor(
+    clipboard: &mut arboard::Clipboard,
+    flavor: &Flavor,
+    contents: Vec<u8>,
+) -> Result<(), Box<dyn Error>> {
+    match flavor {
+        Flavor::Text => Ok(clipboard.set_text(String::from_utf8(contents)?)?),
+        Flavor::Html => {
+            Ok(clipboard.set_html(String::from_utf8(contents)?, None)?)
+        }
+        Flavor::Image => Ok(clipboard.set_image(decode_png(&contents)?)?),
+        Flavor::Custom(_) => {
+            Err(format!("{:?} flavor not supported", flavor).into())
+        }
+    }
+}
+
+/// Encodes an image of the clipboard as the PNG contents of
+/// [`Flavor::Image`].
+///
+/// [`Flavor::Image`]: enum.Flavor.html#variant.Image
+fn encode_png(
+    image: arboard::ImageData<'_>,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut png = Vec::new();
+
+    image::png::PngEncoder::new(&mut png).encode(
+        &image.bytes,
+        image.width as u32,
+        image.height as u32,
+        image::ColorType::Rgba8,
+    )?;
+
+    Ok(png)
+}
+
+/// Decodes the PNG contents of [`Flavor::Image`] into an image for the
+/// clipboard.
+///
+/// [`Flavor::Image`]: enum.Flavor.html#variant.Image
+fn decode_png(
+    png: &[u8],
+) -> Result<arboard::ImageData<'static>, Box<dyn Error>> {
+    let image =
+        image::load_from_memory_with_format(png, image::ImageFormat::Png)?
+            .to_rgba8();
+
+    Ok(arboard::ImageData {
+        width: image.width() as usize,
+        height: image.height() as usize,
+        bytes: Cow::Owned(image.into_raw()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_images_to_and_from_png() {
+        let pixels = vec![255, 0, 0, 255, 0, 0, 255, 128];
+
+        let png = encode_png(arboard::ImageData {
+            width: 2,
+            height: 1,
+            bytes: Cow::Borrowed(&pixels),
+        })
+        .expect("Encode PNG");
+
+        let image = decode_png(&png).expect("Decode PNG");
+
+        assert_eq!((image.width, image.height), (2, 1));
+        assert_eq!(image.bytes.as_ref(), pixels.as_slice());
     }
 }
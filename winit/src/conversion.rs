@@ -4,7 +4,7 @@
 //! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
 use crate::{
     keyboard::{self, KeyCode, ModifiersState},
-    mouse, window, Event, Mode,
+    mouse, stylus, system, touch, window, Event, Mode, Point, Vector,
 };
 
 /// Converts a winit window event into an iced event.
@@ -24,6 +24,14 @@ pub fn window_event(
                 height: logical_size.height,
             }))
         }
+        WindowEvent::Moved(position) => {
+            let position = position.to_logical::<i32>(scale_factor);
+
+            Some(Event::Window(window::Event::Moved {
+                x: position.x,
+                y: position.y,
+            }))
+        }
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical::<f64>(scale_factor);
 
@@ -92,6 +100,26 @@ pub fn window_event(
                 }
             }
         })),
+        WindowEvent::CloseRequested => {
+            Some(Event::Window(window::Event::CloseRequested))
+        }
+        WindowEvent::ScaleFactorChanged { scale_factor, .. } => Some(
+            Event::Window(window::Event::ScaleFactorChanged(*scale_factor)),
+        ),
+        WindowEvent::Focused(focused) => Some(Event::Window(if *focused {
+            window::Event::Focused
+        } else {
+            window::Event::Unfocused
+        })),
+        WindowEvent::ModifiersChanged(new_modifiers) => Some(Event::Keyboard(
+            keyboard::Event::ModifiersChanged(modifiers_state(*new_modifiers)),
+        )),
+        WindowEvent::Touch(touch) => {
+            Some(match stylus_event(*touch, scale_factor) {
+                Some(stylus_event) => Event::Stylus(stylus_event),
+                None => Event::Touch(touch_event(*touch, scale_factor)),
+            })
+        }
         WindowEvent::HoveredFile(path) => {
             Some(Event::Window(window::Event::FileHovered(path.clone())))
         }
@@ -105,6 +133,20 @@ pub fn window_event(
     }
 }
 
+/// Converts a winit device event into an iced event.
+pub fn device_event(event: &winit::event::DeviceEvent) -> Option<Event> {
+    use winit::event::DeviceEvent;
+
+    match event {
+        DeviceEvent::MouseMotion { delta: (x, y) } => {
+            Some(Event::Mouse(mouse::Event::RawMotion {
+                delta: Vector::new(*x as f32, *y as f32),
+            }))
+        }
+        _ => None,
+    }
+}
+
 /// Converts a [`Mode`] to a [`winit`] fullscreen mode.
 ///
 /// [`Mode`]: ../enum.Mode.html
@@ -121,6 +163,60 @@ pub fn fullscreen(
     }
 }
 
+/// Converts a [`winit`] theme to an [`Appearance`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`Appearance`]: ../system/enum.Appearance.html
+pub fn appearance(theme: &winit::window::Theme) -> system::Appearance {
+    match theme {
+        winit::window::Theme::Light => system::Appearance::Light,
+        winit::window::Theme::Dark => system::Appearance::Dark,
+    }
+}
+
+/// Converts a [`winit`] monitor handle to a [`Monitor`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`Monitor`]: ../window/struct.Monitor.html
+pub fn monitor(
+    handle: &winit::monitor::MonitorHandle,
+    current: &winit::monitor::MonitorHandle,
+) -> window::Monitor {
+    let position = handle.position();
+    let size = handle.size();
+
+    // The current refresh rate is unknown, as only the video modes of the
+    // monitor are available, so we report the fastest one matching its
+    // resolution
+    let refresh_rate = handle
+        .video_modes()
+        .filter(|mode| mode.size() == size)
+        .map(|mode| mode.refresh_rate())
+        .max();
+
+    window::Monitor {
+        name: handle.name(),
+        position: (position.x, position.y),
+        size: (size.width, size.height),
+        scale_factor: handle.scale_factor(),
+        refresh_rate,
+        is_current: handle == current,
+    }
+}
+
+/// Converts a window [`Icon`] to a [`winit`] icon.
+///
+/// [`Icon`]: ../window/struct.Icon.html
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn icon(icon: &window::Icon) -> Option<winit::window::Icon> {
+    winit::window::Icon::from_rgba(
+        icon.rgba().to_vec(),
+        icon.width(),
+        icon.height(),
+    )
+    .ok()
+}
+
 /// Converts a `MouseCursor` from [`iced_native`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -158,6 +254,78 @@ pub fn mouse_button(mouse_button: winit::event::MouseButton) -> mouse::Button {
     }
 }
 
+/// Converts a `Touch` from [`winit`] to an [`iced_native`] touch event.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn touch_event(
+    touch: winit::event::Touch,
+    scale_factor: f64,
+) -> touch::Event {
+    let id = touch::Finger(touch.id);
+    let position = {
+        let location = touch.location.to_logical::<f64>(scale_factor);
+
+        Point::new(location.x as f32, location.y as f32)
+    };
+
+    match touch.phase {
+        winit::event::TouchPhase::Started => {
+            touch::Event::FingerPressed { id, position }
+        }
+        winit::event::TouchPhase::Moved => {
+            touch::Event::FingerMoved { id, position }
+        }
+        winit::event::TouchPhase::Ended => {
+            touch::Event::FingerLifted { id, position }
+        }
+        winit::event::TouchPhase::Cancelled => {
+            touch::Event::FingerLost { id, position }
+        }
+    }
+}
+
+/// Converts a `Touch` from [`winit`] to an [`iced_native`] stylus event, if it
+/// was produced by a stylus.
+///
+/// Only the touches reporting the altitude of the stylus are considered, as
+/// [`winit`] does not tell them apart otherwise. Erasers are not reported.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn stylus_event(
+    touch: winit::event::Touch,
+    scale_factor: f64,
+) -> Option<stylus::Event> {
+    let force = touch.force?;
+
+    let altitude = match force {
+        winit::event::Force::Calibrated {
+            altitude_angle: Some(altitude_angle),
+            ..
+        } => altitude_angle,
+        _ => return None,
+    };
+
+    let sample = stylus::Sample {
+        position: {
+            let location = touch.location.to_logical::<f64>(scale_factor);
+
+            Point::new(location.x as f32, location.y as f32)
+        },
+        pressure: force.normalized().min(1.0) as f32,
+        tilt: (std::f64::consts::FRAC_PI_2 - altitude) as f32,
+        tool: stylus::Tool::Pen,
+    };
+
+    Some(match touch.phase {
+        winit::event::TouchPhase::Started => stylus::Event::Pressed(sample),
+        winit::event::TouchPhase::Moved => stylus::Event::Moved(sample),
+        winit::event::TouchPhase::Ended => stylus::Event::Lifted(sample),
+        winit::event::TouchPhase::Cancelled => stylus::Event::Lost(sample),
+    })
+}
+
 /// Converts some `ModifiersState` from [`winit`] to an [`iced_native`]
 /// modifiers state.
 ///
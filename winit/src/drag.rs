@@ -0,0 +1,169 @@
+use crate::window::Direction;
+
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event::{ElementState, MouseButton, WindowEvent};
+
+/// Moves and resizes a window with the cursor, as requested by the
+/// [`window::drag`] and [`window::drag_resize`] commands.
+///
+/// The window follows the cursor until the left mouse button is released.
+///
+/// [`window::drag`]: ../window/fn.drag.html
+/// [`window::drag_resize`]: ../window/fn.drag_resize.html
+#[derive(Debug, Clone, Copy)]
+pub struct Drag {
+    cursor_position: PhysicalPosition<f64>,
+    active: Option<Active>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Active {
+    direction: Option<Direction>,
+    cursor_origin: PhysicalPosition<f64>,
+    position: PhysicalPosition<i32>,
+    size: PhysicalSize<u32>,
+}
+
+impl Drag {
+    /// Creates a new inactive [`Drag`].
+    ///
+    /// [`Drag`]: struct.Drag.html
+    pub fn new() -> Drag {
+        Drag {
+            cursor_position: PhysicalPosition::new(0.0, 0.0),
+            active: None,
+        }
+    }
+
+    /// Starts moving the window with the cursor, or resizing it from the
+    /// given [`Direction`].
+    ///
+    /// Nothing happens if the position of the window cannot be queried.
+    ///
+    /// [`Direction`]: ../window/enum.Direction.html
+    pub fn start(
+        &mut self,
+        window: &winit::window::Window,
+        direction: Option<Direction>,
+    ) {
+        if let Ok(position) = window.outer_position() {
+            self.active = Some(Active {
+                direction,
+                cursor_origin: screen_position(position, self.cursor_position),
+                position,
+                size: window.inner_size(),
+            });
+        }
+    }
+
+    /// Handles a `WindowEvent` of the window, moving or resizing it if a drag
+    /// is in progress.
+    pub fn handle_window_event(
+        &mut self,
+        event: &WindowEvent<'_>,
+        window: &winit::window::Window,
+    ) {
+        match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursor_position = *position;
+
+                if let Some(active) = self.active {
+                    if let Ok(position) = window.outer_position() {
+                        active.update(
+                            window,
+                            screen_position(position, self.cursor_position),
+                        );
+                    }
+                }
+            }
+            WindowEvent::MouseInput {
+                state: ElementState::Released,
+                button: MouseButton::Left,
+                ..
+            }
+            | WindowEvent::Focused(false) => {
+                self.active = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Default for Drag {
+    fn default() -> Drag {
+        Drag::new()
+    }
+}
+
+impl Active {
+    fn update(
+        &self,
+        window: &winit::window::Window,
+        cursor: PhysicalPosition<f64>,
+    ) {
+        let dx = (cursor.x - self.cursor_origin.x) as i32;
+        let dy = (cursor.y - self.cursor_origin.y) as i32;
+
+        let direction = match self.direction {
+            Some(direction) => direction,
+            None => {
+                window.set_outer_position(PhysicalPosition::new(
+                    self.position.x + dx,
+                    self.position.y + dy,
+                ));
+
+                return;
+            }
+        };
+
+        let (west, east, north, south) = match direction {
+            Direction::North => (false, false, true, false),
+            Direction::South => (false, false, false, true),
+            Direction::East => (false, true, false, false),
+            Direction::West => (true, false, false, false),
+            Direction::NorthEast => (false, true, true, false),
+            Direction::NorthWest => (true, false, true, false),
+            Direction::SouthEast => (false, true, false, true),
+            Direction::SouthWest => (true, false, false, true),
+        };
+
+        let mut x = self.position.x;
+        let mut y = self.position.y;
+        let mut width = self.size.width as i32;
+        let mut height = self.size.height as i32;
+
+        if west {
+            let dx = dx.min(width - 1);
+
+            x += dx;
+            width -= dx;
+        } else if east {
+            width = (width + dx).max(1);
+        }
+
+        if north {
+            let dy = dy.min(height - 1);
+
+            y += dy;
+            height -= dy;
+        } else if south {
+            height = (height + dy).max(1);
+        }
+
+        if west || north {
+            window.set_outer_position(PhysicalPosition::new(x, y));
+        }
+
+        window.set_inner_size(PhysicalSize::new(width as u32, height as u32));
+    }
+}
+
+fn screen_position(
+    window_position: PhysicalPosition<i32>,
+    cursor_position: PhysicalPosition<f64>,
+) -> PhysicalPosition<f64> {
+    PhysicalPosition::new(
+        f64::from(window_position.x) + cursor_position.x,
+        f64::from(window_position.y) + cursor_position.y,
+    )
+}
@@ -18,7 +18,7 @@
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 #![forbid(rust_2018_idioms)]
 
 #[doc(no_inline)]
@@ -26,17 +26,24 @@ pub use iced_native::*;
 pub use winit;
 
 pub mod application;
+pub mod clipboard;
 pub mod conversion;
+pub mod multi_window;
 pub mod settings;
+pub mod tray;
 
-mod clipboard;
+mod appearance;
+mod drag;
 mod mode;
+mod notifier;
 mod proxy;
 
 pub use application::Application;
 pub use clipboard::Clipboard;
+pub use drag::Drag;
 pub use mode::Mode;
 pub use proxy::Proxy;
 pub use settings::Settings;
+pub use tray::SystemTray;
 
-pub use iced_graphics::Viewport;
+pub use iced_graphics::{theme, Theme, Viewport};
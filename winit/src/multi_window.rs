@@ -0,0 +1,777 @@
+//! Create interactive, native cross-platform applications with multiple
+//! windows.
+use crate::{
+    application, conversion, mouse, window, Cache, Clipboard, Command, Debug,
+    Drag, Element, Event, Executor, Mode, Proxy, Runtime, Settings, Size,
+    Subscription, SystemTray, Theme, UserInterface,
+};
+use iced_graphics::window::Compositor;
+use iced_graphics::Viewport;
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// An interactive, native cross-platform application with multiple windows.
+///
+/// An [`Application`] starts with a single window, identified by
+/// [`window::Id::MAIN`]. Additional windows can be opened with
+/// [`window::spawn`] and closed with [`window::close`]. Every window has its
+/// own title and view.
+///
+/// The application exits once all of its windows are closed.
+///
+/// [`Application`]: trait.Application.html
+/// [`window::Id::MAIN`]: ../window/struct.Id.html#associatedconstant.MAIN
+/// [`window::spawn`]: ../window/fn.spawn.html
+/// [`window::close`]: ../window/fn.close.html
+pub trait Application: Sized {
+    /// The graphics backend to use to draw the windows of the
+    /// [`Application`].
+    ///
+    /// [`Application`]: trait.Application.html
+    type Renderer: crate::Renderer;
+
+    /// The type of __messages__ your [`Application`] will produce.
+    ///
+    /// [`Application`]: trait.Application.html
+    type Message: std::fmt::Debug + Send;
+
+    /// The data needed to initialize your [`Application`].
+    ///
+    /// [`Application`]: trait.Application.html
+    type Flags;
+
+    /// Initializes the [`Application`] with the flags provided to [`run`] as
+    /// part of the [`Settings`].
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`run`]: fn.run.html
+    /// [`Settings`]: ../settings/struct.Settings.html
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given [`window::Id`].
+    ///
+    /// [`window::Id`]: ../window/struct.Id.html
+    fn title(&self, window: window::Id) -> String;
+
+    /// Handles a __message__ and updates the state of the [`Application`].
+    ///
+    /// Any [`Command`] returned will be executed immediately in the
+    /// background.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Command`]: ../struct.Command.html
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+
+    /// Returns the event `Subscription` for the current state of the
+    /// application.
+    ///
+    /// Subscriptions receive the events of every window.
+    ///
+    /// By default, it returns an empty subscription.
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the current [`Theme`] of the [`Application`], shared by all of
+    /// its windows.
+    ///
+    /// By default, it returns the light theme.
+    ///
+    /// [`Theme`]: ../theme/enum.Theme.html
+    /// [`Application`]: trait.Application.html
+    fn theme(&self) -> Theme {
+        Theme::Light
+    }
+
+    /// Returns the widgets to display in the window with the given
+    /// [`window::Id`].
+    ///
+    /// [`window::Id`]: ../window/struct.Id.html
+    fn view(
+        &mut self,
+        window: window::Id,
+    ) -> Element<'_, Self::Message, Self::Renderer>;
+
+    /// Produces a message, if any, for an [`Event`] of the window with the
+    /// given [`window::Id`].
+    ///
+    /// By default, it produces no messages.
+    ///
+    /// [`Event`]: ../enum.Event.html
+    /// [`window::Id`]: ../window/struct.Id.html
+    fn on_event(
+        &self,
+        _window: window::Id,
+        _event: &Event,
+    ) -> Option<Self::Message> {
+        None
+    }
+
+    /// Returns whether the [`Application`] should exit.
+    ///
+    /// It is checked after every update. By default, it returns `false`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn should_exit(&self) -> bool {
+        false
+    }
+}
+
+/// An error that prevented a multi-window [`Application`] from running.
+///
+/// [`Application`]: trait.Application.html
+#[derive(Debug)]
+pub enum Error {
+    /// The main window could not be opened.
+    WindowCreationFailed(winit::error::OsError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::WindowCreationFailed(error) => {
+                write!(f, "The main window could not be opened: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Runs an [`Application`] with an executor, compositor, and the provided
+/// settings.
+///
+/// The [`Settings`] are used to open the main window. Once it is open, this
+/// function takes control of the current thread and does not return.
+///
+/// [`Application`]: trait.Application.html
+/// [`Settings`]: ../settings/struct.Settings.html
+pub fn run<A, E, C>(
+    settings: Settings<A::Flags>,
+    compositor_settings: C::Settings,
+) -> Result<(), Error>
+where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+{
+    use winit::{
+        event,
+        event_loop::{ControlFlow, EventLoop},
+    };
+
+    let mut debug = Debug::new();
+    debug.startup_started();
+
+    mouse::click::configure(settings.resolve_double_click());
+
+    let event_loop = EventLoop::with_user_event();
+    let mut runtime = {
+        let executor = E::new().expect("Create executor");
+        let proxy = Proxy::new(event_loop.create_proxy());
+
+        Runtime::new(executor, proxy)
+    };
+
+    let flags = settings.flags;
+    let (mut application, init_command) = runtime.enter(|| A::new(flags));
+
+    let (mut compositor, mut renderer) = C::new(compositor_settings);
+
+    compositor.set_theme(&mut renderer, application.theme());
+
+    application::load_fonts(&mut renderer, settings.fonts);
+
+    let primary_monitor = event_loop.primary_monitor();
+
+    let main_window = settings
+        .window
+        .clone()
+        .into_builder(
+            &application.title(window::Id::MAIN),
+            Mode::Windowed,
+            primary_monitor.clone(),
+        )
+        .build(&event_loop)
+        .map_err(Error::WindowCreationFailed)?;
+
+    settings.window.place(&main_window);
+
+    // The windows share the clipboard of the application
+    let clipboard = Clipboard::new(&main_window);
+
+    let mut windows = HashMap::new();
+    let _ = windows.insert(
+        window::Id::MAIN,
+        Window::new(
+            window::Id::MAIN,
+            main_window,
+            &settings.window,
+            &mut application,
+            &mut compositor,
+            &mut renderer,
+            &mut debug,
+        ),
+    );
+
+    let subscription = application.subscription();
+    runtime.track(subscription);
+
+    let mut appearance =
+        application::detect_appearance(&windows[&window::Id::MAIN].raw);
+
+    if let Some(appearance) = appearance {
+        runtime.broadcast(Event::Window(window::Event::AppearanceChanged(
+            appearance,
+        )));
+    }
+
+    let mut pending_command = Some(init_command);
+    let mut queued_messages = Vec::new();
+    let proxy = event_loop.create_proxy();
+    let mut system_tray = SystemTray::new(event_loop.create_proxy());
+    let mut modifiers = winit::event::ModifiersState::default();
+    let mut focused = window::Id::MAIN;
+    let mut is_cursor_grabbed = false;
+
+    debug.startup_finished();
+
+    event_loop.run(move |event, event_loop, control_flow| match event {
+        event::Event::NewEvents(event::StartCause::ResumeTimeReached {
+            ..
+        }) => {
+            let now = std::time::Instant::now();
+
+            // Some widgets requested a frame for this instant
+            for window in windows.values_mut() {
+                if matches!(window.redraw_at, Some(at) if at <= now) {
+                    window.redraw_at = None;
+
+                    window.queued_events.push(Event::Window(
+                        window::Event::RedrawRequested(now),
+                    ));
+                    window.raw.request_redraw();
+                }
+            }
+        }
+        event::Event::MainEventsCleared => {
+            let mut messages = std::mem::take(&mut queued_messages);
+
+            for (id, window) in windows.iter_mut() {
+                if window.queued_events.is_empty() {
+                    continue;
+                }
+
+                let mut user_interface = window.build(
+                    *id,
+                    &mut application,
+                    &mut renderer,
+                    &mut debug,
+                );
+
+                debug.event_processing_started();
+                messages.extend(user_interface.update(
+                    window.queued_events.drain(..),
+                    clipboard.as_ref().map(|c| c as _),
+                    &renderer,
+                ));
+                debug.event_processing_finished();
+
+                window.draw(user_interface, &mut renderer, &mut debug);
+            }
+
+            if messages.is_empty() && pending_command.is_none() {
+                return;
+            }
+
+            let mut command =
+                Command::batch(pending_command.take().into_iter().chain(
+                    messages.into_iter().map(|message| {
+                        debug.log_message(&message);
+
+                        debug.update_started();
+                        let command =
+                            runtime.enter(|| application.update(message));
+                        debug.update_finished();
+
+                        command
+                    }),
+                ));
+
+            for action in command.take_window_actions() {
+                match action {
+                    window::Action::Spawn { id, settings } => {
+                        let settings = crate::settings::Window::from(settings);
+
+                        let raw = match settings
+                            .clone()
+                            .into_builder(
+                                &application.title(id),
+                                Mode::Windowed,
+                                primary_monitor.clone(),
+                            )
+                            .build(event_loop)
+                        {
+                            Ok(raw) => raw,
+                            Err(error) => {
+                                log::error!(
+                                    "Window {:?} could not be opened: {}",
+                                    id,
+                                    error
+                                );
+
+                                let event = Event::Window(
+                                    window::Event::SpawnFailed(id),
+                                );
+
+                                if let Some(message) =
+                                    application.on_event(id, &event)
+                                {
+                                    let _ = proxy.send_event(message);
+                                }
+
+                                runtime.broadcast(event);
+                                continue;
+                            }
+                        };
+
+                        settings.place(&raw);
+
+                        let _ = windows.insert(
+                            id,
+                            Window::new(
+                                id,
+                                raw,
+                                &settings,
+                                &mut application,
+                                &mut compositor,
+                                &mut renderer,
+                                &mut debug,
+                            ),
+                        );
+                    }
+                    window::Action::Close(id) => {
+                        if windows.remove(&id).is_some() {
+                            let event = Event::Window(window::Event::Closed);
+
+                            if let Some(message) =
+                                application.on_event(id, &event)
+                            {
+                                let _ = proxy.send_event(message);
+                            }
+
+                            runtime.broadcast(event);
+                        }
+                    }
+                    window::Action::Exit => {
+                        *control_flow = ControlFlow::Exit;
+                    }
+                    window::Action::SetScaleFactor(id, scale_factor) => {
+                        if let Some(window) = windows.get_mut(&id) {
+                            window.scale_factor = scale_factor;
+
+                            let event = application::scale_viewport(
+                                &window.raw,
+                                scale_factor,
+                                &mut window.viewport,
+                                &mut window.resized,
+                            );
+
+                            // Every window is rebuilt below
+                            runtime.broadcast(event);
+                        }
+                    }
+                    action => {
+                        // Actions that do not target a window, like fetching
+                        // the monitors, are performed by the focused one
+                        let id = action.window().unwrap_or(focused);
+
+                        if let Some(window) = windows.get_mut(&id) {
+                            if let Some(event) =
+                                application::handle_window_action(
+                                    action,
+                                    &window.raw,
+                                    &proxy,
+                                    control_flow,
+                                    &mut is_cursor_grabbed,
+                                    &mut window.drag,
+                                )
+                            {
+                                window.queued_events.push(event.clone());
+                                runtime.broadcast(event);
+                            }
+                        } else {
+                            log::warn!(
+                                "Window {:?} does not exist: {:?}",
+                                id,
+                                action
+                            );
+                        }
+                    }
+                }
+            }
+
+            for action in command.take_notifications() {
+                runtime.spawn(application::show_notification(action));
+            }
+
+            for action in command.take_tray_actions() {
+                system_tray.perform(action);
+            }
+
+            for action in command.take_clipboard_actions() {
+                application::handle_clipboard_action(
+                    action,
+                    clipboard.as_ref(),
+                    proxy.clone(),
+                );
+            }
+
+            for action in command.take_font_actions() {
+                let _ = proxy.send_event(application::handle_font_action(
+                    action,
+                    &mut renderer,
+                ));
+            }
+
+            let focus = command.take_focus();
+
+            runtime.spawn(command);
+
+            // Update subscriptions
+            let subscription = application.subscription();
+            runtime.track(subscription);
+
+            // Update theme
+            compositor.set_theme(&mut renderer, application.theme());
+
+            // Rebuild every window, as the state of the application changed
+            for (id, window) in windows.iter_mut() {
+                let mut user_interface = window.build(
+                    *id,
+                    &mut application,
+                    &mut renderer,
+                    &mut debug,
+                );
+
+                if let Some(focus) = &focus {
+                    let _ = user_interface.focus(focus);
+                }
+
+                window.draw(user_interface, &mut renderer, &mut debug);
+
+                // Update window title
+                let new_title = application.title(*id);
+
+                if window.title != new_title {
+                    window.raw.set_title(&new_title);
+
+                    window.title = new_title;
+                }
+            }
+
+            if windows.is_empty() || application.should_exit() {
+                *control_flow = ControlFlow::Exit;
+            }
+        }
+        event::Event::UserEvent(message) => {
+            queued_messages.push(message);
+        }
+        event::Event::RedrawRequested(window_id) => {
+            let window = match windows
+                .values_mut()
+                .find(|window| window.raw.id() == window_id)
+            {
+                Some(window) => window,
+                None => return,
+            };
+
+            debug.render_started();
+
+            if window.resized {
+                let physical_size = window.viewport.physical_size();
+
+                window.swap_chain = compositor.create_swap_chain(
+                    &window.surface,
+                    physical_size.width,
+                    physical_size.height,
+                );
+
+                window.resized = false;
+            }
+
+            let new_mouse_interaction = compositor.draw(
+                &mut renderer,
+                &mut window.swap_chain,
+                &window.viewport,
+                &window.primitive,
+                &debug.overlay(),
+            );
+
+            debug.render_finished();
+
+            if new_mouse_interaction != window.mouse_interaction {
+                window.raw.set_cursor_icon(conversion::mouse_interaction(
+                    new_mouse_interaction,
+                ));
+
+                window.mouse_interaction = new_mouse_interaction;
+            }
+
+            let frame = Event::Window(window::Event::RedrawRequested(
+                std::time::Instant::now(),
+            ));
+
+            // Widgets with built-in animations keep requesting frames while
+            // they are drawn, either right away or at a later instant
+            window.redraw_at =
+                crate::Renderer::take_redraw_request(&mut renderer);
+
+            if matches!(
+                window.redraw_at,
+                Some(at) if at <= std::time::Instant::now()
+            ) {
+                window.redraw_at = None;
+
+                window.queued_events.push(frame.clone());
+                window.raw.request_redraw();
+            }
+
+            // Subscriptions to `window::frames` will produce a message,
+            // which will trigger another redraw
+            runtime.broadcast(frame);
+        }
+        event::Event::WindowEvent {
+            event: window_event,
+            window_id,
+        } => {
+            let id = match windows
+                .iter()
+                .find(|(_, window)| window.raw.id() == window_id)
+            {
+                Some((id, _)) => *id,
+                None => return,
+            };
+
+            match window_event {
+                winit::event::WindowEvent::CloseRequested
+                    if windows[&id].close_on_request =>
+                {
+                    let _ = windows.remove(&id);
+
+                    // The application keeps track of the windows that exist
+                    let event = Event::Window(window::Event::Closed);
+
+                    if let Some(message) = application.on_event(id, &event) {
+                        queued_messages.push(message);
+                    }
+
+                    runtime.broadcast(event);
+
+                    if windows.is_empty() {
+                        *control_flow = ControlFlow::Exit;
+                    }
+
+                    return;
+                }
+                winit::event::WindowEvent::Focused(true) => {
+                    focused = id;
+                }
+                _ => {}
+            }
+
+            let window = windows.get_mut(&id).expect("Find window");
+
+            window.drag.handle_window_event(&window_event, &window.raw);
+
+            if let Some(event) =
+                application::handle_appearance(&window_event, &mut appearance)
+            {
+                if let Some(message) = application.on_event(id, &event) {
+                    queued_messages.push(message);
+                }
+
+                window.queued_events.push(event.clone());
+                runtime.broadcast(event);
+            }
+
+            application::handle_window_event(
+                &window_event,
+                window.scale_factor,
+                &mut modifiers,
+                &mut window.viewport,
+                &mut window.resized,
+                &mut debug,
+            );
+
+            if let Some(event) = conversion::window_event(
+                &window_event,
+                window.viewport.scale_factor(),
+                modifiers,
+            ) {
+                if let Some(message) = application.on_event(id, &event) {
+                    queued_messages.push(message);
+                }
+
+                window.queued_events.push(event.clone());
+                runtime.broadcast(event);
+            }
+        }
+        event::Event::DeviceEvent {
+            event: device_event,
+            ..
+        } if is_cursor_grabbed => {
+            if let Some(event) = conversion::device_event(&device_event) {
+                if let Some(window) = windows.get_mut(&focused) {
+                    window.queued_events.push(event.clone());
+                }
+
+                runtime.broadcast(event);
+            }
+        }
+        _ => {
+            *control_flow = match windows
+                .values()
+                .filter_map(|window| window.redraw_at)
+                .min()
+            {
+                Some(at) => ControlFlow::WaitUntil(at),
+                None => ControlFlow::Wait,
+            };
+        }
+    })
+}
+
+/// A window of a multi-window application, along with its own surface and
+/// user interface state.
+struct Window<C: Compositor> {
+    raw: winit::window::Window,
+    title: String,
+    close_on_request: bool,
+    surface: C::Surface,
+    swap_chain: C::SwapChain,
+    viewport: Viewport,
+    scale_factor: f64,
+    resized: bool,
+    drag: Drag,
+    cache: Cache,
+    primitive: <C::Renderer as crate::Renderer>::Output,
+    mouse_interaction: mouse::Interaction,
+    queued_events: Vec<Event>,
+    redraw_at: Option<std::time::Instant>,
+}
+
+impl<C: Compositor> Window<C> {
+    fn new<A>(
+        id: window::Id,
+        raw: winit::window::Window,
+        settings: &crate::settings::Window,
+        application: &mut A,
+        compositor: &mut C,
+        renderer: &mut C::Renderer,
+        debug: &mut Debug,
+    ) -> Self
+    where
+        A: Application<Renderer = C::Renderer>,
+    {
+        let title = application.title(id);
+
+        let physical_size = raw.inner_size();
+        let viewport = Viewport::with_physical_size(
+            Size::new(physical_size.width, physical_size.height),
+            raw.scale_factor() * settings.scale_factor,
+        );
+
+        let surface = compositor.create_surface(&raw);
+        let swap_chain = compositor.create_swap_chain(
+            &surface,
+            physical_size.width,
+            physical_size.height,
+        );
+
+        debug.view_started();
+        let view = application.view(id);
+        debug.view_finished();
+
+        let user_interface = build_user_interface(
+            view,
+            viewport.logical_size(),
+            Cache::default(),
+            renderer,
+            debug,
+        );
+
+        debug.draw_started();
+        let primitive = user_interface.draw(renderer);
+        debug.draw_finished();
+
+        Window {
+            raw,
+            title,
+            close_on_request: settings.close_on_request,
+            surface,
+            swap_chain,
+            viewport,
+            scale_factor: settings.scale_factor,
+            resized: false,
+            drag: Drag::new(),
+            cache: user_interface.into_cache(),
+            primitive,
+            mouse_interaction: mouse::Interaction::default(),
+            queued_events: Vec::new(),
+            redraw_at: None,
+        }
+    }
+
+    fn build<'a, A>(
+        &mut self,
+        id: window::Id,
+        application: &'a mut A,
+        renderer: &mut C::Renderer,
+        debug: &mut Debug,
+    ) -> UserInterface<'a, A::Message, C::Renderer>
+    where
+        A: Application<Renderer = C::Renderer>,
+    {
+        debug.view_started();
+        let view = application.view(id);
+        debug.view_finished();
+
+        build_user_interface(
+            view,
+            self.viewport.logical_size(),
+            std::mem::take(&mut self.cache),
+            renderer,
+            debug,
+        )
+    }
+
+    fn draw<Message>(
+        &mut self,
+        user_interface: UserInterface<'_, Message, C::Renderer>,
+        renderer: &mut C::Renderer,
+        debug: &mut Debug,
+    ) {
+        debug.draw_started();
+        self.primitive = user_interface.draw(renderer);
+        debug.draw_finished();
+
+        self.cache = user_interface.into_cache();
+        self.raw.request_redraw();
+    }
+}
+
+fn build_user_interface<'a, Message, Renderer: crate::Renderer>(
+    view: Element<'a, Message, Renderer>,
+    size: Size,
+    cache: Cache,
+    renderer: &mut Renderer,
+    debug: &mut Debug,
+) -> UserInterface<'a, Message, Renderer> {
+    debug.layout_started();
+    let user_interface = UserInterface::build(view, size, cache, renderer);
+    debug.layout_finished();
+
+    user_interface
+}
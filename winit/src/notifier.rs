@@ -0,0 +1,55 @@
+use iced_native::notification::{Action, Error, Notification};
+use iced_native::Command;
+
+/// Turns a [`notification::Action`] into a [`Command`] that shows the
+/// notification using the notification service of the system.
+///
+/// The notification is shown by [`notify-rust`], which talks to the service
+/// natively: through D-Bus on Linux and BSD, `NSUserNotificationCenter` on
+/// macOS, and toast notifications on Windows. The message produced with the
+/// result is delivered by the runtime once the [`Command`] is spawned.
+///
+/// [`notification::Action`]: ../notification/enum.Action.html
+/// [`Command`]: ../struct.Command.html
+/// [`notify-rust`]: https://github.com/hoodie/notify-rust
+pub fn show_notification<Message: Send + 'static>(
+    action: Action<Message>,
+) -> Command<Message> {
+    let Action::Show(notification, on_show) = action;
+
+    Command::perform(show(notification), on_show)
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+async fn show(notification: Notification) -> Result<(), Error> {
+    // The D-Bus service is reached asynchronously, without blocking the
+    // executor
+    let _handle = build(&notification).show_async().await.map_err(failed)?;
+
+    Ok(())
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+async fn show(notification: Notification) -> Result<(), Error> {
+    let _handle = build(&notification).show().map_err(failed)?;
+
+    Ok(())
+}
+
+fn build(notification: &Notification) -> notify_rust::Notification {
+    let mut builder = notify_rust::Notification::new();
+
+    let _ = builder
+        .summary(&notification.title)
+        .body(&notification.body);
+
+    if let Some(icon) = &notification.icon {
+        let _ = builder.icon(icon);
+    }
+
+    builder
+}
+
+fn failed(error: notify_rust::error::Error) -> Error {
+    Error::Failed(error.to_string())
+}
@@ -9,12 +9,14 @@ mod platform;
 pub use platform::PlatformSpecific;
 
 use crate::conversion;
-use crate::Mode;
+use crate::{mouse, window, Mode};
 use winit::monitor::MonitorHandle;
 use winit::window::WindowBuilder;
 
+use std::borrow::Cow;
+
 /// The settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Settings<Flags> {
     /// The [`Window`] settings
     ///
@@ -25,20 +27,77 @@ pub struct Settings<Flags> {
     ///
     /// [`Application`]: trait.Application.html
     pub flags: Flags,
+
+    /// The settings used to recognize double and triple clicks.
+    ///
+    /// If `None` is provided, the settings of the system will be used when
+    /// they can be queried. Otherwise, [`DoubleClick::default`] is used.
+    ///
+    /// [`DoubleClick::default`]: ../mouse/struct.DoubleClick.html
+    pub double_click: Option<mouse::DoubleClick>,
+
+    /// The fonts to register when the application starts.
+    ///
+    /// They can be referenced by their family name with [`Font::Family`].
+    ///
+    /// [`Font::Family`]: ../enum.Font.html#variant.Family
+    pub fonts: Vec<Cow<'static, [u8]>>,
+}
+
+impl<Flags> Settings<Flags> {
+    /// Returns the [`DoubleClick`] settings that the runtime should use.
+    ///
+    /// [`DoubleClick`]: ../mouse/struct.DoubleClick.html
+    pub fn resolve_double_click(&self) -> mouse::DoubleClick {
+        self.double_click
+            .or_else(platform::double_click)
+            .unwrap_or_default()
+    }
 }
 
 /// The window settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Window {
     /// The size of the window.
     pub size: (u32, u32),
 
+    /// The minimum size of the window, if any.
+    pub min_size: Option<(u32, u32)>,
+
+    /// The maximum size of the window, if any.
+    pub max_size: Option<(u32, u32)>,
+
+    /// The initial position of the window.
+    ///
+    /// It is applied by [`place`] once the window is built.
+    ///
+    /// [`place`]: #method.place
+    pub position: window::Position,
+
     /// Whether the window should be resizable or not.
     pub resizable: bool,
 
     /// Whether the window should have a border, a title bar, etc.
     pub decorations: bool,
 
+    /// Whether the window should always be on top of other windows.
+    pub always_on_top: bool,
+
+    /// Whether the background of the window should be transparent.
+    pub transparent: bool,
+
+    /// The scale factor of the user interface, applied on top of the scale
+    /// factor of the system.
+    pub scale_factor: f64,
+
+    /// The icon of the window.
+    pub icon: Option<window::Icon>,
+
+    /// Whether the window should close when the user requests it.
+    ///
+    /// If `false`, a `CloseRequested` window event is produced instead.
+    pub close_on_request: bool,
+
     /// Platform specific settings.
     pub platform_specific: platform::PlatformSpecific,
 }
@@ -60,8 +119,21 @@ impl Window {
             .with_inner_size(winit::dpi::LogicalSize { width, height })
             .with_resizable(self.resizable)
             .with_decorations(self.decorations)
+            .with_always_on_top(self.always_on_top)
+            .with_transparent(self.transparent)
+            .with_window_icon(self.icon.as_ref().and_then(conversion::icon))
             .with_fullscreen(conversion::fullscreen(primary_monitor, mode));
 
+        if let Some((width, height)) = self.min_size {
+            window_builder = window_builder
+                .with_min_inner_size(winit::dpi::LogicalSize { width, height });
+        }
+
+        if let Some((width, height)) = self.max_size {
+            window_builder = window_builder
+                .with_max_inner_size(winit::dpi::LogicalSize { width, height });
+        }
+
         #[cfg(target_os = "windows")]
         {
             use winit::platform::windows::WindowBuilderExtWindows;
@@ -73,14 +145,67 @@ impl Window {
 
         window_builder
     }
+
+    /// Moves a window built from these settings to its initial position.
+    pub fn place(&self, window: &winit::window::Window) {
+        match self.position {
+            window::Position::Default => {}
+            window::Position::Centered => center(window),
+            window::Position::Specific(x, y) => {
+                window.set_outer_position(winit::dpi::LogicalPosition { x, y });
+            }
+        }
+    }
+}
+
+/// Moves the given window to the center of its current monitor.
+pub(crate) fn center(window: &winit::window::Window) {
+    let monitor = window.current_monitor();
+    let monitor_position = monitor.position();
+    let monitor_size = monitor.size();
+    let window_size = window.outer_size();
+
+    window.set_outer_position(winit::dpi::PhysicalPosition {
+        x: monitor_position.x
+            + (monitor_size.width as i32 - window_size.width as i32) / 2,
+        y: monitor_position.y
+            + (monitor_size.height as i32 - window_size.height as i32) / 2,
+    });
+}
+
+impl From<iced_native::window::Settings> for Window {
+    fn from(settings: iced_native::window::Settings) -> Window {
+        Window {
+            size: settings.size,
+            min_size: settings.min_size,
+            max_size: settings.max_size,
+            position: settings.position,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            always_on_top: settings.always_on_top,
+            transparent: settings.transparent,
+            scale_factor: settings.scale_factor,
+            icon: settings.icon,
+            close_on_request: settings.close_on_request,
+            platform_specific: Default::default(),
+        }
+    }
 }
 
 impl Default for Window {
     fn default() -> Window {
         Window {
             size: (1024, 768),
+            min_size: None,
+            max_size: None,
+            position: window::Position::Default,
             resizable: true,
             decorations: true,
+            always_on_top: false,
+            transparent: false,
+            scale_factor: 1.0,
+            icon: None,
+            close_on_request: true,
             platform_specific: Default::default(),
         }
     }
@@ -1,6 +1,14 @@
 #![cfg(not(target_os = "windows"))]
 //! Platform specific settings for not Windows.
+use crate::mouse;
 
 /// The platform specific window settings of an application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct PlatformSpecific {}
+
+/// Queries the double click settings of the system.
+///
+/// They cannot be queried in this platform yet.
+pub fn double_click() -> Option<mouse::DoubleClick> {
+    None
+}
@@ -1,5 +1,8 @@
 #![cfg(target_os = "windows")]
 //! Platform specific settings for Windows.
+use crate::mouse;
+
+use std::time::Duration;
 
 /// The platform specific window settings of an application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -7,3 +10,28 @@ pub struct PlatformSpecific {
     /// Parent Window
     pub parent: Option<winapi::shared::windef::HWND>,
 }
+
+/// Queries the double click settings of the system.
+#[allow(unsafe_code)]
+pub fn double_click() -> Option<mouse::DoubleClick> {
+    use winapi::um::winuser::{
+        GetDoubleClickTime, GetSystemMetrics, SM_CXDOUBLECLK, SM_CYDOUBLECLK,
+    };
+
+    // The system metrics describe a rectangle centered on the first click.
+    //
+    // These functions take no pointers and only read global settings of the
+    // system, so they are always safe to call.
+    let (threshold, width, height) = unsafe {
+        (
+            GetDoubleClickTime(),
+            GetSystemMetrics(SM_CXDOUBLECLK),
+            GetSystemMetrics(SM_CYDOUBLECLK),
+        )
+    };
+
+    Some(mouse::DoubleClick {
+        threshold: Duration::from_millis(u64::from(threshold)),
+        distance: (width.max(height).max(0) / 2) as u16,
+    })
+}
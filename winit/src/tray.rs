@@ -0,0 +1,81 @@
+//! Show an icon in the system tray.
+pub use iced_native::tray::{remove, show, Action, Item, Tray};
+
+#[cfg(all(
+    feature = "tray",
+    unix,
+    not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+))]
+#[path = "tray/status_notifier.rs"]
+mod platform;
+
+#[cfg(all(feature = "tray", any(target_os = "windows", target_os = "macos")))]
+#[path = "tray/tray_icon.rs"]
+mod platform;
+
+#[cfg(not(all(
+    feature = "tray",
+    any(
+        target_os = "windows",
+        target_os = "macos",
+        all(unix, not(any(target_os = "ios", target_os = "android")))
+    )
+)))]
+#[path = "tray/unsupported.rs"]
+mod platform;
+
+use winit::event_loop::EventLoopProxy;
+
+/// The icon of an application in the system tray.
+///
+/// A [`SystemTray`] performs the tray actions of an application and sends the
+/// messages produced by its [`Tray`] to the event loop.
+///
+/// The tray is only available with the `tray` feature. It is shown through
+/// the StatusNotifierItem D-Bus service on Linux and BSD desktops, and through
+/// [`tray-icon`] on Windows and macOS. Elsewhere, showing a [`Tray`] logs a
+/// warning.
+///
+/// [`SystemTray`]: struct.SystemTray.html
+/// [`Tray`]: struct.Tray.html
+/// [`tray-icon`]: https://github.com/tauri-apps/tray-icon
+#[allow(missing_debug_implementations)]
+pub struct SystemTray<Message: 'static> {
+    proxy: EventLoopProxy<Message>,
+    raw: Option<platform::Tray<Message>>,
+}
+
+impl<Message: Send + 'static> SystemTray<Message> {
+    /// Creates a new [`SystemTray`] sending its messages to the given event
+    /// loop.
+    ///
+    /// No icon is shown until a [`Tray`] is shown.
+    ///
+    /// [`SystemTray`]: struct.SystemTray.html
+    /// [`Tray`]: struct.Tray.html
+    pub fn new(proxy: EventLoopProxy<Message>) -> Self {
+        SystemTray { proxy, raw: None }
+    }
+
+    /// Performs a tray [`Action`].
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn perform(&mut self, action: Action<Message>) {
+        match action {
+            Action::Show(tray) => {
+                let result = match &mut self.raw {
+                    Some(raw) => raw.update(tray),
+                    None => platform::Tray::new(tray, self.proxy.clone())
+                        .map(|raw| self.raw = Some(raw)),
+                };
+
+                if let Err(error) = result {
+                    log::warn!("Tray could not be shown: {}", error);
+                }
+            }
+            Action::Remove => {
+                self.raw = None;
+            }
+        }
+    }
+}
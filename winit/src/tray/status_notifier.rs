@@ -0,0 +1,153 @@
+//! The system tray of Linux and BSD desktops.
+//!
+//! The tray is exposed through the StatusNotifierItem D-Bus service, which
+//! [`ksni`] runs in a background thread.
+//!
+//! [`ksni`]: https://github.com/iovxw/ksni
+use iced_native::tray;
+use iced_native::window::Icon;
+
+use ksni::blocking::{Handle, TrayMethods};
+use std::error::Error;
+use winit::event_loop::EventLoopProxy;
+
+pub struct Tray<Message: 'static> {
+    handle: Handle<Service<Message>>,
+}
+
+impl<Message: Send + 'static> Tray<Message> {
+    pub fn new(
+        tray: tray::Tray<Message>,
+        proxy: EventLoopProxy<Message>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let handle = Service { tray, proxy }.spawn()?;
+
+        Ok(Tray { handle })
+    }
+
+    pub fn update(
+        &mut self,
+        tray: tray::Tray<Message>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.handle
+            .update(move |service| service.tray = tray)
+            .ok_or_else(|| "The tray service stopped".into())
+    }
+}
+
+impl<Message: 'static> Drop for Tray<Message> {
+    fn drop(&mut self) {
+        let _ = self.handle.shutdown();
+    }
+}
+
+struct Service<Message: 'static> {
+    tray: tray::Tray<Message>,
+    proxy: EventLoopProxy<Message>,
+}
+
+impl<Message: Send + 'static> Service<Message> {
+    fn select(&mut self, index: usize) {
+        if let Some(message) =
+            self.tray.menu().get(index).and_then(tray::Item::select)
+        {
+            let _ = self.proxy.send_event(message);
+        }
+    }
+}
+
+impl<Message: Send + 'static> ksni::Tray for Service<Message> {
+    fn id(&self) -> String {
+        application_id()
+    }
+
+    fn title(&self) -> String {
+        self.tray.tooltip_text().unwrap_or_default().to_owned()
+    }
+
+    fn icon_pixmap(&self) -> Vec<ksni::Icon> {
+        vec![pixmap(self.tray.icon())]
+    }
+
+    fn tool_tip(&self) -> ksni::ToolTip {
+        ksni::ToolTip {
+            title: self.title(),
+            ..ksni::ToolTip::default()
+        }
+    }
+
+    fn activate(&mut self, _x: i32, _y: i32) {
+        if let Some(message) = self.tray.click() {
+            let _ = self.proxy.send_event(message);
+        }
+    }
+
+    fn menu(&self) -> Vec<ksni::MenuItem<Self>> {
+        self.tray
+            .menu()
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                ksni::menu::StandardItem {
+                    label: item.label().to_owned(),
+                    enabled: item.is_enabled(),
+                    activate: Box::new(move |service: &mut Self| {
+                        service.select(index)
+                    }),
+                    ..ksni::menu::StandardItem::default()
+                }
+                .into()
+            })
+            .collect()
+    }
+}
+
+/// Derives the identifier of the tray from the name of the executable, as it
+/// becomes part of a D-Bus name.
+fn application_id() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|path| {
+            path.file_stem()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .map(|name| {
+            name.chars()
+                .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .collect::<String>()
+        })
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| String::from("iced"))
+}
+
+/// Converts an [`Icon`] into a pixmap of the StatusNotifierItem
+/// specification, which stores its pixels in ARGB32.
+///
+/// [`Icon`]: ../../window/struct.Icon.html
+fn pixmap(icon: &Icon) -> ksni::Icon {
+    ksni::Icon {
+        width: icon.width() as i32,
+        height: icon.height() as i32,
+        data: icon
+            .rgba()
+            .chunks_exact(4)
+            .flat_map(|pixel| [pixel[3], pixel[0], pixel[1], pixel[2]])
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_icons_to_argb() {
+        let icon = Icon::from_rgba(vec![1, 2, 3, 4, 5, 6, 7, 8], 2, 1)
+            .expect("Create icon");
+
+        let pixmap = pixmap(&icon);
+
+        assert_eq!((pixmap.width, pixmap.height), (2, 1));
+        assert_eq!(pixmap.data, vec![4, 1, 2, 3, 8, 5, 6, 7]);
+    }
+}
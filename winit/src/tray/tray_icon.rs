@@ -0,0 +1,158 @@
+//! The system tray of Windows and macOS.
+//!
+//! The tray is shown by [`tray-icon`], which needs to run in the thread of the
+//! event loop.
+//!
+//! [`tray-icon`]: https://github.com/tauri-apps/tray-icon
+use iced_native::tray;
+use iced_native::window::Icon;
+
+use std::error::Error;
+use std::sync::{Arc, Mutex, Once};
+use tray_icon::menu::{Menu, MenuEvent, MenuItem};
+use tray_icon::{
+    MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent,
+};
+use winit::event_loop::EventLoopProxy;
+
+pub struct Tray<Message: 'static> {
+    raw: TrayIcon,
+    shared: Arc<Mutex<Shared<Message>>>,
+}
+
+struct Shared<Message: 'static> {
+    tray: tray::Tray<Message>,
+    proxy: EventLoopProxy<Message>,
+}
+
+impl<Message: Send + 'static> Tray<Message> {
+    pub fn new(
+        tray: tray::Tray<Message>,
+        proxy: EventLoopProxy<Message>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut builder = TrayIconBuilder::new()
+            .with_icon(icon(tray.icon())?)
+            .with_menu(Box::new(menu(&tray)?))
+            .with_menu_on_left_click(false);
+
+        if let Some(tooltip) = tray.tooltip_text() {
+            builder = builder.with_tooltip(tooltip);
+        }
+
+        let raw = builder.build()?;
+        let shared = Arc::new(Mutex::new(Shared { tray, proxy }));
+
+        listen(shared.clone());
+
+        Ok(Tray { raw, shared })
+    }
+
+    pub fn update(
+        &mut self,
+        tray: tray::Tray<Message>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.raw.set_icon(Some(icon(tray.icon())?))?;
+        self.raw.set_tooltip(tray.tooltip_text())?;
+        self.raw.set_menu(Some(Box::new(menu(&tray)?)));
+
+        if let Ok(mut shared) = self.shared.lock() {
+            shared.tray = tray;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Message: 'static> Drop for Tray<Message> {
+    fn drop(&mut self) {
+        if let Ok(mut listener) = LISTENER.lock() {
+            *listener = None;
+        }
+    }
+}
+
+enum Event {
+    Tray(TrayIconEvent),
+    Menu(MenuEvent),
+}
+
+type Listener = Box<dyn Fn(Event) + Send>;
+
+/// The listener of the current tray.
+///
+/// The event handlers of `tray-icon` can only be set once per process, so
+/// they forward their events to the current tray through it.
+static LISTENER: Mutex<Option<Listener>> = Mutex::new(None);
+
+fn listen<Message: Send + 'static>(shared: Arc<Mutex<Shared<Message>>>) {
+    static HANDLERS: Once = Once::new();
+
+    HANDLERS.call_once(|| {
+        TrayIconEvent::set_event_handler(Some(|event| {
+            forward(Event::Tray(event))
+        }));
+        MenuEvent::set_event_handler(Some(|event| forward(Event::Menu(event))));
+    });
+
+    let listener: Listener = Box::new(move |event| {
+        let shared = match shared.lock() {
+            Ok(shared) => shared,
+            Err(_) => return,
+        };
+
+        let message = match event {
+            Event::Tray(TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            }) => shared.tray.click(),
+            Event::Tray(_) => None,
+            Event::Menu(MenuEvent { id }) => {
+                id.0.parse::<usize>()
+                    .ok()
+                    .and_then(|index| shared.tray.menu().get(index))
+                    .and_then(tray::Item::select)
+            }
+        };
+
+        if let Some(message) = message {
+            let _ = shared.proxy.send_event(message);
+        }
+    });
+
+    if let Ok(mut current) = LISTENER.lock() {
+        *current = Some(listener);
+    }
+}
+
+fn forward(event: Event) {
+    if let Ok(listener) = LISTENER.lock() {
+        if let Some(listener) = listener.as_ref() {
+            listener(event);
+        }
+    }
+}
+
+fn icon(icon: &Icon) -> Result<tray_icon::Icon, Box<dyn Error>> {
+    Ok(tray_icon::Icon::from_rgba(
+        icon.rgba().to_vec(),
+        icon.width(),
+        icon.height(),
+    )?)
+}
+
+/// Builds the menu of a [`Tray`], identifying each item by its index.
+///
+/// [`Tray`]: ../struct.Tray.html
+fn menu<Message>(tray: &tray::Tray<Message>) -> Result<Menu, Box<dyn Error>> {
+    let menu = Menu::new();
+
+    for (index, item) in tray.menu().iter().enumerate() {
+        // An ampersand marks a mnemonic unless it is doubled
+        let label = item.label().replace('&', "&&");
+
+        menu.append(&MenuItem::with_id(index, label, item.is_enabled(), None))?;
+    }
+
+    Ok(menu)
+}
@@ -0,0 +1,25 @@
+//! The system tray of platforms that are not supported yet.
+use iced_native::tray;
+
+use std::error::Error;
+use std::marker::PhantomData;
+
+use winit::event_loop::EventLoopProxy;
+
+pub struct Tray<Message>(PhantomData<Message>);
+
+impl<Message: Send + 'static> Tray<Message> {
+    pub fn new(
+        _tray: tray::Tray<Message>,
+        _proxy: EventLoopProxy<Message>,
+    ) -> Result<Self, Box<dyn Error>> {
+        Err("The system tray is not supported on this platform".into())
+    }
+
+    pub fn update(
+        &mut self,
+        _tray: tray::Tray<Message>,
+    ) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}